@@ -158,6 +158,14 @@ impl AnimatedValue for AnimColor {
             ..self
         }
     }
+
+    fn retarget(&mut self, from: &Self, direction: AnimDirection) {
+        match direction {
+            AnimDirection::Forward => self.origin = from.value,
+            AnimDirection::Reverse => self.destination = from.value,
+        }
+        self.value = from.value;
+    }
 }
 
 impl ReadAnimatedValue for AnimColor {
@@ -126,6 +126,14 @@ impl AnimatedValue for AnimNum {
             ..self
         }
     }
+
+    fn retarget(&mut self, from: &Self, direction: AnimDirection) {
+        match direction {
+            AnimDirection::Forward => self.origin = from.value,
+            AnimDirection::Reverse => self.destination = from.value,
+        }
+        self.value = from.value;
+    }
 }
 
 impl ReadAnimatedValue for AnimNum {
@@ -115,4 +115,10 @@ impl<Animated: AnimatedValue, const N: usize> AnimatedValue for AnimSequential<A
             acc_index: 0,
         }
     }
+
+    fn retarget(&mut self, from: &Self, direction: AnimDirection) {
+        for (value, from) in self.values.iter_mut().zip(from.values.iter()) {
+            value.retarget(from, direction);
+        }
+    }
 }
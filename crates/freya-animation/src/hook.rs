@@ -1,19 +1,31 @@
 use std::{
+    fmt::Debug,
     ops::Deref,
-    time::{
-        Duration,
-        Instant,
-    },
+    time::Duration,
 };
 
 use async_io::Timer;
 use freya_core::prelude::*;
 
-#[derive(Default, PartialEq, Clone, Debug)]
+#[derive(Default, PartialEq, Clone)]
 pub struct AnimConfiguration {
     on_finish: OnFinish,
     on_creation: OnCreation,
     on_change: OnChange,
+    repeat: Repeat,
+    on_end: Option<NoArgCallback<()>>,
+}
+
+impl Debug for AnimConfiguration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AnimConfiguration")
+            .field("on_finish", &self.on_finish)
+            .field("on_creation", &self.on_creation)
+            .field("on_change", &self.on_change)
+            .field("repeat", &self.repeat)
+            .field("on_end", &self.on_end.is_some())
+            .finish()
+    }
 }
 
 impl AnimConfiguration {
@@ -22,6 +34,45 @@ impl AnimConfiguration {
         self
     }
 
+    /// Run the animation `times` times in total.
+    ///
+    /// Restarts the animation on finish unless another [OnFinish] was configured.
+    pub fn repeat(&mut self, times: usize) -> &mut Self {
+        self.repeat = Repeat::Times(times);
+        if self.on_finish == OnFinish::Nothing {
+            self.on_finish = OnFinish::restart();
+        }
+        self
+    }
+
+    /// Keep running the animation forever.
+    ///
+    /// Restarts the animation on finish unless another [OnFinish] was configured.
+    pub fn infinite(&mut self) -> &mut Self {
+        self.repeat = Repeat::Infinite;
+        if self.on_finish == OnFinish::Nothing {
+            self.on_finish = OnFinish::restart();
+        }
+        self
+    }
+
+    /// Toggle the direction of the animation on every repetition, keeping any configured delay.
+    pub fn alternate(&mut self) -> &mut Self {
+        self.on_finish = match self.on_finish {
+            OnFinish::Restart { delay } | OnFinish::Reverse { delay } => {
+                OnFinish::Reverse { delay }
+            }
+            OnFinish::Nothing => OnFinish::reverse(),
+        };
+        self
+    }
+
+    /// Called once the animation ends by itself, after all its repetitions.
+    pub fn on_end(&mut self, on_end: impl FnMut() + 'static) -> &mut Self {
+        self.on_end = Some(NoArgCallback::new(on_end));
+        self
+    }
+
     pub fn on_creation(&mut self, on_creation: OnCreation) -> &mut Self {
         self.on_creation = on_creation;
         self
@@ -100,6 +151,18 @@ impl OnFinish {
     }
 }
 
+/// How many times the animation runs when using [OnFinish::Restart] or [OnFinish::Reverse].
+///
+/// By default it is [Repeat::Infinite].
+#[derive(PartialEq, Clone, Copy, Default, Debug)]
+pub enum Repeat {
+    /// Runs forever.
+    #[default]
+    Infinite,
+    /// Runs the given amount of times in total.
+    Times(usize),
+}
+
 /// What to do once the animation gets created.
 ///
 /// By default it is [OnCreation::Nothing]
@@ -126,6 +189,8 @@ pub enum OnChange {
     Finish,
     /// Reruns the animation.
     Rerun,
+    /// Reruns the animation starting from the current values, so it does not snap back.
+    Retarget,
     /// Does nothing at all.
     Nothing,
 }
@@ -145,6 +210,9 @@ pub trait AnimatedValue: Clone + Default + 'static {
     fn finish(&mut self, direction: AnimDirection);
 
     fn into_reversed(self) -> Self;
+
+    /// Start from the current values of `from` instead of the origin, used by [OnChange::Retarget].
+    fn retarget(&mut self, _from: &Self, _direction: AnimDirection) {}
 }
 
 #[derive(Default, Clone, Copy, PartialEq, Eq)]
@@ -243,7 +311,10 @@ impl<Animated: AnimatedValue> UseAnimation<Animated> {
         let mut task = self.task;
         let mut last_direction = self.last_direction;
 
-        let on_finish = self.config.peek().on_finish;
+        let (on_finish, repeat, on_end) = {
+            let config = self.config.peek();
+            (config.on_finish, config.repeat, config.on_end.clone())
+        };
         let mut animated_value = self.animated_value;
 
         last_direction.set(direction);
@@ -266,7 +337,8 @@ impl<Animated: AnimatedValue> UseAnimation<Animated> {
             platform.send(UserEvent::RequestRedraw);
 
             let mut index = 0u128;
-            let mut prev_frame = Instant::now();
+            let mut iterations = 1;
+            let mut prev_frame = animation_clock.now();
 
             if !peek_has_run_yet {
                 *has_run_yet.write() = true;
@@ -281,7 +353,9 @@ impl<Animated: AnimatedValue> UseAnimation<Animated> {
                 // Request another redraw to move the animation forward
                 platform.send(UserEvent::RequestRedraw);
 
-                let elapsed = animation_clock.correct_elapsed_duration(prev_frame.elapsed());
+                let now = animation_clock.now();
+                let elapsed = animation_clock
+                    .correct_elapsed_duration(now.saturating_duration_since(prev_frame));
 
                 index += elapsed.as_millis();
 
@@ -295,19 +369,26 @@ impl<Animated: AnimatedValue> UseAnimation<Animated> {
                 };
 
                 if is_finished {
+                    let is_last_iteration = match repeat {
+                        Repeat::Infinite => false,
+                        Repeat::Times(times) => iterations >= times,
+                    };
+
                     let delay = match on_finish {
-                        OnFinish::Reverse { delay } => {
+                        OnFinish::Reverse { delay } if !is_last_iteration => {
                             // Toggle direction
                             direction.toggle();
                             delay
                         }
-                        OnFinish::Restart { delay } => delay,
-                        OnFinish::Nothing => {
+                        OnFinish::Restart { delay } if !is_last_iteration => delay,
+                        _ => {
                             // Stop if all the animations are finished
                             break;
                         }
                     };
 
+                    iterations += 1;
+
                     if !delay.is_zero() {
                         Timer::after(delay).await;
                     }
@@ -318,11 +399,15 @@ impl<Animated: AnimatedValue> UseAnimation<Animated> {
                     animated_value.write().prepare(direction);
                 }
 
-                prev_frame = Instant::now();
+                prev_frame = animation_clock.now();
             }
 
             is_running.set(false);
             task.write().take();
+
+            if let Some(on_end) = on_end {
+                on_end.call();
+            }
         });
 
         // Cancel previous animations
@@ -405,6 +490,24 @@ impl<Animated: AnimatedValue> UseAnimation<Animated> {
 /// }
 /// ```
 ///
+/// Use [`AnimConfiguration::repeat`], [`AnimConfiguration::infinite`] and [`AnimConfiguration::alternate`]
+/// to control how it loops, and [`AnimConfiguration::on_end`] to know when it ends.
+///
+/// ```rust, no_run
+/// # use freya::prelude::*;
+/// # use freya::animation::*;
+/// fn app() -> impl IntoElement {
+///     let animation = use_animation(|conf| {
+///         conf.repeat(3).alternate().on_end(|| println!("Done"));
+///         // ...
+///         # AnimNum::new(0., 1.)
+///     });
+///
+///     // ...
+///     # rect()
+/// }
+/// ```
+///
 /// You can subscribe your animation to reactive [state](freya_core::prelude::use_state) values, these are considered dependencies.
 ///
 /// ```rust, no_run
@@ -448,13 +551,17 @@ pub fn use_animation<Animated: AnimatedValue>(
 
         Effect::create_sync_with_gen(move |current_gen| {
             let mut anim_conf = AnimConfiguration::default();
-            animated_value.set(run(&mut anim_conf));
+            let mut value = run(&mut anim_conf);
+            if anim_conf.on_change == OnChange::Retarget && current_gen > 0 {
+                value.retarget(&animated_value.peek(), *animation.last_direction.peek());
+            }
+            animated_value.set(value);
             *config.write() = anim_conf;
             match config.peek().on_change {
                 OnChange::Finish if current_gen > 0 => {
                     animation.finish();
                 }
-                OnChange::Rerun if current_gen > 0 => {
+                OnChange::Rerun | OnChange::Retarget if current_gen > 0 => {
                     let last_direction = *animation.last_direction.peek();
                     animation.run(last_direction);
                 }
@@ -495,6 +602,24 @@ pub fn use_animation<Animated: AnimatedValue>(
 ///     # rect()
 /// }
 /// ```
+///
+/// Use [OnChange::Retarget] to animate from wherever the animation currently is towards the new values,
+/// even if it is still running.
+///
+/// ```rust, no_run
+/// # use freya::prelude::*;
+/// # use freya::animation::*;
+/// fn app() -> impl IntoElement {
+///     let target = use_state(|| 100.);
+///     let animation = use_animation_with_dependencies(&target(), |conf, target| {
+///         conf.on_change(OnChange::Retarget);
+///         AnimNum::new(0., *target).time(200)
+///     });
+///
+///     // ...
+///     # rect()
+/// }
+/// ```
 pub fn use_animation_with_dependencies<Animated: AnimatedValue, D: 'static + Clone + PartialEq>(
     dependencies: &D,
     mut run: impl 'static + FnMut(&mut AnimConfiguration, &D) -> Animated,
@@ -522,14 +647,18 @@ pub fn use_animation_with_dependencies<Animated: AnimatedValue, D: 'static + Clo
         Effect::create_sync_with_gen(move |current_gen| {
             let dependencies = dependencies.read();
             let mut anim_conf = AnimConfiguration::default();
-            animated_value.set(run(&mut anim_conf, &dependencies));
+            let mut value = run(&mut anim_conf, &dependencies);
+            if anim_conf.on_change == OnChange::Retarget && current_gen > 0 {
+                value.retarget(&animated_value.peek(), *animation.last_direction.peek());
+            }
+            animated_value.set(value);
             *config.write() = anim_conf;
 
             match config.peek().on_change {
                 OnChange::Finish if current_gen > 0 => {
                     animation.finish();
                 }
-                OnChange::Rerun if current_gen > 0 => {
+                OnChange::Rerun | OnChange::Retarget if current_gen > 0 => {
                     let last_direction = *animation.last_direction.peek();
                     animation.run(last_direction);
                 }
@@ -605,6 +734,19 @@ macro_rules! impl_tuple_call {
                         )*
                     )
                 }
+
+                fn retarget(&mut self, from: &Self, direction: AnimDirection) {
+                    #[allow(non_snake_case)]
+                    let ($($type,)*) = from;
+                    let mut from = [$($type as &dyn std::any::Any,)*].into_iter();
+                    #[allow(non_snake_case)]
+                    let ($($type,)*) = self;
+                    $(
+                        if let Some(from) = from.next().and_then(|from| from.downcast_ref::<$type>()) {
+                            $type.retarget(from, direction);
+                        }
+                    )*
+                }
             }
             impl<$($type,)*> ReadAnimatedValue for  ($($type,)*)
             where
@@ -305,3 +305,114 @@ pub fn animation_with_dependencies_on_change_rerun() {
     let rect = &test.find_many(|t, e| Rect::try_downcast(e).map(|_| t.layout()))[2];
     assert_eq!(rect.area.width(), 200.0);
 }
+
+#[test]
+pub fn repeat_count() {
+    fn use_animation_app() -> impl IntoElement {
+        let mut ends = use_state(|| 0);
+
+        let animation = use_animation(move |conf| {
+            conf.on_creation(OnCreation::Run);
+            conf.repeat(2);
+            conf.on_end(move || *ends.write() += 1);
+
+            AnimNum::new(0., 100.).time(50)
+        });
+
+        let progress = animation.get().value();
+
+        rect()
+            .width(Size::px(progress))
+            .height(Size::px(ends() as f32 * 10.))
+    }
+
+    let mut test = launch_test(use_animation_app);
+
+    test.poll(Duration::from_millis(1), Duration::from_millis(75));
+    let rect = &test.find_many(|t, e| Rect::try_downcast(e).map(|_| t.layout()))[2];
+    assert!(rect.area.width() < 100.0);
+    assert_eq!(rect.area.height(), 0.0);
+
+    test.poll(Duration::from_millis(1), Duration::from_millis(125));
+    let rect = &test.find_many(|t, e| Rect::try_downcast(e).map(|_| t.layout()))[2];
+    assert_eq!(rect.area.width(), 100.0);
+    assert_eq!(rect.area.height(), 10.0);
+}
+
+#[test]
+pub fn animation_with_dependencies_on_change_retarget() {
+    fn use_animation_app() -> impl IntoElement {
+        let mut target = use_state(|| 100.0);
+
+        let animation = use_animation_with_dependencies(&target(), |conf, target| {
+            conf.on_creation(OnCreation::Run);
+            conf.on_change(OnChange::Retarget);
+
+            AnimNum::new(0., *target).time(50)
+        });
+
+        let progress = animation.get().value();
+
+        rect()
+            .width(Size::px(progress))
+            .height(Size::fill())
+            .background(Color::WHITE)
+            .child(
+                Button::new()
+                    .on_press(move |_| target.set(200.0))
+                    .child("Change Target"),
+            )
+    }
+
+    let mut test = launch_test(use_animation_app);
+
+    test.poll(Duration::from_millis(1), Duration::from_millis(25));
+    let rect = &test.find_many(|t, e| Rect::try_downcast(e).map(|_| t.layout()))[2];
+    let width_mid = rect.area.width();
+    assert!(width_mid > 0.0 && width_mid < 100.0);
+
+    // Click button to change target to 200
+    // With OnChange::Retarget, should continue from the current value instead of snapping to 0
+    test.click_cursor((7.0, 7.0));
+    test.sync_and_update();
+    test.sync_and_update();
+    test.sync_and_update();
+
+    let rect = &test.find_many(|t, e| Rect::try_downcast(e).map(|_| t.layout()))[2];
+    assert!(rect.area.width() >= width_mid);
+
+    test.poll(Duration::from_millis(1), Duration::from_millis(100));
+    let rect = &test.find_many(|t, e| Rect::try_downcast(e).map(|_| t.layout()))[2];
+    assert_eq!(rect.area.width(), 200.0);
+}
+
+#[test]
+pub fn move_time() {
+    fn use_animation_app() -> impl IntoElement {
+        let mut animation = use_animation(|_| AnimNum::new(0., 100.).time(50));
+
+        let progress = animation.get().value();
+
+        rect()
+            .on_press(move |_| animation.start())
+            .width(Size::px(progress))
+            .height(Size::fill())
+            .background(Color::WHITE)
+    }
+
+    let mut test = launch_test(use_animation_app);
+    test.animation_clock().freeze();
+
+    test.click_cursor((5., 5.));
+    test.move_time(Duration::from_millis(25));
+    let rect = &test.find_many(|t, e| Rect::try_downcast(e).map(|_| t.layout()))[2];
+    assert_eq!(rect.area.width(), 50.0);
+
+    test.move_time(Duration::from_millis(10));
+    let rect = &test.find_many(|t, e| Rect::try_downcast(e).map(|_| t.layout()))[2];
+    assert_eq!(rect.area.width(), 70.0);
+
+    test.move_time(Duration::from_millis(100));
+    let rect = &test.find_many(|t, e| Rect::try_downcast(e).map(|_| t.layout()))[2];
+    assert_eq!(rect.area.width(), 100.0);
+}
@@ -1,2 +1,4 @@
 pub static BASE_FONT_SIZE: f32 = 5.0;
 pub static MAX_FONT_SIZE: f32 = 150.0;
+pub static GUTTER_PADDING: f32 = 20.0;
+pub static GUTTER_MIN_DIGITS: usize = 3;
@@ -256,6 +256,7 @@ impl TextEditor for CodeEditorData {
             idx,
             ch,
             len: inserted_text_len,
+            runs: TextRuns::default(),
             selection,
         });
 
@@ -299,6 +300,7 @@ impl TextEditor for CodeEditorData {
             idx,
             text: text.to_owned(),
             len: inserted_text_len,
+            runs: TextRuns::default(),
             selection,
         });
 
@@ -341,6 +343,7 @@ impl TextEditor for CodeEditorData {
             idx: range_utf16.end - removed_text_len,
             text,
             len: removed_text_len,
+            runs: TextRuns::default(),
             selection,
         });
 
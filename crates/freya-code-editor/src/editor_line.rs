@@ -13,6 +13,7 @@ use torin::{
 };
 
 use crate::{
+    constants::GUTTER_PADDING,
     editor_data::CodeEditorData,
     editor_theme::EditorTheme,
     syntax::TextNode,
@@ -54,9 +55,15 @@ impl Component for EditorLineUI {
         let theme = theme.read();
 
         let longest_width = editor_data.metrics.longest_width;
-        let line = editor_data.metrics.syntax_blocks.get_line(line_index);
+        let line =
+            editor_data
+                .metrics
+                .syntax_blocks
+                .get_line(line_index, &editor_data.rope, theme.text);
         let highlights = editor_data.get_visible_selection(EditorLine::Paragraph(line_index));
-        let gutter_width = font_size * 5.0;
+        let gutter_width = editor_data
+            .metrics
+            .gutter_width(editor_data.rope.len_lines());
         let is_line_selected = editor_data.cursor_row() == line_index;
 
         let on_pointer_down = {
@@ -125,7 +132,7 @@ impl Component for EditorLineUI {
                     rect()
                         .width(Size::px(gutter_width))
                         .height(Size::fill())
-                        .padding(Gaps::new(0., 0., 0., 20.))
+                        .padding(Gaps::new(0., 0., 0., GUTTER_PADDING))
                         .main_align(Alignment::Center)
                         .child(
                             label()
@@ -4,6 +4,10 @@ use ropey::Rope;
 use tree_sitter::InputEdit;
 
 use crate::{
+    constants::{
+        GUTTER_MIN_DIGITS,
+        GUTTER_PADDING,
+    },
     editor_theme::SyntaxTheme,
     languages::LanguageId,
     syntax::*,
@@ -12,6 +16,7 @@ use crate::{
 pub struct EditorMetrics {
     pub(crate) syntax_blocks: SyntaxBlocks,
     pub(crate) longest_width: f32,
+    pub(crate) char_width: f32,
     pub(crate) highlighter: SyntaxHighlighter,
 }
 
@@ -26,6 +31,7 @@ impl EditorMetrics {
         Self {
             syntax_blocks: SyntaxBlocks::default(),
             longest_width: 0.0,
+            char_width: 0.0,
             highlighter: SyntaxHighlighter::new(),
         }
     }
@@ -51,9 +57,16 @@ impl EditorMetrics {
         // Find the line with the maximum character count
         let max_chars = rope.lines().map(|line| line.len_chars()).max().unwrap_or(0);
 
+        self.char_width = char_width;
         self.longest_width = max_chars as f32 * char_width;
     }
 
+    /// Width of the line numbers gutter, wide enough for the number of the last line.
+    pub fn gutter_width(&self, lines_len: usize) -> f32 {
+        let digits = lines_len.max(1).ilog10() as usize + 1;
+        (digits.max(GUTTER_MIN_DIGITS) + 1) as f32 * self.char_width + GUTTER_PADDING
+    }
+
     pub fn run_parser(
         &mut self,
         rope: &Rope,
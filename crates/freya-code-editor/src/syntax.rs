@@ -1,4 +1,7 @@
-use std::ops::Range;
+use std::{
+    borrow::Cow,
+    ops::Range,
+};
 
 use freya_core::prelude::Color;
 use ropey::Rope;
@@ -77,6 +80,7 @@ fn resolve_capture_color(name: &str, theme: &SyntaxTheme) -> Color {
     theme.text
 }
 
+#[derive(Clone, Debug, PartialEq)]
 pub enum TextNode {
     Range(Range<usize>),
     LineOfChars { len: usize, char: char },
@@ -94,8 +98,26 @@ impl SyntaxBlocks {
         self.blocks.insert(self.len(), line);
     }
 
-    pub fn get_line(&self, line: usize) -> &[(Color, TextNode)] {
-        self.blocks.get(&line).unwrap()
+    /// Highlighted spans of the given line,
+    /// or its plain text with the given color if it has not been parsed yet.
+    pub fn get_line(&self, line: usize, rope: &Rope, color: Color) -> Cow<'_, [(Color, TextNode)]> {
+        if let Some(line) = self.blocks.get(&line) {
+            return Cow::Borrowed(line.as_slice());
+        }
+        let Some(line_slice) = rope.get_line(line) else {
+            return Cow::Borrowed(&[]);
+        };
+        let start = rope.line_to_char(line);
+        let len = line_slice.len_chars()
+            - line_slice
+                .chars()
+                .rev()
+                .take_while(|ch| matches!(ch, '\n' | '\r'))
+                .count();
+        if len == 0 {
+            return Cow::Borrowed(&[]);
+        }
+        Cow::Owned(vec![(color, TextNode::Range(start..start + len))])
     }
 
     pub fn len(&self) -> usize {
@@ -474,3 +496,93 @@ impl LanguageId {
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use freya_core::prelude::Color;
+    use ropey::Rope;
+
+    use super::{
+        SyntaxBlocks,
+        TextNode,
+    };
+
+    #[test]
+    fn unparsed_lines_fall_back_to_plain_text() {
+        let rope = Rope::from_str("let a = 1;\r\nlet b = 2;\n");
+        let blocks = SyntaxBlocks::default();
+        let color = Color::from_rgb(255, 0, 0);
+
+        assert_eq!(
+            &*blocks.get_line(0, &rope, color),
+            &[(color, TextNode::Range(0..10))]
+        );
+        assert_eq!(
+            &*blocks.get_line(1, &rope, color),
+            &[(color, TextNode::Range(12..22))]
+        );
+        assert!(blocks.get_line(2, &rope, color).is_empty());
+        assert!(blocks.get_line(5, &rope, color).is_empty());
+    }
+
+    #[cfg(feature = "rust")]
+    #[test]
+    fn incremental_highlighting_matches_full_parse() {
+        use tree_sitter::InputEdit;
+
+        use super::{
+            InputEditExt,
+            SyntaxHighlighter,
+        };
+        use crate::{
+            editor_theme::SyntaxTheme,
+            languages::LanguageId,
+        };
+
+        let theme = SyntaxTheme::default();
+        let highlight = |rope: &Rope, highlighter: &mut SyntaxHighlighter, edit| {
+            let mut blocks = SyntaxBlocks::default();
+            highlighter.parse(rope, &mut blocks, edit, &theme);
+            (0..rope.len_lines())
+                .map(|line| {
+                    blocks
+                        .get_line(line, rope, theme.text)
+                        .iter()
+                        .map(|(color, node)| {
+                            let text = match node {
+                                TextNode::Range(range) => rope.slice(range.clone()).to_string(),
+                                TextNode::LineOfChars { len, .. } => " ".repeat(*len),
+                            };
+                            (*color, text)
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let mut rope = Rope::from_str("fn main() {\n    let a = 1;\n}\n");
+        let mut highlighter = SyntaxHighlighter::new();
+        highlighter.set_language(LanguageId::Rust, &theme);
+        highlight(&rope, &mut highlighter, None);
+
+        // Replace the number with a string, reusing the previous parse tree
+        let idx = rope.to_string().find('1').unwrap();
+        let column = idx - rope.line_to_char(1);
+        rope.remove(idx..idx + 1);
+        rope.insert(idx, "\"one\"");
+        let edit = InputEdit::new_edit(
+            idx,
+            idx + 1,
+            idx + 5,
+            (1, column),
+            (1, column + 1),
+            (1, column + 5),
+        );
+        let incremental = highlight(&rope, &mut highlighter, Some(edit));
+
+        let mut full_highlighter = SyntaxHighlighter::new();
+        full_highlighter.set_language(LanguageId::Rust, &theme);
+        assert_eq!(incremental, highlight(&rope, &mut full_highlighter, None));
+        assert!(incremental[1].contains(&(theme.string, "\"one\"".to_string())));
+    }
+}
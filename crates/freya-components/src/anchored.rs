@@ -0,0 +1,293 @@
+use freya_core::prelude::*;
+use torin::prelude::{
+    Area,
+    Point2D,
+    Position,
+    Size2D,
+};
+
+use crate::attached::AttachedPosition;
+
+/// Alignment of an anchored element along the side of its anchor.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum AnchoredAlignment {
+    Start,
+    #[default]
+    Center,
+    End,
+}
+
+/// How an anchored element is placed relative to its anchor, see [use_anchored_position].
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct AnchoredOptions {
+    placement: AttachedPosition,
+    alignment: AnchoredAlignment,
+    offset: f32,
+    padding: f32,
+    flip: bool,
+    shift: bool,
+}
+
+impl Default for AnchoredOptions {
+    fn default() -> Self {
+        Self {
+            placement: AttachedPosition::Bottom,
+            alignment: AnchoredAlignment::Center,
+            offset: 0.,
+            padding: 0.,
+            flip: true,
+            shift: true,
+        }
+    }
+}
+
+impl AnchoredOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Preferred side of the anchor. Defaults to [AttachedPosition::Bottom].
+    pub fn placement(mut self, placement: AttachedPosition) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    pub fn alignment(mut self, alignment: AnchoredAlignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Distance between the anchor and the anchored element.
+    pub fn offset(mut self, offset: f32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Minimum distance to keep from the window edges.
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Move to the opposite side when the preferred one has no room. Defaults to `true`.
+    pub fn flip(mut self, flip: bool) -> Self {
+        self.flip = flip;
+        self
+    }
+
+    /// Slide along the anchor to stay inside the window. Defaults to `true`.
+    pub fn shift(mut self, shift: bool) -> Self {
+        self.shift = shift;
+        self
+    }
+}
+
+/// Resolved position of an anchored element.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct AnchoredPosition {
+    /// Global area of the anchored element.
+    pub area: Area,
+    /// Side it ended up on, which differs from the preferred one when flipped.
+    pub placement: AttachedPosition,
+}
+
+impl AnchoredPosition {
+    /// Position the `floating` size next to the `anchor` area without leaving `bounds`.
+    pub fn compute(
+        anchor: Area,
+        floating: Size2D,
+        bounds: Size2D,
+        options: &AnchoredOptions,
+    ) -> Self {
+        let mut placement = options.placement;
+        let mut origin = place(anchor, floating, placement, options);
+
+        if options.flip && overflows(origin, floating, bounds, placement, options) {
+            let opposite = opposite(placement);
+            let flipped = place(anchor, floating, opposite, options);
+            if !overflows(flipped, floating, bounds, opposite, options) {
+                placement = opposite;
+                origin = flipped;
+            }
+        }
+
+        if options.shift {
+            let clamp = |value: f32, size: f32, bound: f32| {
+                value
+                    .min(bound - size - options.padding)
+                    .max(options.padding)
+            };
+            match placement {
+                AttachedPosition::Top | AttachedPosition::Bottom => {
+                    origin.x = clamp(origin.x, floating.width, bounds.width);
+                }
+                AttachedPosition::Left | AttachedPosition::Right => {
+                    origin.y = clamp(origin.y, floating.height, bounds.height);
+                }
+            }
+        }
+
+        Self {
+            area: Area::new(origin, floating),
+            placement,
+        }
+    }
+}
+
+fn opposite(placement: AttachedPosition) -> AttachedPosition {
+    match placement {
+        AttachedPosition::Top => AttachedPosition::Bottom,
+        AttachedPosition::Bottom => AttachedPosition::Top,
+        AttachedPosition::Left => AttachedPosition::Right,
+        AttachedPosition::Right => AttachedPosition::Left,
+    }
+}
+
+fn place(
+    anchor: Area,
+    floating: Size2D,
+    placement: AttachedPosition,
+    options: &AnchoredOptions,
+) -> Point2D {
+    let align = |start: f32, length: f32, size: f32| match options.alignment {
+        AnchoredAlignment::Start => start,
+        AnchoredAlignment::Center => start + (length - size) / 2.,
+        AnchoredAlignment::End => start + length - size,
+    };
+    let x = align(anchor.min_x(), anchor.width(), floating.width);
+    let y = align(anchor.min_y(), anchor.height(), floating.height);
+
+    match placement {
+        AttachedPosition::Top => Point2D::new(x, anchor.min_y() - options.offset - floating.height),
+        AttachedPosition::Bottom => Point2D::new(x, anchor.max_y() + options.offset),
+        AttachedPosition::Left => Point2D::new(anchor.min_x() - options.offset - floating.width, y),
+        AttachedPosition::Right => Point2D::new(anchor.max_x() + options.offset, y),
+    }
+}
+
+fn overflows(
+    origin: Point2D,
+    floating: Size2D,
+    bounds: Size2D,
+    placement: AttachedPosition,
+    options: &AnchoredOptions,
+) -> bool {
+    match placement {
+        AttachedPosition::Top => origin.y < options.padding,
+        AttachedPosition::Bottom => origin.y + floating.height > bounds.height - options.padding,
+        AttachedPosition::Left => origin.x < options.padding,
+        AttachedPosition::Right => origin.x + floating.width > bounds.width - options.padding,
+    }
+}
+
+/// Position an element of size `floating` next to the `anchor` area, flipping and shifting it
+/// to stay inside the window. Returns `None` until both have been measured.
+pub fn use_anchored_position(
+    anchor: Option<Area>,
+    floating: Option<Size2D>,
+    options: AnchoredOptions,
+) -> Option<AnchoredPosition> {
+    let platform = use_hook(Platform::get);
+    let bounds = *platform.root_size.read();
+
+    let (anchor, floating) = (anchor?, floating?);
+    Some(AnchoredPosition::compute(
+        anchor, floating, bounds, &options,
+    ))
+}
+
+/// Render elements in the overlay layer of the window, anchored to an inner element.
+///
+/// Unlike [Attached](crate::attached::Attached), the anchored elements escape the layout of
+/// their ancestors and are kept inside the window by flipping and shifting them.
+///
+/// # Example
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> impl IntoElement {
+///     let mut open = use_state(|| false);
+///
+///     AnchoredPortal::new(
+///         Button::new()
+///             .on_press(move |_| open.toggle())
+///             .child("Toggle"),
+///     )
+///     .options(
+///         AnchoredOptions::new()
+///             .placement(AttachedPosition::Top)
+///             .offset(4.),
+///     )
+///     .maybe_child(open().then(|| label().text("Anchored above!")))
+/// }
+/// ```
+#[derive(PartialEq)]
+pub struct AnchoredPortal {
+    inner: Element,
+    children: Vec<Element>,
+    options: AnchoredOptions,
+    key: DiffKey,
+}
+
+impl KeyExt for AnchoredPortal {
+    fn write_key(&mut self) -> &mut DiffKey {
+        &mut self.key
+    }
+}
+
+impl ChildrenExt for AnchoredPortal {
+    fn get_children(&mut self) -> &mut Vec<Element> {
+        &mut self.children
+    }
+}
+
+impl AnchoredPortal {
+    pub fn new(inner: impl IntoElement) -> Self {
+        Self {
+            inner: inner.into_element(),
+            children: vec![],
+            options: AnchoredOptions::default(),
+            key: DiffKey::None,
+        }
+    }
+
+    pub fn options(mut self, options: AnchoredOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn placement(mut self, placement: AttachedPosition) -> Self {
+        self.options = self.options.placement(placement);
+        self
+    }
+}
+
+impl Component for AnchoredPortal {
+    fn render(&self) -> impl IntoElement {
+        let mut anchor_area = use_state(|| None::<Area>);
+        let mut floating_size = use_state(|| None::<Size2D>);
+
+        let position = use_anchored_position(anchor_area(), floating_size(), self.options);
+        let (left, top) = position
+            .map(|position| (position.area.min_x(), position.area.min_y()))
+            .unwrap_or_default();
+
+        rect()
+            .on_sized(move |e: Event<SizedEventData>| anchor_area.set_if_modified(Some(e.area)))
+            .child(self.inner.clone())
+            .maybe_child((!self.children.is_empty()).then(|| {
+                rect()
+                    .on_sized(move |e: Event<SizedEventData>| {
+                        floating_size.set_if_modified(Some(e.area.size))
+                    })
+                    .layer(Layer::Overlay)
+                    .position(Position::new_global().left(left).top(top))
+                    .opacity(if position.is_some() { 1. } else { 0. })
+                    .children(self.children.clone())
+            }))
+    }
+
+    fn render_key(&self) -> DiffKey {
+        self.key.clone().or(self.default_key())
+    }
+}
@@ -55,6 +55,7 @@ pub struct CanvasElement {
     pub event_handlers: FxHashMap<EventName, EventHandlerType>,
     pub effect: Option<EffectData>,
     pub on_render: RenderCallback,
+    pub continuous: bool,
 }
 
 impl CanvasElement {}
@@ -93,7 +94,7 @@ impl ElementExt for CanvasElement {
             diff.insert(DiffModifies::EVENT_HANDLERS);
         }
 
-        if self.on_render != rect.on_render {
+        if self.on_render != rect.on_render || self.continuous != rect.continuous {
             diff.insert(DiffModifies::STYLE);
         }
 
@@ -155,6 +156,10 @@ impl ElementExt for CanvasElement {
         self.on_render.call(&mut context);
         context.canvas.restore();
     }
+
+    fn needs_next_frame(&self) -> bool {
+        self.continuous
+    }
 }
 
 pub struct Canvas {
@@ -208,12 +213,19 @@ impl Canvas {
                 layout: LayoutData::default(),
                 event_handlers: HashMap::default(),
                 effect: None,
+                continuous: false,
             },
             elements: Vec::default(),
             key: DiffKey::None,
         }
     }
 
+    /// Render the canvas on every frame while it's visible, for content that changes over time.
+    pub fn continuous(mut self, continuous: bool) -> Self {
+        self.element.continuous = continuous;
+        self
+    }
+
     pub fn try_downcast(element: &dyn ElementExt) -> Option<CanvasElement> {
         (element as &dyn Any)
             .downcast_ref::<CanvasElement>()
@@ -20,7 +20,7 @@ pub(crate) enum ContextMenuCloseRequest {
 /// # use freya::prelude::*;
 /// fn app() -> impl IntoElement {
 ///     rect()
-///         .on_secondary_down(move |e: Event<PressEventData>| {
+///         .on_context_menu(move |e: Event<PressEventData>| {
 ///             ContextMenu::open_from_event(
 ///                 &e,
 ///                 Menu::new().child(MenuButton::new().child("Option 1")),
@@ -73,9 +73,21 @@ impl ContextMenu {
     /// the first close request is consumed to prevent the menu from closing immediately.
     /// When opened from a secondary button (right click) down event, the menu can be
     /// closed with a single click.
+    /// When opened from the keyboard, the menu is placed below the focused element instead of the cursor.
     pub fn open_from_event(event: &Event<PressEventData>, menu: Menu) {
         let mut this = Self::get();
-        this.menu.set(Some(((this.location)(), menu)));
+        let location = match event.data() {
+            PressEventData::Keyboard(_) => {
+                let bounds = Platform::get()
+                    .focused_accessibility_node
+                    .read()
+                    .bounds()
+                    .unwrap_or_default();
+                CursorPoint::new(bounds.x0, bounds.y1)
+            }
+            _ => (this.location)(),
+        };
+        this.menu.set(Some((location, menu)));
 
         let close_request = match event.data() {
             PressEventData::Mouse(mouse) if mouse.button == Some(MouseButton::Left) => {
@@ -90,3 +102,66 @@ impl ContextMenu {
         Self::get().menu.set(None);
     }
 }
+
+/// Opens the given [Menu] as the [ContextMenu] when its children request one,
+/// either with a right click or with the `ContextMenu` key while focused.
+///
+/// # Example
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> impl IntoElement {
+///     ContextMenuArea::new(
+///         Menu::new().child(MenuButton::new().child("Copy")).child(
+///             SubMenu::new()
+///                 .label("Share")
+///                 .child(MenuButton::new().child("Email")),
+///         ),
+///     )
+///     .child("Right click me")
+/// }
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct ContextMenuArea {
+    menu: Menu,
+    children: Vec<Element>,
+    key: DiffKey,
+}
+
+impl ContextMenuArea {
+    pub fn new(menu: Menu) -> Self {
+        Self {
+            menu,
+            children: Vec::new(),
+            key: DiffKey::None,
+        }
+    }
+}
+
+impl ChildrenExt for ContextMenuArea {
+    fn get_children(&mut self) -> &mut Vec<Element> {
+        &mut self.children
+    }
+}
+
+impl KeyExt for ContextMenuArea {
+    fn write_key(&mut self) -> &mut DiffKey {
+        &mut self.key
+    }
+}
+
+impl Component for ContextMenuArea {
+    fn render(&self) -> impl IntoElement {
+        let menu = self.menu.clone();
+
+        rect()
+            .on_context_menu(move |e: Event<PressEventData>| {
+                ContextMenu::open_from_event(&e, menu.clone());
+            })
+            .children(self.children.clone())
+    }
+
+    fn render_key(&self) -> DiffKey {
+        self.key.clone().or(self.default_key())
+    }
+}
@@ -0,0 +1,519 @@
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    rc::Rc,
+};
+
+use freya_core::prelude::*;
+use torin::size::Size;
+
+use crate::{
+    get_theme,
+    scrollviews::VirtualScrollView,
+    table::{
+        OrderDirection,
+        TableCell,
+        TableTheme,
+        TableThemePartial,
+        TableThemePreference,
+    },
+};
+
+/// Column of a [DataTable].
+#[derive(Clone, PartialEq)]
+pub struct DataColumn {
+    pub title: Cow<'static, str>,
+    /// Initial width in pixels, it can be changed by dragging the edge of the header.
+    pub width: f32,
+    pub min_width: f32,
+    pub sortable: bool,
+}
+
+impl DataColumn {
+    pub fn new(title: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            title: title.into(),
+            width: 150.,
+            min_width: 40.,
+            sortable: true,
+        }
+    }
+
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn min_width(mut self, min_width: f32) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+}
+
+/// How the rows of a [DataTable] can be selected.
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub enum RowSelectionMode {
+    None,
+    #[default]
+    Single,
+    /// Select ranges with `Shift` and toggle rows with `Ctrl` (`Cmd` on macOS).
+    Multiple,
+}
+
+/// Selected rows of a [DataTable].
+#[derive(Clone, PartialEq, Default, Debug)]
+pub struct RowSelection {
+    rows: Vec<usize>,
+    anchor: Option<usize>,
+}
+
+impl RowSelection {
+    /// Selected rows in the order they were selected.
+    pub fn rows(&self) -> &[usize] {
+        &self.rows
+    }
+
+    pub fn contains(&self, row: usize) -> bool {
+        self.rows.contains(&row)
+    }
+
+    /// Select the given row, extending the selection with `range` or toggling it with `toggle`.
+    pub fn select(&mut self, row: usize, mode: RowSelectionMode, range: bool, toggle: bool) {
+        match mode {
+            RowSelectionMode::None => {}
+            RowSelectionMode::Multiple if range && self.anchor.is_some() => {
+                let anchor = self.anchor.unwrap_or(row);
+                self.rows = if anchor <= row {
+                    (anchor..=row).collect()
+                } else {
+                    (row..=anchor).rev().collect()
+                };
+            }
+            RowSelectionMode::Multiple if toggle => {
+                if let Some(index) = self.rows.iter().position(|selected| *selected == row) {
+                    self.rows.remove(index);
+                } else {
+                    self.rows.push(row);
+                }
+                self.anchor = Some(row);
+            }
+            _ => {
+                self.rows = vec![row];
+                self.anchor = Some(row);
+            }
+        }
+    }
+}
+
+/// Table of columns and virtualized rows with sorting, row selection and resizable columns.
+///
+/// The data is sorted by the app through [DataTable::on_sort], the table only shows the
+/// current [DataTable::sort].
+///
+/// # Example
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> impl IntoElement {
+///     let rows = vec![("Zeus", "Sky"), ("Poseidon", "Sea"), ("Ares", "War")];
+///     let length = rows.len();
+///
+///     DataTable::new(rows, |row, column, rows| {
+///         let (name, domain) = rows[row];
+///         label().text(if column == 0 { name } else { domain }).into()
+///     })
+///     .columns(vec![DataColumn::new("Name"), DataColumn::new("Domain")])
+///     .length(length)
+/// }
+/// ```
+pub struct DataTable<D> {
+    data: D,
+    builder: Rc<dyn Fn(usize, usize, &D) -> Element>,
+    columns: Vec<DataColumn>,
+    length: usize,
+    row_height: f32,
+    height: Size,
+    sort: Option<(usize, OrderDirection)>,
+    on_sort: Option<EventHandler<(usize, OrderDirection)>>,
+    selection_mode: RowSelectionMode,
+    on_selection_change: Option<EventHandler<RowSelection>>,
+    theme: Option<TableThemePartial>,
+    key: DiffKey,
+}
+
+impl<D: PartialEq> PartialEq for DataTable<D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+            && self.columns == other.columns
+            && self.length == other.length
+            && self.row_height == other.row_height
+            && self.height == other.height
+            && self.sort == other.sort
+            && self.on_sort == other.on_sort
+            && self.selection_mode == other.selection_mode
+            && self.on_selection_change == other.on_selection_change
+            && self.theme == other.theme
+            && self.key == other.key
+    }
+}
+
+impl<D> DataTable<D> {
+    /// Create a table whose cells are built with the given `(row, column, data)` builder.
+    pub fn new(data: D, builder: impl Fn(usize, usize, &D) -> Element + 'static) -> Self {
+        Self {
+            data,
+            builder: Rc::new(builder),
+            columns: Vec::new(),
+            length: 0,
+            row_height: 36.,
+            height: Size::fill(),
+            sort: None,
+            on_sort: None,
+            selection_mode: RowSelectionMode::default(),
+            on_selection_change: None,
+            theme: None,
+            key: DiffKey::None,
+        }
+    }
+
+    pub fn columns(mut self, columns: impl Into<Vec<DataColumn>>) -> Self {
+        self.columns = columns.into();
+        self
+    }
+
+    /// Number of rows.
+    pub fn length(mut self, length: impl Into<usize>) -> Self {
+        self.length = length.into();
+        self
+    }
+
+    pub fn row_height(mut self, row_height: impl Into<f32>) -> Self {
+        self.row_height = row_height.into();
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<Size>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Column the data is currently sorted by and its direction.
+    pub fn sort(mut self, sort: impl Into<Option<(usize, OrderDirection)>>) -> Self {
+        self.sort = sort.into();
+        self
+    }
+
+    /// Called with the new column and direction when the header of a sortable column is pressed.
+    pub fn on_sort(mut self, on_sort: impl Into<EventHandler<(usize, OrderDirection)>>) -> Self {
+        self.on_sort = Some(on_sort.into());
+        self
+    }
+
+    pub fn selection_mode(mut self, selection_mode: RowSelectionMode) -> Self {
+        self.selection_mode = selection_mode;
+        self
+    }
+
+    pub fn on_selection_change(
+        mut self,
+        on_selection_change: impl Into<EventHandler<RowSelection>>,
+    ) -> Self {
+        self.on_selection_change = Some(on_selection_change.into());
+        self
+    }
+
+    pub fn theme(mut self, theme: TableThemePartial) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+}
+
+impl<D> KeyExt for DataTable<D> {
+    fn write_key(&mut self) -> &mut DiffKey {
+        &mut self.key
+    }
+}
+
+/// Column being resized, with the pointer position and column width when it started.
+#[derive(Clone, Copy, PartialEq)]
+struct ColumnResize {
+    column: usize,
+    start_x: f64,
+    start_width: f32,
+}
+
+impl<D: PartialEq + Clone + 'static> Component for DataTable<D> {
+    fn render(&self) -> impl IntoElement {
+        let TableTheme {
+            background,
+            arrow_fill: _,
+            hover_row_background,
+            row_background,
+            selected_row_background,
+            divider_fill,
+            corner_radius,
+            color,
+        } = get_theme!(&self.theme, TableThemePreference, "table");
+        let mut selection = use_state(RowSelection::default);
+        let mut resized_widths = use_state(HashMap::<usize, f32>::new);
+        let mut resizing = use_state::<Option<ColumnResize>>(|| None);
+        let mut pressing_shift = use_state(|| false);
+        let mut pressing_toggle = use_state(|| false);
+
+        let widths = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(i, column)| {
+                resized_widths
+                    .read()
+                    .get(&i)
+                    .copied()
+                    .unwrap_or(column.width)
+            })
+            .collect::<Vec<f32>>();
+
+        let on_global_key_down = move |e: Event<KeyboardEventData>| {
+            if e.key == Key::Named(NamedKey::Shift) {
+                pressing_shift.set(true);
+            } else if is_toggle_key(&e.key) {
+                pressing_toggle.set(true);
+            }
+        };
+
+        let on_global_key_up = move |e: Event<KeyboardEventData>| {
+            if e.key == Key::Named(NamedKey::Shift) {
+                pressing_shift.set(false);
+            } else if is_toggle_key(&e.key) {
+                pressing_toggle.set(false);
+            }
+        };
+
+        let min_widths = self
+            .columns
+            .iter()
+            .map(|column| column.min_width)
+            .collect::<Vec<f32>>();
+        let on_capture_global_pointer_move = move |e: Event<PointerEventData>| {
+            let Some(resize) = *resizing.read() else {
+                return;
+            };
+            e.prevent_default();
+            let delta = (e.global_location().x - resize.start_x) as f32;
+            let min_width = min_widths.get(resize.column).copied().unwrap_or_default();
+            let width = (resize.start_width + delta).max(min_width);
+            resized_widths.write().insert(resize.column, width);
+        };
+
+        let on_global_pointer_press = move |_: Event<PointerEventData>| {
+            if resizing.read().is_some() {
+                resizing.set(None);
+                Cursor::set(CursorIcon::default());
+            }
+        };
+
+        let head = rect().horizontal().children(
+            self.columns
+                .iter()
+                .zip(widths.iter().copied())
+                .enumerate()
+                .map(|(i, (column, width))| {
+                    let order_direction = self
+                        .sort
+                        .filter(|(sort_column, _)| *sort_column == i)
+                        .map(|(_, direction)| direction);
+                    let mut cell = TableCell::new()
+                        .height(Size::px(self.row_height))
+                        .order_direction(order_direction)
+                        .child(label().text(column.title.clone()).max_lines(1));
+                    if column.sortable
+                        && let Some(on_sort) = self.on_sort.clone()
+                    {
+                        cell = cell.on_press(move |_| {
+                            let direction = match order_direction {
+                                Some(OrderDirection::Down) => OrderDirection::Up,
+                                Some(OrderDirection::Up) | None => OrderDirection::Down,
+                            };
+                            on_sort.call((i, direction));
+                        });
+                    }
+
+                    let on_pointer_down = move |e: Event<PointerEventData>| {
+                        e.stop_propagation();
+                        e.prevent_default();
+                        resizing.set(Some(ColumnResize {
+                            column: i,
+                            start_x: e.global_location().x,
+                            start_width: width,
+                        }));
+                    };
+                    let separator = rect()
+                        .width(Size::px(4.))
+                        .height(Size::fill())
+                        .background(divider_fill)
+                        .on_pointer_enter(|_| Cursor::set(CursorIcon::ColResize))
+                        .on_pointer_leave(move |_| {
+                            if resizing.read().is_none() {
+                                Cursor::set(CursorIcon::default());
+                            }
+                        })
+                        .on_pointer_down(on_pointer_down);
+
+                    rect()
+                        .key(i)
+                        .horizontal()
+                        .width(Size::px(width))
+                        .child(rect().width(Size::px(width - 4.)).child(cell))
+                        .child(separator)
+                        .into()
+                }),
+        );
+
+        let selection_mode = self.selection_mode;
+        let on_selection_change = self.on_selection_change.clone();
+        let builder = self.builder.clone();
+        let row_height = self.row_height;
+        let columns_len = self.columns.len();
+        let body = VirtualScrollView::new_with_data(
+            (self.data.clone(), widths, selection.read().clone()),
+            move |row, (data, widths, current_selection)| {
+                let on_press = {
+                    let on_selection_change = on_selection_change.clone();
+                    move |_: Event<PressEventData>| {
+                        if selection_mode == RowSelectionMode::None {
+                            return;
+                        }
+                        let mut new_selection = selection.read().clone();
+                        new_selection.select(
+                            row,
+                            selection_mode,
+                            pressing_shift(),
+                            pressing_toggle(),
+                        );
+                        if let Some(on_selection_change) = &on_selection_change {
+                            on_selection_change.call(new_selection.clone());
+                        }
+                        selection.set(new_selection);
+                    }
+                };
+                let cells = (0..columns_len).map(|column| {
+                    let width = widths.get(column).copied().unwrap_or_default();
+                    rect()
+                        .key(column)
+                        .width(Size::px(width))
+                        .child(
+                            TableCell::new()
+                                .height(Size::px(row_height))
+                                .child(builder(row, column, data)),
+                        )
+                        .into()
+                });
+                DataTableRow {
+                    selected: current_selection.contains(row),
+                    background: row_background,
+                    hover_background: hover_row_background,
+                    selected_background: selected_row_background,
+                    divider_fill,
+                    children: cells.collect(),
+                    on_press: on_press.into(),
+                    key: DiffKey::from(&row),
+                }
+                .into()
+            },
+        )
+        .length(self.length)
+        .item_size(row_height + 1.);
+
+        rect()
+            .overflow(Overflow::Clip)
+            .color(color)
+            // Tabular numbers keep the digits of numeric columns aligned
+            .font_features("tnum")
+            .background(background)
+            .corner_radius(corner_radius)
+            .height(self.height.clone())
+            .border(
+                Border::new()
+                    .alignment(BorderAlignment::Outer)
+                    .fill(divider_fill)
+                    .width(1.0),
+            )
+            .on_global_key_down(on_global_key_down)
+            .on_global_key_up(on_global_key_up)
+            .on_capture_global_pointer_move(on_capture_global_pointer_move)
+            .on_global_pointer_press(on_global_pointer_press)
+            .child(head)
+            .child(
+                rect()
+                    .height(Size::px(1.))
+                    .width(Size::fill())
+                    .background(divider_fill),
+            )
+            .child(body)
+    }
+
+    fn render_key(&self) -> DiffKey {
+        self.key.clone().or(self.default_key())
+    }
+}
+
+#[derive(PartialEq)]
+struct DataTableRow {
+    selected: bool,
+    background: Color,
+    hover_background: Color,
+    selected_background: Color,
+    divider_fill: Color,
+    children: Vec<Element>,
+    on_press: EventHandler<Event<PressEventData>>,
+    key: DiffKey,
+}
+
+impl Component for DataTableRow {
+    fn render(&self) -> impl IntoElement {
+        let mut hovering = use_state(|| false);
+
+        let background = if self.selected {
+            self.selected_background
+        } else if hovering() {
+            self.hover_background
+        } else {
+            self.background
+        };
+        let on_press = self.on_press.clone();
+
+        rect()
+            .a11y_role(AccessibilityRole::Row)
+            .a11y_focusable(true)
+            .on_pointer_enter(move |_| hovering.set(true))
+            .on_pointer_leave(move |_| hovering.set(false))
+            .on_press(move |e| on_press.call(e))
+            .background(background)
+            .child(rect().horizontal().children(self.children.clone()))
+            .child(
+                rect()
+                    .height(Size::px(1.))
+                    .width(Size::fill())
+                    .background(self.divider_fill),
+            )
+    }
+
+    fn render_key(&self) -> DiffKey {
+        self.key.clone().or(self.default_key())
+    }
+}
+
+/// Whether the key toggles rows in and out of the selection, `Cmd` on macOS and `Ctrl` elsewhere.
+fn is_toggle_key(key: &Key) -> bool {
+    let toggle_key = if cfg!(target_os = "macos") {
+        NamedKey::Meta
+    } else {
+        NamedKey::Control
+    };
+    *key == Key::Named(toggle_key)
+}
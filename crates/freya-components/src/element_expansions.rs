@@ -21,6 +21,7 @@ impl RectThemeExt for Rect {
 
 pub trait LabelThemeExt {
     fn theme_color(self) -> Self;
+    fn theme_highlight_color(self) -> Self;
 }
 
 impl LabelThemeExt for Label {
@@ -28,10 +29,16 @@ impl LabelThemeExt for Label {
         let theme = get_theme_or_default();
         self.color(theme.read().colors.text_primary)
     }
+
+    fn theme_highlight_color(self) -> Self {
+        let theme = get_theme_or_default();
+        self.highlight_color(theme.read().colors.text_highlight)
+    }
 }
 
 pub trait ParagraphThemeExt {
     fn theme_color(self) -> Self;
+    fn theme_highlight_color(self) -> Self;
 }
 
 impl ParagraphThemeExt for Paragraph {
@@ -39,6 +46,11 @@ impl ParagraphThemeExt for Paragraph {
         let theme = get_theme_or_default();
         self.color(theme.read().colors.text_primary)
     }
+
+    fn theme_highlight_color(self) -> Self {
+        let theme = get_theme_or_default();
+        self.highlight_color(theme.read().colors.text_highlight)
+    }
 }
 
 pub trait SvgThemeExt {
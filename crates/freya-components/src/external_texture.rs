@@ -0,0 +1,319 @@
+use std::{
+    any::Any,
+    borrow::Cow,
+    cell::RefCell,
+    collections::HashMap,
+    rc::Rc,
+};
+
+use freya_core::{
+    integration::*,
+    prelude::*,
+};
+use freya_engine::prelude::{
+    AlphaType,
+    BackendTexture,
+    ClipOp,
+    ColorType,
+    DirectContext,
+    Paint,
+    SkRect,
+    SurfaceOrigin,
+    borrow_texture_from,
+};
+use torin::prelude::Area;
+
+/// A GPU texture along with how to read it, shown by an [external_texture] element.
+pub struct ExternalTextureFrame {
+    texture: BackendTexture,
+    origin: SurfaceOrigin,
+    color_type: ColorType,
+}
+
+impl ExternalTextureFrame {
+    pub fn new(texture: BackendTexture, origin: SurfaceOrigin, color_type: ColorType) -> Self {
+        Self {
+            texture,
+            origin,
+            color_type,
+        }
+    }
+}
+
+type ExternalTextureRenderer =
+    Box<dyn FnMut(&mut DirectContext, Area) -> Option<ExternalTextureFrame>>;
+
+/// Handle to a GPU texture rendered by something else, such as a wgpu or OpenGL renderer,
+/// that is shown by an [external_texture] element without copying it to the CPU.
+///
+/// Wrap the texture with the Skia GPU APIs, like [freya_engine::prelude::backend_textures::make_gl].
+/// It must belong to the same GPU context that Freya renders with, use [ExternalTexture::on_render]
+/// to get access to that context.
+#[derive(Clone)]
+pub struct ExternalTexture {
+    frame: Rc<RefCell<Option<ExternalTextureFrame>>>,
+    renderer: Rc<RefCell<Option<ExternalTextureRenderer>>>,
+    platform: Platform,
+}
+
+impl PartialEq for ExternalTexture {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.frame, &other.frame)
+    }
+}
+
+impl ExternalTexture {
+    pub fn new() -> Self {
+        Self {
+            frame: Rc::default(),
+            renderer: Rc::default(),
+            platform: Platform::get(),
+        }
+    }
+
+    /// Run `renderer` with the GPU context Freya renders with every time the element is rendered,
+    /// along with the area of the element, to import or render textures in that context.
+    /// The frame it returns, if any, replaces the current one.
+    ///
+    /// Raster canvases, like the ones used in tests, have no GPU context so it doesn't run there.
+    pub fn on_render(
+        &self,
+        renderer: impl FnMut(&mut DirectContext, Area) -> Option<ExternalTextureFrame> + 'static,
+    ) {
+        *self.renderer.borrow_mut() = Some(Box::new(renderer));
+        self.platform.send(UserEvent::RequestRedraw);
+    }
+
+    /// Render the element again, useful to run the [ExternalTexture::on_render] callback every frame.
+    pub fn redraw(&self) {
+        self.platform.send(UserEvent::RequestRedraw);
+    }
+
+    /// Show a new frame, call it every time the texture is rendered again.
+    pub fn set(&self, texture: BackendTexture, origin: SurfaceOrigin, color_type: ColorType) {
+        *self.frame.borrow_mut() = Some(ExternalTextureFrame {
+            texture,
+            origin,
+            color_type,
+        });
+        self.platform.send(UserEvent::RequestRedraw);
+    }
+
+    /// Stop showing the texture.
+    pub fn clear(&self) {
+        *self.frame.borrow_mut() = None;
+        *self.renderer.borrow_mut() = None;
+        self.platform.send(UserEvent::RequestRedraw);
+    }
+}
+
+impl Default for ExternalTexture {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Create an [ExternalTexture] that lives as long as the component.
+pub fn use_external_texture() -> ExternalTexture {
+    use_hook(ExternalTexture::new)
+}
+
+#[derive(PartialEq, Clone)]
+pub struct ExternalTextureElement {
+    pub layout: LayoutData,
+    pub event_handlers: FxHashMap<EventName, EventHandlerType>,
+    pub effect: Option<EffectData>,
+    pub texture: ExternalTexture,
+}
+
+impl ElementExt for ExternalTextureElement {
+    fn changed(&self, other: &Rc<dyn ElementExt>) -> bool {
+        let Some(element) = (other.as_ref() as &dyn Any).downcast_ref::<Self>() else {
+            return false;
+        };
+
+        self != element
+    }
+
+    fn diff(&self, other: &Rc<dyn ElementExt>) -> DiffModifies {
+        let Some(element) = (other.as_ref() as &dyn Any).downcast_ref::<Self>() else {
+            return DiffModifies::all();
+        };
+
+        let mut diff = DiffModifies::empty();
+
+        if self.effect != element.effect {
+            diff.insert(DiffModifies::EFFECT);
+        }
+
+        if !self.layout.self_layout_eq(&element.layout.layout) {
+            diff.insert(DiffModifies::STYLE);
+            diff.insert(DiffModifies::LAYOUT);
+        }
+
+        if !self.layout.inner_layout_eq(&element.layout.layout) {
+            diff.insert(DiffModifies::STYLE);
+            diff.insert(DiffModifies::INNER_LAYOUT);
+        }
+
+        if self.event_handlers != element.event_handlers {
+            diff.insert(DiffModifies::EVENT_HANDLERS);
+        }
+
+        if self.texture != element.texture {
+            diff.insert(DiffModifies::STYLE);
+        }
+
+        diff
+    }
+
+    fn layout(&'_ self) -> Cow<'_, LayoutData> {
+        Cow::Borrowed(&self.layout)
+    }
+
+    fn effect(&'_ self) -> Option<Cow<'_, EffectData>> {
+        self.effect.as_ref().map(Cow::Borrowed)
+    }
+
+    fn style(&'_ self) -> Cow<'_, StyleState> {
+        Cow::Owned(StyleState::default())
+    }
+
+    fn text_style(&'_ self) -> Cow<'_, TextStyleData> {
+        Cow::Owned(TextStyleData::default())
+    }
+
+    fn accessibility(&'_ self) -> Cow<'_, AccessibilityData> {
+        Cow::Owned(AccessibilityData::default())
+    }
+
+    fn events_handlers(&'_ self) -> Option<Cow<'_, FxHashMap<EventName, EventHandlerType>>> {
+        Some(Cow::Borrowed(&self.event_handlers))
+    }
+
+    fn clip(&self, context: ClipContext) {
+        let area = context.visible_area;
+
+        context.canvas.clip_rect(
+            SkRect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y()),
+            ClipOp::Intersect,
+            true,
+        );
+    }
+
+    fn render(&self, context: RenderContext) {
+        // Raster canvases, like the ones used in tests, can't draw GPU textures
+        let Some(mut recording_context) = context.canvas.recording_context() else {
+            return;
+        };
+        let area = context.layout_node.visible_area();
+
+        if let Some(renderer) = self.texture.renderer.borrow_mut().as_mut()
+            && let Some(mut direct_context) = recording_context.as_direct_context()
+            && let Some(frame) = renderer(&mut direct_context, area)
+        {
+            *self.texture.frame.borrow_mut() = Some(frame);
+        }
+
+        let frame = self.texture.frame.borrow();
+        let Some(frame) = frame.as_ref() else {
+            return;
+        };
+        let Some(image) = borrow_texture_from(
+            &mut recording_context,
+            &frame.texture,
+            frame.origin,
+            frame.color_type,
+            AlphaType::Premul,
+            None,
+        ) else {
+            tracing::warn!("Failed to use the external texture.");
+            return;
+        };
+
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+
+        context.canvas.draw_image_rect(
+            image,
+            None,
+            SkRect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y()),
+            &paint,
+        );
+    }
+}
+
+pub struct ExternalTextureView {
+    element: ExternalTextureElement,
+    elements: Vec<Element>,
+    key: DiffKey,
+}
+
+impl ChildrenExt for ExternalTextureView {
+    fn get_children(&mut self) -> &mut Vec<Element> {
+        &mut self.elements
+    }
+}
+
+impl KeyExt for ExternalTextureView {
+    fn write_key(&mut self) -> &mut DiffKey {
+        &mut self.key
+    }
+}
+
+impl EventHandlersExt for ExternalTextureView {
+    fn get_event_handlers(&mut self) -> &mut FxHashMap<EventName, EventHandlerType> {
+        &mut self.element.event_handlers
+    }
+}
+
+impl MaybeExt for ExternalTextureView {}
+
+impl From<ExternalTextureView> for Element {
+    fn from(value: ExternalTextureView) -> Self {
+        Element::Element {
+            key: value.key,
+            element: Rc::new(value.element),
+            elements: value.elements,
+        }
+    }
+}
+
+/// Create a new `ExternalTextureView` element that shows the frames of an [ExternalTexture],
+/// stretched to fill its area.
+///
+/// See the available methods in [ExternalTextureView].
+pub fn external_texture(texture: ExternalTexture) -> ExternalTextureView {
+    ExternalTextureView::new(texture)
+}
+
+impl ExternalTextureView {
+    pub fn new(texture: ExternalTexture) -> Self {
+        Self {
+            element: ExternalTextureElement {
+                texture,
+                layout: LayoutData::default(),
+                event_handlers: HashMap::default(),
+                effect: None,
+            },
+            elements: Vec::default(),
+            key: DiffKey::None,
+        }
+    }
+
+    pub fn try_downcast(element: &dyn ElementExt) -> Option<ExternalTextureElement> {
+        (element as &dyn Any)
+            .downcast_ref::<ExternalTextureElement>()
+            .cloned()
+    }
+}
+
+impl LayoutExt for ExternalTextureView {
+    fn get_layout(&mut self) -> &mut LayoutData {
+        &mut self.element.layout
+    }
+}
+
+impl ContainerExt for ExternalTextureView {}
+
+impl ContainerWithContentExt for ExternalTextureView {}
@@ -10,6 +10,7 @@ use std::{
         Hash,
         Hasher,
     },
+    io::Cursor,
     path::PathBuf,
     rc::Rc,
     time::Duration,
@@ -48,6 +49,14 @@ use freya_engine::prelude::{
     raster_n32_premul,
 };
 use gif::DisposalMethod;
+use image::{
+    AnimationDecoder,
+    ImageFormat,
+    codecs::{
+        png::PngDecoder,
+        webp::WebPDecoder,
+    },
+};
 use torin::prelude::Size2D;
 #[cfg(feature = "remote-asset")]
 use ureq::http::Uri;
@@ -55,6 +64,7 @@ use ureq::http::Uri;
 use crate::{
     cache::*,
     loader::CircularLoader,
+    render_notifier::RenderNotifier,
 };
 
 /// ### URI
@@ -179,7 +189,10 @@ impl GifSource {
     }
 }
 
-/// GIF viewer component.
+/// Viewer for animated GIF, APNG and WebP images.
+///
+/// Frames only advance while the viewer is visible, control the playback with
+/// [GifViewer::paused] and [GifViewer::looping].
 ///
 /// # Example
 ///
@@ -208,6 +221,8 @@ impl GifSource {
 #[derive(PartialEq)]
 pub struct GifViewer {
     source: GifSource,
+    paused: bool,
+    looping: bool,
 
     layout: LayoutData,
     image_data: ImageData,
@@ -220,12 +235,26 @@ impl GifViewer {
     pub fn new(source: impl Into<GifSource>) -> Self {
         GifViewer {
             source: source.into(),
+            paused: false,
+            looping: true,
             layout: LayoutData::default(),
             image_data: ImageData::default(),
             accessibility: AccessibilityData::default(),
             key: DiffKey::None,
         }
     }
+
+    /// Stop the animation in the current frame.
+    pub fn paused(mut self, paused: bool) -> Self {
+        self.paused = paused;
+        self
+    }
+
+    /// Whether the animation starts again after the last frame, `true` by default.
+    pub fn looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
 }
 
 impl KeyExt for GifViewer {
@@ -269,89 +298,61 @@ impl Component for GifViewer {
         let mut asset_cacher = use_hook(AssetCacher::get);
         let mut assets_tasks = use_state::<Vec<TaskHandle>>(Vec::new);
 
-        let mut stream_gif = async move |bytes: Bytes| -> anyhow::Result<()> {
-            // Decode and pre-composite all frames upfront
-            let frames_data = unblock(move || -> anyhow::Result<Vec<CachedFrame>> {
-                let mut decoder_options = gif::DecodeOptions::new();
-                decoder_options.set_color_output(gif::ColorOutput::RGBA);
-                let cursor = std::io::Cursor::new(&bytes);
-                let mut decoder = decoder_options.read_info(cursor)?;
-                let width = decoder.width() as i32;
-                let height = decoder.height() as i32;
-
-                // Create a surface for compositing frames
-                let mut surface =
-                    raster_n32_premul((width, height)).context("Failed to create GIF surface")?;
-
-                let mut frames: Vec<CachedFrame> = Vec::new();
-
-                while let Ok(Some(frame)) = decoder.read_next_frame() {
-                    // Handle disposal of previous frame
-                    if let Some(prev_frame) = frames.last()
-                        && prev_frame.dispose == DisposalMethod::Background
-                    {
-                        let canvas = surface.canvas();
-                        let clear_rect = Rect::from_xywh(
-                            prev_frame.left,
-                            prev_frame.top,
-                            prev_frame.width,
-                            prev_frame.height,
-                        );
-                        canvas.save();
-                        canvas.clip_rect(clear_rect, None, false);
-                        canvas.clear(Color::TRANSPARENT);
-                        canvas.restore();
-                    }
+        let paused = use_reactive(&self.paused);
+        let looping = use_reactive(&self.looping);
 
-                    // Decode frame image
-                    let row_bytes = (frame.width * 4) as usize;
-                    let data = unsafe { Data::new_bytes(&frame.buffer) };
-                    let isize = ISize::new(frame.width as i32, frame.height as i32);
-                    let frame_image = raster_from_data(
-                        &ImageInfo::new(isize, ColorType::RGBA8888, AlphaType::Unpremul, None),
-                        data,
-                        row_bytes,
-                    )
-                    .context("Failed to create GIF Frame.")?;
-
-                    // Composite frame onto surface
-                    surface.canvas().draw_image(
-                        &frame_image,
-                        (frame.left as f32, frame.top as f32),
-                        None,
-                    );
-
-                    // Take a snapshot of the fully composed frame
-                    let composed_image = surface.image_snapshot();
-
-                    frames.push(CachedFrame {
-                        image: composed_image,
-                        dispose: frame.dispose,
-                        left: frame.left as f32,
-                        top: frame.top as f32,
-                        width: frame.width as f32,
-                        height: frame.height as f32,
-                        delay: Duration::from_millis(frame.delay as u64 * 10),
-                    });
-                }
+        let mut playback_task = use_state::<Option<TaskHandle>>(|| None);
 
-                Ok(frames)
-            })
-            .await?;
+        let mut stream_gif = async move |bytes: Bytes| -> anyhow::Result<()> {
+            // Decode and pre-composite all frames upfront
+            let frames_data = unblock(move || decode_frames(&bytes)).await?;
 
             let frames = Rc::new(CachedGifFrames {
                 frames: frames_data,
+                render_notifier: RenderNotifier::default(),
             });
-            *cached_frames.write() = Some(frames.clone());
+            *status.write() = Status::Playing(0);
+            *cached_frames.write() = Some(frames);
+            Ok(())
+        };
 
-            // Now loop through cached frames
-            loop {
-                for (i, frame) in frames.frames.iter().enumerate() {
-                    *status.write() = Status::Playing(i);
-                    Timer::after(frame.delay).await;
-                }
+        // Advance the frames only while playing, the task ends when paused or finished
+        // and starts again from the current frame when resumed
+        use_side_effect(move || {
+            let paused = *paused.read();
+            let looping = *looping.read();
+            let frames = cached_frames.read().clone();
+
+            if let Some(task) = playback_task.write().take() {
+                task.cancel();
             }
-        };
+
+            let (Some(frames), false) = (frames, paused) else {
+                return;
+            };
+            let Status::Playing(mut frame_idx) = *status.peek() else {
+                return;
+            };
+
+            let task = spawn(async move {
+                loop {
+                    let next_frame_idx = (frame_idx + 1) % frames.frames.len();
+                    if next_frame_idx == frame_idx || (next_frame_idx == 0 && !looping) {
+                        break;
+                    }
+
+                    Timer::after(frames.frames[frame_idx].delay).await;
+
+                    // Hidden GIFs are not rendered, so they stay in their current frame
+                    // without scheduling redraws until they are visible again
+                    frames.render_notifier.rendered().await;
+
+                    frame_idx = next_frame_idx;
+                    *status.write() = Status::Playing(frame_idx);
+                }
+            });
+            *playback_task.write() = Some(task);
+        });
 
         use_side_effect_with_deps(&self.source, {
             let asset_config = asset_config.clone();
@@ -645,6 +646,7 @@ impl ElementExt for GifElement {
         let rect = SkRect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y());
 
         let current_frame = &self.frames.frames[self.frame_idx];
+        self.frames.render_notifier.notify();
 
         // Simply render the pre-composed frame image directly
         context.canvas.draw_image_rect_with_sampling_options(
@@ -659,14 +661,115 @@ impl ElementExt for GifElement {
 
 struct CachedFrame {
     image: SkImage,
-    dispose: DisposalMethod,
-    left: f32,
-    top: f32,
-    width: f32,
-    height: f32,
     delay: Duration,
 }
 
+/// Shortest delay between frames, browsers also clamp the delays of frames meant to be shown at once.
+const MIN_FRAME_DELAY: Duration = Duration::from_millis(20);
+
 struct CachedGifFrames {
     frames: Vec<CachedFrame>,
+    render_notifier: RenderNotifier,
+}
+
+fn decode_frames(bytes: &[u8]) -> anyhow::Result<Vec<CachedFrame>> {
+    let frames = match image::guess_format(bytes)? {
+        ImageFormat::Gif => decode_gif_frames(bytes)?,
+        ImageFormat::Png => decode_animation_frames(PngDecoder::new(Cursor::new(bytes))?.apng()?)?,
+        ImageFormat::WebP => decode_animation_frames(WebPDecoder::new(Cursor::new(bytes))?)?,
+        format => anyhow::bail!("Unsupported animated image format {format:?}."),
+    };
+    anyhow::ensure!(!frames.is_empty(), "The image has no frames.");
+    Ok(frames
+        .into_iter()
+        .map(|frame| CachedFrame {
+            delay: frame.delay.max(MIN_FRAME_DELAY),
+            ..frame
+        })
+        .collect())
+}
+
+fn decode_gif_frames(bytes: &[u8]) -> anyhow::Result<Vec<CachedFrame>> {
+    let mut decoder_options = gif::DecodeOptions::new();
+    decoder_options.set_color_output(gif::ColorOutput::RGBA);
+    let mut decoder = decoder_options.read_info(Cursor::new(bytes))?;
+    let width = decoder.width() as i32;
+    let height = decoder.height() as i32;
+
+    // Create a surface for compositing frames
+    let mut surface = raster_n32_premul((width, height)).context("Failed to create GIF surface")?;
+
+    let mut frames: Vec<CachedFrame> = Vec::new();
+    let mut previous_frame: Option<(DisposalMethod, Rect)> = None;
+
+    while let Ok(Some(frame)) = decoder.read_next_frame() {
+        // Handle disposal of previous frame
+        if let Some((DisposalMethod::Background, clear_rect)) = previous_frame {
+            let canvas = surface.canvas();
+            canvas.save();
+            canvas.clip_rect(clear_rect, None, false);
+            canvas.clear(Color::TRANSPARENT);
+            canvas.restore();
+        }
+
+        // Decode frame image
+        let row_bytes = (frame.width * 4) as usize;
+        let data = unsafe { Data::new_bytes(&frame.buffer) };
+        let isize = ISize::new(frame.width as i32, frame.height as i32);
+        let frame_image = raster_from_data(
+            &ImageInfo::new(isize, ColorType::RGBA8888, AlphaType::Unpremul, None),
+            data,
+            row_bytes,
+        )
+        .context("Failed to create GIF Frame.")?;
+
+        // Composite frame onto surface
+        surface
+            .canvas()
+            .draw_image(&frame_image, (frame.left as f32, frame.top as f32), None);
+
+        // Take a snapshot of the fully composed frame
+        frames.push(CachedFrame {
+            image: surface.image_snapshot(),
+            delay: Duration::from_millis(frame.delay as u64 * 10),
+        });
+        previous_frame = Some((
+            frame.dispose,
+            Rect::from_xywh(
+                frame.left as f32,
+                frame.top as f32,
+                frame.width as f32,
+                frame.height as f32,
+            ),
+        ));
+    }
+
+    Ok(frames)
+}
+
+/// Decode APNG and WebP animations, their frames come already composited.
+fn decode_animation_frames<'a>(
+    decoder: impl AnimationDecoder<'a>,
+) -> anyhow::Result<Vec<CachedFrame>> {
+    decoder
+        .into_frames()
+        .map(|frame| {
+            let frame = frame?;
+            let delay = Duration::from(frame.delay());
+            let buffer = frame.into_buffer();
+            let (width, height) = buffer.dimensions();
+            let image = raster_from_data(
+                &ImageInfo::new(
+                    (width as i32, height as i32),
+                    ColorType::RGBA8888,
+                    AlphaType::Unpremul,
+                    None,
+                ),
+                Data::new_copy(&buffer),
+                width as usize * 4,
+            )
+            .context("Failed to create animation frame.")?;
+            Ok(CachedFrame { image, delay })
+        })
+        .collect()
 }
@@ -391,7 +391,21 @@ impl Component for Input {
             if e.data().text.is_empty() {
                 editor.clear_preedit();
             } else {
-                editor.set_preedit(&e.data().text);
+                editor.set_preedit_with_cursor(&e.data().text, e.data().cursor);
+            }
+        };
+
+        // Caret and selection moved by assistive technologies
+        let on_a11y_action = move |e: Event<AccessibilityActionEventData>| {
+            if let Some((anchor, focus)) = e.text_selection {
+                let mut editor = editable.editor_mut().write();
+                let len = editor.len_utf16_cu();
+                let (anchor, focus) = (anchor.min(len), focus.min(len));
+                if anchor == focus {
+                    *editor.selection_mut() = TextSelection::new_cursor(focus);
+                } else {
+                    editor.set_selection((anchor, focus));
+                }
             }
         };
 
@@ -608,6 +622,7 @@ impl Component for Input {
                     .on_key_down(on_key_down)
                     .on_pointer_down(on_input_pointer_down)
                     .on_ime_preedit(on_ime_preedit)
+                    .on_a11y_action(on_a11y_action)
                     .on_pointer_press(on_pointer_press)
                     .on_global_pointer_press(on_global_pointer_press)
                     .on_global_pointer_move(on_global_pointer_move)
@@ -3,7 +3,10 @@ use freya_core::{
     layers::Layer,
     prelude::*,
 };
-use torin::prelude::Position;
+use torin::prelude::{
+    CursorPoint,
+    Position,
+};
 
 use crate::context_menu::{
     ContextMenu,
@@ -13,8 +16,13 @@ use crate::context_menu::{
 pub fn integration(app: AppComponent) -> impl IntoElement {
     let platform = use_hook(Platform::get);
     let mut context = use_hook(ContextMenu::get);
+    let shortcuts = use_hook(ShortcutRegistry::get);
 
     let on_global_key_down = move |e: Event<KeyboardEventData>| match e.key {
+        _ if shortcuts.handle(&e) => {}
+        Key::Named(NamedKey::Escape) if context.menu.read().is_some() => {
+            context.menu.set(None);
+        }
         Key::Named(NamedKey::Tab) if e.modifiers == Modifiers::SHIFT => {
             platform.send(UserEvent::FocusAccessibilityNode(
                 AccessibilityFocusStrategy::Backward(AccessibilityFocusMovement::OutsideGroup),
@@ -42,9 +50,29 @@ pub fn integration(app: AppComponent) -> impl IntoElement {
         context.location.set(e.global_location());
     };
 
+    // Preview of the in-app drag, if any
+    let mut drag_preview = use_state(|| None::<(CursorPoint, Element)>);
+
+    let on_global_drag_over = move |e: Event<DragEventData>| {
+        drag_preview.set(
+            e.drag_data
+                .preview()
+                .map(|preview| (e.global_location, preview)),
+        );
+    };
+
+    let on_drag_end = move |_: Event<DragEventData>| {
+        if drag_preview.read().is_some() {
+            drag_preview.set(None);
+        }
+    };
+
     rect()
         .on_global_pointer_move(on_global_pointer_move)
         .on_global_key_down(on_global_key_down)
+        .on_global_drag_over(on_global_drag_over)
+        .on_global_drop(on_drag_end)
+        .on_global_drag_cancel(on_drag_end)
         .child(app)
         .maybe_child(context.menu.read().clone().map(|(location, menu)| {
             let location = location.to_f32();
@@ -61,4 +89,11 @@ pub fn integration(app: AppComponent) -> impl IntoElement {
                     }
                 }))
         }))
+        .maybe_child(drag_preview.read().clone().map(|(location, preview)| {
+            let location = location.to_f32();
+            rect()
+                .layer(Layer::Overlay)
+                .position(Position::new_global().left(location.x).top(location.y))
+                .child(preview)
+        }))
 }
@@ -2,6 +2,7 @@
 
 pub mod accordion;
 pub mod activable_route_context;
+pub mod anchored;
 pub mod attached;
 pub mod button;
 pub mod cache;
@@ -15,9 +16,11 @@ pub mod color_picker;
 pub mod context_menu;
 pub mod cursor_area;
 pub mod cursor_blink;
+pub mod data_table;
 pub mod drag_drop;
 pub mod draggable_canvas;
 pub mod element_expansions;
+pub mod external_texture;
 pub mod floating_tab;
 pub mod icons;
 pub mod image_viewer;
@@ -39,11 +42,14 @@ pub mod sidebar;
 pub mod slider;
 pub mod switch;
 pub mod table;
+pub mod tabs;
 pub mod theming;
 pub mod tile;
 #[cfg(feature = "titlebar")]
 pub mod titlebar;
+pub mod toast;
 pub mod tooltip;
+pub mod tree_view;
 
 #[cfg(feature = "remote-asset")]
 pub use ureq::http::Uri;
@@ -63,6 +69,18 @@ cfg_if::cfg_if! {
     }
 }
 
+cfg_if::cfg_if! {
+    if #[cfg(feature = "lottie")] {
+        pub mod lottie;
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(any(feature = "gif", feature = "lottie"))] {
+        mod render_notifier;
+    }
+}
+
 cfg_if::cfg_if! {
     if #[cfg(feature = "markdown")] {
         pub mod markdown;
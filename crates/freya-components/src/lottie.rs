@@ -0,0 +1,418 @@
+use std::{
+    any::Any,
+    borrow::Cow,
+    cell::RefCell,
+    collections::HashMap,
+    ops::Range,
+    rc::Rc,
+    time::Duration,
+};
+
+use async_io::Timer;
+use bytes::Bytes;
+use freya_core::{
+    integration::*,
+    prelude::*,
+};
+use freya_engine::prelude::{
+    ClipOp,
+    SkRect,
+    skottie::Animation,
+};
+
+use crate::render_notifier::RenderNotifier;
+
+/// Control the playback of a [LottieViewer] from outside, created with [use_lottie].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseLottie {
+    playing: State<bool>,
+    frame: State<usize>,
+}
+
+impl UseLottie {
+    pub fn play(&mut self) {
+        self.playing.set(true);
+    }
+
+    pub fn pause(&mut self) {
+        self.playing.set(false);
+    }
+
+    pub fn toggle(&mut self) {
+        self.playing.toggle();
+    }
+
+    pub fn is_playing(&self) -> bool {
+        *self.playing.read()
+    }
+
+    /// Current frame of the animation.
+    pub fn frame(&self) -> usize {
+        *self.frame.read()
+    }
+
+    /// Jump to the given frame.
+    pub fn seek(&mut self, frame: usize) {
+        self.frame.set(frame);
+    }
+}
+
+/// Create a [UseLottie] to play, pause and seek a [LottieViewer].
+pub fn use_lottie() -> UseLottie {
+    let playing = use_state(|| true);
+    let frame = use_state(|| 0);
+    UseLottie { playing, frame }
+}
+
+/// Player for Lottie animations exported from After Effects with Bodymovin.
+///
+/// Needs the `lottie` feature, animations are rendered with Skia's Skottie player.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> impl IntoElement {
+///     let mut lottie = use_lottie();
+///
+///     rect()
+///         .child(
+///             LottieViewer::new(("loading", include_bytes!("../../../examples/pulse.json")))
+///                 .controller(lottie)
+///                 .speed(2.)
+///                 .expanded(),
+///         )
+///         .child(
+///             Button::new()
+///                 .on_press(move |_| lottie.toggle())
+///                 .child("Play/Pause"),
+///         )
+/// }
+/// ```
+#[derive(PartialEq)]
+pub struct LottieViewer {
+    id: &'static str,
+    json: Bytes,
+    controller: Option<UseLottie>,
+    speed: f32,
+    segment: Option<Range<usize>>,
+    looping: bool,
+
+    layout: LayoutData,
+    accessibility: AccessibilityData,
+
+    key: DiffKey,
+}
+
+impl LottieViewer {
+    /// Create a player for the given Bodymovin JSON, `id` identifies the animation.
+    pub fn new((id, json): (&'static str, impl Into<Bytes>)) -> Self {
+        Self {
+            id,
+            json: json.into(),
+            controller: None,
+            speed: 1.,
+            segment: None,
+            looping: true,
+            layout: LayoutData::default(),
+            accessibility: AccessibilityData::default(),
+            key: DiffKey::None,
+        }
+    }
+
+    pub fn controller(mut self, controller: UseLottie) -> Self {
+        self.controller = Some(controller);
+        self
+    }
+
+    /// Playback speed, `1.` by default.
+    pub fn speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Only play the given range of frames.
+    pub fn segment(mut self, segment: Range<usize>) -> Self {
+        self.segment = Some(segment);
+        self
+    }
+
+    /// Whether the animation starts again after the last frame, `true` by default.
+    pub fn looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+}
+
+impl KeyExt for LottieViewer {
+    fn write_key(&mut self) -> &mut DiffKey {
+        &mut self.key
+    }
+}
+
+impl LayoutExt for LottieViewer {
+    fn get_layout(&mut self) -> &mut LayoutData {
+        &mut self.layout
+    }
+}
+
+impl ContainerSizeExt for LottieViewer {}
+
+impl AccessibilityExt for LottieViewer {
+    fn get_accessibility_data(&mut self) -> &mut AccessibilityData {
+        &mut self.accessibility
+    }
+}
+
+struct LottieAnimation {
+    animation: RefCell<Animation>,
+    total_frames: usize,
+    frame_rate: f64,
+    render_notifier: RenderNotifier,
+}
+
+impl Component for LottieViewer {
+    fn render(&self) -> impl IntoElement {
+        let own_controller = use_lottie();
+        let mut controller = self.controller.unwrap_or(own_controller);
+        let speed = use_reactive(&self.speed);
+        let segment = use_reactive(&self.segment);
+        let looping = use_reactive(&self.looping);
+        let source = use_reactive(&(self.id, self.json.clone()));
+
+        let mut playback = use_state::<Option<TaskHandle>>(|| None);
+
+        let animation = use_side_effect_value(move || {
+            let (_, json) = source.read().clone();
+            Animation::from_bytes(&json).map(|animation| {
+                let frame_rate = f64::from(animation.fps()).max(1.);
+                Rc::new(LottieAnimation {
+                    total_frames: (f64::from(animation.duration()) * frame_rate)
+                        .round()
+                        .max(1.) as usize,
+                    frame_rate,
+                    animation: RefCell::new(animation),
+                    render_notifier: RenderNotifier::default(),
+                })
+            })
+        });
+
+        // Advance the frames only while playing, the task ends when paused or finished
+        // and starts again when resumed
+        use_side_effect(move || {
+            let playing = *controller.playing.read();
+            let looping = *looping.read();
+            let segment = segment.read().clone();
+            let animation = animation.read().clone();
+
+            if let Some(playback) = playback.write().take() {
+                playback.cancel();
+            }
+            let (Some(animation), true) = (animation, playing) else {
+                return;
+            };
+            *playback.write() = Some(spawn(async move {
+                let range = segment.unwrap_or(0..animation.total_frames);
+                loop {
+                    let frame = *controller.frame.peek();
+                    let next_frame = if range.contains(&(frame + 1)) {
+                        frame + 1
+                    } else if looping || !range.contains(&frame) {
+                        range.start
+                    } else {
+                        break;
+                    };
+                    if next_frame == frame {
+                        break;
+                    }
+
+                    let speed = speed.peek().max(f32::EPSILON) as f64;
+                    Timer::after(Duration::from_secs_f64(1. / (animation.frame_rate * speed)))
+                        .await;
+
+                    // Hidden animations are not rendered, so they stay in their current frame
+                    // without scheduling redraws until they are visible again
+                    animation.render_notifier.rendered().await;
+
+                    controller.frame.set(next_frame);
+                }
+            }));
+        });
+
+        match animation.read().clone() {
+            Some(animation) => lottie(animation, controller.frame())
+                .accessibility(self.accessibility.clone())
+                .a11y_role(AccessibilityRole::Image)
+                .layout(self.layout.clone())
+                .into_element(),
+            None => "Invalid Lottie animation".into_element(),
+        }
+    }
+
+    fn render_key(&self) -> DiffKey {
+        self.key.clone().or(self.default_key())
+    }
+}
+
+pub struct Lottie {
+    key: DiffKey,
+    element: LottieElement,
+}
+
+impl Lottie {
+    pub fn try_downcast(element: &dyn ElementExt) -> Option<LottieElement> {
+        (element as &dyn Any)
+            .downcast_ref::<LottieElement>()
+            .cloned()
+    }
+}
+
+impl From<Lottie> for Element {
+    fn from(value: Lottie) -> Self {
+        Element::Element {
+            key: value.key,
+            element: Rc::new(value.element),
+            elements: vec![],
+        }
+    }
+}
+
+fn lottie(animation: Rc<LottieAnimation>, frame: usize) -> Lottie {
+    Lottie {
+        key: DiffKey::None,
+        element: LottieElement {
+            animation,
+            frame,
+            accessibility: AccessibilityData::default(),
+            layout: LayoutData::default(),
+            event_handlers: HashMap::default(),
+        },
+    }
+}
+
+impl LayoutExt for Lottie {
+    fn get_layout(&mut self) -> &mut LayoutData {
+        &mut self.element.layout
+    }
+}
+
+impl ContainerExt for Lottie {}
+
+impl KeyExt for Lottie {
+    fn write_key(&mut self) -> &mut DiffKey {
+        &mut self.key
+    }
+}
+
+impl EventHandlersExt for Lottie {
+    fn get_event_handlers(&mut self) -> &mut FxHashMap<EventName, EventHandlerType> {
+        &mut self.element.event_handlers
+    }
+}
+
+impl AccessibilityExt for Lottie {
+    fn get_accessibility_data(&mut self) -> &mut AccessibilityData {
+        &mut self.element.accessibility
+    }
+}
+
+impl MaybeExt for Lottie {}
+
+#[derive(Clone)]
+pub struct LottieElement {
+    accessibility: AccessibilityData,
+    layout: LayoutData,
+    event_handlers: FxHashMap<EventName, EventHandlerType>,
+    animation: Rc<LottieAnimation>,
+    frame: usize,
+}
+
+impl PartialEq for LottieElement {
+    fn eq(&self, other: &Self) -> bool {
+        self.accessibility == other.accessibility
+            && self.layout == other.layout
+            && self.event_handlers == other.event_handlers
+            && Rc::ptr_eq(&self.animation, &other.animation)
+            && self.frame == other.frame
+    }
+}
+
+impl ElementExt for LottieElement {
+    fn changed(&self, other: &Rc<dyn ElementExt>) -> bool {
+        let Some(lottie) = (other.as_ref() as &dyn Any).downcast_ref::<LottieElement>() else {
+            return false;
+        };
+        self != lottie
+    }
+
+    fn diff(&self, other: &Rc<dyn ElementExt>) -> DiffModifies {
+        let Some(lottie) = (other.as_ref() as &dyn Any).downcast_ref::<LottieElement>() else {
+            return DiffModifies::all();
+        };
+
+        let mut diff = DiffModifies::empty();
+
+        if self.accessibility != lottie.accessibility {
+            diff.insert(DiffModifies::ACCESSIBILITY);
+        }
+
+        if self.layout != lottie.layout {
+            diff.insert(DiffModifies::LAYOUT);
+        }
+
+        if self.event_handlers != lottie.event_handlers {
+            diff.insert(DiffModifies::EVENT_HANDLERS);
+        }
+
+        if self.frame != lottie.frame || !Rc::ptr_eq(&self.animation, &lottie.animation) {
+            diff.insert(DiffModifies::STYLE);
+        }
+
+        diff
+    }
+
+    fn layout(&'_ self) -> Cow<'_, LayoutData> {
+        Cow::Borrowed(&self.layout)
+    }
+
+    fn effect(&'_ self) -> Option<Cow<'_, EffectData>> {
+        None
+    }
+
+    fn style(&'_ self) -> Cow<'_, StyleState> {
+        Cow::Owned(StyleState::default())
+    }
+
+    fn text_style(&'_ self) -> Cow<'_, TextStyleData> {
+        Cow::Owned(TextStyleData::default())
+    }
+
+    fn accessibility(&'_ self) -> Cow<'_, AccessibilityData> {
+        Cow::Borrowed(&self.accessibility)
+    }
+
+    fn events_handlers(&'_ self) -> Option<Cow<'_, FxHashMap<EventName, EventHandlerType>>> {
+        Some(Cow::Borrowed(&self.event_handlers))
+    }
+
+    fn clip(&self, context: ClipContext) {
+        let area = context.visible_area;
+        context.canvas.clip_rect(
+            SkRect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y()),
+            ClipOp::Intersect,
+            true,
+        );
+    }
+
+    fn render(&self, context: RenderContext) {
+        self.animation.render_notifier.notify();
+
+        let area = context.layout_node.visible_area();
+        let mut animation = self.animation.animation.borrow_mut();
+        animation.seek_frame(self.frame as f64);
+        animation.render(
+            context.canvas,
+            &SkRect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y()),
+        );
+    }
+}
@@ -73,6 +73,8 @@ define_theme! {
 /// - Blockquotes
 /// - Horizontal rules
 ///
+/// Links open with the router, unless a handler is set with [MarkdownViewer::on_link_click].
+///
 /// # Example
 ///
 /// ```rust
@@ -86,6 +88,7 @@ pub struct MarkdownViewer {
     content: Cow<'static, str>,
     layout: LayoutData,
     key: DiffKey,
+    on_link_click: Option<EventHandler<String>>,
     pub(crate) theme: Option<MarkdownViewerThemePartial>,
 }
 
@@ -95,9 +98,16 @@ impl MarkdownViewer {
             content: content.into(),
             layout: LayoutData::default(),
             key: DiffKey::None,
+            on_link_click: None,
             theme: None,
         }
     }
+
+    /// Handle clicks on links yourself, the handler receives the URL of the clicked link.
+    pub fn on_link_click(mut self, on_link_click: impl Into<EventHandler<String>>) -> Self {
+        self.on_link_click = Some(on_link_click.into());
+        self
+    }
 }
 
 impl KeyExt for MarkdownViewer {
@@ -605,6 +615,20 @@ impl Component for MarkdownViewer {
                 MarkdownElement::Image { alt, .. } => {
                     label().key(idx).text(format!("[Image: {}]", alt)).into()
                 }
+                MarkdownElement::Link { url, text, .. } if self.on_link_click.is_some() => {
+                    let on_link_click = self.on_link_click.clone();
+                    rect()
+                        .key(idx)
+                        .a11y_role(AccessibilityRole::Link)
+                        .a11y_focusable(true)
+                        .on_press(move |_| {
+                            if let Some(on_link_click) = &on_link_click {
+                                on_link_click.call(url.clone());
+                            }
+                        })
+                        .child(render_spans(&text, paragraph_size, Some(color)))
+                        .into()
+                }
                 #[cfg(feature = "router")]
                 MarkdownElement::Link { url, title, text } => {
                     let mut tooltip = LinkTooltip::Default;
@@ -141,8 +141,18 @@ impl ComponentOwned for Menu {
             .on_press(move |ev: Event<PressEventData>| {
                 ev.stop_propagation();
             })
-            .on_global_pointer_press(move |_: Event<PointerEventData>| {
-                if let Some(on_close) = &self.on_close {
+            .on_global_pointer_press({
+                let on_close = self.on_close.clone();
+                move |_: Event<PointerEventData>| {
+                    if let Some(on_close) = &on_close {
+                        on_close.call(());
+                    }
+                }
+            })
+            .on_global_key_down(move |e: Event<KeyboardEventData>| {
+                if e.key == Key::Named(NamedKey::Escape)
+                    && let Some(on_close) = &self.on_close
+                {
                     on_close.call(());
                 }
             })
@@ -168,6 +178,7 @@ impl ComponentOwned for Menu {
 #[derive(Default, Clone, PartialEq)]
 pub struct MenuContainer {
     pub(crate) theme: Option<MenuContainerThemePartial>,
+    pub(crate) auto_focus: bool,
     children: Vec<Element>,
     key: DiffKey,
 }
@@ -195,6 +206,12 @@ impl ComponentOwned for MenuContainer {
         let focus = use_focus();
         let theme = get_theme!(self.theme, MenuContainerThemePreference, "menu_container");
         let mut measured = use_state(|| None::<(Area, f32, f32)>);
+        let auto_focus = self.auto_focus;
+        use_hook(move || {
+            if auto_focus {
+                focus.request_focus();
+            }
+        });
 
         use_provide_context(move || MenuGroup {
             group_id: focus.a11y_id(),
@@ -267,6 +284,8 @@ pub struct MenuItem {
     children: Vec<Element>,
     on_press: Option<EventHandler<Event<PressEventData>>>,
     on_pointer_enter: Option<EventHandler<Event<PointerEventData>>>,
+    on_key_down: Option<EventHandler<Event<KeyboardEventData>>>,
+    a11y_id: Option<AccessibilityId>,
     selected: bool,
     padding: Gaps,
     key: DiffKey,
@@ -279,6 +298,8 @@ impl Default for MenuItem {
             children: Vec::new(),
             on_press: None,
             on_pointer_enter: None,
+            on_key_down: None,
+            a11y_id: None,
             selected: false,
             padding: (6.0, 12.0).into(),
             key: DiffKey::None,
@@ -313,6 +334,20 @@ impl MenuItem {
         self
     }
 
+    /// Called for keys other than the ones activating [MenuItem::on_press].
+    pub fn on_key_down<F>(mut self, f: F) -> Self
+    where
+        F: Into<EventHandler<Event<KeyboardEventData>>>,
+    {
+        self.on_key_down = Some(f.into());
+        self
+    }
+
+    pub(crate) fn a11y_id(mut self, a11y_id: AccessibilityId) -> Self {
+        self.a11y_id = Some(a11y_id);
+        self
+    }
+
     pub fn selected(mut self, selected: bool) -> Self {
         self.selected = selected;
         self
@@ -351,7 +386,7 @@ impl ComponentOwned for MenuItem {
     fn render(self) -> impl IntoElement {
         let theme = get_theme!(self.theme, MenuItemThemePreference, "menu_item");
         let mut hovering = use_state(|| false);
-        let focus = use_focus();
+        let focus = use_hook(|| self.a11y_id.map_or_else(Focus::create, Focus::new_for_id));
         let focus_status = use_focus_status(focus);
         let MenuGroup { group_id } = use_consume::<MenuGroup>();
 
@@ -386,7 +421,7 @@ impl ComponentOwned for MenuItem {
             hovering.set(false);
         };
 
-        let on_press = move |e: Event<PressEventData>| {
+        let on_press = EventHandler::from(move |e: Event<PressEventData>| {
             let prevent_default = e.get_prevent_default();
             if let Some(on_press) = &self.on_press {
                 on_press.call(e);
@@ -394,7 +429,7 @@ impl ComponentOwned for MenuItem {
             if *prevent_default.borrow() {
                 focus.request_focus();
             }
-        };
+        });
 
         rect()
             .a11y_role(AccessibilityRole::MenuItem)
@@ -414,7 +449,17 @@ impl ComponentOwned for MenuItem {
             .overflow(Overflow::Clip)
             .on_pointer_enter(on_pointer_enter)
             .on_pointer_leave(on_pointer_leave)
-            .on_press(on_press)
+            .on_press(on_press.clone())
+            .map(self.on_key_down, |el, on_key_down| {
+                // This replaces the keyboard activation of `on_press`
+                el.on_key_down(move |e: Event<KeyboardEventData>| {
+                    if Focus::is_pressed(&e) {
+                        on_press.call(e.map(PressEventData::Keyboard));
+                    } else {
+                        on_key_down.call(e);
+                    }
+                })
+            })
             .children(self.children)
     }
 
@@ -469,9 +514,18 @@ impl ComponentOwned for MenuButton {
     fn render(self) -> impl IntoElement {
         let mut menus = use_consume::<State<Vec<MenuId>>>();
         let parent_menu_id = use_consume::<MenuId>();
+        let origin = use_try_consume::<SubMenuOrigin>();
 
         MenuItem::new()
             .on_pointer_enter(move |_| close_menus_until(&mut menus, parent_menu_id))
+            .on_key_down(move |e: Event<KeyboardEventData>| {
+                if e.key == Key::Named(NamedKey::ArrowLeft)
+                    && let Some(origin) = origin
+                {
+                    e.stop_propagation();
+                    origin.close(&mut menus);
+                }
+            })
             .map(self.on_press.clone(), |el, on_press| el.on_press(on_press))
             .children(self.children)
     }
@@ -528,29 +582,57 @@ impl ComponentOwned for SubMenu {
         let parent_menu_id = use_consume::<MenuId>();
         let mut menus = use_consume::<State<Vec<MenuId>>>();
         let mut menus_ids_generator = use_consume::<State<usize>>();
+        // Consumed before providing the origin of this submenu's own items
+        let origin = use_try_consume::<SubMenuOrigin>();
+        let focus = use_focus();
+        let mut focus_on_open = use_state(|| false);
 
         let submenu_id = use_hook(|| {
             *menus_ids_generator.write() += 1;
             let menu_id = MenuId(*menus_ids_generator.peek());
             provide_context(menu_id);
+            provide_context(SubMenuOrigin {
+                parent_menu_id,
+                focus,
+            });
             menu_id
         });
 
         let show_submenu = menus.read().contains(&submenu_id);
 
         let on_pointer_enter = move |_| {
+            focus_on_open.set_if_modified(false);
             close_menus_until(&mut menus, parent_menu_id);
             push_menu(&mut menus, submenu_id);
         };
 
-        let on_press = move |_| {
+        let on_press = move |e: Event<PressEventData>| {
+            focus_on_open.set(matches!(e.data(), PressEventData::Keyboard(_)));
             close_menus_until(&mut menus, parent_menu_id);
             push_menu(&mut menus, submenu_id);
         };
 
+        let on_key_down = move |e: Event<KeyboardEventData>| match e.key {
+            Key::Named(NamedKey::ArrowRight) => {
+                e.stop_propagation();
+                focus_on_open.set(true);
+                close_menus_until(&mut menus, parent_menu_id);
+                push_menu(&mut menus, submenu_id);
+            }
+            Key::Named(NamedKey::ArrowLeft) => {
+                if let Some(origin) = origin {
+                    e.stop_propagation();
+                    origin.close(&mut menus);
+                }
+            }
+            _ => {}
+        };
+
         MenuItem::new()
+            .a11y_id(focus.a11y_id())
             .on_pointer_enter(on_pointer_enter)
             .on_press(on_press)
+            .on_key_down(on_key_down)
             .child(rect().horizontal().maybe_child(self.label.clone()))
             .maybe_child(show_submenu.then(|| {
                 rect()
@@ -560,7 +642,10 @@ impl ComponentOwned for SubMenu {
                     .child(
                         rect()
                             .width(Size::window_percent(100.))
-                            .child(MenuContainer::new().children(self.items)),
+                            .child(MenuContainer {
+                                auto_focus: focus_on_open(),
+                                ..MenuContainer::new().children(self.items)
+                            }),
                     )
             }))
     }
@@ -586,6 +671,20 @@ static ROOT_MENU: MenuId = MenuId(0);
 #[derive(Clone, Copy, PartialEq, Eq)]
 struct MenuId(usize);
 
+/// Item that opened a submenu, for its items to return to it.
+#[derive(Clone, Copy)]
+struct SubMenuOrigin {
+    parent_menu_id: MenuId,
+    focus: Focus,
+}
+
+impl SubMenuOrigin {
+    fn close(&self, menus: &mut State<Vec<MenuId>>) {
+        close_menus_until(menus, self.parent_menu_id);
+        self.focus.request_focus();
+    }
+}
+
 fn close_menus_until(menus: &mut State<Vec<MenuId>>, until: MenuId) {
     menus.write().retain(|&id| id.0 <= until.0);
 }
@@ -46,6 +46,7 @@ impl PopupBackground {
 
 impl Component for PopupBackground {
     fn render(&self) -> impl IntoElement {
+        use_focus_scope();
         let on_press = self.on_press.clone();
 
         rect()
@@ -63,6 +64,7 @@ impl Component for PopupBackground {
                     .height(Size::window_percent(100.))
                     .width(Size::window_percent(100.))
                     .center()
+                    .a11y_focus_scope(true)
                     .child(self.children.clone()),
             )
     }
@@ -0,0 +1,40 @@
+use std::{
+    cell::Cell,
+    task::{
+        Poll,
+        Waker,
+    },
+};
+
+/// Lets a playback task wait until its element is painted, so hidden animations
+/// stay in their current frame instead of advancing unseen.
+#[derive(Default)]
+pub(crate) struct RenderNotifier {
+    /// Whether the element was rendered since the last wait.
+    rendered: Cell<bool>,
+    /// Task waiting for the element to be rendered.
+    waker: Cell<Option<Waker>>,
+}
+
+impl RenderNotifier {
+    /// Mark the element as rendered, called from its render.
+    pub(crate) fn notify(&self) {
+        self.rendered.set(true);
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+
+    /// Wait until the element is rendered since the last wait.
+    pub(crate) async fn rendered(&self) {
+        std::future::poll_fn(|cx| {
+            if self.rendered.replace(false) {
+                Poll::Ready(())
+            } else {
+                self.waker.set(Some(cx.waker().clone()));
+                Poll::Pending
+            }
+        })
+        .await
+    }
+}
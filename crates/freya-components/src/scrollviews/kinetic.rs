@@ -0,0 +1,212 @@
+use std::time::{
+    Duration,
+    Instant,
+};
+
+use freya_animation::{
+    easing::Function,
+    hook::{
+        Ease,
+        OnChange,
+        ReadAnimatedValue,
+        use_animation_with_dependencies,
+    },
+    prelude::AnimNum,
+};
+use freya_core::prelude::*;
+
+use crate::scrollviews::shared::get_corrected_scroll_position;
+
+/// Deceleration of momentum scrolling, in pixels per squared millisecond.
+pub const DEFAULT_DECELERATION: f32 = 0.002;
+/// Fastest speed momentum scrolling can start at, in pixels per millisecond.
+const MAX_VELOCITY: f32 = 8.;
+/// Slower releases, in pixels per millisecond, don't start momentum scrolling.
+const MIN_VELOCITY: f32 = 0.1;
+/// Weight of the latest movement in the tracked velocity.
+const VELOCITY_SMOOTHING: f32 = 0.8;
+/// Holding still for this long before releasing doesn't start momentum scrolling.
+const VELOCITY_TIMEOUT: Duration = Duration::from_millis(80);
+/// Time without wheel events after which the wheel is considered released,
+/// shorter than [VELOCITY_TIMEOUT] so it keeps its momentum.
+pub(crate) const WHEEL_RELEASE_DELAY: Duration = Duration::from_millis(50);
+/// Portion of the movement past the edges that stretches the content.
+const OVERSCROLL_RESISTANCE: f32 = 0.4;
+/// Portion of the viewport the content can be stretched by.
+const MAX_OVERSCROLL: f32 = 0.25;
+const OVERSCROLL_RELEASE_DURATION: u64 = 300;
+
+/// Scroll offset and duration in milliseconds of the momentum started when releasing a drag
+/// at `velocity`, if any.
+#[doc(hidden)]
+pub fn get_momentum(velocity: (f32, f32), deceleration: f32) -> Option<((f32, f32), u64)> {
+    let speed = velocity.0.hypot(velocity.1);
+    if speed < MIN_VELOCITY || deceleration <= 0. {
+        return None;
+    }
+
+    let scale = speed.min(MAX_VELOCITY) / speed;
+    let (velocity_x, velocity_y) = (velocity.0 * scale, velocity.1 * scale);
+    let duration = speed.min(MAX_VELOCITY) / deceleration;
+    if duration < 1. {
+        return None;
+    }
+
+    // Distance travelled while slowing down at a constant rate until stopping
+    Some((
+        (velocity_x * duration / 2., velocity_y * duration / 2.),
+        duration as u64,
+    ))
+}
+
+/// Apply a drag `movement` to the scroll position, stretching the content by the movement
+/// past the edges. Returns the new scroll position and overscroll.
+#[doc(hidden)]
+pub fn get_overscrolled_position(
+    movement: f32,
+    scroll_position: f32,
+    overscroll: f32,
+    inner_size: f32,
+    viewport_size: f32,
+) -> (f32, f32) {
+    let mut movement = movement;
+    let mut overscroll = overscroll;
+
+    // Moving back undoes the overscroll before scrolling again
+    if overscroll * movement < 0. {
+        let remaining = overscroll + movement;
+        if remaining * overscroll > 0. {
+            return (scroll_position, remaining);
+        }
+        movement = remaining;
+        overscroll = 0.;
+    }
+
+    let position = scroll_position + movement;
+    let corrected_position = get_corrected_scroll_position(inner_size, viewport_size, position);
+    let max_overscroll = viewport_size * MAX_OVERSCROLL;
+    let overscroll = (overscroll + (position - corrected_position) * OVERSCROLL_RESISTANCE)
+        .clamp(-max_overscroll, max_overscroll);
+
+    (corrected_position, overscroll)
+}
+
+/// Tracks the speed of drag and wheel scrolling to keep scrolling after it's released.
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) struct VelocityTracker {
+    last_movement: State<Option<Instant>>,
+    velocity: State<(f32, f32)>,
+}
+
+impl VelocityTracker {
+    pub(crate) fn start(&mut self) {
+        self.last_movement.set(Some(AnimationClock::get().now()));
+        self.velocity.set((0., 0.));
+    }
+
+    pub(crate) fn track(&mut self, movement: (f32, f32)) {
+        let now = AnimationClock::get().now();
+        let velocity = match *self.last_movement.peek() {
+            // Moving again after holding still starts from rest
+            Some(last_movement)
+                if now.saturating_duration_since(last_movement) < VELOCITY_TIMEOUT =>
+            {
+                let elapsed =
+                    (now.saturating_duration_since(last_movement).as_secs_f32() * 1000.).max(1.);
+                let (velocity_x, velocity_y) = *self.velocity.peek();
+                (
+                    velocity_x * (1. - VELOCITY_SMOOTHING)
+                        + movement.0 / elapsed * VELOCITY_SMOOTHING,
+                    velocity_y * (1. - VELOCITY_SMOOTHING)
+                        + movement.1 / elapsed * VELOCITY_SMOOTHING,
+                )
+            }
+            _ => (0., 0.),
+        };
+        self.velocity.set(velocity);
+        self.last_movement.set(Some(now));
+    }
+
+    /// Velocity in pixels per millisecond at which the drag was released.
+    pub(crate) fn release(&mut self) -> (f32, f32) {
+        let now = AnimationClock::get().now();
+        let last_movement = self.last_movement.write().take();
+        let velocity = *self.velocity.peek();
+        self.velocity.set((0., 0.));
+        match last_movement {
+            Some(last_movement)
+                if now.saturating_duration_since(last_movement) < VELOCITY_TIMEOUT =>
+            {
+                velocity
+            }
+            _ => (0., 0.),
+        }
+    }
+}
+
+pub(crate) fn use_velocity_tracker() -> VelocityTracker {
+    VelocityTracker {
+        last_movement: use_state(|| None),
+        velocity: use_state(|| (0., 0.)),
+    }
+}
+
+/// Content stretched past the edges while dragging, springing back once released.
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) struct Overscroll {
+    offset: State<(f32, f32)>,
+    releasing: State<Option<(f32, f32)>>,
+}
+
+impl Overscroll {
+    pub(crate) fn get(&self) -> (f32, f32) {
+        *self.offset.read()
+    }
+
+    pub(crate) fn peek(&self) -> (f32, f32) {
+        *self.offset.peek()
+    }
+
+    pub(crate) fn set(&mut self, offset: (f32, f32)) {
+        if self.releasing.peek().is_some() {
+            self.releasing.set(None);
+        }
+        self.offset.set_if_modified(offset);
+    }
+
+    pub(crate) fn release(&mut self) {
+        let offset = self.peek();
+        if offset != (0., 0.) {
+            self.releasing.set(Some(offset));
+        }
+    }
+}
+
+pub(crate) fn use_overscroll() -> Overscroll {
+    let mut offset = use_state(|| (0., 0.));
+    let releasing = use_state(|| None::<(f32, f32)>);
+
+    let animation = use_animation_with_dependencies(&releasing(), |conf, releasing| {
+        conf.on_change(OnChange::Rerun);
+        let (x, y) = releasing.unwrap_or_default();
+        (
+            AnimNum::new(x, 0.)
+                .time(OVERSCROLL_RELEASE_DURATION)
+                .ease(Ease::Out)
+                .function(Function::Cubic),
+            AnimNum::new(y, 0.)
+                .time(OVERSCROLL_RELEASE_DURATION)
+                .ease(Ease::Out)
+                .function(Function::Cubic),
+        )
+    });
+
+    use_side_effect(move || {
+        let value = animation.read().value();
+        if releasing.peek().is_some() {
+            offset.set(value);
+        }
+    });
+
+    Overscroll { offset, releasing }
+}
@@ -1,3 +1,6 @@
+mod kinetic;
+mod scroll_animation;
+mod scroll_snap;
 mod scrollbar;
 mod scrollthumb;
 mod scrollview;
@@ -5,6 +8,12 @@ mod shared;
 mod use_scroll_controller;
 mod virtual_scrollview;
 
+pub use kinetic::{
+    DEFAULT_DECELERATION,
+    get_momentum,
+    get_overscrolled_position,
+};
+pub use scroll_snap::*;
 pub use scrollbar::*;
 pub use scrollthumb::*;
 pub use scrollview::*;
@@ -0,0 +1,135 @@
+use std::time::Duration;
+
+use async_io::Timer;
+use freya_animation::{
+    easing::Function,
+    hook::{
+        Ease,
+        OnChange,
+        ReadAnimatedValue,
+        use_animation_with_dependencies,
+    },
+    prelude::AnimNum,
+};
+use freya_core::prelude::*;
+
+use crate::scrollviews::ScrollController;
+
+/// Time without scrolling to wait before settling.
+const SETTLE_DELAY: Duration = Duration::from_millis(150);
+const SCROLL_ANIMATION_DURATION: u64 = 250;
+
+/// Scroll positions to animate between.
+#[derive(Clone, Copy, Default, PartialEq)]
+pub(crate) struct ScrollAnimation {
+    from: (f32, f32),
+    to: (f32, f32),
+    duration: u64,
+    function: Function,
+}
+
+impl ScrollAnimation {
+    /// Short animation used for snapping and animated scroll requests.
+    pub(crate) fn new(from: (f32, f32), to: (f32, f32)) -> Self {
+        Self {
+            from,
+            to,
+            duration: SCROLL_ANIMATION_DURATION,
+            function: Function::Expo,
+        }
+    }
+
+    /// Constant deceleration over `duration` milliseconds, used for momentum scrolling.
+    pub(crate) fn decelerate(from: (f32, f32), to: (f32, f32), duration: u64) -> Self {
+        Self {
+            from,
+            to,
+            duration,
+            function: Function::Quad,
+        }
+    }
+}
+
+/// Animates the scroll position, used for snapping, momentum and animated scroll requests.
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) struct ScrollAnimator {
+    animation: State<Option<ScrollAnimation>>,
+    task: State<Option<TaskHandle>>,
+}
+
+impl ScrollAnimator {
+    pub(crate) fn animate(&mut self, animation: ScrollAnimation) {
+        self.interrupt();
+        self.animation.set(Some(animation));
+    }
+
+    /// Animate towards the positions returned by `target` unless the user keeps scrolling.
+    pub(crate) fn animate_later(
+        &mut self,
+        target: impl FnOnce() -> Option<ScrollAnimation> + 'static,
+    ) {
+        self.animate_after(SETTLE_DELAY, target);
+    }
+
+    /// Like [ScrollAnimator::animate_later] but waiting for `delay`.
+    pub(crate) fn animate_after(
+        &mut self,
+        delay: Duration,
+        target: impl FnOnce() -> Option<ScrollAnimation> + 'static,
+    ) {
+        self.interrupt();
+        let mut animation = self.animation;
+        let task = spawn(async move {
+            Timer::after(delay).await;
+            if let Some(target) = target() {
+                animation.set(Some(target));
+            }
+        });
+        self.task.set(Some(task));
+    }
+
+    /// Stop animating, the user is scrolling again.
+    pub(crate) fn interrupt(&mut self) {
+        if let Some(task) = self.task.write().take() {
+            task.cancel();
+        }
+        if self.animation.peek().is_some() {
+            self.animation.set(None);
+        }
+    }
+}
+
+pub(crate) fn use_scroll_animator(mut scroll_controller: ScrollController) -> ScrollAnimator {
+    let animation = use_state(|| None::<ScrollAnimation>);
+    let task = use_state(|| None);
+
+    let animated = use_animation_with_dependencies(&animation(), |conf, animation| {
+        conf.on_change(OnChange::Rerun);
+        let ScrollAnimation {
+            from: (from_x, from_y),
+            to: (to_x, to_y),
+            duration,
+            function,
+        } = animation.unwrap_or_default();
+        (
+            AnimNum::new(from_x, to_x)
+                .time(duration)
+                .ease(Ease::Out)
+                .function(function),
+            AnimNum::new(from_y, to_y)
+                .time(duration)
+                .ease(Ease::Out)
+                .function(function),
+        )
+    });
+
+    use_side_effect(move || {
+        let (x, y) = animated.read().value();
+        if animation.peek().is_some() {
+            scroll_controller.scroll_to_x(x as i32);
+            scroll_controller.scroll_to_y(y as i32);
+        }
+    });
+
+    ScrollAnimator { animation, task }
+}
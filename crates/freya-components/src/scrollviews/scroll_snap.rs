@@ -0,0 +1,257 @@
+use std::collections::BTreeMap;
+
+use freya_core::prelude::*;
+use torin::prelude::Area;
+
+/// How a scroll view settles on its items once the user stops scrolling.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ScrollSnap {
+    /// Stay wherever the scrolling stopped.
+    #[default]
+    None,
+    /// Always settle on the closest item.
+    Mandatory,
+    /// Only settle when the closest item is near enough.
+    Proximity,
+}
+
+/// Edge of the items aligned with the viewport when snapping.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SnapAlign {
+    #[default]
+    Start,
+    Center,
+    End,
+}
+
+/// Portion of an item it can be away from to settle with [ScrollSnap::Proximity].
+const PROXIMITY_THRESHOLD: f32 = 0.3;
+
+/// Scroll position to settle on given items of `interval` size, if any.
+#[doc(hidden)]
+pub fn get_snap_position(
+    snap: ScrollSnap,
+    align: SnapAlign,
+    interval: f32,
+    scroll_position: f32,
+    inner_size: f32,
+    viewport_size: f32,
+) -> Option<f32> {
+    if snap == ScrollSnap::None || interval <= 0. || viewport_size >= inner_size {
+        return None;
+    }
+
+    let offset = -scroll_position;
+    let shift = get_align_shift(align, interval, viewport_size);
+    let index = ((offset - shift) / interval).round();
+    let target = (index * interval + shift).clamp(0., inner_size - viewport_size);
+
+    if snap == ScrollSnap::Proximity && (target - offset).abs() > interval * PROXIMITY_THRESHOLD {
+        return None;
+    }
+
+    Some(-target)
+}
+
+/// Item to settle on, along one axis of the content of a scroll view.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SnapPoint {
+    /// Position of the item from the start of the content.
+    pub start: f32,
+    pub size: f32,
+    pub align: SnapAlign,
+}
+
+/// Scroll position to settle on given the items of the content, if any.
+#[doc(hidden)]
+pub fn get_closest_snap_position(
+    snap: ScrollSnap,
+    points: &[SnapPoint],
+    scroll_position: f32,
+    inner_size: f32,
+    viewport_size: f32,
+) -> Option<f32> {
+    if snap == ScrollSnap::None || viewport_size >= inner_size {
+        return None;
+    }
+
+    let offset = -scroll_position;
+    let (target, size) = points
+        .iter()
+        .map(|point| {
+            let shift = get_align_shift(point.align, point.size, viewport_size);
+            let target = (point.start + shift).clamp(0., inner_size - viewport_size);
+            (target, point.size)
+        })
+        .min_by(|(a, _), (b, _)| (a - offset).abs().total_cmp(&(b - offset).abs()))?;
+
+    if snap == ScrollSnap::Proximity && (target - offset).abs() > size * PROXIMITY_THRESHOLD {
+        return None;
+    }
+
+    Some(-target)
+}
+
+/// Distance from the start of an item to the scroll offset that aligns it with the viewport.
+fn get_align_shift(align: SnapAlign, size: f32, viewport_size: f32) -> f32 {
+    match align {
+        SnapAlign::Start => 0.,
+        SnapAlign::Center => (size - viewport_size) / 2.,
+        SnapAlign::End => size - viewport_size,
+    }
+}
+
+/// Layout of the [SnapItem]s inside a scroll view.
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) struct SnapItems {
+    items: State<BTreeMap<usize, (Area, Option<SnapAlign>)>>,
+    /// Offset of the content when the items were last laid out, only peeked.
+    offset: State<(f32, f32)>,
+}
+
+impl SnapItems {
+    pub(crate) fn create() -> Self {
+        Self {
+            items: State::create(BTreeMap::new()),
+            offset: State::create((0., 0.)),
+        }
+    }
+
+    /// Remember the offset the content is about to be laid out with.
+    pub(crate) fn set_offset(&mut self, offset: (f32, f32)) {
+        self.offset.set_if_modified(offset);
+    }
+
+    /// Snap points of the items along each axis, or `None` if there are no items.
+    pub(crate) fn points(
+        &self,
+        viewport: &Area,
+        align: SnapAlign,
+    ) -> Option<(Vec<SnapPoint>, Vec<SnapPoint>)> {
+        let items = self.items.peek();
+        if items.is_empty() {
+            return None;
+        }
+        let points = items
+            .values()
+            .map(|(area, item_align)| {
+                let align = item_align.unwrap_or(align);
+                (
+                    SnapPoint {
+                        start: area.min_x() - viewport.min_x(),
+                        size: area.width(),
+                        align,
+                    },
+                    SnapPoint {
+                        start: area.min_y() - viewport.min_y(),
+                        size: area.height(),
+                        align,
+                    },
+                )
+            })
+            .unzip();
+        Some(points)
+    }
+}
+
+/// Item of a [ScrollView](crate::scrollviews::ScrollView) to settle on when
+/// [scroll_snap](crate::scrollviews::ScrollView::scroll_snap) is enabled.
+///
+/// The scroll view snaps on the position and size of its snap items, so they can be of different sizes.
+/// Without any, it snaps on items of [snap_interval](crate::scrollviews::ScrollView::snap_interval) size.
+///
+/// # Example
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> impl IntoElement {
+///     ScrollView::new()
+///         .scroll_snap(ScrollSnap::Mandatory)
+///         .children((0..10).map(|i| {
+///             SnapItem::new()
+///                 .key(i)
+///                 .height(Size::px(100. + i as f32 * 20.))
+///                 .snap_align(SnapAlign::Center)
+///                 .child(format!("Item {i}"))
+///                 .into()
+///         }))
+/// }
+/// ```
+#[derive(Clone, PartialEq, Default)]
+pub struct SnapItem {
+    children: Vec<Element>,
+    layout: LayoutData,
+    snap_align: Option<SnapAlign>,
+    key: DiffKey,
+}
+
+impl ChildrenExt for SnapItem {
+    fn get_children(&mut self) -> &mut Vec<Element> {
+        &mut self.children
+    }
+}
+
+impl KeyExt for SnapItem {
+    fn write_key(&mut self) -> &mut DiffKey {
+        &mut self.key
+    }
+}
+
+impl LayoutExt for SnapItem {
+    fn get_layout(&mut self) -> &mut LayoutData {
+        &mut self.layout
+    }
+}
+
+impl ContainerExt for SnapItem {}
+
+impl SnapItem {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Edge of this item aligned with the viewport when snapping.
+    /// Defaults to the [snap_align](crate::scrollviews::ScrollView::snap_align) of the scroll view.
+    pub fn snap_align(mut self, snap_align: SnapAlign) -> Self {
+        self.snap_align = Some(snap_align);
+        self
+    }
+}
+
+impl Component for SnapItem {
+    fn render(&self) -> impl IntoElement {
+        let snap_items = use_try_consume::<SnapItems>();
+        let id = use_id::<SnapItem>();
+        let snap_align = self.snap_align;
+
+        use_drop(move || {
+            if let Some(SnapItems { mut items, .. }) = snap_items {
+                items.write().remove(&id);
+            }
+        });
+
+        let on_sized = move |e: Event<SizedEventData>| {
+            let Some(SnapItems { mut items, offset }) = snap_items else {
+                return;
+            };
+            // Keep the area the item would have without scrolling
+            let (offset_x, offset_y) = *offset.peek();
+            let mut area = e.area;
+            area.origin.x -= offset_x;
+            area.origin.y -= offset_y;
+            let item = (area, snap_align);
+            if items.peek().get(&id) != Some(&item) {
+                items.write().insert(id, item);
+            }
+        };
+
+        rect()
+            .layout(self.layout.clone())
+            .on_sized(on_sized)
+            .children(self.children.clone())
+    }
+
+    fn render_key(&self) -> DiffKey {
+        self.key.clone().or(self.default_key())
+    }
+}
@@ -13,10 +13,28 @@ use torin::{
 };
 
 use crate::scrollviews::{
+    DEFAULT_DECELERATION,
     ScrollBar,
     ScrollConfig,
     ScrollController,
+    ScrollEvent,
+    ScrollSnap,
     ScrollThumb,
+    SnapAlign,
+    get_closest_snap_position,
+    get_momentum,
+    get_overscrolled_position,
+    get_snap_position,
+    kinetic::{
+        WHEEL_RELEASE_DELAY,
+        use_overscroll,
+        use_velocity_tracker,
+    },
+    scroll_animation::{
+        ScrollAnimation,
+        use_scroll_animator,
+    },
+    scroll_snap::SnapItems,
     shared::{
         Axis,
         get_container_sizes,
@@ -67,6 +85,11 @@ pub struct ScrollView {
     scroll_controller: Option<ScrollController>,
     invert_scroll_wheel: bool,
     drag_scrolling: bool,
+    scroll_snap: ScrollSnap,
+    snap_align: SnapAlign,
+    snap_interval: Option<f32>,
+    deceleration: f32,
+    overscroll: bool,
     key: DiffKey,
 }
 
@@ -97,6 +120,11 @@ impl Default for ScrollView {
             scroll_controller: None,
             invert_scroll_wheel: false,
             drag_scrolling: cfg!(target_os = "android"),
+            scroll_snap: ScrollSnap::None,
+            snap_align: SnapAlign::Start,
+            snap_interval: None,
+            deceleration: DEFAULT_DECELERATION,
+            overscroll: true,
             key: DiffKey::None,
         }
     }
@@ -144,6 +172,38 @@ impl ScrollView {
         self
     }
 
+    /// Settle on the closest item once the user stops scrolling, like the pages of a carousel.
+    pub fn scroll_snap(mut self, scroll_snap: ScrollSnap) -> Self {
+        self.scroll_snap = scroll_snap;
+        self
+    }
+
+    /// Edge of the items aligned with the viewport when snapping.
+    pub fn snap_align(mut self, snap_align: SnapAlign) -> Self {
+        self.snap_align = snap_align;
+        self
+    }
+
+    /// Size of the items to snap on, including the spacing. Defaults to the size of the viewport.
+    /// Ignored when the content has any [SnapItem](crate::scrollviews::SnapItem).
+    pub fn snap_interval(mut self, snap_interval: impl Into<f32>) -> Self {
+        self.snap_interval = Some(snap_interval.into());
+        self
+    }
+
+    /// How fast drag and wheel scrolling slow down once released,
+    /// in pixels per squared millisecond. Use `f32::INFINITY` to stop right away.
+    pub fn deceleration(mut self, deceleration: impl Into<f32>) -> Self {
+        self.deceleration = deceleration.into();
+        self
+    }
+
+    /// Stretch the content past the edges when drag scrolling, springing back once released.
+    pub fn overscroll(mut self, overscroll: bool) -> Self {
+        self.overscroll = overscroll;
+        self
+    }
+
     pub fn max_width(mut self, max_width: impl Into<Size>) -> Self {
         self.layout.maximum_width = max_width.into();
         self
@@ -179,12 +239,165 @@ impl Component for ScrollView {
         let layout = &self.layout.layout;
         let direction = layout.direction;
         let drag_scrolling = self.drag_scrolling;
+        let mut scroll_animator = use_scroll_animator(scroll_controller);
+        let mut velocity_tracker = use_velocity_tracker();
+        let mut overscroll = use_overscroll();
+        let mut snap_items = use_provide_context(SnapItems::create);
+        let scroll_snap = self.scroll_snap;
+        let snap_align = self.snap_align;
+        let snap_interval = self.snap_interval;
+        let deceleration = self.deceleration;
+        let allow_overscroll = self.overscroll;
+
+        // Snap position closest to the given one, on the snap items if there are any
+        let snap_target = move |(x, y): (f32, f32)| {
+            let size = size.peek();
+            let (inner_width, inner_height) = (size.inner_sizes.width, size.inner_sizes.height);
+            let (viewport_width, viewport_height) = (size.area.width(), size.area.height());
+            if let Some((points_x, points_y)) = snap_items.points(&size.area, snap_align) {
+                return (
+                    get_closest_snap_position(
+                        scroll_snap,
+                        &points_x,
+                        x,
+                        inner_width,
+                        viewport_width,
+                    ),
+                    get_closest_snap_position(
+                        scroll_snap,
+                        &points_y,
+                        y,
+                        inner_height,
+                        viewport_height,
+                    ),
+                );
+            }
+            (
+                get_snap_position(
+                    scroll_snap,
+                    snap_align,
+                    snap_interval.unwrap_or(viewport_width),
+                    x,
+                    inner_width,
+                    viewport_width,
+                ),
+                get_snap_position(
+                    scroll_snap,
+                    snap_align,
+                    snap_interval.unwrap_or(viewport_height),
+                    y,
+                    inner_height,
+                    viewport_height,
+                ),
+            )
+        };
+
+        // Current scroll position, corrected to stay within the content
+        let current_position = move || {
+            let (x, y): (i32, i32) = scroll_controller.into();
+            let size = size.peek();
+            (
+                get_corrected_scroll_position(size.inner_sizes.width, size.area.width(), x as f32),
+                get_corrected_scroll_position(
+                    size.inner_sizes.height,
+                    size.area.height(),
+                    y as f32,
+                ),
+            )
+        };
+
+        // Settle on the closest snap position, if any
+        let snap_animation = move || {
+            let from = current_position();
+            let (snap_x, snap_y) = snap_target(from);
+            (snap_x.is_some() || snap_y.is_some()).then(|| {
+                ScrollAnimation::new(from, (snap_x.unwrap_or(from.0), snap_y.unwrap_or(from.1)))
+            })
+        };
+
+        let mut settle = move || {
+            if scroll_snap == ScrollSnap::None {
+                scroll_animator.interrupt();
+                return;
+            }
+            scroll_animator.animate_later(snap_animation);
+        };
 
-        scroll_controller.use_apply(
+        // Keep scrolling at the released velocity, slowing down until stopping
+        let momentum_animation = move |velocity: (f32, f32)| {
+            let ((distance_x, distance_y), duration) = get_momentum(velocity, deceleration)?;
+            let from = current_position();
+            let to = {
+                let size = size.peek();
+                (
+                    get_corrected_scroll_position(
+                        size.inner_sizes.width,
+                        size.area.width(),
+                        from.0 + distance_x,
+                    ),
+                    get_corrected_scroll_position(
+                        size.inner_sizes.height,
+                        size.area.height(),
+                        from.1 + distance_y,
+                    ),
+                )
+            };
+            // Land on the closest item when snapping
+            let (snap_x, snap_y) = snap_target(to);
+            let to = (snap_x.unwrap_or(to.0), snap_y.unwrap_or(to.1));
+            Some(ScrollAnimation::decelerate(from, to, duration))
+        };
+
+        let mut fling = move || match momentum_animation(velocity_tracker.release()) {
+            Some(animation) => scroll_animator.animate(animation),
+            None => settle(),
+        };
+
+        // The wheel has no release event, so it is released once it stops for a moment
+        let mut release_wheel = move || {
+            scroll_animator.animate_after(WHEEL_RELEASE_DELAY, move || {
+                momentum_animation(velocity_tracker.release()).or_else(snap_animation)
+            });
+        };
+
+        let mut scroll_requests = use_state(|| (None::<i32>, None::<i32>));
+        scroll_controller.use_apply_animated(
             size.read().inner_sizes.width,
             size.read().inner_sizes.height,
+            move |event| match event {
+                ScrollEvent::X(x) => scroll_requests.write().0 = Some(x),
+                ScrollEvent::Y(y) => scroll_requests.write().1 = Some(y),
+            },
         );
 
+        // Animate towards the animated scroll requests
+        use_side_effect(move || {
+            let (to_x, to_y) = *scroll_requests.read();
+            if to_x.is_none() && to_y.is_none() {
+                return;
+            }
+            scroll_requests.set((None, None));
+            let from = current_position();
+            let size = size.peek();
+            let to = (
+                to_x.map_or(from.0, |x| {
+                    get_corrected_scroll_position(
+                        size.inner_sizes.width,
+                        size.area.width(),
+                        x as f32,
+                    )
+                }),
+                to_y.map_or(from.1, |y| {
+                    get_corrected_scroll_position(
+                        size.inner_sizes.height,
+                        size.area.height(),
+                        y as f32,
+                    )
+                }),
+            );
+            scroll_animator.animate(ScrollAnimation::new(from, to));
+        });
+
         let corrected_scrolled_x = get_corrected_scroll_position(
             size.read().inner_sizes.width,
             size.read().area.width(),
@@ -196,6 +409,13 @@ impl Component for ScrollView {
             size.read().area.height(),
             scrolled_y as f32,
         );
+
+        let (overscroll_x, overscroll_y) = overscroll.get();
+        snap_items.set_offset((
+            corrected_scrolled_x + overscroll_x,
+            corrected_scrolled_y + overscroll_y,
+        ));
+
         let horizontal_scrollbar_is_visible = !timeout.elapsed()
             && is_scrollbar_visible(
                 self.show_scrollbar,
@@ -230,9 +450,14 @@ impl Component for ScrollView {
             if clicking_scrollbar.read().is_some() {
                 e.prevent_default();
                 clicking_scrollbar.set(None);
+                settle();
             }
 
             if drag_scrolling && (dragging_content().is_some() || drag_origin().is_some()) {
+                if dragging_content().is_some() {
+                    overscroll.release();
+                    fling();
+                }
                 dragging_content.set(None);
                 drag_origin.set(None);
             }
@@ -272,20 +497,57 @@ impl Component for ScrollView {
                 e.stop_propagation();
             });
             timeout.reset();
+
+            if e.source == WheelSource::Device {
+                velocity_tracker.track((
+                    scroll_position_x as f32 - corrected_scrolled_x,
+                    scroll_position_y as f32 - corrected_scrolled_y,
+                ));
+                release_wheel();
+            } else {
+                settle();
+            }
         };
 
         let on_mouse_move = move |_| {
             timeout.reset();
         };
 
+        let mut drag_content = move |delta: CursorPoint| {
+            let movement = (-delta.x as f32, -delta.y as f32);
+            velocity_tracker.track(movement);
+            if !allow_overscroll {
+                scroll_controller.scroll_to_y((corrected_scrolled_y + movement.1) as i32);
+                scroll_controller.scroll_to_x((corrected_scrolled_x + movement.0) as i32);
+                return;
+            }
+            let size = size.peek();
+            let (overscroll_x, overscroll_y) = overscroll.peek();
+            let (x, overscroll_x) = get_overscrolled_position(
+                movement.0,
+                corrected_scrolled_x,
+                overscroll_x,
+                size.inner_sizes.width,
+                size.area.width(),
+            );
+            let (y, overscroll_y) = get_overscrolled_position(
+                movement.1,
+                corrected_scrolled_y,
+                overscroll_y,
+                size.inner_sizes.height,
+                size.area.height(),
+            );
+            scroll_controller.scroll_to_y(y as i32);
+            scroll_controller.scroll_to_x(x as i32);
+            overscroll.set((overscroll_x, overscroll_y));
+        };
+
         let on_capture_global_pointer_move = move |e: Event<PointerEventData>| {
             if drag_scrolling {
                 if let Some(prev) = dragging_content() {
+                    scroll_animator.interrupt();
                     let coords = e.global_location();
-                    let delta = prev - coords;
-
-                    scroll_controller.scroll_to_y((corrected_scrolled_y - delta.y as f32) as i32);
-                    scroll_controller.scroll_to_x((corrected_scrolled_x - delta.x as f32) as i32);
+                    drag_content(prev - coords);
 
                     dragging_content.set(Some(coords));
                     e.prevent_default();
@@ -300,12 +562,7 @@ impl Component for ScrollView {
                     const DRAG_THRESHOLD: f64 = 2.0;
 
                     if distance.x > DRAG_THRESHOLD || distance.y > DRAG_THRESHOLD {
-                        let delta = origin - coords;
-
-                        scroll_controller
-                            .scroll_to_y((corrected_scrolled_y - delta.y as f32) as i32);
-                        scroll_controller
-                            .scroll_to_x((corrected_scrolled_x - delta.x as f32) as i32);
+                        drag_content(origin - coords);
 
                         dragging_content.set(Some(coords));
                         e.prevent_default();
@@ -378,6 +635,7 @@ impl Component for ScrollView {
                 scroll_controller.scroll_to_y(y as i32);
                 e.stop_propagation();
                 timeout.reset();
+                settle();
             }
         };
 
@@ -397,6 +655,8 @@ impl Component for ScrollView {
 
         let on_pointer_down = move |e: Event<PointerEventData>| {
             if drag_scrolling {
+                scroll_animator.interrupt();
+                velocity_tracker.start();
                 drag_origin.set(Some(e.global_location()));
                 focus.request_focus();
                 timeout.reset();
@@ -436,8 +696,8 @@ impl Component for ScrollView {
                             .height(content_height)
                             .max_width(layout.maximum_width.clone())
                             .max_height(layout.maximum_height.clone())
-                            .offset_x(corrected_scrolled_x)
-                            .offset_y(corrected_scrolled_y)
+                            .offset_x(corrected_scrolled_x + overscroll_x)
+                            .offset_y(corrected_scrolled_y + overscroll_y)
                             .spacing(layout.spacing.get())
                             .overflow(Overflow::Clip)
                             .on_sized(move |e: Event<SizedEventData>| {
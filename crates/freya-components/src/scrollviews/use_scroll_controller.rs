@@ -6,6 +6,8 @@ pub enum ScrollPosition {
     #[default]
     Start,
     End,
+    /// Scroll offset, like the ones given to [ScrollController::scroll_to_y].
+    Offset(i32),
 }
 
 #[derive(Default)]
@@ -18,6 +20,7 @@ pub struct ScrollRequest {
     pub(crate) position: ScrollPosition,
     pub(crate) direction: Direction,
     pub(crate) init: bool,
+    pub(crate) animated: bool,
 }
 
 impl ScrollRequest {
@@ -26,8 +29,15 @@ impl ScrollRequest {
             position,
             direction,
             init: false,
+            animated: false,
         }
     }
+
+    /// Animate towards the position instead of jumping to it.
+    pub fn animated(mut self) -> ScrollRequest {
+        self.animated = true;
+        self
+    }
 }
 
 pub enum ScrollEvent {
@@ -85,23 +95,33 @@ impl ScrollController {
     }
 
     pub fn use_apply(&mut self, width: f32, height: f32) {
+        let mut on_scroll = self.on_scroll;
+        self.use_apply_animated(width, height, move |event| {
+            on_scroll.write().call(event);
+        });
+    }
+
+    /// Like [ScrollController::use_apply], but the offsets of the animated requests are given to
+    /// `animate` for the scroll view to animate towards them, instead of jumping to them.
+    pub fn use_apply_animated(
+        &mut self,
+        width: f32,
+        height: f32,
+        mut animate: impl FnMut(ScrollEvent),
+    ) {
         let _ = self.notifier.read();
         for request in self.requests.write().drain(..) {
-            match request {
+            let event = match request {
                 ScrollRequest {
                     position: ScrollPosition::Start,
                     direction: Direction::Vertical,
                     ..
-                } => {
-                    self.on_scroll.write().call(ScrollEvent::Y(0));
-                }
+                } => ScrollEvent::Y(0),
                 ScrollRequest {
                     position: ScrollPosition::Start,
                     direction: Direction::Horizontal,
                     ..
-                } => {
-                    self.on_scroll.write().call(ScrollEvent::X(0));
-                }
+                } => ScrollEvent::X(0),
                 ScrollRequest {
                     position: ScrollPosition::End,
                     direction: Direction::Vertical,
@@ -112,9 +132,7 @@ impl ScrollController {
                         continue;
                     }
                     let (_x, y) = self.get_scroll.read().call(());
-                    self.on_scroll
-                        .write()
-                        .call(ScrollEvent::Y(y - height as i32));
+                    ScrollEvent::Y(y - height as i32)
                 }
                 ScrollRequest {
                     position: ScrollPosition::End,
@@ -127,10 +145,24 @@ impl ScrollController {
                     }
 
                     let (x, _y) = self.get_scroll.read().call(());
-                    self.on_scroll
-                        .write()
-                        .call(ScrollEvent::X(x - width as i32));
+                    ScrollEvent::X(x - width as i32)
                 }
+                ScrollRequest {
+                    position: ScrollPosition::Offset(offset),
+                    direction: Direction::Vertical,
+                    ..
+                } => ScrollEvent::Y(offset),
+                ScrollRequest {
+                    position: ScrollPosition::Offset(offset),
+                    direction: Direction::Horizontal,
+                    ..
+                } => ScrollEvent::X(offset),
+            };
+
+            if request.animated {
+                animate(event);
+            } else {
+                self.on_scroll.write().call(event);
             }
         }
     }
@@ -144,9 +176,20 @@ impl ScrollController {
     }
 
     pub fn scroll_to(&mut self, scroll_position: ScrollPosition, scroll_direction: Direction) {
-        self.requests
-            .write()
-            .push(ScrollRequest::new(scroll_position, scroll_direction));
+        self.request(ScrollRequest::new(scroll_position, scroll_direction));
+    }
+
+    /// Like [ScrollController::scroll_to] but smoothly animating towards the position.
+    pub fn scroll_to_animated(
+        &mut self,
+        scroll_position: ScrollPosition,
+        scroll_direction: Direction,
+    ) {
+        self.request(ScrollRequest::new(scroll_position, scroll_direction).animated());
+    }
+
+    pub fn request(&mut self, request: ScrollRequest) {
+        self.requests.write().push(request);
         self.notifier.write();
     }
 }
@@ -163,11 +206,13 @@ pub fn use_scroll_controller(init: impl FnOnce() -> ScrollConfig) -> ScrollContr
                     position: config.default_vertical_position,
                     direction: Direction::Vertical,
                     init: true,
+                    animated: false,
                 },
                 ScrollRequest {
                     position: config.default_horizontal_position,
                     direction: Direction::Horizontal,
                     init: true,
+                    animated: false,
                 },
             ],
         )
@@ -1,4 +1,5 @@
 use std::{
+    collections::BTreeMap,
     ops::Range,
     time::Duration,
 };
@@ -13,10 +14,28 @@ use torin::{
 };
 
 use crate::scrollviews::{
+    DEFAULT_DECELERATION,
     ScrollBar,
     ScrollConfig,
     ScrollController,
+    ScrollEvent,
+    ScrollSnap,
     ScrollThumb,
+    SnapAlign,
+    SnapPoint,
+    get_closest_snap_position,
+    get_momentum,
+    get_overscrolled_position,
+    get_snap_position,
+    kinetic::{
+        WHEEL_RELEASE_DELAY,
+        use_overscroll,
+        use_velocity_tracker,
+    },
+    scroll_animation::{
+        ScrollAnimation,
+        use_scroll_animator,
+    },
     shared::{
         Axis,
         get_container_sizes,
@@ -61,6 +80,9 @@ use crate::scrollviews::{
 /// # });
 /// ```
 ///
+/// Items of different sizes, like the messages of a chat, can be measured as they render with
+/// [VirtualScrollView::dynamic_item_size], the `item_size` is then used as their estimated size.
+///
 /// # Preview
 /// ![VirtualScrollView Preview][virtual_scrollview]
 #[cfg_attr(feature = "docs",
@@ -71,6 +93,7 @@ pub struct VirtualScrollView<D, B: Fn(usize, &D) -> Element> {
     builder: B,
     builder_data: D,
     item_size: f32,
+    dynamic_item_size: bool,
     length: usize,
     layout: LayoutData,
     show_scrollbar: bool,
@@ -78,6 +101,11 @@ pub struct VirtualScrollView<D, B: Fn(usize, &D) -> Element> {
     scroll_controller: Option<ScrollController>,
     invert_scroll_wheel: bool,
     drag_scrolling: bool,
+    scroll_snap: ScrollSnap,
+    snap_align: SnapAlign,
+    snap_interval: Option<f32>,
+    deceleration: f32,
+    overscroll: bool,
     key: DiffKey,
 }
 
@@ -99,12 +127,18 @@ impl<D: PartialEq, B: Fn(usize, &D) -> Element> PartialEq for VirtualScrollView<
     fn eq(&self, other: &Self) -> bool {
         self.builder_data == other.builder_data
             && self.item_size == other.item_size
+            && self.dynamic_item_size == other.dynamic_item_size
             && self.length == other.length
             && self.layout == other.layout
             && self.show_scrollbar == other.show_scrollbar
             && self.scroll_with_arrows == other.scroll_with_arrows
             && self.scroll_controller == other.scroll_controller
             && self.invert_scroll_wheel == other.invert_scroll_wheel
+            && self.scroll_snap == other.scroll_snap
+            && self.snap_align == other.snap_align
+            && self.snap_interval == other.snap_interval
+            && self.deceleration == other.deceleration
+            && self.overscroll == other.overscroll
     }
 }
 
@@ -114,6 +148,7 @@ impl<B: Fn(usize, &()) -> Element> VirtualScrollView<(), B> {
             builder,
             builder_data: (),
             item_size: 0.,
+            dynamic_item_size: false,
             length: 0,
             layout: {
                 let mut l = LayoutData::default();
@@ -126,6 +161,11 @@ impl<B: Fn(usize, &()) -> Element> VirtualScrollView<(), B> {
             scroll_controller: None,
             invert_scroll_wheel: false,
             drag_scrolling: cfg!(target_os = "android"),
+            scroll_snap: ScrollSnap::None,
+            snap_align: SnapAlign::Start,
+            snap_interval: None,
+            deceleration: DEFAULT_DECELERATION,
+            overscroll: true,
             key: DiffKey::None,
         }
     }
@@ -135,6 +175,7 @@ impl<B: Fn(usize, &()) -> Element> VirtualScrollView<(), B> {
             builder,
             builder_data: (),
             item_size: 0.,
+            dynamic_item_size: false,
             length: 0,
             layout: {
                 let mut l = LayoutData::default();
@@ -147,6 +188,11 @@ impl<B: Fn(usize, &()) -> Element> VirtualScrollView<(), B> {
             scroll_controller: Some(scroll_controller),
             invert_scroll_wheel: false,
             drag_scrolling: cfg!(target_os = "android"),
+            scroll_snap: ScrollSnap::None,
+            snap_align: SnapAlign::Start,
+            snap_interval: None,
+            deceleration: DEFAULT_DECELERATION,
+            overscroll: true,
             key: DiffKey::None,
         }
     }
@@ -158,6 +204,7 @@ impl<D, B: Fn(usize, &D) -> Element> VirtualScrollView<D, B> {
             builder,
             builder_data,
             item_size: 0.,
+            dynamic_item_size: false,
             length: 0,
             layout: Node {
                 width: Size::fill(),
@@ -170,6 +217,11 @@ impl<D, B: Fn(usize, &D) -> Element> VirtualScrollView<D, B> {
             scroll_controller: None,
             invert_scroll_wheel: false,
             drag_scrolling: cfg!(target_os = "android"),
+            scroll_snap: ScrollSnap::None,
+            snap_align: SnapAlign::Start,
+            snap_interval: None,
+            deceleration: DEFAULT_DECELERATION,
+            overscroll: true,
             key: DiffKey::None,
         }
     }
@@ -183,6 +235,7 @@ impl<D, B: Fn(usize, &D) -> Element> VirtualScrollView<D, B> {
             builder,
             builder_data,
             item_size: 0.,
+            dynamic_item_size: false,
             length: 0,
 
             layout: Node {
@@ -196,6 +249,11 @@ impl<D, B: Fn(usize, &D) -> Element> VirtualScrollView<D, B> {
             scroll_controller: Some(scroll_controller),
             invert_scroll_wheel: false,
             drag_scrolling: cfg!(target_os = "android"),
+            scroll_snap: ScrollSnap::None,
+            snap_align: SnapAlign::Start,
+            snap_interval: None,
+            deceleration: DEFAULT_DECELERATION,
+            overscroll: true,
             key: DiffKey::None,
         }
     }
@@ -220,6 +278,15 @@ impl<D, B: Fn(usize, &D) -> Element> VirtualScrollView<D, B> {
         self
     }
 
+    /// Measure the items as they render instead of assuming they all have the same size.
+    /// The `item_size` is then used as the estimated size of the items not rendered yet.
+    ///
+    /// Give the items a key so the measurements of replaced items are discarded.
+    pub fn dynamic_item_size(mut self, dynamic_item_size: impl Into<bool>) -> Self {
+        self.dynamic_item_size = dynamic_item_size.into();
+        self
+    }
+
     pub fn length(mut self, length: impl Into<usize>) -> Self {
         self.length = length.into();
         self
@@ -235,6 +302,38 @@ impl<D, B: Fn(usize, &D) -> Element> VirtualScrollView<D, B> {
         self
     }
 
+    /// Settle on the closest item once the user stops scrolling, like the pages of a carousel.
+    pub fn scroll_snap(mut self, scroll_snap: ScrollSnap) -> Self {
+        self.scroll_snap = scroll_snap;
+        self
+    }
+
+    /// Edge of the items aligned with the viewport when snapping.
+    pub fn snap_align(mut self, snap_align: SnapAlign) -> Self {
+        self.snap_align = snap_align;
+        self
+    }
+
+    /// Size of the items to snap on. Defaults to the `item_size`, or to the measured size of
+    /// every item with [VirtualScrollView::dynamic_item_size].
+    pub fn snap_interval(mut self, snap_interval: impl Into<f32>) -> Self {
+        self.snap_interval = Some(snap_interval.into());
+        self
+    }
+
+    /// How fast drag and wheel scrolling slow down once released,
+    /// in pixels per squared millisecond. Use `f32::INFINITY` to stop right away.
+    pub fn deceleration(mut self, deceleration: impl Into<f32>) -> Self {
+        self.deceleration = deceleration.into();
+        self
+    }
+
+    /// Stretch the content past the edges when drag scrolling, springing back once released.
+    pub fn overscroll(mut self, overscroll: bool) -> Self {
+        self.overscroll = overscroll;
+        self
+    }
+
     pub fn scroll_controller(
         mut self,
         scroll_controller: impl Into<Option<ScrollController>>,
@@ -267,24 +366,37 @@ impl<D: PartialEq + 'static, B: Fn(usize, &D) -> Element + 'static> Component
             .scroll_controller
             .unwrap_or_else(|| use_scroll_controller(ScrollConfig::default));
         let mut dragging_content = use_state::<Option<CursorPoint>>(|| None);
+        let mut measured_sizes = use_state(BTreeMap::<usize, MeasuredItem>::new);
         let mut drag_origin = use_state::<Option<CursorPoint>>(|| None);
         let (scrolled_x, scrolled_y) = scroll_controller.into();
         let layout = &self.layout.layout;
         let direction = layout.direction;
         let drag_scrolling = self.drag_scrolling;
+        let dynamic_item_size = self.dynamic_item_size;
+
+        // The measured items might not be the same anymore
+        use_side_effect_with_deps(&self.length, move |_| {
+            if !measured_sizes.peek().is_empty() {
+                measured_sizes.write().clear();
+            }
+        });
+
+        let content_size = if dynamic_item_size {
+            get_dynamic_content_size(&measured_sizes.read(), self.item_size, self.length)
+        } else {
+            self.item_size * self.length as f32
+        };
 
         let (inner_width, inner_height) = match direction {
-            Direction::Vertical => (
-                size.read().inner_sizes.width,
-                self.item_size * self.length as f32,
-            ),
-            Direction::Horizontal => (
-                self.item_size * self.length as f32,
-                size.read().inner_sizes.height,
-            ),
+            Direction::Vertical => (size.read().inner_sizes.width, content_size),
+            Direction::Horizontal => (content_size, size.read().inner_sizes.height),
         };
 
-        scroll_controller.use_apply(inner_width, inner_height);
+        let mut scroll_requests = use_state(|| (None::<i32>, None::<i32>));
+        scroll_controller.use_apply_animated(inner_width, inner_height, move |event| match event {
+            ScrollEvent::X(x) => scroll_requests.write().0 = Some(x),
+            ScrollEvent::Y(y) => scroll_requests.write().1 = Some(y),
+        });
 
         let corrected_scrolled_x =
             get_corrected_scroll_position(inner_width, size.read().area.width(), scrolled_x as f32);
@@ -310,6 +422,171 @@ impl<D: PartialEq + 'static, B: Fn(usize, &D) -> Element + 'static> Component
         let (container_width, content_width) = get_container_sizes(self.layout.width.clone());
         let (container_height, content_height) = get_container_sizes(self.layout.height.clone());
 
+        let mut scroll_animator = use_scroll_animator(scroll_controller);
+        let mut velocity_tracker = use_velocity_tracker();
+        let mut overscroll = use_overscroll();
+        let scroll_snap = self.scroll_snap;
+        let snap_align = self.snap_align;
+        let snap_interval = self
+            .snap_interval
+            .or((!dynamic_item_size).then_some(self.item_size));
+        let deceleration = self.deceleration;
+        let allow_overscroll = self.overscroll;
+
+        // Animate towards the animated scroll requests
+        use_side_effect_with_deps(
+            &(inner_width, inner_height),
+            move |&(inner_width, inner_height)| {
+                let (to_x, to_y) = *scroll_requests.read();
+                if to_x.is_none() && to_y.is_none() {
+                    return;
+                }
+                scroll_requests.set((None, None));
+                let (x, y): (i32, i32) = scroll_controller.into();
+                let size = size.peek();
+                let (viewport_width, viewport_height) = (size.area.width(), size.area.height());
+                let from = (
+                    get_corrected_scroll_position(inner_width, viewport_width, x as f32),
+                    get_corrected_scroll_position(inner_height, viewport_height, y as f32),
+                );
+                let to = (
+                    to_x.map_or(from.0, |x| {
+                        get_corrected_scroll_position(inner_width, viewport_width, x as f32)
+                    }),
+                    to_y.map_or(from.1, |y| {
+                        get_corrected_scroll_position(inner_height, viewport_height, y as f32)
+                    }),
+                );
+                scroll_animator.animate(ScrollAnimation::new(from, to));
+            },
+        );
+
+        let (overscroll_x, overscroll_y) = overscroll.get();
+
+        // Current scroll position, corrected to stay within the content
+        let current_position = move || {
+            let (x, y): (i32, i32) = scroll_controller.into();
+            let size = size.peek();
+            (
+                get_corrected_scroll_position(inner_width, size.area.width(), x as f32),
+                get_corrected_scroll_position(inner_height, size.area.height(), y as f32),
+            )
+        };
+
+        // Snap position closest to the given one, only along the main axis
+        let item_size = self.item_size;
+        let item_length = self.length;
+        let snap_target = move |(x, y): (f32, f32)| {
+            let size = size.peek();
+            let snap_position = |scroll_position: f32, inner_size: f32, viewport_size: f32| {
+                match snap_interval {
+                    Some(interval) => get_snap_position(
+                        scroll_snap,
+                        snap_align,
+                        interval,
+                        scroll_position,
+                        inner_size,
+                        viewport_size,
+                    ),
+                    // Items of different sizes snap on their measured sizes
+                    None => get_closest_snap_position(
+                        scroll_snap,
+                        &get_dynamic_snap_points(
+                            &measured_sizes.peek(),
+                            viewport_size,
+                            scroll_position,
+                            item_size,
+                            item_length,
+                            snap_align,
+                        ),
+                        scroll_position,
+                        inner_size,
+                        viewport_size,
+                    ),
+                }
+            };
+            match direction {
+                Direction::Vertical => (None, snap_position(y, inner_height, size.area.height())),
+                Direction::Horizontal => (snap_position(x, inner_width, size.area.width()), None),
+            }
+        };
+
+        // Settle on the closest snap position, if any
+        let snap_animation = move || {
+            let from = current_position();
+            let (snap_x, snap_y) = snap_target(from);
+            (snap_x.is_some() || snap_y.is_some()).then(|| {
+                ScrollAnimation::new(from, (snap_x.unwrap_or(from.0), snap_y.unwrap_or(from.1)))
+            })
+        };
+
+        let mut settle = move || {
+            if scroll_snap == ScrollSnap::None {
+                scroll_animator.interrupt();
+                return;
+            }
+            scroll_animator.animate_later(snap_animation);
+        };
+
+        // Keep scrolling at the released velocity, slowing down until stopping
+        let momentum_animation = move |velocity: (f32, f32)| {
+            let ((distance_x, distance_y), duration) = get_momentum(velocity, deceleration)?;
+            let from = current_position();
+            let (viewport_width, viewport_height) = {
+                let size = size.peek();
+                (size.area.width(), size.area.height())
+            };
+            let to = (
+                get_corrected_scroll_position(inner_width, viewport_width, from.0 + distance_x),
+                get_corrected_scroll_position(inner_height, viewport_height, from.1 + distance_y),
+            );
+            // Land on the closest item when snapping
+            let (snap_x, snap_y) = snap_target(to);
+            let to = (snap_x.unwrap_or(to.0), snap_y.unwrap_or(to.1));
+            Some(ScrollAnimation::decelerate(from, to, duration))
+        };
+
+        let mut fling = move || match momentum_animation(velocity_tracker.release()) {
+            Some(animation) => scroll_animator.animate(animation),
+            None => settle(),
+        };
+
+        // The wheel has no release event, so it is released once it stops for a moment
+        let mut release_wheel = move || {
+            scroll_animator.animate_after(WHEEL_RELEASE_DELAY, move || {
+                momentum_animation(velocity_tracker.release()).or_else(snap_animation)
+            });
+        };
+
+        let mut drag_content = move |delta: CursorPoint| {
+            let movement = (-delta.x as f32, -delta.y as f32);
+            velocity_tracker.track(movement);
+            if !allow_overscroll {
+                scroll_controller.scroll_to_y((corrected_scrolled_y + movement.1) as i32);
+                scroll_controller.scroll_to_x((corrected_scrolled_x + movement.0) as i32);
+                return;
+            }
+            let size = size.peek();
+            let (overscroll_x, overscroll_y) = overscroll.peek();
+            let (x, overscroll_x) = get_overscrolled_position(
+                movement.0,
+                corrected_scrolled_x,
+                overscroll_x,
+                inner_width,
+                size.area.width(),
+            );
+            let (y, overscroll_y) = get_overscrolled_position(
+                movement.1,
+                corrected_scrolled_y,
+                overscroll_y,
+                inner_height,
+                size.area.height(),
+            );
+            scroll_controller.scroll_to_y(y as i32);
+            scroll_controller.scroll_to_x(x as i32);
+            overscroll.set((overscroll_x, overscroll_y));
+        };
+
         let scroll_with_arrows = self.scroll_with_arrows;
         let invert_scroll_wheel = self.invert_scroll_wheel;
 
@@ -317,9 +594,14 @@ impl<D: PartialEq + 'static, B: Fn(usize, &D) -> Element + 'static> Component
             if clicking_scrollbar.read().is_some() {
                 e.prevent_default();
                 clicking_scrollbar.set(None);
+                settle();
             }
 
             if drag_scrolling && (dragging_content().is_some() || drag_origin().is_some()) {
+                if dragging_content().is_some() {
+                    overscroll.release();
+                    fling();
+                }
                 dragging_content.set(None);
                 drag_origin.set(None);
             }
@@ -359,6 +641,16 @@ impl<D: PartialEq + 'static, B: Fn(usize, &D) -> Element + 'static> Component
                 e.stop_propagation();
             });
             timeout.reset();
+
+            if e.source == WheelSource::Device {
+                velocity_tracker.track((
+                    scroll_position_x as f32 - corrected_scrolled_x,
+                    scroll_position_y as f32 - corrected_scrolled_y,
+                ));
+                release_wheel();
+            } else {
+                settle();
+            }
         };
 
         let on_mouse_move = move |_| {
@@ -368,11 +660,9 @@ impl<D: PartialEq + 'static, B: Fn(usize, &D) -> Element + 'static> Component
         let on_capture_global_pointer_move = move |e: Event<PointerEventData>| {
             if drag_scrolling {
                 if let Some(prev) = dragging_content() {
+                    scroll_animator.interrupt();
                     let coords = e.global_location();
-                    let delta = prev - coords;
-
-                    scroll_controller.scroll_to_y((corrected_scrolled_y - delta.y as f32) as i32);
-                    scroll_controller.scroll_to_x((corrected_scrolled_x - delta.x as f32) as i32);
+                    drag_content(prev - coords);
 
                     dragging_content.set(Some(coords));
                     e.prevent_default();
@@ -387,12 +677,7 @@ impl<D: PartialEq + 'static, B: Fn(usize, &D) -> Element + 'static> Component
                     const DRAG_THRESHOLD: f64 = 2.0;
 
                     if distance.x > DRAG_THRESHOLD || distance.y > DRAG_THRESHOLD {
-                        let delta = origin - coords;
-
-                        scroll_controller
-                            .scroll_to_y((corrected_scrolled_y - delta.y as f32) as i32);
-                        scroll_controller
-                            .scroll_to_x((corrected_scrolled_x - delta.x as f32) as i32);
+                        drag_content(origin - coords);
 
                         dragging_content.set(Some(coords));
                         e.prevent_default();
@@ -465,6 +750,7 @@ impl<D: PartialEq + 'static, B: Fn(usize, &D) -> Element + 'static> Component
                 scroll_controller.scroll_to_y(y as i32);
                 e.stop_propagation();
                 timeout.reset();
+                settle();
             }
         };
 
@@ -488,18 +774,95 @@ impl<D: PartialEq + 'static, B: Fn(usize, &D) -> Element + 'static> Component
             (size.read().area.width(), corrected_scrolled_x)
         };
 
-        let render_range = get_render_range(
-            viewport_size,
-            scroll_position,
-            self.item_size,
-            self.length as f32,
-        );
+        let (render_range, render_offset) = if dynamic_item_size {
+            get_dynamic_render_range(
+                &measured_sizes.read(),
+                viewport_size,
+                scroll_position,
+                self.item_size,
+                self.length,
+            )
+        } else {
+            let render_range = get_render_range(
+                viewport_size,
+                scroll_position,
+                self.item_size,
+                self.length as f32,
+            );
+            (render_range, 0.)
+        };
 
+        let position = -scroll_position;
+        let mut item_offset = render_offset;
         let children = render_range
-            .map(|i| (self.builder)(i, &self.builder_data))
+            .map(|i| {
+                let child = (self.builder)(i, &self.builder_data);
+                if !dynamic_item_size {
+                    return child;
+                }
+                let (Element::Element { key, .. } | Element::Component { key, .. }) = &child;
+                let key = key.clone();
+
+                // A different item is now in this position, so the following ones might have changed too
+                if measured_sizes
+                    .peek()
+                    .get(&i)
+                    .is_some_and(|measured| measured.key != key)
+                {
+                    measured_sizes.write().split_off(&i);
+                }
+
+                let estimated_size = measured_sizes
+                    .peek()
+                    .get(&i)
+                    .map_or(self.item_size, |measured| measured.size);
+                let starts_above = item_offset < position;
+                item_offset += estimated_size;
+
+                // Measure the item so the following ones are placed correctly
+                let on_sized = move |e: Event<SizedEventData>| {
+                    let item_size = match direction {
+                        Direction::Vertical => e.area.height(),
+                        Direction::Horizontal => e.area.width(),
+                    };
+                    let previous_size = measured_sizes
+                        .peek()
+                        .get(&i)
+                        .map_or(estimated_size, |measured| measured.size);
+                    let measured = MeasuredItem {
+                        key: key.clone(),
+                        size: item_size,
+                    };
+                    if measured_sizes.peek().get(&i) == Some(&measured) {
+                        return;
+                    }
+                    measured_sizes.write().insert(i, measured);
+
+                    // Keep the visible items in place when an item starting above them changes its size
+                    let difference = (item_size - previous_size).round() as i32;
+                    if starts_above && difference != 0 {
+                        let (x, y): (i32, i32) = scroll_controller.into();
+                        match direction {
+                            Direction::Vertical => scroll_controller.scroll_to_y(y - difference),
+                            Direction::Horizontal => scroll_controller.scroll_to_x(x - difference),
+                        };
+                    }
+                };
+                let item = match direction {
+                    Direction::Vertical => rect().width(Size::fill()),
+                    Direction::Horizontal => rect().height(Size::fill()),
+                };
+                item.key(i).on_sized(on_sized).child(child).into()
+            })
             .collect::<Vec<Element>>();
 
         let (offset_x, offset_y) = match direction {
+            Direction::Vertical if dynamic_item_size => {
+                (corrected_scrolled_x, corrected_scrolled_y + render_offset)
+            }
+            Direction::Horizontal if dynamic_item_size => {
+                (corrected_scrolled_x + render_offset, corrected_scrolled_y)
+            }
             Direction::Vertical => {
                 let offset_y_min =
                     (-corrected_scrolled_y / self.item_size).floor() * self.item_size;
@@ -518,6 +881,8 @@ impl<D: PartialEq + 'static, B: Fn(usize, &D) -> Element + 'static> Component
 
         let on_pointer_down = move |e: Event<PointerEventData>| {
             if drag_scrolling {
+                scroll_animator.interrupt();
+                velocity_tracker.start();
                 drag_origin.set(Some(e.global_location()));
                 focus.request_focus();
                 timeout.reset();
@@ -553,8 +918,8 @@ impl<D: PartialEq + 'static, B: Fn(usize, &D) -> Element + 'static> Component
                             .direction(direction)
                             .width(content_width)
                             .height(content_height)
-                            .offset_x(offset_x)
-                            .offset_y(offset_y)
+                            .offset_x(offset_x + overscroll_x)
+                            .offset_y(offset_y + overscroll_y)
                             .overflow(Overflow::Clip)
                             .on_sized(move |e: Event<SizedEventData>| {
                                 size.set_if_modified(e.clone())
@@ -617,3 +982,114 @@ fn get_render_range(
 
     render_index_start as usize..(render_index_end as usize)
 }
+
+/// Size of an item measured with [VirtualScrollView::dynamic_item_size].
+#[derive(Clone, PartialEq)]
+struct MeasuredItem {
+    /// Key of the element that was measured.
+    key: DiffKey,
+    size: f32,
+}
+
+/// Total size of the items, using the estimated size for those not measured yet.
+fn get_dynamic_content_size(
+    measured_sizes: &BTreeMap<usize, MeasuredItem>,
+    estimated_size: f32,
+    item_length: usize,
+) -> f32 {
+    let measured = measured_sizes.range(..item_length);
+    let measured_count = measured.clone().count();
+    let measured_size = measured.map(|(_, measured)| measured.size).sum::<f32>();
+    measured_size + (item_length - measured_count) as f32 * estimated_size
+}
+
+/// Range of the visible items and the offset where the first one starts.
+fn get_dynamic_render_range(
+    measured_sizes: &BTreeMap<usize, MeasuredItem>,
+    viewport_size: f32,
+    scroll_position: f32,
+    estimated_size: f32,
+    item_length: usize,
+) -> (Range<usize>, f32) {
+    if item_length == 0 || estimated_size <= 0. {
+        return (0..0, 0.);
+    }
+    let position = -scroll_position;
+
+    // Find the first visible item, jumping over the items not measured yet
+    let mut start = None;
+    let mut index = 0;
+    let mut offset = 0.;
+    for (&measured_index, &MeasuredItem { size, .. }) in measured_sizes.range(..item_length) {
+        let gap = (measured_index - index) as f32 * estimated_size;
+        if position < offset + gap {
+            break;
+        }
+        offset += gap;
+        index = measured_index;
+        if position < offset + size {
+            start = Some((index, offset));
+            break;
+        }
+        offset += size;
+        index += 1;
+    }
+    let (start, start_offset) = start.unwrap_or_else(|| {
+        let skipped = (((position - offset) / estimated_size).floor().max(0.) as usize)
+            .min(item_length.saturating_sub(index + 1));
+        (index + skipped, offset + skipped as f32 * estimated_size)
+    });
+
+    // Render items until the viewport is covered
+    let mut end = start;
+    let mut end_offset = start_offset;
+    while end < item_length && end_offset < position + viewport_size {
+        end_offset += measured_sizes
+            .get(&end)
+            .map_or(estimated_size, |measured| measured.size);
+        end += 1;
+    }
+
+    (start..end, start_offset)
+}
+
+/// Snap points of the items around the given scroll position, measured or estimated.
+fn get_dynamic_snap_points(
+    measured_sizes: &BTreeMap<usize, MeasuredItem>,
+    viewport_size: f32,
+    scroll_position: f32,
+    estimated_size: f32,
+    item_length: usize,
+    align: SnapAlign,
+) -> Vec<SnapPoint> {
+    let (range, start_offset) = get_dynamic_render_range(
+        measured_sizes,
+        viewport_size,
+        scroll_position,
+        estimated_size,
+        item_length,
+    );
+    let item_size = |index: usize| {
+        measured_sizes
+            .get(&index)
+            .map_or(estimated_size, |measured| measured.size)
+    };
+
+    let mut points = Vec::new();
+    // Also snap on the items right before and after the visible ones
+    if let Some(previous) = range.start.checked_sub(1) {
+        let size = item_size(previous);
+        points.push(SnapPoint {
+            start: start_offset - size,
+            size,
+            align,
+        });
+    }
+    let mut start = start_offset;
+    for index in range.start..(range.end + 1).min(item_length) {
+        let size = item_size(index);
+        points.push(SnapPoint { start, size, align });
+        start += size;
+    }
+    points
+}
@@ -1,3 +1,4 @@
+use accesskit::Action;
 use freya_core::prelude::*;
 use torin::prelude::*;
 
@@ -140,6 +141,15 @@ impl Component for Slider {
             }
         };
 
+        let on_a11y_action = {
+            let on_moved = self.on_moved.clone();
+            move |e: Event<AccessibilityActionEventData>| match e.action {
+                Action::Increment => on_moved.call((value + 4.0).clamp(0.0, 100.0)),
+                Action::Decrement => on_moved.call((value - 4.0).clamp(0.0, 100.0)),
+                _ => {}
+            }
+        };
+
         let on_pointer_enter = move |_| {
             hovering.set(true);
             if enabled() {
@@ -271,6 +281,9 @@ impl Component for Slider {
             .on_sized(move |e: Event<SizedEventData>| size.set(e.area))
             .maybe(self.enabled, |rect| {
                 rect.on_key_down(on_key_down)
+                    .a11y_action(Action::Increment)
+                    .a11y_action(Action::Decrement)
+                    .on_a11y_action(on_a11y_action)
                     .on_pointer_down(on_pointer_down)
                     .on_global_pointer_move(on_global_pointer_move)
                     .on_global_pointer_press(on_global_pointer_press)
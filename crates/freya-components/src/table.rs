@@ -20,6 +20,7 @@ define_theme! {
         arrow_fill: Color,
         hover_row_background: Color,
         row_background: Color,
+        selected_row_background: Color,
         divider_fill: Color,
         corner_radius: CornerRadius,
         color: Color,
@@ -0,0 +1,379 @@
+use std::borrow::Cow;
+
+use freya_core::prelude::*;
+use torin::{
+    gaps::Gaps,
+    prelude::{
+        Alignment,
+        Direction,
+    },
+    size::Size,
+};
+
+use crate::{
+    define_theme,
+    drag_drop::{
+        DragZone,
+        DropZone,
+    },
+    get_theme,
+    scrollviews::ScrollView,
+};
+
+define_theme! {
+    %[component]
+    pub Tabs {
+        %[fields]
+        background: Color,
+        hover_background: Color,
+        active_background: Color,
+        indicator_fill: Color,
+        border_fill: Color,
+        color: Color,
+        padding: Gaps,
+    }
+}
+
+/// Tab of a [Tabs] strip.
+#[derive(Clone, PartialEq)]
+pub struct Tab {
+    title: Cow<'static, str>,
+    closable: bool,
+}
+
+impl Tab {
+    pub fn new(title: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            title: title.into(),
+            closable: true,
+        }
+    }
+
+    /// Show a button to close this tab, calling [Tabs::on_close]. Defaults to `true`.
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+}
+
+/// Content of a [Tab], only the one of the selected tab is rendered.
+#[derive(Clone, PartialEq, Default)]
+pub struct TabPanel {
+    children: Vec<Element>,
+    key: DiffKey,
+}
+
+impl TabPanel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ChildrenExt for TabPanel {
+    fn get_children(&mut self) -> &mut Vec<Element> {
+        &mut self.children
+    }
+}
+
+impl KeyExt for TabPanel {
+    fn write_key(&mut self) -> &mut DiffKey {
+        &mut self.key
+    }
+}
+
+impl Component for TabPanel {
+    fn render(&self) -> impl IntoElement {
+        rect()
+            .a11y_role(AccessibilityRole::TabPanel)
+            .expanded()
+            .children(self.children.clone())
+    }
+
+    fn render_key(&self) -> DiffKey {
+        self.key.clone().or(self.default_key())
+    }
+}
+
+/// Strip of tabs and the [TabPanel] of the selected one.
+///
+/// The tabs are controlled by the app: [Tabs::on_select], [Tabs::on_close] and
+/// [Tabs::on_reorder] report what the user did. Tabs can be reordered by dragging them and
+/// navigated with the arrow keys, `Home` and `End` once the strip is focused.
+///
+/// # Example
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> impl IntoElement {
+///     let mut selected = use_state(|| 0);
+///
+///     Tabs::new()
+///         .selected(selected())
+///         .on_select(move |tab| selected.set(tab))
+///         .tab(Tab::new("Home").closable(false))
+///         .tab(Tab::new("Settings"))
+///         .child(TabPanel::new().child("Welcome!"))
+///         .child(TabPanel::new().child("Nothing to set yet"))
+/// }
+/// ```
+#[derive(Clone, PartialEq, Default)]
+pub struct Tabs {
+    pub(crate) theme: Option<TabsThemePartial>,
+    tabs: Vec<Tab>,
+    selected: usize,
+    on_select: Option<EventHandler<usize>>,
+    on_close: Option<EventHandler<usize>>,
+    on_reorder: Option<EventHandler<(usize, usize)>>,
+    children: Vec<Element>,
+    key: DiffKey,
+}
+
+impl Tabs {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn tab(mut self, tab: Tab) -> Self {
+        self.tabs.push(tab);
+        self
+    }
+
+    pub fn tabs(mut self, tabs: impl IntoIterator<Item = Tab>) -> Self {
+        self.tabs.extend(tabs);
+        self
+    }
+
+    /// Index of the selected tab.
+    pub fn selected(mut self, selected: usize) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    pub fn on_select(mut self, on_select: impl Into<EventHandler<usize>>) -> Self {
+        self.on_select = Some(on_select.into());
+        self
+    }
+
+    pub fn on_close(mut self, on_close: impl Into<EventHandler<usize>>) -> Self {
+        self.on_close = Some(on_close.into());
+        self
+    }
+
+    /// Called with the `(from, to)` indices of a tab dropped over another one.
+    pub fn on_reorder(mut self, on_reorder: impl Into<EventHandler<(usize, usize)>>) -> Self {
+        self.on_reorder = Some(on_reorder.into());
+        self
+    }
+
+    pub fn theme(mut self, theme: TabsThemePartial) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+}
+
+impl ChildrenExt for Tabs {
+    fn get_children(&mut self) -> &mut Vec<Element> {
+        &mut self.children
+    }
+}
+
+impl KeyExt for Tabs {
+    fn write_key(&mut self) -> &mut DiffKey {
+        &mut self.key
+    }
+}
+
+/// Index of the tab being dragged, distinct from other `usize` drags of the app.
+#[derive(Clone, Copy, PartialEq)]
+struct DraggedTab(usize);
+
+impl Component for Tabs {
+    fn render(&self) -> impl IntoElement {
+        let theme = get_theme!(&self.theme, TabsThemePreference, "tabs");
+        let focus = use_focus();
+        let focus_status = use_focus_status(focus);
+
+        let tabs_len = self.tabs.len();
+        let selected = self.selected;
+
+        let on_key_down = {
+            let on_select = self.on_select.clone();
+            let on_close = self.on_close.clone();
+            let closable = self.tabs.get(selected).is_some_and(|tab| tab.closable);
+            move |e: Event<KeyboardEventData>| {
+                if tabs_len == 0 {
+                    return;
+                }
+                let new_selected = match e.key {
+                    Key::Named(NamedKey::ArrowRight) => (selected + 1) % tabs_len,
+                    Key::Named(NamedKey::ArrowLeft) => (selected + tabs_len - 1) % tabs_len,
+                    Key::Named(NamedKey::Home) => 0,
+                    Key::Named(NamedKey::End) => tabs_len - 1,
+                    Key::Named(NamedKey::Delete) if closable => {
+                        if let Some(on_close) = &on_close {
+                            on_close.call(selected);
+                        }
+                        e.stop_propagation();
+                        return;
+                    }
+                    _ => return,
+                };
+                e.stop_propagation();
+                if let Some(on_select) = &on_select {
+                    on_select.call(new_selected);
+                }
+            }
+        };
+
+        let strip = ScrollView::new()
+            .direction(Direction::Horizontal)
+            .height(Size::auto())
+            .show_scrollbar(false)
+            .scroll_with_arrows(false)
+            .invert_scroll_wheel(true)
+            .children(self.tabs.iter().enumerate().map(|(index, tab)| {
+                let on_press = {
+                    let on_select = self.on_select.clone();
+                    move |_: Event<PressEventData>| {
+                        focus.request_focus();
+                        if let Some(on_select) = &on_select {
+                            on_select.call(index);
+                        }
+                    }
+                };
+                let on_close = self.on_close.clone().map(|on_close| {
+                    EventHandler::from(move |e: Event<PressEventData>| {
+                        e.stop_propagation();
+                        on_close.call(index);
+                    })
+                });
+                let on_drop = {
+                    let on_reorder = self.on_reorder.clone();
+                    move |DraggedTab(from): DraggedTab| {
+                        if let Some(on_reorder) = &on_reorder
+                            && from != index
+                        {
+                            on_reorder.call((from, index));
+                        }
+                    }
+                };
+                let button = TabButton {
+                    title: tab.title.clone(),
+                    active: index == selected,
+                    focused: index == selected && focus_status() == FocusStatus::Keyboard,
+                    on_press: on_press.into(),
+                    on_close: on_close.filter(|_| tab.closable),
+                    theme: theme.clone(),
+                };
+                DropZone::new(DragZone::new(DraggedTab(index), button), on_drop)
+                    .key(index)
+                    .into()
+            }));
+
+        rect()
+            .expanded()
+            .color(theme.color)
+            .child(
+                rect()
+                    .a11y_id(focus.a11y_id())
+                    .a11y_role(AccessibilityRole::TabList)
+                    .a11y_focusable(true)
+                    .width(Size::fill())
+                    .background(theme.background)
+                    .border(
+                        Border::new()
+                            .fill(theme.border_fill)
+                            .width(BorderWidth {
+                                bottom: 1.,
+                                ..BorderWidth::default()
+                            })
+                            .alignment(BorderAlignment::Inner),
+                    )
+                    .on_key_down(on_key_down)
+                    .child(strip),
+            )
+            .maybe_child(self.children.get(selected).cloned())
+    }
+
+    fn render_key(&self) -> DiffKey {
+        self.key.clone().or(self.default_key())
+    }
+}
+
+#[derive(PartialEq)]
+struct TabButton {
+    title: Cow<'static, str>,
+    active: bool,
+    focused: bool,
+    on_press: EventHandler<Event<PressEventData>>,
+    on_close: Option<EventHandler<Event<PressEventData>>>,
+    theme: TabsTheme,
+}
+
+impl Component for TabButton {
+    fn render(&self) -> impl IntoElement {
+        let mut hovering = use_state(|| false);
+        let TabsTheme {
+            background,
+            hover_background,
+            active_background,
+            indicator_fill,
+            padding,
+            ..
+        } = self.theme.clone();
+
+        let background = if self.active || self.focused {
+            active_background
+        } else if hovering() {
+            hover_background
+        } else {
+            background
+        };
+        let indicator = if self.active {
+            indicator_fill
+        } else {
+            Color::TRANSPARENT
+        };
+        let on_press = self.on_press.clone();
+        let active = self.active;
+
+        rect()
+            .a11y_role(AccessibilityRole::Tab)
+            .a11y_builder(|node| node.set_selected(active))
+            .horizontal()
+            .cross_align(Alignment::Center)
+            .spacing(6.)
+            .padding(padding)
+            .background(background)
+            .border(
+                Border::new()
+                    .fill(indicator)
+                    .width(BorderWidth {
+                        bottom: 2.,
+                        ..BorderWidth::default()
+                    })
+                    .alignment(BorderAlignment::Inner),
+            )
+            .on_pointer_enter(move |_| {
+                Cursor::set(CursorIcon::Pointer);
+                hovering.set(true);
+            })
+            .on_pointer_leave(move |_| {
+                Cursor::set(CursorIcon::default());
+                hovering.set(false);
+            })
+            .on_press(move |e| on_press.call(e))
+            .child(label().text(self.title.clone()).max_lines(1))
+            .maybe_child(self.on_close.clone().map(|on_close| {
+                rect()
+                    .a11y_role(AccessibilityRole::Button)
+                    .a11y_alt("Close")
+                    .width(Size::px(16.))
+                    .height(Size::px(16.))
+                    .center()
+                    .corner_radius(4.)
+                    .on_press(move |e| on_close.call(e))
+                    .child(label().text("×"))
+            }))
+    }
+}
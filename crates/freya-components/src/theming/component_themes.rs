@@ -1,6 +1,7 @@
 use std::{
     any::Any,
     fmt,
+    rc::Rc,
 };
 
 use freya_core::{
@@ -10,10 +11,12 @@ use freya_core::{
 
 use crate::theming::themes::light_theme;
 
+#[derive(Clone)]
 pub struct Theme {
     pub name: &'static str,
     pub colors: ColorsSheet,
-    themes: FxHashMap<&'static str, Box<dyn Any>>,
+    pub tokens: DesignTokens,
+    themes: FxHashMap<&'static str, Rc<dyn Any>>,
 }
 
 impl Theme {
@@ -21,10 +24,17 @@ impl Theme {
         Self {
             name,
             colors,
+            tokens: DesignTokens::default(),
             themes: FxHashMap::default(),
         }
     }
 
+    /// Replace the [DesignTokens] of this theme.
+    pub fn with_tokens(mut self, tokens: DesignTokens) -> Self {
+        self.tokens = tokens;
+        self
+    }
+
     /// Get a component theme by key.
     pub fn get<T: 'static>(&self, key: &str) -> Option<&T> {
         self.themes.get(key).and_then(|v| v.downcast_ref())
@@ -32,7 +42,7 @@ impl Theme {
 
     /// Set a component theme by key.
     pub fn set<T: 'static>(&mut self, key: &'static str, val: T) {
-        self.themes.insert(key, Box::new(val));
+        self.themes.insert(key, Rc::new(val));
     }
 }
 
@@ -41,6 +51,7 @@ impl fmt::Debug for Theme {
         f.debug_struct("Theme")
             .field("name", &self.name)
             .field("colors", &self.colors)
+            .field("tokens", &self.tokens)
             .field("themes", &format!("({} entries)", self.themes.len()))
             .finish()
     }
@@ -48,7 +59,7 @@ impl fmt::Debug for Theme {
 
 impl PartialEq for Theme {
     fn eq(&self, other: &Self) -> bool {
-        self.name == other.name && self.colors == other.colors
+        self.name == other.name && self.colors == other.colors && self.tokens == other.tokens
     }
 }
 
@@ -58,6 +69,80 @@ impl Default for Theme {
     }
 }
 
+/// Named values that component themes can reference with
+/// [Preference::Reference](crate::theming::macros::Preference::Reference),
+/// next to the [ColorsSheet].
+///
+/// Spacing tokens resolve sizes, gaps and numbers, radius tokens resolve corner radiuses
+/// and palette tokens resolve colors that are not in the [ColorsSheet].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DesignTokens {
+    spacing: FxHashMap<&'static str, f32>,
+    radius: FxHashMap<&'static str, CornerRadius>,
+    palette: FxHashMap<&'static str, Color>,
+}
+
+impl DesignTokens {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn spacing(mut self, name: &'static str, value: f32) -> Self {
+        self.spacing.insert(name, value);
+        self
+    }
+
+    pub fn radius(mut self, name: &'static str, value: impl Into<CornerRadius>) -> Self {
+        self.radius.insert(name, value.into());
+        self
+    }
+
+    pub fn color(mut self, name: &'static str, value: impl Into<Color>) -> Self {
+        self.palette.insert(name, value.into());
+        self
+    }
+
+    pub fn get_spacing(&self, name: &str) -> Option<f32> {
+        self.spacing.get(name).copied()
+    }
+
+    pub fn get_radius(&self, name: &str) -> Option<CornerRadius> {
+        self.radius.get(name).copied()
+    }
+
+    pub fn get_color(&self, name: &str) -> Option<Color> {
+        self.palette.get(name).copied()
+    }
+
+    pub fn spacing_mut(&mut self, name: &str) -> Option<&mut f32> {
+        self.spacing.get_mut(name)
+    }
+
+    pub fn radius_mut(&mut self, name: &str) -> Option<&mut CornerRadius> {
+        self.radius.get_mut(name)
+    }
+
+    pub fn color_mut(&mut self, name: &str) -> Option<&mut Color> {
+        self.palette.get_mut(name)
+    }
+
+    /// Spacing of the given token, or `0` if there is no such token.
+    pub(crate) fn resolve_spacing(&self, name: &str) -> f32 {
+        self.get_spacing(name).unwrap_or_else(|| {
+            tracing::warn!("Spacing token not found: {name}");
+            0.
+        })
+    }
+
+    /// Radius of the given token, or no radius if there is no such token.
+    pub(crate) fn resolve_radius(&self, name: &str) -> CornerRadius {
+        self.get_radius(name).unwrap_or_else(|| {
+            tracing::warn!("Radius token not found: {name}");
+            CornerRadius::default()
+        })
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ColorsSheet {
     // Brand & Accent
@@ -102,3 +187,40 @@ pub struct ColorsSheet {
     pub overlay: Color,
     pub shadow: Color,
 }
+
+impl ColorsSheet {
+    /// Get a color of the sheet by its field name, like `primary` or `text_secondary`.
+    pub fn color_mut(&mut self, name: &str) -> Option<&mut Color> {
+        Some(match name {
+            "primary" => &mut self.primary,
+            "secondary" => &mut self.secondary,
+            "tertiary" => &mut self.tertiary,
+            "success" => &mut self.success,
+            "warning" => &mut self.warning,
+            "error" => &mut self.error,
+            "info" => &mut self.info,
+            "background" => &mut self.background,
+            "surface_primary" => &mut self.surface_primary,
+            "surface_secondary" => &mut self.surface_secondary,
+            "surface_tertiary" => &mut self.surface_tertiary,
+            "surface_inverse" => &mut self.surface_inverse,
+            "surface_inverse_secondary" => &mut self.surface_inverse_secondary,
+            "surface_inverse_tertiary" => &mut self.surface_inverse_tertiary,
+            "border" => &mut self.border,
+            "border_focus" => &mut self.border_focus,
+            "border_disabled" => &mut self.border_disabled,
+            "text_primary" => &mut self.text_primary,
+            "text_secondary" => &mut self.text_secondary,
+            "text_placeholder" => &mut self.text_placeholder,
+            "text_inverse" => &mut self.text_inverse,
+            "text_highlight" => &mut self.text_highlight,
+            "hover" => &mut self.hover,
+            "focus" => &mut self.focus,
+            "active" => &mut self.active,
+            "disabled" => &mut self.disabled,
+            "overlay" => &mut self.overlay,
+            "shadow" => &mut self.shadow,
+            _ => return None,
+        })
+    }
+}
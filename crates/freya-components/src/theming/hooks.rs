@@ -1,3 +1,16 @@
+#[cfg(feature = "theme-hot-reload")]
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+#[cfg(feature = "theme-hot-reload")]
+use freya_core::prelude::{
+    OwnedTaskHandle,
+    spawn,
+    use_side_effect_with_deps,
+    use_state,
+};
 use freya_core::{
     prelude::{
         Readable,
@@ -13,6 +26,8 @@ use freya_core::{
 };
 
 use crate::theming::component_themes::Theme;
+#[cfg(feature = "theme-hot-reload")]
+use crate::theming::overrides::ThemeOverrides;
 
 /// Provides a custom [`Theme`].
 /// If a [`Theme`] context already exists, it reuses it instead of creating a new one.
@@ -48,6 +63,89 @@ pub fn use_theme() -> State<Theme> {
     use_consume::<State<Theme>>()
 }
 
+/// Apply a [`ThemeOverrides`] file on top of the inherited [`Theme`] and apply it again every time
+/// the file changes, so colors and tokens can be tweaked without restarting the app.
+///
+/// The file is watched in the background and watched again when `path` changes. Only watches the file in debug builds,
+/// the theme is left untouched in release builds.
+#[cfg(feature = "theme-hot-reload")]
+pub fn use_theme_hot_reload(path: impl Into<PathBuf>) {
+    let path = path.into();
+    let theme = use_hook(try_consume_context::<State<Theme>>);
+    let mut watcher = use_state(|| None::<OwnedTaskHandle>);
+
+    use_side_effect_with_deps(&path, move |path| {
+        if !cfg!(debug_assertions) {
+            return;
+        }
+        let Some(theme) = theme else {
+            tracing::warn!("There is no Theme to hot reload.");
+            return;
+        };
+        // Replacing the previous task stops watching the previous file
+        watcher.set(Some(
+            spawn(watch_theme_overrides(theme, path.clone())).owned(),
+        ));
+    });
+}
+
+#[cfg(feature = "theme-hot-reload")]
+async fn watch_theme_overrides(mut theme: State<Theme>, path: PathBuf) {
+    use futures_lite::StreamExt;
+    use notify::Watcher;
+
+    let (changed_sender, mut changed) = futures_channel::mpsc::unbounded::<()>();
+    let file_name = path.file_name().map(ToOwned::to_owned);
+    let watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        if let Ok(event) = event
+            && event
+                .paths
+                .iter()
+                .any(|changed| changed.file_name() == file_name.as_deref())
+        {
+            let _ = changed_sender.unbounded_send(());
+        }
+    });
+    // Editors usually replace the file instead of writing to it, so watch its directory
+    let directory = path
+        .parent()
+        .filter(|directory| !directory.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    let _watcher = match watcher.and_then(|mut watcher| {
+        watcher.watch(directory, notify::RecursiveMode::NonRecursive)?;
+        Ok(watcher)
+    }) {
+        Ok(watcher) => watcher,
+        Err(err) => {
+            tracing::warn!("Failed to watch the theme in {path:?}: {err}");
+            return;
+        }
+    };
+
+    let mut restore = ThemeOverrides::default();
+    loop {
+        let overrides = match std::fs::read_to_string(&path) {
+            Ok(text) => ThemeOverrides::parse(&text),
+            // Removing the file goes back to the original theme
+            Err(_) => Ok(ThemeOverrides::default()),
+        };
+        match overrides {
+            Ok(overrides) => {
+                let mut theme = theme.write();
+                restore.apply(&mut *theme);
+                restore = overrides.apply(&mut *theme);
+            }
+            Err(err) => tracing::warn!("Failed to reload the theme from {path:?}: {err}"),
+        }
+
+        if changed.next().await.is_none() {
+            break;
+        }
+        // Saving a file usually fires a few events at once
+        while changed.try_recv().is_ok() {}
+    }
+}
+
 /// Subscribe to [`Theme`] changes, default theme will be used if there is no provided [`Theme`].
 ///
 /// Primarily used by built-in components that have no control of whether they will inherit a [`Theme`] or not.
@@ -6,7 +6,7 @@ use torin::{
     size::Size,
 };
 
-use crate::theming::component_themes::ColorsSheet;
+use crate::theming::component_themes::Theme;
 
 #[macro_export]
 macro_rules! define_theme {
@@ -70,12 +70,12 @@ macro_rules! define_theme {
                 }
 
                 #[doc = "Checks each field in `optional` and if it's `Some`, it overwrites the corresponding `self` field."]
-                pub fn resolve(&mut self, colors_sheet: &$crate::theming::component_themes::ColorsSheet) -> [<$name Theme>] {
+                pub fn resolve(&mut self, theme: &$crate::theming::component_themes::Theme) -> [<$name Theme>] {
                     use $crate::theming::macros::ResolvablePreference;
                     [<$name Theme>] {
                         $(
                             $(
-                                $field_name: self.$field_name.resolve(colors_sheet),
+                                $field_name: self.$field_name.resolve(theme),
                             )*
                         )?
                     }
@@ -170,12 +170,12 @@ macro_rules! define_theme {
                 }
 
                 #[doc = "Checks each field in `optional` and if it's `Some`, it overwrites the corresponding `self` field."]
-                pub fn resolve(&mut self, colors_sheet: &$crate::theming::component_themes::ColorsSheet) -> [<$name Theme>] {
+                pub fn resolve(&mut self, theme: &$crate::theming::component_themes::Theme) -> [<$name Theme>] {
                     use $crate::theming::macros::ResolvablePreference;
                     [<$name Theme>] {
                         $(
                             $(
-                                $field_name: self.$field_name.resolve(colors_sheet),
+                                $field_name: self.$field_name.resolve(theme),
                             )*
                         )?
                     }
@@ -230,7 +230,7 @@ macro_rules! get_theme {
             requested_theme.apply_optional(&theme_override);
         }
 
-        requested_theme.resolve(&theme.colors)
+        requested_theme.resolve(&theme)
     }};
 }
 
@@ -247,11 +247,12 @@ impl<T> From<T> for Preference<T> {
 }
 
 pub trait ResolvablePreference<T: Clone> {
-    fn resolve(&self, colors_sheet: &ColorsSheet) -> T;
+    fn resolve(&self, theme: &Theme) -> T;
 }
 
 impl ResolvablePreference<Color> for Preference<Color> {
-    fn resolve(&self, colors_sheet: &ColorsSheet) -> Color {
+    fn resolve(&self, theme: &Theme) -> Color {
+        let colors_sheet = &theme.colors;
         match self {
             Self::Reference(reference) => match *reference {
                 // Brand & Accent
@@ -296,8 +297,11 @@ impl ResolvablePreference<Color> for Preference<Color> {
                 "overlay" => colors_sheet.overlay,
                 "shadow" => colors_sheet.shadow,
 
-                // Fallback
-                _ => colors_sheet.primary,
+                // Palette tokens, or else the primary color
+                reference => theme
+                    .tokens
+                    .get_color(reference)
+                    .unwrap_or(colors_sheet.primary),
             },
 
             Self::Specific(value) => *value,
@@ -306,44 +310,36 @@ impl ResolvablePreference<Color> for Preference<Color> {
 }
 
 impl ResolvablePreference<Size> for Preference<Size> {
-    fn resolve(&self, _colors_sheet: &ColorsSheet) -> Size {
+    fn resolve(&self, theme: &Theme) -> Size {
         match self {
-            Self::Reference(_) => {
-                panic!("Only Colors support references.")
-            }
+            Self::Reference(reference) => Size::px(theme.tokens.resolve_spacing(reference)),
             Self::Specific(value) => value.clone(),
         }
     }
 }
 
 impl ResolvablePreference<Gaps> for Preference<Gaps> {
-    fn resolve(&self, _colors_sheet: &ColorsSheet) -> Gaps {
+    fn resolve(&self, theme: &Theme) -> Gaps {
         match self {
-            Self::Reference(_) => {
-                panic!("Only Colors support references.")
-            }
+            Self::Reference(reference) => Gaps::new_all(theme.tokens.resolve_spacing(reference)),
             Self::Specific(value) => *value,
         }
     }
 }
 
 impl ResolvablePreference<CornerRadius> for Preference<CornerRadius> {
-    fn resolve(&self, _colors_sheet: &ColorsSheet) -> CornerRadius {
+    fn resolve(&self, theme: &Theme) -> CornerRadius {
         match self {
-            Self::Reference(_) => {
-                panic!("Only Colors support references.")
-            }
+            Self::Reference(reference) => theme.tokens.resolve_radius(reference),
             Self::Specific(value) => *value,
         }
     }
 }
 
 impl ResolvablePreference<f32> for Preference<f32> {
-    fn resolve(&self, _colors_sheet: &ColorsSheet) -> f32 {
+    fn resolve(&self, theme: &Theme) -> f32 {
         match self {
-            Self::Reference(_) => {
-                panic!("Only Colors support references.")
-            }
+            Self::Reference(reference) => theme.tokens.resolve_spacing(reference),
             Self::Specific(value) => *value,
         }
     }
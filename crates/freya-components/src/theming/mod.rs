@@ -2,4 +2,6 @@ pub mod component_themes;
 pub mod extensions;
 pub mod hooks;
 pub mod macros;
+pub mod overrides;
+pub mod provider;
 pub mod themes;
@@ -0,0 +1,119 @@
+use freya_core::prelude::*;
+use thiserror::Error;
+
+use crate::theming::component_themes::Theme;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ThemeOverridesError {
+    #[error("Line {0} is not a `section.name = value` pair")]
+    InvalidLine(usize),
+    #[error("Line {0} has an invalid value")]
+    InvalidValue(usize),
+    #[error("Line {0} overrides an unknown section")]
+    UnknownSection(usize),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum ThemeOverride {
+    Color(String, Color),
+    Spacing(String, f32),
+    Radius(String, CornerRadius),
+    Palette(String, Color),
+}
+
+/// Changes to apply on top of a [Theme], parsed from a file with one override per line:
+///
+/// ```text
+/// // Comments start with two slashes
+/// colors.primary = #ff5722
+/// spacing.md = 12
+/// radius.md = 8
+/// palette.brand = #0ea5e9
+/// ```
+///
+/// `colors` are fields of the [ColorsSheet](crate::theming::component_themes::ColorsSheet),
+/// `spacing`, `radius` and `palette` are tokens already defined in the
+/// [DesignTokens](crate::theming::component_themes::DesignTokens) of the theme.
+/// See `use_theme_hot_reload`, enabled by the `theme-hot-reload` feature.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ThemeOverrides {
+    overrides: Vec<ThemeOverride>,
+}
+
+impl ThemeOverrides {
+    pub fn parse(text: &str) -> Result<Self, ThemeOverridesError> {
+        let mut overrides = Vec::new();
+        for (i, line) in text.lines().enumerate() {
+            let line_number = i + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with("//") {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or(ThemeOverridesError::InvalidLine(line_number))?;
+            let (section, name) = key
+                .trim()
+                .split_once('.')
+                .ok_or(ThemeOverridesError::InvalidLine(line_number))?;
+            let name = name.to_string();
+            let value = value.trim();
+
+            let color =
+                || Color::from_hex(value).ok_or(ThemeOverridesError::InvalidValue(line_number));
+            let number = || {
+                value
+                    .parse::<f32>()
+                    .map_err(|_| ThemeOverridesError::InvalidValue(line_number))
+            };
+
+            overrides.push(match section {
+                "colors" => ThemeOverride::Color(name, color()?),
+                "spacing" => ThemeOverride::Spacing(name, number()?),
+                "radius" => ThemeOverride::Radius(name, CornerRadius::new_all(number()?)),
+                "palette" => ThemeOverride::Palette(name, color()?),
+                _ => return Err(ThemeOverridesError::UnknownSection(line_number)),
+            });
+        }
+        Ok(Self { overrides })
+    }
+
+    /// Apply the overrides to the given theme, overrides of unknown colors or tokens are ignored.
+    ///
+    /// Returns the overrides that restore the previous values of the theme.
+    pub fn apply(&self, theme: &mut Theme) -> ThemeOverrides {
+        let mut restore = Vec::new();
+        for theme_override in &self.overrides {
+            match theme_override {
+                ThemeOverride::Color(name, color) => {
+                    if let Some(target) = theme.colors.color_mut(name) {
+                        restore.push(ThemeOverride::Color(name.clone(), *target));
+                        *target = *color;
+                    }
+                }
+                ThemeOverride::Spacing(name, spacing) => {
+                    if let Some(target) = theme.tokens.spacing_mut(name) {
+                        restore.push(ThemeOverride::Spacing(name.clone(), *target));
+                        *target = *spacing;
+                    }
+                }
+                ThemeOverride::Radius(name, radius) => {
+                    if let Some(target) = theme.tokens.radius_mut(name) {
+                        restore.push(ThemeOverride::Radius(name.clone(), *target));
+                        *target = *radius;
+                    }
+                }
+                ThemeOverride::Palette(name, color) => {
+                    if let Some(target) = theme.tokens.color_mut(name) {
+                        restore.push(ThemeOverride::Palette(name.clone(), *target));
+                        *target = *color;
+                    }
+                }
+            }
+        }
+        // Restore in reverse so values overridden twice end up as they were originally
+        restore.reverse();
+        ThemeOverrides { overrides: restore }
+    }
+}
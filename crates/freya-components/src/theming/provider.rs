@@ -0,0 +1,98 @@
+use freya_core::prelude::*;
+
+use crate::theming::{
+    component_themes::Theme,
+    themes::{
+        dark_theme,
+        light_theme,
+    },
+};
+
+/// Provide a [Theme] to the components inside of it, overriding the inherited one.
+///
+/// By default it follows the [PreferredTheme] of the platform, switching between the built-in
+/// light and dark themes as it changes. Changing the provided theme rerenders the components
+/// that use it.
+///
+/// # Example
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> impl IntoElement {
+///     let tokens = DesignTokens::new().spacing("md", 12.).radius("md", 8.);
+///
+///     ThemeProvider::new(
+///         rect()
+///             .child(Button::new().child("Follows the platform"))
+///             .child(
+///                 ThemeProvider::new(Button::new().child("Always dark"))
+///                     .theme(dark_theme().with_tokens(tokens)),
+///             ),
+///     )
+/// }
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct ThemeProvider {
+    light: Theme,
+    dark: Option<Theme>,
+    child: Element,
+    key: DiffKey,
+}
+
+impl KeyExt for ThemeProvider {
+    fn write_key(&mut self) -> &mut DiffKey {
+        &mut self.key
+    }
+}
+
+impl ThemeProvider {
+    pub fn new(child: impl IntoElement) -> Self {
+        Self {
+            light: light_theme(),
+            dark: Some(dark_theme()),
+            child: child.into_element(),
+            key: DiffKey::None,
+        }
+    }
+
+    /// Always use the given theme, whatever the platform prefers.
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.light = theme;
+        self.dark = None;
+        self
+    }
+
+    /// Theme used while the platform prefers a light theme.
+    pub fn light_theme(mut self, theme: Theme) -> Self {
+        self.light = theme;
+        self
+    }
+
+    /// Theme used while the platform prefers a dark theme.
+    pub fn dark_theme(mut self, theme: Theme) -> Self {
+        self.dark = Some(theme);
+        self
+    }
+}
+
+impl Component for ThemeProvider {
+    fn render(&self) -> impl IntoElement {
+        let platform = use_hook(Platform::get);
+        let theme = match (&self.dark, *platform.preferred_theme.read()) {
+            (Some(dark), PreferredTheme::Dark) => dark,
+            _ => &self.light,
+        };
+
+        let mut state = use_provide_context(|| State::create(theme.clone()));
+
+        if *state.peek() != *theme {
+            state.set(theme.clone());
+        }
+
+        self.child.clone()
+    }
+
+    fn render_key(&self) -> DiffKey {
+        self.key.clone().or(self.default_key())
+    }
+}
@@ -54,6 +54,7 @@ use crate::{
         SwitchLayoutThemePreference,
     },
     table::TableThemePreference,
+    tabs::TabsThemePreference,
     theming::{
         component_themes::{
             ColorsSheet,
@@ -61,7 +62,9 @@ use crate::{
         },
         macros::Preference,
     },
+    toast::ToastThemePreference,
     tooltip::TooltipThemePreference,
+    tree_view::TreeViewThemePreference,
 };
 
 pub const LIGHT_COLORS: ColorsSheet = ColorsSheet {
@@ -493,11 +496,48 @@ fn register_base_component_themes(theme: &mut Theme) {
             arrow_fill: Preference::Reference("text_primary"),
             row_background: Preference::Specific(Color::TRANSPARENT),
             hover_row_background: Preference::Reference("surface_secondary"),
+            selected_row_background: Preference::Reference("secondary"),
             divider_fill: Preference::Reference("surface_primary"),
             corner_radius: Preference::Specific(CornerRadius::new_all(6.)),
             color: Preference::Reference("text_primary"),
         },
     );
+    theme.set(
+        "tabs",
+        TabsThemePreference {
+            background: Preference::Reference("surface_tertiary"),
+            hover_background: Preference::Reference("hover"),
+            active_background: Preference::Reference("background"),
+            indicator_fill: Preference::Reference("primary"),
+            border_fill: Preference::Reference("border"),
+            color: Preference::Reference("text_primary"),
+            padding: Preference::Specific(Gaps::new(8., 12., 8., 12.)),
+        },
+    );
+    theme.set(
+        "toast",
+        ToastThemePreference {
+            background: Preference::Reference("background"),
+            color: Preference::Reference("text_primary"),
+            description_color: Preference::Reference("text_secondary"),
+            border_fill: Preference::Reference("border"),
+            shadow: Preference::Reference("shadow"),
+            corner_radius: Preference::Specific(CornerRadius::new_all(8.)),
+            padding: Preference::Specific(Gaps::new(10., 14., 10., 14.)),
+            width: Preference::Specific(Size::px(300.)),
+        },
+    );
+    theme.set(
+        "tree_view",
+        TreeViewThemePreference {
+            color: Preference::Reference("text_primary"),
+            hover_background: Preference::Reference("hover"),
+            active_background: Preference::Reference("secondary"),
+            arrow_fill: Preference::Reference("text_primary"),
+            indent: Preference::Specific(16.),
+            row_height: Preference::Specific(28.),
+        },
+    );
     #[cfg(feature = "markdown")]
     theme.set(
         "markdown_viewer",
@@ -0,0 +1,404 @@
+use std::{
+    borrow::Cow,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use accesskit::Live;
+use async_io::Timer;
+use freya_animation::prelude::*;
+use freya_core::prelude::*;
+use torin::{
+    gaps::Gaps,
+    prelude::{
+        Alignment,
+        Position,
+    },
+    size::Size,
+};
+
+use crate::{
+    define_theme,
+    get_theme,
+};
+
+define_theme! {
+    %[component]
+    pub Toast {
+        %[fields]
+        background: Color,
+        color: Color,
+        description_color: Color,
+        border_fill: Color,
+        shadow: Color,
+        corner_radius: CornerRadius,
+        padding: Gaps,
+        width: Size,
+    }
+}
+
+/// Corner of the window where a [ToastProvider] stacks its toasts.
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum ToastPosition {
+    TopLeft,
+    TopCenter,
+    TopRight,
+    BottomLeft,
+    BottomCenter,
+    #[default]
+    BottomRight,
+}
+
+impl ToastPosition {
+    fn alignments(&self) -> (Alignment, Alignment) {
+        let main = match self {
+            Self::TopLeft | Self::TopCenter | Self::TopRight => Alignment::Start,
+            Self::BottomLeft | Self::BottomCenter | Self::BottomRight => Alignment::End,
+        };
+        let cross = match self {
+            Self::TopLeft | Self::BottomLeft => Alignment::Start,
+            Self::TopCenter | Self::BottomCenter => Alignment::Center,
+            Self::TopRight | Self::BottomRight => Alignment::End,
+        };
+        (main, cross)
+    }
+}
+
+/// Notification shown with [ToastManager::show].
+#[derive(Clone, PartialEq, Debug)]
+pub struct Toast {
+    title: Cow<'static, str>,
+    description: Option<Cow<'static, str>>,
+    duration: Option<Duration>,
+}
+
+impl Toast {
+    pub fn new(title: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            title: title.into(),
+            description: None,
+            duration: Some(Duration::from_secs(4)),
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<Cow<'static, str>>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Time the toast stays visible. Defaults to 4 seconds.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Keep the toast until it is pressed or dismissed with [ToastManager::dismiss].
+    pub fn persistent(mut self) -> Self {
+        self.duration = None;
+        self
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ToastId(u64);
+
+#[derive(Clone, PartialEq)]
+struct ToastEntry {
+    id: ToastId,
+    toast: Toast,
+    closing: bool,
+}
+
+/// Queue of toasts provided by a [ToastProvider], get it with [use_toast].
+#[derive(Clone, Copy, PartialEq)]
+pub struct ToastManager {
+    entries: State<Vec<ToastEntry>>,
+    next_id: State<u64>,
+}
+
+impl ToastManager {
+    /// Queue a toast, it is shown as soon as there is room in the stack.
+    pub fn show(&self, toast: Toast) -> ToastId {
+        let mut next_id = self.next_id;
+        let id = ToastId(next_id());
+        *next_id.write() += 1;
+        let mut entries = self.entries;
+        entries.write().push(ToastEntry {
+            id,
+            toast,
+            closing: false,
+        });
+        id
+    }
+
+    /// Animate the toast out and remove it.
+    pub fn dismiss(&self, id: ToastId) {
+        let mut entries = self.entries;
+        let mut entries = entries.write();
+        if let Some(entry) = entries.iter_mut().find(|entry| entry.id == id) {
+            entry.closing = true;
+        }
+    }
+
+    pub fn dismiss_all(&self) {
+        let mut entries = self.entries;
+        for entry in entries.write().iter_mut() {
+            entry.closing = true;
+        }
+    }
+
+    /// Number of toasts, including the queued ones.
+    pub fn len(&self) -> usize {
+        self.entries.read().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn remove(&self, id: ToastId) {
+        let mut entries = self.entries;
+        entries.write().retain(|entry| entry.id != id);
+    }
+}
+
+/// Get the [ToastManager] of the closest [ToastProvider].
+pub fn use_toast() -> ToastManager {
+    use_consume::<ToastManager>()
+}
+
+/// Stacks the toasts shown with [use_toast] over its children.
+///
+/// Toasts dismiss themselves after their [Toast::duration], which is paused while hovered,
+/// and are announced by screen readers as they appear.
+///
+/// # Example
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> impl IntoElement {
+///     ToastProvider::new().child(SaveButton {})
+/// }
+///
+/// #[derive(PartialEq)]
+/// struct SaveButton {}
+///
+/// impl Component for SaveButton {
+///     fn render(&self) -> impl IntoElement {
+///         let toasts = use_toast();
+///
+///         Button::new()
+///             .on_press(move |_| {
+///                 toasts.show(Toast::new("Saved").description("All changes were saved"));
+///             })
+///             .child("Save")
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct ToastProvider {
+    pub(crate) theme: Option<ToastThemePartial>,
+    position: ToastPosition,
+    max_visible: usize,
+    children: Vec<Element>,
+    key: DiffKey,
+}
+
+impl Default for ToastProvider {
+    fn default() -> Self {
+        Self {
+            theme: None,
+            position: ToastPosition::default(),
+            max_visible: 3,
+            children: Vec::new(),
+            key: DiffKey::None,
+        }
+    }
+}
+
+impl ToastProvider {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn position(mut self, position: ToastPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Toasts shown at once, the rest wait in the queue. Defaults to `3`.
+    pub fn max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = max_visible;
+        self
+    }
+
+    pub fn theme(mut self, theme: ToastThemePartial) -> Self {
+        self.theme = Some(theme);
+        self
+    }
+}
+
+impl ChildrenExt for ToastProvider {
+    fn get_children(&mut self) -> &mut Vec<Element> {
+        &mut self.children
+    }
+}
+
+impl KeyExt for ToastProvider {
+    fn write_key(&mut self) -> &mut DiffKey {
+        &mut self.key
+    }
+}
+
+impl Component for ToastProvider {
+    fn render(&self) -> impl IntoElement {
+        let theme = get_theme!(&self.theme, ToastThemePreference, "toast");
+        let manager = use_provide_context(|| ToastManager {
+            entries: State::create(Vec::new()),
+            next_id: State::create(0),
+        });
+        let (main_align, cross_align) = self.position.alignments();
+
+        let toasts = manager
+            .entries
+            .read()
+            .iter()
+            .take(self.max_visible)
+            .map(|entry| {
+                ToastItem {
+                    entry: entry.clone(),
+                    manager,
+                    theme: theme.clone(),
+                    key: DiffKey::from(&entry.id.0),
+                }
+                .into()
+            })
+            .collect::<Vec<Element>>();
+
+        rect().expanded().children(self.children.clone()).child(
+            rect()
+                .layer(Layer::Overlay)
+                .position(Position::new_global().top(0.).left(0.))
+                .width(Size::window_percent(100.))
+                .height(Size::window_percent(100.))
+                .padding(16.)
+                .spacing(8.)
+                .main_align(main_align)
+                .cross_align(cross_align)
+                .a11y_builder(|node| node.set_live(Live::Polite))
+                .children(toasts),
+        )
+    }
+
+    fn render_key(&self) -> DiffKey {
+        self.key.clone().or(self.default_key())
+    }
+}
+
+#[derive(PartialEq)]
+struct ToastItem {
+    entry: ToastEntry,
+    manager: ToastManager,
+    theme: ToastTheme,
+    key: DiffKey,
+}
+
+impl Component for ToastItem {
+    fn render(&self) -> impl IntoElement {
+        let manager = self.manager;
+        let id = self.entry.id;
+        let duration = self.entry.toast.duration;
+        let mut hovering = use_state(|| false);
+
+        let animation =
+            use_animation_with_dependencies(&self.entry.closing, move |conf, closing| {
+                conf.on_creation(OnCreation::Run);
+                conf.on_change(OnChange::Rerun);
+
+                let closing = *closing;
+                conf.on_end(move || {
+                    if closing {
+                        manager.remove(id);
+                    }
+                });
+
+                let values = (
+                    AnimNum::new(0., 1.)
+                        .time(200)
+                        .ease(Ease::Out)
+                        .function(Function::Expo),
+                    AnimNum::new(16., 0.)
+                        .time(200)
+                        .ease(Ease::Out)
+                        .function(Function::Expo),
+                );
+                if closing {
+                    values.into_reversed()
+                } else {
+                    values
+                }
+            });
+
+        // Auto dismiss, the timer is stopped while hovered and resumed with the time that was left
+        let mut remaining = use_state(|| duration);
+        let mut timer = use_state(|| None::<(Instant, OwnedTaskHandle)>);
+        use_side_effect(move || {
+            let hovering = *hovering.read();
+            let Some(left) = *remaining.peek() else {
+                return;
+            };
+            if hovering {
+                if let Some((started, _)) = timer.write().take() {
+                    remaining.set(Some(left.saturating_sub(started.elapsed())));
+                }
+            } else if timer.peek().is_none() {
+                let task = spawn(async move {
+                    Timer::after(left).await;
+                    manager.dismiss(id);
+                });
+                timer.set(Some((Instant::now(), task.owned())));
+            }
+        });
+
+        let (opacity, offset_y) = animation.read().value();
+        let ToastTheme {
+            background,
+            color,
+            description_color,
+            border_fill,
+            shadow,
+            corner_radius,
+            padding,
+            width,
+        } = self.theme.clone();
+
+        rect()
+            .a11y_role(AccessibilityRole::Status)
+            .width(width)
+            .padding(padding)
+            .spacing(4.)
+            .opacity(opacity)
+            .offset_y(offset_y)
+            .background(background)
+            .color(color)
+            .corner_radius(corner_radius)
+            .border(Border::new().width(1.).fill(border_fill))
+            .shadow((0.0, 4.0, 10.0, 0., shadow))
+            .on_pointer_enter(move |_| hovering.set(true))
+            .on_pointer_leave(move |_| hovering.set(false))
+            .on_press(move |_| manager.dismiss(id))
+            .child(label().text(self.entry.toast.title.clone()).font_size(15.))
+            .maybe_child(self.entry.toast.description.clone().map(|description| {
+                label()
+                    .text(description)
+                    .font_size(13.)
+                    .color(description_color)
+            }))
+    }
+
+    fn render_key(&self) -> DiffKey {
+        self.key.clone()
+    }
+}
@@ -0,0 +1,473 @@
+use std::{
+    borrow::Cow,
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    future::Future,
+    hash::Hash,
+    pin::Pin,
+    rc::Rc,
+};
+
+use freya_core::prelude::*;
+use torin::{
+    gaps::Gaps,
+    prelude::Alignment,
+    size::Size,
+};
+
+use crate::{
+    define_theme,
+    get_theme,
+    icons::arrow::ArrowIcon,
+    scrollviews::{
+        ScrollConfig,
+        VirtualScrollView,
+        use_scroll_controller,
+    },
+};
+
+define_theme! {
+    %[component]
+    pub TreeView {
+        %[fields]
+        color: Color,
+        hover_background: Color,
+        active_background: Color,
+        arrow_fill: Color,
+        indent: f32,
+        row_height: f32,
+    }
+}
+
+/// Node of a [TreeView].
+#[derive(Clone, PartialEq, Debug)]
+pub struct TreeItem<K> {
+    pub id: K,
+    pub label: Cow<'static, str>,
+    /// Children known upfront.
+    pub children: Option<Vec<TreeItem<K>>>,
+    /// Whether the children are loaded with [TreeView::load_children] once expanded.
+    pub lazy: bool,
+}
+
+impl<K> TreeItem<K> {
+    /// Create an item without children.
+    pub fn new(id: K, label: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            id,
+            label: label.into(),
+            children: None,
+            lazy: false,
+        }
+    }
+
+    pub fn children(mut self, children: impl Into<Vec<TreeItem<K>>>) -> Self {
+        self.children = Some(children.into());
+        self
+    }
+
+    /// Load the children of this item with [TreeView::load_children] when it is expanded.
+    pub fn lazy(mut self) -> Self {
+        self.lazy = true;
+        self
+    }
+
+    fn is_expandable(&self) -> bool {
+        self.children.is_some() || self.lazy
+    }
+}
+
+type ChildrenLoader<K> = Rc<dyn Fn(K) -> Pin<Box<dyn Future<Output = Vec<TreeItem<K>>>>>>;
+
+/// Expandable tree of items, like a file explorer.
+///
+/// Only the rows of the expanded items are built and only the visible ones are rendered,
+/// so large trees stay smooth. Use the arrow keys to move between rows,
+/// `ArrowRight` / `ArrowLeft` to expand or collapse them and `Enter` to select them.
+///
+/// # Example
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> impl IntoElement {
+///     TreeView::new(vec![
+///         TreeItem::new("src", "src").children(vec![
+///             TreeItem::new("src/main.rs", "main.rs"),
+///             TreeItem::new("src/lib.rs", "lib.rs"),
+///         ]),
+///         TreeItem::new("Cargo.toml", "Cargo.toml"),
+///     ])
+///     .on_select(|id| println!("Selected {id}"))
+/// }
+/// ```
+pub struct TreeView<K> {
+    pub(crate) theme: Option<TreeViewThemePartial>,
+    items: Vec<TreeItem<K>>,
+    load_children: Option<ChildrenLoader<K>>,
+    on_select: Option<EventHandler<K>>,
+    height: Size,
+    key: DiffKey,
+}
+
+impl<K: PartialEq> PartialEq for TreeView<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.theme == other.theme
+            && self.items == other.items
+            && self.on_select == other.on_select
+            && self.height == other.height
+            && self.key == other.key
+    }
+}
+
+impl<K> TreeView<K> {
+    pub fn new(items: impl Into<Vec<TreeItem<K>>>) -> Self {
+        Self {
+            theme: None,
+            items: items.into(),
+            load_children: None,
+            on_select: None,
+            height: Size::fill(),
+            key: DiffKey::None,
+        }
+    }
+
+    /// Load the children of the [lazy](TreeItem::lazy) items when they are first expanded.
+    pub fn load_children<F: Future<Output = Vec<TreeItem<K>>> + 'static>(
+        mut self,
+        load_children: impl Fn(K) -> F + 'static,
+    ) -> Self {
+        self.load_children = Some(Rc::new(move |id| Box::pin(load_children(id))));
+        self
+    }
+
+    /// Called with the id of the item pressed or selected with `Enter`.
+    pub fn on_select(mut self, on_select: impl Into<EventHandler<K>>) -> Self {
+        self.on_select = Some(on_select.into());
+        self
+    }
+
+    pub fn height(mut self, height: impl Into<Size>) -> Self {
+        self.height = height.into();
+        self
+    }
+}
+
+impl<K> KeyExt for TreeView<K> {
+    fn write_key(&mut self) -> &mut DiffKey {
+        &mut self.key
+    }
+}
+
+/// Visible row of a [TreeView], the tree is flattened into these.
+#[derive(Clone, PartialEq)]
+struct TreeRow<K> {
+    id: K,
+    label: Cow<'static, str>,
+    depth: usize,
+    parent: Option<usize>,
+    expandable: bool,
+    expanded: bool,
+    lazy: bool,
+    loading: bool,
+}
+
+/// Expansion state of a [TreeView] and the children loaded so far.
+struct TreeState<K> {
+    expanded: State<HashSet<K>>,
+    loaded: State<HashMap<K, Vec<TreeItem<K>>>>,
+    loading: State<HashSet<K>>,
+}
+
+impl<K> Clone for TreeState<K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K> Copy for TreeState<K> {}
+
+impl<K: Clone + Eq + Hash + 'static> TreeState<K> {
+    fn set_expanded(
+        &mut self,
+        row: &TreeRow<K>,
+        expanded: bool,
+        load_children: Option<&ChildrenLoader<K>>,
+    ) {
+        if !row.expandable || row.expanded == expanded {
+            return;
+        }
+        if !expanded {
+            self.expanded.write().remove(&row.id);
+            return;
+        }
+        self.expanded.write().insert(row.id.clone());
+
+        // Load the children of lazy items the first time they are expanded
+        let needs_loading = row.lazy
+            && !self.loaded.read().contains_key(&row.id)
+            && !self.loading.read().contains(&row.id);
+        if let Some(load_children) = load_children
+            && needs_loading
+        {
+            let future = load_children(row.id.clone());
+            let id = row.id.clone();
+            let mut state = *self;
+            state.loading.write().insert(id.clone());
+            spawn(async move {
+                let children = future.await;
+                state.loaded.write().insert(id.clone(), children);
+                state.loading.write().remove(&id);
+            });
+        }
+    }
+
+    fn flatten(&self, items: &[TreeItem<K>]) -> Vec<TreeRow<K>> {
+        let mut rows = Vec::new();
+        self.flatten_into(items, 0, None, &mut rows);
+        rows
+    }
+
+    fn flatten_into(
+        &self,
+        items: &[TreeItem<K>],
+        depth: usize,
+        parent: Option<usize>,
+        rows: &mut Vec<TreeRow<K>>,
+    ) {
+        for item in items {
+            let index = rows.len();
+            let expandable = item.is_expandable();
+            let expanded = expandable && self.expanded.read().contains(&item.id);
+            rows.push(TreeRow {
+                id: item.id.clone(),
+                label: item.label.clone(),
+                depth,
+                parent,
+                expandable,
+                expanded,
+                lazy: item.lazy && item.children.is_none(),
+                loading: expanded && self.loading.read().contains(&item.id),
+            });
+            if !expanded {
+                continue;
+            }
+            if let Some(children) = &item.children {
+                self.flatten_into(children, depth + 1, Some(index), rows);
+            } else if let Some(children) = self.loaded.read().get(&item.id) {
+                self.flatten_into(children, depth + 1, Some(index), rows);
+            }
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash + 'static> Component for TreeView<K> {
+    fn render(&self) -> impl IntoElement {
+        let TreeViewTheme {
+            color,
+            hover_background,
+            active_background,
+            arrow_fill,
+            indent,
+            row_height,
+        } = get_theme!(&self.theme, TreeViewThemePreference, "tree_view");
+        let focus = use_focus();
+        let mut tree = TreeState {
+            expanded: use_state(HashSet::new),
+            loaded: use_state(HashMap::new),
+            loading: use_state(HashSet::new),
+        };
+        let mut active = use_state::<Option<K>>(|| None);
+        let mut viewport_height = use_state(|| 0.);
+        let mut scroll_controller = use_scroll_controller(ScrollConfig::default);
+
+        let rows = tree.flatten(&self.items);
+        let active_index = active
+            .read()
+            .as_ref()
+            .and_then(|id| rows.iter().position(|row| row.id == *id));
+
+        let on_key_down = {
+            let rows = rows.clone();
+            let load_children = self.load_children.clone();
+            let on_select = self.on_select.clone();
+            move |e: Event<KeyboardEventData>| {
+                let Some(index) = active_index else {
+                    if let Some(row) = rows.first()
+                        && e.key == Key::Named(NamedKey::ArrowDown)
+                    {
+                        active.set(Some(row.id.clone()));
+                    }
+                    return;
+                };
+                let row = &rows[index];
+                let new_index = match &e.key {
+                    Key::Named(NamedKey::ArrowDown) => (index + 1).min(rows.len() - 1),
+                    Key::Named(NamedKey::ArrowUp) => index.saturating_sub(1),
+                    Key::Named(NamedKey::ArrowRight) if row.expandable && !row.expanded => {
+                        tree.set_expanded(row, true, load_children.as_ref());
+                        index
+                    }
+                    Key::Named(NamedKey::ArrowRight)
+                        if rows
+                            .get(index + 1)
+                            .is_some_and(|next| next.parent == Some(index)) =>
+                    {
+                        index + 1
+                    }
+                    Key::Named(NamedKey::ArrowLeft) if row.expanded => {
+                        tree.set_expanded(row, false, None);
+                        index
+                    }
+                    Key::Named(NamedKey::ArrowLeft) => row.parent.unwrap_or(index),
+                    Key::Named(NamedKey::Enter) => {
+                        tree.set_expanded(row, !row.expanded, load_children.as_ref());
+                        if let Some(on_select) = &on_select {
+                            on_select.call(row.id.clone());
+                        }
+                        index
+                    }
+                    _ => return,
+                };
+                e.stop_propagation();
+                active.set(Some(rows[new_index].id.clone()));
+
+                // Keep the active row visible
+                let (_, scroll_y) = scroll_controller.into();
+                let top = new_index as f32 * row_height;
+                let viewport_height = *viewport_height.read();
+                if top < -scroll_y as f32 {
+                    scroll_controller.scroll_to_y(-top as i32);
+                } else if top + row_height > -scroll_y as f32 + viewport_height {
+                    scroll_controller.scroll_to_y(-(top + row_height - viewport_height) as i32);
+                }
+            }
+        };
+
+        let load_children = self.load_children.clone();
+        let on_select = self.on_select.clone();
+        let length = rows.len();
+        let list = VirtualScrollView::new_with_data_controlled(
+            (rows, active_index),
+            move |index, (rows, active_index)| {
+                let row = rows[index].clone();
+                let on_press = {
+                    let row = row.clone();
+                    let load_children = load_children.clone();
+                    let on_select = on_select.clone();
+                    move |_: Event<PressEventData>| {
+                        focus.request_focus();
+                        active.set(Some(row.id.clone()));
+                        tree.set_expanded(&row, !row.expanded, load_children.as_ref());
+                        if let Some(on_select) = &on_select {
+                            on_select.call(row.id.clone());
+                        }
+                    }
+                };
+                TreeViewRow {
+                    label: row.label,
+                    depth: row.depth,
+                    expandable: row.expandable,
+                    expanded: row.expanded,
+                    loading: row.loading,
+                    active: *active_index == Some(index),
+                    hover_background,
+                    active_background,
+                    arrow_fill,
+                    indent,
+                    row_height,
+                    on_press: on_press.into(),
+                    key: DiffKey::from(&index),
+                }
+                .into()
+            },
+            scroll_controller,
+        )
+        .length(length)
+        .item_size(row_height)
+        .scroll_with_arrows(false);
+
+        rect()
+            .a11y_id(focus.a11y_id())
+            .a11y_role(AccessibilityRole::Tree)
+            .a11y_focusable(true)
+            .height(self.height.clone())
+            .color(color)
+            .on_key_down(on_key_down)
+            .on_sized(move |e: Event<SizedEventData>| {
+                viewport_height.set_if_modified(e.area.height())
+            })
+            .child(list)
+    }
+
+    fn render_key(&self) -> DiffKey {
+        self.key.clone().or(self.default_key())
+    }
+}
+
+#[derive(PartialEq)]
+struct TreeViewRow {
+    label: Cow<'static, str>,
+    depth: usize,
+    expandable: bool,
+    expanded: bool,
+    loading: bool,
+    active: bool,
+    hover_background: Color,
+    active_background: Color,
+    arrow_fill: Color,
+    indent: f32,
+    row_height: f32,
+    on_press: EventHandler<Event<PressEventData>>,
+    key: DiffKey,
+}
+
+impl Component for TreeViewRow {
+    fn render(&self) -> impl IntoElement {
+        let mut hovering = use_state(|| false);
+
+        let background = if self.active {
+            self.active_background
+        } else if hovering() {
+            self.hover_background
+        } else {
+            Color::TRANSPARENT
+        };
+        let on_press = self.on_press.clone();
+        let rotate = if self.expanded { 0. } else { -90. };
+
+        rect()
+            .a11y_role(AccessibilityRole::TreeItem)
+            .a11y_builder(|node| {
+                if self.expandable {
+                    node.set_expanded(self.expanded);
+                }
+            })
+            .horizontal()
+            .width(Size::fill())
+            .height(Size::px(self.row_height))
+            .cross_align(Alignment::Center)
+            .padding(Gaps::new(0., 8., 0., 8. + self.depth as f32 * self.indent))
+            .spacing(6.)
+            .background(background)
+            .on_pointer_enter(move |_| hovering.set(true))
+            .on_pointer_leave(move |_| hovering.set(false))
+            .on_press(move |e| on_press.call(e))
+            .child(
+                rect()
+                    .width(Size::px(10.))
+                    .height(Size::px(10.))
+                    .maybe_child(
+                        self.expandable
+                            .then(|| ArrowIcon::new().rotate(rotate).fill(self.arrow_fill)),
+                    ),
+            )
+            .child(label().text(self.label.clone()).max_lines(1))
+            .maybe_child(self.loading.then(|| label().text("…")))
+    }
+
+    fn render_key(&self) -> DiffKey {
+        self.key.clone().or(self.default_key())
+    }
+}
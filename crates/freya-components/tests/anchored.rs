@@ -0,0 +1,94 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+fn area(x: f32, y: f32, width: f32, height: f32) -> Area {
+    Area::new((x, y).into(), (width, height).into())
+}
+
+#[test]
+pub fn anchored_position_placement() {
+    let bounds = Size2D::new(500., 500.);
+    let anchor = area(100., 100., 100., 20.);
+    let floating = Size2D::new(50., 30.);
+
+    let position =
+        AnchoredPosition::compute(anchor, floating, bounds, &AnchoredOptions::new().offset(4.));
+    assert_eq!(position.placement, AttachedPosition::Bottom);
+    assert_eq!(position.area, area(125., 124., 50., 30.));
+
+    let position = AnchoredPosition::compute(
+        anchor,
+        floating,
+        bounds,
+        &AnchoredOptions::new()
+            .placement(AttachedPosition::Right)
+            .alignment(AnchoredAlignment::Start),
+    );
+    assert_eq!(position.area, area(200., 100., 50., 30.));
+}
+
+#[test]
+pub fn anchored_position_flip_and_shift() {
+    let bounds = Size2D::new(500., 500.);
+    let floating = Size2D::new(200., 100.);
+
+    // No room below, so it flips above
+    let position = AnchoredPosition::compute(
+        area(100., 450., 100., 20.),
+        floating,
+        bounds,
+        &AnchoredOptions::new(),
+    );
+    assert_eq!(position.placement, AttachedPosition::Top);
+    assert_eq!(position.area.origin.y, 350.);
+
+    // Too close to the right edge, so it shifts left
+    let position = AnchoredPosition::compute(
+        area(440., 100., 50., 20.),
+        floating,
+        bounds,
+        &AnchoredOptions::new().padding(8.),
+    );
+    assert_eq!(position.placement, AttachedPosition::Bottom);
+    assert_eq!(position.area.origin.x, 292.);
+
+    // Without shifting it overflows
+    let position = AnchoredPosition::compute(
+        area(440., 100., 50., 20.),
+        floating,
+        bounds,
+        &AnchoredOptions::new().shift(false),
+    );
+    assert_eq!(position.area.origin.x, 365.);
+}
+
+#[test]
+pub fn anchored_portal_render() {
+    fn app() -> impl IntoElement {
+        rect().padding(Gaps::new(480., 0., 0., 100.)).child(
+            AnchoredPortal::new(rect().width(Size::px(100.)).height(Size::px(20.))).child(
+                rect()
+                    .width(Size::px(80.))
+                    .height(Size::px(40.))
+                    .child(label().text("Anchored")),
+            ),
+        )
+    }
+
+    let mut test = launch_test(app);
+    test.sync_and_update();
+    test.sync_and_update();
+
+    let anchored = test
+        .find(|node, element| {
+            Label::try_downcast(element)
+                .filter(|l| l.text == "Anchored")
+                .map(|_| node)
+        })
+        .unwrap();
+
+    // The anchor is at the bottom of the window, so the label is flipped above it
+    let area = anchored.layout().area;
+    assert_eq!(area.origin.y, 440.);
+    assert_eq!(area.origin.x, 110.);
+}
@@ -68,3 +68,34 @@ pub fn button_keyboard_press_test() {
     });
     assert!(label.is_some());
 }
+
+#[test]
+pub fn button_queries_test() {
+    fn button_app() -> impl IntoElement {
+        let mut state = use_state(|| false);
+
+        Button::new()
+            .on_press(move |_| {
+                state.toggle();
+            })
+            .child(format!("{}", state.read()))
+    }
+
+    let mut test = launch_test(button_app);
+
+    assert!(test.find_by_text("false").is_some());
+    assert!(test.find_by_text("true").is_none());
+
+    let button = test
+        .find_by_role(AccessibilityRole::Button)
+        .expect("Button not found.");
+    assert_eq!(test.find_all_by_role(AccessibilityRole::Button).len(), 1);
+
+    let button = test
+        .find_by_a11y_id(button.a11y_id())
+        .expect("Button not found.");
+    let area = button.layout().area;
+    test.click_cursor((area.min_x() + 5., area.min_y() + 5.));
+
+    assert!(test.find_by_text("true").is_some());
+}
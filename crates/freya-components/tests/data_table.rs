@@ -0,0 +1,89 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+fn rows() -> Vec<(&'static str, &'static str)> {
+    vec![("Zeus", "Sky"), ("Poseidon", "Sea"), ("Ares", "War")]
+}
+
+fn columns() -> Vec<DataColumn> {
+    vec![DataColumn::new("Name"), DataColumn::new("Domain")]
+}
+
+fn find_label(test: &TestingRunner, text: &str) -> bool {
+    test.find(|_, element| Label::try_downcast(element).filter(|label| label.text.as_ref() == text))
+        .is_some()
+}
+
+#[test]
+pub fn data_table_sort() {
+    fn app() -> impl IntoElement {
+        let mut sort = use_state::<Option<(usize, OrderDirection)>>(|| None);
+
+        rect()
+            .child(
+                DataTable::new(rows(), |row, column, rows| {
+                    let (name, domain) = rows[row];
+                    label().text(if column == 0 { name } else { domain }).into()
+                })
+                .columns(columns())
+                .length(3usize)
+                .height(Size::px(300.))
+                .sort(sort())
+                .on_sort(move |new_sort| sort.set(Some(new_sort))),
+            )
+            .child(
+                label().text(format!(
+                    "{:?}",
+                    sort()
+                        .map(|(column, direction)| { (column, direction == OrderDirection::Down) })
+                )),
+            )
+    }
+
+    let mut test = launch_test(app);
+    test.sync_and_update();
+    assert!(find_label(&test, "None"));
+
+    // Pressing a header sorts by its column
+    test.click_cursor((50., 18.));
+    assert!(find_label(&test, "Some((0, true))"));
+
+    // Pressing it again reverses the direction
+    test.click_cursor((50., 18.));
+    assert!(find_label(&test, "Some((0, false))"));
+}
+
+#[test]
+pub fn data_table_multiple_selection() {
+    fn app() -> impl IntoElement {
+        let mut selected = use_state(Vec::<usize>::new);
+
+        rect()
+            .child(
+                DataTable::new(rows(), |row, column, rows| {
+                    let (name, domain) = rows[row];
+                    label().text(if column == 0 { name } else { domain }).into()
+                })
+                .columns(columns())
+                .length(3usize)
+                .height(Size::px(300.))
+                .selection_mode(RowSelectionMode::Multiple)
+                .on_selection_change(move |selection: RowSelection| {
+                    selected.set(selection.rows().to_vec())
+                }),
+            )
+            .child(label().text(format!("{:?}", selected.read())))
+    }
+
+    let mut test = launch_test(app);
+    test.sync_and_update();
+
+    // The header takes 37 pixels and every row 37 more
+    test.click_cursor((50., 50.));
+    assert!(find_label(&test, "[0]"));
+
+    // Shift selects the range from the last selected row
+    test.press_key(Key::Named(NamedKey::Shift));
+    test.click_cursor((50., 124.));
+    assert!(find_label(&test, "[0, 1, 2]"));
+}
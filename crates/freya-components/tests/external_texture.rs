@@ -0,0 +1,48 @@
+use std::{
+    cell::Cell,
+    rc::Rc,
+};
+
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[test]
+pub fn external_texture_layout_and_raster_render() {
+    fn external_texture_app() -> impl IntoElement {
+        let calls = use_consume::<Rc<Cell<usize>>>();
+        let texture = use_external_texture();
+        use_hook(move || {
+            texture.on_render(move |_, _| {
+                calls.set(calls.get() + 1);
+                None
+            })
+        });
+
+        rect().padding(10.).child(
+            external_texture(texture)
+                .width(Size::px(200.))
+                .height(Size::px(100.)),
+        )
+    }
+
+    let (mut test, calls) = TestingRunner::new(
+        external_texture_app,
+        (500., 500.).into(),
+        |runner| runner.provide_root_context(|| Rc::new(Cell::new(0))),
+        1.,
+    );
+    test.sync_and_update();
+
+    let texture = test
+        .find(|node, element| ExternalTextureView::try_downcast(element).map(|_| node))
+        .unwrap();
+    let area = texture.layout().area;
+    assert_eq!(area.origin.x, 10.);
+    assert_eq!(area.size.width, 200.);
+    assert_eq!(area.size.height, 100.);
+
+    // Raster canvases have no GPU context to render with
+    let png = test.render();
+    assert!(!png.is_empty());
+    assert_eq!(calls.get(), 0);
+}
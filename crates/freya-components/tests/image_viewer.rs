@@ -127,3 +127,37 @@ pub fn image_viewer_load_and_render() {
         "Image element should be rendered after loading"
     );
 }
+
+#[test]
+pub fn image_viewer_slice() {
+    fn image_viewer_app() -> impl IntoElement {
+        let source: ImageSource = (
+            "rust-logo",
+            include_bytes!("../../../examples/rust_logo.png"),
+        )
+            .into();
+
+        ImageViewer::new(source)
+            .image_slice(ImageSlice::new(10., 10., 10., 10.).repeat(ImageSliceRepeat::Tile))
+            .width(Size::px(300.))
+            .height(Size::px(100.))
+    }
+
+    let mut test = launch_test(image_viewer_app);
+    test.sync_and_update();
+
+    test.poll(
+        std::time::Duration::from_millis(1),
+        std::time::Duration::from_millis(70),
+    );
+    test.sync_and_update();
+
+    // Sliced images ignore the aspect ratio and fill their area
+    let image_element = test
+        .find(|node, element| Image::try_downcast(element).map(|_| node))
+        .unwrap();
+    assert_eq!(image_element.layout().area.width(), 300.);
+    assert_eq!(image_element.layout().area.height(), 100.);
+
+    assert!(!test.render().is_empty());
+}
@@ -1,4 +1,8 @@
 use freya::prelude::*;
+use freya_core::{
+    events::ime_composition::ImeComposition,
+    integration::AccessibilityTree,
+};
 use freya_testing::prelude::*;
 
 #[test]
@@ -219,3 +223,136 @@ pub fn input_auto_focus_test() {
     });
     assert!(label.is_some());
 }
+
+#[test]
+pub fn input_ime_composition_test() {
+    fn ime_app() -> impl IntoElement {
+        let value = use_state(String::new);
+
+        rect()
+            .child(Input::new(value))
+            .child(format!("value={}", value.read()))
+    }
+
+    let mut test = launch_test(ime_app);
+    let mut composition = ImeComposition::default();
+
+    test.click_cursor((15.0, 15.0));
+
+    // The preedit is rendered underlined but not committed yet
+    for event in composition.preedit("ni".to_string(), Some((2, 2))) {
+        test.send_event(event);
+    }
+    test.sync_and_update();
+    assert!(composition.is_composing());
+
+    let preedit = test.find(|_, element| {
+        Paragraph::try_downcast(element).filter(|paragraph| {
+            paragraph.spans.iter().any(|s| {
+                s.text == "ni"
+                    && s.text_style_data.text_decoration == Some(TextDecoration::Underline)
+            })
+        })
+    });
+    assert!(preedit.is_some());
+    let label = test.find(|_, element| {
+        Label::try_downcast(element).filter(|label| label.text.as_ref() == "value=")
+    });
+    assert!(label.is_some());
+
+    // Committing replaces the preedit
+    for event in composition.preedit(String::new(), None) {
+        test.send_event(event);
+    }
+    assert!(composition.is_composing());
+    test.write_text("你");
+    let composition_end = composition.end("你".to_string());
+    assert!(matches!(
+        &composition_end,
+        Some(PlatformEvent::ImePreedit { name: ImeEventName::CompositionEnd, text, .. }) if text == "你"
+    ));
+    test.send_event(composition_end.unwrap());
+    test.sync_and_update();
+    assert!(!composition.is_composing());
+
+    let label = test.find(|_, element| {
+        Label::try_downcast(element).filter(|label| label.text.as_ref() == "value=你")
+    });
+    assert!(label.is_some());
+}
+
+#[test]
+pub fn input_ime_helpers_test() {
+    fn ime_app() -> impl IntoElement {
+        let value = use_state(String::new);
+
+        rect()
+            .child(Input::new(value))
+            .child(format!("value={}", value.read()))
+    }
+
+    let mut test = launch_test(ime_app);
+
+    test.click_cursor((15.0, 15.0));
+    test.ime_preedit("nihon", Some((5, 5)));
+
+    let label = test.find(|_, element| {
+        Label::try_downcast(element).filter(|label| label.text.as_ref() == "value=")
+    });
+    assert!(label.is_some());
+
+    test.ime_commit("日本");
+
+    let label = test.find(|_, element| {
+        Label::try_downcast(element).filter(|label| label.text.as_ref() == "value=日本")
+    });
+    assert!(label.is_some());
+}
+
+#[test]
+pub fn input_a11y_text_selection_test() {
+    fn selection_app() -> impl IntoElement {
+        let value = use_state(String::new);
+
+        rect().child(Input::new(value))
+    }
+
+    let mut test = launch_test(selection_app);
+
+    test.click_cursor((15.0, 15.0));
+    test.write_text("Rust");
+
+    let input_id = test
+        .find(|_, element| {
+            let accessibility = element.accessibility();
+            (accessibility.builder.role() == AccessibilityRole::TextInput)
+                .then_some(accessibility.a11y_id)
+                .flatten()
+        })
+        .unwrap();
+    let text_run_id = test
+        .find(|node, element| {
+            Paragraph::try_downcast(element).map(|_| AccessibilityTree::text_run_id(node.a11y_id()))
+        })
+        .unwrap();
+    let position = |character_index| accesskit::TextPosition {
+        node: text_run_id,
+        character_index,
+    };
+
+    test.request_a11y_action(
+        input_id,
+        accesskit::Action::SetTextSelection,
+        Some(accesskit::ActionData::SetTextSelection(
+            accesskit::TextSelection {
+                anchor: position(1),
+                focus: position(3),
+            },
+        )),
+    );
+
+    let highlights = test
+        .find(|_, element| Paragraph::try_downcast(element).map(|p| p.highlights.clone()))
+        .unwrap();
+    assert_eq!(highlights, vec![(1, 3)]);
+}
@@ -0,0 +1,60 @@
+use std::time::Duration;
+
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+fn lottie_app() -> impl IntoElement {
+    let mut controller = use_lottie();
+
+    rect()
+        .expanded()
+        .on_press(move |_| controller.toggle())
+        .child(format!("frame {}", controller.frame()))
+        .child(
+            LottieViewer::new(("pulse", include_bytes!("../../../examples/pulse.json")))
+                .controller(controller)
+                .width(Size::px(200.))
+                .height(Size::px(200.)),
+        )
+}
+
+fn current_frame(test: &TestingRunner) -> usize {
+    (0..1000)
+        .find(|frame| test.find_by_text(&format!("frame {frame}")).is_some())
+        .unwrap()
+}
+
+/// Frames only advance once the previous one was rendered.
+fn play(test: &mut TestingRunner, frames: usize) {
+    for _ in 0..frames {
+        test.render();
+        test.poll(Duration::from_millis(20), Duration::from_millis(60));
+    }
+    test.sync_and_update();
+}
+
+#[test]
+pub fn lottie_viewer_plays_and_pauses() {
+    let mut test = launch_test(lottie_app);
+    test.sync_and_update();
+
+    let lottie = test.find(|node, element| Lottie::try_downcast(element).map(|_| node));
+    assert!(lottie.is_some(), "The animation should be parsed");
+    assert_eq!(current_frame(&test), 0);
+
+    play(&mut test, 5);
+    let frame = current_frame(&test);
+    assert!(frame > 0, "Frames should advance while playing");
+
+    test.click_cursor((300., 300.));
+    play(&mut test, 5);
+    let paused_frame = current_frame(&test);
+    assert!(paused_frame <= frame + 1);
+
+    play(&mut test, 5);
+    assert_eq!(
+        current_frame(&test),
+        paused_frame,
+        "Paused animations stay still"
+    );
+}
@@ -130,3 +130,27 @@ fn theme_font_sizes_applied() {
         Some(12.0)
     );
 }
+
+#[test]
+fn link_click_handler() {
+    fn app() -> impl IntoElement {
+        let mut clicked = use_state(String::new);
+
+        rect()
+            .child(
+                MarkdownViewer::new("[Freya](https://freyaui.dev)")
+                    .on_link_click(move |url| clicked.set(url)),
+            )
+            .child(label().text(clicked.read().clone()))
+    }
+
+    let mut test = launch_test(app);
+    test.sync_and_update();
+
+    test.click_cursor((5.0, 5.0));
+
+    let label = test.find(|_, element| {
+        Label::try_downcast(element).filter(|label| label.text.as_ref() == "https://freyaui.dev")
+    });
+    assert!(label.is_some());
+}
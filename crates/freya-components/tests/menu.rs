@@ -339,3 +339,113 @@ pub fn menu_container_standalone() {
         "Item 3"
     );
 }
+
+#[test]
+pub fn menu_close_on_escape() {
+    fn menu_app() -> impl IntoElement {
+        let mut show_menu = use_state(|| true);
+
+        rect()
+            .child(label().text(format!("Menu Open: {}", show_menu())))
+            .maybe_child(show_menu().then(|| {
+                Menu::new()
+                    .on_close(move |_| show_menu.set(false))
+                    .child(MenuButton::new().child("Item"))
+            }))
+    }
+
+    let mut test = launch_test(menu_app);
+    test.sync_and_update();
+
+    test.press_key(Key::Named(NamedKey::Escape));
+
+    let status_label = test
+        .find(|node, element| {
+            Label::try_downcast(element)
+                .filter(|l| l.text.starts_with("Menu Open:"))
+                .map(|_| node)
+        })
+        .unwrap();
+
+    assert_eq!(
+        Label::try_downcast(&*status_label.element()).unwrap().text,
+        "Menu Open: false"
+    );
+}
+
+#[test]
+pub fn context_menu_area_right_click() {
+    fn menu_app() -> impl IntoElement {
+        ContextMenuArea::new(Menu::new().child(MenuButton::new().child("Copy")))
+            .child(rect().width(Size::px(300.)).height(Size::px(300.)))
+    }
+
+    let mut test = launch_test(menu_app);
+    test.sync_and_update();
+
+    let find_copy = |test: &TestingRunner| {
+        test.find(|node, element| {
+            Label::try_downcast(element)
+                .filter(|l| l.text == "Copy")
+                .map(|_| node)
+        })
+    };
+    assert!(find_copy(&test).is_none());
+
+    test.move_cursor((100., 100.));
+    test.sync_and_update();
+    test.send_event(PlatformEvent::Mouse {
+        name: MouseEventName::MouseDown,
+        cursor: (100., 100.).into(),
+        button: Some(MouseButton::Right),
+        click_count: 1,
+    });
+    test.sync_and_update();
+
+    assert!(find_copy(&test).is_some());
+
+    // Escape dismisses it
+    test.press_key(Key::Named(NamedKey::Escape));
+    assert!(find_copy(&test).is_none());
+}
+
+#[test]
+pub fn context_menu_area_keyboard() {
+    fn menu_app() -> impl IntoElement {
+        rect().padding(100.).child(
+            ContextMenuArea::new(Menu::new().child(MenuButton::new().child("Copy")))
+                .child(Button::new().child("Target")),
+        )
+    }
+
+    let mut test = launch_test(menu_app);
+    test.sync_and_update();
+
+    test.press_key(Key::Named(NamedKey::Tab));
+    test.sync_and_update();
+    test.sync_and_update();
+
+    test.press_key(Key::Named(NamedKey::ContextMenu));
+    test.sync_and_update();
+
+    let target = test
+        .find(|node, element| {
+            Label::try_downcast(element)
+                .filter(|l| l.text == "Target")
+                .map(|_| node)
+        })
+        .unwrap();
+    let copy = test
+        .find(|node, element| {
+            Label::try_downcast(element)
+                .filter(|l| l.text == "Copy")
+                .map(|_| node)
+        })
+        .unwrap();
+
+    // The menu opens below the focused button rather than at the cursor
+    let target_area = target.layout().area;
+    let copy_area = copy.layout().area;
+    assert!(copy_area.min_x() >= 100.);
+    assert!(copy_area.min_y() > target_area.max_y());
+}
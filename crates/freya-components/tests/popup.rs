@@ -0,0 +1,85 @@
+use std::time::Duration;
+
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[derive(PartialEq)]
+struct FocusableItem {
+    name: &'static str,
+    on_press: Option<EventHandler<Event<PressEventData>>>,
+}
+
+impl Component for FocusableItem {
+    fn render(&self) -> impl IntoElement {
+        let focus = use_focus();
+        let focus_status = use_focus_status(focus);
+        let on_press = self.on_press.clone();
+
+        rect()
+            .a11y_id(focus.a11y_id())
+            .a11y_focusable(true)
+            .map(on_press, |el, on_press| el.on_press(on_press))
+            .child(label().text(format!("{}: {}", self.name, focus_status().is_focused())))
+    }
+}
+
+fn is_focused(test: &TestingRunner, name: &str) -> bool {
+    let text = format!("{name}: true");
+    test.find(|node, element| {
+        Label::try_downcast(element)
+            .filter(|l| l.text == text)
+            .map(|_| node)
+    })
+    .is_some()
+}
+
+#[test]
+pub fn popup_traps_focus() {
+    fn app() -> impl IntoElement {
+        let mut show = use_state(|| false);
+
+        rect()
+            .child(FocusableItem {
+                name: "outside",
+                on_press: Some(EventHandler::new(move |_| show.set(true))),
+            })
+            .child(
+                Popup::new()
+                    .show(show())
+                    .on_close_request(move |_| show.set(false))
+                    .child(FocusableItem {
+                        name: "first",
+                        on_press: None,
+                    })
+                    .child(FocusableItem {
+                        name: "second",
+                        on_press: None,
+                    }),
+            )
+    }
+
+    let mut test = launch_test(app);
+    test.sync_and_update();
+
+    test.press_key(Key::Named(NamedKey::Tab));
+    test.sync_and_update();
+    assert!(is_focused(&test, "outside"));
+
+    // Opening the popup moves the focus into it
+    test.press_key(Key::Named(NamedKey::Enter));
+    test.poll(Duration::from_millis(16), Duration::from_millis(300));
+    assert!(is_focused(&test, "first"));
+
+    // Tab never leaves the popup
+    for expected in ["second", "first", "second"] {
+        test.press_key(Key::Named(NamedKey::Tab));
+        test.sync_and_update();
+        assert!(is_focused(&test, expected));
+        assert!(!is_focused(&test, "outside"));
+    }
+
+    // Closing it gives the focus back
+    test.press_key(Key::Named(NamedKey::Escape));
+    test.poll(Duration::from_millis(16), Duration::from_millis(500));
+    assert!(is_focused(&test, "outside"));
+}
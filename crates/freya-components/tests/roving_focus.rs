@@ -0,0 +1,189 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[derive(PartialEq)]
+struct FocusableItem {
+    name: &'static str,
+    tab_index: i32,
+}
+
+impl Component for FocusableItem {
+    fn render(&self) -> impl IntoElement {
+        let focus = use_focus();
+        let focus_status = use_focus_status(focus);
+
+        rect()
+            .a11y_id(focus.a11y_id())
+            .a11y_focusable(true)
+            .a11y_tab_index(self.tab_index)
+            .child(label().text(format!("{}: {}", self.name, focus_status().is_focused())))
+    }
+}
+
+#[derive(PartialEq)]
+struct ToolbarButton {
+    name: &'static str,
+}
+
+impl Component for ToolbarButton {
+    fn render(&self) -> impl IntoElement {
+        let item = use_roving_item();
+        let focus_status = use_focus_status(Focus::new_for_id(item.a11y_id()));
+
+        rect()
+            .a11y_id(item.a11y_id())
+            .a11y_focusable(true)
+            .a11y_tab_index(item.tab_index())
+            .on_key_down({
+                let item = item.clone();
+                move |e: Event<KeyboardEventData>| item.on_key_down(&e)
+            })
+            .child(label().text(format!("{}: {}", self.name, focus_status().is_focused())))
+    }
+}
+
+#[derive(PartialEq)]
+struct Toolbar;
+
+impl Component for Toolbar {
+    fn render(&self) -> impl IntoElement {
+        use_roving_focus(RovingDirection::Horizontal);
+
+        rect()
+            .horizontal()
+            .child(ToolbarButton { name: "bold" })
+            .child(ToolbarButton { name: "italic" })
+            .child(ToolbarButton { name: "underline" })
+    }
+}
+
+fn is_focused(test: &TestingRunner, name: &str) -> bool {
+    let text = format!("{name}: true");
+    test.find(|node, element| {
+        Label::try_downcast(element)
+            .filter(|l| l.text == text)
+            .map(|_| node)
+    })
+    .is_some()
+}
+
+#[test]
+pub fn roving_focus_single_tab_stop() {
+    fn app() -> impl IntoElement {
+        rect().child(Toolbar).child(FocusableItem {
+            name: "after",
+            tab_index: 0,
+        })
+    }
+
+    let mut test = launch_test(app);
+    test.sync_and_update();
+
+    test.press_key(Key::Named(NamedKey::Tab));
+    test.sync_and_update();
+    assert!(is_focused(&test, "bold"));
+
+    // Arrows move inside the group
+    test.press_key(Key::Named(NamedKey::ArrowRight));
+    test.sync_and_update();
+    assert!(is_focused(&test, "italic"));
+
+    test.press_key(Key::Named(NamedKey::End));
+    test.sync_and_update();
+    assert!(is_focused(&test, "underline"));
+
+    test.press_key(Key::Named(NamedKey::ArrowRight));
+    test.sync_and_update();
+    assert!(is_focused(&test, "bold"));
+
+    test.press_key(Key::Named(NamedKey::ArrowLeft));
+    test.sync_and_update();
+    assert!(is_focused(&test, "underline"));
+
+    // Tab leaves the group
+    test.press_key(Key::Named(NamedKey::Tab));
+    test.sync_and_update();
+    assert!(is_focused(&test, "after"));
+
+    // And comes back to the last active item
+    test.press_key(Key::Named(NamedKey::Tab));
+    test.sync_and_update();
+    assert!(is_focused(&test, "underline"));
+}
+
+#[test]
+pub fn tab_index_order() {
+    fn app() -> impl IntoElement {
+        rect()
+            .child(FocusableItem {
+                name: "first",
+                tab_index: 0,
+            })
+            .child(FocusableItem {
+                name: "skipped",
+                tab_index: -1,
+            })
+            .child(FocusableItem {
+                name: "second",
+                tab_index: 0,
+            })
+            .child(FocusableItem {
+                name: "priority",
+                tab_index: 1,
+            })
+    }
+
+    let mut test = launch_test(app);
+    test.sync_and_update();
+
+    // Positive indices come first, then the rest in tree order
+    for expected in ["priority", "first", "second", "priority"] {
+        test.press_key(Key::Named(NamedKey::Tab));
+        test.sync_and_update();
+        assert!(is_focused(&test, expected));
+        assert!(!is_focused(&test, "skipped"));
+    }
+}
+
+#[test]
+pub fn roving_focus_tree_order() {
+    fn app() -> impl IntoElement {
+        use_roving_focus(RovingDirection::Horizontal);
+        let mut show_first = use_state(|| false);
+
+        rect()
+            .horizontal()
+            .on_global_key_down(move |e: Event<KeyboardEventData>| {
+                if e.key == Key::Character("n".into()) {
+                    show_first.set(true);
+                }
+            })
+            .maybe_child(show_first().then_some(ToolbarButton { name: "new" }))
+            .child(ToolbarButton { name: "bold" })
+            .child(ToolbarButton { name: "italic" })
+    }
+
+    let mut test = launch_test(app);
+    test.sync_and_update();
+
+    // Insert an item before the others, created after them
+    test.press_key(Key::Character("n".into()));
+    test.sync_and_update();
+
+    test.press_key(Key::Named(NamedKey::Tab));
+    test.sync_and_update();
+    assert!(is_focused(&test, "bold"));
+
+    // The arrows follow the order of the tree rather than the order of creation
+    test.press_key(Key::Named(NamedKey::ArrowLeft));
+    test.sync_and_update();
+    assert!(is_focused(&test, "new"));
+
+    test.press_key(Key::Named(NamedKey::End));
+    test.sync_and_update();
+    assert!(is_focused(&test, "italic"));
+
+    test.press_key(Key::Named(NamedKey::Home));
+    test.sync_and_update();
+    assert!(is_focused(&test, "new"));
+}
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use freya::prelude::*;
 use freya_testing::prelude::*;
 
@@ -269,3 +271,335 @@ pub fn scroll_view_drag_scrolling_horizontal() {
     assert!(content[2].is_visible());
     assert!(content[3].is_visible());
 }
+
+#[test]
+pub fn scroll_view_snap() {
+    fn scroll_view_snap_app() -> impl IntoElement {
+        ScrollView::new()
+            .scroll_snap(ScrollSnap::Mandatory)
+            .snap_interval(200.)
+            .child(rect().height(Size::px(200.)).width(Size::px(200.)))
+            .child(rect().height(Size::px(200.)).width(Size::px(200.)))
+            .child(rect().height(Size::px(200.)).width(Size::px(200.)))
+            .child(rect().height(Size::px(200.)).width(Size::px(200.)))
+    }
+
+    let mut test = launch_test(scroll_view_snap_app);
+    let scrollview = test
+        .find(|node, element| {
+            Rect::try_downcast(element)
+                .filter(|rect| rect.accessibility.builder.role() == AccessibilityRole::ScrollView)
+                .map(move |_| node)
+        })
+        .unwrap();
+    let content = scrollview.children()[0].children()[0].children();
+
+    test.scroll((5., 5.), (0., -250.));
+    assert_eq!(content[1].layout().area.min_y(), -50.);
+
+    // Settles on the start of the closest item
+    test.poll(Duration::from_millis(16), Duration::from_millis(600));
+    assert_eq!(content[1].layout().area.min_y(), 0.);
+
+    test.scroll((5., 5.), (0., 120.));
+    test.poll(Duration::from_millis(16), Duration::from_millis(600));
+    assert_eq!(content[0].layout().area.min_y(), 0.);
+}
+
+#[test]
+pub fn scroll_view_snap_items() {
+    fn scroll_view_snap_items_app() -> impl IntoElement {
+        ScrollView::new()
+            .scroll_snap(ScrollSnap::Mandatory)
+            .child(SnapItem::new().height(Size::px(300.)).width(Size::px(200.)))
+            .child(
+                SnapItem::new()
+                    .height(Size::px(400.))
+                    .width(Size::px(200.))
+                    .snap_align(SnapAlign::Center),
+            )
+            .child(
+                SnapItem::new()
+                    .height(Size::px(300.))
+                    .width(Size::px(200.))
+                    .snap_align(SnapAlign::End),
+            )
+            .child(SnapItem::new().height(Size::px(400.)).width(Size::px(200.)))
+    }
+
+    let mut test = launch_test(scroll_view_snap_items_app);
+    let scrollview = test
+        .find(|node, element| {
+            Rect::try_downcast(element)
+                .filter(|rect| rect.accessibility.builder.role() == AccessibilityRole::ScrollView)
+                .map(move |_| node)
+        })
+        .unwrap();
+    let content = scrollview.children()[0].children()[0].children();
+
+    // Settles with the center of the second item in the center of the viewport
+    test.scroll((5., 5.), (0., -230.));
+    test.poll(Duration::from_millis(16), Duration::from_millis(600));
+    assert_eq!(content[1].layout().area.min_y(), 50.);
+
+    // Settles with the end of the third item at the end of the viewport
+    test.scroll((5., 5.), (0., -220.));
+    test.poll(Duration::from_millis(16), Duration::from_millis(600));
+    assert_eq!(content[2].layout().area.max_y(), 500.);
+}
+
+#[test]
+pub fn snap_position() {
+    // Mandatory always settles on the closest item
+    assert_eq!(
+        get_snap_position(
+            ScrollSnap::Mandatory,
+            SnapAlign::Start,
+            100.,
+            -130.,
+            1000.,
+            300.
+        ),
+        Some(-100.)
+    );
+    assert_eq!(
+        get_snap_position(
+            ScrollSnap::Mandatory,
+            SnapAlign::Center,
+            100.,
+            -130.,
+            1000.,
+            300.
+        ),
+        Some(-100.)
+    );
+    // Proximity ignores items too far away
+    assert_eq!(
+        get_snap_position(
+            ScrollSnap::Proximity,
+            SnapAlign::Start,
+            100.,
+            -150.,
+            1000.,
+            300.
+        ),
+        None
+    );
+    // Never snaps past the end
+    assert_eq!(
+        get_snap_position(
+            ScrollSnap::Mandatory,
+            SnapAlign::Start,
+            400.,
+            -650.,
+            1000.,
+            300.
+        ),
+        Some(-700.)
+    );
+}
+
+#[test]
+pub fn scroll_view_momentum() {
+    fn scroll_view_momentum_app() -> impl IntoElement {
+        ScrollView::new()
+            .drag_scrolling(true)
+            .children((0..8).map(|_| rect().height(Size::px(200.)).width(Size::px(200.)).into()))
+    }
+
+    let mut test = launch_test(scroll_view_momentum_app);
+    let scrollview = test
+        .find(|node, element| {
+            Rect::try_downcast(element)
+                .filter(|rect| rect.accessibility.builder.role() == AccessibilityRole::ScrollView)
+                .map(move |_| node)
+        })
+        .unwrap();
+    let content = scrollview.children()[0].children()[0].children();
+
+    // Fling upwards, 40px every 16ms
+    test.animation_clock().freeze();
+    test.press_cursor((100., 400.));
+    test.sync_and_update();
+    for y in [360., 320., 280.] {
+        test.animation_clock().advance(Duration::from_millis(16));
+        test.move_cursor((100., y));
+        test.sync_and_update();
+    }
+    test.release_cursor((100., 280.));
+    test.sync_and_update();
+    assert_eq!(content[0].layout().area.min_y(), -120.);
+
+    // Keeps scrolling until reaching the end
+    test.poll(Duration::from_millis(16), Duration::from_millis(1500));
+    assert_eq!(content[0].layout().area.min_y(), -1100.);
+}
+
+#[test]
+pub fn scroll_view_wheel_momentum() {
+    fn scroll_view_wheel_momentum_app() -> impl IntoElement {
+        ScrollView::new()
+            .children((0..10).map(|_| rect().height(Size::px(200.)).width(Size::px(200.)).into()))
+    }
+
+    let mut test = launch_test(scroll_view_wheel_momentum_app);
+    let scrollview = test
+        .find(|node, element| {
+            Rect::try_downcast(element)
+                .filter(|rect| rect.accessibility.builder.role() == AccessibilityRole::ScrollView)
+                .map(move |_| node)
+        })
+        .unwrap();
+    let content = scrollview.children()[0].children()[0].children();
+
+    // Every wheel event scrolls right away
+    for _ in 0..4 {
+        test.scroll((5., 5.), (0., -60.));
+        test.poll_n(Duration::from_millis(16), 1);
+    }
+    assert_eq!(content[0].layout().area.min_y(), -240.);
+
+    // Keeps scrolling once the wheel stops
+    test.poll(Duration::from_millis(16), Duration::from_millis(1500));
+    assert!(content[0].layout().area.min_y() < -240.);
+}
+
+#[test]
+pub fn scroll_view_overscroll() {
+    fn scroll_view_overscroll_app() -> impl IntoElement {
+        ScrollView::new()
+            .drag_scrolling(true)
+            .child(rect().height(Size::px(200.)).width(Size::px(200.)))
+            .child(rect().height(Size::px(200.)).width(Size::px(200.)))
+            .child(rect().height(Size::px(200.)).width(Size::px(200.)))
+            .child(rect().height(Size::px(200.)).width(Size::px(200.)))
+    }
+
+    let mut test = launch_test(scroll_view_overscroll_app);
+    let scrollview = test
+        .find(|node, element| {
+            Rect::try_downcast(element)
+                .filter(|rect| rect.accessibility.builder.role() == AccessibilityRole::ScrollView)
+                .map(move |_| node)
+        })
+        .unwrap();
+    let content = scrollview.children()[0].children()[0].children();
+
+    // Dragging past the start stretches the content with some resistance
+    test.press_cursor((100., 100.));
+    test.sync_and_update();
+    test.move_cursor((100., 300.));
+    test.sync_and_update();
+    assert_eq!(content[0].layout().area.min_y(), 80.);
+
+    // Springs back once released
+    test.release_cursor((100., 300.));
+    test.poll(Duration::from_millis(16), Duration::from_millis(500));
+    assert_eq!(content[0].layout().area.min_y(), 0.);
+}
+
+#[test]
+pub fn scroll_view_scroll_to_animated() {
+    fn scroll_view_scroll_to_animated_app() -> impl IntoElement {
+        let mut scroll_controller = use_scroll_controller(ScrollConfig::default);
+
+        rect()
+            .expanded()
+            .child(
+                rect()
+                    .height(Size::px(100.))
+                    .width(Size::fill())
+                    .on_press(move |_| {
+                        scroll_controller
+                            .scroll_to_animated(ScrollPosition::End, Direction::Vertical)
+                    }),
+            )
+            .child(
+                ScrollView::new_controlled(scroll_controller)
+                    .child(rect().height(Size::px(200.)).width(Size::px(200.)))
+                    .child(rect().height(Size::px(200.)).width(Size::px(200.)))
+                    .child(rect().height(Size::px(200.)).width(Size::px(200.)))
+                    .child(rect().height(Size::px(200.)).width(Size::px(200.))),
+            )
+    }
+
+    let mut test = launch_test(scroll_view_scroll_to_animated_app);
+    let scrollview = test
+        .find(|node, element| {
+            Rect::try_downcast(element)
+                .filter(|rect| rect.accessibility.builder.role() == AccessibilityRole::ScrollView)
+                .map(move |_| node)
+        })
+        .unwrap();
+    let content = scrollview.children()[0].children()[0].children();
+    assert_eq!(content[0].layout().area.min_y(), 100.);
+
+    test.click_cursor((5., 5.));
+    test.poll(Duration::from_millis(16), Duration::from_millis(500));
+    assert_eq!(content[0].layout().area.min_y(), -300.);
+}
+
+#[test]
+pub fn momentum_and_overscroll() {
+    assert_eq!(get_momentum((3., -4.), 0.25), Some(((30., -40.), 20)));
+    // Too fast flings are slowed down
+    assert_eq!(get_momentum((0., 16.), 0.5), Some(((0., 64.), 16)));
+    // Too slow ones don't move at all
+    assert_eq!(get_momentum((0.05, 0.), 0.5), None);
+    assert_eq!(get_momentum((0., 2.), f32::INFINITY), None);
+
+    // Dragging past the start only stretches part of the movement
+    assert_eq!(
+        get_overscrolled_position(50., 0., 0., 800., 500.),
+        (0., 20.)
+    );
+    // Dragging back undoes the overscroll before scrolling
+    assert_eq!(
+        get_overscrolled_position(-10., 0., 20., 800., 500.),
+        (0., 10.)
+    );
+    assert_eq!(
+        get_overscrolled_position(-30., 0., 20., 800., 500.),
+        (-10., 0.)
+    );
+    // Stretches up to a quarter of the viewport
+    assert_eq!(
+        get_overscrolled_position(1000., 0., 0., 800., 500.),
+        (0., 125.)
+    );
+}
+
+#[test]
+pub fn closest_snap_position() {
+    let points = [
+        SnapPoint {
+            start: 0.,
+            size: 300.,
+            align: SnapAlign::Start,
+        },
+        SnapPoint {
+            start: 300.,
+            size: 400.,
+            align: SnapAlign::Center,
+        },
+        SnapPoint {
+            start: 700.,
+            size: 300.,
+            align: SnapAlign::End,
+        },
+    ];
+    // Each item aligns its own edge
+    assert_eq!(
+        get_closest_snap_position(ScrollSnap::Mandatory, &points, -200., 1000., 500.),
+        Some(-250.)
+    );
+    assert_eq!(
+        get_closest_snap_position(ScrollSnap::Mandatory, &points, -450., 1000., 500.),
+        Some(-500.)
+    );
+    // Proximity ignores items too far away
+    assert_eq!(
+        get_closest_snap_position(ScrollSnap::Proximity, &points, -125., 1000., 500.),
+        None
+    );
+}
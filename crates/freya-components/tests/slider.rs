@@ -282,3 +282,46 @@ pub fn slider_disabled() {
     // Value should still be 50
     assert_eq!(value, 50.0);
 }
+
+#[test]
+pub fn slider_a11y_actions() {
+    fn slider_app() -> impl IntoElement {
+        let mut value = use_state(|| 50.0);
+
+        rect()
+            .child(label().text(format!("Value: {}", value() as i32)))
+            .child(
+                Slider::new(move |v| value.set(v))
+                    .value(value())
+                    .size(Size::px(200.)),
+            )
+    }
+
+    let mut test = launch_test(slider_app);
+    test.sync_and_update();
+
+    let slider_id = test
+        .find(|_, element| {
+            let accessibility = element.accessibility();
+            (accessibility.builder.role() == AccessibilityRole::Slider)
+                .then_some(accessibility.a11y_id)
+                .flatten()
+        })
+        .unwrap();
+
+    let value = |test: &TestingRunner| {
+        test.find(|_, element| {
+            Label::try_downcast(element)
+                .filter(|l| l.text.starts_with("Value:"))
+                .map(|l| l.text.to_string())
+        })
+        .unwrap()
+    };
+
+    test.request_a11y_action(slider_id, accesskit::Action::Increment, None);
+    assert_eq!(value(&test), "Value: 54");
+
+    test.request_a11y_action(slider_id, accesskit::Action::Decrement, None);
+    test.request_a11y_action(slider_id, accesskit::Action::Decrement, None);
+    assert_eq!(value(&test), "Value: 46");
+}
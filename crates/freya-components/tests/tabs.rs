@@ -0,0 +1,51 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+fn has_label(test: &TestingRunner, text: &str) -> bool {
+    test.find(|_, element| Label::try_downcast(element).filter(|label| label.text.as_ref() == text))
+        .is_some()
+}
+
+#[test]
+pub fn tabs_keyboard_and_close() {
+    fn app() -> impl IntoElement {
+        let mut selected = use_state(|| 0);
+        let mut tabs = use_state(|| vec!["Home", "Docs", "Blog"]);
+
+        Tabs::new()
+            .selected(selected())
+            .on_select(move |tab| selected.set(tab))
+            .on_close(move |tab| {
+                tabs.write().remove(tab);
+                selected.set(tab.saturating_sub(1));
+            })
+            .tabs(tabs.read().iter().map(|title| Tab::new(*title)))
+            .children(
+                tabs.read()
+                    .iter()
+                    .map(|title| TabPanel::new().child(format!("{title} content")).into()),
+            )
+    }
+
+    let mut test = launch_test(app);
+    test.sync_and_update();
+    assert!(has_label(&test, "Home content"));
+
+    // Focus the strip by pressing the first tab, then move with the arrows
+    test.click_cursor((15., 15.));
+    test.press_key(Key::Named(NamedKey::ArrowRight));
+    assert!(has_label(&test, "Docs content"));
+
+    test.press_key(Key::Named(NamedKey::End));
+    assert!(has_label(&test, "Blog content"));
+
+    // Wraps around to the first tab
+    test.press_key(Key::Named(NamedKey::ArrowRight));
+    assert!(has_label(&test, "Home content"));
+
+    // Delete closes the selected tab
+    test.press_key(Key::Named(NamedKey::ArrowRight));
+    test.press_key(Key::Named(NamedKey::Delete));
+    assert!(!has_label(&test, "Docs"));
+    assert!(has_label(&test, "Home content"));
+}
@@ -0,0 +1,115 @@
+use freya::prelude::*;
+use freya_components::theming::macros::ResolvablePreference;
+use freya_testing::prelude::*;
+
+#[derive(PartialEq)]
+struct ThemeName;
+
+impl Component for ThemeName {
+    fn render(&self) -> impl IntoElement {
+        let theme = get_theme_or_default();
+        label().text(format!("theme={}", theme.read().name))
+    }
+}
+
+#[test]
+pub fn theme_provider_test() {
+    fn theme_app() -> impl IntoElement {
+        ThemeProvider::new(
+            rect()
+                .child(
+                    rect()
+                        .width(Size::px(100.))
+                        .height(Size::px(100.))
+                        .on_press(|_| {
+                            let mut preferred_theme = Platform::get().preferred_theme;
+                            preferred_theme.set(PreferredTheme::Dark);
+                        }),
+                )
+                .child(ThemeName)
+                .child(ThemeProvider::new(ThemeName).theme(Theme::new("custom", LIGHT_COLORS))),
+        )
+    }
+
+    let mut test = launch_test(theme_app);
+    test.sync_and_update();
+
+    let names = |test: &TestingRunner| {
+        test.find_many(|_, element| {
+            Label::try_downcast(element)
+                .filter(|label| label.text.starts_with("theme="))
+                .map(|label| label.text.to_string())
+        })
+    };
+    assert_eq!(names(&test), vec!["theme=light", "theme=custom"]);
+
+    // Following the platform preference, except for the overridden subtree
+    test.click_cursor((50., 50.));
+    assert_eq!(names(&test), vec!["theme=dark", "theme=custom"]);
+}
+
+#[test]
+pub fn design_tokens_test() {
+    let theme = light_theme().with_tokens(
+        DesignTokens::new()
+            .spacing("md", 12.)
+            .radius("md", 8.)
+            .color("brand", Color::RED),
+    );
+
+    let padding = Preference::<Gaps>::Reference("md").resolve(&theme);
+    assert_eq!(padding, Gaps::new_all(12.));
+    let corner_radius = Preference::<CornerRadius>::Reference("md").resolve(&theme);
+    assert_eq!(corner_radius, CornerRadius::new_all(8.));
+    let color = Preference::<Color>::Reference("brand").resolve(&theme);
+    assert_eq!(color, Color::RED);
+    // Colors sheet references keep working
+    let color = Preference::<Color>::Reference("primary").resolve(&theme);
+    assert_eq!(color, theme.colors.primary);
+    // Missing tokens fall back instead of panicking
+    let padding = Preference::<Gaps>::Reference("xl").resolve(&theme);
+    assert_eq!(padding, Gaps::new_all(0.));
+    let corner_radius = Preference::<CornerRadius>::Reference("xl").resolve(&theme);
+    assert_eq!(corner_radius, CornerRadius::default());
+}
+
+#[test]
+pub fn theme_overrides_test() {
+    let overrides = ThemeOverrides::parse(
+        "// Brand\ncolors.primary = #ff0000\n\nspacing.md = 16\nradius.md = 4\n\
+         colors.unknown = #00ff00",
+    )
+    .unwrap();
+
+    let mut theme =
+        light_theme().with_tokens(DesignTokens::new().spacing("md", 12.).radius("md", 8.));
+    let original_primary = theme.colors.primary;
+    let restore = overrides.apply(&mut theme);
+
+    assert_eq!(theme.colors.primary, Color::RED);
+    assert_eq!(theme.tokens.get_spacing("md"), Some(16.));
+    assert_eq!(
+        theme.tokens.get_radius("md"),
+        Some(CornerRadius::new_all(4.))
+    );
+
+    // Restoring keeps the changes made to the theme by others
+    theme.colors.secondary = Color::BLUE;
+    restore.apply(&mut theme);
+    assert_eq!(theme.colors.primary, original_primary);
+    assert_eq!(theme.colors.secondary, Color::BLUE);
+    assert_eq!(theme.tokens.get_spacing("md"), Some(12.));
+    assert_eq!(
+        theme.tokens.get_radius("md"),
+        Some(CornerRadius::new_all(8.))
+    );
+
+    assert_eq!(
+        ThemeOverrides::parse("colors.primary = red"),
+        Err(ThemeOverridesError::InvalidValue(1))
+    );
+    assert_eq!(
+        ThemeOverrides::parse("// Fonts\nfonts.body = 12"),
+        Err(ThemeOverridesError::UnknownSection(2))
+    );
+}
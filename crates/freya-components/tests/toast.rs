@@ -0,0 +1,101 @@
+use std::time::Duration;
+
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[derive(PartialEq)]
+struct ShowToasts(Vec<Toast>);
+
+impl Component for ShowToasts {
+    fn render(&self) -> impl IntoElement {
+        let toasts = use_toast();
+        let to_show = self.0.clone();
+
+        rect()
+            .width(Size::px(100.))
+            .height(Size::px(100.))
+            .on_press(move |_| {
+                for toast in &to_show {
+                    toasts.show(toast.clone());
+                }
+            })
+    }
+}
+
+fn has_label(test: &TestingRunner, text: &str) -> bool {
+    test.find(|node, element| {
+        Label::try_downcast(element)
+            .filter(|l| l.text == text)
+            .map(|_| node)
+    })
+    .is_some()
+}
+
+#[test]
+pub fn toast_auto_dismiss() {
+    fn app() -> impl IntoElement {
+        ToastProvider::new().child(ShowToasts(vec![
+            Toast::new("Saved")
+                .description("All changes were saved")
+                .duration(Duration::from_millis(200)),
+        ]))
+    }
+
+    let mut test = launch_test(app);
+    test.sync_and_update();
+    assert!(!has_label(&test, "Saved"));
+
+    test.click_cursor((50., 50.));
+    assert!(has_label(&test, "Saved"));
+    assert!(has_label(&test, "All changes were saved"));
+
+    // The timer and the exit animation
+    test.poll(Duration::from_millis(16), Duration::from_millis(700));
+    assert!(!has_label(&test, "Saved"));
+}
+
+#[test]
+pub fn toast_queue() {
+    fn app() -> impl IntoElement {
+        ToastProvider::new().max_visible(1).child(ShowToasts(vec![
+            Toast::new("First").persistent(),
+            Toast::new("Second").persistent(),
+        ]))
+    }
+
+    let mut test = launch_test(app);
+    test.sync_and_update();
+
+    test.click_cursor((50., 50.));
+    test.poll(Duration::from_millis(16), Duration::from_millis(300));
+    assert!(has_label(&test, "First"));
+    assert!(!has_label(&test, "Second"));
+
+    // Pressing a toast dismisses it, letting the queued one in
+    test.click_cursor((300., 470.));
+    test.poll(Duration::from_millis(16), Duration::from_millis(400));
+    assert!(!has_label(&test, "First"));
+    assert!(has_label(&test, "Second"));
+}
+
+#[test]
+pub fn toast_hover_pauses_dismiss() {
+    fn app() -> impl IntoElement {
+        ToastProvider::new().child(ShowToasts(vec![
+            Toast::new("Saved").duration(Duration::from_millis(200)),
+        ]))
+    }
+
+    let mut test = launch_test(app);
+    test.sync_and_update();
+
+    test.click_cursor((50., 50.));
+    test.move_cursor((300., 470.));
+    test.poll(Duration::from_millis(16), Duration::from_millis(500));
+    assert!(has_label(&test, "Saved"));
+
+    // Leaving the toast resumes the timer
+    test.move_cursor((50., 50.));
+    test.poll(Duration::from_millis(16), Duration::from_millis(700));
+    assert!(!has_label(&test, "Saved"));
+}
@@ -0,0 +1,65 @@
+use std::time::Duration;
+
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+fn items() -> Vec<TreeItem<&'static str>> {
+    vec![
+        TreeItem::new("fruits", "Fruits").children(vec![
+            TreeItem::new("apple", "Apple"),
+            TreeItem::new("pear", "Pear"),
+        ]),
+        TreeItem::new("vegetables", "Vegetables").lazy(),
+    ]
+}
+
+fn has_label(test: &TestingRunner, text: &str) -> bool {
+    test.find(|_, element| Label::try_downcast(element).filter(|label| label.text.as_ref() == text))
+        .is_some()
+}
+
+#[test]
+pub fn tree_view_expand_and_keyboard() {
+    fn app() -> impl IntoElement {
+        TreeView::new(items())
+    }
+
+    let mut test = launch_test(app);
+    test.sync_and_update();
+    assert!(!has_label(&test, "Apple"));
+
+    // Pressing an item expands it
+    test.click_cursor((20., 14.));
+    assert!(has_label(&test, "Apple"));
+    assert!(has_label(&test, "Pear"));
+
+    // Move to the first child and back to its parent
+    test.press_key(Key::Named(NamedKey::ArrowDown));
+    test.press_key(Key::Named(NamedKey::ArrowLeft));
+
+    // Collapse the parent
+    test.press_key(Key::Named(NamedKey::ArrowLeft));
+    assert!(!has_label(&test, "Apple"));
+
+    // And expand it again
+    test.press_key(Key::Named(NamedKey::ArrowRight));
+    assert!(has_label(&test, "Apple"));
+}
+
+#[test]
+pub fn tree_view_lazy_children() {
+    fn app() -> impl IntoElement {
+        TreeView::new(items()).load_children(|id| async move {
+            vec![TreeItem::new("carrot", format!("Carrot of {id}"))]
+        })
+    }
+
+    let mut test = launch_test(app);
+    test.sync_and_update();
+
+    // Expand the lazy item, the second row
+    test.click_cursor((20., 42.));
+    test.poll(Duration::from_millis(1), Duration::from_millis(10));
+    test.sync_and_update();
+    assert!(has_label(&test, "Carrot of vegetables"));
+}
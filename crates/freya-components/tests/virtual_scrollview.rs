@@ -447,3 +447,101 @@ pub fn virtual_scroll_view_keyboard_navigation_horizontal() {
         "0"
     );
 }
+
+#[test]
+pub fn virtual_scroll_view_dynamic_item_size() {
+    fn virtual_scroll_view_dynamic_app() -> impl IntoElement {
+        VirtualScrollView::new(|i, _| {
+            let height = if i % 2 == 0 { 100. } else { 50. };
+            label()
+                .key(i)
+                .height(Size::px(height))
+                .text(format!("{i} Hello, World!"))
+                .into()
+        })
+        .length(30usize)
+        .item_size(50.)
+        .dynamic_item_size(true)
+    }
+
+    let mut test = launch_test(virtual_scroll_view_dynamic_app);
+    test.sync_and_update();
+    test.sync_and_update();
+    let scrollview = test
+        .find(|node, element| {
+            Rect::try_downcast(element)
+                .filter(|rect| rect.accessibility.builder.role() == AccessibilityRole::ScrollView)
+                .map(move |_| node)
+        })
+        .unwrap();
+
+    let labels = || {
+        scrollview.children()[0].children()[0]
+            .children()
+            .iter()
+            .map(|item| {
+                Label::try_downcast(&*item.children()[0].element())
+                    .unwrap()
+                    .text
+                    .to_string()
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // Once measured, only the items covering the 500 pixels of the viewport are rendered
+    let items = labels();
+    assert_eq!(items.len(), 7);
+    assert_eq!(items[0], "0 Hello, World!");
+
+    // 300 pixels are the first 4 items (100 + 50 + 100 + 50)
+    test.scroll((5., 5.), (0., -300.));
+    test.sync_and_update();
+
+    let items = labels();
+    assert_eq!(items.len(), 7);
+    assert_eq!(items[0], "4 Hello, World!");
+}
+
+#[test]
+pub fn virtual_scroll_view_dynamic_item_size_anchoring() {
+    fn virtual_scroll_view_dynamic_app() -> impl IntoElement {
+        VirtualScrollView::new(|i, _| {
+            let height = if i % 2 == 0 { 100. } else { 50. };
+            label()
+                .key(i)
+                .height(Size::px(height))
+                .text(format!("{i} Hello, World!"))
+                .into()
+        })
+        .length(30usize)
+        .item_size(50.)
+        .dynamic_item_size(true)
+    }
+
+    let mut test = launch_test(virtual_scroll_view_dynamic_app);
+    test.sync_and_update();
+    test.sync_and_update();
+
+    let label_y = |test: &TestingRunner, text: &str| {
+        test.find(|node, element| {
+            Label::try_downcast(element)
+                .filter(|label| label.text == text)
+                .map(move |_| node)
+        })
+        .map(|node| node.layout().area.min_y())
+    };
+
+    // Jump over items 10 to 14, which stay estimated at 50 pixels
+    test.scroll((5., 5.), (0., -1000.));
+    test.sync_and_update();
+    test.sync_and_update();
+
+    // Item 12 is measured at 100 pixels while it is still above the viewport,
+    // so the scroll is moved to keep item 13 where it was placed with the estimation
+    test.scroll((5., 5.), (0., 120.));
+    test.sync_and_update();
+    test.sync_and_update();
+    test.sync_and_update();
+
+    assert_eq!(label_y(&test, "13 Hello, World!"), Some(20.));
+}
@@ -15,12 +15,14 @@ use crate::{
         Platform,
     },
     prelude::{
+        AccessibilityFocusMovement,
         AccessibilityFocusStrategy,
         KeyboardEventData,
         Memo,
         ScreenReader,
         UserEvent,
         consume_root_context,
+        use_drop,
         use_hook,
         use_memo,
     },
@@ -95,6 +97,31 @@ pub fn use_focus() -> Focus {
     use_hook(Focus::create)
 }
 
+/// Trap the keyboard navigation inside the element marked with
+/// [`a11y_focus_scope`](crate::prelude::AccessibilityExt::a11y_focus_scope) for as long as
+/// this component lives.
+///
+/// The first focusable element of the scope gets focused when created, and the element
+/// that was focused before gets it back when dropped.
+pub fn use_focus_scope() {
+    let platform = use_hook(Platform::get);
+    let previous = use_hook({
+        let platform = platform.clone();
+        move || {
+            let previous = *platform.focused_accessibility_id.peek();
+            platform.send(UserEvent::FocusAccessibilityNode(
+                AccessibilityFocusStrategy::Forward(AccessibilityFocusMovement::OutsideGroup),
+            ));
+            previous
+        }
+    });
+    use_drop(move || {
+        platform.send(UserEvent::FocusAccessibilityNode(
+            AccessibilityFocusStrategy::Node(previous),
+        ));
+    });
+}
+
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum FocusStatus {
     Not,
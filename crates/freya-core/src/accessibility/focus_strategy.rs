@@ -11,6 +11,17 @@ pub enum AccessibilityFocusStrategy {
     Forward(AccessibilityFocusMovement),
     Backward(AccessibilityFocusMovement),
     Node(accesskit::NodeId),
+    /// Move between the given nodes in the order of the tree, wrapping around.
+    Among(Vec<accesskit::NodeId>, AccessibilityFocusTarget),
+}
+
+/// Node to focus of those given to [AccessibilityFocusStrategy::Among].
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum AccessibilityFocusTarget {
+    Next,
+    Previous,
+    First,
+    Last,
 }
 
 impl AccessibilityFocusStrategy {
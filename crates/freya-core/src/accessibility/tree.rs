@@ -1,8 +1,13 @@
+use std::any::Any;
+
 use accesskit::{
     Action,
+    ActionData,
+    Affine,
     Node,
     Rect,
     Role,
+    TextPosition,
     TreeId,
     TreeUpdate,
 };
@@ -18,11 +23,17 @@ use torin::prelude::{
 
 use crate::{
     accessibility::{
-        focus_strategy::AccessibilityFocusStrategy,
+        focus_strategy::{
+            AccessibilityFocusStrategy,
+            AccessibilityFocusTarget,
+        },
         focusable::Focusable,
         id::AccessibilityId,
     },
-    elements::label::Label,
+    elements::{
+        label::Label,
+        paragraph::ParagraphElement,
+    },
     events::emittable::EmmitableEvent,
     integration::{
         EventName,
@@ -30,6 +41,7 @@ use crate::{
     },
     node_id::NodeId,
     prelude::{
+        AccessibilityActionEventData,
         AccessibilityFocusMovement,
         EventType,
         Paragraph,
@@ -41,6 +53,9 @@ use crate::{
 
 pub const ACCESSIBILITY_ROOT_ID: AccessibilityId = AccessibilityId(0);
 
+/// Set in the [AccessibilityId] of the text run node created for every paragraph.
+const TEXT_RUN_FLAG: u64 = 1 << 63;
+
 pub struct AccessibilityTree {
     pub map: FxHashMap<AccessibilityId, NodeId>,
     // Current focused Accessibility Node.
@@ -65,17 +80,29 @@ impl AccessibilityTree {
         self.map.get(&self.focused_id).cloned()
     }
 
+    /// [AccessibilityId]s of the ancestors of the focused node, from the root down.
+    pub fn focused_ancestors(&self, tree: &Tree) -> Vec<AccessibilityId> {
+        let mut ancestors = Vec::new();
+        let mut node_id = self.focused_node_id();
+        while let Some(parent_id) = node_id.and_then(|node_id| tree.parents.get(&node_id)) {
+            if let Some(accessibility_state) = tree.accessibility_state.get(parent_id) {
+                ancestors.push(accessibility_state.a11y_id);
+            }
+            node_id = Some(*parent_id);
+        }
+        ancestors.reverse();
+        ancestors
+    }
+
     /// Initialize the Accessibility Tree
-    pub fn init(&mut self, tree: &mut Tree) -> TreeUpdate {
+    pub fn init(&mut self, tree: &mut Tree, scale_factor: f64) -> TreeUpdate {
         tree.accessibility_diff.clear();
 
         let mut nodes = vec![];
 
         tree.traverse_depth(|node_id| {
             let accessibility_state = tree.accessibility_state.get(&node_id).unwrap();
-            let layout_node = tree.layout.get(&node_id).unwrap();
-            let accessibility_node = Self::create_node(node_id, layout_node, tree);
-            nodes.push((accessibility_state.a11y_id, accessibility_node));
+            Self::push_node(&mut nodes, node_id, tree, scale_factor);
             self.map.insert(accessibility_state.a11y_id, node_id);
         });
 
@@ -103,6 +130,7 @@ impl AccessibilityTree {
         &mut self,
         tree: &mut Tree,
         events_sender: &futures_channel::mpsc::UnboundedSender<EventsChunk>,
+        scale_factor: f64,
     ) -> TreeUpdate {
         let requested_focus = tree.accessibility_diff.requested_focus.take();
         let removed_ids = tree
@@ -147,13 +175,30 @@ impl AccessibilityTree {
             added_or_updated_ids.insert(*node_parent_id);
         }
 
+        // Text inputs report the selection of the paragraphs inside them
+        for node_id in added_or_updated_ids.clone() {
+            if tree
+                .elements
+                .get(&node_id)
+                .is_some_and(|element| (element.as_ref() as &dyn Any).is::<ParagraphElement>())
+            {
+                let mut ancestor_id = tree.parents.get(&node_id);
+                while let Some(parent_id) = ancestor_id {
+                    if tree.elements.get(parent_id).is_some_and(|element| {
+                        is_text_input(element.accessibility().builder.role())
+                    }) {
+                        added_or_updated_ids.insert(*parent_id);
+                        break;
+                    }
+                    ancestor_id = tree.parents.get(parent_id);
+                }
+            }
+        }
+
         // Create the updated nodes
         let mut nodes = Vec::new();
         for node_id in added_or_updated_ids {
-            let accessibility_state = tree.accessibility_state.get(&node_id).unwrap();
-            let layout_node = tree.layout.get(&node_id).unwrap();
-            let accessibility_node = Self::create_node(node_id, layout_node, tree);
-            nodes.push((accessibility_state.a11y_id, accessibility_node));
+            Self::push_node(&mut nodes, node_id, tree, scale_factor);
         }
 
         let has_request_focus = requested_focus.is_some();
@@ -195,6 +240,38 @@ impl AccessibilityTree {
             return;
         }
 
+        if let AccessibilityFocusStrategy::Among(ids, target) = &strategy {
+            let mut nodes = Vec::new();
+            tree.traverse_depth(|node_id| {
+                let accessibility_state = tree.accessibility_state.get(&node_id).unwrap();
+                if accessibility_state.a11y_focusable == Focusable::Enabled
+                    && ids.contains(&accessibility_state.a11y_id)
+                {
+                    nodes.push(accessibility_state.a11y_id);
+                }
+            });
+
+            let node_index = nodes.iter().position(|id| *id == self.focused_id);
+            let target_node = match (target, node_index) {
+                (AccessibilityFocusTarget::Next, Some(node_index)) => {
+                    nodes.get((node_index + 1) % nodes.len())
+                }
+                (AccessibilityFocusTarget::Previous, Some(node_index)) => {
+                    nodes.get((node_index + nodes.len() - 1) % nodes.len())
+                }
+                (AccessibilityFocusTarget::Next | AccessibilityFocusTarget::First, _) => {
+                    nodes.first()
+                }
+                (AccessibilityFocusTarget::Previous | AccessibilityFocusTarget::Last, _) => {
+                    nodes.last()
+                }
+            };
+            if let Some(target_node) = target_node {
+                self.focused_id = *target_node;
+            }
+            return;
+        }
+
         let (navigable_nodes, focused_id) = if strategy.mode()
             == Some(AccessibilityFocusMovement::InsideGroup)
         {
@@ -222,7 +299,16 @@ impl AccessibilityTree {
         } else {
             let mut nodes = Vec::new();
 
+            // Only navigate inside the last focus scope in depth-first order, if any
+            let mut scope = NodeId::ROOT;
             tree.traverse_depth(|node_id| {
+                let accessibility_state = tree.accessibility_state.get(&node_id).unwrap();
+                if accessibility_state.a11y_focus_scope {
+                    scope = node_id;
+                }
+            });
+
+            tree.traverse_depth_from(scope, |node_id| {
                 let accessibility_state = tree.accessibility_state.get(&node_id).unwrap();
                 let member_accessibility_id = accessibility_state.a11y_member_of;
 
@@ -232,12 +318,25 @@ impl AccessibilityTree {
                 {
                     return;
                 }
-                if accessibility_state.a11y_focusable == Focusable::Enabled {
-                    nodes.push(accessibility_state.a11y_id);
+                // Skipped nodes still count when focused, so `Tab` continues from them
+                if accessibility_state.a11y_focusable == Focusable::Enabled
+                    && (accessibility_state.a11y_tab_index >= 0
+                        || accessibility_state.a11y_id == self.focused_id)
+                {
+                    nodes.push((
+                        accessibility_state.a11y_id,
+                        accessibility_state.a11y_tab_index.max(0),
+                    ));
                 }
             });
 
-            (nodes, self.focused_id)
+            // Positive tab indices go first, the rest keep the tree order
+            nodes.sort_by_key(|(_, tab_index)| (*tab_index == 0, *tab_index));
+
+            (
+                nodes.into_iter().map(|(id, _)| id).collect(),
+                self.focused_id,
+            )
         };
 
         let node_index = navigable_nodes
@@ -278,6 +377,73 @@ impl AccessibilityTree {
         tracing::info!("Focused {:?} node.", self.focused_id);
     }
 
+    /// Handle an action requested by an assistive technology on the given node.
+    ///
+    /// Focus and scroll requests are handled here, the rest are emitted as
+    /// [EventName::AccessibilityAction] events to the node. Returns whether the
+    /// accessibility tree needs to be processed again, see [AccessibilityTree::process_updates].
+    pub fn process_action(
+        &mut self,
+        action: Action,
+        target: AccessibilityId,
+        data: Option<ActionData>,
+        tree: &mut Tree,
+        events_sender: &futures_channel::mpsc::UnboundedSender<EventsChunk>,
+    ) -> bool {
+        let Some(node_id) = self.map.get(&target).copied() else {
+            return false;
+        };
+
+        match action {
+            Action::Focus => {
+                tree.accessibility_diff
+                    .request_focus(AccessibilityFocusStrategy::Node(target));
+                true
+            }
+            Action::ScrollIntoView => {
+                self.scroll_to(node_id, tree, events_sender);
+                false
+            }
+            action => {
+                let mut event_data = AccessibilityActionEventData::new(action, data.clone());
+                if let Some(ActionData::SetTextSelection(selection)) = data {
+                    event_data.text_selection = self
+                        .text_position_to_utf16(selection.anchor, tree)
+                        .zip(self.text_position_to_utf16(selection.focus, tree));
+                }
+                events_sender
+                    .unbounded_send(EventsChunk::Processed(ProcessedEvents {
+                        emmitable_events: vec![EmmitableEvent {
+                            name: EventName::AccessibilityAction,
+                            source_event: EventName::AccessibilityAction,
+                            node_id,
+                            data: EventType::AccessibilityAction(event_data),
+                            bubbles: false,
+                        }],
+                        ..Default::default()
+                    }))
+                    .ok();
+                false
+            }
+        }
+    }
+
+    /// Offset in UTF-16 code units in its paragraph of a position in a text run.
+    fn text_position_to_utf16(&self, position: TextPosition, tree: &Tree) -> Option<usize> {
+        if position.node.0 & TEXT_RUN_FLAG == 0 {
+            return None;
+        }
+        let node_id = self
+            .map
+            .get(&AccessibilityId(position.node.0 & !TEXT_RUN_FLAG))?;
+        let element = tree.elements.get(node_id)?;
+        let paragraph = (element.as_ref() as &dyn Any).downcast_ref::<ParagraphElement>()?;
+        Some(char_to_utf16_index(
+            &paragraph_text(paragraph),
+            position.character_index,
+        ))
+    }
+
     /// Send the necessary wheel events to scroll views so that the given focused [NodeId] is visible on screen.
     fn scroll_to(
         &self,
@@ -345,8 +511,88 @@ impl AccessibilityTree {
             .unwrap();
     }
 
+    /// Create the accessibility node of the given [NodeId], plus the text run of paragraphs.
+    fn push_node(
+        nodes: &mut Vec<(AccessibilityId, Node)>,
+        node_id: NodeId,
+        tree: &Tree,
+        scale_factor: f64,
+    ) {
+        let accessibility_state = tree.accessibility_state.get(&node_id).unwrap();
+        let layout_node = tree.layout.get(&node_id).unwrap();
+        let accessibility_node = Self::create_node(node_id, layout_node, tree, scale_factor);
+        nodes.push((accessibility_state.a11y_id, accessibility_node));
+
+        if let Some(paragraph) = tree
+            .elements
+            .get(&node_id)
+            .and_then(|element| (element.as_ref() as &dyn Any).downcast_ref::<ParagraphElement>())
+        {
+            nodes.push((
+                Self::text_run_id(accessibility_state.a11y_id),
+                Self::create_text_run(paragraph, layout_node),
+            ));
+        }
+    }
+
+    /// [AccessibilityId] of the text run node of the paragraph with the given [AccessibilityId].
+    pub fn text_run_id(a11y_id: AccessibilityId) -> AccessibilityId {
+        AccessibilityId(a11y_id.0 | TEXT_RUN_FLAG)
+    }
+
+    /// Text run with the text of a paragraph, which is what screen readers read and navigate.
+    fn create_text_run(paragraph: &ParagraphElement, layout_node: &LayoutNode) -> Node {
+        let text = paragraph_text(paragraph);
+        let area = layout_node.area.to_f64();
+        let mut node = Node::new(Role::TextRun);
+        node.set_bounds(Rect {
+            x0: area.min_x(),
+            x1: area.max_x(),
+            y0: area.min_y(),
+            y1: area.max_y(),
+        });
+        node.set_character_lengths(
+            text.chars()
+                .map(|character| character.len_utf8() as u8)
+                .collect::<Vec<_>>(),
+        );
+        node.set_word_lengths(word_lengths(&text));
+        node.set_value(text);
+        node
+    }
+
+    /// Selection of a paragraph with a cursor, in the characters of its text run.
+    fn paragraph_text_selection(
+        a11y_id: AccessibilityId,
+        paragraph: &ParagraphElement,
+    ) -> Option<accesskit::TextSelection> {
+        let cursor_index = paragraph.cursor_index?;
+        let text = paragraph_text(paragraph);
+        let (anchor, focus) = paragraph
+            .highlights
+            .first()
+            .copied()
+            .unwrap_or((cursor_index, cursor_index));
+        let node = Self::text_run_id(a11y_id);
+        Some(accesskit::TextSelection {
+            anchor: TextPosition {
+                node,
+                character_index: utf16_to_char_index(&text, anchor),
+            },
+            focus: TextPosition {
+                node,
+                character_index: utf16_to_char_index(&text, focus),
+            },
+        })
+    }
+
     /// Create an accessibility node
-    pub fn create_node(node_id: NodeId, layout_node: &LayoutNode, tree: &Tree) -> Node {
+    pub fn create_node(
+        node_id: NodeId,
+        layout_node: &LayoutNode,
+        tree: &Tree,
+        scale_factor: f64,
+    ) -> Node {
         let element = tree.elements.get(&node_id).unwrap();
         let mut accessibility_data = element.accessibility().into_owned();
 
@@ -355,7 +601,7 @@ impl AccessibilityTree {
         }
 
         // Set children
-        let children = tree
+        let mut children = tree
             .children
             .get(&node_id)
             .cloned()
@@ -363,8 +609,41 @@ impl AccessibilityTree {
             .into_iter()
             .map(|child| tree.accessibility_state.get(&child).unwrap().a11y_id)
             .collect::<Vec<_>>();
+        let paragraph = (element.as_ref() as &dyn Any).downcast_ref::<ParagraphElement>();
+        let a11y_id = tree.accessibility_state.get(&node_id).unwrap().a11y_id;
+        if paragraph.is_some() {
+            children.push(Self::text_run_id(a11y_id));
+        }
         accessibility_data.builder.set_children(children);
 
+        // Report the caret and selection of the paragraph, or of the one inside a text input
+        if let Some(paragraph) = paragraph {
+            if let Some(selection) = Self::paragraph_text_selection(a11y_id, paragraph) {
+                accessibility_data.builder.set_text_selection(selection);
+                accessibility_data
+                    .builder
+                    .add_action(Action::SetTextSelection);
+            }
+        } else if is_text_input(accessibility_data.builder.role()) {
+            let mut selection = None;
+            tree.traverse_depth_from(node_id, |child_id| {
+                if selection.is_none()
+                    && let Some(paragraph) = tree.elements.get(&child_id).and_then(|element| {
+                        (element.as_ref() as &dyn Any).downcast_ref::<ParagraphElement>()
+                    })
+                {
+                    let child_a11y_id = tree.accessibility_state.get(&child_id).unwrap().a11y_id;
+                    selection = Self::paragraph_text_selection(child_a11y_id, paragraph);
+                }
+            });
+            if let Some(selection) = selection {
+                accessibility_data.builder.set_text_selection(selection);
+                accessibility_data
+                    .builder
+                    .add_action(Action::SetTextSelection);
+            }
+        }
+
         // Set the area
         let area = layout_node.area.to_f64();
         accessibility_data.builder.set_bounds(Rect {
@@ -401,16 +680,21 @@ impl AccessibilityTree {
             // accessibility_data.builder.add_action(Action::Click);
         }
 
-        // // Rotation transform
-        // if let Some((_, rotation)) = transform_state
-        //     .rotations
-        //     .iter()
-        //     .find(|(id, _)| id == &node_ref.id())
-        // {
-        //     let rotation = rotation.to_radians() as f64;
-        //     let (s, c) = rotation.sin_cos();
-        //     builder.set_transform(Affine::new([c, s, -s, c, 0.0, 0.0]));
-        // }
+        // Rotation and transform of this node, the ones of its ancestors are inherited
+        if let Some(effect_state) = tree.effect_state.get(&node_id)
+            && effect_state.transforms.last() == Some(&node_id)
+        {
+            let matrix =
+                effect_state.local_matrix(&layout_node.visible_area(), scale_factor as f32);
+            accessibility_data.builder.set_transform(Affine::new([
+                matrix.scale_x() as f64,
+                matrix.skew_y() as f64,
+                matrix.skew_x() as f64,
+                matrix.scale_y() as f64,
+                matrix.translate_x() as f64,
+                matrix.translate_y() as f64,
+            ]));
+        }
 
         // // Clipping overflow
         // if style_state.overflow == OverflowMode::Clip {
@@ -512,3 +796,65 @@ impl AccessibilityTree {
 //         TextDecorationStyle::Wavy => accesskit::TextDecoration::Wavy,
 //     }
 // }
+
+pub(crate) fn is_text_input(role: Role) -> bool {
+    matches!(
+        role,
+        Role::TextInput
+            | Role::MultilineTextInput
+            | Role::PasswordInput
+            | Role::SearchInput
+            | Role::EmailInput
+            | Role::NumberInput
+            | Role::PhoneNumberInput
+            | Role::UrlInput
+            | Role::Terminal
+    )
+}
+
+fn paragraph_text(paragraph: &ParagraphElement) -> String {
+    paragraph
+        .spans
+        .iter()
+        .map(|span| span.text.as_ref())
+        .collect()
+}
+
+/// Convert an offset in UTF-16 code units, like the cursor of a paragraph, to a character index.
+fn utf16_to_char_index(text: &str, utf16_index: usize) -> usize {
+    let mut utf16_len = 0;
+    text.chars()
+        .take_while(|character| {
+            utf16_len += character.len_utf16();
+            utf16_len <= utf16_index
+        })
+        .count()
+}
+
+/// Convert a character index to an offset in UTF-16 code units.
+fn char_to_utf16_index(text: &str, char_index: usize) -> usize {
+    text.chars()
+        .take(char_index)
+        .map(|character| character.len_utf16())
+        .sum()
+}
+
+/// Length in characters of every word, including its trailing whitespace.
+fn word_lengths(text: &str) -> Vec<u8> {
+    let mut lengths = Vec::new();
+    let mut length = 0u8;
+    let mut in_whitespace = false;
+    for character in text.chars() {
+        let is_whitespace = character.is_whitespace();
+        if (in_whitespace && !is_whitespace) || length == u8::MAX {
+            lengths.push(length);
+            length = 0;
+        }
+        in_whitespace = is_whitespace;
+        length += 1;
+    }
+    if length > 0 {
+        lengths.push(length);
+    }
+    lengths
+}
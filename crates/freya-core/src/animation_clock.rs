@@ -1,12 +1,16 @@
 use std::{
     sync::{
         Arc,
+        Mutex,
         atomic::{
             AtomicU32,
             Ordering,
         },
     },
-    time::Duration,
+    time::{
+        Duration,
+        Instant,
+    },
 };
 
 use tracing::info;
@@ -14,7 +18,11 @@ use tracing::info;
 use crate::prelude::consume_root_context;
 
 #[derive(Clone)]
-pub struct AnimationClock(Arc<AtomicU32>);
+pub struct AnimationClock {
+    speed: Arc<AtomicU32>,
+    /// Time set manually with [AnimationClock::freeze], used instead of the real one.
+    manual_time: Arc<Mutex<Option<Instant>>>,
+}
 
 impl Default for AnimationClock {
     fn default() -> Self {
@@ -33,34 +41,78 @@ impl AnimationClock {
     }
 
     pub fn new() -> Self {
-        Self(Arc::new(AtomicU32::new(Self::DEFAULT_SPEED.to_bits())))
+        Self {
+            speed: Arc::new(AtomicU32::new(Self::DEFAULT_SPEED.to_bits())),
+            manual_time: Arc::default(),
+        }
     }
 
     pub fn speed(&self) -> f32 {
-        let bits = self.0.load(Ordering::Relaxed);
+        let bits = self.speed.load(Ordering::Relaxed);
         (f32::from_bits(bits) * 100.0).round() / 100.0
     }
 
     pub fn enable(&self) {
-        self.0
+        self.speed
             .store(Self::DEFAULT_SPEED.to_bits(), Ordering::Relaxed);
         info!("Animation clock speed enabled");
     }
 
     pub fn disable(&self) {
-        self.0
+        self.speed
             .store(Self::DISABLED_SPEED.to_bits(), Ordering::Relaxed);
         info!("Animation clock speed disabled");
     }
 
     pub fn set_speed(&self, speed: f32) {
         let speed = speed.clamp(Self::MIN_SPEED, Self::MAX_SPEED);
-        self.0.store(speed.to_bits(), Ordering::Relaxed);
+        self.speed.store(speed.to_bits(), Ordering::Relaxed);
         info!("Animation clock speed changed to {:.2}x", speed);
     }
 
+    /// Current time of the animations, the real one unless the clock is frozen.
+    pub fn now(&self) -> Instant {
+        self.manual_time
+            .lock()
+            .ok()
+            .and_then(|manual_time| *manual_time)
+            .unwrap_or_else(Instant::now)
+    }
+
+    /// Stop following the real time, so it only moves with [AnimationClock::advance].
+    /// Useful to test animations deterministically.
+    pub fn freeze(&self) {
+        if let Ok(mut manual_time) = self.manual_time.lock() {
+            manual_time.get_or_insert_with(Instant::now);
+        }
+    }
+
+    /// Follow the real time again.
+    pub fn unfreeze(&self) {
+        if let Ok(mut manual_time) = self.manual_time.lock() {
+            *manual_time = None;
+        }
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.manual_time
+            .lock()
+            .is_ok_and(|manual_time| manual_time.is_some())
+    }
+
+    /// Move the time of a frozen clock forward.
+    pub fn advance(&self, duration: Duration) {
+        if let Ok(mut manual_time) = self.manual_time.lock()
+            && let Some(manual_time) = manual_time.as_mut()
+        {
+            *manual_time += duration;
+        }
+    }
+
+    /// Scale a real elapsed time by the speed of the clock.
     pub fn correct_elapsed_duration(&self, elapsed: Duration) -> Duration {
-        let scaled_secs = elapsed.as_secs_f32() * self.speed();
-        Duration::from_secs_f32(scaled_secs)
+        // f64 keeps whole milliseconds exact at the default speed
+        let scaled_secs = elapsed.as_secs_f64() * self.speed() as f64;
+        Duration::try_from_secs_f64(scaled_secs).unwrap_or(Duration::MAX)
     }
 }
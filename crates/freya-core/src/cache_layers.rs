@@ -0,0 +1,71 @@
+use std::cell::RefCell;
+
+use freya_engine::prelude::Picture;
+use rustc_hash::FxHashMap;
+use torin::prelude::Area;
+
+use crate::node_id::NodeId;
+
+/// Painting of a subtree recorded by the render pipeline.
+///
+/// Elements of other subtrees can be painted in between the elements of a cached subtree,
+/// so a subtree is recorded in one painting for every run of its elements that is painted without interruptions.
+pub struct CachedLayer {
+    pub picture: Picture,
+    /// Elements painted in the recording, in paint order.
+    pub nodes: Vec<NodeId>,
+    /// Visible area of the cached element when it was recorded.
+    pub area: Area,
+    pub scale_factor: f64,
+}
+
+/// Recorded paintings of the elements with
+/// [cache_layer](crate::prelude::EffectExt::cache_layer) enabled.
+#[derive(Default)]
+pub struct CacheLayers(RefCell<FxHashMap<NodeId, Vec<CachedLayer>>>);
+
+impl CacheLayers {
+    /// Get the recorded painting of the given run of elements of the given cached element,
+    /// if it was recorded with the same area and scale factor.
+    pub fn get(
+        &self,
+        node_id: &NodeId,
+        nodes: &[NodeId],
+        area: &Area,
+        scale_factor: f64,
+    ) -> Option<Picture> {
+        self.0
+            .borrow()
+            .get(node_id)?
+            .iter()
+            .find(|layer| {
+                layer.nodes == nodes && layer.area == *area && layer.scale_factor == scale_factor
+            })
+            .map(|layer| layer.picture.clone())
+    }
+
+    /// Store the recording of a run of elements, replacing the older recordings of any of them.
+    pub fn insert(&self, node_id: NodeId, layer: CachedLayer) {
+        let mut layers = self.0.borrow_mut();
+        let layers = layers.entry(node_id).or_default();
+        layers.retain(|cached| !cached.nodes.iter().any(|node| layer.nodes.contains(node)));
+        layers.push(layer);
+    }
+
+    /// Discard the recorded paintings of the given element, if any.
+    pub fn invalidate(&mut self, node_id: &NodeId) {
+        self.0.get_mut().remove(node_id);
+    }
+
+    pub fn contains(&self, node_id: &NodeId) -> bool {
+        self.0.borrow().contains_key(node_id)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.borrow().is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.borrow().len()
+    }
+}
@@ -1,4 +1,29 @@
+use std::{
+    fmt::{
+        self,
+        Debug,
+    },
+    hash::{
+        Hash,
+        Hasher,
+    },
+    sync::atomic::{
+        AtomicU64,
+        Ordering,
+    },
+};
+
+use bytes::Bytes;
 use cursor_icon::CursorIcon;
+use freya_engine::prelude::{
+    AlphaType,
+    ColorType,
+    ImageInfo,
+    SkData,
+    SkImage,
+    raster_n32_premul,
+};
+use ragnarok::CursorPoint;
 
 use crate::{
     platform::Platform,
@@ -12,4 +37,208 @@ impl Cursor {
         let platform = Platform::get();
         platform.send(UserEvent::SetCursorIcon(cursor_icon));
     }
+
+    /// Use an image as cursor, see [CustomCursor].
+    pub fn set_custom(custom_cursor: CustomCursor) {
+        let platform = Platform::get();
+        platform.send(UserEvent::SetCustomCursor(custom_cursor));
+    }
+
+    /// Set a built-in or a custom cursor.
+    pub fn set_mouse_cursor(mouse_cursor: impl Into<MouseCursor>) {
+        let platform = Platform::get();
+        platform.send(UserEvent::from(mouse_cursor.into()));
+    }
+}
+
+/// Cursor made out of an image.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> impl IntoElement {
+///     let brush = use_hook(|| {
+///         CustomCursor::from_encoded(include_bytes!("../../../examples/rust_logo.png"), (8, 8))
+///     });
+///
+///     rect()
+///         .expanded()
+///         .map(brush.clone(), |rect, brush| rect.cursor(brush))
+/// }
+/// ```
+#[derive(Clone)]
+pub struct CustomCursor {
+    id: u64,
+    rgba: Bytes,
+    width: u16,
+    height: u16,
+    hotspot: (u16, u16),
+}
+
+static NEXT_CUSTOM_CURSOR_ID: AtomicU64 = AtomicU64::new(0);
+
+impl PartialEq for CustomCursor {
+    fn eq(&self, other: &Self) -> bool {
+        // Only the pixels of cursors created separately need to be compared
+        self.id == other.id
+            || (self.width == other.width
+                && self.height == other.height
+                && self.hotspot == other.hotspot
+                && self.rgba == other.rgba)
+    }
+}
+
+impl Eq for CustomCursor {}
+
+impl Hash for CustomCursor {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.width.hash(state);
+        self.height.hash(state);
+        self.hotspot.hash(state);
+    }
+}
+
+impl Debug for CustomCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CustomCursor")
+            .field("id", &self.id)
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("hotspot", &self.hotspot)
+            .finish()
+    }
+}
+
+impl CustomCursor {
+    /// Create a cursor from unpremultiplied RGBA pixels, row by row.
+    /// The `hotspot` is the pixel that points at things, `None` if the pixels
+    /// don't match the size or the hotspot is outside of the image.
+    pub fn from_rgba(
+        rgba: impl Into<Bytes>,
+        width: u16,
+        height: u16,
+        hotspot: (u16, u16),
+    ) -> Option<Self> {
+        let rgba = rgba.into();
+        if rgba.len() != width as usize * height as usize * 4
+            || hotspot.0 >= width
+            || hotspot.1 >= height
+        {
+            return None;
+        }
+        Some(Self {
+            id: NEXT_CUSTOM_CURSOR_ID.fetch_add(1, Ordering::Relaxed),
+            rgba,
+            width,
+            height,
+            hotspot,
+        })
+    }
+
+    /// Decode an encoded image such as a PNG to use it as cursor, `None` if it can't be decoded.
+    pub fn from_encoded(bytes: impl AsRef<[u8]>, hotspot: (u16, u16)) -> Option<Self> {
+        let image = SkImage::from_encoded(SkData::new_copy(bytes.as_ref()))?;
+        let width = u16::try_from(image.width()).ok()?;
+        let height = u16::try_from(image.height()).ok()?;
+
+        let mut surface = raster_n32_premul((image.width(), image.height()))?;
+        surface.canvas().draw_image(&image, (0., 0.), None);
+
+        let info = ImageInfo::new(
+            (image.width(), image.height()),
+            ColorType::RGBA8888,
+            AlphaType::Unpremul,
+            None,
+        );
+        let row_bytes = width as usize * 4;
+        let mut pixels = vec![0; row_bytes * height as usize];
+        if !surface.read_pixels(&info, &mut pixels, row_bytes, (0, 0)) {
+            return None;
+        }
+
+        Self::from_rgba(pixels, width, height, hotspot)
+    }
+
+    /// Identifier shared by the clones of this cursor, cheap to compare and hash.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Unpremultiplied RGBA pixels, row by row.
+    pub fn rgba(&self) -> &Bytes {
+        &self.rgba
+    }
+
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    pub fn hotspot(&self) -> (u16, u16) {
+        self.hotspot
+    }
+}
+
+/// Cursor of the element under the pointer, see
+/// [crate::elements::extensions::InteractiveExt::cursor].
+///
+/// It is resolved again after every layout, as the elements might have moved under a still pointer.
+#[derive(Default, Debug)]
+pub struct HoveredCursor {
+    pointer: Option<CursorPoint>,
+    current: Option<MouseCursor>,
+    change: Option<MouseCursor>,
+}
+
+impl HoveredCursor {
+    /// Last position of the pointer, if it ever moved.
+    pub fn pointer(&self) -> Option<CursorPoint> {
+        self.pointer
+    }
+
+    pub(crate) fn set_pointer(&mut self, pointer: CursorPoint) {
+        self.pointer = Some(pointer);
+    }
+
+    /// Update with the cursor of the element now under the pointer.
+    pub fn update(&mut self, cursor: Option<MouseCursor>) {
+        if cursor != self.current {
+            self.change = Some(cursor.clone().unwrap_or_default());
+            self.current = cursor;
+        }
+    }
+
+    /// Cursor to show since the last call, if it changed.
+    pub fn take_change(&mut self) -> Option<MouseCursor> {
+        self.change.take()
+    }
+}
+
+/// A built-in cursor icon or a [CustomCursor].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MouseCursor {
+    Icon(CursorIcon),
+    Custom(CustomCursor),
+}
+
+impl Default for MouseCursor {
+    fn default() -> Self {
+        Self::Icon(CursorIcon::default())
+    }
+}
+
+impl From<CursorIcon> for MouseCursor {
+    fn from(cursor_icon: CursorIcon) -> Self {
+        Self::Icon(cursor_icon)
+    }
+}
+
+impl From<CustomCursor> for MouseCursor {
+    fn from(custom_cursor: CustomCursor) -> Self {
+        Self::Custom(custom_cursor)
+    }
 }
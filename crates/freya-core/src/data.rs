@@ -8,6 +8,12 @@ use std::{
     rc::Rc,
 };
 
+use freya_engine::prelude::{
+    SkMatrix,
+    SkPoint,
+    TextStyle,
+};
+use rustc_hash::FxHashMap;
 use torin::{
     prelude::Area,
     torin::Torin,
@@ -28,28 +34,45 @@ use crate::{
     layers::{
         Layer,
         Layers,
+        PaintKey,
+        StackingLevel,
     },
     node_id::NodeId,
     prelude::{
         AccessibilityFocusStrategy,
         CursorStyle,
+        MouseCursor,
     },
     style::{
+        blend_mode::BlendMode,
         border::Border,
+        clip_path::ClipPath,
         color::Color,
         corner_radius::CornerRadius,
         fill::Fill,
+        filter::LayerFilter,
+        font_features::FontFeatures,
         font_size::FontSize,
         font_slant::FontSlant,
         font_weight::FontWeight,
         font_width::FontWidth,
+        mask::Mask,
         scale::Scale,
+        shader::ShaderEffect,
         shadow::Shadow,
         text_align::TextAlign,
         text_decoration::TextDecoration,
+        text_direction::TextDirection,
         text_height::TextHeightBehavior,
         text_overflow::TextOverflow,
         text_shadow::TextShadow,
+        text_spacing::TextSpacing,
+        text_stroke::TextStroke,
+        text_wrap::{
+            Hyphenation,
+            OverflowWrap,
+        },
+        transform::Transform,
     },
 };
 
@@ -85,8 +108,17 @@ pub struct EffectData {
     pub scale: Option<Scale>,
     pub opacity: Option<f32>,
     pub blur: Option<f32>,
+    pub backdrop_blur: Option<f32>,
+    pub filters: Vec<LayerFilter>,
+    pub blend_mode: Option<BlendMode>,
+    pub shader: Option<ShaderEffect>,
+    pub clip_path: Option<ClipPath>,
+    pub mask: Option<Mask>,
+    pub transform: Option<Transform>,
     pub scrollable: bool,
     pub interactive: Interactive,
+    pub cursor: Option<MouseCursor>,
+    pub cache_layer: bool,
 }
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -121,14 +153,21 @@ pub struct TextStyleState {
     pub font_size: FontSize,
     pub color: Color,
     pub text_align: TextAlign,
+    pub text_direction: TextDirection,
     pub font_families: Vec<Cow<'static, str>>,
     pub text_height: TextHeightBehavior,
     pub text_overflow: TextOverflow,
     pub text_shadows: Vec<TextShadow>,
+    pub text_stroke: Option<TextStroke>,
     pub text_decoration: TextDecoration,
     pub font_slant: FontSlant,
     pub font_weight: FontWeight,
     pub font_width: FontWidth,
+    pub font_features: FontFeatures,
+    pub letter_spacing: TextSpacing,
+    pub word_spacing: TextSpacing,
+    pub overflow_wrap: OverflowWrap,
+    pub hyphenation: Hyphenation,
 }
 
 impl Default for TextStyleState {
@@ -137,14 +176,21 @@ impl Default for TextStyleState {
             font_size: FontSize::default(),
             color: Color::BLACK,
             text_align: TextAlign::default(),
+            text_direction: TextDirection::default(),
             font_families: Vec::new(),
             text_height: TextHeightBehavior::default(),
             text_overflow: TextOverflow::default(),
             text_shadows: Vec::new(),
+            text_stroke: None,
             text_decoration: TextDecoration::default(),
             font_slant: FontSlant::default(),
             font_weight: FontWeight::default(),
             font_width: FontWidth::default(),
+            font_features: FontFeatures::default(),
+            letter_spacing: TextSpacing::default(),
+            word_spacing: TextSpacing::default(),
+            overflow_wrap: OverflowWrap::default(),
+            hyphenation: Hyphenation::default(),
         }
     }
 }
@@ -153,10 +199,16 @@ impl TextStyleState {
     pub fn from_data(parent: &TextStyleState, data: &TextStyleData) -> Self {
         let color = data.color.unwrap_or(parent.color);
 
-        let text_align = data.text_align.unwrap_or_default();
+        let text_direction = data.text_direction.unwrap_or(parent.text_direction);
+        // Text without an alignment starts from the side its language is written from
+        let text_align = data.text_align.unwrap_or(match text_direction {
+            TextDirection::Ltr => TextAlign::Left,
+            TextDirection::Rtl => TextAlign::Right,
+        });
         let text_height = data.text_height.unwrap_or_default();
         let text_overflow = data.text_overflow.clone().unwrap_or_default();
         let text_shadows = data.text_shadows.clone();
+        let text_stroke = data.text_stroke;
         let text_decoration = data.text_decoration.unwrap_or_default();
 
         // Font values can be inherited
@@ -166,19 +218,42 @@ impl TextStyleState {
         let font_width = data.font_width.unwrap_or(parent.font_width);
         let mut font_families = data.font_families.clone();
         font_families.extend_from_slice(&parent.font_families);
+        let letter_spacing = data.letter_spacing.unwrap_or(parent.letter_spacing);
+        let word_spacing = data.word_spacing.unwrap_or(parent.word_spacing);
+        let overflow_wrap = data.overflow_wrap.unwrap_or(parent.overflow_wrap);
+        let hyphenation = data.hyphenation.unwrap_or(parent.hyphenation);
+        // Features of the element are added after the inherited ones so they take precedence
+        let mut font_features = parent.font_features.clone();
+        font_features.0.extend_from_slice(&data.font_features.0);
 
         Self {
             color,
             text_align,
+            text_direction,
             text_height,
             text_overflow,
             text_shadows,
+            text_stroke,
             text_decoration,
             font_size,
             font_slant,
             font_weight,
             font_width,
             font_families,
+            font_features,
+            letter_spacing,
+            word_spacing,
+            overflow_wrap,
+            hyphenation,
+        }
+    }
+
+    /// Apply the letter and word spacing and the font features to a [TextStyle].
+    pub(crate) fn apply_typography(&self, text_style: &mut TextStyle, scale_factor: f32) {
+        text_style.set_letter_spacing(f32::from(self.letter_spacing) * scale_factor);
+        text_style.set_word_spacing(f32::from(self.word_spacing) * scale_factor);
+        for feature in self.font_features.iter() {
+            text_style.add_font_feature(feature.tag(), feature.value());
         }
     }
 
@@ -209,31 +284,52 @@ pub struct TextStyleData {
     pub font_size: Option<FontSize>,
     pub font_families: Vec<Cow<'static, str>>,
     pub text_align: Option<TextAlign>,
+    pub text_direction: Option<TextDirection>,
     pub text_height: Option<TextHeightBehavior>,
     pub text_overflow: Option<TextOverflow>,
     pub text_shadows: Vec<TextShadow>,
+    pub text_stroke: Option<TextStroke>,
     pub text_decoration: Option<TextDecoration>,
     pub font_slant: Option<FontSlant>,
     pub font_weight: Option<FontWeight>,
     pub font_width: Option<FontWidth>,
+    pub font_features: FontFeatures,
+    pub letter_spacing: Option<TextSpacing>,
+    pub word_spacing: Option<TextSpacing>,
+    pub overflow_wrap: Option<OverflowWrap>,
+    pub hyphenation: Option<Hyphenation>,
 }
 
 #[derive(Debug, Default)]
 pub struct LayerState {
     pub layer: i16,
+    /// Stacking contexts the descendants of this node are painted in.
+    pub stacking_contexts: PaintKey,
+    /// Position of this node in the paint order.
+    pub paint_key: PaintKey,
 }
 
 impl LayerState {
     pub fn create_for_root(node_id: NodeId, layers: &mut Layers) -> Self {
         let layer = 0;
+        let paint_key = PaintKey::from([StackingLevel {
+            overlay: 0,
+            z_index: 0,
+            layer,
+            node_id,
+        }]);
 
-        layers.insert_node_in_layer(node_id, layer);
+        layers.insert_node(node_id, paint_key.clone());
 
-        Self { layer }
+        Self {
+            layer,
+            stacking_contexts: PaintKey::default(),
+            paint_key,
+        }
     }
 
-    pub fn remove(self, node_id: NodeId, layers: &mut Layers) {
-        layers.remove_node_from_layer(&node_id, self.layer);
+    pub fn remove(self, layers: &mut Layers) {
+        layers.remove_node(&self.paint_key);
     }
 
     pub fn update(
@@ -246,7 +342,7 @@ impl LayerState {
         let relative_layer = element.layer();
 
         // Old
-        layers.remove_node_from_layer(&node_id, self.layer);
+        layers.remove_node(&self.paint_key);
 
         // New
         self.layer = match relative_layer {
@@ -259,7 +355,34 @@ impl LayerState {
                 (relative_layer.min(1) as i16).saturating_mul(i16::MAX / 16)
             }
         };
-        layers.insert_node_in_layer(node_id, self.layer);
+
+        // Overlays escape the stacking contexts of their ancestors
+        let mut stacking_contexts = match relative_layer {
+            Layer::Relative(_) => parent_layer.stacking_contexts.to_vec(),
+            Layer::Overlay | Layer::RelativeOverlay(_) => Vec::new(),
+        };
+        let overlay = self.layer.max(0) / (i16::MAX / 16);
+        let z_index = element.z_index();
+        if z_index != 0 {
+            stacking_contexts.push(StackingLevel {
+                overlay,
+                z_index,
+                layer: self.layer,
+                node_id,
+            });
+        }
+        if self.stacking_contexts.as_ref() != stacking_contexts {
+            self.stacking_contexts = PaintKey::from(stacking_contexts.as_slice());
+        }
+
+        stacking_contexts.push(StackingLevel {
+            overlay,
+            z_index: 0,
+            layer: self.layer,
+            node_id,
+        });
+        self.paint_key = PaintKey::from(stacking_contexts);
+        layers.insert_node(node_id, self.paint_key.clone());
     }
 }
 
@@ -294,16 +417,40 @@ pub struct EffectState {
     pub rotations: Rc<[NodeId]>,
     pub rotation: Option<f32>,
 
+    /// Nodes with a rotation or a transform, from the root to this node.
+    pub transforms: Rc<[NodeId]>,
+    pub transform: Option<Transform>,
+
     pub scales: Rc<[NodeId]>,
     pub scale: Option<Scale>,
 
     pub opacities: Rc<[f32]>,
 
+    pub filters: Rc<[LayerFilter]>,
+
     pub blur: Option<f32>,
 
+    pub backdrop_blur: Option<f32>,
+
+    pub blend_mode: Option<BlendMode>,
+
+    pub shader: Option<ShaderEffect>,
+
+    pub clip_paths: Rc<[NodeId]>,
+    pub clip_path: Option<ClipPath>,
+
+    pub masks: Rc<[NodeId]>,
+    pub mask: Option<Mask>,
+
     pub scrollables: Rc<[NodeId]>,
 
     pub interactive: Interactive,
+
+    /// Cursor of this node or the closest ancestor that has one.
+    pub cursor: Option<MouseCursor>,
+
+    /// Outermost element with a cache layer that contains this node.
+    pub cache_layer: Option<NodeId>,
 }
 
 impl EffectState {
@@ -318,7 +465,13 @@ impl EffectState {
         *self = Self {
             overflow: Overflow::default(),
             blur: None,
+            backdrop_blur: None,
+            blend_mode: None,
+            shader: None,
+            clip_path: None,
+            mask: None,
             rotation: None,
+            transform: None,
             scale: None,
             ..parent_effect_state.clone()
         };
@@ -326,6 +479,8 @@ impl EffectState {
         match layer {
             Layer::Overlay => {
                 self.clips = Rc::default();
+                self.clip_paths = Rc::default();
+                self.masks = Rc::default();
             }
             Layer::Relative(_) if parent_effect_state.overflow == Overflow::Clip => {
                 let mut clips = parent_effect_state.clips.to_vec();
@@ -340,6 +495,9 @@ impl EffectState {
         if let Some(effect_data) = effect_data {
             self.overflow = effect_data.overflow;
             self.blur = effect_data.blur;
+            self.backdrop_blur = effect_data.backdrop_blur;
+            self.blend_mode = effect_data.blend_mode;
+            self.shader = effect_data.shader.clone();
 
             if let Some(rotation) = effect_data.rotation {
                 let mut rotations = parent_effect_state.rotations.to_vec();
@@ -350,6 +508,15 @@ impl EffectState {
                 }
             }
 
+            if effect_data.rotation.is_some() || effect_data.transform.is_some() {
+                let mut transforms = parent_effect_state.transforms.to_vec();
+                transforms.push(node_id);
+                self.transform = effect_data.transform.clone();
+                if self.transforms.as_ref() != transforms {
+                    self.transforms = Rc::from(transforms);
+                }
+            }
+
             if let Some(scale) = effect_data.scale {
                 let mut scales = parent_effect_state.scales.to_vec();
                 scales.push(node_id);
@@ -367,6 +534,28 @@ impl EffectState {
                 }
             }
 
+            if let Some(clip_path) = &effect_data.clip_path {
+                let mut clip_paths = self.clip_paths.to_vec();
+                clip_paths.push(node_id);
+                self.clip_path = Some(clip_path.clone());
+                self.clip_paths = Rc::from(clip_paths);
+            }
+
+            if let Some(mask) = &effect_data.mask {
+                let mut masks = self.masks.to_vec();
+                masks.push(node_id);
+                self.mask = Some(mask.clone());
+                self.masks = Rc::from(masks);
+            }
+
+            if !effect_data.filters.is_empty() {
+                let mut filters = parent_effect_state.filters.to_vec();
+                filters.extend_from_slice(&effect_data.filters);
+                if self.filters.as_ref() != filters {
+                    self.filters = Rc::from(filters);
+                }
+            }
+
             if effect_data.scrollable {
                 let mut scrolls = parent_effect_state.scrollables.to_vec();
                 scrolls.push(node_id);
@@ -376,7 +565,49 @@ impl EffectState {
             }
 
             self.interactive = effect_data.interactive;
+
+            if effect_data.cursor.is_some() {
+                self.cursor = effect_data.cursor.clone();
+            }
+
+            if effect_data.cache_layer && self.cache_layer.is_none() {
+                self.cache_layer = Some(node_id);
+            }
+        }
+    }
+
+    /// Closest ancestor with a mask, this node is painted in the mask layer of that ancestor.
+    pub fn masked_by(&self, node_id: NodeId) -> Option<NodeId> {
+        self.masks.iter().rev().find(|id| **id != node_id).copied()
+    }
+
+    /// Matrix of the rotation and transform of this node alone.
+    pub fn local_matrix(&self, area: &Area, scale_factor: f32) -> SkMatrix {
+        let mut matrix = SkMatrix::new_identity();
+        if let Some(rotation) = self.rotation {
+            let center = area.center();
+            matrix.set_rotate(rotation, Some(SkPoint::new(center.x, center.y)));
+        }
+        if let Some(transform) = &self.transform {
+            matrix.pre_concat(&transform.to_matrix(area, scale_factor));
+        }
+        matrix
+    }
+
+    /// Matrix of the rotations and transforms of this node and its ancestors.
+    pub fn transform_matrix(
+        &self,
+        layout: &Torin<NodeId>,
+        effect_states: &FxHashMap<NodeId, EffectState>,
+        scale_factor: f32,
+    ) -> SkMatrix {
+        let mut matrix = SkMatrix::new_identity();
+        for id in self.transforms.iter() {
+            let area = layout.get(id).unwrap().visible_area();
+            let effect = effect_states.get(id).unwrap();
+            matrix.pre_concat(&effect.local_matrix(&area, scale_factor));
         }
+        matrix
     }
 
     pub fn is_visible(&self, layout: &Torin<NodeId>, area: &Area) -> bool {
@@ -396,6 +627,8 @@ pub struct AccessibilityState {
     pub a11y_id: AccessibilityId,
     pub a11y_focusable: Focusable,
     pub a11y_member_of: Option<AccessibilityId>,
+    pub a11y_focus_scope: bool,
+    pub a11y_tab_index: i32,
 }
 
 impl AccessibilityState {
@@ -433,6 +666,8 @@ impl AccessibilityState {
             a11y_id,
             a11y_focusable: data.a11y_focusable.clone(),
             a11y_member_of: data.builder.member_of(),
+            a11y_focus_scope: data.a11y_focus_scope,
+            a11y_tab_index: data.a11y_tab_index,
         }
     }
 
@@ -485,6 +720,8 @@ impl AccessibilityState {
         }
 
         self.a11y_focusable = data.a11y_focusable.clone();
+        self.a11y_focus_scope = data.a11y_focus_scope;
+        self.a11y_tab_index = data.a11y_tab_index;
     }
 }
 
@@ -494,5 +731,9 @@ pub struct AccessibilityData {
     pub a11y_id: Option<AccessibilityId>,
     pub a11y_auto_focus: bool,
     pub a11y_focusable: Focusable,
+    /// Keep the keyboard navigation inside this element while it exists.
+    pub a11y_focus_scope: bool,
+    /// Order in the keyboard navigation, see [crate::prelude::AccessibilityExt::a11y_tab_index].
+    pub a11y_tab_index: i32,
     pub builder: accesskit::Node,
 }
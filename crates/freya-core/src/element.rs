@@ -9,6 +9,7 @@ use freya_engine::prelude::{
     Canvas,
     FontCollection,
     FontMgr,
+    SkParagraph,
     SkRRect,
     SkRect,
 };
@@ -35,9 +36,13 @@ use crate::{
     event_handler::EventHandler,
     events::{
         data::{
+            AccessibilityActionEventData,
+            DragEventData,
             Event,
+            GestureEventData,
             KeyboardEventData,
             MouseEventData,
+            PenEventData,
             PointerEventData,
             SizedEventData,
             TouchEventData,
@@ -100,6 +105,10 @@ pub trait ElementExt: Any {
         Layer::default()
     }
 
+    fn z_index(&self) -> i16 {
+        0
+    }
+
     fn events_handlers(&'_ self) -> Option<Cow<'_, FxHashMap<EventName, EventHandlerType>>> {
         None
     }
@@ -116,6 +125,11 @@ pub trait ElementExt: Any {
         true
     }
 
+    /// Distance from the top of the measured content to its first baseline.
+    fn baseline(&self, _data: &Rc<dyn Any>) -> Option<f32> {
+        None
+    }
+
     fn is_point_inside(&self, context: EventMeasurementContext) -> bool {
         context
             .layout_node
@@ -123,10 +137,25 @@ pub trait ElementExt: Any {
             .contains(context.cursor.to_f32())
     }
 
+    /// Text that can be selected with the [TextSelection](crate::text_selection::TextSelection).
+    fn selectable_text(&'_ self) -> Option<Cow<'_, str>> {
+        None
+    }
+
+    /// Measured paragraph of the [selectable text](ElementExt::selectable_text).
+    fn selectable_paragraph(&self, _layout_node: &LayoutNode) -> Option<Rc<SkParagraph>> {
+        None
+    }
+
     fn clip(&self, _context: ClipContext) {}
 
     fn render(&self, _context: RenderContext) {}
 
+    /// Whether the element has to render again in the next frame, for elements that change on every frame.
+    fn needs_next_frame(&self) -> bool {
+        false
+    }
+
     fn render_rect(&self, area: &Area, scale_factor: f32) -> SkRRect {
         let style = self.style();
         let corner_radius = style.corner_radius.with_scale(scale_factor);
@@ -157,6 +186,7 @@ pub struct LayoutContext<'a> {
 
 #[allow(dead_code)]
 pub struct RenderContext<'a> {
+    pub node_id: NodeId,
     pub font_collection: &'a mut FontCollection,
     pub canvas: &'a Canvas,
     pub layout_node: &'a LayoutNode,
@@ -381,4 +411,8 @@ pub enum EventHandlerType {
     Pointer(EventHandler<Event<PointerEventData>>),
     ImePreedit(EventHandler<Event<ImePreeditEventData>>),
     File(EventHandler<Event<FileEventData>>),
+    Gesture(EventHandler<Event<GestureEventData>>),
+    Pen(EventHandler<Event<PenEventData>>),
+    Drag(EventHandler<Event<DragEventData>>),
+    AccessibilityAction(EventHandler<Event<AccessibilityActionEventData>>),
 }
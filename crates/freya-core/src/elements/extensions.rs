@@ -14,6 +14,7 @@ use rustc_hash::{
 use torin::{
     content::Content,
     gaps::Gaps,
+    grid::GridPlacement,
     prelude::{
         Alignment,
         Direction,
@@ -41,6 +42,7 @@ use crate::{
         AspectRatio,
         ImageCover,
         ImageData,
+        ImageSlice,
         SamplingMode,
     },
     event_handler::EventHandler,
@@ -57,14 +59,27 @@ use crate::{
     layers::Layer,
     prelude::*,
     style::{
+        blend_mode::BlendMode,
+        clip_path::ClipPath,
+        filter::LayerFilter,
+        font_features::FontFeatures,
         font_size::FontSize,
         font_slant::FontSlant,
         font_weight::FontWeight,
         font_width::FontWidth,
+        mask::Mask,
         scale::Scale,
+        shader::ShaderEffect,
         text_height::TextHeightBehavior,
         text_overflow::TextOverflow,
         text_shadow::TextShadow,
+        text_spacing::TextSpacing,
+        text_stroke::TextStroke,
+        text_wrap::{
+            Hyphenation,
+            OverflowWrap,
+        },
+        transform::Transform,
     },
 };
 
@@ -177,6 +192,7 @@ pub trait EventHandlersExt: Sized {
         mouse_down => EventName::MouseDown;
         mouse_up => EventName::MouseUp;
         mouse_move => EventName::MouseMove;
+        double_click => EventName::DoubleClick;
 
     }
 
@@ -247,6 +263,46 @@ pub trait EventHandlersExt: Sized {
         ImePreeditEventData;
 
         ime_preedit => EventName::ImePreedit;
+        ime_composition_start => EventName::ImeCompositionStart;
+        ime_composition_end => EventName::ImeCompositionEnd;
+    }
+
+    event_handlers! {
+        Gesture,
+        GestureEventData;
+
+        pinch => EventName::Pinch;
+        rotate => EventName::Rotate;
+        pan => EventName::Pan;
+    }
+
+    event_handlers! {
+        Pen,
+        PenEventData;
+
+        pen_down => EventName::PenDown;
+        pen_move => EventName::PenMove;
+        pen_up => EventName::PenUp;
+    }
+
+    event_handlers! {
+        Drag,
+        DragEventData;
+
+        drag_start => EventName::DragStart;
+        drag_over => EventName::DragOver;
+        drop => EventName::Drop;
+
+        global_drag_over => EventName::GlobalDragOver;
+        global_drop => EventName::GlobalDrop;
+        global_drag_cancel => EventName::GlobalDragCancel;
+    }
+
+    event_handlers! {
+        AccessibilityAction,
+        AccessibilityActionEventData;
+
+        a11y_action => EventName::AccessibilityAction;
     }
 
     fn on_sized(mut self, on_sized: impl Into<EventHandler<Event<SizedEventData>>>) -> Self
@@ -310,6 +366,29 @@ pub trait EventHandlersExt: Sized {
         })
     }
 
+    /// Request of a context menu for this element.
+    /// Gets triggered when:
+    /// - **Click**: There is a `MouseDown` (Right button) event
+    /// - **Activated**: The element is focused and there is a keydown event pressing the `ContextMenu` key or `Shift+F10`
+    fn on_context_menu(
+        self,
+        on_context_menu: impl Into<EventHandler<Event<PressEventData>>>,
+    ) -> Self {
+        let on_context_menu = on_context_menu.into();
+        self.on_secondary_down(on_context_menu.clone()).on_key_down(
+            move |e: Event<KeyboardEventData>| {
+                let is_context_menu = match e.key {
+                    Key::Named(NamedKey::ContextMenu) => true,
+                    Key::Named(NamedKey::F10) => e.modifiers == Modifiers::SHIFT,
+                    _ => false,
+                };
+                if is_context_menu {
+                    on_context_menu.call(e.map(PressEventData::Keyboard))
+                }
+            },
+        )
+    }
+
     /// Gets triggered when:
     /// - **Click**: There is a `MouseUp` event (Any button) with the in the same element that there had been a `MouseDown` just before
     /// - **Touched**: There is a `TouchEnd` event in the same element that there had been a `TouchStart` just before
@@ -370,6 +449,13 @@ where
         self.get_layout().layout.content = content;
         self
     }
+
+    /// Align the wrapped lines along the cross axis when using [Content::Wrap].
+    fn wrap_align(mut self, wrap_align: Alignment) -> Self {
+        self.get_layout().layout.wrap_alignment = wrap_align;
+        self
+    }
+
     fn center(mut self) -> Self {
         self.get_layout().layout.main_alignment = Alignment::Center;
         self.get_layout().layout.cross_alignment = Alignment::Center;
@@ -412,6 +498,12 @@ where
         self
     }
 
+    /// Derive the unsized axis from the other one so that `width / height` equals `ratio`.
+    fn keep_aspect_ratio(mut self, ratio: impl Into<f32>) -> Self {
+        self.get_layout().layout.aspect_ratio = Some(ratio.into());
+        self
+    }
+
     /// Expand both `width` and `height` using [Size::fill()].
     fn expanded(mut self) -> Self {
         self.get_layout().layout.width = Size::fill();
@@ -431,6 +523,18 @@ where
         self
     }
 
+    /// Place this element in the given columns of a parent using [Content::Grid].
+    fn grid_column(mut self, grid_column: impl Into<GridPlacement>) -> Self {
+        self.get_layout().layout.grid_column = grid_column.into();
+        self
+    }
+
+    /// Place this element in the given rows of a parent using [Content::Grid].
+    fn grid_row(mut self, grid_row: impl Into<GridPlacement>) -> Self {
+        self.get_layout().layout.grid_row = grid_row.into();
+        self
+    }
+
     fn padding(mut self, padding: impl Into<Gaps>) -> Self {
         self.get_layout().layout.padding = padding.into();
         self
@@ -509,6 +613,12 @@ where
         self.get_image_data().image_cover = image_cover;
         self
     }
+
+    /// Scale the image as a nine-patch, see [ImageSlice].
+    fn image_slice(mut self, image_slice: impl Into<ImageSlice>) -> Self {
+        self.get_image_data().image_slice = Some(image_slice.into());
+        self
+    }
 }
 
 pub trait AccessibilityExt: Sized {
@@ -534,6 +644,23 @@ pub trait AccessibilityExt: Sized {
         self
     }
 
+    /// Trap the keyboard navigation inside this element. When there are several scopes,
+    /// the last one in tree order wins, which for overlays like popups is the one on top.
+    /// See `use_focus_scope`.
+    fn a11y_focus_scope(mut self, a11y_focus_scope: impl Into<bool>) -> Self {
+        self.get_accessibility_data().a11y_focus_scope = a11y_focus_scope.into();
+        self
+    }
+
+    /// Order of this element when navigating with `Tab`, like in the web:
+    /// - `0` (default): Reached in the order of the tree.
+    /// - Positive: Reached before the rest, lowest first.
+    /// - Negative: Skipped, it can still be focused otherwise.
+    fn a11y_tab_index(mut self, a11y_tab_index: i32) -> Self {
+        self.get_accessibility_data().a11y_tab_index = a11y_tab_index;
+        self
+    }
+
     fn a11y_member_of(mut self, a11y_member_of: impl Into<AccessibilityId>) -> Self {
         self.get_accessibility_data()
             .builder
@@ -553,6 +680,35 @@ pub trait AccessibilityExt: Sized {
         self
     }
 
+    /// Announce the changes inside this element with the given politeness.
+    fn a11y_live(mut self, live: accesskit::Live) -> Self {
+        self.get_accessibility_data().builder.set_live(live);
+        self
+    }
+
+    /// Elements whose text describes this one, like a hint or an error message.
+    fn a11y_described_by(
+        mut self,
+        described_by: impl IntoIterator<Item = AccessibilityId>,
+    ) -> Self {
+        self.get_accessibility_data()
+            .builder
+            .set_described_by(described_by.into_iter().collect::<Vec<_>>());
+        self
+    }
+
+    /// Whether the content controlled by this element, like a menu or a tree item, is shown.
+    fn a11y_expanded(mut self, expanded: bool) -> Self {
+        self.get_accessibility_data().builder.set_expanded(expanded);
+        self
+    }
+
+    /// Offer an action to assistive technologies, handle it with `on_a11y_action`.
+    fn a11y_action(mut self, action: accesskit::Action) -> Self {
+        self.get_accessibility_data().builder.add_action(action);
+        self
+    }
+
     fn a11y_builder(mut self, with: impl FnOnce(&mut accesskit::Node)) -> Self {
         with(&mut self.get_accessibility_data().builder);
         self
@@ -580,6 +736,16 @@ where
         self
     }
 
+    /// Direction of the text of this element and its descendants.
+    /// [TextAlign::Start] and [TextAlign::End] follow it.
+    ///
+    /// In [TextDirection::Rtl] their layout is mirrored too: rows are laid out from the right,
+    /// and the start and end of the alignments and paddings are swapped.
+    fn text_direction(mut self, text_direction: impl Into<TextDirection>) -> Self {
+        self.get_text_style_data().text_direction = Some(text_direction.into());
+        self
+    }
+
     fn font_size(mut self, font_size: impl Into<FontSize>) -> Self {
         self.get_text_style_data().font_size = Some(font_size.into());
         self
@@ -607,6 +773,39 @@ where
         self
     }
 
+    /// OpenType features like `"tnum, ss01"`, added to the inherited ones.
+    fn font_features(mut self, font_features: impl Into<FontFeatures>) -> Self {
+        self.get_text_style_data()
+            .font_features
+            .0
+            .extend(font_features.into().0);
+        self
+    }
+
+    /// Extra space between letters, inherited by the children.
+    fn letter_spacing(mut self, letter_spacing: impl Into<TextSpacing>) -> Self {
+        self.get_text_style_data().letter_spacing = Some(letter_spacing.into());
+        self
+    }
+
+    /// Extra space between words, inherited by the children.
+    fn word_spacing(mut self, word_spacing: impl Into<TextSpacing>) -> Self {
+        self.get_text_style_data().word_spacing = Some(word_spacing.into());
+        self
+    }
+
+    /// Whether words that do not fit in a line are broken or overflow, inherited by the children.
+    fn overflow_wrap(mut self, overflow_wrap: impl Into<OverflowWrap>) -> Self {
+        self.get_text_style_data().overflow_wrap = Some(overflow_wrap.into());
+        self
+    }
+
+    /// Break words between syllables, inherited by the children. Ignored in text with a cursor.
+    fn hyphenation(mut self, hyphenation: impl Into<Hyphenation>) -> Self {
+        self.get_text_style_data().hyphenation = Some(hyphenation.into());
+        self
+    }
+
     fn text_height(mut self, text_height: impl Into<TextHeightBehavior>) -> Self {
         self.get_text_style_data().text_height = Some(text_height.into());
         self
@@ -624,6 +823,18 @@ where
         self
     }
 
+    /// Replace the text shadows, they are painted in the given order.
+    fn text_shadows(mut self, text_shadows: impl IntoIterator<Item = TextShadow>) -> Self {
+        self.get_text_style_data().text_shadows = text_shadows.into_iter().collect();
+        self
+    }
+
+    /// Outline around the glyphs, useful to keep text readable over images.
+    fn text_stroke(mut self, text_stroke: impl Into<TextStroke>) -> Self {
+        self.get_text_style_data().text_stroke = Some(text_stroke.into());
+        self
+    }
+
     fn text_decoration(mut self, text_decoration: impl Into<TextDecoration>) -> Self {
         self.get_text_style_data().text_decoration = Some(text_decoration.into());
         self
@@ -758,10 +969,19 @@ where
 {
     fn get_layer(&mut self) -> &mut Layer;
 
+    fn get_z_index(&mut self) -> &mut i16;
+
     fn layer(mut self, layer: impl Into<Layer>) -> Self {
         *self.get_layer() = layer.into();
         self
     }
+
+    /// Create a stacking context that paints this element and its descendants together, above the rest of the parent
+    /// context for positive values and below it for negative ones. Higher values are painted on top and receive the events first.
+    fn z_index(mut self, z_index: i16) -> Self {
+        *self.get_z_index() = z_index;
+        self
+    }
 }
 
 pub trait ScrollableExt
@@ -788,6 +1008,23 @@ where
         self.get_effect().interactive = interactive.into();
         self
     }
+
+    /// Cursor shown while the pointer is over this element or its descendants,
+    /// unless a descendant sets its own.
+    ///
+    /// ```rust,no_run
+    /// # use freya::prelude::*;
+    /// fn app() -> impl IntoElement {
+    ///     rect()
+    ///         .cursor(CursorIcon::Pointer)
+    ///         .on_press(|_| println!("Pressed!"))
+    ///         .child("Click me")
+    /// }
+    /// ```
+    fn cursor(mut self, cursor: impl Into<MouseCursor>) -> Self {
+        self.get_effect().cursor = Some(cursor.into());
+        self
+    }
 }
 
 pub trait EffectExt: Sized {
@@ -808,11 +1045,46 @@ pub trait EffectExt: Sized {
         self
     }
 
+    /// Blur what is behind this element, useful for frosted glass panels with a translucent background.
+    fn backdrop_blur(mut self, backdrop_blur: impl Into<f32>) -> Self {
+        self.get_effect().backdrop_blur = Some(backdrop_blur.into());
+        self
+    }
+
+    /// Add a [LayerFilter] to this element and its descendants, replacing any other of the same kind.
+    fn filter(mut self, filter: LayerFilter) -> Self {
+        let filters = &mut self.get_effect().filters;
+        filters.retain(|f| std::mem::discriminant(f) != std::mem::discriminant(&filter));
+        filters.push(filter);
+        self
+    }
+
+    /// Shorthand for [Self::filter] with [LayerFilter::Grayscale].
+    fn grayscale(self, grayscale: impl Into<f32>) -> Self {
+        self.filter(LayerFilter::Grayscale(grayscale.into()))
+    }
+
+    /// Shorthand for [Self::filter] with [LayerFilter::Brightness].
+    fn brightness(self, brightness: impl Into<f32>) -> Self {
+        self.filter(LayerFilter::Brightness(brightness.into()))
+    }
+
+    /// Shorthand for [Self::filter] with [LayerFilter::Saturate].
+    fn saturate(self, saturate: impl Into<f32>) -> Self {
+        self.filter(LayerFilter::Saturate(saturate.into()))
+    }
+
     fn rotation(mut self, rotation: impl Into<f32>) -> Self {
         self.get_effect().rotation = Some(rotation.into());
         self
     }
 
+    /// Transform this element and its descendants, see [Transform].
+    fn transform(mut self, transform: impl Into<Transform>) -> Self {
+        self.get_effect().transform = Some(transform.into());
+        self
+    }
+
     fn opacity(mut self, opacity: impl Into<f32>) -> Self {
         self.get_effect().opacity = Some(opacity.into());
         self
@@ -822,4 +1094,36 @@ pub trait EffectExt: Sized {
         self.get_effect().scale = Some(scale.into());
         self
     }
+
+    /// How what this element paints is composited with what is behind it.
+    fn blend_mode(mut self, blend_mode: impl Into<BlendMode>) -> Self {
+        self.get_effect().blend_mode = Some(blend_mode.into());
+        self
+    }
+
+    /// Apply a SkSL [ShaderEffect] to what this element paints.
+    fn shader(mut self, shader: ShaderEffect) -> Self {
+        self.get_effect().shader = Some(shader);
+        self
+    }
+
+    /// Clip this element and its descendants to the given [ClipPath].
+    fn clip_path(mut self, clip_path: impl Into<ClipPath>) -> Self {
+        self.get_effect().clip_path = Some(clip_path.into());
+        self
+    }
+
+    /// Hide the parts of this element and its descendants where the [Mask] is transparent.
+    fn mask(mut self, mask: impl Into<Mask>) -> Self {
+        self.get_effect().mask = Some(mask.into());
+        self
+    }
+
+    /// Record the painting of this element and its descendants and reuse it until any of them changes.
+    /// Useful for complex static content that would otherwise be repainted with every frame.
+    /// Descendants painted above other elements, like overlays, keep their place in the paint order.
+    fn cache_layer(mut self, cache_layer: impl Into<bool>) -> Self {
+        self.get_effect().cache_layer = cache_layer.into();
+        self
+    }
 }
@@ -10,6 +10,7 @@ use std::{
 
 use bytes::Bytes;
 use freya_engine::prelude::{
+    Canvas,
     ClipOp,
     CubicResampler,
     FilterMode,
@@ -18,6 +19,7 @@ use freya_engine::prelude::{
     SamplingOptions,
     SkImage,
     SkRect,
+    SrcRectConstraint,
 };
 use rustc_hash::FxHashMap;
 use torin::prelude::Size2D;
@@ -49,6 +51,7 @@ use crate::{
         EffectExt,
         EventHandlersExt,
         ImageExt,
+        InteractiveExt,
         KeyExt,
         LayerExt,
         LayoutExt,
@@ -74,6 +77,7 @@ pub fn image(image_holder: ImageHolder) -> Image {
             event_handlers: HashMap::default(),
             image_data: ImageData::default(),
             relative_layer: Layer::default(),
+            z_index: 0,
             effect: None,
             corner_radius: None,
         },
@@ -107,6 +111,54 @@ pub enum SamplingMode {
     CatmullRom,
 }
 
+/// How the edges and the center of an [ImageSlice] fill their area.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum ImageSliceRepeat {
+    #[default]
+    Stretch,
+    Tile,
+}
+
+/// Nine-patch scaling, the image is split in nine parts by these insets in image pixels.
+/// The corners keep their size while the edges and the center fill the rest of the area.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct ImageSlice {
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+    pub left: f32,
+    pub repeat: ImageSliceRepeat,
+}
+
+impl ImageSlice {
+    pub fn new(top: f32, right: f32, bottom: f32, left: f32) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+            repeat: ImageSliceRepeat::default(),
+        }
+    }
+
+    pub fn repeat(mut self, repeat: ImageSliceRepeat) -> Self {
+        self.repeat = repeat;
+        self
+    }
+}
+
+impl From<f32> for ImageSlice {
+    fn from(inset: f32) -> Self {
+        Self::new(inset, inset, inset, inset)
+    }
+}
+
+impl From<(f32, f32, f32, f32)> for ImageSlice {
+    fn from((top, right, bottom, left): (f32, f32, f32, f32)) -> Self {
+        Self::new(top, right, bottom, left)
+    }
+}
+
 #[derive(Clone)]
 pub struct ImageHolder {
     pub image: Rc<RefCell<SkImage>>,
@@ -124,6 +176,7 @@ pub struct ImageData {
     pub sampling_mode: SamplingMode,
     pub aspect_ratio: AspectRatio,
     pub image_cover: ImageCover,
+    pub image_slice: Option<ImageSlice>,
 }
 
 #[derive(PartialEq, Clone)]
@@ -134,6 +187,7 @@ pub struct ImageElement {
     pub image_holder: ImageHolder,
     pub image_data: ImageData,
     pub relative_layer: Layer,
+    pub z_index: i16,
     pub effect: Option<EffectData>,
     pub corner_radius: Option<CornerRadius>,
 }
@@ -157,7 +211,7 @@ impl ElementExt for ImageElement {
             diff.insert(DiffModifies::ACCESSIBILITY);
         }
 
-        if self.relative_layer != image.relative_layer {
+        if self.relative_layer != image.relative_layer || self.z_index != image.z_index {
             diff.insert(DiffModifies::LAYER);
         }
 
@@ -165,7 +219,7 @@ impl ElementExt for ImageElement {
             diff.insert(DiffModifies::LAYOUT);
         }
 
-        if self.image_holder != image.image_holder {
+        if self.image_holder != image.image_holder || self.image_data != image.image_data {
             diff.insert(DiffModifies::LAYOUT);
             diff.insert(DiffModifies::STYLE);
         }
@@ -204,6 +258,10 @@ impl ElementExt for ImageElement {
         self.relative_layer
     }
 
+    fn z_index(&self) -> i16 {
+        self.z_index
+    }
+
     fn should_measure_inner_children(&self) -> bool {
         true
     }
@@ -221,7 +279,14 @@ impl ElementExt for ImageElement {
         let width_ratio = context.area_size.width / image.width() as f32;
         let height_ratio = context.area_size.height / image.height() as f32;
 
-        let size = match self.image_data.aspect_ratio {
+        let aspect_ratio = if self.image_data.image_slice.is_some() {
+            // Sliced images stretch to their area
+            &AspectRatio::None
+        } else {
+            &self.image_data.aspect_ratio
+        };
+
+        let size = match aspect_ratio {
             AspectRatio::Max => {
                 let ratio = width_ratio.max(height_ratio);
 
@@ -289,14 +354,107 @@ impl ElementExt for ImageElement {
         let mut paint = Paint::default();
         paint.set_anti_alias(true);
 
-        context
-            .canvas
-            .draw_image_rect_with_sampling_options(&*image, None, rect, sampling, &paint);
+        if let Some(image_slice) = &self.image_data.image_slice {
+            render_image_slice(
+                context.canvas,
+                &image,
+                image_slice,
+                rect,
+                context.scale_factor as f32,
+                sampling,
+                &paint,
+            );
+        } else {
+            context
+                .canvas
+                .draw_image_rect_with_sampling_options(&*image, None, rect, sampling, &paint);
+        }
 
         context.canvas.restore();
     }
 }
 
+fn render_image_slice(
+    canvas: &Canvas,
+    image: &SkImage,
+    image_slice: &ImageSlice,
+    rect: SkRect,
+    scale_factor: f32,
+    sampling: SamplingOptions,
+    paint: &Paint,
+) {
+    let width = image.width() as f32;
+    let height = image.height() as f32;
+    let src_x = [0., image_slice.left, width - image_slice.right, width];
+    let src_y = [0., image_slice.top, height - image_slice.bottom, height];
+    let dst_x = [
+        rect.left,
+        rect.left + image_slice.left * scale_factor,
+        rect.right - image_slice.right * scale_factor,
+        rect.right,
+    ];
+    let dst_y = [
+        rect.top,
+        rect.top + image_slice.top * scale_factor,
+        rect.bottom - image_slice.bottom * scale_factor,
+        rect.bottom,
+    ];
+
+    for row in 0..3 {
+        for column in 0..3 {
+            let src = SkRect::new(src_x[column], src_y[row], src_x[column + 1], src_y[row + 1]);
+            let dst = SkRect::new(dst_x[column], dst_y[row], dst_x[column + 1], dst_y[row + 1]);
+            if src.width() <= 0. || src.height() <= 0. || dst.width() <= 0. || dst.height() <= 0. {
+                continue;
+            }
+
+            // Corners always keep their size
+            let is_corner = row != 1 && column != 1;
+            if is_corner || image_slice.repeat == ImageSliceRepeat::Stretch {
+                canvas.draw_image_rect_with_sampling_options(
+                    image,
+                    Some((&src, SrcRectConstraint::Strict)),
+                    dst,
+                    sampling,
+                    paint,
+                );
+                continue;
+            }
+
+            // Edges only repeat along their length, the center repeats in both directions
+            let tile_width = if column == 1 {
+                src.width() * scale_factor
+            } else {
+                dst.width()
+            };
+            let tile_height = if row == 1 {
+                src.height() * scale_factor
+            } else {
+                dst.height()
+            };
+
+            canvas.save();
+            canvas.clip_rect(dst, ClipOp::Intersect, false);
+            let mut y = dst.top;
+            while y < dst.bottom {
+                let mut x = dst.left;
+                while x < dst.right {
+                    canvas.draw_image_rect_with_sampling_options(
+                        image,
+                        Some((&src, SrcRectConstraint::Strict)),
+                        SkRect::new(x, y, x + tile_width, y + tile_height),
+                        sampling,
+                        paint,
+                    );
+                    x += tile_width;
+                }
+                y += tile_height;
+            }
+            canvas.restore();
+        }
+    }
+}
+
 impl From<Image> for Element {
     fn from(value: Image) -> Self {
         Element::Element {
@@ -351,6 +509,10 @@ impl LayerExt for Image {
     fn get_layer(&mut self) -> &mut Layer {
         &mut self.element.relative_layer
     }
+
+    fn get_z_index(&mut self) -> &mut i16 {
+        &mut self.element.z_index
+    }
 }
 
 impl EffectExt for Image {
@@ -359,6 +521,12 @@ impl EffectExt for Image {
     }
 }
 
+impl InteractiveExt for Image {
+    fn get_effect(&mut self) -> &mut EffectData {
+        self.element.effect.get_or_insert_with(EffectData::default)
+    }
+}
+
 pub struct Image {
     key: DiffKey,
     element: ImageElement,
@@ -9,18 +9,26 @@ use std::{
 use freya_engine::prelude::{
     ClipOp,
     FontStyle,
+    Paint,
+    PaintStyle,
     ParagraphBuilder,
     ParagraphStyle,
+    RectHeightStyle,
+    RectWidthStyle,
     SkParagraph,
     SkRect,
     TextStyle,
 };
 use rustc_hash::FxHashMap;
-use torin::prelude::Size2D;
+use torin::prelude::{
+    LayoutNode,
+    Size2D,
+};
 
 use crate::{
     data::{
         AccessibilityData,
+        CursorStyleData,
         EffectData,
         LayoutData,
         StyleState,
@@ -39,8 +47,11 @@ use crate::{
     layers::Layer,
     prelude::{
         AccessibilityExt,
+        Color,
         ContainerExt,
+        EffectExt,
         EventHandlersExt,
+        InteractiveExt,
         KeyExt,
         LayerExt,
         LayoutExt,
@@ -49,6 +60,10 @@ use crate::{
         TextAlign,
         TextStyleExt,
     },
+    style::text_wrap::{
+        hyphenate,
+        overflow_width,
+    },
     text_cache::CachedParagraph,
     tree::DiffModifies,
 };
@@ -97,6 +112,10 @@ pub struct LabelElement {
     pub max_lines: Option<usize>,
     pub line_height: Option<f32>,
     pub relative_layer: Layer,
+    pub z_index: i16,
+    pub effect: Option<EffectData>,
+    pub selectable: bool,
+    pub highlight_color: Color,
 }
 
 impl Default for LabelElement {
@@ -112,6 +131,10 @@ impl Default for LabelElement {
             max_lines: None,
             line_height: None,
             relative_layer: Layer::default(),
+            z_index: 0,
+            effect: None,
+            selectable: false,
+            highlight_color: CursorStyleData::default().highlight_color,
         }
     }
 }
@@ -136,11 +159,15 @@ impl ElementExt for LabelElement {
             diff.insert(DiffModifies::LAYOUT);
         }
 
+        if self.selectable != label.selectable || self.highlight_color != label.highlight_color {
+            diff.insert(DiffModifies::STYLE);
+        }
+
         if self.accessibility != label.accessibility {
             diff.insert(DiffModifies::ACCESSIBILITY);
         }
 
-        if self.relative_layer != label.relative_layer {
+        if self.relative_layer != label.relative_layer || self.z_index != label.z_index {
             diff.insert(DiffModifies::LAYER);
         }
 
@@ -159,6 +186,10 @@ impl ElementExt for LabelElement {
             diff.insert(DiffModifies::EVENT_HANDLERS);
         }
 
+        if self.effect != label.effect {
+            diff.insert(DiffModifies::EFFECT);
+        }
+
         diff
     }
 
@@ -167,7 +198,7 @@ impl ElementExt for LabelElement {
     }
 
     fn effect(&'_ self) -> Option<Cow<'_, EffectData>> {
-        None
+        self.effect.as_ref().map(Cow::Borrowed)
     }
 
     fn style(&'_ self) -> Cow<'_, StyleState> {
@@ -186,6 +217,10 @@ impl ElementExt for LabelElement {
         self.relative_layer
     }
 
+    fn z_index(&self) -> i16 {
+        self.z_index
+    }
+
     fn events_handlers(&'_ self) -> Option<Cow<'_, FxHashMap<EventName, EventHandlerType>>> {
         Some(Cow::Borrowed(&self.event_handlers))
     }
@@ -218,6 +253,9 @@ impl ElementExt for LabelElement {
                     context.text_style_state.font_width.into(),
                     context.text_style_state.font_slant.into(),
                 ));
+                context
+                    .text_style_state
+                    .apply_typography(&mut text_style, context.scale_factor as f32);
 
                 if context.text_style_state.text_height.needs_custom_height() {
                     text_style.set_height_override(true);
@@ -239,23 +277,32 @@ impl ElementExt for LabelElement {
                 paragraph_style.set_text_style(&text_style);
                 paragraph_style.set_max_lines(self.max_lines);
                 paragraph_style.set_text_align(context.text_style_state.text_align.into());
+                paragraph_style.set_text_direction(context.text_style_state.text_direction.into());
 
                 let mut paragraph_builder =
                     ParagraphBuilder::new(&paragraph_style, &*context.font_collection);
 
-                paragraph_builder.add_text(&self.text);
+                paragraph_builder
+                    .add_text(hyphenate(&self.text, context.text_style_state.hyphenation));
 
                 let mut paragraph = paragraph_builder.build();
-                paragraph.layout(
-                    if self.max_lines == Some(1)
-                        && context.text_style_state.text_align == TextAlign::default()
-                        && !paragraph_style.ellipsized()
-                    {
-                        f32::MAX
-                    } else {
-                        context.area_size.width + 1.0
-                    },
-                );
+                if self.max_lines == Some(1)
+                    && context.text_style_state.text_align == TextAlign::default()
+                    && !paragraph_style.ellipsized()
+                {
+                    paragraph.layout(f32::MAX);
+                } else {
+                    let width = context.area_size.width + 1.0;
+                    paragraph.layout(width);
+                    let overflow_width = overflow_width(
+                        context.text_style_state.overflow_wrap,
+                        width,
+                        paragraph.min_intrinsic_width(),
+                    );
+                    if overflow_width != width {
+                        paragraph.layout(overflow_width);
+                    }
+                }
 
                 context
                     .text_cache
@@ -275,6 +322,22 @@ impl ElementExt for LabelElement {
         false
     }
 
+    fn baseline(&self, data: &Rc<dyn Any>) -> Option<f32> {
+        data.downcast_ref::<SkParagraph>()
+            .map(|paragraph| paragraph.alphabetic_baseline())
+    }
+
+    fn selectable_text(&'_ self) -> Option<Cow<'_, str>> {
+        self.selectable.then_some(Cow::Borrowed(&*self.text))
+    }
+
+    fn selectable_paragraph(&self, layout_node: &LayoutNode) -> Option<Rc<SkParagraph>> {
+        if !self.selectable {
+            return None;
+        }
+        layout_node.data.clone()?.downcast::<SkParagraph>().ok()
+    }
+
     fn clip(&self, context: ClipContext) {
         let area = context.visible_area;
         context.canvas.clip_rect(
@@ -288,10 +351,41 @@ impl ElementExt for LabelElement {
         let layout_data = context.layout_node.data.as_ref().unwrap();
         let paragraph = layout_data.downcast_ref::<SkParagraph>().unwrap();
 
-        paragraph.paint(
-            context.canvas,
-            context.layout_node.visible_area().origin.to_tuple(),
-        );
+        let stroke_layer = context
+            .text_style_state
+            .text_stroke
+            .and_then(|text_stroke| {
+                text_stroke.save_layer(context.canvas, context.scale_factor as f32)
+            });
+
+        let origin = context.layout_node.visible_area().origin;
+
+        // Draw the text selected with the TextSelection
+        if let Some((from, to)) = context.tree.text_selection.range_of(&context.node_id) {
+            let mut paint = Paint::default();
+            paint.set_anti_alias(true);
+            paint.set_style(PaintStyle::Fill);
+            paint.set_color(self.highlight_color);
+            for rect in paragraph.get_rects_for_range(
+                from..to,
+                RectHeightStyle::Tight,
+                RectWidthStyle::Tight,
+            ) {
+                let rect = SkRect::new(
+                    origin.x + rect.rect.left,
+                    origin.y + rect.rect.top,
+                    origin.x + rect.rect.right,
+                    origin.y + rect.rect.bottom,
+                );
+                context.canvas.draw_rect(rect, &paint);
+            }
+        }
+
+        paragraph.paint(context.canvas, origin.to_tuple());
+
+        if let Some(stroke_layer) = stroke_layer {
+            context.canvas.restore_to_count(stroke_layer);
+        }
     }
 }
 
@@ -333,10 +427,26 @@ impl LayerExt for Label {
     fn get_layer(&mut self) -> &mut Layer {
         &mut self.element.relative_layer
     }
+
+    fn get_z_index(&mut self) -> &mut i16 {
+        &mut self.element.z_index
+    }
 }
 
 impl MaybeExt for Label {}
 
+impl EffectExt for Label {
+    fn get_effect(&mut self) -> &mut EffectData {
+        self.element.effect.get_or_insert_with(EffectData::default)
+    }
+}
+
+impl InteractiveExt for Label {
+    fn get_effect(&mut self) -> &mut EffectData {
+        self.element.effect.get_or_insert_with(EffectData::default)
+    }
+}
+
 pub struct Label {
     key: DiffKey,
     element: LabelElement,
@@ -355,6 +465,18 @@ impl Label {
         self
     }
 
+    /// Allow selecting the text with the [TextSelection](crate::text_selection::TextSelection).
+    pub fn selectable(mut self, selectable: impl Into<bool>) -> Self {
+        self.element.selectable = selectable.into();
+        self
+    }
+
+    /// Color of the selected text.
+    pub fn highlight_color(mut self, highlight_color: impl Into<Color>) -> Self {
+        self.element.highlight_color = highlight_color.into();
+        self
+    }
+
     pub fn max_lines(mut self, max_lines: impl Into<Option<usize>>) -> Self {
         self.element.max_lines = max_lines.into();
         self
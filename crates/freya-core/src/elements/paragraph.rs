@@ -24,7 +24,10 @@ use freya_engine::prelude::{
     TextStyle,
 };
 use rustc_hash::FxHashMap;
-use torin::prelude::Size2D;
+use torin::prelude::{
+    LayoutNode,
+    Size2D,
+};
 
 use crate::{
     data::{
@@ -50,7 +53,9 @@ use crate::{
         AccessibilityExt,
         Color,
         ContainerExt,
+        EffectExt,
         EventHandlersExt,
+        InteractiveExt,
         KeyExt,
         LayerExt,
         LayoutExt,
@@ -59,9 +64,15 @@ use crate::{
         TextStyleExt,
         VerticalAlign,
     },
-    style::cursor::{
-        CursorMode,
-        CursorStyle,
+    style::{
+        cursor::{
+            CursorMode,
+            CursorStyle,
+        },
+        text_wrap::{
+            hyphenate,
+            overflow_width,
+        },
     },
     text_cache::CachedParagraph,
     tree::DiffModifies,
@@ -126,9 +137,12 @@ pub struct ParagraphElement {
     pub max_lines: Option<usize>,
     pub line_height: Option<f32>,
     pub relative_layer: Layer,
+    pub z_index: i16,
     pub cursor_style: CursorStyle,
     pub cursor_mode: CursorMode,
     pub vertical_align: VerticalAlign,
+    pub effect: Option<EffectData>,
+    pub selectable: bool,
 }
 
 impl Default for ParagraphElement {
@@ -148,9 +162,12 @@ impl Default for ParagraphElement {
             max_lines: Default::default(),
             line_height: Default::default(),
             relative_layer: Default::default(),
+            z_index: 0,
             cursor_style: CursorStyle::default(),
             cursor_mode: CursorMode::default(),
             vertical_align: VerticalAlign::default(),
+            effect: None,
+            selectable: false,
         }
     }
 }
@@ -194,7 +211,7 @@ impl ElementExt for ParagraphElement {
             diff.insert(DiffModifies::ACCESSIBILITY);
         }
 
-        if self.relative_layer != paragraph.relative_layer {
+        if self.relative_layer != paragraph.relative_layer || self.z_index != paragraph.z_index {
             diff.insert(DiffModifies::LAYER);
         }
 
@@ -206,10 +223,15 @@ impl ElementExt for ParagraphElement {
             diff.insert(DiffModifies::EVENT_HANDLERS);
         }
 
+        if self.effect != paragraph.effect {
+            diff.insert(DiffModifies::EFFECT);
+        }
+
         if self.cursor_index != paragraph.cursor_index
             || self.highlights != paragraph.highlights
             || self.cursor_mode != paragraph.cursor_mode
             || self.vertical_align != paragraph.vertical_align
+            || self.selectable != paragraph.selectable
         {
             diff.insert(DiffModifies::STYLE);
         }
@@ -234,7 +256,7 @@ impl ElementExt for ParagraphElement {
         Cow::Borrowed(&self.layout)
     }
     fn effect(&'_ self) -> Option<Cow<'_, EffectData>> {
-        None
+        self.effect.as_ref().map(Cow::Borrowed)
     }
 
     fn style(&'_ self) -> Cow<'_, StyleState> {
@@ -253,6 +275,10 @@ impl ElementExt for ParagraphElement {
         self.relative_layer
     }
 
+    fn z_index(&self) -> i16 {
+        self.z_index
+    }
+
     fn measure(&self, context: LayoutContext) -> Option<(Size2D, Rc<dyn Any>)> {
         let cached_paragraph = CachedParagraph {
             text_style_state: context.text_style_state,
@@ -281,6 +307,9 @@ impl ElementExt for ParagraphElement {
                     context.text_style_state.font_width.into(),
                     context.text_style_state.font_slant.into(),
                 ));
+                context
+                    .text_style_state
+                    .apply_typography(&mut text_style, context.scale_factor as f32);
 
                 if context.text_style_state.text_height.needs_custom_height() {
                     text_style.set_height_override(true);
@@ -303,6 +332,7 @@ impl ElementExt for ParagraphElement {
                 paragraph_style.set_text_style(&text_style);
                 paragraph_style.set_max_lines(self.max_lines);
                 paragraph_style.set_text_align(context.text_style_state.text_align.into());
+                paragraph_style.set_text_direction(context.text_style_state.text_direction.into());
 
                 let mut paragraph_builder =
                     ParagraphBuilder::new(&paragraph_style, &*context.font_collection);
@@ -328,26 +358,40 @@ impl ElementExt for ParagraphElement {
                         text_style_state.font_width.into(),
                         text_style_state.font_slant.into(),
                     ));
+                    text_style_state.apply_typography(&mut text_style, context.scale_factor as f32);
                     text_style.set_decoration_type(text_style_state.text_decoration.into());
                     if let Some(line_height) = self.line_height {
                         text_style.set_height_override(true);
                         text_style.set_height(line_height);
                     }
                     paragraph_builder.push_style(&text_style);
-                    paragraph_builder.add_text(&span.text);
+                    // Text with a cursor keeps the offsets of its editor
+                    if self.cursor_index.is_none() {
+                        paragraph_builder
+                            .add_text(hyphenate(&span.text, text_style_state.hyphenation));
+                    } else {
+                        paragraph_builder.add_text(&span.text);
+                    }
                 }
 
                 let mut paragraph = paragraph_builder.build();
-                paragraph.layout(
-                    if self.max_lines == Some(1)
-                        && context.text_style_state.text_align == TextAlign::default()
-                        && !paragraph_style.ellipsized()
-                    {
-                        f32::MAX
-                    } else {
-                        context.area_size.width + 1.0
-                    },
-                );
+                if self.max_lines == Some(1)
+                    && context.text_style_state.text_align == TextAlign::default()
+                    && !paragraph_style.ellipsized()
+                {
+                    paragraph.layout(f32::MAX);
+                } else {
+                    let width = context.area_size.width + 1.0;
+                    paragraph.layout(width);
+                    let overflow_width = overflow_width(
+                        context.text_style_state.overflow_wrap,
+                        width,
+                        paragraph.min_intrinsic_width(),
+                    );
+                    if overflow_width != width {
+                        paragraph.layout(overflow_width);
+                    }
+                }
                 context
                     .text_cache
                     .insert(context.node_id, &cached_paragraph, paragraph)
@@ -374,10 +418,39 @@ impl ElementExt for ParagraphElement {
         false
     }
 
+    fn baseline(&self, _data: &Rc<dyn Any>) -> Option<f32> {
+        self.sk_paragraph
+            .0
+            .borrow()
+            .as_ref()
+            .map(|holder| holder.paragraph.alphabetic_baseline())
+    }
+
     fn events_handlers(&'_ self) -> Option<Cow<'_, FxHashMap<EventName, EventHandlerType>>> {
         Some(Cow::Borrowed(&self.event_handlers))
     }
 
+    fn selectable_text(&'_ self) -> Option<Cow<'_, str>> {
+        self.selectable.then(|| {
+            self.spans
+                .iter()
+                .map(|span| span.text.as_ref())
+                .collect::<String>()
+                .into()
+        })
+    }
+
+    fn selectable_paragraph(&self, _layout_node: &LayoutNode) -> Option<Rc<SkParagraph>> {
+        if !self.selectable {
+            return None;
+        }
+        self.sk_paragraph
+            .0
+            .borrow()
+            .as_ref()
+            .map(|holder| holder.paragraph.clone())
+    }
+
     fn render(&self, context: RenderContext) {
         let paragraph = self.sk_paragraph.0.borrow();
         let ParagraphHolderInner { paragraph, .. } = paragraph.as_ref().unwrap();
@@ -404,14 +477,15 @@ impl ElementExt for ParagraphElement {
             CursorMode::Expanded => vertical_offset * 2.,
         };
 
-        // Draw highlights
-        for (from, to) in self.highlights.iter() {
+        // Draw highlights, including the text selected with the TextSelection
+        let selection = context.tree.text_selection.range_of(&context.node_id);
+        for (from, to) in self.highlights.iter().copied().chain(selection) {
             if from == to {
                 continue;
             }
             let (from, to) = { if from < to { (from, to) } else { (to, from) } };
             let rects = paragraph.get_rects_for_range(
-                *from..*to,
+                from..to,
                 RectHeightStyle::Tight,
                 RectWidthStyle::Tight,
             );
@@ -421,7 +495,7 @@ impl ElementExt for ParagraphElement {
             highlights_paint.set_style(PaintStyle::Fill);
             highlights_paint.set_color(self.cursor_style_data.highlight_color);
 
-            if rects.is_empty() && *from == 0 {
+            if rects.is_empty() && from == 0 {
                 let avg_line_height =
                     paragraph.height() / paragraph.get_line_metrics().len().max(1) as f32;
                 let rect = SkRect::new(
@@ -503,11 +577,22 @@ impl ElementExt for ParagraphElement {
         }
 
         // Draw text (always uses visible_area with vertical_offset)
+        let stroke_layer = context
+            .text_style_state
+            .text_stroke
+            .and_then(|text_stroke| {
+                text_stroke.save_layer(context.canvas, context.scale_factor as f32)
+            });
+
         paragraph.paint(
             context.canvas,
             (visible_area.min_x(), visible_area.min_y() + vertical_offset),
         );
 
+        if let Some(stroke_layer) = stroke_layer {
+            context.canvas.restore_to_count(stroke_layer);
+        }
+
         // Draw cursor
         if let Some(cursor_index) = self.cursor_index
             && !visible_highlights
@@ -601,10 +686,26 @@ impl EventHandlersExt for Paragraph {
 
 impl MaybeExt for Paragraph {}
 
+impl EffectExt for Paragraph {
+    fn get_effect(&mut self) -> &mut EffectData {
+        self.element.effect.get_or_insert_with(EffectData::default)
+    }
+}
+
+impl InteractiveExt for Paragraph {
+    fn get_effect(&mut self) -> &mut EffectData {
+        self.element.effect.get_or_insert_with(EffectData::default)
+    }
+}
+
 impl LayerExt for Paragraph {
     fn get_layer(&mut self) -> &mut Layer {
         &mut self.element.relative_layer
     }
+
+    fn get_z_index(&mut self) -> &mut i16 {
+        &mut self.element.z_index
+    }
 }
 
 pub struct Paragraph {
@@ -687,6 +788,12 @@ impl Paragraph {
         self
     }
 
+    /// Allow selecting the text with the [TextSelection](crate::text_selection::TextSelection).
+    pub fn selectable(mut self, selectable: impl Into<bool>) -> Self {
+        self.element.selectable = selectable.into();
+        self
+    }
+
     pub fn max_lines(mut self, max_lines: impl Into<Option<usize>>) -> Self {
         self.element.max_lines = max_lines.into();
         self
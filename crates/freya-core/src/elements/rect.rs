@@ -10,11 +10,13 @@ use freya_engine::prelude::{
     Canvas,
     ClipOp,
     Paint,
+    PaintCap,
     PaintStyle,
     PathBuilder,
     SkBlurStyle,
     SkMaskFilter,
     SkPath,
+    SkPathEffect,
     SkPathFillType,
     SkPoint,
     SkRRect,
@@ -71,9 +73,11 @@ pub struct RectElement {
     pub layout: LayoutData,
     pub text_style_data: TextStyleData,
     pub relative_layer: Layer,
+    pub z_index: i16,
     pub event_handlers: FxHashMap<EventName, EventHandlerType>,
     pub accessibility: AccessibilityData,
     pub effect: Option<EffectData>,
+    pub transitions: Transitions,
 }
 
 impl Default for RectElement {
@@ -87,9 +91,11 @@ impl Default for RectElement {
             layout: Default::default(),
             text_style_data: Default::default(),
             relative_layer: Default::default(),
+            z_index: 0,
             event_handlers: Default::default(),
             accessibility,
             effect: Default::default(),
+            transitions: Default::default(),
         }
     }
 }
@@ -164,28 +170,161 @@ impl RectElement {
         corner_radius: &CornerRadius,
     ) {
         let mut border_paint = Paint::default();
-        border_paint.set_style(PaintStyle::Fill);
         border_paint.set_anti_alias(true);
-        border_paint.set_color(border.fill);
 
+        if border.style == BorderStyle::Solid && border.side_fills.is_none() {
+            border_paint.set_style(PaintStyle::Fill);
+            border_paint.set_color(border.fill);
+            Self::draw_border_shape(canvas, rect, corner_radius, border, &border_paint);
+            return;
+        }
+
+        let ((outer, outer_corner_radius), (inner, inner_corner_radius)) =
+            Self::border_rrects(rect, corner_radius, border);
+
+        // The stroke of the dashed and dotted styles follows the middle of the border
+        let middle_path = {
+            let (outer_rect, inner_rect) = (outer.rect(), inner.rect());
+            let rect = SkRect::new(
+                (outer_rect.left + inner_rect.left) / 2.,
+                (outer_rect.top + inner_rect.top) / 2.,
+                (outer_rect.right + inner_rect.right) / 2.,
+                (outer_rect.bottom + inner_rect.bottom) / 2.,
+            );
+            let middle = |outer: f32, inner: f32| ((outer + inner) / 2.).max(0.);
+            let corner_radius = CornerRadius {
+                top_left: middle(outer_corner_radius.top_left, inner_corner_radius.top_left),
+                top_right: middle(outer_corner_radius.top_right, inner_corner_radius.top_right),
+                bottom_left: middle(
+                    outer_corner_radius.bottom_left,
+                    inner_corner_radius.bottom_left,
+                ),
+                bottom_right: middle(
+                    outer_corner_radius.bottom_right,
+                    inner_corner_radius.bottom_right,
+                ),
+                smoothing: corner_radius.smoothing,
+            };
+            let rrect = SkRRect::new_rect_radii(
+                rect,
+                &[
+                    (corner_radius.top_left, corner_radius.top_left).into(),
+                    (corner_radius.top_right, corner_radius.top_right).into(),
+                    (corner_radius.bottom_right, corner_radius.bottom_right).into(),
+                    (corner_radius.bottom_left, corner_radius.bottom_left).into(),
+                ],
+            );
+            if corner_radius.smoothing > 0.0 {
+                corner_radius.smoothed_path(rrect)
+            } else {
+                let mut path = PathBuilder::new();
+                path.add_rrect(rrect, None, None);
+                path.detach()
+            }
+        };
+
+        let widths = [
+            border.width.top,
+            border.width.right,
+            border.width.bottom,
+            border.width.left,
+        ];
+        let sides = Self::border_side_wedges(*outer.rect(), *inner.rect());
+
+        // Every side is clipped by the diagonals that join the outer and inner corners
+        for ((wedge, color), width) in sides.iter().zip(border.side_colors()).zip(widths) {
+            if width <= 0. || color == Color::TRANSPARENT {
+                continue;
+            }
+
+            border_paint.set_color(color);
+
+            canvas.save();
+            canvas.clip_path(wedge, ClipOp::Intersect, true);
+            match border.style {
+                BorderStyle::Solid => {
+                    border_paint.set_style(PaintStyle::Fill);
+                    Self::draw_border_shape(canvas, rect, corner_radius, border, &border_paint);
+                }
+                BorderStyle::Dashed => {
+                    border_paint.set_style(PaintStyle::Stroke);
+                    border_paint.set_stroke_width(width);
+                    border_paint.set_stroke_cap(PaintCap::Butt);
+                    let dashed = SkPathEffect::dash(&[width * 3., width * 2.], 0.);
+                    border_paint.set_path_effect(dashed);
+                    canvas.draw_path(&middle_path, &border_paint);
+                }
+                BorderStyle::Dotted => {
+                    border_paint.set_style(PaintStyle::Stroke);
+                    border_paint.set_stroke_width(width);
+                    border_paint.set_stroke_cap(PaintCap::Round);
+                    let dotted = SkPathEffect::dash(&[0., width * 2.], 0.);
+                    border_paint.set_path_effect(dotted);
+                    canvas.draw_path(&middle_path, &border_paint);
+                }
+            }
+            canvas.restore();
+        }
+    }
+
+    fn draw_border_shape(
+        canvas: &Canvas,
+        rect: SkRect,
+        corner_radius: &CornerRadius,
+        border: &Border,
+        paint: &Paint,
+    ) {
         match Self::border_shape(rect, corner_radius, border) {
             BorderShape::DRRect(outer, inner) => {
-                canvas.draw_drrect(outer, inner, &border_paint);
+                canvas.draw_drrect(outer, inner, paint);
             }
             BorderShape::Path(path) => {
-                canvas.draw_path(&path, &border_paint);
+                canvas.draw_path(&path, paint);
             }
         }
     }
 
-    /// Returns a `Path` that will draw a [`Border`] around a base rectangle.
-    ///
-    /// We don't use Skia's stroking API here, since we might need different widths for each side.
-    pub fn border_shape(
+    /// Areas of the `top`, `right`, `bottom` and `left` sides of a border,
+    /// split by the lines that go from the outer corners to the inner ones.
+    fn border_side_wedges(outer: SkRect, inner: SkRect) -> [SkPath; 4] {
+        let corners = [
+            (
+                SkPoint::new(outer.left, outer.top),
+                SkPoint::new(inner.left, inner.top),
+            ),
+            (
+                SkPoint::new(outer.right, outer.top),
+                SkPoint::new(inner.right, inner.top),
+            ),
+            (
+                SkPoint::new(outer.right, outer.bottom),
+                SkPoint::new(inner.right, inner.bottom),
+            ),
+            (
+                SkPoint::new(outer.left, outer.bottom),
+                SkPoint::new(inner.left, inner.bottom),
+            ),
+        ];
+
+        std::array::from_fn(|side| {
+            let (outer_start, inner_start) = corners[side];
+            let (outer_end, inner_end) = corners[(side + 1) % 4];
+            let mut path = PathBuilder::new();
+            path.move_to(outer_start);
+            path.line_to(outer_end);
+            path.line_to(inner_end);
+            path.line_to(inner_start);
+            path.close();
+            path.detach()
+        })
+    }
+
+    /// Outer and inner rounded rectangles of a [`Border`], with their corner radius.
+    fn border_rrects(
         base_rect: SkRect,
         base_corner_radius: &CornerRadius,
         border: &Border,
-    ) -> BorderShape {
+    ) -> ((SkRRect, CornerRadius), (SkRRect, CornerRadius)) {
         let border_alignment = border.alignment;
         let border_width = border.width;
 
@@ -307,6 +446,23 @@ impl RectElement {
             (rrect, corner_radius)
         };
 
+        (
+            (outer_rrect, outer_corner_radius),
+            (inner_rrect, inner_corner_radius),
+        )
+    }
+
+    /// Returns a `Path` that will draw a [`Border`] around a base rectangle.
+    ///
+    /// We don't use Skia's stroking API here, since we might need different widths for each side.
+    pub fn border_shape(
+        base_rect: SkRect,
+        base_corner_radius: &CornerRadius,
+        border: &Border,
+    ) -> BorderShape {
+        let ((outer_rrect, outer_corner_radius), (inner_rrect, inner_corner_radius)) =
+            Self::border_rrects(base_rect, base_corner_radius, border);
+
         if base_corner_radius.smoothing > 0.0 {
             let mut path = PathBuilder::new();
             path.set_fill_type(SkPathFillType::EvenOdd);
@@ -411,7 +567,7 @@ impl ElementExt for RectElement {
             diff.insert(DiffModifies::ACCESSIBILITY);
         }
 
-        if self.relative_layer != rect.relative_layer {
+        if self.relative_layer != rect.relative_layer || self.z_index != rect.z_index {
             diff.insert(DiffModifies::LAYER);
         }
 
@@ -450,6 +606,10 @@ impl ElementExt for RectElement {
         self.relative_layer
     }
 
+    fn z_index(&self) -> i16 {
+        self.z_index
+    }
+
     fn events_handlers(&'_ self) -> Option<Cow<'_, FxHashMap<EventName, EventHandlerType>>> {
         Some(Cow::Borrowed(&self.event_handlers))
     }
@@ -579,6 +739,10 @@ impl LayerExt for Rect {
     fn get_layer(&mut self) -> &mut Layer {
         &mut self.element.relative_layer
     }
+
+    fn get_z_index(&mut self) -> &mut i16 {
+        &mut self.element.z_index
+    }
 }
 
 impl LayoutExt for Rect {
@@ -693,4 +857,23 @@ impl Rect {
             .blur = Some(blur.into());
         self
     }
+
+    /// Smoothly animate changes of the given properties instead of applying them instantly.
+    ///
+    /// ```rust
+    /// # use freya::prelude::*;
+    /// fn app() -> impl IntoElement {
+    ///     let mut toggled = use_state(|| false);
+    ///     rect()
+    ///         .transition("background 200ms ease-out, width 300ms")
+    ///         .width(Size::px(if toggled() { 200. } else { 100. }))
+    ///         .height(Size::px(50.))
+    ///         .background(if toggled() { Color::RED } else { Color::BLUE })
+    ///         .on_press(move |_| toggled.toggle())
+    /// }
+    /// ```
+    pub fn transition(mut self, transitions: impl Into<Transitions>) -> Self {
+        self.element.transitions = transitions.into();
+        self
+    }
 }
@@ -110,6 +110,7 @@ pub fn svg(bytes: impl Into<SvgBytes>) -> Svg {
             stroke_width: None,
             fill: None,
             relative_layer: Layer::default(),
+            z_index: 0,
         },
     }
 }
@@ -126,6 +127,7 @@ pub struct SvgElement {
     pub fill: Option<Color>,
     pub effect: Option<EffectData>,
     pub relative_layer: Layer,
+    pub z_index: i16,
 }
 
 impl ElementExt for SvgElement {
@@ -147,7 +149,7 @@ impl ElementExt for SvgElement {
             diff.insert(DiffModifies::ACCESSIBILITY);
         }
 
-        if self.relative_layer != svg.relative_layer {
+        if self.relative_layer != svg.relative_layer || self.z_index != svg.z_index {
             diff.insert(DiffModifies::LAYER);
         }
 
@@ -199,6 +201,10 @@ impl ElementExt for SvgElement {
         self.relative_layer
     }
 
+    fn z_index(&self) -> i16 {
+        self.z_index
+    }
+
     fn should_measure_inner_children(&self) -> bool {
         false
     }
@@ -340,6 +346,10 @@ impl LayerExt for Svg {
     fn get_layer(&mut self) -> &mut Layer {
         &mut self.element.relative_layer
     }
+
+    fn get_z_index(&mut self) -> &mut i16 {
+        &mut self.element.z_index
+    }
 }
 
 pub struct Svg {
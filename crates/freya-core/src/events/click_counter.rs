@@ -0,0 +1,74 @@
+use std::time::{
+    Duration,
+    Instant,
+};
+
+use torin::prelude::CursorPoint;
+
+use crate::events::data::MouseButton;
+
+/// Default maximum time between two presses for them to be counted as consecutive clicks.
+pub const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Maximum distance in physical pixels between two presses for them to be counted as consecutive clicks.
+const DOUBLE_CLICK_DISTANCE: f64 = 5.0;
+
+struct LastPress {
+    instant: Instant,
+    location: CursorPoint,
+    button: Option<MouseButton>,
+    count: u8,
+}
+
+/// Counts the consecutive clicks of a mouse, used by renderers to fill the `click_count` of mouse events
+/// and to emit double click events.
+pub struct ClickCounter {
+    interval: Duration,
+    last_press: Option<LastPress>,
+}
+
+impl Default for ClickCounter {
+    fn default() -> Self {
+        Self::new(DOUBLE_CLICK_INTERVAL)
+    }
+}
+
+impl ClickCounter {
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            last_press: None,
+        }
+    }
+
+    /// Register a new press and get its click count.
+    pub fn press(&mut self, location: CursorPoint, button: Option<MouseButton>) -> u8 {
+        let count = match &self.last_press {
+            Some(last_press)
+                if last_press.button == button
+                    && last_press.instant.elapsed() <= self.interval
+                    && last_press.location.distance_to(location) <= DOUBLE_CLICK_DISTANCE =>
+            {
+                last_press.count.saturating_add(1)
+            }
+            _ => 1,
+        };
+
+        self.last_press = Some(LastPress {
+            instant: Instant::now(),
+            location,
+            button,
+            count,
+        });
+
+        count
+    }
+
+    /// Click count of the last press.
+    pub fn count(&self) -> u8 {
+        self.last_press
+            .as_ref()
+            .map(|last_press| last_press.count)
+            .unwrap_or_default()
+    }
+}
@@ -1,5 +1,10 @@
 use std::{
+    any::{
+        Any,
+        TypeId,
+    },
     cell::RefCell,
+    fmt,
     ops::{
         Deref,
         Div,
@@ -8,12 +13,20 @@ use std::{
     rc::Rc,
 };
 
+use ragnarok::PointerCapture;
+use rustc_hash::FxHashMap;
 use torin::prelude::{
     Area,
     CursorPoint,
     Size2D,
 };
 
+use crate::{
+    element::Element,
+    node_id::NodeId,
+};
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 pub enum MouseButton {
     Left,
@@ -29,6 +42,8 @@ pub struct MouseEventData {
     pub global_location: CursorPoint,
     pub element_location: CursorPoint,
     pub button: Option<MouseButton>,
+    /// Number of consecutive clicks, e.g `2` for the second press of a double click.
+    pub click_count: u8,
 }
 
 /// Data of a Keyboard event.
@@ -64,10 +79,18 @@ impl KeyboardEventData {
     }
 }
 
+/// Pointer capture requested from the event handlers of a node.
+#[derive(Clone)]
+pub(crate) struct EventPointerCapture {
+    pub(crate) node_id: NodeId,
+    pub(crate) request: Rc<RefCell<Option<PointerCapture<NodeId>>>>,
+}
+
 pub struct Event<D> {
     pub(crate) data: D,
     pub(crate) propagate: Rc<RefCell<bool>>,
     pub(crate) default: Rc<RefCell<bool>>,
+    pub(crate) pointer_capture: EventPointerCapture,
 }
 
 impl<D> Deref for Event<D> {
@@ -84,6 +107,7 @@ impl<D> Event<D> {
             data: data(self.data),
             propagate: self.propagate,
             default: self.default,
+            pointer_capture: self.pointer_capture,
         }
     }
 
@@ -92,6 +116,7 @@ impl<D> Event<D> {
             data: data(self.data)?,
             propagate: self.propagate,
             default: self.default,
+            pointer_capture: self.pointer_capture,
         })
     }
 
@@ -111,6 +136,38 @@ impl<D> Event<D> {
     pub fn get_prevent_default(&self) -> Rc<RefCell<bool>> {
         self.default.clone()
     }
+
+    /// Keep receiving the pointer movement and release events in the node this event was emitted to,
+    /// even if the pointer leaves it. The capture ends when the pointer is released or with [Event::release_pointer].
+    pub fn capture_pointer(&self) {
+        *self.pointer_capture.request.borrow_mut() =
+            Some(PointerCapture::Capture(self.pointer_capture.node_id));
+    }
+
+    /// Stop capturing the pointer. See [Event::capture_pointer].
+    pub fn release_pointer(&self) {
+        *self.pointer_capture.request.borrow_mut() = Some(PointerCapture::Release);
+    }
+}
+
+/// Data of an action requested by an assistive technology, such as a screen reader.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessibilityActionEventData {
+    pub action: accesskit::Action,
+    pub data: Option<accesskit::ActionData>,
+    /// Anchor and focus of a [accesskit::Action::SetTextSelection] in UTF-16 code units,
+    /// like the cursor of a paragraph.
+    pub text_selection: Option<(usize, usize)>,
+}
+
+impl AccessibilityActionEventData {
+    pub fn new(action: accesskit::Action, data: Option<accesskit::ActionData>) -> Self {
+        Self {
+            action,
+            data,
+            text_selection: None,
+        }
+    }
 }
 
 /// Data of a Sized event.
@@ -118,6 +175,8 @@ impl<D> Event<D> {
 pub struct SizedEventData {
     pub area: Area,
     pub visible_area: Area,
+    /// Part of the element inside the window and the viewports clipping it, like scroll views.
+    pub clipped_area: Area,
     pub inner_sizes: Size2D,
 }
 
@@ -125,6 +184,7 @@ impl SizedEventData {
     pub fn div(&mut self, rhs: f32) {
         self.area = self.area.div(rhs);
         self.visible_area = self.visible_area.div(rhs);
+        self.clipped_area = self.clipped_area.div(rhs);
         self.inner_sizes = self.inner_sizes.div(rhs);
     }
 }
@@ -134,11 +194,13 @@ impl SizedEventData {
         Self {
             area,
             visible_area,
+            clipped_area: visible_area,
             inner_sizes,
         }
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, PartialEq, Copy)]
 pub enum WheelSource {
     Device,
@@ -173,6 +235,7 @@ impl WheelEventData {
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
 pub enum TouchPhase {
     Started,
@@ -181,6 +244,7 @@ pub enum TouchPhase {
     Cancelled,
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Force {
     Calibrated {
@@ -249,9 +313,12 @@ impl PointerEventData {
     }
 }
 
+/// Data of the IME composition events.
 #[derive(Debug, Clone, PartialEq)]
 pub struct ImePreeditEventData {
+    /// Preedit text, or the committed text when the composition ends.
     pub text: String,
+    /// Byte range of the cursor inside the preedit text.
     pub cursor: Option<(usize, usize)>,
 }
 
@@ -273,6 +340,170 @@ impl FileEventData {
     }
 }
 
+/// Change reported by a touchpad gesture since the last event.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GestureDelta {
+    /// Positive values mean magnification (zoom in), negative mean shrinking (zoom out).
+    Pinch(f64),
+    /// Rotation in degrees, positive values mean counter clockwise.
+    Rotate(f64),
+    /// Two fingers panning, in logical pixels.
+    Pan(CursorPoint),
+}
+
+/// Data of a touchpad gesture event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GestureEventData {
+    pub global_location: CursorPoint,
+    pub element_location: CursorPoint,
+    pub delta: GestureDelta,
+    pub phase: TouchPhase,
+}
+
+impl GestureEventData {
+    pub fn new(
+        global_location: CursorPoint,
+        element_location: CursorPoint,
+        delta: GestureDelta,
+        phase: TouchPhase,
+    ) -> Self {
+        Self {
+            global_location,
+            element_location,
+            delta,
+            phase,
+        }
+    }
+}
+
+/// State of a stylus, the values that the platform doesn't report are `None`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PenState {
+    /// Pressure between `0.0` and `1.0`.
+    pub pressure: f64,
+    /// Angle in degrees between the pen and the surface, `90.0` means perpendicular to it.
+    pub altitude: f64,
+    /// Tilt in degrees on the X and Y axes, `(0., 0.)` when the pen is perpendicular to the surface.
+    pub tilt: Option<CursorPoint>,
+    /// Rotation in degrees around the pen axis.
+    pub twist: Option<f64>,
+    /// Whether the eraser end of the pen is being used.
+    pub eraser: Option<bool>,
+    /// Whether the barrel button is pressed.
+    pub barrel_button: Option<bool>,
+}
+
+/// Data of a stylus event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PenEventData {
+    pub global_location: CursorPoint,
+    pub element_location: CursorPoint,
+    pub state: PenState,
+}
+
+impl PenEventData {
+    pub fn new(
+        global_location: CursorPoint,
+        element_location: CursorPoint,
+        state: PenState,
+    ) -> Self {
+        Self {
+            global_location,
+            element_location,
+            state,
+        }
+    }
+}
+
+#[derive(Default)]
+struct DragDataInner {
+    payloads: FxHashMap<TypeId, Rc<dyn Any>>,
+    preview: Option<Element>,
+}
+
+/// Payloads of an in-app drag, one per type.
+///
+/// Drag sources fill it from their `on_drag_start` handler, a drag only starts if it has any payload.
+/// Drop targets then read the payload types they accept.
+#[derive(Clone, Default)]
+pub struct DragData(Rc<RefCell<DragDataInner>>);
+
+impl DragData {
+    /// Set the payload of type `T`, replacing the previous one.
+    pub fn set<T: 'static>(&self, payload: T) {
+        self.0
+            .borrow_mut()
+            .payloads
+            .insert(TypeId::of::<T>(), Rc::new(payload));
+    }
+
+    /// Get the payload of type `T`.
+    pub fn get<T: Clone + 'static>(&self) -> Option<T> {
+        self.0
+            .borrow()
+            .payloads
+            .get(&TypeId::of::<T>())
+            .and_then(|payload| payload.downcast_ref::<T>())
+            .cloned()
+    }
+
+    /// Whether there is a payload of type `T`.
+    pub fn contains<T: 'static>(&self) -> bool {
+        self.0.borrow().payloads.contains_key(&TypeId::of::<T>())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.borrow().payloads.is_empty()
+    }
+
+    /// Set the element rendered under the cursor while dragging.
+    pub fn set_preview(&self, preview: impl Into<Element>) {
+        self.0.borrow_mut().preview = Some(preview.into());
+    }
+
+    pub fn preview(&self) -> Option<Element> {
+        self.0.borrow().preview.clone()
+    }
+}
+
+impl PartialEq for DragData {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for DragData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DragData")
+            .field("payloads", &self.0.borrow().payloads.len())
+            .finish()
+    }
+}
+
+/// Data of an in-app drag event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DragEventData {
+    pub global_location: CursorPoint,
+    pub element_location: CursorPoint,
+    pub drag_data: DragData,
+}
+
+impl DragEventData {
+    pub fn new(
+        global_location: CursorPoint,
+        element_location: CursorPoint,
+        drag_data: DragData,
+    ) -> Self {
+        Self {
+            global_location,
+            element_location,
+            drag_data,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum EventType {
     Mouse(MouseEventData),
@@ -283,4 +514,8 @@ pub enum EventType {
     Pointer(PointerEventData),
     ImePreedit(ImePreeditEventData),
     File(FileEventData),
+    Gesture(GestureEventData),
+    Pen(PenEventData),
+    Drag(DragEventData),
+    AccessibilityAction(AccessibilityActionEventData),
 }
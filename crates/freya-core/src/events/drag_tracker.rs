@@ -0,0 +1,89 @@
+use torin::prelude::CursorPoint;
+
+use crate::events::{
+    data::DragData,
+    platform::{
+        DragEventName,
+        PlatformEvent,
+    },
+};
+
+/// Minimum distance in physical pixels the pointer needs to move while pressed to start a drag.
+pub const DRAG_THRESHOLD: f64 = 4.0;
+
+#[derive(Default)]
+enum DragPhase {
+    #[default]
+    Idle,
+    Pressing(CursorPoint),
+    Dragging(DragData),
+}
+
+/// Tracks the pointer to start in-app drags, used by renderers to emit the drag events
+/// next to the pointer events.
+#[derive(Default)]
+pub struct DragTracker {
+    phase: DragPhase,
+}
+
+impl DragTracker {
+    /// Whether a drag source has started a drag.
+    pub fn is_dragging(&self) -> bool {
+        matches!(&self.phase, DragPhase::Dragging(drag_data) if !drag_data.is_empty())
+    }
+
+    /// Register a pointer press.
+    pub fn press(&mut self, cursor: CursorPoint) {
+        self.phase = DragPhase::Pressing(cursor);
+    }
+
+    /// Register a pointer movement and get the drag event it causes, if any.
+    ///
+    /// Once the pointer moves far enough a [DragEventName::DragStart] is emitted where the press happened.
+    /// If none of its handlers sets a payload there is no drag.
+    pub fn moved(&mut self, cursor: CursorPoint) -> Option<PlatformEvent> {
+        match &self.phase {
+            DragPhase::Pressing(origin) if origin.distance_to(cursor) >= DRAG_THRESHOLD => {
+                let origin = *origin;
+                let drag_data = DragData::default();
+                self.phase = DragPhase::Dragging(drag_data.clone());
+                Some(PlatformEvent::Drag {
+                    name: DragEventName::DragStart,
+                    cursor: origin,
+                    drag_data,
+                })
+            }
+            DragPhase::Dragging(drag_data) if !drag_data.is_empty() => Some(PlatformEvent::Drag {
+                name: DragEventName::DragOver,
+                cursor,
+                drag_data: drag_data.clone(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Stop tracking the pointer without dropping, and get the [DragEventName::DragCancel] event
+    /// if there was a drag.
+    pub fn cancel(&mut self, cursor: CursorPoint) -> Option<PlatformEvent> {
+        match std::mem::take(&mut self.phase) {
+            DragPhase::Dragging(drag_data) if !drag_data.is_empty() => Some(PlatformEvent::Drag {
+                name: DragEventName::DragCancel,
+                cursor,
+                drag_data,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Register a pointer release and get the drop event if there was a drag.
+    pub fn release(&mut self, cursor: CursorPoint) -> Option<PlatformEvent> {
+        match std::mem::take(&mut self.phase) {
+            DragPhase::Dragging(drag_data) if !drag_data.is_empty() => Some(PlatformEvent::Drag {
+                name: DragEventName::Drop,
+                cursor,
+                drag_data,
+            }),
+            _ => None,
+        }
+    }
+}
@@ -7,9 +7,13 @@ use torin::prelude::CursorPoint;
 use crate::{
     events::{
         data::{
+            DragEventData,
             EventType,
+            GestureDelta,
+            GestureEventData,
             KeyboardEventData,
             MouseEventData,
+            PenEventData,
             PointerEventData,
             TouchEventData,
             WheelEventData,
@@ -79,7 +83,7 @@ impl EmmitableEvent {
                 name: platform_event_name,
                 cursor,
                 button,
-                ..
+                click_count,
             } if name.is_enter()
                 || name.is_left()
                 || name.is_press()
@@ -97,6 +101,7 @@ impl EmmitableEvent {
                     global_location,
                     element_location: CursorPoint::new(element_x, element_y),
                     button,
+                    click_count,
                 }));
 
                 Self {
@@ -147,7 +152,7 @@ impl EmmitableEvent {
                 name: platform_event_name,
                 cursor,
                 button,
-                ..
+                click_count,
             } => {
                 let global_location = cursor / scale_factor;
                 let element_x =
@@ -159,6 +164,7 @@ impl EmmitableEvent {
                     global_location,
                     element_location: CursorPoint::new(element_x, element_y),
                     button,
+                    click_count,
                 });
 
                 Self {
@@ -265,6 +271,81 @@ impl EmmitableEvent {
                 data: EventType::File(FileEventData::new(cursor, file_path)),
                 bubbles,
             },
+            PlatformEvent::Gesture {
+                name: platform_event_name,
+                cursor,
+                delta,
+                phase,
+            } => {
+                let global_location = cursor / scale_factor;
+                let element_x =
+                    (cursor.x - node_area.unwrap_or_default().min_x() as f64) / scale_factor;
+                let element_y =
+                    (cursor.y - node_area.unwrap_or_default().min_y() as f64) / scale_factor;
+                let delta = match delta {
+                    GestureDelta::Pan(pan) => GestureDelta::Pan(pan / scale_factor),
+                    delta => delta,
+                };
+
+                Self {
+                    node_id,
+                    name,
+                    source_event: platform_event_name.into(),
+                    data: EventType::Gesture(GestureEventData::new(
+                        global_location,
+                        CursorPoint::new(element_x, element_y),
+                        delta,
+                        phase,
+                    )),
+                    bubbles,
+                }
+            }
+            PlatformEvent::Pen {
+                name: platform_event_name,
+                location,
+                state,
+            } => {
+                let global_location = location / scale_factor;
+                let element_x =
+                    (location.x - node_area.unwrap_or_default().min_x() as f64) / scale_factor;
+                let element_y =
+                    (location.y - node_area.unwrap_or_default().min_y() as f64) / scale_factor;
+
+                Self {
+                    node_id,
+                    name,
+                    source_event: platform_event_name.into(),
+                    data: EventType::Pen(PenEventData::new(
+                        global_location,
+                        CursorPoint::new(element_x, element_y),
+                        state,
+                    )),
+                    bubbles,
+                }
+            }
+            PlatformEvent::Drag {
+                name: platform_event_name,
+                cursor,
+                drag_data,
+            } => {
+                let global_location = cursor / scale_factor;
+                let element_x =
+                    (cursor.x - node_area.unwrap_or_default().min_x() as f64) / scale_factor;
+                let element_y =
+                    (cursor.y - node_area.unwrap_or_default().min_y() as f64) / scale_factor;
+
+                Self {
+                    node_id,
+                    name,
+                    source_event: platform_event_name.into(),
+                    data: EventType::Drag(DragEventData::new(
+                        global_location,
+                        CursorPoint::new(element_x, element_y),
+                        drag_data,
+                    )),
+                    bubbles,
+                }
+            }
         }
     }
 }
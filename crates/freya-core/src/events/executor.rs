@@ -2,7 +2,10 @@ pub enum EventsChunk {
     Batch(Vec<EmmitableEvent>),
     Processed(ProcessedEvents<NodeId, EventName, EmmitableEvent, PlatformEvent>),
 }
-use ragnarok::ProcessedEvents;
+use ragnarok::{
+    PointerCapture,
+    ProcessedEvents,
+};
 
 use crate::{
     events::{
@@ -29,4 +32,8 @@ impl ragnarok::EventsExecutor for EventsExecutorAdapter<'_> {
         self.runner
             .handle_event(event.node_id, event.name, event.data, event.bubbles)
     }
+
+    fn take_pointer_capture(&mut self) -> Option<PointerCapture<Self::Key>> {
+        self.runner.take_pointer_capture()
+    }
 }
@@ -0,0 +1,54 @@
+use crate::events::platform::{
+    ImeEventName,
+    PlatformEvent,
+};
+
+/// Tracks the IME composition of a window, used by renderers to emit the
+/// composition start and end events around the preedit events.
+#[derive(Default)]
+pub struct ImeComposition {
+    composing: bool,
+}
+
+impl ImeComposition {
+    pub fn is_composing(&self) -> bool {
+        self.composing
+    }
+
+    /// Get the events of a new preedit text.
+    ///
+    /// An empty text only clears the preedit, platforms send it right before committing,
+    /// so the composition ends later with the committed text through [ImeComposition::end].
+    pub fn preedit(&mut self, text: String, cursor: Option<(usize, usize)>) -> Vec<PlatformEvent> {
+        let mut events = Vec::new();
+
+        if !text.is_empty() && !self.composing {
+            self.composing = true;
+            events.push(PlatformEvent::ImePreedit {
+                name: ImeEventName::CompositionStart,
+                text: String::new(),
+                cursor: None,
+            });
+        }
+        events.push(PlatformEvent::ImePreedit {
+            name: ImeEventName::Preedit,
+            text,
+            cursor,
+        });
+
+        events
+    }
+
+    /// End the composition if there is one, with the committed `text`.
+    pub fn end(&mut self, text: String) -> Option<PlatformEvent> {
+        if !self.composing {
+            return None;
+        }
+        self.composing = false;
+        Some(PlatformEvent::ImePreedit {
+            name: ImeEventName::CompositionEnd,
+            text,
+            cursor: None,
+        })
+    }
+}
@@ -1,14 +1,25 @@
+use ragnarok::{
+    CursorPoint,
+    EventsMeasurer,
+    NodesState,
+    ProcessedEvents,
+};
+
 use crate::{
     data::Interactive,
     element::EventMeasurementContext,
     events::{
         emittable::EmmitableEvent,
         name::EventName,
-        platform::PlatformEvent,
+        platform::{
+            MouseEventName,
+            PlatformEvent,
+        },
     },
     node_id::NodeId,
     prelude::Color,
     style::fill::Fill,
+    text_selection::TextSelection,
     tree::Tree,
 };
 
@@ -17,6 +28,100 @@ pub struct EventsMeasurerAdapter<'a> {
     pub scale_factor: f64,
 }
 
+impl EventsMeasurerAdapter<'_> {
+    /// Update the [TextSelection] with the given events and then measure them.
+    pub fn run(
+        &mut self,
+        events: &mut Vec<PlatformEvent>,
+        nodes_state: &mut NodesState<NodeId>,
+        focus_id: Option<NodeId>,
+    ) -> ProcessedEvents<NodeId, EventName, EmmitableEvent, PlatformEvent> {
+        let mut text_selection = std::mem::take(&mut self.tree.text_selection);
+        let selected_nodes = text_selection.nodes().to_vec();
+        for event in events.iter() {
+            text_selection.process_event(self, event, focus_id);
+        }
+        if text_selection.has_changed() {
+            for node_id in selected_nodes.iter().chain(text_selection.nodes()) {
+                self.tree.invalidate_cache_layers(*node_id);
+            }
+        }
+        self.tree.text_selection = text_selection;
+
+        let pointer = events.iter().rev().find_map(|event| match event {
+            PlatformEvent::Mouse {
+                name: MouseEventName::MouseMove,
+                cursor,
+                ..
+            } => Some(*cursor),
+            _ => None,
+        });
+        if let Some(pointer) = pointer {
+            self.tree.hovered_cursor.set_pointer(pointer);
+            self.update_hovered_cursor();
+        }
+
+        ragnarok::EventsMeasurerRunner::run(self, events, nodes_state, focus_id)
+    }
+
+    /// Update the [HoveredCursor](crate::cursor::HoveredCursor) with the cursor of the
+    /// topmost element under the last position of the pointer.
+    pub fn update_hovered_cursor(&mut self) {
+        let Some(pointer) = self.tree.hovered_cursor.pointer() else {
+            return;
+        };
+        // Elements that are not interactive let the pointer through
+        let cursor = self
+            .tree
+            .layers
+            .paint_order()
+            .rev()
+            .find(|node_id| {
+                let interactive = self
+                    .tree
+                    .effect_state
+                    .get(node_id)
+                    .is_none_or(|effect_state| effect_state.interactive != Interactive::No);
+                interactive && self.is_point_inside(node_id, pointer)
+            })
+            .and_then(|node_id| self.tree.effect_state.get(node_id))
+            .and_then(|effect_state| effect_state.cursor.clone());
+        self.tree.hovered_cursor.update(cursor);
+    }
+
+    /// Topmost element under the cursor, in paint order.
+    pub(crate) fn node_at(&self, cursor: CursorPoint) -> Option<NodeId> {
+        self.tree
+            .layers
+            .paint_order()
+            .rev()
+            .find(|node_id| self.is_point_inside(node_id, cursor))
+            .copied()
+    }
+
+    /// Map the cursor to the coordinates of the given node before its rotations and transforms.
+    pub(crate) fn local_cursor(&self, node_id: &NodeId, cursor: CursorPoint) -> CursorPoint {
+        let Some(effect_state) = self.tree.effect_state.get(node_id) else {
+            return cursor;
+        };
+        if effect_state.transforms.is_empty() {
+            return cursor;
+        }
+        let matrix = effect_state.transform_matrix(
+            &self.tree.layout,
+            &self.tree.effect_state,
+            self.scale_factor as f32,
+        );
+        match matrix.invert() {
+            Some(inverse) => {
+                let point = inverse.map_point((cursor.x as f32, cursor.y as f32));
+                CursorPoint::new(point.x as f64, point.y as f64)
+            }
+            None => cursor,
+        }
+    }
+}
+
 impl ragnarok::EventsMeasurer for EventsMeasurerAdapter<'_> {
     type Key = NodeId;
     type Name = EventName;
@@ -40,10 +145,8 @@ impl ragnarok::EventsMeasurer for EventsMeasurerAdapter<'_> {
     }
 
     fn get_layers(&self) -> impl Iterator<Item = (&i16, impl Iterator<Item = &Self::Key>)> {
-        self.tree
-            .layers
-            .iter()
-            .map(|(layer, nodes)| (layer, nodes.iter()))
+        // The nodes are already sorted from the bottom to the top
+        std::iter::once((&0, self.tree.layers.paint_order()))
     }
 
     fn is_point_inside(&self, key: &Self::Key, cursor: ragnarok::CursorPoint) -> bool {
@@ -54,7 +157,7 @@ impl ragnarok::EventsMeasurer for EventsMeasurerAdapter<'_> {
 
         // Make sure the cursor is inside the element
         if !element.is_point_inside(EventMeasurementContext {
-            cursor,
+            cursor: self.local_cursor(key, cursor),
             layout_node,
             scale_factor: self.scale_factor,
         }) {
@@ -69,13 +172,28 @@ impl ragnarok::EventsMeasurer for EventsMeasurerAdapter<'_> {
                 let element = self.tree.elements.get(node_id).unwrap();
                 let layout_node = self.tree.layout.get(node_id).unwrap();
                 if !element.is_point_inside(EventMeasurementContext {
-                    cursor,
+                    cursor: self.local_cursor(node_id, cursor),
                     layout_node,
                     scale_factor: self.scale_factor,
                 }) {
                     return false;
                 }
             }
+
+            // Make sure the cursor is inside all the inherited clip paths of the element
+            for node_id in effect_state.clip_paths.iter() {
+                let layout_node = self.tree.layout.get(node_id).unwrap();
+                let effect = self.tree.effect_state.get(node_id).unwrap();
+                if let Some(clip_path) = &effect.clip_path
+                    && !clip_path.contains(
+                        &layout_node.visible_area(),
+                        self.scale_factor as f32,
+                        self.local_cursor(node_id, cursor),
+                    )
+                {
+                    return false;
+                }
+            }
         }
 
         true
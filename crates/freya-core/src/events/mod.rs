@@ -1,6 +1,10 @@
+pub mod click_counter;
 pub mod data;
+pub mod drag_tracker;
 pub mod emittable;
 pub mod executor;
+pub mod ime_composition;
 pub mod measurer;
 pub mod name;
 pub mod platform;
+pub mod recording;
@@ -4,6 +4,7 @@ pub enum EventName {
     MouseUp,
     MouseDown,
     MouseMove,
+    DoubleClick,
 
     // Platform Mouse or Touch
     PointerPress,
@@ -44,6 +45,29 @@ pub enum EventName {
     FileDrop,
 
     ImePreedit,
+    ImeCompositionStart,
+    ImeCompositionEnd,
+
+    // Platform Touchpad
+    Pinch,
+    Rotate,
+    Pan,
+
+    // Platform Stylus
+    PenDown,
+    PenMove,
+    PenUp,
+
+    // In-app drag and drop
+    DragStart,
+    DragOver,
+    Drop,
+    GlobalDragOver,
+    GlobalDrop,
+    GlobalDragCancel,
+
+    // Assistive technologies
+    AccessibilityAction,
 }
 
 use std::collections::HashSet;
@@ -138,6 +162,10 @@ impl ragnarok::NameOfEvent for EventName {
 
             Self::GlobalFileHover => HashSet::from([Self::GlobalFileHover]),
             Self::GlobalFileHoverCancelled => HashSet::from([Self::GlobalFileHoverCancelled]),
+
+            Self::DragOver => HashSet::from([Self::GlobalDragOver]),
+            Self::Drop => HashSet::from([Self::GlobalDrop]),
+            Self::GlobalDragCancel => HashSet::from([Self::GlobalDragCancel]),
             _ => HashSet::new(),
         }
     }
@@ -223,6 +251,9 @@ impl ragnarok::NameOfEvent for EventName {
                 | Self::GlobalPointerMove
                 | Self::GlobalFileHover
                 | Self::GlobalFileHoverCancelled
+                | Self::GlobalDragOver
+                | Self::GlobalDrop
+                | Self::GlobalDragCancel
         )
     }
 
@@ -234,6 +265,20 @@ impl ragnarok::NameOfEvent for EventName {
                 | Self::PointerMove
                 | Self::CaptureGlobalPointerMove
                 | Self::GlobalPointerMove
+                | Self::PenMove
+        )
+    }
+
+    fn is_capturable(&self) -> bool {
+        matches!(
+            self,
+            Self::MouseMove
+                | Self::MouseUp
+                | Self::TouchMove
+                | Self::TouchEnd
+                | Self::TouchCancel
+                | Self::PenMove
+                | Self::PenUp
         )
     }
 
@@ -250,8 +295,8 @@ impl ragnarok::NameOfEvent for EventName {
     }
 
     fn does_go_through_solid(&self) -> bool {
-        // TODO
-        false
+        // Drag previews are rendered under the cursor
+        matches!(self, Self::DragOver | Self::Drop)
     }
 
     fn is_enter(&self) -> bool {
@@ -10,7 +10,10 @@ use torin::prelude::CursorPoint;
 use crate::{
     events::{
         data::{
+            DragData,
+            GestureDelta,
             MouseButton,
+            PenState,
             WheelSource,
         },
         name::EventName,
@@ -21,11 +24,13 @@ use crate::{
     },
 };
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Copy, Eq, Hash)]
 pub enum MouseEventName {
     MouseUp,
     MouseDown,
     MouseMove,
+    DoubleClick,
 }
 
 impl From<MouseEventName> for EventName {
@@ -34,10 +39,12 @@ impl From<MouseEventName> for EventName {
             MouseEventName::MouseUp => EventName::MouseUp,
             MouseEventName::MouseMove => EventName::MouseMove,
             MouseEventName::MouseDown => EventName::MouseDown,
+            MouseEventName::DoubleClick => EventName::DoubleClick,
         }
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Copy, Eq, Hash)]
 pub enum WheelEventName {
     Wheel,
@@ -51,6 +58,7 @@ impl From<WheelEventName> for EventName {
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Copy, Eq, Hash)]
 pub enum KeyboardEventName {
     KeyDown,
@@ -66,6 +74,7 @@ impl From<KeyboardEventName> for EventName {
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Copy, Eq, Hash)]
 pub enum FileEventName {
     FileDrop,
@@ -83,7 +92,63 @@ impl From<FileEventName> for EventName {
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Copy, Eq, Hash)]
+pub enum GestureEventName {
+    Pinch,
+    Rotate,
+    Pan,
+}
+
+impl From<GestureEventName> for EventName {
+    fn from(value: GestureEventName) -> Self {
+        match value {
+            GestureEventName::Pinch => EventName::Pinch,
+            GestureEventName::Rotate => EventName::Rotate,
+            GestureEventName::Pan => EventName::Pan,
+        }
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Copy, Eq, Hash)]
+pub enum PenEventName {
+    PenDown,
+    PenMove,
+    PenUp,
+}
+
+impl From<PenEventName> for EventName {
+    fn from(value: PenEventName) -> Self {
+        match value {
+            PenEventName::PenDown => EventName::PenDown,
+            PenEventName::PenMove => EventName::PenMove,
+            PenEventName::PenUp => EventName::PenUp,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Copy, Eq, Hash)]
+pub enum DragEventName {
+    DragStart,
+    DragOver,
+    Drop,
+    DragCancel,
+}
+
+impl From<DragEventName> for EventName {
+    fn from(value: DragEventName) -> Self {
+        match value {
+            DragEventName::DragStart => EventName::DragStart,
+            DragEventName::DragOver => EventName::DragOver,
+            DragEventName::Drop => EventName::Drop,
+            DragEventName::DragCancel => EventName::GlobalDragCancel,
+        }
+    }
+}
+
 /// Data for [PlatformEvent].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq)]
 pub enum PlatformEvent {
     /// A Mouse Event.
@@ -91,6 +156,8 @@ pub enum PlatformEvent {
         name: MouseEventName,
         cursor: CursorPoint,
         button: Option<MouseButton>,
+        /// Number of consecutive clicks, `0` for movement events.
+        click_count: u8,
     },
     /// A Keyboard Event.
     Keyboard {
@@ -126,21 +193,47 @@ pub enum PlatformEvent {
         cursor: CursorPoint,
         file_path: Option<PathBuf>,
     },
+    /// A touchpad Gesture event.
+    Gesture {
+        name: GestureEventName,
+        cursor: CursorPoint,
+        delta: GestureDelta,
+        phase: TouchPhase,
+    },
+    /// A stylus Event.
+    Pen {
+        name: PenEventName,
+        location: CursorPoint,
+        state: PenState,
+    },
+    /// An in-app drag Event.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Drag {
+        name: DragEventName,
+        cursor: CursorPoint,
+        drag_data: DragData,
+    },
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Copy, Eq, Hash)]
 pub enum ImeEventName {
     Preedit,
+    CompositionStart,
+    CompositionEnd,
 }
 
 impl From<ImeEventName> for EventName {
     fn from(value: ImeEventName) -> Self {
         match value {
             ImeEventName::Preedit => EventName::ImePreedit,
+            ImeEventName::CompositionStart => EventName::ImeCompositionStart,
+            ImeEventName::CompositionEnd => EventName::ImeCompositionEnd,
         }
     }
 }
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Copy, Eq, Hash)]
 pub enum TouchEventName {
     TouchStart,
@@ -173,6 +266,9 @@ impl ragnarok::SourceEvent for PlatformEvent {
             } | Self::Touch {
                 phase: TouchPhase::Moved,
                 ..
+            } | Self::Pen {
+                name: PenEventName::PenMove,
+                ..
             }
         )
     }
@@ -202,6 +298,23 @@ impl ragnarok::SourceEvent for PlatformEvent {
         )
     }
 
+    /// Check if this event releases the pointer device.
+    fn is_released(&self) -> bool {
+        matches!(
+            &self,
+            Self::Mouse {
+                name: MouseEventName::MouseUp,
+                ..
+            } | Self::Touch {
+                phase: TouchPhase::Ended | TouchPhase::Cancelled,
+                ..
+            } | Self::Pen {
+                name: PenEventName::PenUp,
+                ..
+            }
+        )
+    }
+
     fn as_event_name(&self) -> EventName {
         match self {
             Self::Mouse { name, .. } => (*name).into(),
@@ -210,6 +323,9 @@ impl ragnarok::SourceEvent for PlatformEvent {
             Self::ImePreedit { name, .. } => (*name).into(),
             Self::Touch { name, .. } => (*name).into(),
             Self::File { name, .. } => (*name).into(),
+            Self::Gesture { name, .. } => (*name).into(),
+            Self::Pen { name, .. } => (*name).into(),
+            Self::Drag { name, .. } => (*name).into(),
         }
     }
 
@@ -219,6 +335,9 @@ impl ragnarok::SourceEvent for PlatformEvent {
             PlatformEvent::Wheel { cursor, .. } => Some(*cursor),
             PlatformEvent::Touch { location, .. } => Some(*location),
             PlatformEvent::File { cursor, .. } => Some(*cursor),
+            PlatformEvent::Gesture { cursor, .. } => Some(*cursor),
+            PlatformEvent::Pen { location, .. } => Some(*location),
+            PlatformEvent::Drag { cursor, .. } => Some(*cursor),
             _ => None,
         }
     }
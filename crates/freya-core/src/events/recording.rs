@@ -0,0 +1,162 @@
+use std::time::Duration;
+
+use torin::prelude::{
+    CursorPoint,
+    Size2D,
+};
+
+use crate::events::{
+    data::GestureDelta,
+    platform::PlatformEvent,
+};
+
+/// [PlatformEvent] received by a window while recording its events.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordedEvent {
+    /// Time since the recording started.
+    pub timestamp: Duration,
+    pub event: PlatformEvent,
+}
+
+/// Window the events of an [EventRecording] were received by.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct RecordedWindow {
+    scale_factor: f64,
+    window_size: Size2D,
+}
+
+/// Events recorded from a window in the order they were received, to be replayed later on.
+///
+/// In-app drag events are not recorded as their payload can't be serialized.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EventRecording {
+    /// Scale factor of the recorded window.
+    pub scale_factor: f64,
+    /// Size of the recorded window in physical pixels.
+    pub window_size: Size2D,
+    pub events: Vec<RecordedEvent>,
+}
+
+impl EventRecording {
+    /// Create an empty recording of a window with the given scale factor and physical size.
+    pub fn new(scale_factor: f64, window_size: Size2D) -> Self {
+        Self {
+            scale_factor,
+            window_size,
+            events: Vec::new(),
+        }
+    }
+
+    /// Add an event unless it can't be replayed.
+    pub fn push(&mut self, timestamp: Duration, event: PlatformEvent) {
+        if !matches!(event, PlatformEvent::Drag { .. }) {
+            self.events.push(RecordedEvent { timestamp, event });
+        }
+    }
+
+    /// The recorded events with their positions moved to a window with the given scale factor and physical size.
+    ///
+    /// Positions keep their relative place in the window, so they land on the same elements
+    /// as long as the layout grows with the window.
+    pub fn events_for(
+        &self,
+        scale_factor: f64,
+        window_size: Size2D,
+    ) -> impl Iterator<Item = RecordedEvent> + '_ {
+        let scale = scale_factor / self.scale_factor;
+        let ratio = |to: f32, from: f32| {
+            if from > 0. {
+                to as f64 / from as f64
+            } else {
+                scale
+            }
+        };
+        let ratio = (
+            ratio(window_size.width, self.window_size.width),
+            ratio(window_size.height, self.window_size.height),
+        );
+        let position =
+            move |point: CursorPoint| CursorPoint::new(point.x * ratio.0, point.y * ratio.1);
+
+        self.events.iter().cloned().map(move |mut recorded| {
+            match &mut recorded.event {
+                PlatformEvent::Mouse { cursor, .. }
+                | PlatformEvent::Wheel { cursor, .. }
+                | PlatformEvent::File { cursor, .. }
+                | PlatformEvent::Drag { cursor, .. } => *cursor = position(*cursor),
+                PlatformEvent::Touch { location, .. } | PlatformEvent::Pen { location, .. } => {
+                    *location = position(*location)
+                }
+                PlatformEvent::Gesture { cursor, delta, .. } => {
+                    *cursor = position(*cursor);
+                    if let GestureDelta::Pan(pan) = delta {
+                        *pan = *pan * scale;
+                    }
+                }
+                PlatformEvent::Keyboard { .. } | PlatformEvent::ImePreedit { .. } => {}
+            }
+            recorded
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl EventRecording {
+    /// Serialize the scale factor and size of the recorded window as the first JSON line of a recording file.
+    pub fn header_to_json_line(
+        scale_factor: f64,
+        window_size: Size2D,
+    ) -> serde_json::Result<String> {
+        serde_json::to_string(&RecordedWindow {
+            scale_factor,
+            window_size,
+        })
+        .map(|line| line + "\n")
+    }
+
+    /// Serialize an event as a single JSON line, the format used by recording files.
+    pub fn event_to_json_line(event: &RecordedEvent) -> serde_json::Result<String> {
+        serde_json::to_string(event).map(|line| line + "\n")
+    }
+
+    /// Parse a recording with the recorded window in the first line and then one JSON event per line,
+    /// empty lines are ignored.
+    pub fn from_json_lines(text: &str) -> serde_json::Result<Self> {
+        let mut lines = text.lines().filter(|line| !line.trim().is_empty());
+        let RecordedWindow {
+            scale_factor,
+            window_size,
+        } = serde_json::from_str(lines.next().unwrap_or_default())?;
+        let events = lines
+            .map(serde_json::from_str)
+            .collect::<serde_json::Result<_>>()?;
+        Ok(Self {
+            scale_factor,
+            window_size,
+            events,
+        })
+    }
+
+    /// Serialize the recording with the recorded window in the first line and then one JSON event per line.
+    pub fn to_json_lines(&self) -> serde_json::Result<String> {
+        let header = Self::header_to_json_line(self.scale_factor, self.window_size)?;
+        self.events
+            .iter()
+            .map(Self::event_to_json_line)
+            .collect::<serde_json::Result<String>>()
+            .map(|events| header + &events)
+    }
+
+    /// Load a recording file.
+    pub fn load(path: impl AsRef<std::path::Path>) -> std::io::Result<Self> {
+        let text = std::fs::read_to_string(path)?;
+        Ok(Self::from_json_lines(&text)?)
+    }
+
+    /// Save the recording to a file.
+    pub fn save(&self, path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        std::fs::write(path, self.to_json_lines()?)
+    }
+}
@@ -26,6 +26,14 @@ where
         Self::default()
     }
 
+    /// Create a cache that keeps the given number of entries before evicting the oldest.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity,
+            ..Self::default()
+        }
+    }
+
     pub fn insert(&mut self, key: K, value: V) {
         if self.map.contains_key(&key) {
             self.map.insert(key, value);
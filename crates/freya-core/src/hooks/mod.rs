@@ -1,2 +1,5 @@
 pub mod previous_and_current;
+pub mod use_gesture;
 pub mod use_id;
+pub mod use_roving_focus;
+pub mod use_visibility;
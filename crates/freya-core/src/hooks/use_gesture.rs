@@ -0,0 +1,157 @@
+use std::time::{
+    Duration,
+    Instant,
+};
+
+use rustc_hash::FxHashMap;
+use torin::prelude::CursorPoint;
+
+use crate::prelude::{
+    State,
+    TouchEventData,
+    TouchPhase,
+    use_hook,
+};
+
+/// Minimum distance in logical pixels a finger needs to travel for a swipe.
+pub const SWIPE_MIN_DISTANCE: f64 = 50.;
+
+/// Maximum duration of a swipe.
+pub const SWIPE_MAX_DURATION: Duration = Duration::from_millis(500);
+
+/// Gesture recognized by [use_gesture].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Gesture {
+    SwipeLeft,
+    SwipeRight,
+    SwipeUp,
+    SwipeDown,
+    /// Two fingers pinching, `scale` is relative to the distance between the fingers when the pinch started.
+    Pinch {
+        scale: f64,
+    },
+}
+
+struct Finger {
+    start: CursorPoint,
+    current: CursorPoint,
+    started: Instant,
+}
+
+#[derive(Default)]
+struct GestureTracker {
+    fingers: FxHashMap<u64, Finger>,
+    pinch_distance: Option<f64>,
+    multi_touch: bool,
+}
+
+impl GestureTracker {
+    fn fingers_distance(&self) -> Option<f64> {
+        let mut fingers = self.fingers.values();
+        match (fingers.next(), fingers.next(), fingers.next()) {
+            (Some(a), Some(b), None) => Some(a.current.distance_to(b.current)),
+            _ => None,
+        }
+    }
+}
+
+/// Recognizes gestures from raw touch events. See [use_gesture].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseGesture {
+    tracker: State<GestureTracker>,
+}
+
+impl UseGesture {
+    /// Feed a touch event, returns the [Gesture] it completes, if any.
+    pub fn handle(&mut self, data: &TouchEventData) -> Option<Gesture> {
+        let mut tracker = self.tracker.write();
+        let location = data.global_location;
+
+        match data.phase {
+            TouchPhase::Started => {
+                tracker.fingers.insert(
+                    data.finger_id,
+                    Finger {
+                        start: location,
+                        current: location,
+                        started: Instant::now(),
+                    },
+                );
+                if tracker.fingers.len() > 1 {
+                    tracker.multi_touch = true;
+                }
+                tracker.pinch_distance = tracker.fingers_distance();
+                None
+            }
+            TouchPhase::Moved => {
+                tracker.fingers.get_mut(&data.finger_id)?.current = location;
+                let start_distance = tracker.pinch_distance.filter(|distance| *distance > 0.)?;
+                let distance = tracker.fingers_distance()?;
+                Some(Gesture::Pinch {
+                    scale: distance / start_distance,
+                })
+            }
+            TouchPhase::Ended | TouchPhase::Cancelled => {
+                let finger = tracker.fingers.remove(&data.finger_id)?;
+                let multi_touch = tracker.multi_touch;
+                tracker.pinch_distance = tracker.fingers_distance();
+                if tracker.fingers.is_empty() {
+                    tracker.multi_touch = false;
+                }
+
+                if data.phase == TouchPhase::Cancelled
+                    || multi_touch
+                    || finger.started.elapsed() > SWIPE_MAX_DURATION
+                {
+                    return None;
+                }
+
+                let delta = location - finger.start;
+                if delta.length() < SWIPE_MIN_DISTANCE {
+                    return None;
+                }
+
+                Some(if delta.x.abs() > delta.y.abs() {
+                    if delta.x > 0. {
+                        Gesture::SwipeRight
+                    } else {
+                        Gesture::SwipeLeft
+                    }
+                } else if delta.y > 0. {
+                    Gesture::SwipeDown
+                } else {
+                    Gesture::SwipeUp
+                })
+            }
+        }
+    }
+}
+
+/// Recognize swipes and two fingers pinches from the touch events of an element.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> impl IntoElement {
+///     let mut gesture = use_gesture();
+///     let mut scale = use_state(|| 1.);
+///
+///     let on_touch = move |e: Event<TouchEventData>| {
+///         if let Some(Gesture::Pinch { scale: s }) = gesture.handle(&e) {
+///             scale.set(s);
+///         }
+///     };
+///
+///     rect()
+///         .expanded()
+///         .on_touch_start(on_touch)
+///         .on_touch_move(on_touch)
+///         .on_touch_end(on_touch)
+///         .on_touch_cancel(on_touch)
+///         .child(format!("Scale: {}", scale()))
+/// }
+/// ```
+pub fn use_gesture() -> UseGesture {
+    use_hook(|| UseGesture {
+        tracker: State::create(GestureTracker::default()),
+    })
+}
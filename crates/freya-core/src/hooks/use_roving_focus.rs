@@ -0,0 +1,168 @@
+use keyboard_types::{
+    Key,
+    NamedKey,
+};
+
+use crate::{
+    accessibility::id::AccessibilityId,
+    prelude::{
+        AccessibilityFocusStrategy,
+        AccessibilityFocusTarget,
+        Event,
+        Focus,
+        KeyboardEventData,
+        Platform,
+        State,
+        UserEvent,
+        WritableUtils,
+        use_consume,
+        use_drop,
+        use_focus,
+        use_hook,
+        use_provide_context,
+        use_side_effect,
+    },
+};
+
+/// Arrow keys that move the focus between the items of a [RovingFocus].
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum RovingDirection {
+    #[default]
+    Vertical,
+    Horizontal,
+    Both,
+}
+
+/// Composite widget (menu, radio group, toolbar...) that is a single `Tab` stop,
+/// with the arrow keys moving the focus between its items.
+///
+/// Provided to the descendants by [use_roving_focus], items join it with [use_roving_item].
+#[derive(Clone, Copy, PartialEq)]
+pub struct RovingFocus {
+    /// Items in order of creation, the arrow keys move between them in the order of the tree.
+    items: State<Vec<AccessibilityId>>,
+    active: State<Option<AccessibilityId>>,
+    direction: RovingDirection,
+}
+
+impl RovingFocus {
+    /// Item reached with `Tab`, the last focused one or else the first one created.
+    pub fn active(&self) -> Option<AccessibilityId> {
+        let items = self.items.read();
+        (*self.active.read())
+            .filter(|active| items.contains(active))
+            .or_else(|| items.first().copied())
+    }
+
+    /// Make the given item the active one and focus it.
+    pub fn focus(&self, a11y_id: AccessibilityId) {
+        let mut active = self.active;
+        active.set(Some(a11y_id));
+        Focus::new_for_id(a11y_id).request_focus();
+    }
+}
+
+/// Create a [RovingFocus] for the items created below this component.
+pub fn use_roving_focus(direction: RovingDirection) -> RovingFocus {
+    let roving = use_provide_context(|| RovingFocus {
+        items: State::create(Vec::new()),
+        active: State::create(None),
+        direction,
+    });
+
+    // Follow the focus as it moves between the items
+    use_side_effect(move || {
+        let focused_id = *Platform::get().focused_accessibility_id.read();
+        if roving.items.peek().contains(&focused_id) {
+            let mut active = roving.active;
+            active.set_if_modified(Some(focused_id));
+        }
+    });
+
+    roving
+}
+
+/// Item of the closest [RovingFocus].
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # use freya::prelude::*;
+/// #[derive(PartialEq)]
+/// struct ToolbarButton(&'static str);
+///
+/// impl Component for ToolbarButton {
+///     fn render(&self) -> impl IntoElement {
+///         let item = use_roving_item();
+///
+///         rect()
+///             .a11y_id(item.a11y_id())
+///             .a11y_focusable(true)
+///             .a11y_tab_index(item.tab_index())
+///             .on_key_down(move |e: Event<KeyboardEventData>| item.on_key_down(&e))
+///             .child(self.0)
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct RovingItem {
+    roving: RovingFocus,
+    focus: Focus,
+}
+
+pub fn use_roving_item() -> RovingItem {
+    let mut roving = use_consume::<RovingFocus>();
+    let focus = use_focus();
+    use_drop(move || {
+        roving.items.write().retain(|id| *id != focus.a11y_id());
+    });
+    use_hook(move || {
+        roving.items.write().push(focus.a11y_id());
+        RovingItem { roving, focus }
+    })
+}
+
+impl RovingItem {
+    pub fn a11y_id(&self) -> AccessibilityId {
+        self.focus.a11y_id()
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.roving.active() == Some(self.a11y_id())
+    }
+
+    /// `0` for the active item and `-1` for the rest, so only the active one is reached with `Tab`.
+    pub fn tab_index(&self) -> i32 {
+        if self.is_active() { 0 } else { -1 }
+    }
+
+    /// Make this the active item, for instance when pressed.
+    pub fn activate(&self) {
+        let mut active = self.roving.active;
+        active.set_if_modified(Some(self.a11y_id()));
+    }
+
+    /// Move the focus to the previous or next item with the arrow keys,
+    /// or to the first and last with `Home` and `End`.
+    pub fn on_key_down(&self, event: &Event<KeyboardEventData>) {
+        let (vertical, horizontal) = match self.roving.direction {
+            RovingDirection::Vertical => (true, false),
+            RovingDirection::Horizontal => (false, true),
+            RovingDirection::Both => (true, true),
+        };
+        let target = match event.key {
+            Key::Named(NamedKey::ArrowDown) if vertical => AccessibilityFocusTarget::Next,
+            Key::Named(NamedKey::ArrowRight) if horizontal => AccessibilityFocusTarget::Next,
+            Key::Named(NamedKey::ArrowUp) if vertical => AccessibilityFocusTarget::Previous,
+            Key::Named(NamedKey::ArrowLeft) if horizontal => AccessibilityFocusTarget::Previous,
+            Key::Named(NamedKey::Home) => AccessibilityFocusTarget::First,
+            Key::Named(NamedKey::End) => AccessibilityFocusTarget::Last,
+            _ => return,
+        };
+        event.stop_propagation();
+        let items = self.roving.items.peek().clone();
+        Platform::get().send(UserEvent::FocusAccessibilityNode(
+            AccessibilityFocusStrategy::Among(items, target),
+        ));
+    }
+}
@@ -0,0 +1,113 @@
+use std::{
+    cell::RefCell,
+    rc::Rc,
+};
+
+use crate::{
+    accessibility::id::AccessibilityId,
+    prelude::{
+        State,
+        WritableUtils,
+        consume_root_context,
+        use_drop,
+        use_hook,
+    },
+    tree::Tree,
+};
+
+/// Elements tracked with [use_visibility], updated by the [Tree] after every layout.
+#[derive(Clone, Default)]
+pub struct VisibilityTracker(Rc<RefCell<Vec<(AccessibilityId, State<f32>)>>>);
+
+impl VisibilityTracker {
+    fn insert(&self, a11y_id: AccessibilityId, ratio: State<f32>) {
+        self.0.borrow_mut().push((a11y_id, ratio));
+    }
+
+    fn remove(&self, ratio: State<f32>) {
+        self.0.borrow_mut().retain(|(_, tracked)| *tracked != ratio);
+    }
+
+    /// Update the ratios of the tracked elements, they might have been moved, resized or scrolled.
+    pub fn update(&self, tree: &Tree) {
+        let tracked = self.0.borrow();
+        if tracked.is_empty() {
+            return;
+        }
+        for (node_id, state) in &tree.accessibility_state {
+            for (a11y_id, ratio) in tracked.iter() {
+                if *a11y_id != state.a11y_id {
+                    continue;
+                }
+                let area = tree
+                    .layout
+                    .get(node_id)
+                    .map(|layout_node| layout_node.visible_area().area())
+                    .unwrap_or_default();
+                let visible_ratio = if area > 0. {
+                    (tree.clipped_area(*node_id).area() / area).clamp(0., 1.)
+                } else {
+                    0.
+                };
+                let mut ratio = *ratio;
+                ratio.set_if_modified(visible_ratio);
+            }
+        }
+    }
+}
+
+/// Tracks how much of an element is visible. See [use_visibility].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseVisibility {
+    ratio: State<f32>,
+}
+
+impl UseVisibility {
+    /// Portion of the element inside the window and its scroll views, from `0.` to `1.`.
+    pub fn ratio(&self) -> f32 {
+        *self.ratio.read()
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.ratio() > 0.
+    }
+
+    pub fn is_fully_visible(&self) -> bool {
+        self.ratio() >= 1.
+    }
+}
+
+/// Know whether the element with the given [AccessibilityId] is inside the window and the
+/// scroll views containing it, for example to lazy load images or pause animations that are out of sight.
+///
+/// The ratio is updated after every layout, so it follows the element as it moves, resizes or
+/// scrolls. The element is the one the id was given to with
+/// [a11y_id](crate::prelude::AccessibilityExt::a11y_id) and it is expected to stay the same.
+///
+/// Reading the ratio of a partially visible element reruns the component as it scrolls,
+/// prefer [UseVisibility::is_visible] in a memo when only that matters.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> impl IntoElement {
+///     let a11y_id = use_hook(Focus::new_id);
+///     let visibility = use_visibility(a11y_id);
+///
+///     rect()
+///         .a11y_id(a11y_id)
+///         .height(Size::px(100.))
+///         .child(format!("Visible: {:.0}%", visibility.ratio() * 100.))
+/// }
+/// ```
+pub fn use_visibility(a11y_id: AccessibilityId) -> UseVisibility {
+    let (tracker, ratio) = use_hook(|| {
+        let tracker = consume_root_context::<VisibilityTracker>();
+        let ratio = State::create(0.);
+        tracker.insert(a11y_id, ratio);
+        (tracker, ratio)
+    });
+
+    use_drop(move || tracker.remove(ratio));
+
+    UseVisibility { ratio }
+}
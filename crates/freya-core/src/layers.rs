@@ -1,11 +1,6 @@
-use std::ops::{
-    Deref,
-    DerefMut,
-};
-
-use rustc_hash::{
-    FxHashMap,
-    FxHashSet,
+use std::{
+    collections::BTreeMap,
+    rc::Rc,
 };
 
 use crate::node_id::NodeId;
@@ -29,39 +24,48 @@ impl From<i16> for Layer {
     }
 }
 
+/// Position of an element inside a stacking context.
+///
+/// Elements with a z-index create a stacking context where they and their descendants are painted together,
+/// below the rest of the parent context for negative values and above it for positive ones.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct StackingLevel {
+    /// Overlays are painted above any stacking context.
+    pub overlay: i16,
+    pub z_index: i16,
+    pub layer: i16,
+    /// Elements with the same layer and z-index are painted in the order they were created.
+    pub node_id: NodeId,
+}
+
+/// Position of an element in the paint order, one [StackingLevel] for every stacking context it is inside of.
+pub type PaintKey = Rc<[StackingLevel]>;
+
+/// Nodes sorted by the order in which they are painted.
 #[derive(Default, Clone, Debug, PartialEq)]
-pub struct Layers(FxHashMap<i16, FxHashSet<NodeId>>);
+pub struct Layers(BTreeMap<PaintKey, NodeId>);
 
 impl Layers {
-    /// Insert the given [NodeId] in the given layer. Will create an entry for the layer if missing.
-    pub fn insert_node_in_layer(&mut self, node_id: NodeId, layer_n: i16) {
-        let layer = self.0.entry(layer_n).or_default();
-        layer.insert(node_id);
+    /// Insert the given [NodeId] in the given position of the paint order.
+    pub fn insert_node(&mut self, node_id: NodeId, paint_key: PaintKey) {
+        self.0.insert(paint_key, node_id);
     }
 
-    /// Remove the [NodeId] from the given layer. Will remove the entry of the layer if it becomes empty.
-    pub fn remove_node_from_layer(&mut self, node_id: &NodeId, layer_n: i16) {
-        let layer = self.0.get_mut(&layer_n);
-        if let Some(layer) = layer {
-            layer.remove(node_id);
-
-            if layer.is_empty() {
-                self.0.remove(&layer_n);
-            }
-        }
+    /// Remove the node in the given position of the paint order.
+    pub fn remove_node(&mut self, paint_key: &PaintKey) {
+        self.0.remove(paint_key);
     }
-}
 
-impl Deref for Layers {
-    type Target = FxHashMap<i16, FxHashSet<NodeId>>;
+    /// Iterate over the nodes from the bottom to the top.
+    pub fn paint_order(&self) -> impl DoubleEndedIterator<Item = &NodeId> {
+        self.0.values()
+    }
 
-    fn deref(&self) -> &Self::Target {
-        &self.0
+    pub fn len(&self) -> usize {
+        self.0.len()
     }
-}
 
-impl DerefMut for Layers {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
     }
 }
@@ -1,5 +1,6 @@
 pub mod accessibility;
 pub mod animation_clock;
+pub mod cache_layers;
 pub mod current_context;
 pub mod cursor;
 pub mod data;
@@ -24,11 +25,15 @@ pub mod platform;
 pub mod reactive_context;
 pub mod render_pipeline;
 pub mod rendering_ticker;
+pub mod repaints;
 pub mod runner;
 pub mod scope;
 pub mod scope_id;
+pub mod shortcuts;
 pub mod style;
 pub mod text_cache;
+pub mod text_selection;
+pub mod transitions;
 pub mod tree;
 pub mod tree_layout_adapter;
 pub mod user_event;
@@ -74,6 +79,8 @@ pub mod prelude {
             image::{
                 AspectRatio,
                 ImageCover,
+                ImageSlice,
+                ImageSliceRepeat,
                 // The image element is hidden on purpose as its a "low level" element, users should rather use the `ImageViewer` component.
                 SamplingMode,
             },
@@ -107,7 +114,10 @@ pub mod prelude {
         events::*,
         events_combos::*,
         hooks::previous_and_current::*,
+        hooks::use_gesture::*,
         hooks::use_id::*,
+        hooks::use_roving_focus::*,
+        hooks::use_visibility::*,
         layers::Layer,
         lifecycle::{
             base::*,
@@ -126,25 +136,42 @@ pub mod prelude {
         reactive_context::ReactiveContext,
         rendering_ticker::RenderingTicker,
         scope_id::ScopeId,
+        shortcuts::*,
         style::{
+            blend_mode::*,
             border::*,
+            clip_path::*,
             color::*,
             corner_radius::*,
             cursor::*,
             fill::*,
+            filter::*,
+            font_features::*,
             font_slant::*,
             font_weight::*,
             font_width::*,
             gradient::*,
+            mask::*,
             scale::*,
+            shader::*,
             shadow::*,
             text_align::*,
             text_decoration::*,
+            text_direction::*,
             text_height::*,
             text_overflow::*,
             text_shadow::*,
+            text_spacing::*,
+            text_stroke::*,
+            text_wrap::*,
+            transform::*,
+            transition::*,
             vertical_align::*,
         },
+        text_selection::{
+            TextPosition,
+            TextSelection,
+        },
         user_event::UserEvent,
     };
 }
@@ -166,12 +193,17 @@ pub mod integration {
         element::*,
         elements::extensions::*,
         events::{
+            click_counter::*,
             data::*,
+            drag_tracker::*,
             executor::*,
+            ime_composition::*,
             measurer::*,
             name::*,
             platform::*,
+            recording::*,
         },
+        hooks::use_visibility::VisibilityTracker,
         lifecycle::{
             state::State,
             writable_utils::WritableUtils,
@@ -180,9 +212,11 @@ pub mod integration {
         platform::*,
         render_pipeline::RenderPipeline,
         rendering_ticker::*,
+        repaints::RepaintTracker,
         runner::Runner,
         scope_id::ScopeId,
         style::default_fonts::default_fonts,
+        transitions::ElementTransitions,
         tree::{
             DiffModifies,
             Tree,
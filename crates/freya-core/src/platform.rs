@@ -24,6 +24,16 @@ pub enum PreferredTheme {
     Dark,
 }
 
+/// How often a window renders, see [`Platform::set_render_mode`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum RenderMode {
+    /// Render only when something changed.
+    #[default]
+    OnDemand,
+    /// Render every frame, for real-time visualizations and games.
+    Continuous,
+}
+
 use crate::user_event::UserEvent;
 
 /// Access point to different Freya-managed states such as the focused node,
@@ -36,6 +46,8 @@ pub struct Platform {
     pub focused_accessibility_id: State<AccessibilityId>,
     /// The accessibility node data of the currently focused node.
     pub focused_accessibility_node: State<accesskit::Node>,
+    /// The [`AccessibilityId`]s of the ancestors of the focused node, from the root down.
+    pub focused_accessibility_ancestors: State<Vec<AccessibilityId>>,
     /// The size of the root window.
     pub root_size: State<Size2D>,
     /// The current [`NavigationMode`].
@@ -56,4 +68,14 @@ impl Platform {
     pub fn send(&self, event: UserEvent) {
         (self.sender)(event)
     }
+
+    /// Switch the window between rendering on demand and every frame.
+    pub fn set_render_mode(&self, render_mode: RenderMode) {
+        self.send(UserEvent::SetRenderMode(render_mode))
+    }
+
+    /// Tint the elements repainted every frame, useful to find what keeps an idle app rendering.
+    pub fn show_repaints(&self, show: bool) {
+        self.send(UserEvent::ShowRepaints(show))
+    }
 }
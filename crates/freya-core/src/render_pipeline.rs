@@ -1,24 +1,47 @@
+use std::ops::Range;
+
 use freya_engine::prelude::{
     Canvas,
     ClipOp,
     FontCollection,
     FontMgr,
+    Paint,
+    PictureRecorder,
     SaveLayerRec,
+    SkBlendMode,
     SkMatrix,
-    SkPoint,
+    SkRect,
     blur,
 };
+use rustc_hash::FxHashSet;
 
 use crate::{
+    accessibility::id::AccessibilityId,
+    cache_layers::CachedLayer,
     element::{
         ClipContext,
         RenderContext,
     },
+    node_id::NodeId,
     prelude::Color,
-    style::shadow::ShadowPosition,
+    style::{
+        mask::Mask,
+        shadow::ShadowPosition,
+    },
     tree::Tree,
 };
 
+/// What a rendering pass paints.
+#[derive(Clone, Copy)]
+struct RenderPass<'a> {
+    filter: &'a dyn Fn(&NodeId) -> bool,
+    use_cache_layers: bool,
+    /// Element whose mask layer is being painted.
+    mask_layer: Option<NodeId>,
+    /// Elements referenced by a [Mask::Node], they are only painted as part of the masks.
+    mask_sources: &'a FxHashSet<NodeId>,
+}
+
 pub struct RenderPipeline<'a> {
     pub font_collection: &'a mut FontCollection,
     pub font_manager: &'a FontMgr,
@@ -29,173 +52,501 @@ pub struct RenderPipeline<'a> {
 }
 
 impl RenderPipeline<'_> {
+    /// Returns `true` if a rendered element has to render again in the next frame.
     #[cfg_attr(feature = "hotpath", hotpath::measure)]
-    pub fn render(self) {
+    pub fn render(mut self) -> bool {
         self.canvas.clear(self.background);
 
-        // TODO: Use incremental rendering
-        for i16 in itertools::sorted(self.tree.layers.keys()) {
-            let nodes = self.tree.layers.get(i16).unwrap();
-            'rendering: for node_id in nodes {
-                let layer = self.canvas.save();
-
-                let element = self.tree.elements.get(node_id).unwrap();
-                let text_style_state = self.tree.text_style_state.get(node_id).unwrap();
-                let layout_node = self.tree.layout.get(node_id).unwrap();
-                let effect_state = self.tree.effect_state.get(node_id);
-
-                if let Some(effect_state) = effect_state {
-                    let mut visible_area = layout_node.visible_area();
-
-                    // Transform the element area given the scale effects
-                    for id in effect_state.scales.iter() {
-                        let layout_node = self.tree.layout.get(id).unwrap();
-                        let effect = self.tree.effect_state.get(id).unwrap();
-                        let area = layout_node.visible_area();
-                        let center = area.center();
-                        let scale = effect.scale.unwrap();
-
-                        visible_area = visible_area.translate(-center.to_vector());
-                        visible_area = visible_area.scale(scale.x, scale.y);
-                        visible_area = visible_area.translate(center.to_vector());
+        let mask_sources = self.mask_sources();
+        self.render_nodes(RenderPass {
+            filter: &|_| true,
+            use_cache_layers: true,
+            mask_layer: None,
+            mask_sources: &mask_sources,
+        })
+    }
+
+    /// Render only the given node and its descendants, with the top-left corner of the node at the origin of the canvas.
+    pub fn render_node(mut self, node_id: NodeId) -> bool {
+        self.canvas.clear(self.background);
+
+        let Some(layout_node) = self.tree.layout.get(&node_id) else {
+            return false;
+        };
+        let area = layout_node.visible_area();
+        self.canvas.translate((-area.min_x(), -area.min_y()));
+
+        let subtree = self.subtree(node_id);
+        let mask_sources = self.mask_sources();
+
+        // The cache layers might be outside of the subtree
+        self.render_nodes(RenderPass {
+            filter: &|node_id| subtree.contains(node_id),
+            use_cache_layers: false,
+            mask_layer: None,
+            mask_sources: &mask_sources,
+        })
+    }
+
+    /// The given node and all its descendants.
+    fn subtree(&self, node_id: NodeId) -> FxHashSet<NodeId> {
+        let mut subtree = FxHashSet::default();
+        let mut pending = vec![node_id];
+        while let Some(node_id) = pending.pop() {
+            subtree.insert(node_id);
+            if let Some(children) = self.tree.children.get(&node_id) {
+                pending.extend(children);
+            }
+        }
+        subtree
+    }
+
+    /// Node with the given accessibility id.
+    fn find_node(&self, a11y_id: AccessibilityId) -> Option<NodeId> {
+        self.tree
+            .accessibility_state
+            .iter()
+            .find_map(|(node_id, state)| (state.a11y_id == a11y_id).then_some(*node_id))
+    }
+
+    /// Elements referenced by a [Mask::Node] and their descendants.
+    fn mask_sources(&self) -> FxHashSet<NodeId> {
+        let mut mask_sources = FxHashSet::default();
+        for effect_state in self.tree.effect_state.values() {
+            if let Some(Mask::Node(a11y_id)) = effect_state.mask
+                && let Some(node_id) = self.find_node(a11y_id)
+            {
+                mask_sources.extend(self.subtree(node_id));
+            }
+        }
+        mask_sources
+    }
+
+    /// Rotations, transforms and scales applied to the given element.
+    fn element_matrix(&self, node_id: NodeId) -> SkMatrix {
+        let effect_state = self.tree.effect_state.get(&node_id).unwrap();
+        let mut matrix = effect_state.transform_matrix(
+            &self.tree.layout,
+            &self.tree.effect_state,
+            self.scale_factor as f32,
+        );
+        for id in effect_state.scales.iter() {
+            let area = self.tree.layout.get(id).unwrap().visible_area();
+            let center = area.center();
+            let scale = self.tree.effect_state.get(id).unwrap().scale.unwrap();
+            matrix.pre_translate((center.x, center.y));
+            matrix.pre_scale((scale.x, scale.y), None);
+            matrix.pre_translate((-center.x, -center.y));
+        }
+        matrix
+    }
+
+    /// Paint a masked element and its descendants in a separate layer, and apply the mask once on top of all of them.
+    fn render_mask_layer(&mut self, node_id: NodeId, pass: RenderPass) -> bool {
+        let tree = self.tree;
+        let Some(mask) = tree
+            .effect_state
+            .get(&node_id)
+            .and_then(|effect_state| effect_state.mask.as_ref())
+        else {
+            return false;
+        };
+        let area = tree.layout.get(&node_id).unwrap().visible_area();
+        let rect = SkRect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y());
+        let matrix = self.element_matrix(node_id);
+
+        // Anything outside of the element is hidden
+        let layer = self.canvas.save();
+        self.canvas.concat(&matrix);
+        self.canvas.clip_rect(rect, ClipOp::Intersect, true);
+        if let Some(inverse) = matrix.invert() {
+            self.canvas.concat(&inverse);
+        }
+        self.canvas.save_layer(&SaveLayerRec::default());
+
+        let needs_next_frame = self.render_nodes(RenderPass {
+            mask_layer: Some(node_id),
+            ..pass
+        });
+
+        self.canvas.concat(&matrix);
+        match mask {
+            Mask::Node(a11y_id) => {
+                if let Some(source_id) = self.find_node(*a11y_id) {
+                    let source = self.subtree(source_id);
+                    if !source.contains(&node_id) {
+                        let source_area = tree.layout.get(&source_id).unwrap().visible_area();
+                        let mut paint = Paint::default();
+                        paint.set_blend_mode(SkBlendMode::DstIn);
+                        self.canvas
+                            .save_layer(&SaveLayerRec::default().paint(&paint));
+                        self.canvas.translate((
+                            area.min_x() - source_area.min_x(),
+                            area.min_y() - source_area.min_y(),
+                        ));
+                        self.render_nodes(RenderPass {
+                            filter: &|node_id| source.contains(node_id),
+                            use_cache_layers: false,
+                            mask_layer: None,
+                            mask_sources: &FxHashSet::default(),
+                        });
                     }
+                }
+            }
+            mask => mask.render(self.canvas, area),
+        }
 
-                    hotpath::measure_block!("Element Clipping", {
-                        for clip_node_id in effect_state.clips.iter() {
-                            let clip_element = self.tree.elements.get(clip_node_id).unwrap();
-                            let clip_layout_node = self.tree.layout.get(clip_node_id).unwrap();
-                            let clip_effect = self.tree.effect_state.get(clip_node_id).unwrap();
-
-                            let mut transformed_clip_area = clip_layout_node.visible_area();
-
-                            // For every clip area that his element gets we also need to apply the effects to each one so that
-                            // we can properly assume whether this element is actually visible or not
-                            for id in clip_effect.scales.iter() {
-                                let scale_layout_node = self.tree.layout.get(id).unwrap();
-                                let scale_effect = self.tree.effect_state.get(id).unwrap();
-                                let area = scale_layout_node.visible_area();
-                                let center = area.center();
-                                let scale = scale_effect.scale.unwrap();
-
-                                transformed_clip_area =
-                                    transformed_clip_area.translate(-center.to_vector());
-                                transformed_clip_area =
-                                    transformed_clip_area.scale(scale.x, scale.y);
-                                transformed_clip_area =
-                                    transformed_clip_area.translate(center.to_vector());
-                            }
-
-                            // No need to render this element as it is completely clipped
-                            if !visible_area.intersects(&transformed_clip_area) {
-                                self.canvas.restore_to_count(layer);
-                                continue 'rendering;
-                            }
-
-                            let clip_context = ClipContext {
-                                canvas: self.canvas,
-                                visible_area: &transformed_clip_area,
-                                scale_factor: self.scale_factor,
-                            };
-
-                            clip_element.clip(clip_context);
-                        }
-                    });
-
-                    // Pass rotate effect to children
-                    for id in effect_state.rotations.iter() {
-                        let layout_node = self.tree.layout.get(id).unwrap();
-                        let effect = self.tree.effect_state.get(id).unwrap();
-                        let area = layout_node.visible_area();
-                        let mut matrix = SkMatrix::new_identity();
-                        matrix.set_rotate(
-                            effect.rotation.unwrap(),
-                            Some(SkPoint {
-                                x: area.min_x() + area.width() / 2.0,
-                                y: area.min_y() + area.height() / 2.0,
-                            }),
-                        );
-                        self.canvas.concat(&matrix);
+        self.canvas.restore_to_count(layer);
+        needs_next_frame
+    }
+
+    /// Outermost element with a cache layer that contains the given element.
+    fn cache_layer(&self, node_id: &NodeId) -> Option<NodeId> {
+        self.tree
+            .effect_state
+            .get(node_id)
+            .and_then(|effect_state| effect_state.cache_layer)
+    }
+
+    /// Paint a run of elements of a cache layer, recording them first if they are missing or outdated.
+    fn render_cache_layer(
+        &mut self,
+        node_id: NodeId,
+        nodes: &[NodeId],
+        run: Range<usize>,
+        pass: RenderPass,
+    ) -> bool {
+        let Some(layout_node) = self.tree.layout.get(&node_id) else {
+            return false;
+        };
+        let area = layout_node.visible_area();
+        let run_nodes = &nodes[run.clone()];
+
+        if let Some(picture) =
+            self.tree
+                .cache_layers
+                .get(&node_id, run_nodes, &area, self.scale_factor)
+        {
+            self.canvas.draw_picture(&picture, None, None);
+            return false;
+        }
+
+        // Elements of the subtree that are painted before or after other elements
+        let other_runs = nodes[..run.start]
+            .iter()
+            .chain(&nodes[run.end..])
+            .filter(|id| self.cache_layer(id) == Some(node_id))
+            .copied()
+            .collect::<FxHashSet<_>>();
+        let subtree = self.subtree(node_id);
+        let mut recorder = PictureRecorder::new();
+        let bounds = self.canvas.local_clip_bounds().unwrap_or_default();
+        let needs_next_frame = RenderPipeline {
+            font_collection: self.font_collection,
+            font_manager: self.font_manager,
+            canvas: recorder.begin_recording(bounds, false),
+            tree: self.tree,
+            scale_factor: self.scale_factor,
+            background: self.background,
+        }
+        .render_nodes(RenderPass {
+            filter: &|node_id| subtree.contains(node_id) && !other_runs.contains(node_id),
+            use_cache_layers: false,
+            mask_layer: None,
+            mask_sources: pass.mask_sources,
+        });
+
+        let Some(picture) = recorder.finish_recording_as_picture(None) else {
+            return needs_next_frame;
+        };
+        self.canvas.draw_picture(&picture, None, None);
+
+        // Elements that paint something new every frame would invalidate the recording anyway
+        if !needs_next_frame {
+            self.tree.cache_layers.insert(
+                node_id,
+                CachedLayer {
+                    picture,
+                    nodes: run_nodes.to_vec(),
+                    area,
+                    scale_factor: self.scale_factor,
+                },
+            );
+        }
+
+        needs_next_frame
+    }
+
+    fn render_nodes(&mut self, pass: RenderPass) -> bool {
+        let mut needs_next_frame = false;
+        let tree = self.tree;
+
+        let nodes = tree
+            .layers
+            .paint_order()
+            .copied()
+            .filter(|node_id| {
+                if !(pass.filter)(node_id) || pass.mask_sources.contains(node_id) {
+                    return false;
+                }
+                // Descendants of a mask are painted in its layer
+                match tree
+                    .effect_state
+                    .get(node_id)
+                    .and_then(|effect_state| effect_state.masked_by(*node_id))
+                {
+                    Some(masked_by) if pass.mask_layer != Some(*node_id) => {
+                        pass.mask_layer == Some(masked_by) || !(pass.filter)(&masked_by)
                     }
+                    _ => true,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // TODO: Use incremental rendering
+        let mut index = 0;
+        'rendering: while let Some(node_id) = nodes.get(index) {
+            index += 1;
+
+            if pass.mask_layer != Some(*node_id)
+                && let Some(effect_state) = self.tree.effect_state.get(node_id)
+            {
+                if pass.use_cache_layers
+                    && let Some(cache_layer) = effect_state.cache_layer
+                {
+                    // Descendants painted right after each other are recorded together,
+                    // the rest keep their place in the paint order
+                    let start = index - 1;
+                    index = start
+                        + nodes[start..]
+                            .iter()
+                            .take_while(|id| self.cache_layer(id) == Some(cache_layer))
+                            .count();
+                    needs_next_frame |=
+                        self.render_cache_layer(cache_layer, &nodes, start..index, pass);
+                    continue;
+                }
+
+                if effect_state.mask.is_some() {
+                    needs_next_frame |= self.render_mask_layer(*node_id, pass);
+                    continue;
+                }
+            }
+
+            let layer = self.canvas.save();
+
+            let element = self.tree.elements.get(node_id).unwrap();
+            let text_style_state = self.tree.text_style_state.get(node_id).unwrap();
+            let layout_node = self.tree.layout.get(node_id).unwrap();
+            let effect_state = self.tree.effect_state.get(node_id);
 
-                    let render_rect = element.render_rect(&visible_area, self.scale_factor as f32);
+            if let Some(effect_state) = effect_state {
+                let mut visible_area = layout_node.visible_area();
 
-                    // Apply inherited opacity effects with bounds expanded
-                    // to accommodate outset shadows
-                    let mut layer_bounds = *render_rect.rect();
-                    let scale_factor = self.scale_factor as f32;
+                // Transform the element area given the scale effects
+                for id in effect_state.scales.iter() {
+                    let layout_node = self.tree.layout.get(id).unwrap();
+                    let effect = self.tree.effect_state.get(id).unwrap();
+                    let area = layout_node.visible_area();
+                    let center = area.center();
+                    let scale = effect.scale.unwrap();
 
-                    for shadow in element.style().shadows.iter() {
-                        if shadow.position == ShadowPosition::Normal {
-                            let outset_x = shadow.x.abs() + shadow.spread + shadow.blur;
-                            let outset_y = shadow.y.abs() + shadow.spread + shadow.blur;
-                            layer_bounds = layer_bounds
-                                .with_outset((outset_x * scale_factor, outset_y * scale_factor));
+                    visible_area = visible_area.translate(-center.to_vector());
+                    visible_area = visible_area.scale(scale.x, scale.y);
+                    visible_area = visible_area.translate(center.to_vector());
+                }
+
+                hotpath::measure_block!("Element Clipping", {
+                    for clip_node_id in effect_state.clips.iter() {
+                        let clip_element = self.tree.elements.get(clip_node_id).unwrap();
+                        let clip_layout_node = self.tree.layout.get(clip_node_id).unwrap();
+                        let clip_effect = self.tree.effect_state.get(clip_node_id).unwrap();
+
+                        let mut transformed_clip_area = clip_layout_node.visible_area();
+
+                        // For every clip area that his element gets we also need to apply the effects to each one so that
+                        // we can properly assume whether this element is actually visible or not
+                        for id in clip_effect.scales.iter() {
+                            let scale_layout_node = self.tree.layout.get(id).unwrap();
+                            let scale_effect = self.tree.effect_state.get(id).unwrap();
+                            let area = scale_layout_node.visible_area();
+                            let center = area.center();
+                            let scale = scale_effect.scale.unwrap();
+
+                            transformed_clip_area =
+                                transformed_clip_area.translate(-center.to_vector());
+                            transformed_clip_area = transformed_clip_area.scale(scale.x, scale.y);
+                            transformed_clip_area =
+                                transformed_clip_area.translate(center.to_vector());
+                        }
+
+                        // No need to render this element as it is completely clipped
+                        if !visible_area.intersects(&transformed_clip_area) {
+                            self.canvas.restore_to_count(layer);
+                            continue 'rendering;
                         }
+
+                        let clip_context = ClipContext {
+                            canvas: self.canvas,
+                            visible_area: &transformed_clip_area,
+                            scale_factor: self.scale_factor,
+                        };
+
+                        clip_element.clip(clip_context);
                     }
 
-                    for opacity in effect_state.opacities.iter() {
-                        self.canvas.save_layer_alpha_f(layer_bounds, *opacity);
+                    for clip_node_id in effect_state.clip_paths.iter() {
+                        let clip_layout_node = self.tree.layout.get(clip_node_id).unwrap();
+                        let clip_effect = self.tree.effect_state.get(clip_node_id).unwrap();
+                        let path = clip_effect.clip_path.as_ref().and_then(|clip_path| {
+                            clip_path
+                                .to_path(&clip_layout_node.visible_area(), self.scale_factor as f32)
+                        });
+                        if let Some(path) = path {
+                            self.canvas.clip_path(&path, ClipOp::Intersect, true);
+                        }
                     }
+                });
 
-                    // Transform the canvas area given the scale effects
-                    for id in effect_state.scales.iter() {
-                        let layout_node = self.tree.layout.get(id).unwrap();
-                        let effect = self.tree.effect_state.get(id).unwrap();
-                        let area = layout_node.visible_area();
-                        let center = area.center();
-                        let scale = effect.scale.unwrap();
-
-                        self.canvas.translate((center.x, center.y));
-                        self.canvas.scale((scale.x, scale.y));
-                        self.canvas.translate((-center.x, -center.y));
+                // Pass the rotation and transform effects to children
+                if !effect_state.transforms.is_empty() {
+                    self.canvas.concat(&effect_state.transform_matrix(
+                        &self.tree.layout,
+                        &self.tree.effect_state,
+                        self.scale_factor as f32,
+                    ));
+                }
+
+                let render_rect = element.render_rect(&visible_area, self.scale_factor as f32);
+
+                // Apply inherited opacity effects with bounds expanded
+                // to accommodate outset shadows
+                let mut layer_bounds = *render_rect.rect();
+                let scale_factor = self.scale_factor as f32;
+
+                for shadow in element.style().shadows.iter() {
+                    if shadow.position == ShadowPosition::Normal {
+                        let outset_x = shadow.x.abs() + shadow.spread + shadow.blur;
+                        let outset_y = shadow.y.abs() + shadow.spread + shadow.blur;
+                        layer_bounds = layer_bounds
+                            .with_outset((outset_x * scale_factor, outset_y * scale_factor));
                     }
                 }
 
-                let render_context = RenderContext {
-                    font_collection: self.font_collection,
-                    canvas: self.canvas,
-                    layout_node,
-                    tree: self.tree,
-                    text_style_state,
-                    scale_factor: self.scale_factor,
-                };
+                for opacity in effect_state.opacities.iter() {
+                    self.canvas.save_layer_alpha_f(layer_bounds, *opacity);
+                }
 
-                hotpath::measure_block!("Element Render", {
-                    element.render(render_context);
-                });
+                // Apply inherited color filters
+                for filter in effect_state.filters.iter() {
+                    let mut paint = Paint::default();
+                    paint.set_color_filter(filter.color_filter());
+                    self.canvas
+                        .save_layer(&SaveLayerRec::default().bounds(&layer_bounds).paint(&paint));
+                }
+
+                // Transform the canvas area given the scale effects
+                for id in effect_state.scales.iter() {
+                    let layout_node = self.tree.layout.get(id).unwrap();
+                    let effect = self.tree.effect_state.get(id).unwrap();
+                    let area = layout_node.visible_area();
+                    let center = area.center();
+                    let scale = effect.scale.unwrap();
+
+                    self.canvas.translate((center.x, center.y));
+                    self.canvas.scale((scale.x, scale.y));
+                    self.canvas.translate((-center.x, -center.y));
+                }
 
-                if let Some(effect_state) = effect_state {
-                    let visible_area = layout_node.visible_area();
-                    let render_rect = element.render_rect(&visible_area, self.scale_factor as f32);
-                    // Apply blur effect
-                    if let Some(blur_radius) = effect_state.blur {
-                        let style = element.style();
-
-                        let image_filter = blur(
-                            (
-                                blur_radius * self.scale_factor as f32,
-                                blur_radius * self.scale_factor as f32,
-                            ),
-                            None,
-                            None,
-                            render_rect.rect(),
+                // Blur what was already painted behind this element
+                if let Some(backdrop_blur) = effect_state.backdrop_blur {
+                    let backdrop_rect =
+                        element.render_rect(&layout_node.visible_area(), scale_factor);
+                    let sigma = backdrop_blur * scale_factor;
+                    if let Some(image_filter) =
+                        blur((sigma, sigma), None, None, backdrop_rect.rect())
+                    {
+                        let backdrop_layer = self.canvas.save();
+                        self.canvas
+                            .clip_rrect(backdrop_rect, ClipOp::Intersect, true);
+                        self.canvas.save_layer(
+                            &SaveLayerRec::default()
+                                .bounds(backdrop_rect.rect())
+                                .backdrop(&image_filter),
                         );
-                        if let Some(image_filter) = image_filter {
-                            let rec = SaveLayerRec::default()
-                                .bounds(render_rect.rect())
-                                .backdrop(&image_filter);
-                            if style.corner_radius.is_round() {
-                                self.canvas.clip_rrect(render_rect, ClipOp::Intersect, true);
-                                self.canvas.save_layer(&rec);
-                            } else {
-                                self.canvas.save_layer(&rec);
-                            }
-                        }
+                        self.canvas.restore_to_count(backdrop_layer);
                     }
                 }
 
-                self.canvas.restore_to_count(layer);
+                // Composite what this element paints with the given blend mode
+                if let Some(blend_mode) = effect_state.blend_mode {
+                    let mut paint = Paint::default();
+                    paint.set_blend_mode(blend_mode.into());
+                    self.canvas
+                        .save_layer(&SaveLayerRec::default().bounds(&layer_bounds).paint(&paint));
+                }
+
+                // Apply the shader effect to what this element paints
+                if let Some(image_filter) = effect_state
+                    .shader
+                    .as_ref()
+                    .and_then(|shader| shader.image_filter(&layout_node.visible_area()))
+                {
+                    let mut paint = Paint::default();
+                    paint.set_image_filter(image_filter);
+                    self.canvas
+                        .save_layer(&SaveLayerRec::default().bounds(&layer_bounds).paint(&paint));
+                }
+            }
+
+            let render_context = RenderContext {
+                node_id: *node_id,
+                font_collection: self.font_collection,
+                canvas: self.canvas,
+                layout_node,
+                tree: self.tree,
+                text_style_state,
+                scale_factor: self.scale_factor,
+            };
+
+            hotpath::measure_block!("Element Render", {
+                element.render(render_context);
+            });
+            needs_next_frame |= element.needs_next_frame();
+
+            if let Some(effect_state) = effect_state {
+                let visible_area = layout_node.visible_area();
+                let render_rect = element.render_rect(&visible_area, self.scale_factor as f32);
+                // Apply blur effect
+                if let Some(blur_radius) = effect_state.blur {
+                    let style = element.style();
+
+                    let image_filter = blur(
+                        (
+                            blur_radius * self.scale_factor as f32,
+                            blur_radius * self.scale_factor as f32,
+                        ),
+                        None,
+                        None,
+                        render_rect.rect(),
+                    );
+                    if let Some(image_filter) = image_filter {
+                        let rec = SaveLayerRec::default()
+                            .bounds(render_rect.rect())
+                            .backdrop(&image_filter);
+                        if style.corner_radius.is_round() {
+                            self.canvas.clip_rrect(render_rect, ClipOp::Intersect, true);
+                            self.canvas.save_layer(&rec);
+                        } else {
+                            self.canvas.save_layer(&rec);
+                        }
+                    }
+                }
             }
+
+            self.canvas.restore_to_count(layer);
         }
+
+        needs_next_frame
     }
 }
@@ -0,0 +1,113 @@
+use freya_engine::prelude::{
+    Canvas,
+    Paint,
+    PaintStyle,
+    SkRect,
+};
+use rustc_hash::FxHashSet;
+use torin::prelude::Area;
+
+use crate::{
+    node_id::NodeId,
+    prelude::Color,
+    tree::Tree,
+};
+
+/// Colors cycled every frame so consecutive repaints of the same element flicker.
+const REPAINT_COLORS: [Color; 3] = [
+    Color::from_af32rgb(0.3, 255, 80, 80),
+    Color::from_af32rgb(0.3, 80, 200, 255),
+    Color::from_af32rgb(0.3, 255, 200, 40),
+];
+
+/// Keeps track of the elements that changed since the last frame, to visualize what triggers
+/// repaints. Only tracks elements while enabled.
+#[derive(Default)]
+pub struct RepaintTracker {
+    enabled: bool,
+    frame: usize,
+    nodes: FxHashSet<NodeId>,
+}
+
+impl RepaintTracker {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        self.nodes.clear();
+    }
+
+    /// Mark an element as repainted in the next frame.
+    pub fn track(&mut self, node_id: NodeId) {
+        if self.enabled {
+            self.nodes.insert(node_id);
+        }
+    }
+
+    /// Elements that changed since the last frame.
+    pub fn nodes(&self) -> &FxHashSet<NodeId> {
+        &self.nodes
+    }
+
+    /// Forget the tracked elements once the frame is presented.
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.frame = self.frame.wrapping_add(1);
+    }
+
+    /// Area containing all the changed elements.
+    pub fn dirty_area(&self, tree: &Tree) -> Option<Area> {
+        self.nodes
+            .iter()
+            .filter_map(|node_id| tree.layout.get(node_id))
+            .map(|layout_node| layout_node.visible_area())
+            .reduce(|a, b| a.union(&b))
+    }
+
+    /// Tint the changed elements and outline the dirty area. Frames rendered without any
+    /// changed element, like animations or continuous rendering, outline the whole canvas.
+    pub fn render(&self, canvas: &Canvas, tree: &Tree) {
+        if !self.enabled {
+            return;
+        }
+
+        let color = REPAINT_COLORS[self.frame % REPAINT_COLORS.len()];
+
+        let mut fill = Paint::default();
+        fill.set_anti_alias(true);
+        fill.set_style(PaintStyle::Fill);
+        fill.set_color(color);
+
+        let mut stroke = Paint::default();
+        stroke.set_anti_alias(true);
+        stroke.set_style(PaintStyle::Stroke);
+        stroke.set_stroke_width(2.);
+        stroke.set_color(color.with_a(255));
+
+        for layout_node in self
+            .nodes
+            .iter()
+            .filter_map(|node_id| tree.layout.get(node_id))
+        {
+            let area = layout_node.visible_area();
+            canvas.draw_rect(
+                SkRect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y()),
+                &fill,
+            );
+        }
+
+        let dirty_area = self.dirty_area(tree).or_else(|| {
+            tree.layout
+                .get(&NodeId::ROOT)
+                .map(|layout_node| layout_node.area)
+        });
+        if let Some(area) = dirty_area {
+            canvas.draw_rect(
+                SkRect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y()),
+                &stroke,
+            );
+        }
+    }
+}
@@ -18,6 +18,7 @@ use futures_lite::{
 };
 use itertools::Itertools;
 use pathgraph::PathGraph;
+use ragnarok::PointerCapture;
 use rustc_hash::{
     FxHashMap,
     FxHashSet,
@@ -34,6 +35,7 @@ use crate::{
     events::{
         data::{
             Event,
+            EventPointerCapture,
             EventType,
         },
         name::EventName,
@@ -167,6 +169,8 @@ pub struct Runner {
 
     pub(crate) sender: futures_channel::mpsc::UnboundedSender<Message>,
     pub(crate) receiver: futures_channel::mpsc::UnboundedReceiver<Message>,
+
+    pub(crate) pointer_capture: Option<PointerCapture<NodeId>>,
 }
 
 impl Debug for Runner {
@@ -267,6 +271,7 @@ impl Runner {
 
             sender,
             receiver,
+            pointer_capture: None,
         }
     }
 
@@ -329,6 +334,10 @@ impl Runner {
         tracing::info!("Handling event {event_name:?} for {node_id:?}");
         let propagate = Rc::new(RefCell::new(bubbles));
         let default = Rc::new(RefCell::new(true));
+        let pointer_capture = EventPointerCapture {
+            node_id,
+            request: Rc::default(),
+        };
 
         let Some(scope_id) = self.node_to_scope.get(&node_id) else {
             return false;
@@ -378,6 +387,7 @@ impl Runner {
                                                         data: data.clone(),
                                                         propagate: propagate.clone(),
                                                         default: default.clone(),
+                                                        pointer_capture: pointer_capture.clone(),
                                                     });
                                                 }
                                                 Some(_) => unreachable!(),
@@ -394,6 +404,7 @@ impl Runner {
                                                         data: data.clone(),
                                                         propagate: propagate.clone(),
                                                         default: default.clone(),
+                                                        pointer_capture: pointer_capture.clone(),
                                                     });
                                                 }
                                                 Some(_) => unreachable!(),
@@ -410,6 +421,7 @@ impl Runner {
                                                         data: data.clone(),
                                                         propagate: propagate.clone(),
                                                         default: default.clone(),
+                                                        pointer_capture: pointer_capture.clone(),
                                                     });
                                                 }
                                                 Some(_) => unreachable!(),
@@ -426,6 +438,7 @@ impl Runner {
                                                         data: data.clone(),
                                                         propagate: propagate.clone(),
                                                         default: default.clone(),
+                                                        pointer_capture: pointer_capture.clone(),
                                                     });
                                                 }
                                                 Some(_) => unreachable!(),
@@ -442,6 +455,7 @@ impl Runner {
                                                         data: data.clone(),
                                                         propagate: propagate.clone(),
                                                         default: default.clone(),
+                                                        pointer_capture: pointer_capture.clone(),
                                                     });
                                                 }
                                                 Some(_) => unreachable!(),
@@ -458,6 +472,7 @@ impl Runner {
                                                         data: data.clone(),
                                                         propagate: propagate.clone(),
                                                         default: default.clone(),
+                                                        pointer_capture: pointer_capture.clone(),
                                                     });
                                                 }
                                                 Some(_) => unreachable!(),
@@ -474,6 +489,7 @@ impl Runner {
                                                         data: data.clone(),
                                                         propagate: propagate.clone(),
                                                         default: default.clone(),
+                                                        pointer_capture: pointer_capture.clone(),
                                                     });
                                                 }
                                                 Some(_) => unreachable!(),
@@ -490,6 +506,77 @@ impl Runner {
                                                         data: data.clone(),
                                                         propagate: propagate.clone(),
                                                         default: default.clone(),
+                                                        pointer_capture: pointer_capture.clone(),
+                                                    });
+                                                }
+                                                Some(_) => unreachable!(),
+                                                _ => {}
+                                            }
+                                        }
+                                    }
+                                    EventType::Gesture(data) => {
+                                        let event_handlers = element.events_handlers();
+                                        if let Some(event_handlers) = event_handlers {
+                                            match event_handlers.get(&event_name) {
+                                                Some(EventHandlerType::Gesture(handler)) => {
+                                                    handler.call(Event {
+                                                        data: data.clone(),
+                                                        propagate: propagate.clone(),
+                                                        default: default.clone(),
+                                                        pointer_capture: pointer_capture.clone(),
+                                                    });
+                                                }
+                                                Some(_) => unreachable!(),
+                                                _ => {}
+                                            }
+                                        }
+                                    }
+                                    EventType::Pen(data) => {
+                                        let event_handlers = element.events_handlers();
+                                        if let Some(event_handlers) = event_handlers {
+                                            match event_handlers.get(&event_name) {
+                                                Some(EventHandlerType::Pen(handler)) => {
+                                                    handler.call(Event {
+                                                        data: data.clone(),
+                                                        propagate: propagate.clone(),
+                                                        default: default.clone(),
+                                                        pointer_capture: pointer_capture.clone(),
+                                                    });
+                                                }
+                                                Some(_) => unreachable!(),
+                                                _ => {}
+                                            }
+                                        }
+                                    }
+                                    EventType::Drag(data) => {
+                                        let event_handlers = element.events_handlers();
+                                        if let Some(event_handlers) = event_handlers {
+                                            match event_handlers.get(&event_name) {
+                                                Some(EventHandlerType::Drag(handler)) => {
+                                                    handler.call(Event {
+                                                        data: data.clone(),
+                                                        propagate: propagate.clone(),
+                                                        default: default.clone(),
+                                                        pointer_capture: pointer_capture.clone(),
+                                                    });
+                                                }
+                                                Some(_) => unreachable!(),
+                                                _ => {}
+                                            }
+                                        }
+                                    }
+                                    EventType::AccessibilityAction(data) => {
+                                        let event_handlers = element.events_handlers();
+                                        if let Some(event_handlers) = event_handlers {
+                                            match event_handlers.get(&event_name) {
+                                                Some(EventHandlerType::AccessibilityAction(
+                                                    handler,
+                                                )) => {
+                                                    handler.call(Event {
+                                                        data: data.clone(),
+                                                        propagate: propagate.clone(),
+                                                        default: default.clone(),
+                                                        pointer_capture: pointer_capture.clone(),
                                                     });
                                                 }
                                                 Some(_) => unreachable!(),
@@ -542,9 +629,17 @@ impl Runner {
                 }
             });
         }
+        if let Some(request) = pointer_capture.request.take() {
+            self.pointer_capture = Some(request);
+        }
         *default.borrow()
     }
 
+    /// Take the pointer capture change requested by the last handled events, if any.
+    pub fn take_pointer_capture(&mut self) -> Option<PointerCapture<NodeId>> {
+        self.pointer_capture.take()
+    }
+
     #[cfg_attr(feature = "hotpath", hotpath::measure)]
     pub async fn handle_events(&mut self) {
         loop {
@@ -611,6 +706,23 @@ impl Runner {
         }
     }
 
+    /// Whether there are scopes to run again or tasks that were woken up,
+    /// useful for renderers that poll for work instead of being woken up.
+    pub fn has_pending_work(&mut self) -> bool {
+        while let Ok(msg) = self.receiver.try_recv() {
+            match msg {
+                Message::MarkScopeAsDirty(scope_id) => {
+                    self.dirty_scopes.insert(scope_id);
+                }
+                Message::PollTask(task_id) => {
+                    self.dirty_tasks.push_back(task_id);
+                }
+            }
+        }
+
+        !self.dirty_scopes.is_empty() || !self.dirty_tasks.is_empty()
+    }
+
     /// Useful for freya-testing
     #[cfg_attr(feature = "hotpath", hotpath::measure)]
     pub fn handle_events_immediately(&mut self) {
@@ -0,0 +1,389 @@
+use std::{
+    cell::RefCell,
+    fmt,
+    rc::{
+        Rc,
+        Weak,
+    },
+    str::FromStr,
+};
+
+use keyboard_types::{
+    Key,
+    Modifiers,
+    NamedKey,
+};
+
+use crate::{
+    accessibility::{
+        id::AccessibilityId,
+        tree::is_text_input,
+    },
+    integration::ScopeId,
+    prelude::{
+        KeyboardEventData,
+        NoArgCallback,
+        Platform,
+        provide_context_for_scope_id,
+        try_consume_root_context,
+        use_hook,
+    },
+};
+
+/// Modifiers taken into account when matching a [Shortcut].
+const SHORTCUT_MODIFIERS: Modifiers = Modifiers::CONTROL
+    .union(Modifiers::SHIFT)
+    .union(Modifiers::ALT)
+    .union(Modifiers::META);
+
+/// Key combination such as `ctrl+shift+p`, see [use_shortcut].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Shortcut {
+    modifiers: Modifiers,
+    key: Key,
+}
+
+/// Error returned when parsing an invalid [Shortcut].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ShortcutParseError {
+    MissingKey,
+    UnknownModifier(String),
+    UnknownKey(String),
+}
+
+impl fmt::Display for ShortcutParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingKey => f.write_str("missing key"),
+            Self::UnknownModifier(modifier) => write!(f, "unknown modifier `{modifier}`"),
+            Self::UnknownKey(key) => write!(f, "unknown key `{key}`"),
+        }
+    }
+}
+
+impl std::error::Error for ShortcutParseError {}
+
+impl Shortcut {
+    pub fn new(modifiers: Modifiers, key: Key) -> Self {
+        Self {
+            modifiers: modifiers & SHORTCUT_MODIFIERS,
+            key: normalize_key(key),
+        }
+    }
+
+    /// Modifier used for application shortcuts, `Cmd` on macOS and `Ctrl` elsewhere.
+    pub fn primary_modifier() -> Modifiers {
+        if cfg!(target_os = "macos") {
+            Modifiers::META
+        } else {
+            Modifiers::CONTROL
+        }
+    }
+
+    /// Parse `+` separated modifiers followed by a key, case insensitive.
+    ///
+    /// Modifiers are `ctrl`, `shift`, `alt`, `meta` (or `cmd`, `super`) and `mod`,
+    /// which is the [Shortcut::primary_modifier] of the platform.
+    pub fn parse(shortcut: &str) -> Result<Self, ShortcutParseError> {
+        let mut parts = shortcut.split('+').map(str::trim).collect::<Vec<_>>();
+        // `ctrl++` binds the plus key
+        if shortcut.ends_with("++") {
+            parts.truncate(parts.len().saturating_sub(2));
+            parts.push("+");
+        }
+        let Some((key, modifiers)) = parts.split_last() else {
+            return Err(ShortcutParseError::MissingKey);
+        };
+
+        let mut shortcut_modifiers = Modifiers::empty();
+        for modifier in modifiers {
+            shortcut_modifiers |= match modifier.to_lowercase().as_str() {
+                "ctrl" | "control" => Modifiers::CONTROL,
+                "shift" => Modifiers::SHIFT,
+                "alt" | "option" => Modifiers::ALT,
+                "meta" | "cmd" | "command" | "super" => Modifiers::META,
+                "mod" | "cmdorctrl" => Self::primary_modifier(),
+                _ => return Err(ShortcutParseError::UnknownModifier(modifier.to_string())),
+            };
+        }
+
+        Ok(Self::new(shortcut_modifiers, parse_key(key)?))
+    }
+
+    pub fn modifiers(&self) -> Modifiers {
+        self.modifiers
+    }
+
+    pub fn key(&self) -> &Key {
+        &self.key
+    }
+
+    /// Whether this shortcut is a key that writes text when pressed in a text input.
+    fn types_text(&self) -> bool {
+        matches!(self.key, Key::Character(_)) && (self.modifiers - Modifiers::SHIFT).is_empty()
+    }
+
+    /// Check if a keyboard event triggers this shortcut.
+    pub fn matches(&self, data: &KeyboardEventData) -> bool {
+        data.modifiers & SHORTCUT_MODIFIERS == self.modifiers
+            && normalize_key(data.key.clone()) == self.key
+    }
+}
+
+fn normalize_key(key: Key) -> Key {
+    match key {
+        Key::Character(character) => Key::Character(character.to_lowercase()),
+        key => key,
+    }
+}
+
+const FUNCTION_KEYS: [NamedKey; 12] = [
+    NamedKey::F1,
+    NamedKey::F2,
+    NamedKey::F3,
+    NamedKey::F4,
+    NamedKey::F5,
+    NamedKey::F6,
+    NamedKey::F7,
+    NamedKey::F8,
+    NamedKey::F9,
+    NamedKey::F10,
+    NamedKey::F11,
+    NamedKey::F12,
+];
+
+fn parse_key(key: &str) -> Result<Key, ShortcutParseError> {
+    let named = match key.to_lowercase().as_str() {
+        "" => return Err(ShortcutParseError::MissingKey),
+        "enter" | "return" => NamedKey::Enter,
+        "esc" | "escape" => NamedKey::Escape,
+        "tab" => NamedKey::Tab,
+        "backspace" => NamedKey::Backspace,
+        "delete" | "del" => NamedKey::Delete,
+        "insert" => NamedKey::Insert,
+        "home" => NamedKey::Home,
+        "end" => NamedKey::End,
+        "pageup" => NamedKey::PageUp,
+        "pagedown" => NamedKey::PageDown,
+        "up" | "arrowup" => NamedKey::ArrowUp,
+        "down" | "arrowdown" => NamedKey::ArrowDown,
+        "left" | "arrowleft" => NamedKey::ArrowLeft,
+        "right" | "arrowright" => NamedKey::ArrowRight,
+        "space" => return Ok(Key::Character(" ".to_string())),
+        "plus" => return Ok(Key::Character("+".to_string())),
+        lowercase => {
+            if let Some(number) = lowercase.strip_prefix('f')
+                && let Ok(number) = number.parse::<usize>()
+                && let Some(named) = FUNCTION_KEYS.get(number.wrapping_sub(1))
+            {
+                return Ok(Key::Named(*named));
+            }
+            if key.chars().count() == 1 {
+                return Ok(Key::Character(lowercase.to_string()));
+            }
+            return Err(ShortcutParseError::UnknownKey(key.to_string()));
+        }
+    };
+    Ok(Key::Named(named))
+}
+
+impl FromStr for Shortcut {
+    type Err = ShortcutParseError;
+
+    fn from_str(shortcut: &str) -> Result<Self, Self::Err> {
+        Self::parse(shortcut)
+    }
+}
+
+/// # Panics
+///
+/// If the shortcut is not valid, use [Shortcut::parse] for shortcuts not known at compile time.
+impl From<&'static str> for Shortcut {
+    fn from(shortcut: &'static str) -> Self {
+        Self::parse(shortcut).unwrap_or_else(|err| panic!("Invalid shortcut `{shortcut}`: {err}"))
+    }
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (modifier, name) in [
+            (Modifiers::CONTROL, "Ctrl"),
+            (Modifiers::ALT, "Alt"),
+            (Modifiers::SHIFT, "Shift"),
+            (
+                Modifiers::META,
+                if cfg!(target_os = "macos") {
+                    "Cmd"
+                } else {
+                    "Meta"
+                },
+            ),
+        ] {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{name}+")?;
+            }
+        }
+        match &self.key {
+            Key::Character(character) if character == " " => f.write_str("Space"),
+            Key::Character(character) => f.write_str(&character.to_uppercase()),
+            key => write!(f, "{key}"),
+        }
+    }
+}
+
+/// Where a [Shortcut] is active.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ShortcutScope {
+    /// Active wherever the focus is.
+    #[default]
+    Global,
+    /// Active while the focus is on the given node or inside of it.
+    Focused(AccessibilityId),
+}
+
+struct ShortcutEntry {
+    shortcut: Shortcut,
+    scope: ShortcutScope,
+    handler: Rc<RefCell<NoArgCallback<()>>>,
+    alive: Weak<()>,
+}
+
+/// Shortcuts registered with [use_shortcut] in the app.
+///
+/// When several shortcuts match a key press only one runs: the one scoped to the closest
+/// ancestor of the focused node, or else the last registered global one.
+#[derive(Clone, Default)]
+pub struct ShortcutRegistry {
+    entries: Rc<RefCell<Vec<ShortcutEntry>>>,
+}
+
+impl ShortcutRegistry {
+    pub fn get() -> Self {
+        match try_consume_root_context() {
+            Some(rt) => rt,
+            None => {
+                let registry = ShortcutRegistry::default();
+                provide_context_for_scope_id(registry.clone(), ScopeId::ROOT);
+                registry
+            }
+        }
+    }
+
+    fn register(
+        &self,
+        shortcut: Shortcut,
+        scope: ShortcutScope,
+        handler: Rc<RefCell<NoArgCallback<()>>>,
+        alive: Weak<()>,
+    ) {
+        let mut entries = self.entries.borrow_mut();
+        entries.retain(|entry| entry.alive.strong_count() > 0);
+
+        #[cfg(debug_assertions)]
+        if entries
+            .iter()
+            .any(|entry| entry.shortcut == shortcut && entry.scope == scope)
+        {
+            tracing::warn!("Shortcut {shortcut} registered more than once, the last one wins.");
+        }
+
+        entries.push(ShortcutEntry {
+            shortcut,
+            scope,
+            handler,
+            alive,
+        });
+    }
+
+    /// Run the shortcut triggered by a keyboard event, if any. Returns whether one ran.
+    ///
+    /// Shortcuts that type text, like `shift+a`, are left to the text inputs while they are focused.
+    pub fn handle(&self, data: &KeyboardEventData) -> bool {
+        let platform = Platform::get();
+        let focused_id = *platform.focused_accessibility_id.peek();
+        let ancestors = platform.focused_accessibility_ancestors.peek().clone();
+        let typing = is_text_input(platform.focused_accessibility_node.peek().role());
+
+        // Deeper scopes win over shallower ones, and those over the global ones
+        let depth = |scope: &ShortcutScope| match scope {
+            ShortcutScope::Global => Some(0),
+            ShortcutScope::Focused(id) if *id == focused_id => Some(ancestors.len() + 1),
+            ShortcutScope::Focused(id) => ancestors
+                .iter()
+                .position(|ancestor| ancestor == id)
+                .map(|position| position + 1),
+        };
+
+        let handler = {
+            let entries = self.entries.borrow();
+            entries
+                .iter()
+                .filter(|entry| entry.alive.strong_count() > 0 && entry.shortcut.matches(data))
+                .filter(|entry| !typing || !entry.shortcut.types_text())
+                .filter_map(|entry| Some((depth(&entry.scope)?, entry)))
+                .max_by_key(|(depth, _)| *depth)
+                .map(|(_, entry)| entry.handler.borrow().clone())
+        };
+
+        match handler {
+            Some(handler) => {
+                handler.call();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Run `handler` when the given [Shortcut] is pressed, wherever the focus is.
+///
+/// # Example
+///
+/// ```rust, no_run
+/// # use freya::prelude::*;
+/// fn app() -> impl IntoElement {
+///     let mut palette = use_state(|| false);
+///
+///     use_shortcut("mod+shift+p", move || palette.toggle());
+///
+///     rect().maybe_child(palette().then(|| "Command palette"))
+/// }
+/// ```
+pub fn use_shortcut(shortcut: impl Into<Shortcut>, handler: impl FnMut() + 'static) {
+    use_shortcut_with_scope(shortcut, ShortcutScope::Global, handler)
+}
+
+/// Like [use_shortcut] but only active in the given [ShortcutScope].
+pub fn use_shortcut_with_scope(
+    shortcut: impl Into<Shortcut>,
+    scope: ShortcutScope,
+    handler: impl FnMut() + 'static,
+) {
+    let shortcut = shortcut.into();
+    let (current_handler, registration) = use_hook(|| {
+        let handler = Rc::new(RefCell::new(NoArgCallback::new(|| {})));
+        let registration = Rc::new(RefCell::new(None::<(Shortcut, ShortcutScope, Rc<()>)>));
+        (handler, registration)
+    });
+
+    // Register again if the shortcut or scope changed, dropping the old registration
+    let mut registration = registration.borrow_mut();
+    if registration
+        .as_ref()
+        .is_none_or(|(registered, registered_scope, _)| {
+            *registered != shortcut || *registered_scope != scope
+        })
+    {
+        let alive = Rc::new(());
+        ShortcutRegistry::get().register(
+            shortcut.clone(),
+            scope,
+            current_handler.clone(),
+            Rc::downgrade(&alive),
+        );
+        *registration = Some((shortcut, scope, alive));
+    }
+
+    // Keep the handler up to date with the latest render
+    *current_handler.borrow_mut() = NoArgCallback::new(handler);
+}
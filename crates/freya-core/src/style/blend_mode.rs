@@ -0,0 +1,73 @@
+use freya_engine::prelude::SkBlendMode;
+
+/// How the painted content of an element is composited with what is behind it.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Default, Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum BlendMode {
+    #[default]
+    Normal,
+    Multiply,
+    Screen,
+    Overlay,
+    Darken,
+    Lighten,
+    ColorDodge,
+    ColorBurn,
+    HardLight,
+    SoftLight,
+    Difference,
+    Exclusion,
+    Hue,
+    Saturation,
+    Color,
+    Luminosity,
+    Plus,
+}
+
+impl From<BlendMode> for SkBlendMode {
+    fn from(value: BlendMode) -> Self {
+        match value {
+            BlendMode::Normal => SkBlendMode::SrcOver,
+            BlendMode::Multiply => SkBlendMode::Multiply,
+            BlendMode::Screen => SkBlendMode::Screen,
+            BlendMode::Overlay => SkBlendMode::Overlay,
+            BlendMode::Darken => SkBlendMode::Darken,
+            BlendMode::Lighten => SkBlendMode::Lighten,
+            BlendMode::ColorDodge => SkBlendMode::ColorDodge,
+            BlendMode::ColorBurn => SkBlendMode::ColorBurn,
+            BlendMode::HardLight => SkBlendMode::HardLight,
+            BlendMode::SoftLight => SkBlendMode::SoftLight,
+            BlendMode::Difference => SkBlendMode::Difference,
+            BlendMode::Exclusion => SkBlendMode::Exclusion,
+            BlendMode::Hue => SkBlendMode::Hue,
+            BlendMode::Saturation => SkBlendMode::Saturation,
+            BlendMode::Color => SkBlendMode::Color,
+            BlendMode::Luminosity => SkBlendMode::Luminosity,
+            BlendMode::Plus => SkBlendMode::Plus,
+        }
+    }
+}
+
+impl BlendMode {
+    pub fn pretty(&self) -> String {
+        match self {
+            Self::Normal => "normal".to_string(),
+            Self::Multiply => "multiply".to_string(),
+            Self::Screen => "screen".to_string(),
+            Self::Overlay => "overlay".to_string(),
+            Self::Darken => "darken".to_string(),
+            Self::Lighten => "lighten".to_string(),
+            Self::ColorDodge => "color-dodge".to_string(),
+            Self::ColorBurn => "color-burn".to_string(),
+            Self::HardLight => "hard-light".to_string(),
+            Self::SoftLight => "soft-light".to_string(),
+            Self::Difference => "difference".to_string(),
+            Self::Exclusion => "exclusion".to_string(),
+            Self::Hue => "hue".to_string(),
+            Self::Saturation => "saturation".to_string(),
+            Self::Color => "color".to_string(),
+            Self::Luminosity => "luminosity".to_string(),
+            Self::Plus => "plus".to_string(),
+        }
+    }
+}
@@ -47,6 +47,47 @@ impl fmt::Display for BorderWidth {
     }
 }
 
+/// Color of each side of a [Border].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub struct BorderColor {
+    pub top: Color,
+    pub right: Color,
+    pub bottom: Color,
+    pub left: Color,
+}
+
+impl BorderColor {
+    pub fn new(
+        top: impl Into<Color>,
+        right: impl Into<Color>,
+        bottom: impl Into<Color>,
+        left: impl Into<Color>,
+    ) -> Self {
+        Self {
+            top: top.into(),
+            right: right.into(),
+            bottom: bottom.into(),
+            left: left.into(),
+        }
+    }
+}
+
+impl From<Color> for BorderColor {
+    fn from(color: Color) -> Self {
+        Self::new(color, color, color, color)
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub enum BorderStyle {
+    #[default]
+    Solid,
+    Dashed,
+    Dotted,
+}
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Default, Clone, Copy, Debug, PartialEq)]
 pub enum BorderAlignment {
@@ -65,8 +106,11 @@ pub enum BorderShape {
 #[derive(Default, Clone, Debug, PartialEq)]
 pub struct Border {
     pub fill: Color,
+    /// Colors for each side, overriding `fill`.
+    pub side_fills: Option<BorderColor>,
     pub width: BorderWidth,
     pub alignment: BorderAlignment,
+    pub style: BorderStyle,
 }
 
 impl Border {
@@ -84,22 +128,44 @@ impl Border {
         self
     }
 
+    /// Use a different color for each side.
+    pub fn side_fills(mut self, side_fills: impl Into<BorderColor>) -> Self {
+        self.side_fills = Some(side_fills.into());
+        self
+    }
+
     pub fn alignment(mut self, alignment: impl Into<BorderAlignment>) -> Self {
         self.alignment = alignment.into();
         self
     }
 
+    pub fn style(mut self, style: BorderStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Color of each side, in the `top`, `right`, `bottom` and `left` order.
+    pub fn side_colors(&self) -> [Color; 4] {
+        match self.side_fills {
+            Some(fills) => [fills.top, fills.right, fills.bottom, fills.left],
+            None => [self.fill; 4],
+        }
+    }
+
     #[inline]
     pub(crate) fn is_visible(&self) -> bool {
         !(self.width.top == 0.0
             && self.width.left == 0.0
             && self.width.bottom == 0.0
             && self.width.right == 0.0)
-            && self.fill != Color::TRANSPARENT
+            && self
+                .side_colors()
+                .iter()
+                .any(|color| *color != Color::TRANSPARENT)
     }
 
     pub fn pretty(&self) -> String {
-        format!("{} {:?}", self.width, self.alignment)
+        format!("{} {:?} {:?}", self.width, self.alignment, self.style)
     }
 }
 
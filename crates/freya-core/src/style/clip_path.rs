@@ -0,0 +1,93 @@
+use freya_engine::prelude::{
+    PathBuilder,
+    SkMatrix,
+    SkPath,
+    SkPoint,
+    SkRect,
+};
+use torin::prelude::{
+    Area,
+    CursorPoint,
+};
+
+/// Non-rectangular shape that clips an element and its descendants, events outside of it are ignored.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClipPath {
+    /// Largest circle that fits in the element, centered.
+    Circle,
+    /// Ellipse that fills the element.
+    Ellipse,
+    /// Polygon with points relative to the element, `(0., 0.)` is the top left corner and `(1., 1.)` the bottom right one.
+    Polygon(Vec<(f32, f32)>),
+    /// SVG path data in logical pixels, relative to the top left corner of the element.
+    Path(String),
+}
+
+impl ClipPath {
+    pub fn polygon(points: impl IntoIterator<Item = (f32, f32)>) -> Self {
+        Self::Polygon(points.into_iter().collect())
+    }
+
+    pub fn path(svg: impl Into<String>) -> Self {
+        Self::Path(svg.into())
+    }
+
+    /// Build the clip path for the given area, `None` if the SVG path is invalid.
+    pub fn to_path(&self, area: &Area, scale_factor: f32) -> Option<SkPath> {
+        match self {
+            Self::Circle => {
+                let radius = area.width().min(area.height()) / 2.;
+                let center = area.center();
+                let mut path = PathBuilder::new();
+                path.add_oval(
+                    SkRect::new(
+                        center.x - radius,
+                        center.y - radius,
+                        center.x + radius,
+                        center.y + radius,
+                    ),
+                    None,
+                    None,
+                );
+                Some(path.detach())
+            }
+            Self::Ellipse => {
+                let mut path = PathBuilder::new();
+                path.add_oval(
+                    SkRect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y()),
+                    None,
+                    None,
+                );
+                Some(path.detach())
+            }
+            Self::Polygon(points) => {
+                let mut path = PathBuilder::new();
+                for (i, (x, y)) in points.iter().enumerate() {
+                    let point = SkPoint::new(
+                        area.min_x() + area.width() * x,
+                        area.min_y() + area.height() * y,
+                    );
+                    if i == 0 {
+                        path.move_to(point);
+                    } else {
+                        path.line_to(point);
+                    }
+                }
+                path.close();
+                Some(path.detach())
+            }
+            Self::Path(svg) => {
+                let mut matrix = SkMatrix::translate((area.min_x(), area.min_y()));
+                matrix.pre_scale((scale_factor, scale_factor), None);
+                SkPath::from_svg(svg).map(|path| path.with_transform(&matrix))
+            }
+        }
+    }
+
+    /// Whether the given point is inside the clip path.
+    pub fn contains(&self, area: &Area, scale_factor: f32, point: CursorPoint) -> bool {
+        self.to_path(area, scale_factor)
+            .map(|path| path.contains((point.x as f32, point.y as f32)))
+            .unwrap_or(true)
+    }
+}
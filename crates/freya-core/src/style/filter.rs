@@ -0,0 +1,47 @@
+use freya_engine::prelude::{
+    ColorFilter,
+    color_filters,
+};
+
+/// Color filter applied to an element and all its descendants.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LayerFilter {
+    /// Convert to grayscale, `0.` leaves the colors untouched and `1.` is completely gray.
+    Grayscale(f32),
+    /// Multiply the colors, `1.` leaves them untouched.
+    Brightness(f32),
+    /// Change the saturation, `0.` is completely gray and `1.` leaves the colors untouched.
+    Saturate(f32),
+}
+
+impl LayerFilter {
+    pub fn color_filter(&self) -> ColorFilter {
+        let matrix = match *self {
+            Self::Grayscale(amount) => saturation_matrix(1. - amount.clamp(0., 1.)),
+            Self::Saturate(amount) => saturation_matrix(amount.max(0.)),
+            Self::Brightness(amount) => brightness_matrix(amount.max(0.)),
+        };
+        color_filters::matrix_row_major(&matrix, None)
+    }
+}
+
+/// Saturation matrix as defined by the CSS Filter Effects spec.
+#[rustfmt::skip]
+fn saturation_matrix(s: f32) -> [f32; 20] {
+    [
+        0.213 + 0.787 * s, 0.715 - 0.715 * s, 0.072 - 0.072 * s, 0., 0.,
+        0.213 - 0.213 * s, 0.715 + 0.285 * s, 0.072 - 0.072 * s, 0., 0.,
+        0.213 - 0.213 * s, 0.715 - 0.715 * s, 0.072 + 0.928 * s, 0., 0.,
+        0.,                0.,                0.,                1., 0.,
+    ]
+}
+
+#[rustfmt::skip]
+fn brightness_matrix(b: f32) -> [f32; 20] {
+    [
+        b,  0., 0., 0., 0.,
+        0., b,  0., 0., 0.,
+        0., 0., b,  0., 0.,
+        0., 0., 0., 1., 0.,
+    ]
+}
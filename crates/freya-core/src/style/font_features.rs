@@ -0,0 +1,101 @@
+use std::fmt;
+
+/// OpenType feature of a font, like `tnum` for tabular numbers or `liga` for ligatures.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FontFeature {
+    tag: [u8; 4],
+    value: i32,
+}
+
+impl FontFeature {
+    /// Returns `None` if the tag is not made of four ASCII characters.
+    pub fn new(tag: &str, value: i32) -> Option<Self> {
+        let tag: [u8; 4] = tag.as_bytes().try_into().ok()?;
+        tag.is_ascii().then_some(Self { tag, value })
+    }
+
+    pub fn tag(&self) -> &str {
+        std::str::from_utf8(&self.tag).unwrap_or_default()
+    }
+
+    /// `1` enables the feature, `0` disables it and higher values pick an alternate glyph.
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+
+    /// Parse a feature like `tnum`, `-liga` (disabled) or `salt=2`.
+    pub fn parse(feature: &str) -> Option<Self> {
+        let feature = feature.trim();
+        if let Some(tag) = feature.strip_prefix('-') {
+            return Self::new(tag, 0);
+        }
+        match feature.split_once('=') {
+            Some((tag, value)) => Self::new(tag.trim(), value.trim().parse().ok()?),
+            None => Self::new(feature, 1),
+        }
+    }
+}
+
+impl fmt::Display for FontFeature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.value {
+            0 => write!(f, "-{}", self.tag()),
+            1 => f.write_str(self.tag()),
+            value => write!(f, "{}={value}", self.tag()),
+        }
+    }
+}
+
+/// List of [FontFeature]s, which can be created from a comma separated string like
+/// `"tnum, ss01, -liga"`. Invalid features are ignored.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct FontFeatures(pub Vec<FontFeature>);
+
+impl FontFeatures {
+    pub fn parse(features: &str) -> Self {
+        Self(
+            features
+                .split(',')
+                .filter(|feature| !feature.trim().is_empty())
+                .filter_map(|feature| {
+                    let parsed = FontFeature::parse(feature);
+                    #[cfg(debug_assertions)]
+                    if parsed.is_none() {
+                        tracing::warn!("Invalid font feature `{}`", feature.trim());
+                    }
+                    parsed
+                })
+                .collect(),
+        )
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &FontFeature> {
+        self.0.iter()
+    }
+}
+
+impl From<&str> for FontFeatures {
+    fn from(features: &str) -> Self {
+        Self::parse(features)
+    }
+}
+
+impl From<FontFeature> for FontFeatures {
+    fn from(feature: FontFeature) -> Self {
+        Self(vec![feature])
+    }
+}
+
+impl From<Vec<FontFeature>> for FontFeatures {
+    fn from(features: Vec<FontFeature>) -> Self {
+        Self(features)
+    }
+}
+
+impl<const N: usize> From<[FontFeature; N]> for FontFeatures {
+    fn from(features: [FontFeature; N]) -> Self {
+        Self(features.to_vec())
+    }
+}
@@ -40,11 +40,56 @@ impl<C: Into<Color>> From<(C, f32)> for GradientStop {
     }
 }
 
+/// Skia colors of the given stops.
+///
+/// Repeating stops are normalized to the range between the first and the last stop,
+/// which is returned so the shader geometry can be adjusted to it.
+fn gradient_colors(
+    stops: &[GradientStop],
+    repeating: bool,
+) -> (Vec<SkColor4f>, Vec<f32>, TileMode, (f32, f32)) {
+    let colors = stops
+        .iter()
+        .map(|stop| SkColor4f::from(stop.color))
+        .collect();
+    let offsets = stops.iter().map(|stop| stop.offset);
+
+    let range = match (stops.first(), stops.last()) {
+        (Some(first), Some(last)) if repeating && last.offset > first.offset => {
+            (first.offset, last.offset)
+        }
+        _ => return (colors, offsets.collect(), TileMode::Clamp, (0., 1.)),
+    };
+
+    let offsets = offsets
+        .map(|offset| (offset - range.0) / (range.1 - range.0))
+        .collect();
+    (colors, offsets, TileMode::Repeat, range)
+}
+
+/// Center of the gradient given a position relative to the bounds, the center of the bounds by default.
+fn gradient_center(bounds: Area, center: Option<(f32, f32)>) -> SkPoint {
+    let (x, y) = center.unwrap_or((0.5, 0.5));
+    SkPoint::new(
+        bounds.min_x() + bounds.width() * x,
+        bounds.min_y() + bounds.height() * y,
+    )
+}
+
+fn display_stops(stops: &[GradientStop]) -> String {
+    stops
+        .iter()
+        .map(|stop| stop.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct LinearGradient {
     stops: Vec<GradientStop>,
     angle: f32,
+    repeating: bool,
 }
 
 impl LinearGradient {
@@ -74,15 +119,16 @@ impl LinearGradient {
         self
     }
 
+    /// Repeat the stops, from the first one to the last one, along the whole gradient line.
+    pub fn repeating(mut self, repeating: bool) -> Self {
+        self.repeating = repeating;
+        self
+    }
+
     pub fn into_shader(&self, bounds: Area) -> Option<Shader> {
-        let colors: Vec<SkColor4f> = self
-            .stops
-            .iter()
-            .map(|stop| SkColor4f::from(stop.color))
-            .collect();
-        let offsets: Vec<f32> = self.stops.iter().map(|stop| stop.offset).collect();
-
-        let grad_colors = Colors::new(&colors[..], Some(&offsets[..]), TileMode::Clamp, None);
+        let (colors, offsets, tile_mode, (from, to)) = gradient_colors(&self.stops, self.repeating);
+
+        let grad_colors = Colors::new(&colors[..], Some(&offsets[..]), tile_mode, None);
         let grad = Gradient::new(grad_colors, Flags::default());
 
         let (dy, dx) = (self.angle.to_radians() + FRAC_PI_2).sin_cos();
@@ -95,11 +141,10 @@ impl LinearGradient {
         let endpoint = farthest_corner + SkPoint::new(-u * dy, u * dx);
 
         let origin = SkPoint::new(bounds.min_x(), bounds.min_y());
+        let start = SkPoint::new(bounds.width(), bounds.height()) - endpoint + origin;
+        let end = endpoint + origin;
         shaders::linear_gradient(
-            (
-                SkPoint::new(bounds.width(), bounds.height()) - endpoint + origin,
-                endpoint + origin,
-            ),
+            (start + (end - start) * from, start + (end - start) * to),
             &grad,
             None,
         )
@@ -110,13 +155,10 @@ impl fmt::Display for LinearGradient {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "linear-gradient({}deg, {})",
+            "{}linear-gradient({}deg, {})",
+            if self.repeating { "repeating-" } else { "" },
             self.angle,
-            self.stops
-                .iter()
-                .map(|stop| stop.to_string())
-                .collect::<Vec<_>>()
-                .join(", ")
+            display_stops(&self.stops)
         )
     }
 }
@@ -125,6 +167,8 @@ impl fmt::Display for LinearGradient {
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct RadialGradient {
     stops: Vec<GradientStop>,
+    center: Option<(f32, f32)>,
+    repeating: bool,
 }
 
 impl RadialGradient {
@@ -148,27 +192,49 @@ impl RadialGradient {
         self
     }
 
-    pub fn into_shader(&self, bounds: Area) -> Option<Shader> {
-        let colors: Vec<SkColor4f> = self
-            .stops
-            .iter()
-            .map(|stop| SkColor4f::from(stop.color))
-            .collect();
-        let offsets: Vec<f32> = self.stops.iter().map(|stop| stop.offset).collect();
+    /// Set the center, relative to the element: `(0., 0.)` is the top left corner and `(1., 1.)` the bottom right one.
+    pub fn center(mut self, x: f32, y: f32) -> Self {
+        self.center = Some((x, y));
+        self
+    }
 
-        let center = bounds.center();
+    /// Repeat the stops, from the first one to the last one, outwards.
+    pub fn repeating(mut self, repeating: bool) -> Self {
+        self.repeating = repeating;
+        self
+    }
 
-        let grad_colors = Colors::new(&colors[..], Some(&offsets[..]), TileMode::Clamp, None);
+    pub fn into_shader(&self, bounds: Area) -> Option<Shader> {
+        let (colors, offsets, tile_mode, (from, to)) = gradient_colors(&self.stops, self.repeating);
+
+        let center = gradient_center(bounds, self.center);
+        let radius = match self.center {
+            // Reach the farthest corner when the center is moved
+            Some(_) => [
+                (bounds.min_x(), bounds.min_y()),
+                (bounds.max_x(), bounds.min_y()),
+                (bounds.min_x(), bounds.max_y()),
+                (bounds.max_x(), bounds.max_y()),
+            ]
+            .into_iter()
+            .map(|(x, y)| (SkPoint::new(x, y) - center).length())
+            .fold(0., f32::max),
+            None => bounds.width().max(bounds.height()) / 2.0,
+        };
+
+        let grad_colors = Colors::new(&colors[..], Some(&offsets[..]), tile_mode, None);
         let grad = Gradient::new(grad_colors, Flags::default());
 
-        shaders::radial_gradient(
-            (
-                SkPoint::new(center.x, center.y),
-                bounds.width().max(bounds.height()) / 2.0,
-            ),
-            &grad,
-            None,
-        )
+        if from > 0. {
+            shaders::two_point_conical_gradient(
+                (center, radius * from),
+                (center, radius * to),
+                &grad,
+                None,
+            )
+        } else {
+            shaders::radial_gradient((center, radius * to), &grad, None)
+        }
     }
 }
 
@@ -176,13 +242,15 @@ impl fmt::Display for RadialGradient {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "radial-gradient({})",
-            self.stops
-                .iter()
-                .map(|stop| stop.to_string())
-                .collect::<Vec<_>>()
-                .join(", ")
-        )
+            "{}radial-gradient(",
+            if self.repeating { "repeating-" } else { "" }
+        )?;
+
+        if let Some((x, y)) = self.center {
+            write!(f, "at {}% {}%, ", x * 100., y * 100.)?;
+        }
+
+        write!(f, "{})", display_stops(&self.stops))
     }
 }
 
@@ -192,6 +260,8 @@ pub struct ConicGradient {
     stops: Vec<GradientStop>,
     angles: Option<(f32, f32)>,
     angle: Option<f32>,
+    center: Option<(f32, f32)>,
+    repeating: bool,
 }
 
 impl ConicGradient {
@@ -227,27 +297,35 @@ impl ConicGradient {
         self
     }
 
+    /// Set the center, relative to the element: `(0., 0.)` is the top left corner and `(1., 1.)` the bottom right one.
+    pub fn center(mut self, x: f32, y: f32) -> Self {
+        self.center = Some((x, y));
+        self
+    }
+
+    /// Repeat the stops, from the first one to the last one, around the center.
+    pub fn repeating(mut self, repeating: bool) -> Self {
+        self.repeating = repeating;
+        self
+    }
+
     pub fn into_shader(&self, bounds: Area) -> Option<Shader> {
-        let colors: Vec<SkColor4f> = self
-            .stops
-            .iter()
-            .map(|stop| SkColor4f::from(stop.color))
-            .collect();
-        let offsets: Vec<f32> = self.stops.iter().map(|stop| stop.offset).collect();
+        let (colors, offsets, tile_mode, (from, to)) = gradient_colors(&self.stops, self.repeating);
 
-        let center = bounds.center();
+        let center = gradient_center(bounds, self.center);
 
         let matrix =
             Matrix::rotate_deg_pivot(-90.0 + self.angle.unwrap_or(0.0), (center.x, center.y));
 
-        let grad_colors = Colors::new(&colors[..], Some(&offsets[..]), TileMode::Clamp, None);
+        let grad_colors = Colors::new(&colors[..], Some(&offsets[..]), tile_mode, None);
         let grad = Gradient::new(grad_colors, Flags::default());
 
         let (start_angle, end_angle) = self.angles.unwrap_or((0.0, 360.0));
+        let sweep = end_angle - start_angle;
 
         shaders::sweep_gradient(
-            SkPoint::new(center.x, center.y),
-            (start_angle, end_angle),
+            center,
+            (start_angle + sweep * from, start_angle + sweep * to),
             &grad,
             Some(&matrix),
         )
@@ -256,7 +334,11 @@ impl ConicGradient {
 
 impl fmt::Display for ConicGradient {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "conic-gradient(")?;
+        write!(
+            f,
+            "{}conic-gradient(",
+            if self.repeating { "repeating-" } else { "" }
+        )?;
 
         if let Some(angle) = self.angle {
             write!(f, "{angle}deg, ")?;
@@ -266,14 +348,10 @@ impl fmt::Display for ConicGradient {
             write!(f, "from {start}deg to {end}deg, ")?;
         }
 
-        write!(
-            f,
-            "{})",
-            self.stops
-                .iter()
-                .map(|stop| stop.to_string())
-                .collect::<Vec<_>>()
-                .join(", ")
-        )
+        if let Some((x, y)) = self.center {
+            write!(f, "at {}% {}%, ", x * 100., y * 100.)?;
+        }
+
+        write!(f, "{})", display_stops(&self.stops))
     }
 }
@@ -0,0 +1,114 @@
+use std::{
+    cell::RefCell,
+    fmt::Debug,
+    rc::Rc,
+};
+
+use bytes::Bytes;
+use freya_engine::prelude::{
+    Canvas,
+    Paint,
+    SkBlendMode,
+    SkData,
+    SkImage,
+    SkRect,
+};
+use torin::prelude::Area;
+
+use crate::{
+    accessibility::id::AccessibilityId,
+    elements::image::ImageHolder,
+    style::{
+        fill::Fill,
+        gradient::{
+            ConicGradient,
+            LinearGradient,
+            RadialGradient,
+        },
+    },
+};
+
+/// Alpha mask applied to an element and its descendants, anything outside of the element is hidden.
+#[derive(Clone, PartialEq)]
+pub enum Mask {
+    /// Alpha of an image, stretched to the area of the element.
+    Image(ImageHolder),
+    /// Alpha of a color or gradient, such as a [LinearGradient] that fades to transparent.
+    Fill(Fill),
+    /// Alpha of what the element with the given [AccessibilityId] paints, placed at the top left corner of
+    /// the masked element. The referenced element is only painted as part of the mask and must not contain the masked element.
+    Node(AccessibilityId),
+}
+
+impl Debug for Mask {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Image(_) => f.write_str("Image"),
+            Self::Fill(fill) => f.debug_tuple("Fill").field(fill).finish(),
+            Self::Node(id) => f.debug_tuple("Node").field(id).finish(),
+        }
+    }
+}
+
+impl Mask {
+    /// Decode the given encoded image to use it as mask, `None` if it can't be decoded.
+    pub fn image(bytes: impl Into<Bytes>) -> Option<Self> {
+        let bytes = bytes.into();
+        let image = SkImage::from_encoded(SkData::new_copy(&bytes))?;
+        Some(Self::Image(ImageHolder {
+            image: Rc::new(RefCell::new(image)),
+            bytes,
+        }))
+    }
+
+    /// Keep only what was painted in the current layer where this mask is opaque.
+    ///
+    /// [Mask::Node] is painted by the render pipeline as it needs the tree.
+    pub fn render(&self, canvas: &Canvas, area: Area) {
+        let rect = SkRect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y());
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        paint.set_blend_mode(SkBlendMode::DstIn);
+
+        match self {
+            Self::Image(holder) => {
+                canvas.draw_image_rect(&*holder.image.borrow(), None, rect, &paint);
+            }
+            Self::Fill(fill) => {
+                fill.apply_to_paint(&mut paint, area);
+                canvas.draw_rect(rect, &paint);
+            }
+            Self::Node(_) => {}
+        }
+    }
+}
+
+impl From<AccessibilityId> for Mask {
+    fn from(id: AccessibilityId) -> Self {
+        Self::Node(id)
+    }
+}
+
+impl From<Fill> for Mask {
+    fn from(fill: Fill) -> Self {
+        Self::Fill(fill)
+    }
+}
+
+impl From<LinearGradient> for Mask {
+    fn from(gradient: LinearGradient) -> Self {
+        Self::Fill(Fill::LinearGradient(Box::new(gradient)))
+    }
+}
+
+impl From<RadialGradient> for Mask {
+    fn from(gradient: RadialGradient) -> Self {
+        Self::Fill(Fill::RadialGradient(Box::new(gradient)))
+    }
+}
+
+impl From<ConicGradient> for Mask {
+    fn from(gradient: ConicGradient) -> Self {
+        Self::Fill(Fill::ConicGradient(Box::new(gradient)))
+    }
+}
@@ -1,19 +1,31 @@
+pub mod blend_mode;
 pub mod border;
+pub mod clip_path;
 pub mod color;
 pub mod corner_radius;
 pub mod cursor;
 pub mod default_fonts;
 pub mod fill;
+pub mod filter;
+pub mod font_features;
 pub mod font_size;
 pub mod font_slant;
 pub mod font_weight;
 pub mod font_width;
 pub mod gradient;
+pub mod mask;
 pub mod scale;
+pub mod shader;
 pub mod shadow;
 pub mod text_align;
 pub mod text_decoration;
+pub mod text_direction;
 pub mod text_height;
 pub mod text_overflow;
 pub mod text_shadow;
+pub mod text_spacing;
+pub mod text_stroke;
+pub mod text_wrap;
+pub mod transform;
+pub mod transition;
 pub mod vertical_align;
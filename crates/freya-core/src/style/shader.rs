@@ -0,0 +1,175 @@
+use std::{
+    fmt::Debug,
+    rc::Rc,
+};
+
+use freya_engine::prelude::{
+    ImageFilter,
+    RuntimeEffect,
+    RuntimeShaderBuilder,
+    runtime_shader,
+};
+use torin::prelude::Area;
+
+use crate::prelude::Color;
+
+/// Value of a SkSL uniform passed to a [ShaderEffect].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShaderUniform {
+    Float(f32),
+    Float2([f32; 2]),
+    Float3([f32; 3]),
+    Float4([f32; 4]),
+}
+
+impl ShaderUniform {
+    fn as_slice(&self) -> &[f32] {
+        match self {
+            Self::Float(value) => std::slice::from_ref(value),
+            Self::Float2(value) => value,
+            Self::Float3(value) => value,
+            Self::Float4(value) => value,
+        }
+    }
+}
+
+impl From<f32> for ShaderUniform {
+    fn from(value: f32) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl From<(f32, f32)> for ShaderUniform {
+    fn from((x, y): (f32, f32)) -> Self {
+        Self::Float2([x, y])
+    }
+}
+
+impl From<(f32, f32, f32)> for ShaderUniform {
+    fn from((x, y, z): (f32, f32, f32)) -> Self {
+        Self::Float3([x, y, z])
+    }
+}
+
+impl From<(f32, f32, f32, f32)> for ShaderUniform {
+    fn from((x, y, z, w): (f32, f32, f32, f32)) -> Self {
+        Self::Float4([x, y, z, w])
+    }
+}
+
+impl From<Color> for ShaderUniform {
+    /// Normalized and unpremultiplied RGBA, to be used as a `half4` or `float4`.
+    fn from(color: Color) -> Self {
+        Self::Float4([
+            color.r() as f32 / 255.,
+            color.g() as f32 / 255.,
+            color.b() as f32 / 255.,
+            color.a() as f32 / 255.,
+        ])
+    }
+}
+
+/// A SkSL runtime shader applied to what an element paints, its children are not affected.
+///
+/// The painted content is available as a `uniform shader content`, sampled with `content.eval(coord)`.
+/// Coordinates are in physical pixels of the window. These uniforms are filled in when declared:
+/// - `float2 u_resolution`: Size of the element.
+/// - `float2 u_offset`: Position of the element.
+///
+/// Compile it once, for example with `use_hook`, and set the uniforms on every render:
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// const SKSL: &str = "
+///     uniform shader content;
+///     uniform float2 u_resolution;
+///     uniform float2 u_offset;
+///     uniform float u_strength;
+///
+///     half4 main(float2 coord) {
+///         float x = (coord.x - u_offset.x) / u_resolution.x;
+///         return content.eval(coord) * half4(half3(mix(1.0, x, u_strength)), 1.0);
+///     }
+/// ";
+///
+/// fn app() -> impl IntoElement {
+///     let shader = use_hook(|| ShaderEffect::new(SKSL).expect("Invalid SkSL"));
+///
+///     rect()
+///         .expanded()
+///         .background((255, 0, 0))
+///         .shader(shader.clone().uniform("u_strength", 0.5))
+/// }
+/// ```
+#[derive(Clone)]
+pub struct ShaderEffect {
+    effect: Rc<RuntimeEffect>,
+    uniforms: Vec<(String, ShaderUniform)>,
+}
+
+impl PartialEq for ShaderEffect {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.effect, &other.effect) && self.uniforms == other.uniforms
+    }
+}
+
+impl Debug for ShaderEffect {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ShaderEffect")
+            .field("uniforms", &self.uniforms)
+            .finish()
+    }
+}
+
+impl ShaderEffect {
+    /// Compile the given SkSL, returning the compiler errors if it is invalid.
+    pub fn new(sksl: &str) -> Result<Self, String> {
+        RuntimeEffect::make_for_shader(sksl, None).map(|effect| Self {
+            effect: Rc::new(effect),
+            uniforms: Vec::new(),
+        })
+    }
+
+    /// Set the value of a uniform, replacing its previous value.
+    pub fn uniform(mut self, name: impl Into<String>, value: impl Into<ShaderUniform>) -> Self {
+        let name = name.into();
+        let value = value.into();
+        match self.uniforms.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, previous)) => *previous = value,
+            None => self.uniforms.push((name, value)),
+        }
+        self
+    }
+
+    pub fn uniforms(&self) -> &[(String, ShaderUniform)] {
+        &self.uniforms
+    }
+
+    pub(crate) fn image_filter(&self, area: &Area) -> Option<ImageFilter> {
+        let mut builder = RuntimeShaderBuilder::new(self.effect.as_ref().clone());
+
+        let builtins = [
+            (
+                "u_resolution",
+                ShaderUniform::from((area.width(), area.height())),
+            ),
+            (
+                "u_offset",
+                ShaderUniform::from((area.min_x(), area.min_y())),
+            ),
+        ];
+        for (name, value) in builtins {
+            if self.effect.uniforms().iter().any(|u| u.name() == name) {
+                let _ = builder.set_uniform_float(name, value.as_slice());
+            }
+        }
+
+        for (name, value) in &self.uniforms {
+            if let Err(err) = builder.set_uniform_float(name, value.as_slice()) {
+                tracing::warn!("Failed to set the shader uniform `{name}`: {err:?}");
+            }
+        }
+
+        runtime_shader(&builder, "content", None)
+    }
+}
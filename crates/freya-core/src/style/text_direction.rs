@@ -0,0 +1,30 @@
+use freya_engine::prelude::SkTextDirection;
+
+/// Direction in which the text of a language is written.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Default, Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum TextDirection {
+    /// Left to right, like English.
+    #[default]
+    Ltr,
+    /// Right to left, like Arabic or Hebrew.
+    Rtl,
+}
+
+impl From<TextDirection> for SkTextDirection {
+    fn from(value: TextDirection) -> Self {
+        match value {
+            TextDirection::Ltr => SkTextDirection::LTR,
+            TextDirection::Rtl => SkTextDirection::RTL,
+        }
+    }
+}
+
+impl TextDirection {
+    pub fn pretty(&self) -> String {
+        match self {
+            Self::Ltr => "ltr".to_string(),
+            Self::Rtl => "rtl".to_string(),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use std::hash::Hash;
+
+/// Extra space in logical pixels added between letters or words, negative values tighten it.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub struct TextSpacing(pub f32);
+
+impl Hash for TextSpacing {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+impl From<f32> for TextSpacing {
+    fn from(value: f32) -> Self {
+        TextSpacing(value)
+    }
+}
+
+impl From<TextSpacing> for f32 {
+    fn from(value: TextSpacing) -> Self {
+        value.0
+    }
+}
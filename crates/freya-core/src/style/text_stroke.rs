@@ -0,0 +1,52 @@
+use std::hash::Hash;
+
+use freya_engine::prelude::*;
+
+use crate::style::color::Color;
+
+/// Outline painted around the glyphs, behind the text fill.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub struct TextStroke {
+    pub width: f32,
+    pub color: Color,
+}
+
+impl Hash for TextStroke {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.width.to_bits().hash(state);
+        self.color.hash(state);
+    }
+}
+
+impl TextStroke {
+    pub fn new(width: f32, color: impl Into<Color>) -> Self {
+        Self {
+            width,
+            color: color.into(),
+        }
+    }
+
+    /// Save a layer that outlines the text painted in it once restored.
+    pub(crate) fn save_layer(&self, canvas: &Canvas, scale_factor: f32) -> Option<usize> {
+        let image_filter = self.image_filter(scale_factor)?;
+        let mut paint = Paint::default();
+        paint.set_image_filter(image_filter);
+        Some(canvas.save_layer(&SaveLayerRec::default().paint(&paint)))
+    }
+
+    /// Dilate the painted text and place the colored result under it.
+    fn image_filter(&self, scale_factor: f32) -> Option<ImageFilter> {
+        let radius = self.width * scale_factor;
+        let outline = dilate((radius, radius), None, None)?;
+        let color_filter = color_filters::blend(SkColor::from(self.color), SkBlendMode::SrcIn)?;
+        let outline = color_image_filter(color_filter, outline, None)?;
+        merge_image_filters([Some(outline), None], None)
+    }
+}
+
+impl<C: Into<Color>> From<(f32, C)> for TextStroke {
+    fn from((width, color): (f32, C)) -> Self {
+        Self::new(width, color)
+    }
+}
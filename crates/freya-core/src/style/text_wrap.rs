@@ -0,0 +1,141 @@
+use std::borrow::Cow;
+
+/// Break opportunity that shows a hyphen when taken.
+pub(crate) const SOFT_HYPHEN: char = '\u{00AD}';
+
+/// What to do with words that do not fit in a line.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Default, Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum OverflowWrap {
+    /// Let them overflow the element.
+    Normal,
+    /// Break them between any two characters. Only the words that do not fit in a line are broken.
+    #[default]
+    Anywhere,
+}
+
+impl OverflowWrap {
+    pub fn pretty(&self) -> String {
+        match self {
+            Self::Normal => "normal".to_string(),
+            Self::Anywhere => "anywhere".to_string(),
+        }
+    }
+}
+
+/// Break words between their syllables, needs the `hyphenation` feature.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Default, Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum Hyphenation {
+    #[default]
+    None,
+    /// Hyphenate with the English (US) dictionary.
+    Auto,
+}
+
+impl Hyphenation {
+    pub fn pretty(&self) -> String {
+        match self {
+            Self::None => "none".to_string(),
+            Self::Auto => "auto".to_string(),
+        }
+    }
+}
+
+/// Width to lay out a paragraph with so its words wrap as the given [OverflowWrap] says.
+///
+/// The paragraph already breaks the words that do not fit in a line,
+/// so they only need more room when they should overflow instead.
+pub(crate) fn overflow_width(overflow_wrap: OverflowWrap, width: f32, min_width: f32) -> f32 {
+    match overflow_wrap {
+        OverflowWrap::Normal if min_width > width => min_width,
+        _ => width,
+    }
+}
+
+/// Add soft hyphens between the syllables of the words of a text.
+///
+/// Texts are hyphenated once and then reused.
+#[cfg(feature = "hyphenation")]
+pub(crate) fn hyphenate(text: &str, hyphenation: Hyphenation) -> Cow<'_, str> {
+    use std::{
+        cell::RefCell,
+        sync::OnceLock,
+    };
+
+    use hyphenation::{
+        Hyphenator,
+        Language,
+        Load,
+        Standard,
+    };
+
+    use crate::fifo_cache::FifoCache;
+
+    static DICTIONARY: OnceLock<Option<Standard>> = OnceLock::new();
+
+    thread_local! {
+        static HYPHENATED: RefCell<FifoCache<String, String>> = RefCell::default();
+    }
+
+    if hyphenation == Hyphenation::None {
+        return Cow::Borrowed(text);
+    }
+    let Some(dictionary) = DICTIONARY
+        .get_or_init(|| Standard::from_embedded(Language::EnglishUS).ok())
+        .as_ref()
+    else {
+        return Cow::Borrowed(text);
+    };
+
+    let key = text.to_string();
+    if let Some(hyphenated) = HYPHENATED.with_borrow(|cache| cache.get(&key).cloned()) {
+        return Cow::Owned(hyphenated);
+    }
+
+    let mut hyphenated = String::with_capacity(text.len() + text.len() / 4);
+    let mut word_start = None;
+    for (index, character) in text.char_indices().chain([(text.len(), ' ')]) {
+        match (character.is_alphabetic(), word_start) {
+            (true, None) => word_start = Some(index),
+            (false, Some(start)) => {
+                let word = &text[start..index];
+                let mut last = 0;
+                for position in dictionary.hyphenate(word).breaks {
+                    hyphenated.push_str(&word[last..position]);
+                    hyphenated.push(SOFT_HYPHEN);
+                    last = position;
+                }
+                hyphenated.push_str(&word[last..]);
+                word_start = None;
+            }
+            _ => {}
+        }
+        if word_start.is_none() && index < text.len() {
+            hyphenated.push(character);
+        }
+    }
+
+    HYPHENATED.with_borrow_mut(|cache| cache.insert(key, hyphenated.clone()));
+    Cow::Owned(hyphenated)
+}
+
+#[cfg(not(feature = "hyphenation"))]
+pub(crate) fn hyphenate(text: &str, _hyphenation: Hyphenation) -> Cow<'_, str> {
+    Cow::Borrowed(text)
+}
+
+#[cfg(all(test, feature = "hyphenation"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn hyphenate_words() {
+        let hyphenated = hyphenate("the hyphenation", Hyphenation::Auto);
+        assert_eq!(hyphenated, "the hy\u{AD}phen\u{AD}ation");
+
+        // Cached texts give the same result
+        assert_eq!(hyphenate("the hyphenation", Hyphenation::Auto), hyphenated);
+        assert_eq!(hyphenate("hyphenation", Hyphenation::None), "hyphenation");
+    }
+}
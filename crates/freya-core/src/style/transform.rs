@@ -0,0 +1,107 @@
+use freya_engine::prelude::SkMatrix;
+use torin::prelude::Area;
+
+use crate::style::scale::Scale;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TransformOperation {
+    /// Rotation in degrees.
+    Rotate(f32),
+    Scale(Scale),
+    /// Translation in logical pixels.
+    Translate(f32, f32),
+    /// Skew in degrees for each axis.
+    Skew(f32, f32),
+    /// Affine matrix `[a, b, c, d, e, f]` as in CSS, with the translation in logical pixels.
+    Matrix([f32; 6]),
+}
+
+impl TransformOperation {
+    fn to_matrix(self, scale_factor: f32) -> SkMatrix {
+        match self {
+            Self::Rotate(degrees) => SkMatrix::rotate_deg(degrees),
+            Self::Scale(scale) => SkMatrix::scale((scale.x, scale.y)),
+            Self::Translate(x, y) => SkMatrix::translate((x * scale_factor, y * scale_factor)),
+            Self::Skew(x, y) => SkMatrix::skew((x.to_radians().tan(), y.to_radians().tan())),
+            Self::Matrix([a, b, c, d, e, f]) => {
+                SkMatrix::new_all(a, c, e * scale_factor, b, d, f * scale_factor, 0., 0., 1.)
+            }
+        }
+    }
+}
+
+/// 2D transformation of an element and its descendants, applied around its center.
+///
+/// Unlike layout changes, transforms don't move the siblings. Events and accessibility bounds follow the transformed shape.
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// fn app() -> impl IntoElement {
+///     rect()
+///         .transform(
+///             Transform::new()
+///                 .translate(20., 0.)
+///                 .rotate(45.)
+///                 .skew(10., 0.),
+///         )
+///         .width(Size::px(100.))
+///         .height(Size::px(100.))
+///         .background((0, 119, 182))
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Transform {
+    operations: Vec<TransformOperation>,
+}
+
+impl Transform {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn operation(mut self, operation: TransformOperation) -> Self {
+        self.operations.push(operation);
+        self
+    }
+
+    pub fn rotate(self, degrees: f32) -> Self {
+        self.operation(TransformOperation::Rotate(degrees))
+    }
+
+    pub fn scale(self, scale: impl Into<Scale>) -> Self {
+        self.operation(TransformOperation::Scale(scale.into()))
+    }
+
+    pub fn translate(self, x: f32, y: f32) -> Self {
+        self.operation(TransformOperation::Translate(x, y))
+    }
+
+    pub fn skew(self, x: f32, y: f32) -> Self {
+        self.operation(TransformOperation::Skew(x, y))
+    }
+
+    pub fn matrix(self, matrix: [f32; 6]) -> Self {
+        self.operation(TransformOperation::Matrix(matrix))
+    }
+
+    pub fn operations(&self) -> &[TransformOperation] {
+        &self.operations
+    }
+
+    /// Matrix of all the operations applied in order around the center of the given area.
+    pub fn to_matrix(&self, area: &Area, scale_factor: f32) -> SkMatrix {
+        let center = area.center();
+        let mut matrix = SkMatrix::translate((center.x, center.y));
+        for operation in &self.operations {
+            matrix.pre_concat(&operation.to_matrix(scale_factor));
+        }
+        matrix.pre_translate((-center.x, -center.y));
+        matrix
+    }
+}
+
+impl From<TransformOperation> for Transform {
+    fn from(operation: TransformOperation) -> Self {
+        Self::new().operation(operation)
+    }
+}
@@ -0,0 +1,236 @@
+use std::{
+    fmt,
+    str::FromStr,
+    time::Duration,
+};
+
+/// Property of an element that can be transitioned.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransitionProperty {
+    /// All the transitionable properties.
+    All,
+    Background,
+    CornerRadius,
+    Opacity,
+    Width,
+    Height,
+}
+
+impl TransitionProperty {
+    /// Whether this property includes `other`.
+    pub fn contains(&self, other: TransitionProperty) -> bool {
+        *self == Self::All || *self == other
+    }
+}
+
+impl FromStr for TransitionProperty {
+    type Err = ParseTransitionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(Self::All),
+            "background" => Ok(Self::Background),
+            "corner_radius" | "corner-radius" => Ok(Self::CornerRadius),
+            "opacity" => Ok(Self::Opacity),
+            "width" => Ok(Self::Width),
+            "height" => Ok(Self::Height),
+            _ => Err(ParseTransitionError(s.to_string())),
+        }
+    }
+}
+
+/// Easing curve of a [Transition].
+///
+/// By default it is [TransitionEase::EaseOut].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TransitionEase {
+    Linear,
+    EaseIn,
+    #[default]
+    EaseOut,
+    EaseInOut,
+}
+
+impl TransitionEase {
+    /// Map a linear progress between `0.0` and `1.0` to the eased progress.
+    pub fn apply(&self, progress: f32) -> f32 {
+        let t = progress.clamp(0., 1.);
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => t * t * t,
+            Self::EaseOut => 1. - (1. - t).powi(3),
+            Self::EaseInOut => {
+                if t < 0.5 {
+                    4. * t * t * t
+                } else {
+                    1. - (-2. * t + 2.).powi(3) / 2.
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for TransitionEase {
+    type Err = ParseTransitionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "linear" => Ok(Self::Linear),
+            "ease-in" => Ok(Self::EaseIn),
+            "ease" | "ease-out" => Ok(Self::EaseOut),
+            "ease-in-out" => Ok(Self::EaseInOut),
+            _ => Err(ParseTransitionError(s.to_string())),
+        }
+    }
+}
+
+/// Smoothly interpolate a property of an element when it changes.
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use freya::prelude::*;
+/// let transition = Transition::new(TransitionProperty::Background, Duration::from_millis(200))
+///     .ease(TransitionEase::Linear);
+/// let parsed: Transition = "background 200ms linear".parse().unwrap();
+/// assert_eq!(transition, parsed);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transition {
+    pub property: TransitionProperty,
+    pub duration: Duration,
+    pub ease: TransitionEase,
+}
+
+impl Transition {
+    pub fn new(property: TransitionProperty, duration: Duration) -> Self {
+        Self {
+            property,
+            duration,
+            ease: TransitionEase::default(),
+        }
+    }
+
+    pub fn ease(mut self, ease: TransitionEase) -> Self {
+        self.ease = ease;
+        self
+    }
+
+    /// Eased progress of this transition after `elapsed` time, between `0.0` and `1.0`.
+    pub fn progress(&self, elapsed: Duration) -> f32 {
+        if self.duration.is_zero() {
+            return 1.;
+        }
+        self.ease
+            .apply((elapsed.as_secs_f64() / self.duration.as_secs_f64()) as f32)
+    }
+}
+
+impl FromStr for Transition {
+    type Err = ParseTransitionError;
+
+    /// Parse a transition in the form of `<property> <duration> [ease]`, e.g `width 300ms ease-in`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_whitespace();
+
+        let property = parts
+            .next()
+            .ok_or_else(|| ParseTransitionError(s.to_string()))?
+            .parse()?;
+
+        let duration = parts
+            .next()
+            .and_then(parse_duration)
+            .ok_or_else(|| ParseTransitionError(s.to_string()))?;
+
+        let ease = parts
+            .next()
+            .map(str::parse)
+            .transpose()?
+            .unwrap_or_default();
+
+        if parts.next().is_some() {
+            return Err(ParseTransitionError(s.to_string()));
+        }
+
+        Ok(Self {
+            property,
+            duration,
+            ease,
+        })
+    }
+}
+
+fn parse_duration(value: &str) -> Option<Duration> {
+    if let Some(ms) = value.strip_suffix("ms") {
+        ms.parse::<u64>().ok().map(Duration::from_millis)
+    } else if let Some(secs) = value.strip_suffix('s') {
+        // f32 seconds can't represent most decimals, 0.3s would be 300.000012ms
+        secs.parse::<f64>()
+            .ok()
+            .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+    } else {
+        None
+    }
+}
+
+/// Error returned when a [Transition] could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseTransitionError(pub String);
+
+impl fmt::Display for ParseTransitionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid transition: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseTransitionError {}
+
+/// List of [Transition]s of an element.
+///
+/// Can be created from a comma separated list of transitions such as `"background 200ms ease-out, width 300ms"`.
+/// Invalid entries are ignored.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Transitions(pub Vec<Transition>);
+
+impl Transitions {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Transition> {
+        self.0.iter()
+    }
+}
+
+impl From<Transition> for Transitions {
+    fn from(value: Transition) -> Self {
+        Self(vec![value])
+    }
+}
+
+impl From<Vec<Transition>> for Transitions {
+    fn from(value: Vec<Transition>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for Transitions {
+    fn from(value: &str) -> Self {
+        Self(
+            value
+                .split(',')
+                .filter_map(|transition| match transition.parse() {
+                    Ok(transition) => Some(transition),
+                    Err(err) => {
+                        tracing::warn!("{err}");
+                        None
+                    }
+                })
+                .collect(),
+        )
+    }
+}
@@ -0,0 +1,356 @@
+use keyboard_types::{
+    Key,
+    NamedKey,
+};
+use ragnarok::CursorPoint;
+use rustc_hash::FxHashMap;
+
+use crate::{
+    events::{
+        measurer::EventsMeasurerAdapter,
+        platform::PlatformEvent,
+    },
+    node_id::NodeId,
+    prelude::{
+        KeyboardEventName,
+        MouseButton,
+        MouseEventName,
+    },
+    style::text_wrap::{
+        SOFT_HYPHEN,
+        hyphenate,
+    },
+    tree::Tree,
+};
+
+/// Position in the text of a selectable element, in UTF-16 offsets like its paragraph.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TextPosition {
+    pub node_id: NodeId,
+    pub offset: usize,
+}
+
+/// Text selected with the pointer or the keyboard across the
+/// [selectable](crate::elements::label::Label::selectable) labels and paragraphs.
+#[derive(Default, Debug)]
+pub struct TextSelection {
+    anchor: Option<TextPosition>,
+    focus: Option<TextPosition>,
+    dragging: bool,
+    /// Selected range of every selected element.
+    ranges: FxHashMap<NodeId, (usize, usize)>,
+    /// Selected elements in the order of the tree.
+    nodes: Vec<NodeId>,
+    changed: bool,
+}
+
+impl TextSelection {
+    /// Where the selection started.
+    pub fn anchor(&self) -> Option<TextPosition> {
+        self.anchor
+    }
+
+    /// Where the selection ends, moved by the pointer or the keyboard.
+    pub fn focus(&self) -> Option<TextPosition> {
+        self.focus
+    }
+
+    /// Selected elements in the order of the tree.
+    pub fn nodes(&self) -> &[NodeId] {
+        &self.nodes
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.values().all(|(from, to)| from == to)
+    }
+
+    /// Selected range of the given element, if any.
+    pub fn range_of(&self, node_id: &NodeId) -> Option<(usize, usize)> {
+        self.ranges
+            .get(node_id)
+            .copied()
+            .filter(|(from, to)| from != to)
+    }
+
+    /// Selected text of all the selected elements, one per line.
+    pub fn text(&self, tree: &Tree) -> Option<String> {
+        if self.is_empty() {
+            return None;
+        }
+        let text = self
+            .nodes
+            .iter()
+            .filter_map(|node_id| {
+                let (from, to) = self.range_of(node_id)?;
+                let text = laid_out_text(tree, node_id)?;
+                Some(text[byte_index(&text, from)..byte_index(&text, to)].replace(SOFT_HYPHEN, ""))
+            })
+            .collect::<Vec<_>>();
+        Some(text.join("\n"))
+    }
+
+    /// Select the text between the given positions.
+    pub fn select(&mut self, tree: &Tree, anchor: TextPosition, focus: TextPosition) {
+        self.anchor = Some(anchor);
+        self.focus = Some(focus);
+        self.update_ranges(tree);
+    }
+
+    /// Select all the text of all the selectable elements.
+    pub fn select_all(&mut self, tree: &Tree) {
+        let mut first = None;
+        let mut last = None;
+        tree.traverse_depth(|node_id| {
+            if let Some(len) = selectable_len(tree, &node_id) {
+                first.get_or_insert(TextPosition { node_id, offset: 0 });
+                last = Some(TextPosition {
+                    node_id,
+                    offset: len,
+                });
+            }
+        });
+        if let (Some(first), Some(last)) = (first, last) {
+            self.select(tree, first, last);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        if self.anchor.is_some() {
+            *self = Self {
+                changed: true,
+                ..Self::default()
+            };
+        }
+    }
+
+    /// Forget the given element, clearing the selection if it started or ended there.
+    pub(crate) fn remove(&mut self, node_id: &NodeId) {
+        let is_end = |position: Option<TextPosition>| {
+            position.is_some_and(|position| position.node_id == *node_id)
+        };
+        if is_end(self.anchor) || is_end(self.focus) {
+            self.clear();
+        } else if self.ranges.remove(node_id).is_some() {
+            self.nodes.retain(|id| id != node_id);
+            self.changed = true;
+        }
+    }
+
+    pub(crate) fn has_changed(&self) -> bool {
+        self.changed
+    }
+
+    /// Whether the selection changed since the last call, so it can be rendered again.
+    pub fn take_changed(&mut self) -> bool {
+        std::mem::take(&mut self.changed)
+    }
+
+    fn update_ranges(&mut self, tree: &Tree) {
+        self.ranges.clear();
+        self.nodes.clear();
+        self.changed = true;
+
+        let (Some(anchor), Some(focus)) = (self.anchor, self.focus) else {
+            return;
+        };
+
+        if anchor.node_id == focus.node_id {
+            let range = (
+                anchor.offset.min(focus.offset),
+                anchor.offset.max(focus.offset),
+            );
+            self.ranges.insert(anchor.node_id, range);
+            self.nodes.push(anchor.node_id);
+            return;
+        }
+
+        // Select everything between both ends in the order of the tree
+        let mut started = false;
+        tree.traverse_depth_cancel(|node_id| {
+            let end = [anchor, focus]
+                .into_iter()
+                .find(|position| position.node_id == node_id);
+            let range = match (started, end) {
+                (false, Some(start)) => {
+                    started = true;
+                    selectable_len(tree, &node_id).map(|len| (start.offset, len))
+                }
+                (true, Some(end)) => Some((0, end.offset)),
+                (true, None) => selectable_len(tree, &node_id).map(|len| (0, len)),
+                (false, None) => None,
+            };
+            if let Some(range) = range {
+                self.ranges.insert(node_id, range);
+                self.nodes.push(node_id);
+            }
+            started && end.is_some() && self.nodes.len() > 1
+        });
+    }
+
+    /// Update the selection with the given pointer or keyboard event.
+    pub(crate) fn process_event(
+        &mut self,
+        adapter: &EventsMeasurerAdapter,
+        event: &PlatformEvent,
+        focus_id: Option<NodeId>,
+    ) {
+        let tree = &*adapter.tree;
+        match event {
+            PlatformEvent::Mouse {
+                name: MouseEventName::MouseDown,
+                cursor,
+                button: Some(MouseButton::Left),
+                click_count,
+            } => {
+                let position = node_at(adapter, *cursor)
+                    .and_then(|node_id| position_at(adapter, node_id, *cursor));
+                match position {
+                    // Select the whole word with a double click
+                    Some(position) if *click_count == 2 => {
+                        let Some(paragraph) = tree
+                            .elements
+                            .get(&position.node_id)
+                            .zip(tree.layout.get(&position.node_id))
+                            .and_then(|(element, layout_node)| {
+                                element.selectable_paragraph(layout_node)
+                            })
+                        else {
+                            return;
+                        };
+                        let word = paragraph.get_word_boundary(position.offset as u32);
+                        self.dragging = false;
+                        self.select(
+                            tree,
+                            TextPosition {
+                                offset: word.start,
+                                ..position
+                            },
+                            TextPosition {
+                                offset: word.end,
+                                ..position
+                            },
+                        );
+                    }
+                    Some(position) => {
+                        self.dragging = true;
+                        self.select(tree, position, position);
+                    }
+                    None => self.clear(),
+                }
+            }
+            PlatformEvent::Mouse {
+                name: MouseEventName::MouseMove,
+                cursor,
+                ..
+            } if self.dragging => {
+                let (Some(anchor), Some(focus)) = (self.anchor, self.focus) else {
+                    return;
+                };
+                // Keep selecting in the last element when the pointer is not over a selectable one
+                let new_focus = node_at(adapter, *cursor)
+                    .and_then(|node_id| position_at(adapter, node_id, *cursor))
+                    .or_else(|| position_at(adapter, focus.node_id, *cursor));
+                if let Some(new_focus) = new_focus
+                    && new_focus != focus
+                {
+                    self.select(tree, anchor, new_focus);
+                }
+            }
+            PlatformEvent::Mouse {
+                name: MouseEventName::MouseUp,
+                ..
+            } => {
+                self.dragging = false;
+            }
+            PlatformEvent::Keyboard {
+                name: KeyboardEventName::KeyDown,
+                key,
+                modifiers,
+                ..
+            } => {
+                let meta_or_ctrl = if cfg!(target_os = "macos") {
+                    modifiers.meta()
+                } else {
+                    modifiers.ctrl()
+                };
+                // Only when no other element is focused, as it might have its own selection
+                let unfocused = focus_id.is_none_or(|id| id == NodeId::ROOT);
+                match key {
+                    Key::Character(character) if character == "a" && meta_or_ctrl && unfocused => {
+                        self.select_all(tree);
+                    }
+                    Key::Named(named_key @ (NamedKey::ArrowLeft | NamedKey::ArrowRight))
+                        if modifiers.shift() && unfocused =>
+                    {
+                        let (Some(anchor), Some(focus)) = (self.anchor, self.focus) else {
+                            return;
+                        };
+                        let offset = if *named_key == NamedKey::ArrowLeft {
+                            focus.offset.saturating_sub(1)
+                        } else {
+                            (focus.offset + 1)
+                                .min(selectable_len(tree, &focus.node_id).unwrap_or(0))
+                        };
+                        self.select(tree, anchor, TextPosition { offset, ..focus });
+                    }
+                    Key::Named(NamedKey::Escape) => self.clear(),
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Topmost selectable element under the cursor.
+fn node_at(adapter: &EventsMeasurerAdapter, cursor: CursorPoint) -> Option<NodeId> {
+    let node_id = adapter.node_at(cursor)?;
+    selectable_len(&*adapter.tree, &node_id).map(|_| node_id)
+}
+
+/// Position of the cursor in the text of the given element.
+fn position_at(
+    adapter: &EventsMeasurerAdapter,
+    node_id: NodeId,
+    cursor: CursorPoint,
+) -> Option<TextPosition> {
+    let element = adapter.tree.elements.get(&node_id)?;
+    let layout_node = adapter.tree.layout.get(&node_id)?;
+    let paragraph = element.selectable_paragraph(layout_node)?;
+    let cursor = adapter.local_cursor(&node_id, cursor);
+    let origin = layout_node.visible_area().origin;
+    let position = paragraph
+        .get_glyph_position_at_coordinate((cursor.x as f32 - origin.x, cursor.y as f32 - origin.y));
+    Some(TextPosition {
+        node_id,
+        offset: position.position.max(0) as usize,
+    })
+}
+
+/// Text of the given element as it was laid out, including the soft hyphens of its hyphenation.
+fn laid_out_text(tree: &Tree, node_id: &NodeId) -> Option<String> {
+    let text = tree.elements.get(node_id)?.selectable_text()?;
+    let hyphenation = tree
+        .text_style_state
+        .get(node_id)
+        .map(|text_style_state| text_style_state.hyphenation)
+        .unwrap_or_default();
+    Some(hyphenate(&text, hyphenation).into_owned())
+}
+
+/// Length of the laid out text of the given element in UTF-16, if it is selectable.
+fn selectable_len(tree: &Tree, node_id: &NodeId) -> Option<usize> {
+    laid_out_text(tree, node_id).map(|text| text.encode_utf16().count())
+}
+
+/// Byte index of the given UTF-16 offset in the text.
+fn byte_index(text: &str, offset: usize) -> usize {
+    let mut utf16_offset = 0;
+    for (index, character) in text.char_indices() {
+        if utf16_offset >= offset {
+            return index;
+        }
+        utf16_offset += character.len_utf16();
+    }
+    text.len()
+}
@@ -0,0 +1,253 @@
+use std::{
+    any::Any,
+    rc::Rc,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use rustc_hash::FxHashMap;
+use torin::prelude::{
+    Length,
+    Size,
+};
+
+use crate::{
+    animation_clock::AnimationClock,
+    element::ElementExt,
+    elements::rect::RectElement,
+    node_id::NodeId,
+    prelude::{
+        Color,
+        CornerRadius,
+        Fill,
+    },
+    runner::MutationModified,
+    style::transition::TransitionProperty,
+};
+
+struct ActiveTransition {
+    from: RectElement,
+    to: RectElement,
+    /// Time run so far, at the speed of the [AnimationClock].
+    elapsed: Duration,
+    last_tick: Instant,
+}
+
+impl ActiveTransition {
+    /// Move the transition forward to the current time of the clock.
+    fn tick(&mut self, clock: &AnimationClock) {
+        let now = clock.now();
+        let elapsed = clock.correct_elapsed_duration(now.saturating_duration_since(self.last_tick));
+        self.elapsed = self.elapsed.saturating_add(elapsed);
+        self.last_tick = now;
+    }
+
+    /// Create the element to display now, and whether all the transitions have finished.
+    fn interpolate(&self) -> (RectElement, bool) {
+        let elapsed = self.elapsed;
+
+        if self
+            .to
+            .transitions
+            .iter()
+            .all(|transition| elapsed >= transition.duration)
+        {
+            return (self.to.clone(), true);
+        }
+
+        let mut element = self.to.clone();
+
+        for transition in self.to.transitions.iter() {
+            let progress = transition.progress(elapsed);
+            let property = transition.property;
+
+            if property.contains(TransitionProperty::Background)
+                && let (Fill::Color(from), Fill::Color(to)) =
+                    (&self.from.style.background, &self.to.style.background)
+            {
+                element.style.background = Fill::Color(lerp_color(*from, *to, progress));
+            }
+
+            if property.contains(TransitionProperty::CornerRadius) {
+                let from = self.from.style.corner_radius;
+                let to = self.to.style.corner_radius;
+                element.style.corner_radius = CornerRadius {
+                    top_left: lerp(from.top_left, to.top_left, progress),
+                    top_right: lerp(from.top_right, to.top_right, progress),
+                    bottom_right: lerp(from.bottom_right, to.bottom_right, progress),
+                    bottom_left: lerp(from.bottom_left, to.bottom_left, progress),
+                    smoothing: to.smoothing,
+                };
+            }
+
+            if property.contains(TransitionProperty::Opacity) {
+                let from = opacity(&self.from);
+                let to = opacity(&self.to);
+                if from != to {
+                    element.effect.get_or_insert_with(Default::default).opacity =
+                        Some(lerp(from, to, progress));
+                }
+            }
+
+            if property.contains(TransitionProperty::Width)
+                && let Some(width) =
+                    lerp_size(&self.from.layout.width, &self.to.layout.width, progress)
+            {
+                element.layout.layout.width = width;
+            }
+
+            if property.contains(TransitionProperty::Height)
+                && let Some(height) =
+                    lerp_size(&self.from.layout.height, &self.to.layout.height, progress)
+            {
+                element.layout.layout.height = height;
+            }
+        }
+
+        (element, false)
+    }
+}
+
+/// Transitions of elements that are currently running.
+///
+/// See [Rect::transition](crate::elements::rect::Rect::transition).
+#[derive(Default)]
+pub struct ElementTransitions {
+    active: FxHashMap<NodeId, ActiveTransition>,
+}
+
+impl ElementTransitions {
+    pub fn is_running(&self) -> bool {
+        !self.active.is_empty()
+    }
+
+    /// Start or update the transition of a modified node.
+    /// Returns the element that should be displayed for now.
+    pub(crate) fn modified(
+        &mut self,
+        node_id: NodeId,
+        old_element: &Rc<dyn ElementExt>,
+        element: Rc<dyn ElementExt>,
+        clock: &AnimationClock,
+    ) -> Rc<dyn ElementExt> {
+        let (Some(current), Some(to)) = (
+            (old_element.as_ref() as &dyn Any).downcast_ref::<RectElement>(),
+            (element.as_ref() as &dyn Any).downcast_ref::<RectElement>(),
+        ) else {
+            self.active.remove(&node_id);
+            return element;
+        };
+
+        if to.transitions.is_empty() {
+            self.active.remove(&node_id);
+            return element;
+        }
+
+        // Keep running towards the same values
+        if let Some(active) = self.active.get_mut(&node_id)
+            && !transitioned_changed(&active.to, to)
+        {
+            active.to = to.clone();
+            active.tick(clock);
+            return Rc::new(active.interpolate().0);
+        }
+
+        if !transitioned_changed(current, to) {
+            self.active.remove(&node_id);
+            return element;
+        }
+
+        // Start from whatever is being displayed right now
+        let active = ActiveTransition {
+            from: current.clone(),
+            to: to.clone(),
+            elapsed: Duration::ZERO,
+            last_tick: clock.now(),
+        };
+        let (interpolated, _) = active.interpolate();
+        self.active.insert(node_id, active);
+        Rc::new(interpolated)
+    }
+
+    /// Move the running transitions forward.
+    pub(crate) fn advance(
+        &mut self,
+        elements: &FxHashMap<NodeId, Rc<dyn ElementExt>>,
+        clock: &AnimationClock,
+    ) -> Vec<MutationModified> {
+        let mut modified = Vec::new();
+
+        self.active.retain(|node_id, active| {
+            let Some(current) = elements.get(node_id) else {
+                return false;
+            };
+
+            active.tick(clock);
+            let (element, finished) = active.interpolate();
+            let element: Rc<dyn ElementExt> = Rc::new(element);
+            let flags = element.diff(current);
+            if !flags.is_empty() {
+                modified.push(MutationModified {
+                    node_id: *node_id,
+                    element,
+                    flags,
+                });
+            }
+
+            !finished
+        });
+
+        modified
+    }
+}
+
+fn transitioned_changed(a: &RectElement, b: &RectElement) -> bool {
+    b.transitions.iter().any(|transition| {
+        let property = transition.property;
+        (property.contains(TransitionProperty::Background)
+            && a.style.background != b.style.background)
+            || (property.contains(TransitionProperty::CornerRadius)
+                && a.style.corner_radius != b.style.corner_radius)
+            || (property.contains(TransitionProperty::Opacity) && opacity(a) != opacity(b))
+            || (property.contains(TransitionProperty::Width) && a.layout.width != b.layout.width)
+            || (property.contains(TransitionProperty::Height) && a.layout.height != b.layout.height)
+    })
+}
+
+fn opacity(element: &RectElement) -> f32 {
+    element
+        .effect
+        .as_ref()
+        .and_then(|effect| effect.opacity)
+        .unwrap_or(1.)
+}
+
+fn lerp(from: f32, to: f32, progress: f32) -> f32 {
+    from + (to - from) * progress
+}
+
+fn lerp_color(from: Color, to: Color, progress: f32) -> Color {
+    let channel = |from: u8, to: u8| lerp(from as f32, to as f32, progress).round() as u8;
+    Color::from_argb(
+        channel(from.a(), to.a()),
+        channel(from.r(), to.r()),
+        channel(from.g(), to.g()),
+        channel(from.b(), to.b()),
+    )
+}
+
+fn lerp_size(from: &Size, to: &Size, progress: f32) -> Option<Size> {
+    match (from, to) {
+        (Size::Pixels(from), Size::Pixels(to)) => Some(Size::Pixels(Length::new(lerp(
+            from.get(),
+            to.get(),
+            progress,
+        )))),
+        (Size::Percentage(from), Size::Percentage(to)) => Some(Size::Percentage(Length::new(
+            lerp(from.get(), to.get(), progress),
+        ))),
+        _ => None,
+    }
+}
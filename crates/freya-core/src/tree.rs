@@ -6,6 +6,7 @@ use std::{
         hash_map::Entry,
     },
     fmt::Debug,
+    ops::Div,
     rc::Rc,
 };
 
@@ -34,6 +35,9 @@ use torin::{
 
 use crate::{
     accessibility::groups::AccessibilityGroups,
+    animation_clock::AnimationClock,
+    cache_layers::CacheLayers,
+    cursor::HoveredCursor,
     data::{
         AccessibilityState,
         EffectState,
@@ -51,9 +55,11 @@ use crate::{
             SizedEventData,
         },
         emittable::EmmitableEvent,
+        measurer::EventsMeasurerAdapter,
         name::EventName,
     },
     extended_hashmap::ExtendedHashMap,
+    hooks::use_visibility::VisibilityTracker,
     integration::{
         AccessibilityDirtyNodes,
         AccessibilityGenerator,
@@ -61,6 +67,7 @@ use crate::{
     },
     layers::Layers,
     node_id::NodeId,
+    repaints::RepaintTracker,
     runner::{
         MutationAdd,
         MutationModified,
@@ -69,6 +76,8 @@ use crate::{
         Mutations,
     },
     text_cache::TextCache,
+    text_selection::TextSelection,
+    transitions::ElementTransitions,
     tree_layout_adapter::TreeAdapterFreya,
 };
 
@@ -93,6 +102,14 @@ pub struct Tree {
     pub layout: Torin<NodeId>,
     pub layers: Layers,
     pub text_cache: TextCache,
+    pub transitions: ElementTransitions,
+    /// Clock of the transitions, shared with the animations of the app.
+    pub animation_clock: AnimationClock,
+    pub cache_layers: CacheLayers,
+    pub text_selection: TextSelection,
+    pub repaints: RepaintTracker,
+    pub hovered_cursor: HoveredCursor,
+    pub visibility_tracker: VisibilityTracker,
 
     // Accessibility
     pub accessibility_groups: AccessibilityGroups,
@@ -110,11 +127,12 @@ impl Debug for Tree {
             .field("listeners", &self.listeners.capacity())
             .field("layer_state", &self.layer_state.capacity())
             .field("layout_size", &self.layout.size())
-            .field("layers", &self.layers.capacity())
+            .field("layers", &self.layers.len())
             .field("effect_state", &self.effect_state.capacity())
             .field("accessibility_state", &self.accessibility_state.capacity())
             .field("text_style_state", &self.text_style_state.capacity())
             .field("text_cache", &self.text_cache)
+            .field("cache_layers", &self.cache_layers.len())
             .finish()
     }
 }
@@ -124,8 +142,13 @@ impl Tree {
         self.elements.len()
     }
 
-    pub fn traverse_depth(&self, mut then: impl FnMut(NodeId)) {
-        let mut buffer = vec![NodeId::ROOT];
+    pub fn traverse_depth(&self, then: impl FnMut(NodeId)) {
+        self.traverse_depth_from(NodeId::ROOT, then);
+    }
+
+    /// Like [Tree::traverse_depth] but only for the subtree of `root`, `root` included.
+    pub fn traverse_depth_from(&self, root: NodeId, mut then: impl FnMut(NodeId)) {
+        let mut buffer = vec![root];
         while let Some(node_id) = buffer.pop() {
             if let Some(children) = self.children.get(&node_id) {
                 buffer.extend(children.iter().rev());
@@ -147,7 +170,36 @@ impl Tree {
     }
 
     #[cfg_attr(feature = "hotpath", hotpath::measure)]
-    pub fn apply_mutations(&mut self, mutations: Mutations) -> MutationsApplyResult {
+    pub fn apply_mutations(&mut self, mut mutations: Mutations) -> MutationsApplyResult {
+        for modified in mutations.modified.iter_mut() {
+            if let Some(old_element) = self.elements.get(&modified.node_id) {
+                modified.element = self.transitions.modified(
+                    modified.node_id,
+                    old_element,
+                    modified.element.clone(),
+                    &self.animation_clock,
+                );
+            }
+        }
+
+        self.apply_mutations_without_transitions(mutations)
+    }
+
+    /// Advance the running [Transition](crate::style::transition::Transition)s of elements.
+    pub fn advance_transitions(&mut self) -> MutationsApplyResult {
+        let modified = self
+            .transitions
+            .advance(&self.elements, &self.animation_clock);
+        self.apply_mutations_without_transitions(Mutations {
+            modified,
+            ..Default::default()
+        })
+    }
+
+    fn apply_mutations_without_transitions(
+        &mut self,
+        mutations: Mutations,
+    ) -> MutationsApplyResult {
         let mut needs_render = !mutations.removed.is_empty();
         let mut needs_accessibility = !mutations.removed.is_empty();
         let mut dirty = Vec::<(NodeId, DiffModifies)>::default();
@@ -169,6 +221,8 @@ impl Tree {
                     continue;
                 };
                 self.layout.invalidate(parent_id);
+                self.invalidate_cache_layers(parent_id);
+                self.repaints.track(parent_id);
                 needs_render = true;
 
                 while let Some(remove) = buff.pop() {
@@ -213,7 +267,7 @@ impl Tree {
 
                     // Remove from the layers
                     let layer_state = self.layer_state.remove(&node_id).unwrap();
-                    layer_state.remove(node_id, &mut self.layers);
+                    layer_state.remove(&mut self.layers);
 
                     // Remove from the accessibility
                     let accessibility_state = self.accessibility_state.remove(&node_id).unwrap();
@@ -229,6 +283,8 @@ impl Tree {
                     self.effect_state.remove(&node_id);
                     self.text_style_state.remove(&node_id);
                     self.text_cache.remove(&node_id);
+                    self.cache_layers.invalidate(&node_id);
+                    self.text_selection.remove(&node_id);
                 }
             }
 
@@ -322,6 +378,21 @@ impl Tree {
                     }
                 }
 
+                // Transforms move the accessibility bounds
+                if flags.contains(DiffModifies::EFFECT)
+                    && self.accessibility_state.contains_key(&node_id)
+                {
+                    let transform_of = |element: &Rc<dyn ElementExt>| {
+                        element
+                            .effect()
+                            .map(|effect| (effect.rotation, effect.transform.clone()))
+                    };
+                    if transform_of(&old_element) != transform_of(&element) {
+                        self.accessibility_diff.add_or_update(node_id);
+                        needs_accessibility = true;
+                    }
+                }
+
                 self.elements.insert(node_id, element);
             }
         });
@@ -335,6 +406,8 @@ impl Tree {
 
         hotpath::measure_block!("dirty run", {
             for (node_id, flags) in dirty {
+                self.invalidate_cache_layers(node_id);
+
                 let element = self.elements.get(&node_id).unwrap();
                 let height_b = self.heights.get(&node_id).unwrap();
 
@@ -352,6 +425,18 @@ impl Tree {
                     self.layout.invalidate(node_id);
                 }
 
+                if flags.intersects(
+                    DiffModifies::STYLE
+                        | DiffModifies::LAYER
+                        | DiffModifies::EFFECT
+                        | DiffModifies::TEXT_STYLE
+                        | DiffModifies::LAYOUT
+                        | DiffModifies::INNER_LAYOUT
+                        | DiffModifies::REORDER_LAYOUT,
+                ) {
+                    self.repaints.track(node_id);
+                }
+
                 if !needs_render
                     && (flags.intersects(
                         DiffModifies::STYLE
@@ -448,6 +533,7 @@ impl Tree {
                 buffer.push_front(&layer_root);
 
                 while let Some(node_id) = buffer.pop_front() {
+                    self.cache_layers.invalidate(node_id);
                     let element = self.elements.get(node_id).unwrap();
                     if let Some(parent_node_id) = self.parents.get(node_id) {
                         let entries = self
@@ -484,6 +570,7 @@ impl Tree {
                 buffer.push_front(&effect_root);
 
                 while let Some(node_id) = buffer.pop_front() {
+                    self.cache_layers.invalidate(node_id);
                     let element = self.elements.get(node_id).unwrap();
                     if let Some(parent_node_id) = self.parents.get(node_id) {
                         let entries = self.effect_state.get_disjoint_two_entries(
@@ -520,6 +607,7 @@ impl Tree {
                 buffer.push_front(&text_style_root);
 
                 while let Some(node_id) = buffer.pop_front() {
+                    self.cache_layers.invalidate(node_id);
                     let element = self.elements.get(node_id).unwrap();
                     if let Some(parent_node_id) = self.parents.get(node_id) {
                         let entries = self
@@ -556,6 +644,18 @@ impl Tree {
         }
     }
 
+    /// Discard the recorded painting of the cache layers that contain the given node.
+    pub(crate) fn invalidate_cache_layers(&mut self, node_id: NodeId) {
+        if self.cache_layers.is_empty() {
+            return;
+        }
+        let mut current = Some(node_id);
+        while let Some(node_id) = current {
+            self.cache_layers.invalidate(&node_id);
+            current = self.parents.get(&node_id).copied();
+        }
+    }
+
     /// Walk to the ancestor of `base` with the same height of `target`
     fn balance_heights(&self, base: &NodeId, target: &NodeId) -> Option<NodeId> {
         let target_height = self.heights.get(target)?;
@@ -587,6 +687,7 @@ impl Tree {
             parents: &self.parents,
             children: &self.children,
             heights: &self.heights,
+            text_style_state: &self.text_style_state,
             scale_factor,
         };
 
@@ -604,15 +705,52 @@ impl Tree {
         };
 
         self.layout.find_best_root(&mut tree_adapter);
+        if self.repaints.is_enabled() {
+            for node_id in self.layout.get_dirty_nodes().keys() {
+                self.repaints.track(*node_id);
+            }
+        }
         self.layout.measure(
             NodeId::ROOT,
             Area::from_size(size),
             &mut Some(layout_adapter),
             &mut tree_adapter,
         );
+        // Clip once everything is measured, the viewports might have been measured later
+        for event in events.iter_mut() {
+            if let EventType::Sized(data) = &mut event.data {
+                data.clipped_area = self.clipped_area(event.node_id).div(scale_factor as f32);
+            }
+        }
         events_sender
             .unbounded_send(EventsChunk::Batch(events))
             .unwrap();
+        self.visibility_tracker.update(self);
+        EventsMeasurerAdapter {
+            tree: self,
+            scale_factor,
+        }
+        .update_hovered_cursor();
+    }
+
+    /// Visible area of a node clipped by the window and the viewports of its ancestors.
+    pub fn clipped_area(&self, node_id: NodeId) -> Area {
+        let Some(layout_node) = self.layout.get(&node_id) else {
+            return Area::default();
+        };
+        let clips = self
+            .effect_state
+            .get(&node_id)
+            .map(|effect_state| &effect_state.clips[..])
+            .unwrap_or_default();
+        clips
+            .iter()
+            .chain([&NodeId::ROOT])
+            .filter_map(|viewport_id| self.layout.get(viewport_id))
+            .try_fold(layout_node.visible_area(), |area, viewport| {
+                area.intersection(&viewport.visible_area())
+            })
+            .unwrap_or_default()
     }
 
     pub fn print_ascii(&self, node_id: NodeId, prefix: String, last: bool) {
@@ -730,6 +868,10 @@ impl LayoutMeasurer<NodeId> for LayoutMeasurerAdapter<'_> {
         }
     }
 
+    fn baseline(&mut self, node_id: NodeId, data: &Rc<dyn Any>) -> Option<f32> {
+        self.elements.get(&node_id)?.baseline(data)
+    }
+
     fn notify_layout_references(
         &mut self,
         node_id: NodeId,
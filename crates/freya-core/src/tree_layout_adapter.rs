@@ -11,8 +11,10 @@ use torin::{
 };
 
 use crate::{
+    data::TextStyleState,
     element::ElementExt,
     node_id::NodeId,
+    prelude::TextDirection,
 };
 
 pub struct TreeAdapterFreya<'a> {
@@ -20,6 +22,7 @@ pub struct TreeAdapterFreya<'a> {
     pub parents: &'a FxHashMap<NodeId, NodeId>,
     pub children: &'a FxHashMap<NodeId, Vec<NodeId>>,
     pub heights: &'a FxHashMap<NodeId, u16>,
+    pub text_style_state: &'a FxHashMap<NodeId, TextStyleState>,
     pub scale_factor: f64,
 }
 
@@ -39,6 +42,11 @@ impl TreeAdapter<NodeId> for TreeAdapterFreya<'_> {
         self.elements.get(node_id).map(|node| {
             let mut layout_node = node.layout().layout.clone();
             layout_node.scale(self.scale_factor as f32);
+            // Elements follow the direction of the text of their language
+            layout_node.mirrored = self
+                .text_style_state
+                .get(node_id)
+                .is_some_and(|text_style| text_style.text_direction == TextDirection::Rtl);
             layout_node
         })
     }
@@ -51,7 +59,7 @@ impl TreeAdapter<NodeId> for TreeAdapterFreya<'_> {
         self.parents.get(node_id).cloned()
     }
 
-    fn children_of(&mut self, node_id: &NodeId) -> Vec<NodeId> {
+    fn children_of(&self, node_id: &NodeId) -> Vec<NodeId> {
         self.children.get(node_id).cloned().unwrap_or_default()
     }
 }
@@ -5,7 +5,12 @@ use std::{
 
 use cursor_icon::CursorIcon;
 
-use crate::prelude::AccessibilityFocusStrategy;
+use crate::prelude::{
+    AccessibilityFocusStrategy,
+    CustomCursor,
+    MouseCursor,
+    RenderMode,
+};
 
 #[derive(Debug)]
 pub enum UserEvent {
@@ -17,9 +22,27 @@ pub enum UserEvent {
     /// Set a new cursor icon.
     SetCursorIcon(CursorIcon),
 
+    /// Set an image as cursor.
+    SetCustomCursor(CustomCursor),
+
+    /// Switch between rendering on demand and every frame.
+    SetRenderMode(RenderMode),
+
+    /// Tint the elements repainted every frame, see [crate::repaints::RepaintTracker].
+    ShowRepaints(bool),
+
     Erased(SingleThreadErasedEvent),
 }
 
+impl From<MouseCursor> for UserEvent {
+    fn from(mouse_cursor: MouseCursor) -> Self {
+        match mouse_cursor {
+            MouseCursor::Icon(cursor_icon) => Self::SetCursorIcon(cursor_icon),
+            MouseCursor::Custom(custom_cursor) => Self::SetCustomCursor(custom_cursor),
+        }
+    }
+}
+
 pub struct SingleThreadErasedEvent(pub Box<dyn Any>);
 
 impl Debug for SingleThreadErasedEvent {
@@ -1343,6 +1343,179 @@ fn effect_cascade_in_new_nodes_with_parent_effects() {
     );
 }
 
+#[test]
+fn filters_cascade_to_children() {
+    fn app() -> Element {
+        rect()
+            .grayscale(0.5)
+            .grayscale(1.)
+            .child(rect().brightness(0.5).child(rect()))
+            .into()
+    }
+
+    let mut runner = Runner::new(app);
+    let mut tree = Tree::default();
+
+    let mutations = runner.sync_and_update();
+    tree.apply_mutations(mutations);
+    tree.verify_tree_integrity();
+
+    assert_eq!(
+        tree.effect_state
+            .get(&2u64.into())
+            .unwrap()
+            .filters
+            .as_ref(),
+        &[LayerFilter::Grayscale(1.)]
+    );
+    assert_eq!(
+        tree.effect_state
+            .get(&4u64.into())
+            .unwrap()
+            .filters
+            .as_ref(),
+        &[LayerFilter::Grayscale(1.), LayerFilter::Brightness(0.5)]
+    );
+}
+
+#[test]
+fn cache_layer_cascade_to_children() {
+    fn app() -> Element {
+        rect()
+            .child(
+                rect()
+                    .cache_layer(true)
+                    .child(rect().cache_layer(true).child(label().text("Hello"))),
+            )
+            .into()
+    }
+
+    let mut runner = Runner::new(app);
+    let mut tree = Tree::default();
+
+    let mutations = runner.sync_and_update();
+    tree.apply_mutations(mutations);
+    tree.verify_tree_integrity();
+
+    for node_id in [3u64, 4, 5] {
+        assert_eq!(
+            tree.effect_state.get(&node_id.into()).unwrap().cache_layer,
+            Some(3u64.into())
+        );
+    }
+    assert_eq!(
+        tree.effect_state
+            .get(&2u64.into())
+            .and_then(|effect_state| effect_state.cache_layer),
+        None
+    );
+}
+
+#[test]
+fn text_elements_blend_mode() {
+    fn app() -> Element {
+        rect()
+            .child(label().text("Hello").blend_mode(BlendMode::Multiply))
+            .child(paragraph().span("World"))
+            .into()
+    }
+
+    let mut runner = Runner::new(app);
+    let mut tree = Tree::default();
+
+    let mutations = runner.sync_and_update();
+    tree.apply_mutations(mutations);
+    tree.verify_tree_integrity();
+
+    assert_eq!(
+        tree.effect_state.get(&3u64.into()).unwrap().blend_mode,
+        Some(BlendMode::Multiply)
+    );
+    assert_eq!(
+        tree.effect_state
+            .get(&4u64.into())
+            .and_then(|effect_state| effect_state.blend_mode),
+        None
+    );
+}
+
+#[test]
+fn text_shadows_and_stroke() {
+    fn app() -> Element {
+        rect()
+            .text_stroke((2., Color::BLACK))
+            .child(
+                label()
+                    .text("Hello")
+                    .text_shadows([
+                        TextShadow::new(Color::BLACK, (1., 1.), 2.),
+                        TextShadow::new(Color::RED, (-1., -1.), 0.),
+                    ])
+                    .text_stroke((2., Color::WHITE)),
+            )
+            .child(label().text("World"))
+            .into()
+    }
+
+    let mut runner = Runner::new(app);
+    let mut tree = Tree::default();
+
+    let mutations = runner.sync_and_update();
+    tree.apply_mutations(mutations);
+    tree.verify_tree_integrity();
+
+    let text_style_state = tree.text_style_state.get(&3u64.into()).unwrap();
+    assert_eq!(text_style_state.text_shadows.len(), 2);
+    assert_eq!(
+        text_style_state.text_stroke,
+        Some(TextStroke::new(2., Color::WHITE))
+    );
+
+    // Shadows and stroke are not inherited
+    let text_style_state = tree.text_style_state.get(&4u64.into()).unwrap();
+    assert!(text_style_state.text_shadows.is_empty());
+    assert_eq!(text_style_state.text_stroke, None);
+}
+
+#[test]
+fn font_features_and_spacing() {
+    fn app() -> Element {
+        rect()
+            .font_features("tnum, -liga")
+            .letter_spacing(2.)
+            .child(
+                label()
+                    .text("Hello")
+                    .font_features("ss01, nope")
+                    .word_spacing(4.),
+            )
+            .into()
+    }
+
+    let mut runner = Runner::new(app);
+    let mut tree = Tree::default();
+
+    let mutations = runner.sync_and_update();
+    tree.apply_mutations(mutations);
+    tree.verify_tree_integrity();
+
+    // Spacing and features are inherited, invalid features are ignored
+    let text_style_state = tree.text_style_state.get(&3u64.into()).unwrap();
+    assert_eq!(
+        text_style_state
+            .font_features
+            .iter()
+            .map(|feature| feature.to_string())
+            .collect::<Vec<_>>(),
+        vec!["tnum", "-liga", "ss01"]
+    );
+    assert_eq!(text_style_state.letter_spacing, TextSpacing(2.));
+    assert_eq!(text_style_state.word_spacing, TextSpacing(4.));
+
+    assert_eq!(FontFeature::parse("salt=2"), FontFeature::new("salt", 2));
+    assert_eq!(FontFeature::parse("toolong"), None);
+}
+
 #[test]
 fn modified_with_removed_sibling() {
     fn app() -> Element {
@@ -1,11 +1,22 @@
+use std::{
+    path::PathBuf,
+    time::Duration,
+};
+
 use freya::helpers::*;
 use freya_core::{
     integration::*,
     prelude::*,
 };
-use freya_testing::TestingRunner;
+use freya_testing::{
+    TestingRunner,
+    launch_test,
+};
 use rustc_hash::FxHashMap;
-use torin::size::Size;
+use torin::{
+    position::Position,
+    size::Size,
+};
 
 struct RawIdMap(FxHashMap<u64, Vec<u64>>);
 
@@ -213,6 +224,7 @@ fn pointer_events() {
         name: MouseEventName::MouseDown,
         cursor: (15., 15.).into(),
         button: None,
+        click_count: 1,
     });
     test.sync_and_update();
 
@@ -233,6 +245,7 @@ fn pointer_events() {
         name: MouseEventName::MouseUp,
         cursor: (15., 15.).into(),
         button: None,
+        click_count: 1,
     });
     test.sync_and_update();
 
@@ -362,3 +375,793 @@ fn large_coordinate_hover_does_not_trigger_other_elements() {
     test.sync_and_update();
     assert_eq!(*counters.0.peek(), (11, 11));
 }
+
+#[test]
+fn gesture_events() {
+    fn app() -> Element {
+        let mut state = use_consume::<State<f64>>();
+        rect()
+            .expanded()
+            .background((255, 255, 255))
+            .on_pinch(move |e: Event<GestureEventData>| {
+                if let GestureDelta::Pinch(delta) = e.delta {
+                    *state.write() += delta;
+                }
+            })
+            .on_rotate(move |e: Event<GestureEventData>| {
+                if let GestureDelta::Rotate(delta) = e.delta {
+                    *state.write() += delta;
+                }
+            })
+            .into()
+    }
+
+    let (mut test, state) = TestingRunner::new(
+        app,
+        (500., 500.).into(),
+        |runner| runner.provide_root_context(|| State::create(0.)),
+        1.,
+    );
+    test.sync_and_update();
+
+    test.send_event(PlatformEvent::Gesture {
+        name: GestureEventName::Pinch,
+        cursor: (15., 15.).into(),
+        delta: GestureDelta::Pinch(0.5),
+        phase: TouchPhase::Moved,
+    });
+    test.sync_and_update();
+
+    assert_eq!(*state.peek(), 0.5);
+
+    test.send_event(PlatformEvent::Gesture {
+        name: GestureEventName::Rotate,
+        cursor: (15., 15.).into(),
+        delta: GestureDelta::Rotate(10.),
+        phase: TouchPhase::Moved,
+    });
+    test.sync_and_update();
+
+    assert_eq!(*state.peek(), 10.5);
+}
+
+#[test]
+fn pen_events() {
+    fn app() -> Element {
+        let mut strokes = use_consume::<State<Vec<(EventName, f64, Option<bool>)>>>();
+        let mut on_pen = move |name: EventName, e: Event<PenEventData>| {
+            strokes
+                .write()
+                .push((name, e.state.pressure, e.state.eraser));
+        };
+        rect()
+            .expanded()
+            .background((255, 255, 255))
+            .on_pen_down(move |e| on_pen(EventName::PenDown, e))
+            .on_pen_move(move |e| on_pen(EventName::PenMove, e))
+            .on_pen_up(move |e| on_pen(EventName::PenUp, e))
+            .into()
+    }
+
+    let (mut test, strokes) = TestingRunner::new(
+        app,
+        (500., 500.).into(),
+        |runner| runner.provide_root_context(|| State::create(Vec::new())),
+        1.,
+    );
+    test.sync_and_update();
+
+    let pen = |name: PenEventName, pressure: f64, eraser: Option<bool>| PlatformEvent::Pen {
+        name,
+        location: (15., 15.).into(),
+        state: PenState {
+            pressure,
+            eraser,
+            ..PenState::default()
+        },
+    };
+
+    test.send_event(pen(PenEventName::PenDown, 0.2, None));
+    test.send_event(pen(PenEventName::PenMove, 0.8, Some(false)));
+    test.send_event(pen(PenEventName::PenUp, 0., Some(true)));
+    test.sync_and_update();
+
+    assert_eq!(
+        *strokes.peek(),
+        vec![
+            (EventName::PenDown, 0.2, None),
+            (EventName::PenMove, 0.8, Some(false)),
+            (EventName::PenUp, 0., Some(true)),
+        ]
+    );
+}
+
+#[test]
+fn use_gesture_recognizes_swipes_and_pinches() {
+    fn app() -> Element {
+        let mut gestures = use_consume::<State<Vec<Gesture>>>();
+        let mut gesture = use_gesture();
+        let on_touch = move |e: Event<TouchEventData>| {
+            if let Some(recognized) = gesture.handle(&e) {
+                gestures.write().push(recognized);
+            }
+        };
+        rect()
+            .expanded()
+            .background((255, 255, 255))
+            .on_touch_start(on_touch)
+            .on_touch_move(on_touch)
+            .on_touch_end(on_touch)
+            .into()
+    }
+
+    let (mut test, gestures) = TestingRunner::new(
+        app,
+        (500., 500.).into(),
+        |runner| runner.provide_root_context(|| State::create(Vec::new())),
+        1.,
+    );
+    test.sync_and_update();
+
+    // Swipe to the right
+    test.touch(0, (100., 100.), TouchPhase::Started);
+    test.touch(0, (180., 110.), TouchPhase::Moved);
+    test.touch(0, (200., 110.), TouchPhase::Ended);
+
+    assert_eq!(*gestures.peek(), vec![Gesture::SwipeRight]);
+
+    // Pinch out with two fingers
+    test.touch(0, (100., 100.), TouchPhase::Started);
+    test.touch(1, (200., 100.), TouchPhase::Started);
+    test.touch(1, (300., 100.), TouchPhase::Moved);
+    test.touch(1, (300., 100.), TouchPhase::Ended);
+    test.touch(0, (100., 100.), TouchPhase::Ended);
+
+    assert_eq!(
+        *gestures.peek(),
+        vec![Gesture::SwipeRight, Gesture::Pinch { scale: 2. }]
+    );
+}
+
+#[test]
+fn use_visibility_tracks_clipped_elements() {
+    type Context = (State<f32>, State<f32>, State<Vec<f32>>);
+
+    #[derive(PartialEq)]
+    struct Item(usize, f32);
+
+    impl Component for Item {
+        fn render(&self) -> impl IntoElement {
+            let (_, _, mut ratios) = use_consume::<Context>();
+            let a11y_id = use_hook(Focus::new_id);
+            let visibility = use_visibility(a11y_id);
+            let index = self.0;
+            use_side_effect(move || {
+                let ratio = visibility.ratio();
+                ratios.write()[index] = ratio;
+            });
+            rect()
+                .a11y_id(a11y_id)
+                .width(Size::fill())
+                .height(Size::px(self.1))
+        }
+    }
+
+    fn app() -> Element {
+        let (offset, height, _) = use_consume::<Context>();
+        rect()
+            .height(Size::px(height()))
+            .width(Size::fill())
+            .overflow(Overflow::Clip)
+            .offset_y(offset())
+            .child(Item(0, 50.))
+            .child(Item(1, 100.))
+            .child(Item(2, 100.))
+            .into()
+    }
+
+    let (mut test, (mut offset, mut height, ratios)) = TestingRunner::new(
+        app,
+        (500., 500.).into(),
+        |runner| {
+            runner.provide_root_context(|| {
+                (
+                    State::create(0.),
+                    State::create(100.),
+                    State::create(vec![-1.; 3]),
+                )
+            })
+        },
+        1.,
+    );
+    test.sync_and_update();
+    test.sync_and_update();
+    assert_eq!(*ratios.peek(), vec![1., 0.5, 0.]);
+
+    // Scrolling moves the elements in and out of the viewport
+    offset.set(-100.);
+    test.sync_and_update();
+    test.sync_and_update();
+    assert_eq!(*ratios.peek(), vec![0., 0.5, 0.5]);
+
+    // Elements that did not move are updated too when their viewport changes
+    height.set(250.);
+    test.sync_and_update();
+    test.sync_and_update();
+    assert_eq!(*ratios.peek(), vec![0., 0.5, 1.]);
+}
+
+#[test]
+fn pointer_capture() {
+    fn app() -> Element {
+        let mut state = use_consume::<State<(i32, i32)>>();
+        rect()
+            .expanded()
+            .child(
+                rect()
+                    .width(Size::px(100.))
+                    .height(Size::px(100.))
+                    .background((255, 255, 255))
+                    .on_pointer_down(move |e: Event<PointerEventData>| e.capture_pointer())
+                    .on_pointer_move(move |_| state.write().0 += 1)
+                    .on_pointer_press(move |_| state.write().1 += 1),
+            )
+            .into()
+    }
+
+    let (mut test, state) = TestingRunner::new(
+        app,
+        (500., 500.).into(),
+        |runner| runner.provide_root_context(|| State::create((0, 0))),
+        1.,
+    );
+    test.sync_and_update();
+
+    // Without capture the moves outside the node are not received
+    test.move_cursor((50., 50.));
+    test.sync_and_update();
+    test.move_cursor((300., 300.));
+    test.sync_and_update();
+    assert_eq!(*state.peek(), (1, 0));
+
+    // Capture the pointer
+    test.send_event(PlatformEvent::Mouse {
+        name: MouseEventName::MouseDown,
+        cursor: (50., 50.).into(),
+        button: Some(MouseButton::Left),
+        click_count: 1,
+    });
+    test.sync_and_update();
+
+    test.move_cursor((300., 300.));
+    test.sync_and_update();
+    assert_eq!(*state.peek(), (2, 0));
+
+    // Releasing outside of the node still reaches it and ends the capture
+    test.send_event(PlatformEvent::Mouse {
+        name: MouseEventName::MouseUp,
+        cursor: (300., 300.).into(),
+        button: Some(MouseButton::Left),
+        click_count: 1,
+    });
+    test.sync_and_update();
+    assert_eq!(*state.peek(), (2, 1));
+
+    test.move_cursor((350., 350.));
+    test.sync_and_update();
+    assert_eq!(*state.peek(), (2, 1));
+}
+
+#[test]
+fn double_click() {
+    fn app() -> Element {
+        let mut state = use_consume::<State<(u8, i32)>>();
+        rect()
+            .expanded()
+            .background((255, 255, 255))
+            .on_mouse_down(move |e: Event<MouseEventData>| state.write().0 = e.click_count)
+            .on_double_click(move |_| state.write().1 += 1)
+            .into()
+    }
+
+    let (mut test, state) = TestingRunner::new(
+        app,
+        (500., 500.).into(),
+        |runner| runner.provide_root_context(|| State::create((0, 0))),
+        1.,
+    );
+    test.sync_and_update();
+
+    test.click_cursor((15., 15.));
+    assert_eq!(*state.peek(), (1, 0));
+
+    test.click_cursor((15., 15.));
+    assert_eq!(*state.peek(), (2, 1));
+
+    test.click_cursor((15., 15.));
+    assert_eq!(*state.peek(), (3, 1));
+
+    // Clicking somewhere else starts counting again
+    test.click_cursor((200., 200.));
+    assert_eq!(*state.peek(), (1, 1));
+}
+
+#[test]
+fn drag_and_drop() {
+    #[derive(Clone, PartialEq, Debug)]
+    struct Card(u8);
+
+    #[derive(Default)]
+    struct Dragged {
+        over: usize,
+        dropped: Vec<Card>,
+    }
+
+    fn app() -> Element {
+        let mut dragged = use_consume::<State<Dragged>>();
+        rect()
+            .expanded()
+            .horizontal()
+            .child(
+                rect()
+                    .width(Size::px(250.))
+                    .height(Size::fill())
+                    .background((255, 255, 255))
+                    .on_drag_start(|e: Event<DragEventData>| {
+                        e.drag_data.set(Card(7));
+                        e.drag_data.set_preview(label().text("Dragging card"));
+                    }),
+            )
+            .child(
+                rect()
+                    .width(Size::px(250.))
+                    .height(Size::fill())
+                    .background((0, 0, 0))
+                    .on_drag_over(move |_: Event<DragEventData>| dragged.write().over += 1)
+                    .on_drop(move |e: Event<DragEventData>| {
+                        if let Some(card) = e.drag_data.get::<Card>() {
+                            dragged.write().dropped.push(card);
+                        }
+                    }),
+            )
+            .into()
+    }
+
+    let (mut test, dragged) = TestingRunner::new(
+        app,
+        (500., 500.).into(),
+        |runner| runner.provide_root_context(|| State::create(Dragged::default())),
+        1.,
+    );
+    test.sync_and_update();
+
+    // Moving within the threshold does not start a drag
+    test.press_cursor((50., 50.));
+    test.move_cursor((52., 50.));
+    test.sync_and_update();
+
+    // Start dragging
+    test.move_cursor((100., 50.));
+    test.sync_and_update();
+
+    test.move_cursor((300., 50.));
+    test.sync_and_update();
+    assert_eq!(dragged.peek().over, 1);
+    let preview = test.find(|_, element| {
+        Label::try_downcast(element).filter(|label| label.text.as_ref() == "Dragging card")
+    });
+    assert!(preview.is_some());
+
+    // The preview under the cursor does not block the drop
+    test.release_cursor((300., 50.));
+    assert_eq!(dragged.peek().dropped, vec![Card(7)]);
+    let preview = test.find(|_, element| {
+        Label::try_downcast(element).filter(|label| label.text.as_ref() == "Dragging card")
+    });
+    assert!(preview.is_none());
+
+    // Escape cancels the drag and removes its preview
+    test.press_cursor((50., 50.));
+    test.move_cursor((100., 50.));
+    test.sync_and_update();
+    test.move_cursor((300., 50.));
+    test.sync_and_update();
+    assert_eq!(dragged.peek().over, 2);
+    test.press_key(Key::Named(NamedKey::Escape));
+    let preview = test.find(|_, element| {
+        Label::try_downcast(element).filter(|label| label.text.as_ref() == "Dragging card")
+    });
+    assert!(preview.is_none());
+    test.release_cursor((300., 50.));
+    assert_eq!(dragged.peek().dropped.len(), 1);
+
+    // Dragging from an element that is not a drag source does nothing
+    test.press_cursor((300., 50.));
+    test.move_cursor((400., 50.));
+    test.sync_and_update();
+    test.move_cursor((450., 50.));
+    test.sync_and_update();
+    test.release_cursor((450., 50.));
+    assert_eq!(dragged.peek().over, 2);
+    assert_eq!(dragged.peek().dropped.len(), 1);
+}
+
+#[test]
+fn clip_path_hit_testing() {
+    fn app() -> Element {
+        let mut state = use_consume::<State<i32>>();
+        rect()
+            .width(Size::px(100.))
+            .height(Size::px(100.))
+            .background((0, 0, 0))
+            .clip_path(ClipPath::Circle)
+            .on_pointer_enter(move |_| *state.write() += 1)
+            .into()
+    }
+
+    let (mut test, state) = TestingRunner::new(
+        app,
+        (500., 500.).into(),
+        |runner| runner.provide_root_context(|| State::create(0)),
+        1.,
+    );
+    test.sync_and_update();
+
+    // Inside the element but outside the circle
+    test.move_cursor((5., 5.));
+    test.sync_and_update();
+    assert_eq!(*state.peek(), 0);
+
+    test.move_cursor((50., 50.));
+    test.sync_and_update();
+    assert_eq!(*state.peek(), 1);
+}
+
+#[test]
+fn transform_hit_testing() {
+    fn app() -> Element {
+        let mut state = use_consume::<State<i32>>();
+        rect()
+            .width(Size::px(100.))
+            .height(Size::px(100.))
+            .background((0, 0, 0))
+            .transform(Transform::new().translate(200., 0.))
+            .on_pointer_enter(move |_| *state.write() += 1)
+            .into()
+    }
+
+    let (mut test, state) = TestingRunner::new(
+        app,
+        (500., 500.).into(),
+        |runner| runner.provide_root_context(|| State::create(0)),
+        1.,
+    );
+    test.sync_and_update();
+
+    // Inside the layout area but outside the translated element
+    test.move_cursor((50., 50.));
+    test.sync_and_update();
+    assert_eq!(*state.peek(), 0);
+
+    test.move_cursor((250., 50.));
+    test.sync_and_update();
+    assert_eq!(*state.peek(), 1);
+}
+
+#[test]
+fn z_index_paint_order() {
+    fn app() -> Element {
+        let mut state = use_consume::<State<Vec<&'static str>>>();
+        rect()
+            .child(
+                rect()
+                    .position(Position::new_absolute())
+                    .width(Size::px(100.))
+                    .height(Size::px(100.))
+                    .background((255, 0, 0))
+                    .z_index(1)
+                    .on_mouse_down(move |_| state.write().push("first")),
+            )
+            .child(
+                rect()
+                    .position(Position::new_absolute())
+                    .width(Size::px(100.))
+                    .height(Size::px(100.))
+                    .background((0, 0, 255))
+                    .on_mouse_down(move |_| state.write().push("second")),
+            )
+            .into()
+    }
+
+    let (mut test, state) = TestingRunner::new(
+        app,
+        (500., 500.).into(),
+        |runner| runner.provide_root_context(|| State::create(Vec::new())),
+        1.,
+    );
+    test.sync_and_update();
+
+    // The first sibling is painted on top despite coming first in the tree
+    test.click_cursor((50., 50.));
+    assert_eq!(*state.peek(), vec!["first"]);
+}
+
+#[test]
+fn replay_event_recording() {
+    fn app() -> Element {
+        let mut state = use_consume::<State<i32>>();
+        rect()
+            .expanded()
+            .on_mouse_down(move |_| *state.write() += 1)
+            .on_mouse_up(move |_| *state.write() += 10)
+            .into()
+    }
+
+    let (mut test, state) = TestingRunner::new(
+        app,
+        (500., 500.).into(),
+        |runner| runner.provide_root_context(|| State::create(0)),
+        1.,
+    );
+    test.sync_and_update();
+
+    let mut recording = EventRecording::new(1., (500., 500.).into());
+    recording.push(
+        Duration::ZERO,
+        PlatformEvent::Mouse {
+            name: MouseEventName::MouseDown,
+            cursor: (15., 15.).into(),
+            button: Some(MouseButton::Left),
+            click_count: 1,
+        },
+    );
+    recording.push(
+        Duration::from_millis(10),
+        PlatformEvent::Drag {
+            name: DragEventName::DragStart,
+            cursor: (15., 15.).into(),
+            drag_data: DragData::default(),
+        },
+    );
+    recording.push(
+        Duration::from_millis(50),
+        PlatformEvent::Mouse {
+            name: MouseEventName::MouseUp,
+            cursor: (15., 15.).into(),
+            button: Some(MouseButton::Left),
+            click_count: 1,
+        },
+    );
+    // Drag events can't be replayed
+    assert_eq!(recording.events.len(), 2);
+
+    test.animation_clock().freeze();
+    let started = test.animation_clock().now();
+    test.replay(&recording);
+
+    assert_eq!(*state.peek(), 11);
+    assert_eq!(
+        test.animation_clock().now().duration_since(started),
+        Duration::from_millis(50)
+    );
+}
+
+#[test]
+fn cursor_of_hovered_element() {
+    fn app() -> Element {
+        rect()
+            .expanded()
+            .child(
+                rect()
+                    .width(Size::px(100.))
+                    .height(Size::px(100.))
+                    .cursor(CursorIcon::Pointer)
+                    .child(
+                        label()
+                            .width(Size::px(50.))
+                            .height(Size::px(50.))
+                            .cursor(CursorIcon::Text)
+                            .text("Hello"),
+                    )
+                    .child(rect().width(Size::px(50.)).height(Size::px(50.))),
+            )
+            .into()
+    }
+
+    let mut test = launch_test(app);
+    test.sync_and_update();
+    assert_eq!(test.cursor(), MouseCursor::default());
+
+    test.move_cursor((25., 25.));
+    assert_eq!(test.cursor(), MouseCursor::Icon(CursorIcon::Text));
+
+    // Inherited from the parent
+    test.move_cursor((25., 75.));
+    assert_eq!(test.cursor(), MouseCursor::Icon(CursorIcon::Pointer));
+
+    test.move_cursor((250., 250.));
+    assert_eq!(test.cursor(), MouseCursor::default());
+}
+
+#[test]
+fn cursor_follows_elements_under_still_pointer() {
+    fn app() -> Element {
+        let offset = use_consume::<State<f32>>();
+        rect()
+            .expanded()
+            .child(
+                rect()
+                    .offset_y(offset())
+                    .width(Size::px(100.))
+                    .height(Size::px(100.))
+                    .cursor(CursorIcon::Pointer),
+            )
+            .child(
+                rect()
+                    .position(Position::new_absolute())
+                    .expanded()
+                    .interactive(Interactive::No),
+            )
+            .into()
+    }
+
+    let (mut test, mut offset) = TestingRunner::new(
+        app,
+        (500., 500.).into(),
+        |runner| runner.provide_root_context(|| State::create(0.)),
+        1.,
+    );
+    test.sync_and_update();
+
+    // The overlay on top is not interactive
+    test.move_cursor((50., 50.));
+    assert_eq!(test.cursor(), MouseCursor::Icon(CursorIcon::Pointer));
+
+    // Scrolled away from the pointer without moving it
+    offset.set(-200.);
+    test.sync_and_update();
+    assert_eq!(test.cursor(), MouseCursor::default());
+
+    offset.set(0.);
+    test.sync_and_update();
+    assert_eq!(test.cursor(), MouseCursor::Icon(CursorIcon::Pointer));
+}
+
+#[test]
+fn custom_cursor_from_rgba() {
+    assert!(CustomCursor::from_rgba(vec![0; 4 * 4 * 4], 4, 4, (1, 2)).is_some());
+    // Not enough pixels
+    assert!(CustomCursor::from_rgba(vec![0; 4 * 4 * 3], 4, 4, (1, 2)).is_none());
+    // Hotspot outside of the image
+    assert!(CustomCursor::from_rgba(vec![0; 4 * 4 * 4], 4, 4, (4, 0)).is_none());
+
+    // Clones share the id, separate cursors with the same pixels are still equal
+    let cursor = CustomCursor::from_rgba(vec![0; 4 * 4 * 4], 4, 4, (1, 2)).unwrap();
+    let same_pixels = CustomCursor::from_rgba(vec![0; 4 * 4 * 4], 4, 4, (1, 2)).unwrap();
+    assert_eq!(cursor.clone().id(), cursor.id());
+    assert_ne!(same_pixels.id(), cursor.id());
+    assert_eq!(same_pixels, cursor);
+    assert_ne!(
+        CustomCursor::from_rgba(vec![255; 4 * 4 * 4], 4, 4, (1, 2)).unwrap(),
+        cursor
+    );
+}
+
+#[test]
+fn file_drop() {
+    fn app() -> Element {
+        let mut dropped = use_consume::<State<Option<PathBuf>>>();
+        rect()
+            .expanded()
+            .on_file_drop(move |e: Event<FileEventData>| dropped.set(e.file_path.clone()))
+            .into()
+    }
+
+    let (mut test, dropped) = TestingRunner::new(
+        app,
+        (500., 500.).into(),
+        |runner| runner.provide_root_context(|| State::create(None)),
+        1.,
+    );
+    test.sync_and_update();
+
+    test.hover_file((15., 15.), "image.png");
+    assert_eq!(*dropped.peek(), None);
+
+    test.drop_file((15., 15.), "image.png");
+    assert_eq!(*dropped.peek(), Some(PathBuf::from("image.png")));
+}
+
+#[test]
+fn z_index_stacking_context() {
+    fn app() -> Element {
+        let mut state = use_consume::<State<Vec<&'static str>>>();
+        rect()
+            .child(
+                rect().position(Position::new_absolute()).z_index(1).child(
+                    rect()
+                        .width(Size::px(100.))
+                        .height(Size::px(100.))
+                        .background((255, 0, 0))
+                        .on_mouse_down(move |_| state.write().push("first")),
+                ),
+            )
+            .child(
+                rect().position(Position::new_absolute()).child(
+                    rect().child(
+                        rect()
+                            .width(Size::px(100.))
+                            .height(Size::px(100.))
+                            .background((0, 0, 255))
+                            .on_mouse_down(move |_| state.write().push("nested")),
+                    ),
+                ),
+            )
+            .into()
+    }
+
+    let (mut test, state) = TestingRunner::new(
+        app,
+        (500., 500.).into(),
+        |runner| runner.provide_root_context(|| State::create(Vec::new())),
+        1.,
+    );
+    test.sync_and_update();
+
+    // The whole first subtree is above the deeper children of the later sibling
+    test.click_cursor((50., 50.));
+    assert_eq!(*state.peek(), vec!["first"]);
+
+    let pixel = test.render_pixels().color(50, 50);
+    assert_eq!((pixel.r(), pixel.g(), pixel.b()), (255, 0, 0));
+}
+
+#[test]
+fn replay_event_recording_other_window() {
+    fn app() -> Element {
+        let mut state = use_consume::<State<i32>>();
+        rect()
+            .width(Size::percent(40.))
+            .height(Size::percent(40.))
+            .on_mouse_down(move |_| *state.write() += 1)
+            .into()
+    }
+
+    let (mut test, state) = TestingRunner::new(
+        app,
+        (500., 500.).into(),
+        |runner| runner.provide_root_context(|| State::create(0)),
+        1.,
+    );
+    test.sync_and_update();
+
+    // Recorded in a window with twice the scale factor
+    let mut recording = EventRecording::new(2., (1000., 1000.).into());
+    for cursor in [(300., 300.), (500., 500.)] {
+        recording.push(
+            Duration::ZERO,
+            PlatformEvent::Mouse {
+                name: MouseEventName::MouseDown,
+                cursor: cursor.into(),
+                button: Some(MouseButton::Left),
+                click_count: 1,
+            },
+        );
+    }
+    test.replay(&recording);
+    assert_eq!(*state.peek(), 1);
+
+    // Recorded in a bigger window with the same scale factor
+    let mut recording = EventRecording::new(1., (1000., 1000.).into());
+    recording.push(
+        Duration::ZERO,
+        PlatformEvent::Mouse {
+            name: MouseEventName::MouseDown,
+            cursor: (350., 350.).into(),
+            button: Some(MouseButton::Left),
+            click_count: 1,
+        },
+    );
+    test.replay(&recording);
+    assert_eq!(*state.peek(), 2);
+}
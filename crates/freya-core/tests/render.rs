@@ -1,4 +1,8 @@
 use freya::prelude::*;
+use freya_core::integration::{
+    NodeId,
+    RepaintTracker,
+};
 use freya_testing::prelude::*;
 
 #[test]
@@ -28,3 +32,398 @@ pub fn basic_render() {
 
     assert!(!data.is_empty());
 }
+
+#[test]
+pub fn repeating_gradients_render() {
+    fn app() -> impl IntoElement {
+        rect()
+            .horizontal()
+            .child(
+                rect()
+                    .width(Size::px(100.))
+                    .height(Size::px(100.))
+                    .background_linear_gradient(
+                        LinearGradient::new()
+                            .angle(45.)
+                            .repeating(true)
+                            .stop(((255, 0, 0), 0.))
+                            .stop(((0, 0, 255), 10.)),
+                    ),
+            )
+            .child(
+                rect()
+                    .width(Size::px(100.))
+                    .height(Size::px(100.))
+                    .background_radial_gradient(
+                        RadialGradient::new()
+                            .center(0., 0.)
+                            .repeating(true)
+                            .stop(((255, 0, 0), 10.))
+                            .stop(((0, 0, 255), 20.)),
+                    ),
+            )
+            .child(
+                rect()
+                    .width(Size::px(100.))
+                    .height(Size::px(100.))
+                    .background_conic_gradient(
+                        ConicGradient::new()
+                            .center(0.25, 0.75)
+                            .repeating(true)
+                            .stop(((255, 0, 0), 0.))
+                            .stop(((0, 0, 255), 25.)),
+                    ),
+            )
+    }
+
+    let mut test = launch_test(app);
+    test.sync_and_update();
+
+    let data = test.render();
+
+    assert!(!data.is_empty());
+}
+
+#[test]
+pub fn border_styles_render() {
+    fn app() -> impl IntoElement {
+        rect()
+            .horizontal()
+            .spacing(8.)
+            .child(
+                rect()
+                    .width(Size::px(100.))
+                    .height(Size::px(100.))
+                    .corner_radius(16.)
+                    .border(
+                        Border::new()
+                            .width(BorderWidth {
+                                top: 2.,
+                                right: 4.,
+                                bottom: 6.,
+                                left: 8.,
+                            })
+                            .side_fills(BorderColor::new(
+                                (255, 0, 0),
+                                (0, 255, 0),
+                                (0, 0, 255),
+                                (0, 0, 0),
+                            )),
+                    ),
+            )
+            .child(
+                rect()
+                    .width(Size::px(100.))
+                    .height(Size::px(100.))
+                    .corner_radius(16.)
+                    .border(
+                        Border::new()
+                            .width(4.)
+                            .fill((0, 0, 0))
+                            .style(BorderStyle::Dashed),
+                    ),
+            )
+            .child(
+                rect().width(Size::px(100.)).height(Size::px(100.)).border(
+                    Border::new()
+                        .width(4.)
+                        .fill((0, 0, 0))
+                        .alignment(BorderAlignment::Center)
+                        .style(BorderStyle::Dotted),
+                ),
+            )
+    }
+
+    let mut test = launch_test(app);
+    test.sync_and_update();
+
+    let data = test.render();
+
+    assert!(!data.is_empty());
+}
+
+#[test]
+pub fn repaint_tracker() {
+    let mut repaints = RepaintTracker::default();
+
+    repaints.track(NodeId::ROOT);
+    assert!(repaints.nodes().is_empty());
+
+    repaints.set_enabled(true);
+    repaints.track(NodeId::ROOT);
+    assert!(repaints.nodes().contains(&NodeId::ROOT));
+
+    repaints.clear();
+    assert!(repaints.nodes().is_empty());
+}
+
+#[test]
+pub fn show_repaints_render() {
+    fn app() -> impl IntoElement {
+        use_hook(|| Platform::get().show_repaints(true));
+        let mut pressed = use_state(|| false);
+
+        rect()
+            .child(
+                rect()
+                    .width(Size::px(100.))
+                    .height(Size::px(100.))
+                    .background(if *pressed.read() {
+                        (0, 0, 0)
+                    } else {
+                        (255, 255, 255)
+                    })
+                    .on_press(move |_| *pressed.write() = true),
+            )
+            .child(
+                rect()
+                    .width(Size::px(100.))
+                    .height(Size::px(100.))
+                    .background((255, 0, 0)),
+            )
+    }
+
+    let mut test = launch_test(app);
+    test.sync_and_update();
+    test.render_pixels();
+
+    test.click_cursor((50., 50.));
+    let pixels = test.render_pixels();
+
+    // Only the changed element is tinted, with the color of the second frame
+    assert_color(&pixels, (50, 50), (24, 60, 76));
+    assert_color(&pixels, (50, 150), (255, 0, 0));
+
+    // Nothing changed since the last frame so nothing is tinted
+    let pixels = test.render_pixels();
+    assert_color(&pixels, (50, 50), (0, 0, 0));
+    assert_color(&pixels, (50, 150), (255, 0, 0));
+}
+
+fn assert_color(pixels: &RenderedPixels, (x, y): (usize, usize), expected: (u8, u8, u8)) {
+    let color = pixels.color(x, y);
+    let close = |a: u8, b: u8| a.abs_diff(b) <= 2;
+    assert!(
+        close(color.r(), expected.0)
+            && close(color.g(), expected.1)
+            && close(color.b(), expected.2),
+        "Pixel at ({x}, {y}) is {color:?}, expected {expected:?}"
+    );
+}
+
+#[test]
+pub fn mask_applies_once_to_subtree() {
+    fn app() -> impl IntoElement {
+        rect()
+            .width(Size::px(100.))
+            .height(Size::px(100.))
+            .mask(Fill::Color(Color::from_af32rgb(0.5, 0, 0, 0)))
+            .child(
+                rect()
+                    .width(Size::px(100.))
+                    .height(Size::px(100.))
+                    .background((0, 0, 255))
+                    .child(
+                        rect()
+                            .width(Size::px(50.))
+                            .height(Size::px(50.))
+                            .background((255, 0, 0)),
+                    ),
+            )
+    }
+
+    let mut test = launch_test(app);
+    test.sync_and_update();
+    let pixels = test.render_pixels();
+
+    // The red child covers the blue one before the mask is applied
+    assert_color(&pixels, (25, 25), (255, 127, 127));
+    assert_color(&pixels, (75, 75), (127, 127, 255));
+    assert_color(&pixels, (150, 150), (255, 255, 255));
+}
+
+#[test]
+pub fn mask_references_node() {
+    fn app() -> impl IntoElement {
+        let mask_id = use_hook(Focus::new_id);
+
+        rect()
+            .child(
+                rect()
+                    .width(Size::px(100.))
+                    .height(Size::px(100.))
+                    .background((255, 0, 0))
+                    .mask(mask_id),
+            )
+            .child(
+                rect()
+                    .a11y_id(mask_id)
+                    .width(Size::px(50.))
+                    .height(Size::px(50.))
+                    .background((0, 0, 0)),
+            )
+    }
+
+    let mut test = launch_test(app);
+    test.sync_and_update();
+    let pixels = test.render_pixels();
+
+    assert_color(&pixels, (25, 25), (255, 0, 0));
+    assert_color(&pixels, (75, 75), (255, 255, 255));
+    // The referenced element is only painted as the mask
+    assert_color(&pixels, (25, 125), (255, 255, 255));
+}
+
+#[test]
+pub fn overlay_ignores_mask() {
+    fn app() -> impl IntoElement {
+        rect()
+            .width(Size::px(100.))
+            .height(Size::px(100.))
+            .mask(Fill::Color(Color::TRANSPARENT))
+            .child(
+                rect()
+                    .width(Size::px(100.))
+                    .height(Size::px(50.))
+                    .background((0, 0, 255)),
+            )
+            .child(
+                rect()
+                    .layer(Layer::Overlay)
+                    .width(Size::px(100.))
+                    .height(Size::px(50.))
+                    .background((255, 0, 0)),
+            )
+    }
+
+    let mut test = launch_test(app);
+    test.sync_and_update();
+    let pixels = test.render_pixels();
+
+    assert_color(&pixels, (25, 25), (255, 255, 255));
+    assert_color(&pixels, (25, 75), (255, 0, 0));
+}
+
+#[test]
+pub fn cache_layer_reuse() {
+    fn app() -> impl IntoElement {
+        let mut count = use_state(|| 0);
+
+        rect()
+            .child(
+                rect()
+                    .cache_layer(true)
+                    .width(Size::px(100.))
+                    .height(Size::px(100.))
+                    .background((0, 0, 255))
+                    .child(
+                        rect()
+                            .width(Size::px(50.))
+                            .height(Size::px(50.))
+                            .background((255, 0, 0)),
+                    ),
+            )
+            .child(
+                rect()
+                    .width(Size::px(100.))
+                    .height(Size::px(100.))
+                    .background(if *count.read() % 2 == 0 {
+                        (0, 255, 0)
+                    } else {
+                        (0, 0, 0)
+                    })
+                    .on_press(move |_| *count.write() += 1),
+            )
+    }
+
+    let mut test = launch_test(app);
+    test.sync_and_update();
+    let pixels = test.render_pixels();
+    assert_color(&pixels, (25, 25), (255, 0, 0));
+    assert_color(&pixels, (75, 75), (0, 0, 255));
+    assert_color(&pixels, (50, 150), (0, 255, 0));
+
+    // Only the element outside of the cache layer changes
+    test.click_cursor((50., 150.));
+    let pixels = test.render_pixels();
+    assert_color(&pixels, (25, 25), (255, 0, 0));
+    assert_color(&pixels, (75, 75), (0, 0, 255));
+    assert_color(&pixels, (50, 150), (0, 0, 0));
+}
+
+#[test]
+pub fn cache_layer_invalidation() {
+    fn app() -> impl IntoElement {
+        let mut count = use_state(|| 0);
+
+        rect()
+            .cache_layer(true)
+            .width(Size::px(100.))
+            .height(Size::px(100.))
+            .on_press(move |_| *count.write() += 1)
+            .child(
+                rect()
+                    .width(Size::px(50.))
+                    .height(Size::px(50.))
+                    .background(if *count.read() % 2 == 0 {
+                        (255, 0, 0)
+                    } else {
+                        (0, 0, 255)
+                    }),
+            )
+    }
+
+    let mut test = launch_test(app);
+    test.sync_and_update();
+    assert_color(&test.render_pixels(), (25, 25), (255, 0, 0));
+
+    test.click_cursor((25., 25.));
+    assert_color(&test.render_pixels(), (25, 25), (0, 0, 255));
+
+    test.click_cursor((25., 25.));
+    assert_color(&test.render_pixels(), (25, 25), (255, 0, 0));
+}
+
+#[test]
+pub fn cache_layer_keeps_paint_order() {
+    fn app() -> impl IntoElement {
+        rect()
+            .child(
+                rect()
+                    .cache_layer(true)
+                    .width(Size::px(100.))
+                    .height(Size::px(100.))
+                    .child(
+                        rect()
+                            .width(Size::px(100.))
+                            .height(Size::px(50.))
+                            .background((255, 0, 0)),
+                    )
+                    .child(
+                        rect()
+                            .layer(Layer::Overlay)
+                            .width(Size::px(100.))
+                            .height(Size::px(50.))
+                            .background((0, 255, 0)),
+                    ),
+            )
+            .child(
+                rect()
+                    .position(Position::new_absolute())
+                    .width(Size::px(100.))
+                    .height(Size::px(100.))
+                    .background((0, 0, 255)),
+            )
+    }
+
+    let mut test = launch_test(app);
+    test.sync_and_update();
+
+    // Descendants of the cached element are still painted above its later sibling,
+    // both when recording and when reusing the recordings
+    for _ in 0..2 {
+        let pixels = test.render_pixels();
+        assert_color(&pixels, (50, 25), (255, 0, 0));
+        assert_color(&pixels, (50, 75), (0, 255, 0));
+    }
+}
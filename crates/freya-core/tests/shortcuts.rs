@@ -0,0 +1,183 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+fn press(test: &mut TestingRunner, key: Key, modifiers: Modifiers) {
+    test.send_event(PlatformEvent::Keyboard {
+        name: KeyboardEventName::KeyDown,
+        key,
+        code: Code::Unidentified,
+        modifiers,
+    });
+    test.sync_and_update();
+}
+
+#[test]
+fn parse_shortcut() {
+    let shortcut = Shortcut::parse("Ctrl+Shift+P").unwrap();
+    assert_eq!(shortcut.modifiers(), Modifiers::CONTROL | Modifiers::SHIFT);
+    assert_eq!(shortcut.key(), &Key::Character("p".to_string()));
+    assert_eq!(shortcut.to_string(), "Ctrl+Shift+P");
+
+    assert_eq!(
+        Shortcut::parse("mod+s").unwrap().modifiers(),
+        Shortcut::primary_modifier()
+    );
+    assert_eq!(
+        Shortcut::parse("f5").unwrap().key(),
+        &Key::Named(NamedKey::F5)
+    );
+    assert_eq!(
+        Shortcut::parse("ctrl++").unwrap().key(),
+        &Key::Character("+".to_string())
+    );
+
+    assert_eq!(
+        Shortcut::parse("hyper+a"),
+        Err(ShortcutParseError::UnknownModifier("hyper".to_string()))
+    );
+    assert_eq!(
+        Shortcut::parse("ctrl+nope"),
+        Err(ShortcutParseError::UnknownKey("nope".to_string()))
+    );
+    assert_eq!(Shortcut::parse(""), Err(ShortcutParseError::MissingKey));
+}
+
+#[test]
+fn global_shortcut() {
+    fn app() -> Element {
+        let mut state = use_consume::<State<i32>>();
+        use_shortcut("ctrl+shift+p", move || *state.write() += 1);
+        rect().into()
+    }
+
+    let (mut test, state) = TestingRunner::new(
+        app,
+        (500., 500.).into(),
+        |runner| runner.provide_root_context(|| State::create(0)),
+        1.,
+    );
+    test.sync_and_update();
+
+    // Shifted characters still match
+    press(
+        &mut test,
+        Key::Character("P".to_string()),
+        Modifiers::CONTROL | Modifiers::SHIFT,
+    );
+    assert_eq!(*state.peek(), 1);
+
+    // Missing and extra modifiers do not
+    press(
+        &mut test,
+        Key::Character("p".to_string()),
+        Modifiers::CONTROL,
+    );
+    press(
+        &mut test,
+        Key::Character("p".to_string()),
+        Modifiers::CONTROL | Modifiers::SHIFT | Modifiers::ALT,
+    );
+    assert_eq!(*state.peek(), 1);
+}
+
+#[test]
+fn focused_shortcut_wins() {
+    #[derive(PartialEq)]
+    struct Panel {
+        value: i32,
+    }
+
+    impl Component for Panel {
+        fn render(&self) -> impl IntoElement {
+            let mut state = use_consume::<State<i32>>();
+            let focus = use_focus();
+            let value = self.value;
+            use_shortcut_with_scope(
+                "escape",
+                ShortcutScope::Focused(focus.a11y_id()),
+                move || state.set(value),
+            );
+
+            rect().a11y_id(focus.a11y_id()).child(
+                rect()
+                    .a11y_focusable(true)
+                    .width(Size::px(50.))
+                    .height(Size::px(50.)),
+            )
+        }
+    }
+
+    fn app() -> Element {
+        let mut state = use_consume::<State<i32>>();
+        use_shortcut("escape", move || state.set(-1));
+        rect()
+            .child(Panel { value: 1 })
+            .child(Panel { value: 2 })
+            .into()
+    }
+
+    let (mut test, state) = TestingRunner::new(
+        app,
+        (500., 500.).into(),
+        |runner| runner.provide_root_context(|| State::create(0)),
+        1.,
+    );
+    test.sync_and_update();
+
+    // Nothing focused, only the global one is active
+    press(&mut test, Key::Named(NamedKey::Escape), Modifiers::empty());
+    assert_eq!(*state.peek(), -1);
+
+    press(&mut test, Key::Named(NamedKey::Tab), Modifiers::empty());
+    press(&mut test, Key::Named(NamedKey::Escape), Modifiers::empty());
+    assert_eq!(*state.peek(), 1);
+
+    press(&mut test, Key::Named(NamedKey::Tab), Modifiers::empty());
+    press(&mut test, Key::Named(NamedKey::Escape), Modifiers::empty());
+    assert_eq!(*state.peek(), 2);
+}
+
+#[test]
+fn text_input_keeps_typed_keys() {
+    fn app() -> Element {
+        let mut state = use_consume::<State<i32>>();
+        let focus = use_focus();
+        use_shortcut("k", move || *state.write() += 1);
+        use_shortcut("ctrl+k", move || *state.write() += 10);
+        rect()
+            .a11y_id(focus.a11y_id())
+            .a11y_focusable(true)
+            .a11y_role(AccessibilityRole::TextInput)
+            .into()
+    }
+
+    let (mut test, state) = TestingRunner::new(
+        app,
+        (500., 500.).into(),
+        |runner| runner.provide_root_context(|| State::create(0)),
+        1.,
+    );
+    test.sync_and_update();
+
+    press(
+        &mut test,
+        Key::Character("k".to_string()),
+        Modifiers::empty(),
+    );
+    assert_eq!(*state.peek(), 1);
+
+    // Once the input is focused, only the shortcuts with modifiers run
+    press(&mut test, Key::Named(NamedKey::Tab), Modifiers::empty());
+    press(
+        &mut test,
+        Key::Character("k".to_string()),
+        Modifiers::empty(),
+    );
+    assert_eq!(*state.peek(), 1);
+    press(
+        &mut test,
+        Key::Character("k".to_string()),
+        Modifiers::CONTROL,
+    );
+    assert_eq!(*state.peek(), 11);
+}
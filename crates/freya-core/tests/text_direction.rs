@@ -0,0 +1,56 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[test]
+fn text_direction_is_inherited_and_aligns_text() {
+    let root = TextStyleState::default();
+    assert_eq!(root.text_direction, TextDirection::Ltr);
+    assert_eq!(root.text_align, TextAlign::Left);
+
+    let rtl = TextStyleState::from_data(
+        &root,
+        &TextStyleData {
+            text_direction: Some(TextDirection::Rtl),
+            ..Default::default()
+        },
+    );
+    assert_eq!(rtl.text_align, TextAlign::Right);
+
+    let child = TextStyleState::from_data(&rtl, &TextStyleData::default());
+    assert_eq!(child.text_direction, TextDirection::Rtl);
+    assert_eq!(child.text_align, TextAlign::Right);
+
+    // An explicit alignment is kept
+    let centered = TextStyleState::from_data(
+        &rtl,
+        &TextStyleData {
+            text_align: Some(TextAlign::Center),
+            ..Default::default()
+        },
+    );
+    assert_eq!(centered.text_direction, TextDirection::Rtl);
+    assert_eq!(centered.text_align, TextAlign::Center);
+}
+
+#[test]
+fn rtl_mirrors_the_layout() {
+    fn app() -> impl IntoElement {
+        rect()
+            .text_direction(TextDirection::Rtl)
+            .horizontal()
+            .width(Size::px(200.))
+            .height(Size::px(50.))
+            .padding(Gaps::new(0., 0., 0., 20.))
+            .child(label().width(Size::px(50.)).text("First"))
+            .child(label().width(Size::px(30.)).text("Second"))
+    }
+
+    let mut test = launch_test(app);
+    test.sync_and_update();
+
+    let first = test.find_by_text("First").unwrap().layout().area;
+    let second = test.find_by_text("Second").unwrap().layout().area;
+    // The row starts from the right, where the padding of its start is now
+    assert_eq!(first.min_x(), 130.);
+    assert_eq!(second.min_x(), 100.);
+}
@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+fn app() -> impl IntoElement {
+    rect()
+        .child(label().selectable(true).text("Hello Rustaceans"))
+        .child(label().text("Not selectable"))
+        .child(label().selectable(true).text("Hello Rustaceans"))
+}
+
+fn launch_with_fonts() -> TestingRunner {
+    with_fonts(launch_test(app))
+}
+
+fn with_fonts(mut test: TestingRunner) -> TestingRunner {
+    test.set_fonts(HashMap::from_iter([(
+        "NotoSans",
+        include_bytes!("../../freya-edit/tests/NotoSans-Regular.ttf").as_slice(),
+    )]));
+    test.set_default_fonts(&["NotoSans".into()]);
+    test
+}
+
+fn select_all_modifiers() -> Modifiers {
+    if cfg!(target_os = "macos") {
+        Modifiers::META
+    } else {
+        Modifiers::CONTROL
+    }
+}
+
+#[test]
+fn text_selection_across_labels() {
+    let mut test = launch_with_fonts();
+
+    // Drag from the first label to the last one
+    test.press_cursor((35.0, 3.0));
+    test.move_cursor((80.0, 47.0));
+    test.release_cursor((80.0, 47.0));
+
+    assert_eq!(
+        test.selected_text(),
+        Some(" Rustaceans\nHello Rust".to_string())
+    );
+
+    // Pressing outside of any selectable label clears the selection
+    test.press_cursor((35.0, 25.0));
+    test.release_cursor((35.0, 25.0));
+
+    assert_eq!(test.selected_text(), None);
+}
+
+#[test]
+fn text_selection_select_all_and_clear() {
+    let mut test = launch_with_fonts();
+
+    test.send_event(PlatformEvent::Keyboard {
+        name: KeyboardEventName::KeyDown,
+        key: Key::Character("a".to_string()),
+        code: Code::KeyA,
+        modifiers: select_all_modifiers(),
+    });
+    test.sync_and_update();
+
+    assert_eq!(
+        test.selected_text(),
+        Some("Hello Rustaceans\nHello Rustaceans".to_string())
+    );
+
+    test.press_key(Key::Named(NamedKey::Escape));
+
+    assert_eq!(test.selected_text(), None);
+}
+
+#[test]
+fn text_selection_ignores_covered_text() {
+    fn app() -> impl IntoElement {
+        rect()
+            .child(label().selectable(true).text("Hello Rustaceans"))
+            .child(
+                rect()
+                    .position(Position::new_absolute())
+                    .layer(Layer::Overlay)
+                    .width(Size::px(50.))
+                    .height(Size::px(20.)),
+            )
+    }
+
+    let mut test = with_fonts(launch_test(app));
+
+    // The start of the text is below another element
+    test.press_cursor((10.0, 3.0));
+    test.move_cursor((80.0, 3.0));
+    test.release_cursor((80.0, 3.0));
+    assert_eq!(test.selected_text(), None);
+
+    test.press_cursor((60.0, 3.0));
+    test.move_cursor((100.0, 3.0));
+    test.release_cursor((100.0, 3.0));
+    assert!(test.selected_text().is_some());
+}
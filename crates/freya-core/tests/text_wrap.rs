@@ -0,0 +1,97 @@
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+const LONG_WORD: &str = "Supercalifragilisticexpialidocious";
+
+fn label_width(test: &TestingRunner, text: &str) -> f32 {
+    test.find(|node, element| {
+        Label::try_downcast(element)
+            .filter(|label| label.text.as_ref() == text)
+            .map(|_| node.layout().area.width())
+    })
+    .unwrap()
+}
+
+#[test]
+pub fn overflow_wrap_normal() {
+    fn app() -> impl IntoElement {
+        rect().width(Size::px(60.)).child(
+            rect()
+                .overflow_wrap(OverflowWrap::Normal)
+                .child(label().text(LONG_WORD)),
+        )
+    }
+
+    let mut test = launch_test(app);
+    test.sync_and_update();
+
+    // Inherited from the parent, the word overflows instead of being broken
+    assert!(label_width(&test, LONG_WORD) > 61.);
+}
+
+#[test]
+pub fn overflow_wrap_anywhere() {
+    fn app() -> impl IntoElement {
+        rect()
+            .width(Size::px(60.))
+            .child(label().text("Short"))
+            .child(
+                label()
+                    .text(LONG_WORD)
+                    .overflow_wrap(OverflowWrap::Anywhere),
+            )
+    }
+
+    let mut test = launch_test(app);
+    test.sync_and_update();
+
+    assert!(label_width(&test, LONG_WORD) <= 61.);
+
+    // The long word takes several lines
+    let short = test
+        .find(|node, element| {
+            Label::try_downcast(element)
+                .filter(|label| label.text.as_ref() == "Short")
+                .map(|_| node.layout().area.height())
+        })
+        .unwrap();
+    let long = test
+        .find(|node, element| {
+            Label::try_downcast(element)
+                .filter(|label| label.text.as_ref() == LONG_WORD)
+                .map(|_| node.layout().area.height())
+        })
+        .unwrap();
+    assert!(long > short);
+}
+
+#[cfg(feature = "hyphenation")]
+#[test]
+pub fn hyphenation_selectable() {
+    const TEXT: &str = "Internationalization";
+
+    fn app() -> impl IntoElement {
+        rect()
+            .width(Size::px(120.))
+            .hyphenation(Hyphenation::Auto)
+            .child(label().selectable(true).text(TEXT))
+    }
+
+    let mut test = launch_test(app);
+    test.sync_and_update();
+
+    test.send_event(PlatformEvent::Keyboard {
+        name: KeyboardEventName::KeyDown,
+        key: Key::Character("a".to_string()),
+        code: Code::KeyA,
+        modifiers: if cfg!(target_os = "macos") {
+            Modifiers::META
+        } else {
+            Modifiers::CONTROL
+        },
+    });
+    test.sync_and_update();
+
+    // The whole word is selected and copied without its soft hyphens
+    assert_eq!(test.selected_text(), Some(TEXT.to_string()));
+}
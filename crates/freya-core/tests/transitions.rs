@@ -0,0 +1,61 @@
+use std::time::Duration;
+
+use freya::prelude::*;
+use freya_testing::prelude::*;
+
+#[test]
+pub fn transition_width() {
+    fn app() -> impl IntoElement {
+        let mut toggled = use_state(|| false);
+
+        rect()
+            .transition("width 100ms linear")
+            .width(Size::px(if toggled() { 200. } else { 100. }))
+            .height(Size::px(50.))
+            .on_press(move |_| toggled.toggle())
+    }
+
+    let mut test = launch_test(app);
+
+    let rect = &test.find_many(|t, e| Rect::try_downcast(e).map(|_| t.layout()))[2];
+    assert_eq!(rect.area.width(), 100.0);
+
+    test.animation_clock().freeze();
+    test.click_cursor((5., 5.));
+
+    test.move_time(Duration::from_millis(25));
+    let rect = &test.find_many(|t, e| Rect::try_downcast(e).map(|_| t.layout()))[2];
+    assert_eq!(rect.area.width(), 125.0);
+
+    test.move_time(Duration::from_millis(25));
+    let rect = &test.find_many(|t, e| Rect::try_downcast(e).map(|_| t.layout()))[2];
+    assert_eq!(rect.area.width(), 150.0);
+
+    // Finishes at the target once the duration is exceeded
+    test.move_time(Duration::from_millis(60));
+    let rect = &test.find_many(|t, e| Rect::try_downcast(e).map(|_| t.layout()))[2];
+    assert_eq!(rect.area.width(), 200.0);
+}
+
+#[test]
+pub fn parse_transitions() {
+    let transitions = Transitions::from("background 200ms ease-out, width 0.3s, invalid 1ms");
+    assert_eq!(
+        transitions,
+        Transitions(vec![
+            Transition::new(TransitionProperty::Background, Duration::from_millis(200))
+                .ease(TransitionEase::EaseOut),
+            Transition::new(TransitionProperty::Width, Duration::from_millis(300)),
+        ])
+    );
+
+    // Seconds are rounded to the closest nanosecond
+    let transitions = Transitions::from("height 1.1s, opacity 0.0000000016s");
+    assert_eq!(
+        transitions,
+        Transitions(vec![
+            Transition::new(TransitionProperty::Height, Duration::from_millis(1100)),
+            Transition::new(TransitionProperty::Opacity, Duration::from_nanos(2)),
+        ])
+    );
+}
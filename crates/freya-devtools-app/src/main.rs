@@ -16,6 +16,8 @@ use freya_devtools::{
     IncomingMessageAction,
     OutgoingMessage,
     OutgoingMessageAction,
+    PROTOCOL_VERSION,
+    devtools_address,
 };
 use freya_router::prelude::*;
 use futures_util::StreamExt;
@@ -40,6 +42,7 @@ use tabs::{
     computed_layout::computed_layout,
     layout::*,
     misc::*,
+    profiler::*,
     style::*,
     text_style::*,
     tree::*,
@@ -62,6 +65,15 @@ pub fn app() -> impl IntoElement {
         expanded_nodes: HashSet::default(),
         client: Arc::default(),
         animation_speed: AnimationClock::DEFAULT_SPEED / AnimationClock::MAX_SPEED * 100.,
+        picking: false,
+        picked_node: None,
+        show_repaints: false,
+        profiling: false,
+        frames: Vec::new(),
+        // The address can be passed as the first argument, to inspect apps on other machines
+        address: std::env::args().nth(1).unwrap_or_else(devtools_address),
+        connected: false,
+        app_protocol_version: None,
     });
     let mut radio = use_radio(DevtoolsChannel::Global);
 
@@ -69,14 +81,16 @@ pub fn app() -> impl IntoElement {
         spawn(async move {
             async fn connect(
                 mut radio: Radio<DevtoolsState, DevtoolsChannel>,
+                address: &str,
             ) -> Result<(), tungstenite::Error> {
-                let tcp_stream = TcpStream::connect("[::1]:7354").await?;
+                let tcp_stream = TcpStream::connect(address).await?;
                 let (ws_stream, _response) =
-                    async_tungstenite::client_async("ws://[::1]:7354", tcp_stream).await?;
+                    async_tungstenite::client_async(format!("ws://{address}"), tcp_stream).await?;
 
                 let (write, read) = ws_stream.split();
 
                 radio.write_silently().client.lock().await.replace(write);
+                radio.write().connected = true;
 
                 read.for_each(move |message| async move {
                     if let Ok(message) = message
@@ -84,12 +98,48 @@ pub fn app() -> impl IntoElement {
                         && let Ok(outgoing) = serde_json::from_str::<OutgoingMessage>(&text)
                     {
                         match outgoing.action {
+                            OutgoingMessageAction::Hello { protocol_version } => {
+                                radio.write().app_protocol_version = (protocol_version
+                                    != PROTOCOL_VERSION)
+                                    .then_some(protocol_version);
+                            }
                             OutgoingMessageAction::Update { window_id, nodes } => {
                                 radio
                                     .write_channel(DevtoolsChannel::UpdatedTree)
                                     .nodes
                                     .insert(window_id, nodes);
                             }
+                            OutgoingMessageAction::NodePicked { window_id, node_id } => {
+                                // Expand the ancestors so the picked element shows in the tree
+                                let mut state = radio.write_channel(DevtoolsChannel::UpdatedTree);
+                                let parents = state
+                                    .nodes
+                                    .get(&window_id)
+                                    .map(|nodes| {
+                                        nodes
+                                            .iter()
+                                            .map(|node| (node.node_id, node.parent_id))
+                                            .collect::<HashMap<_, _>>()
+                                    })
+                                    .unwrap_or_default();
+                                let mut parent_id = parents.get(&node_id).copied().flatten();
+                                while let Some(node_id) = parent_id {
+                                    state.expanded_nodes.insert((window_id, node_id));
+                                    parent_id = parents.get(&node_id).copied().flatten();
+                                }
+                                drop(state);
+
+                                let mut state = radio.write_channel(DevtoolsChannel::Global);
+                                state.picking = false;
+                                state.picked_node = Some((window_id, node_id));
+                            }
+                            OutgoingMessageAction::FrameProfiled { timings } => {
+                                let mut state = radio.write_channel(DevtoolsChannel::Profiler);
+                                if state.frames.len() >= DevtoolsState::MAX_PROFILED_FRAMES {
+                                    state.frames.remove(0);
+                                }
+                                state.frames.push(timings);
+                            }
                         }
                     }
                 })
@@ -98,9 +148,14 @@ pub fn app() -> impl IntoElement {
                 Ok(())
             }
 
+            let address = radio.read().address.clone();
             loop {
-                println!("Connecting to server...");
-                connect(radio).await.ok();
+                println!("Connecting to server in {address}...");
+                connect(radio, &address).await.ok();
+                let mut state = radio.write();
+                state.connected = false;
+                state.app_protocol_version = None;
+                drop(state);
                 radio
                     .write_channel(DevtoolsChannel::UpdatedTree)
                     .nodes
@@ -110,11 +165,32 @@ pub fn app() -> impl IntoElement {
         })
     });
 
+    let state = radio.read();
+    let status = if !state.connected {
+        Some(format!(
+            "Waiting for an app on {}. Only apps built with the devtools feature can be inspected.",
+            state.address
+        ))
+    } else {
+        state.app_protocol_version.map(|protocol_version| {
+            format!(
+                "The app uses the devtools protocol v{protocol_version} but this devtools app uses v{PROTOCOL_VERSION}, some data might be missing."
+            )
+        })
+    };
+
     rect()
         .width(Size::fill())
         .height(Size::fill())
         .color(Color::WHITE)
         .background((15, 15, 15))
+        .maybe_child(status.map(|status| {
+            rect()
+                .width(Size::fill())
+                .padding((4., 8.))
+                .background((70, 55, 20))
+                .child(label().text(status).max_lines(1))
+        }))
         .child(Router::new(|| {
             RouterConfig::<Route>::default().with_initial_path(Route::TreeInspector {})
         }))
@@ -136,6 +212,10 @@ impl Component for NavBar {
                         Route::TreeInspector {},
                         Link::new(Route::TreeInspector {}).child(SideBarItem::new().child("Tree")),
                     ))
+                    .child(ActivableRoute::new(
+                        Route::Profiler {},
+                        Link::new(Route::Profiler {}).child(SideBarItem::new().child("Profiler")),
+                    ))
                     .child(ActivableRoute::new(
                         Route::Misc {},
                         Link::new(Route::Misc {}).child(SideBarItem::new().child("Misc")),
@@ -155,6 +235,8 @@ pub enum Route {
     #[layout(NavBar)]
         #[route("/misc")]
         Misc {},
+        #[route("/profiler")]
+        Profiler {},
         #[layout(LayoutForTreeInspector)]
             #[nest("/inspector")]
                 #[route("/")]
@@ -302,7 +384,25 @@ struct LayoutForTreeInspector;
 impl Component for LayoutForTreeInspector {
     fn render(&self) -> impl IntoElement {
         let route = use_route::<Route>();
-        let radio = use_radio(DevtoolsChannel::Global);
+        let mut radio = use_radio(DevtoolsChannel::Global);
+
+        // Open the element picked in the app
+        use_side_effect(move || {
+            let picked_node = radio.read().picked_node;
+            if let Some((window_id, node_id)) = picked_node {
+                radio.write_silently().picked_node = None;
+                let _ = RouterContext::get().push(Route::NodeInspectorStyle { node_id, window_id });
+            }
+        });
+
+        let picking = radio.read().picking;
+        let on_pick = move |_| {
+            let mut state = radio.write();
+            state.picking = !state.picking;
+            state.send_action(IncomingMessageAction::SetPicking {
+                enabled: state.picking,
+            });
+        };
 
         let selected_node_id = route.node_id();
         let selected_window_id = route.window_id();
@@ -312,26 +412,34 @@ impl Component for LayoutForTreeInspector {
         ResizableContainer::new()
             .direction(Direction::Horizontal)
             .panel(
-                ResizablePanel::new(PanelSize::percent(60.)).child(rect().padding(10.).child(
-                    NodesTree {
-                        selected_node_id,
-                        selected_window_id,
-                        on_selected: EventHandler::new(move |(window_id, node_id)| {
-                            radio
-                                .read()
-                                .send_action(IncomingMessageAction::HighlightNode {
+                ResizablePanel::new(PanelSize::percent(60.)).child(
+                    rect()
+                        .padding(10.)
+                        .spacing(6.)
+                        .child(Button::new().flat().on_press(on_pick).child(if picking {
+                            "Click an element in the app..."
+                        } else {
+                            "Pick element"
+                        }))
+                        .child(NodesTree {
+                            selected_node_id,
+                            selected_window_id,
+                            on_selected: EventHandler::new(move |(window_id, node_id)| {
+                                radio
+                                    .read()
+                                    .send_action(IncomingMessageAction::HighlightNode {
+                                        window_id,
+                                        node_id,
+                                    });
+                            }),
+                            on_hover: EventHandler::new(move |(window_id, node_id)| {
+                                radio.read().send_action(IncomingMessageAction::HoverNode {
                                     window_id,
                                     node_id,
                                 });
+                            }),
                         }),
-                        on_hover: EventHandler::new(move |(window_id, node_id)| {
-                            radio.read().send_action(IncomingMessageAction::HoverNode {
-                                window_id,
-                                node_id,
-                            });
-                        }),
-                    },
-                )),
+                ),
             )
             .panel(is_expanded_vertical.then(|| {
                 ResizablePanel::new(PanelSize::px(400.))
@@ -13,6 +13,7 @@ use freya_core::{
     prelude::spawn,
 };
 use freya_devtools::{
+    FrameTimings,
     IncomingMessage,
     IncomingMessageAction,
     NodeInfo,
@@ -26,6 +27,22 @@ pub struct DevtoolsState {
     pub(crate) expanded_nodes: HashSet<(u64, NodeId)>,
     pub(crate) client: Arc<Mutex<Option<WebSocketSender<TcpStream>>>>,
     pub(crate) animation_speed: f32,
+    pub(crate) picking: bool,
+    /// Element picked in the app, waiting to be opened in the inspector.
+    pub(crate) picked_node: Option<(u64, NodeId)>,
+    pub(crate) show_repaints: bool,
+    pub(crate) profiling: bool,
+    pub(crate) frames: Vec<FrameTimings>,
+    /// Address of the devtools server of the app.
+    pub(crate) address: String,
+    pub(crate) connected: bool,
+    /// Devtools protocol version of the app, when it differs from the one of this devtools app.
+    pub(crate) app_protocol_version: Option<u32>,
+}
+
+impl DevtoolsState {
+    /// Frames kept while profiling, the oldest ones are dropped.
+    pub const MAX_PROFILED_FRAMES: usize = 3600;
 }
 
 impl DevtoolsState {
@@ -49,6 +66,7 @@ pub enum DevtoolsChannel {
     Global,
     UpdatedTree,
     Misc,
+    Profiler,
 }
 
 impl RadioChannel<DevtoolsState> for DevtoolsChannel {}
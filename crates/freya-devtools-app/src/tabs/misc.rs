@@ -19,6 +19,7 @@ impl Component for Misc {
         });
         let speed = radio.read().animation_speed;
         let normalized_speed = AnimationClock::MAX_SPEED / 100. * speed;
+        let show_repaints = radio.read().show_repaints;
 
         rect()
             .width(Size::fill())
@@ -45,5 +46,19 @@ impl Component for Misc {
                     })
                     .child("Reset"),
             )
+            .child(
+                rect()
+                    .horizontal()
+                    .cross_align(Alignment::center())
+                    .spacing(6.)
+                    .child(Switch::new().toggled(show_repaints).on_toggle(move |_| {
+                        let mut state = radio.write();
+                        state.show_repaints = !state.show_repaints;
+                        state.send_action(IncomingMessageAction::SetShowRepaints {
+                            enabled: state.show_repaints,
+                        });
+                    }))
+                    .child("Show repaints"),
+            )
     }
 }
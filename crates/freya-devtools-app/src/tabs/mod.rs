@@ -1,6 +1,7 @@
 pub mod computed_layout;
 pub mod layout;
 pub mod misc;
+pub mod profiler;
 pub mod style;
 pub mod text_style;
 pub mod tree;
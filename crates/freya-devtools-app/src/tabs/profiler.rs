@@ -0,0 +1,144 @@
+use freya::prelude::*;
+use freya_devtools::{
+    FramePhase,
+    FrameTimings,
+    IncomingMessageAction,
+    chrome_trace,
+};
+use freya_radio::hooks::use_radio;
+
+use crate::state::DevtoolsChannel;
+
+/// Frames shown in the chart.
+const CHART_FRAMES: usize = 120;
+const CHART_HEIGHT: f32 = 200.;
+/// Budget of a frame at 60fps, in microseconds.
+const FRAME_BUDGET: u64 = 16_667;
+const TRACE_PATH: &str = "freya-trace.json";
+
+fn phase_color(phase: FramePhase) -> Color {
+    match phase {
+        FramePhase::VdomPoll => Color::from_rgb(230, 200, 90),
+        FramePhase::TreeUpdates => Color::from_rgb(86, 156, 214),
+        FramePhase::Layout => Color::from_rgb(220, 160, 80),
+        FramePhase::Paint => Color::from_rgb(106, 190, 120),
+        FramePhase::Accessibility => Color::from_rgb(190, 120, 210),
+        FramePhase::Presenting => Color::from_rgb(210, 90, 90),
+    }
+}
+
+fn frame_bar(frame: &FrameTimings, max_duration: u64) -> impl IntoElement {
+    rect()
+        .width(Size::px(4.))
+        .height(Size::fill())
+        .main_align(Alignment::end())
+        .children(FramePhase::ALL.iter().rev().map(|phase| {
+            let duration = frame.phase_duration(*phase);
+            let height = duration as f32 / max_duration as f32 * CHART_HEIGHT;
+            rect()
+                .width(Size::fill())
+                .height(Size::px(height))
+                .background(phase_color(*phase))
+                .into()
+        }))
+}
+
+fn phase_legend(phase: FramePhase, frames: &[FrameTimings]) -> impl IntoElement {
+    let total: u64 = frames.iter().map(|frame| frame.phase_duration(phase)).sum();
+    let average = total as f32 / frames.len().max(1) as f32 / 1000.;
+    rect()
+        .horizontal()
+        .cross_align(Alignment::center())
+        .spacing(6.)
+        .child(
+            rect()
+                .width(Size::px(10.))
+                .height(Size::px(10.))
+                .background(phase_color(phase)),
+        )
+        .child(format!("{}: {average:.2}ms", phase.name()))
+}
+
+#[derive(PartialEq)]
+pub struct Profiler;
+impl Component for Profiler {
+    fn render(&self) -> impl IntoElement {
+        let mut radio = use_radio(DevtoolsChannel::Profiler);
+        let mut export_status = use_state(|| None::<String>);
+
+        let state = radio.read();
+        let profiling = state.profiling;
+        let shown_frames = &state.frames[state.frames.len().saturating_sub(CHART_FRAMES)..];
+        let max_duration = shown_frames
+            .iter()
+            .map(FrameTimings::duration)
+            .max()
+            .unwrap_or_default()
+            .max(FRAME_BUDGET);
+
+        let on_record = move |_| {
+            let mut state = radio.write();
+            state.profiling = !state.profiling;
+            if state.profiling {
+                state.frames.clear();
+            }
+            state.send_action(IncomingMessageAction::SetProfiling {
+                enabled: state.profiling,
+            });
+        };
+
+        let on_export = move |_| {
+            let trace = chrome_trace(&radio.read().frames);
+            let status = serde_json::to_string(&trace)
+                .map_err(|err| err.to_string())
+                .and_then(|json| std::fs::write(TRACE_PATH, json).map_err(|err| err.to_string()));
+            *export_status.write() = Some(match status {
+                Ok(()) => format!("Exported to {TRACE_PATH}"),
+                Err(err) => format!("Failed to export: {err}"),
+            });
+        };
+
+        rect()
+            .width(Size::fill())
+            .height(Size::fill())
+            .padding(8.)
+            .spacing(8.)
+            .child(
+                rect()
+                    .horizontal()
+                    .cross_align(Alignment::center())
+                    .spacing(6.)
+                    .child(Button::new().on_press(on_record).child(if profiling {
+                        "Stop"
+                    } else {
+                        "Record"
+                    }))
+                    .child(Button::new().on_press(on_export).child("Export trace"))
+                    .child(export_status.read().clone().unwrap_or_default()),
+            )
+            .child(format!(
+                "{} frames, scale {:.2}ms",
+                state.frames.len(),
+                max_duration as f32 / 1000.
+            ))
+            .child(
+                rect()
+                    .horizontal()
+                    .width(Size::fill())
+                    .height(Size::px(CHART_HEIGHT))
+                    .background((25, 25, 25))
+                    .spacing(1.)
+                    .overflow(Overflow::Clip)
+                    .children(
+                        shown_frames
+                            .iter()
+                            .map(|frame| frame_bar(frame, max_duration).into()),
+                    ),
+            )
+            .children(
+                FramePhase::ALL
+                    .into_iter()
+                    .map(|phase| phase_legend(phase, shown_frames).into()),
+            )
+    }
+}
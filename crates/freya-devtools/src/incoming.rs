@@ -22,4 +22,16 @@ pub enum IncomingMessageAction {
     SetSpeedTo {
         speed: f32,
     },
+    /// Pick the next clicked element of the app, highlighting the one under the cursor.
+    SetPicking {
+        enabled: bool,
+    },
+    /// Measure the phases of every frame.
+    SetProfiling {
+        enabled: bool,
+    },
+    /// Tint the elements repainted every frame.
+    SetShowRepaints {
+        enabled: bool,
+    },
 }
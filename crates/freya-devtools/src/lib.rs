@@ -1,6 +1,8 @@
 mod incoming;
 mod node_info;
 mod outgoing;
+mod profiler;
+mod protocol;
 
 #[cfg(feature = "server")]
 mod plugin;
@@ -12,3 +14,5 @@ pub use node_info::*;
 pub use outgoing::*;
 #[cfg(feature = "server")]
 pub use plugin::*;
+pub use profiler::*;
+pub use protocol::*;
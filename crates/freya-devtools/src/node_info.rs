@@ -112,6 +112,10 @@ impl NodeStateAttributes for NodeState {
             ),
             ("content", AttributeType::Content(&self.layout.content)),
             ("spacing", AttributeType::Length(self.layout.spacing)),
+            (
+                "wrap_alignment",
+                AttributeType::Alignment(&self.layout.wrap_alignment),
+            ),
         ]
     }
     fn style_attributes(&'_ self) -> Vec<(&'_ str, AttributeType<'_>)> {
@@ -184,8 +188,37 @@ impl NodeStateAttributes for NodeState {
                 "text_decoration",
                 AttributeType::TextDecoration(self.text_style.text_decoration),
             ),
+            (
+                "letter_spacing",
+                AttributeType::Measure(self.text_style.letter_spacing.into()),
+            ),
+            (
+                "word_spacing",
+                AttributeType::Measure(self.text_style.word_spacing.into()),
+            ),
+            (
+                "overflow_wrap",
+                AttributeType::Text(self.text_style.overflow_wrap.pretty()),
+            ),
+            (
+                "hyphenation",
+                AttributeType::Text(self.text_style.hyphenation.pretty()),
+            ),
         ];
 
+        if !self.text_style.font_features.0.is_empty() {
+            let font_features = self
+                .text_style
+                .font_features
+                .iter()
+                .map(|feature| feature.to_string())
+                .collect::<Vec<_>>();
+            attributes.push((
+                "font_features",
+                AttributeType::Text(font_features.join(", ")),
+            ));
+        }
+
         for shadow in self.style.shadows.iter() {
             attributes.push(("shadow", AttributeType::Shadow(shadow)));
         }
@@ -1,9 +1,13 @@
+use freya_core::integration::NodeId;
 use serde::{
     Deserialize,
     Serialize,
 };
 
-use crate::node_info::NodeInfo;
+use crate::{
+    node_info::NodeInfo,
+    profiler::FrameTimings,
+};
 
 #[derive(Deserialize, Serialize)]
 pub struct OutgoingMessage {
@@ -12,8 +16,14 @@ pub struct OutgoingMessage {
 
 #[derive(Deserialize, Serialize)]
 pub enum OutgoingMessageAction {
+    /// First message sent to every client.
+    Hello { protocol_version: u32 },
     Update {
         window_id: u64,
         nodes: Vec<NodeInfo>,
     },
+    /// An element of the app was clicked while picking.
+    NodePicked { window_id: u64, node_id: NodeId },
+    /// A frame was rendered while profiling.
+    FrameProfiled { timings: FrameTimings },
 }
@@ -4,12 +4,19 @@ use std::{
     sync::{
         Arc,
         Mutex,
+        atomic::{
+            AtomicBool,
+            Ordering,
+        },
     },
+    time::Instant,
 };
 
 use async_tungstenite::WebSocketSender;
 use freya_core::{
     integration::{
+        MouseButton,
+        MouseEventName,
         NodeId,
         Tree,
     },
@@ -19,8 +26,11 @@ use freya_core::{
     },
 };
 use freya_engine::prelude::{
+    Canvas,
+    ClipOp,
     Paint,
     PaintStyle,
+    SkRect,
 };
 use freya_winit::{
     plugins::{
@@ -28,17 +38,29 @@ use freya_winit::{
         PluginEvent,
         PluginHandle,
     },
-    reexports::winit::window::WindowId,
+    reexports::winit::window::{
+        Window,
+        WindowId,
+    },
 };
 use smol::net::TcpStream;
-use torin::prelude::Area;
+use torin::prelude::{
+    Area,
+    CursorPoint,
+    LayoutNode,
+};
 use tungstenite::Message;
 
 use crate::{
     NodeState,
     OutgoingMessage,
     OutgoingMessageAction,
+    devtools_address,
     node_info::NodeInfo,
+    profiler::{
+        FramePhase,
+        FrameProfiler,
+    },
     server::run_server,
 };
 
@@ -57,9 +79,22 @@ pub struct DevtoolsPlugin {
     websockets: SharedWebsockets,
     highlighted_node: Arc<Mutex<Option<NodeId>>>,
     hovered_node: Arc<Mutex<Option<NodeId>>>,
+    picking: Arc<AtomicBool>,
+    /// Keeps the release of the press that picked an element from reaching the app.
+    capture_release: bool,
+    profiling: Arc<AtomicBool>,
+    profilers: HashMap<u64, FrameProfiler>,
+    profiling_started: Option<Instant>,
+    address: Option<String>,
 }
 
 impl DevtoolsPlugin {
+    /// Listen on the given address instead of [devtools_address].
+    pub fn with_address(mut self, address: impl Into<String>) -> Self {
+        self.address = Some(address.into());
+        self
+    }
+
     pub fn draw_wireframe(
         canvas: &freya_engine::prelude::Canvas,
         area: &Area,
@@ -95,6 +130,92 @@ impl DevtoolsPlugin {
         canvas.draw_line((x + 1.0, y2), (x + 1.0, y), &paint_inner);
     }
 
+    /// Fill the margin, padding and content boxes of a node and outline its borders.
+    pub fn draw_box_model(canvas: &Canvas, layout_node: &LayoutNode) {
+        let visible_area = layout_node.visible_area();
+        let inner_area = layout_node.inner_area.cast_unit();
+
+        Self::fill_area(
+            canvas,
+            &layout_node.area,
+            Some(&visible_area),
+            Color::from_af32rgb(0.5, 246, 178, 107),
+        );
+        Self::fill_area(
+            canvas,
+            &visible_area,
+            Some(&inner_area),
+            Color::from_af32rgb(0.5, 147, 196, 125),
+        );
+        Self::fill_area(
+            canvas,
+            &inner_area,
+            None,
+            Color::from_af32rgb(0.5, 111, 168, 220),
+        );
+        Self::draw_wireframe(canvas, &visible_area, Color::RED, Color::BLUE);
+    }
+
+    /// Fill `area` except for the `hole` inside of it.
+    fn fill_area(canvas: &Canvas, area: &Area, hole: Option<&Area>, color: Color) {
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        paint.set_style(PaintStyle::Fill);
+        paint.set_color(color);
+
+        canvas.save();
+        if let Some(hole) = hole {
+            canvas.clip_rect(
+                SkRect::new(hole.min_x(), hole.min_y(), hole.max_x(), hole.max_y()),
+                ClipOp::Difference,
+                true,
+            );
+        }
+        canvas.draw_rect(
+            SkRect::new(area.min_x(), area.min_y(), area.max_x(), area.max_y()),
+            &paint,
+        );
+        canvas.restore();
+    }
+
+    /// Topmost node whose visible area contains the cursor.
+    fn node_at(tree: &Tree, cursor: CursorPoint) -> Option<NodeId> {
+        let cursor = cursor.to_f32();
+        let mut found = None;
+        tree.traverse_depth(|node_id| {
+            let Some(layout_node) = tree.layout.get(&node_id) else {
+                return;
+            };
+            if !layout_node.visible_area().contains(cursor) {
+                return;
+            }
+            let layer = tree.layer_state.get(&node_id).map(|s| s.layer).unwrap_or(0);
+            if found.is_none_or(|(found_layer, _)| layer >= found_layer) {
+                found = Some((layer, node_id));
+            }
+        });
+        found.map(|(_, node_id)| node_id)
+    }
+
+    /// Measure the start or finish of a phase of the current frame, if profiling.
+    fn profile(&mut self, window: &Window, phase: FramePhase, is_start: bool) {
+        if !self.profiling.load(Ordering::Relaxed) {
+            self.profilers.clear();
+            self.profiling_started = None;
+            return;
+        }
+        let started = *self.profiling_started.get_or_insert_with(Instant::now);
+        let profiler = self
+            .profilers
+            .entry(window.id().into())
+            .or_insert_with(|| FrameProfiler::new(started));
+        if is_start {
+            profiler.start(phase);
+        } else {
+            profiler.finish(phase);
+        }
+    }
+
     /// Serializes and broadcasts a message to all connected devtools clients.
     fn broadcast(&self, message: OutgoingMessage) {
         let Ok(serialized) = serde_json::to_string(&message) else {
@@ -134,12 +255,18 @@ impl DevtoolsPlugin {
             let websockets = self.websockets.clone();
             let highlighted_node = self.highlighted_node.clone();
             let hovered_node = self.hovered_node.clone();
+            let picking = self.picking.clone();
+            let profiling = self.profiling.clone();
+            let address = self.address.clone().unwrap_or_else(devtools_address);
             smol::spawn(async move {
                 if let Err(err) = run_server(
+                    address,
                     nodes,
                     websockets,
                     highlighted_node,
                     hovered_node,
+                    picking,
+                    profiling,
                     plugin_handle,
                 )
                 .await
@@ -222,6 +349,8 @@ impl FreyaPlugin for DevtoolsPlugin {
                 canvas,
                 ..
             } => {
+                self.profile(window, FramePhase::Paint, false);
+
                 let highlighted_node = *self.highlighted_node.lock().unwrap();
                 let hovered_node = *self.hovered_node.lock().unwrap();
 
@@ -236,12 +365,56 @@ impl FreyaPlugin for DevtoolsPlugin {
                 }
 
                 if let Some(layout_node) = hovered_node.and_then(|n| tree.layout.get(&n)) {
-                    let area = layout_node.visible_area();
-                    Self::draw_wireframe(canvas, &area, Color::RED, Color::BLUE);
+                    if self.picking.load(Ordering::Relaxed) {
+                        Self::draw_box_model(canvas, layout_node);
+                    } else {
+                        let area = layout_node.visible_area();
+                        Self::draw_wireframe(canvas, &area, Color::RED, Color::BLUE);
+                    }
                 }
 
                 self.sync(window.id(), window.scale_factor() as f32, tree);
             }
+            PluginEvent::PointerInput {
+                window,
+                tree,
+                name,
+                cursor,
+                button,
+                captured,
+            } => {
+                if !self.picking.load(Ordering::Relaxed) {
+                    if *name == MouseEventName::MouseUp && self.capture_release {
+                        self.capture_release = false;
+                        **captured = true;
+                    }
+                    return;
+                }
+
+                let node_id = Self::node_at(tree, *cursor);
+                match name {
+                    MouseEventName::MouseMove => {
+                        *self.hovered_node.lock().unwrap() = node_id;
+                    }
+                    MouseEventName::MouseDown if *button == Some(MouseButton::Left) => {
+                        if let Some(node_id) = node_id {
+                            self.picking.store(false, Ordering::Relaxed);
+                            self.capture_release = true;
+                            *self.hovered_node.lock().unwrap() = None;
+                            self.highlighted_node.lock().unwrap().replace(node_id);
+                            self.broadcast(OutgoingMessage {
+                                action: OutgoingMessageAction::NodePicked {
+                                    window_id: window.id().into(),
+                                    node_id,
+                                },
+                            });
+                        }
+                    }
+                    _ => {}
+                }
+                window.request_redraw();
+                **captured = true;
+            }
             PluginEvent::WindowCreated {
                 window,
                 animation_clock,
@@ -249,6 +422,51 @@ impl FreyaPlugin for DevtoolsPlugin {
             } => {
                 self.init(window.id(), animation_clock, plugin_handle);
             }
+            PluginEvent::StartedPollingRunner { window, .. } => {
+                self.profile(window, FramePhase::VdomPoll, true);
+            }
+            PluginEvent::FinishedPollingRunner { window, .. } => {
+                self.profile(window, FramePhase::VdomPoll, false);
+            }
+            PluginEvent::StartedUpdatingTree { window, .. } => {
+                self.profile(window, FramePhase::TreeUpdates, true);
+            }
+            PluginEvent::FinishedUpdatingTree { window, .. } => {
+                self.profile(window, FramePhase::TreeUpdates, false);
+            }
+            PluginEvent::StartedMeasuringLayout { window, .. } => {
+                self.profile(window, FramePhase::Layout, true);
+            }
+            PluginEvent::FinishedMeasuringLayout { window, .. } => {
+                self.profile(window, FramePhase::Layout, false);
+            }
+            PluginEvent::BeforeRender { window, .. } => {
+                self.profile(window, FramePhase::Paint, true);
+            }
+            PluginEvent::BeforeAccessibility { window, .. } => {
+                self.profile(window, FramePhase::Accessibility, true);
+            }
+            PluginEvent::AfterAccessibility { window, .. } => {
+                self.profile(window, FramePhase::Accessibility, false);
+            }
+            PluginEvent::BeforePresenting { window, .. } => {
+                self.profile(window, FramePhase::Presenting, true);
+            }
+            PluginEvent::AfterPresenting { window, .. } => {
+                self.profile(window, FramePhase::Presenting, false);
+            }
+            PluginEvent::AfterRedraw { window, .. } => {
+                let window_id: u64 = window.id().into();
+                if let Some(timings) = self
+                    .profilers
+                    .get_mut(&window_id)
+                    .and_then(|profiler| profiler.end_frame(window_id))
+                {
+                    self.broadcast(OutgoingMessage {
+                        action: OutgoingMessageAction::FrameProfiled { timings },
+                    });
+                }
+            }
             _ => {}
         }
     }
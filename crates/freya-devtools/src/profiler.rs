@@ -0,0 +1,267 @@
+use std::{
+    collections::HashMap,
+    time::Instant,
+};
+
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+/// Phase of a frame measured by the profiler.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FramePhase {
+    /// Running the queued event handlers and async tasks of the components.
+    VdomPoll,
+    /// Applying the changes of the components to the tree.
+    TreeUpdates,
+    Layout,
+    Paint,
+    Accessibility,
+    /// Submitting the frame to the GPU and presenting it.
+    Presenting,
+}
+
+impl FramePhase {
+    pub const ALL: [FramePhase; 6] = [
+        Self::VdomPoll,
+        Self::TreeUpdates,
+        Self::Layout,
+        Self::Paint,
+        Self::Accessibility,
+        Self::Presenting,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::VdomPoll => "VDOM poll",
+            Self::TreeUpdates => "Tree updates",
+            Self::Layout => "Layout",
+            Self::Paint => "Paint",
+            Self::Accessibility => "Accessibility",
+            Self::Presenting => "Presenting",
+        }
+    }
+}
+
+/// Time spent in a [FramePhase], in microseconds.
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq)]
+pub struct PhaseSpan {
+    pub phase: FramePhase,
+    /// Since the profiler started.
+    pub start: u64,
+    pub duration: u64,
+}
+
+/// Phases of a single frame of a window.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct FrameTimings {
+    pub window_id: u64,
+    pub spans: Vec<PhaseSpan>,
+}
+
+impl FrameTimings {
+    /// Total time spent in the given phase, in microseconds.
+    pub fn phase_duration(&self, phase: FramePhase) -> u64 {
+        self.spans
+            .iter()
+            .filter(|span| span.phase == phase)
+            .map(|span| span.duration)
+            .sum()
+    }
+
+    /// Total time spent in all the phases, in microseconds.
+    pub fn duration(&self) -> u64 {
+        self.spans.iter().map(|span| span.duration).sum()
+    }
+}
+
+/// Event of the Chrome trace format, see [chrome_trace].
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct TraceEvent {
+    pub name: String,
+    pub cat: String,
+    pub ph: String,
+    pub ts: u64,
+    pub dur: u64,
+    pub pid: u64,
+    pub tid: u64,
+}
+
+/// Trace in the Chrome trace format, serialize it to JSON and open it in `chrome://tracing`
+/// or [Perfetto](https://ui.perfetto.dev).
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+pub struct ChromeTrace {
+    #[serde(rename = "traceEvents")]
+    pub trace_events: Vec<TraceEvent>,
+}
+
+/// Convert the profiled frames to a [ChromeTrace], with a track for every window.
+pub fn chrome_trace(frames: &[FrameTimings]) -> ChromeTrace {
+    let trace_events = frames
+        .iter()
+        .flat_map(|frame| {
+            frame.spans.iter().map(|span| TraceEvent {
+                name: span.phase.name().to_string(),
+                cat: "frame".to_string(),
+                ph: "X".to_string(),
+                ts: span.start,
+                dur: span.duration,
+                pid: 0,
+                tid: frame.window_id,
+            })
+        })
+        .collect();
+    ChromeTrace { trace_events }
+}
+
+/// Measures the phases of the frames of a window.
+#[cfg(feature = "server")]
+pub(crate) struct FrameProfiler {
+    started: Instant,
+    running: HashMap<FramePhase, Instant>,
+    spans: Vec<PhaseSpan>,
+}
+
+#[cfg(feature = "server")]
+impl FrameProfiler {
+    pub(crate) fn new(started: Instant) -> Self {
+        Self {
+            started,
+            running: HashMap::new(),
+            spans: Vec::new(),
+        }
+    }
+
+    pub(crate) fn start(&mut self, phase: FramePhase) {
+        self.running.insert(phase, Instant::now());
+    }
+
+    pub(crate) fn finish(&mut self, phase: FramePhase) {
+        if let Some(started) = self.running.remove(&phase) {
+            self.spans.push(PhaseSpan {
+                phase,
+                start: started.duration_since(self.started).as_micros() as u64,
+                duration: started.elapsed().as_micros() as u64,
+            });
+        }
+    }
+
+    /// Take the phases measured since the last frame.
+    pub(crate) fn end_frame(&mut self, window_id: u64) -> Option<FrameTimings> {
+        if self.spans.is_empty() {
+            return None;
+        }
+        Some(FrameTimings {
+            window_id,
+            spans: std::mem::take(&mut self.spans),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn chrome_trace_events() {
+        let frames = [
+            FrameTimings {
+                window_id: 1,
+                spans: vec![
+                    PhaseSpan {
+                        phase: FramePhase::VdomPoll,
+                        start: 0,
+                        duration: 40,
+                    },
+                    PhaseSpan {
+                        phase: FramePhase::Layout,
+                        start: 50,
+                        duration: 100,
+                    },
+                ],
+            },
+            FrameTimings {
+                window_id: 2,
+                spans: vec![PhaseSpan {
+                    phase: FramePhase::Paint,
+                    start: 200,
+                    duration: 10,
+                }],
+            },
+        ];
+        assert_eq!(frames[0].duration(), 140);
+        assert_eq!(frames[0].phase_duration(FramePhase::Layout), 100);
+        assert_eq!(frames[0].phase_duration(FramePhase::Paint), 0);
+
+        let trace = chrome_trace(&frames);
+        assert_eq!(
+            trace.trace_events,
+            vec![
+                TraceEvent {
+                    name: "VDOM poll".to_string(),
+                    cat: "frame".to_string(),
+                    ph: "X".to_string(),
+                    ts: 0,
+                    dur: 40,
+                    pid: 0,
+                    tid: 1,
+                },
+                TraceEvent {
+                    name: "Layout".to_string(),
+                    cat: "frame".to_string(),
+                    ph: "X".to_string(),
+                    ts: 50,
+                    dur: 100,
+                    pid: 0,
+                    tid: 1,
+                },
+                TraceEvent {
+                    name: "Paint".to_string(),
+                    cat: "frame".to_string(),
+                    ph: "X".to_string(),
+                    ts: 200,
+                    dur: 10,
+                    pid: 0,
+                    tid: 2,
+                },
+            ]
+        );
+
+        #[cfg(feature = "server")]
+        {
+            let json = serde_json::to_value(&trace).unwrap();
+            assert_eq!(json["traceEvents"][1]["name"], "Layout");
+            assert_eq!(json["traceEvents"][2]["tid"], 2);
+        }
+    }
+
+    #[cfg(feature = "server")]
+    #[test]
+    fn frame_profiler_spans() {
+        let mut profiler = FrameProfiler::new(Instant::now());
+        assert_eq!(profiler.end_frame(1), None);
+
+        profiler.start(FramePhase::VdomPoll);
+        profiler.finish(FramePhase::VdomPoll);
+        // Phases that never started are not measured
+        profiler.finish(FramePhase::Layout);
+        profiler.start(FramePhase::Paint);
+        profiler.finish(FramePhase::Paint);
+
+        let frame = profiler.end_frame(1).unwrap();
+        assert_eq!(frame.window_id, 1);
+        assert_eq!(
+            frame
+                .spans
+                .iter()
+                .map(|span| span.phase)
+                .collect::<Vec<_>>(),
+            vec![FramePhase::VdomPoll, FramePhase::Paint]
+        );
+        assert!(frame.spans[0].start <= frame.spans[1].start);
+
+        // The spans are taken by the frame
+        assert_eq!(profiler.end_frame(1), None);
+    }
+}
@@ -0,0 +1,17 @@
+/// Address the devtools server listens on, and the devtools app connects to, by default.
+pub const DEFAULT_DEVTOOLS_ADDRESS: &str = "[::1]:7354";
+
+/// Environment variable to override [DEFAULT_DEVTOOLS_ADDRESS], for example with `0.0.0.0:7354`
+/// to inspect an app running on another machine.
+///
+/// The server has no authentication, binding it to `0.0.0.0` lets anyone on the network control the app.
+pub const DEVTOOLS_ADDRESS_ENV: &str = "FREYA_DEVTOOLS_ADDRESS";
+
+/// Version of the messages exchanged by the devtools server and app.
+/// Bumped on every breaking change to [crate::IncomingMessage] or [crate::OutgoingMessage].
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Address from [DEVTOOLS_ADDRESS_ENV] or [DEFAULT_DEVTOOLS_ADDRESS].
+pub fn devtools_address() -> String {
+    std::env::var(DEVTOOLS_ADDRESS_ENV).unwrap_or_else(|_| DEFAULT_DEVTOOLS_ADDRESS.to_string())
+}
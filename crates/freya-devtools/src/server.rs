@@ -4,6 +4,7 @@ use std::{
         Arc,
         Mutex,
         atomic::{
+            AtomicBool,
             AtomicU32,
             Ordering,
         },
@@ -32,6 +33,7 @@ use crate::{
     IncomingMessage,
     OutgoingMessage,
     OutgoingMessageAction,
+    PROTOCOL_VERSION,
     SharedWebsockets,
     WindowState,
     incoming::IncomingMessageAction,
@@ -46,11 +48,20 @@ async fn handle_connection(
     websockets: SharedWebsockets,
     highlighted_node: Arc<Mutex<Option<NodeId>>>,
     hovered_node: Arc<Mutex<Option<NodeId>>>,
+    picking: Arc<AtomicBool>,
+    profiling: Arc<AtomicBool>,
     plugin_handle: PluginHandle,
 ) -> anyhow::Result<()> {
     let ws_stream = accept_async(stream).await?;
     let (mut write, mut read) = ws_stream.split();
 
+    let hello = serde_json::to_string(&OutgoingMessage {
+        action: OutgoingMessageAction::Hello {
+            protocol_version: PROTOCOL_VERSION,
+        },
+    })?;
+    write.send(Message::Text(hello.into())).await?;
+
     let windows_snapshot = windows.lock().unwrap().clone();
     for (window_id, WindowState { nodes, .. }) in windows_snapshot {
         let message = Message::Text(
@@ -91,6 +102,37 @@ async fn handle_connection(
                                 },
                             ));
                         }
+                        IncomingMessageAction::SetPicking { enabled } => {
+                            picking.store(enabled, Ordering::Relaxed);
+                            if !enabled {
+                                *hovered_node.lock().unwrap() = None;
+                            }
+                            for window_id in windows.lock().unwrap().keys() {
+                                plugin_handle.send_event_loop_event(NativeEvent::Window(
+                                    NativeWindowEvent {
+                                        window_id: (*window_id).into(),
+                                        action: NativeWindowEventAction::User(
+                                            UserEvent::RequestRedraw,
+                                        ),
+                                    },
+                                ));
+                            }
+                        }
+                        IncomingMessageAction::SetProfiling { enabled } => {
+                            profiling.store(enabled, Ordering::Relaxed);
+                        }
+                        IncomingMessageAction::SetShowRepaints { enabled } => {
+                            for window_id in windows.lock().unwrap().keys() {
+                                plugin_handle.send_event_loop_event(NativeEvent::Window(
+                                    NativeWindowEvent {
+                                        window_id: (*window_id).into(),
+                                        action: NativeWindowEventAction::User(
+                                            UserEvent::ShowRepaints(enabled),
+                                        ),
+                                    },
+                                ));
+                            }
+                        }
                         IncomingMessageAction::SetSpeedTo { speed } => {
                             for WindowState {
                                 animation_clock, ..
@@ -114,21 +156,26 @@ async fn handle_connection(
 }
 
 pub async fn run_server(
+    address: String,
     windows: Arc<Mutex<HashMap<u64, WindowState>>>,
     websockets: SharedWebsockets,
     highlighted_node: Arc<Mutex<Option<NodeId>>>,
     hovered_node: Arc<Mutex<Option<NodeId>>>,
+    picking: Arc<AtomicBool>,
+    profiling: Arc<AtomicBool>,
     plugin_handle: PluginHandle,
 ) -> anyhow::Result<()> {
-    println!("Running the Devtools Server in [::1]:7354");
+    println!("Running the Devtools Server in {address}");
 
-    let listener = TcpListener::bind("[::1]:7354").await?;
+    let listener = TcpListener::bind(address).await?;
     loop {
         let (stream, _) = listener.accept().await?;
         let windows = windows.clone();
         let websockets = websockets.clone();
         let highlighted_node = highlighted_node.clone();
         let hovered_node = hovered_node.clone();
+        let picking = picking.clone();
+        let profiling = profiling.clone();
         let plugin_handle = plugin_handle.clone();
         smol::spawn(async move {
             let id = WEBSOCKET_ID.fetch_add(1, Ordering::Relaxed);
@@ -139,6 +186,8 @@ pub async fn run_server(
                 websockets.clone(),
                 highlighted_node,
                 hovered_node,
+                picking,
+                profiling,
                 plugin_handle,
             )
             .await
@@ -4,6 +4,7 @@ pub struct EditableConfig {
     pub(crate) allow_tabs: bool,
     pub(crate) allow_changes: bool,
     pub(crate) allow_clipboard: bool,
+    pub(crate) allow_formatting: bool,
 }
 
 impl Default for EditableConfig {
@@ -20,6 +21,7 @@ impl EditableConfig {
             allow_tabs: false,
             allow_changes: true,
             allow_clipboard: true,
+            allow_formatting: false,
         }
     }
 
@@ -46,4 +48,10 @@ impl EditableConfig {
         self.allow_clipboard = allow_clipboard;
         self
     }
+
+    /// Allow toggling bold and italic text with the keyboard
+    pub fn with_allow_formatting(mut self, allow_formatting: bool) -> Self {
+        self.allow_formatting = allow_formatting;
+        self
+    }
 }
@@ -5,26 +5,42 @@ use std::time::{
 
 use ropey::Rope;
 
-use crate::text_editor::TextSelection;
+use crate::{
+    rich_text::TextRuns,
+    text_editor::TextSelection,
+};
 
+/// A change to the text, the `runs` are the attributes of the inserted or removed text
+/// and are left empty by editors without rich text.
 #[derive(Clone, Debug, PartialEq)]
 pub enum HistoryChange {
     InsertChar {
         idx: usize,
         len: usize,
         ch: char,
+        runs: TextRuns,
         selection: TextSelection,
     },
     InsertText {
         idx: usize,
         len: usize,
         text: String,
+        runs: TextRuns,
         selection: TextSelection,
     },
     Remove {
         idx: usize,
         len: usize,
         text: String,
+        runs: TextRuns,
+        selection: TextSelection,
+    },
+    /// Attributes of the text in a range changed, without changing the text itself.
+    Format {
+        idx: usize,
+        len: usize,
+        before: TextRuns,
+        after: TextRuns,
         selection: TextSelection,
     },
 }
@@ -138,6 +154,12 @@ impl EditorHistory {
                         rope.remove(start..end);
                         selection = Some(previous_selection.clone());
                     }
+                    HistoryChange::Format {
+                        selection: previous_selection,
+                        ..
+                    } => {
+                        selection = Some(previous_selection.clone());
+                    }
                 }
             }
 
@@ -175,6 +197,7 @@ impl EditorHistory {
                         rope.insert(start, text);
                         *idx + len
                     }
+                    HistoryChange::Format { idx, len, .. } => *idx + len,
                 });
             }
             self.current_transaction += 1;
@@ -208,7 +231,10 @@ mod test {
         EditorHistory,
         HistoryChange,
     };
-    use crate::text_editor::TextSelection;
+    use crate::{
+        rich_text::TextRuns,
+        text_editor::TextSelection,
+    };
 
     #[test]
     fn test_undo_redo() {
@@ -225,6 +251,7 @@ mod test {
             idx: 11,
             text: "\n!!!!".to_owned(),
             len: "\n!!!!".len(),
+            runs: TextRuns::default(),
             selection: TextSelection::new_cursor(11),
         });
 
@@ -243,6 +270,7 @@ mod test {
             idx: 11,
             text: "\n!!!!".to_owned(),
             len: "\n!!!!".len(),
+            runs: TextRuns::default(),
             selection: TextSelection::new_cursor(11),
         });
         rope.insert(16, "\n!!!!");
@@ -250,6 +278,7 @@ mod test {
             idx: 16,
             text: "\n!!!!".to_owned(),
             len: "\n!!!!".len(),
+            runs: TextRuns::default(),
             selection: TextSelection::new_cursor(16),
         });
         rope.insert(21, "\n!!!!");
@@ -257,6 +286,7 @@ mod test {
             idx: 21,
             text: "\n!!!!".to_owned(),
             len: "\n!!!!".len(),
+            runs: TextRuns::default(),
             selection: TextSelection::new_cursor(21),
         });
 
@@ -301,6 +331,7 @@ mod test {
             idx: 0,
             ch: '.',
             len: 1,
+            runs: TextRuns::default(),
             selection: TextSelection::new_cursor(0),
         });
         assert_eq!(history.any_pending_changes(), 0);
@@ -318,6 +349,7 @@ mod test {
             idx: 11,
             ch: '!',
             len: 1,
+            runs: TextRuns::default(),
             selection: TextSelection::new_cursor(11),
         });
 
@@ -340,6 +372,7 @@ mod test {
             idx: 0,
             text: "Hello".to_owned(),
             len: 5,
+            runs: TextRuns::default(),
             selection: TextSelection::new_range((0, 5)),
         });
         assert_eq!(rope.to_string(), " World");
@@ -361,6 +394,7 @@ mod test {
             idx: 5,
             text: " World".to_owned(),
             len: 6,
+            runs: TextRuns::default(),
             selection: TextSelection::new_cursor(5),
         });
 
@@ -386,6 +420,7 @@ mod test {
             idx: 5,
             text: " World".to_owned(),
             len: 6,
+            runs: TextRuns::default(),
             selection: TextSelection::new_cursor(11),
         });
         assert_eq!(rope.to_string(), "Hello");
@@ -1,6 +1,7 @@
 mod config;
 mod editor_history;
 mod event;
+mod rich_text;
 mod rope_editor;
 mod text_editor;
 mod use_editable;
@@ -9,6 +10,7 @@ pub use config::*;
 pub use editor_history::*;
 pub use event::*;
 pub use freya_clipboard::prelude::*;
+pub use rich_text::*;
 pub use rope_editor::*;
 pub use ropey::{
     Rope,
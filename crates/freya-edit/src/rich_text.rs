@@ -0,0 +1,340 @@
+use std::ops::Range;
+
+use freya_core::prelude::{
+    Color,
+    FontSlant,
+    FontWeight,
+    Span,
+    TextStyleExt,
+};
+
+/// Inline styling of a run of text in a [RopeEditor](crate::RopeEditor).
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
+pub struct TextAttributes {
+    pub bold: bool,
+    pub italic: bool,
+    pub color: Option<Color>,
+}
+
+impl TextAttributes {
+    pub fn bold(mut self, bold: bool) -> Self {
+        self.bold = bold;
+        self
+    }
+
+    pub fn italic(mut self, italic: bool) -> Self {
+        self.italic = italic;
+        self
+    }
+
+    pub fn color(mut self, color: impl Into<Option<Color>>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Style the given [Span] with these attributes.
+    pub fn apply<'a>(&self, mut span: Span<'a>) -> Span<'a> {
+        if self.bold {
+            span = span.font_weight(FontWeight::BOLD);
+        }
+        if self.italic {
+            span = span.font_slant(FontSlant::Italic);
+        }
+        if let Some(color) = self.color {
+            span = span.color(color);
+        }
+        span
+    }
+}
+
+/// Text sharing the same [TextAttributes], its length is in UTF-16 code units.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TextRun {
+    pub len: usize,
+    pub attributes: TextAttributes,
+}
+
+/// Text of a run and its attributes, used to save and restore rich text.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RichTextSegment {
+    pub text: String,
+    pub attributes: TextAttributes,
+}
+
+impl RichTextSegment {
+    pub fn new(text: impl Into<String>, attributes: TextAttributes) -> Self {
+        Self {
+            text: text.into(),
+            attributes,
+        }
+    }
+}
+
+/// Attribute runs covering the whole text of a [RopeEditor](crate::RopeEditor).
+#[derive(Clone, Debug, PartialEq, Eq, Default)]
+pub struct TextRuns(Vec<TextRun>);
+
+impl TextRuns {
+    pub fn new(len: usize, attributes: TextAttributes) -> Self {
+        let mut runs = Self(vec![TextRun { len, attributes }]);
+        runs.merge();
+        runs
+    }
+
+    /// Create the text and its runs from the given segments.
+    pub fn from_segments(segments: &[RichTextSegment]) -> (String, Self) {
+        let mut text = String::new();
+        let mut runs = Self::default();
+        for segment in segments {
+            text.push_str(&segment.text);
+            runs.0.push(TextRun {
+                len: segment.text.encode_utf16().count(),
+                attributes: segment.attributes,
+            });
+        }
+        runs.merge();
+        (text, runs)
+    }
+
+    pub fn runs(&self) -> &[TextRun] {
+        &self.0
+    }
+
+    /// Length of the text covered by the runs, in UTF-16 code units.
+    pub fn len(&self) -> usize {
+        self.0.iter().map(|run| run.len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Attributes of the text before the given position, which typed text continues with.
+    pub fn attributes_at(&self, idx: usize) -> TextAttributes {
+        let mut start = 0;
+        for run in &self.0 {
+            if idx > start && idx <= start + run.len {
+                return run.attributes;
+            }
+            start += run.len;
+        }
+        self.0.first().map(|run| run.attributes).unwrap_or_default()
+    }
+
+    /// Whether all the text in the given range matches the predicate.
+    pub fn all(&self, range: Range<usize>, predicate: impl Fn(&TextAttributes) -> bool) -> bool {
+        let mut start = 0;
+        self.0.iter().all(|run| {
+            let run_range = start..start + run.len;
+            start += run.len;
+            run_range.end <= range.start
+                || run_range.start >= range.end
+                || predicate(&run.attributes)
+        })
+    }
+
+    /// Insert `len` code units with the given attributes at `idx`, splitting the run it lands in.
+    pub fn insert(&mut self, idx: usize, len: usize, attributes: TextAttributes) {
+        if len == 0 {
+            return;
+        }
+        let index = self.split_at(idx);
+        self.0.insert(index, TextRun { len, attributes });
+        self.merge();
+    }
+
+    /// Insert the given runs at `idx`, splitting the run it lands in.
+    pub fn insert_runs(&mut self, idx: usize, runs: &TextRuns) {
+        let index = self.split_at(idx);
+        self.0.splice(index..index, runs.0.iter().copied());
+        self.merge();
+    }
+
+    /// Runs of the text in the given range.
+    pub fn slice(&self, range: Range<usize>) -> TextRuns {
+        let mut runs = self.clone();
+        let start = runs.split_at(range.start);
+        let end = runs.split_at(range.end);
+        Self(runs.0.drain(start..end).collect())
+    }
+
+    /// Remove the given range, shrinking or dropping the runs it overlaps.
+    pub fn remove(&mut self, range: Range<usize>) {
+        if range.is_empty() {
+            return;
+        }
+        let start = self.split_at(range.start);
+        let end = self.split_at(range.end);
+        self.0.drain(start..end);
+        self.merge();
+    }
+
+    /// Change the attributes of the text in the given range.
+    pub fn set_attributes(
+        &mut self,
+        range: Range<usize>,
+        mut update: impl FnMut(&mut TextAttributes),
+    ) {
+        if range.is_empty() {
+            return;
+        }
+        let start = self.split_at(range.start);
+        let end = self.split_at(range.end);
+        for run in &mut self.0[start..end] {
+            update(&mut run.attributes);
+        }
+        self.merge();
+    }
+
+    /// Split the given text into the segments of every run.
+    pub fn segments(&self, text: &str) -> Vec<RichTextSegment> {
+        let mut chars = text.chars();
+        self.0
+            .iter()
+            .map(|run| {
+                let mut len = 0;
+                let mut segment = String::new();
+                while len < run.len
+                    && let Some(ch) = chars.next()
+                {
+                    len += ch.len_utf16();
+                    segment.push(ch);
+                }
+                RichTextSegment::new(segment, run.attributes)
+            })
+            .collect()
+    }
+
+    /// Styled [Span]s of the given text, ready to be passed to a `paragraph`.
+    pub fn spans(&self, text: &str) -> Vec<Span<'static>> {
+        self.segments(text)
+            .into_iter()
+            .map(|segment| segment.attributes.apply(Span::new(segment.text)))
+            .collect()
+    }
+
+    /// Split the run containing `idx` and return the index of the run that starts at `idx`.
+    fn split_at(&mut self, idx: usize) -> usize {
+        let mut start = 0;
+        let Some(index) = self.0.iter().position(|run| {
+            let contains = idx < start + run.len;
+            if !contains {
+                start += run.len;
+            }
+            contains
+        }) else {
+            return self.0.len();
+        };
+        if idx == start {
+            return index;
+        }
+        let run = self.0[index];
+        self.0[index].len = idx - start;
+        self.0.insert(
+            index + 1,
+            TextRun {
+                len: start + run.len - idx,
+                attributes: run.attributes,
+            },
+        );
+        index + 1
+    }
+
+    /// Drop the empty runs and join the adjacent ones with the same attributes.
+    fn merge(&mut self) {
+        self.0.retain(|run| run.len > 0);
+        self.0.dedup_by(|next, previous| {
+            if next.attributes == previous.attributes {
+                previous.len += next.len;
+                true
+            } else {
+                false
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        RichTextSegment,
+        TextAttributes,
+        TextRuns,
+    };
+
+    fn bold() -> TextAttributes {
+        TextAttributes::default().bold(true)
+    }
+
+    #[test]
+    fn insert_extends_or_splits_runs() {
+        let mut runs = TextRuns::new(5, TextAttributes::default());
+
+        // Same attributes extend the run
+        runs.insert(5, 3, TextAttributes::default());
+        assert_eq!(runs.runs().len(), 1);
+        assert_eq!(runs.len(), 8);
+
+        // Different attributes split it
+        runs.insert(2, 2, bold());
+        assert_eq!(
+            runs.runs()
+                .iter()
+                .map(|run| (run.len, run.attributes.bold))
+                .collect::<Vec<_>>(),
+            vec![(2, false), (2, true), (6, false)]
+        );
+    }
+
+    #[test]
+    fn remove_and_set_attributes_merge_runs() {
+        let mut runs = TextRuns::new(10, TextAttributes::default());
+        runs.set_attributes(2..6, |attributes| attributes.bold = true);
+        assert_eq!(runs.runs().len(), 3);
+        assert!(runs.all(2..6, |attributes| attributes.bold));
+        assert!(!runs.all(0..6, |attributes| attributes.bold));
+
+        // Removing the bold text joins both sides again
+        runs.remove(1..7);
+        assert_eq!(runs.runs().len(), 1);
+        assert_eq!(runs.len(), 4);
+    }
+
+    #[test]
+    fn slice_and_insert_runs_roundtrip() {
+        let mut runs = TextRuns::new(10, TextAttributes::default());
+        runs.set_attributes(2..6, |attributes| attributes.bold = true);
+        let original = runs.clone();
+
+        let removed = runs.slice(1..4);
+        assert_eq!(removed.len(), 3);
+        runs.remove(1..4);
+        runs.insert_runs(1, &removed);
+        assert_eq!(runs, original);
+    }
+
+    #[test]
+    fn attributes_at_continue_the_previous_run() {
+        let (_, runs) = TextRuns::from_segments(&[
+            RichTextSegment::new("Hello ", bold()),
+            RichTextSegment::new("World", TextAttributes::default()),
+        ]);
+        assert_eq!(runs.attributes_at(0), bold());
+        assert_eq!(runs.attributes_at(6), bold());
+        assert_eq!(runs.attributes_at(7), TextAttributes::default());
+    }
+
+    #[test]
+    fn segments_roundtrip() {
+        let segments = vec![
+            RichTextSegment::new("Hello ", TextAttributes::default()),
+            RichTextSegment::new("你好", bold().italic(true)),
+            RichTextSegment::new("!", TextAttributes::default()),
+        ];
+        let (text, runs) = TextRuns::from_segments(&segments);
+        assert_eq!(text, "Hello 你好!");
+        assert_eq!(runs.segments(&text), segments);
+    }
+}
@@ -3,6 +3,7 @@ use std::{
     ops::Range,
 };
 
+use freya_core::prelude::Span;
 use ropey::{
     Rope,
     iter::Lines,
@@ -14,6 +15,11 @@ use crate::{
         EditorHistory,
         HistoryChange,
     },
+    rich_text::{
+        RichTextSegment,
+        TextAttributes,
+        TextRuns,
+    },
     text_editor::{
         Line,
         TextEditor,
@@ -36,6 +42,9 @@ pub struct RopeEditor {
     pub(crate) indentation: u8,
     pub(crate) history: EditorHistory,
     pub(crate) preedit: Option<PreeditState>,
+    pub(crate) runs: TextRuns,
+    /// Attributes for the next typed text, set when formatting without a selection.
+    pub(crate) typing_attributes: Option<TextAttributes>,
 }
 
 impl Display for RopeEditor {
@@ -52,12 +61,15 @@ impl RopeEditor {
         indentation: u8,
         history: EditorHistory,
     ) -> Self {
+        let rope = Rope::from_str(&text);
         Self {
-            rope: Rope::from_str(&text),
+            runs: TextRuns::new(rope.len_utf16_cu(), TextAttributes::default()),
+            rope,
             selection,
             indentation,
             history,
             preedit: None,
+            typing_attributes: None,
         }
     }
 
@@ -65,17 +77,150 @@ impl RopeEditor {
         &self.rope
     }
 
+    /// Attribute runs of the text.
+    pub fn runs(&self) -> &TextRuns {
+        &self.runs
+    }
+
+    /// Styled [Span]s of the text, ready to be passed to a `paragraph`.
+    pub fn spans(&self) -> Vec<Span<'static>> {
+        let mut spans = self.runs.spans(&self.rope.to_string());
+        // Empty texts still need a span to be measured
+        if spans.is_empty() {
+            spans.push(self.attributes_at_cursor().apply(Span::new("")));
+        }
+        spans
+    }
+
+    /// Text split in segments with their attributes, to save the rich text.
+    pub fn segments(&self) -> Vec<RichTextSegment> {
+        self.runs.segments(&self.rope.to_string())
+    }
+
+    /// Replace the text and its attributes with the given segments.
+    pub fn set_segments(&mut self, segments: &[RichTextSegment]) {
+        let (text, runs) = TextRuns::from_segments(segments);
+        self.rope = Rope::from_str(&text);
+        self.runs = runs;
+        self.preedit = None;
+        self.typing_attributes = None;
+        let len = self.rope.len_utf16_cu();
+        if self.cursor_pos() > len {
+            self.move_cursor_to(len);
+        }
+    }
+
+    /// Change the attributes of the selected text,
+    /// or of the next typed text if there is no selection.
+    pub fn format_selection(&mut self, mut update: impl FnMut(&mut TextAttributes)) {
+        if let Some((start, end)) = self.get_selection_range() {
+            let before = self.runs.slice(start..end);
+            self.runs.set_attributes(start..end, update);
+            let after = self.runs.slice(start..end);
+            if before != after {
+                self.history.push_change(HistoryChange::Format {
+                    idx: start,
+                    len: end - start,
+                    before,
+                    after,
+                    selection: self.selection.clone(),
+                });
+            }
+        } else {
+            let mut attributes = self.attributes_at_cursor();
+            update(&mut attributes);
+            self.typing_attributes = Some(attributes);
+        }
+    }
+
+    /// Make the selected text bold, or regular if it was all bold already.
+    pub fn toggle_bold(&mut self) {
+        let bold = !self.selection_has(|attributes| attributes.bold);
+        self.format_selection(|attributes| attributes.bold = bold);
+    }
+
+    /// Make the selected text italic, or upright if it was all italic already.
+    pub fn toggle_italic(&mut self) {
+        let italic = !self.selection_has(|attributes| attributes.italic);
+        self.format_selection(|attributes| attributes.italic = italic);
+    }
+
+    /// Attributes that typed text will have at the cursor position.
+    pub fn attributes_at_cursor(&self) -> TextAttributes {
+        self.typing_attributes
+            .unwrap_or_else(|| self.runs.attributes_at(self.cursor_pos()))
+    }
+
+    fn selection_has(&self, predicate: impl Fn(&TextAttributes) -> bool) -> bool {
+        match self.get_selection_range() {
+            Some((start, end)) => self.runs.all(start..end, predicate),
+            None => predicate(&self.attributes_at_cursor()),
+        }
+    }
+
+    /// Attributes for text inserted at `idx`.
+    fn insert_attributes(&mut self, idx: usize) -> TextAttributes {
+        self.typing_attributes
+            .take()
+            .unwrap_or_else(|| self.runs.attributes_at(idx))
+    }
+
+    /// Keep the runs in sync with a change undone or redone by the [EditorHistory].
+    fn replay_change_in_runs(&mut self, change: &HistoryChange, undo: bool) {
+        match change {
+            HistoryChange::InsertChar { idx, len, .. }
+            | HistoryChange::InsertText { idx, len, .. }
+                if undo =>
+            {
+                self.runs.remove(*idx..*idx + len);
+            }
+            HistoryChange::Remove { idx, len, .. } if !undo => {
+                self.runs.remove(*idx..*idx + len);
+            }
+            HistoryChange::InsertChar { idx, len, runs, .. }
+            | HistoryChange::InsertText { idx, len, runs, .. }
+            | HistoryChange::Remove { idx, len, runs, .. } => {
+                if runs.len() == *len {
+                    self.runs.insert_runs(*idx, runs);
+                } else {
+                    let attributes = self.runs.attributes_at(*idx);
+                    self.runs.insert(*idx, *len, attributes);
+                }
+            }
+            HistoryChange::Format {
+                idx,
+                len,
+                before,
+                after,
+                ..
+            } => {
+                self.runs.remove(*idx..*idx + len);
+                self.runs
+                    .insert_runs(*idx, if undo { before } else { after });
+            }
+        }
+    }
+
     /// Insert or replace IME preedit text at the current cursor position.
     ///
     /// The preedit text is inserted directly into the rope without recording
     /// undo history. If there is already active preedit text, it is replaced.
     /// An empty `text` clears the preedit.
     pub fn set_preedit(&mut self, text: &str) {
+        self.set_preedit_with_cursor(text, None);
+    }
+
+    /// Same as [RopeEditor::set_preedit] but places the cursor inside the preedit text.
+    ///
+    /// `cursor` is the byte range reported by the IME, the cursor is placed at its end.
+    /// If there is none the cursor is placed after the preedit text.
+    pub fn set_preedit_with_cursor(&mut self, text: &str, cursor: Option<(usize, usize)>) {
         // Remove existing preedit text from the rope if any
         let preedit_start = if let Some(preedit) = self.preedit.take() {
             let start_char = self.rope.utf16_cu_to_char(preedit.start);
             let end_char = self.rope.utf16_cu_to_char(preedit.start + preedit.len);
             self.rope.remove(start_char..end_char);
+            self.runs.remove(preedit.start..preedit.start + preedit.len);
             preedit.start
         } else {
             self.cursor_pos()
@@ -87,12 +232,20 @@ impl RopeEditor {
         self.rope.insert(start_char, text);
         let len_after = self.rope.len_utf16_cu();
         let preedit_len = len_after - len_before;
+        let attributes = self
+            .typing_attributes
+            .unwrap_or_else(|| self.runs.attributes_at(preedit_start));
+        self.runs.insert(preedit_start, preedit_len, attributes);
 
         self.preedit = Some(PreeditState {
             start: preedit_start,
             len: preedit_len,
         });
-        self.selection = TextSelection::Cursor(preedit_start + preedit_len);
+        let cursor_offset = cursor
+            .and_then(|(_, end)| text.get(..end))
+            .map(|text| text.encode_utf16().count())
+            .unwrap_or(preedit_len);
+        self.selection = TextSelection::Cursor(preedit_start + cursor_offset);
     }
 
     /// Remove active preedit text from the rope and restore the cursor.
@@ -101,6 +254,7 @@ impl RopeEditor {
             let start_char = self.rope.utf16_cu_to_char(preedit.start);
             let end_char = self.rope.utf16_cu_to_char(preedit.start + preedit.len);
             self.rope.remove(start_char..end_char);
+            self.runs.remove(preedit.start..preedit.start + preedit.len);
             self.selection = TextSelection::Cursor(preedit.start);
         }
     }
@@ -161,11 +315,14 @@ impl TextEditor for RopeEditor {
         let len_after_insert = self.rope.len_utf16_cu();
 
         let inserted_text_len = len_after_insert - len_before_insert;
+        let attributes = self.insert_attributes(idx);
+        self.runs.insert(idx, inserted_text_len, attributes);
 
         self.history.push_change(HistoryChange::InsertChar {
             idx,
             ch,
             len: inserted_text_len,
+            runs: TextRuns::new(inserted_text_len, attributes),
             selection,
         });
 
@@ -181,11 +338,14 @@ impl TextEditor for RopeEditor {
         let len_after_insert = self.rope.len_utf16_cu();
 
         let inserted_text_len = len_after_insert - len_before_insert;
+        let attributes = self.insert_attributes(idx);
+        self.runs.insert(idx, inserted_text_len, attributes);
 
         self.history.push_change(HistoryChange::InsertText {
             idx,
             text: text.to_owned(),
             len: inserted_text_len,
+            runs: TextRuns::new(inserted_text_len, attributes),
             selection,
         });
 
@@ -203,11 +363,15 @@ impl TextEditor for RopeEditor {
         let len_after_remove = self.rope.len_utf16_cu();
 
         let removed_text_len = len_before_remove - len_after_remove;
+        let removed_range = range_utf16.start..range_utf16.start + removed_text_len;
+        let runs = self.runs.slice(removed_range.clone());
+        self.runs.remove(removed_range);
 
         self.history.push_change(HistoryChange::Remove {
             idx: range_utf16.end - removed_text_len,
             text,
             len: removed_text_len,
+            runs,
             selection,
         });
 
@@ -273,6 +437,7 @@ impl TextEditor for RopeEditor {
     fn set(&mut self, text: &str) {
         self.rope.remove(0..);
         self.rope.insert(0, text);
+        self.runs = TextRuns::new(self.rope.len_utf16_cu(), self.runs.attributes_at(0));
         if self.cursor_pos() > text.len() {
             self.move_cursor_to(text.len());
         }
@@ -314,11 +479,36 @@ impl TextEditor for RopeEditor {
     }
 
     fn undo(&mut self) -> Option<TextSelection> {
-        self.history.undo(&mut self.rope)
+        let changes = self
+            .history
+            .current_change()
+            .checked_sub(1)
+            .and_then(|transaction| self.history.transactions.get(transaction))
+            .map(|transaction| transaction.changes.clone())
+            .unwrap_or_default();
+        let selection = self.history.undo(&mut self.rope);
+        if selection.is_some() {
+            for change in changes.iter().rev() {
+                self.replay_change_in_runs(change, true);
+            }
+        }
+        selection
     }
 
     fn redo(&mut self) -> Option<TextSelection> {
-        self.history.redo(&mut self.rope)
+        let changes = self
+            .history
+            .transactions
+            .get(self.history.current_change())
+            .map(|transaction| transaction.changes.clone())
+            .unwrap_or_default();
+        let selection = self.history.redo(&mut self.rope);
+        if selection.is_some() {
+            for change in changes.iter() {
+                self.replay_change_in_runs(change, false);
+            }
+        }
+        selection
     }
 
     fn editor_history(&mut self) -> &mut EditorHistory {
@@ -428,4 +618,28 @@ mod test {
         assert_eq!(ed.rope().to_string(), "Hello");
         assert_eq!(ed.cursor_pos(), 5);
     }
+
+    #[test]
+    fn preedit_with_cursor() {
+        let mut ed = editor("Hello");
+        ed.move_cursor_to(5);
+
+        // Cursor after the first character of the preedit
+        ed.set_preedit_with_cursor("你好", Some((3, 3)));
+        assert_eq!(ed.rope().to_string(), "Hello你好");
+        assert_eq!(ed.cursor_pos(), 6);
+
+        // Replacing keeps the preedit start
+        ed.set_preedit_with_cursor("abc", Some((0, 0)));
+        assert_eq!(ed.rope().to_string(), "Helloabc");
+        assert_eq!(ed.cursor_pos(), 5);
+
+        // Invalid ranges place the cursor at the end
+        ed.set_preedit_with_cursor("abc", Some((0, 10)));
+        assert_eq!(ed.cursor_pos(), 8);
+
+        ed.clear_preedit();
+        assert_eq!(ed.rope().to_string(), "Hello");
+        assert_eq!(ed.cursor_pos(), 5);
+    }
 }
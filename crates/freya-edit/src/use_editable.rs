@@ -7,6 +7,7 @@ use crate::{
     EditableEvent,
     TextDragging,
     editor_history::EditorHistory,
+    rich_text::RichTextSegment,
     rope_editor::RopeEditor,
     text_editor::TextSelection,
 };
@@ -37,6 +38,13 @@ impl UseEditable {
         }
     }
 
+    /// Same as [UseEditable::create] but with styled text.
+    pub fn create_rich(segments: &[RichTextSegment], config: EditableConfig) -> Self {
+        let mut editable = Self::create(String::new(), config);
+        editable.editor.write().set_segments(segments);
+        editable
+    }
+
     /// Reference to the editor.
     pub fn editor(&self) -> &State<RopeEditor> {
         &self.editor
@@ -49,6 +57,30 @@ impl UseEditable {
 
     /// Process a [`EditableEvent`] event.
     pub fn process_event(&mut self, edit_event: EditableEvent) {
+        if self.config.allow_formatting
+            && let EditableEvent::KeyDown {
+                key: Key::Character(character),
+                modifiers,
+            } = &edit_event
+        {
+            let meta_or_ctrl = if cfg!(target_os = "macos") {
+                modifiers.meta()
+            } else {
+                modifiers.ctrl()
+            };
+            match character.as_str() {
+                "b" if meta_or_ctrl => {
+                    self.editor.write().toggle_bold();
+                    return;
+                }
+                "i" if meta_or_ctrl => {
+                    self.editor.write().toggle_italic();
+                    return;
+                }
+                _ => {}
+            }
+        }
+
         edit_event.process(
             self.editor.into_writable(),
             self.dragging.into_writable(),
@@ -69,3 +101,13 @@ pub fn use_editable(
 ) -> UseEditable {
     use_hook(|| UseEditable::create(content(), config()))
 }
+
+/// Same as [use_editable] but the text is made of styled segments.
+///
+/// Render it with the [spans](RopeEditor::spans) of the editor.
+pub fn use_rich_editable(
+    content: impl FnOnce() -> Vec<RichTextSegment>,
+    config: impl FnOnce() -> EditableConfig,
+) -> UseEditable {
+    use_hook(|| UseEditable::create_rich(&content(), config()))
+}
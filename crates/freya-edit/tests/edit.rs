@@ -1,4 +1,7 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    time::Duration,
+};
 
 use freya::{
     helpers::*,
@@ -269,6 +272,7 @@ fn highlight_multiple_lines_single_editor() {
         name: MouseEventName::MouseDown,
         cursor: (35.0, 3.0).into(),
         button: Some(MouseButton::Left),
+        click_count: 1,
     });
     utils.sync_and_update();
     utils.move_cursor((80.0, 25.0));
@@ -276,6 +280,7 @@ fn highlight_multiple_lines_single_editor() {
         name: MouseEventName::MouseUp,
         cursor: (80.0, 25.0).into(),
         button: Some(MouseButton::Left),
+        click_count: 1,
     });
     utils.sync_and_update();
 
@@ -1331,3 +1336,142 @@ fn navigate_empty_lines() {
     let cursor = utils.find(|_, e| Some(Label::try_downcast(e)?.text.to_string()));
     assert!(cursor.is_some());
 }
+
+#[test]
+fn rich_text_formatting() {
+    fn app() -> impl IntoElement {
+        let mut editable = use_rich_editable(
+            || {
+                vec![
+                    RichTextSegment::new("Hello ", TextAttributes::default()),
+                    RichTextSegment::new("Rustaceans", TextAttributes::default().italic(true)),
+                ]
+            },
+            || EditableConfig::new().with_allow_formatting(true),
+        );
+        let editor = editable.editor().read();
+
+        let on_global_key_down = move |e: Event<KeyboardEventData>| {
+            editable.process_event(EditableEvent::KeyDown {
+                key: &e.key,
+                modifiers: e.modifiers,
+            });
+        };
+
+        rect().font_family("NotoSans").child(
+            paragraph()
+                .on_global_key_down(on_global_key_down)
+                .spans_iter(editor.spans().into_iter()),
+        )
+    }
+    let mut utils = launch_test(app);
+    utils.set_fonts(HashMap::from_iter([(
+        "NotoSans",
+        include_bytes!("./NotoSans-Regular.ttf").as_slice(),
+    )]));
+    utils.set_default_fonts(&["NotoSans".into()]);
+
+    let spans = |utils: &TestingRunner| {
+        utils
+            .find(|_, e| Some(Paragraph::try_downcast(e)?.spans))
+            .unwrap()
+            .into_iter()
+            .map(|span| {
+                (
+                    span.text.to_string(),
+                    span.text_style_data.font_weight == Some(FontWeight::BOLD),
+                    span.text_style_data.font_slant == Some(FontSlant::Italic),
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+    let shortcut = |utils: &mut TestingRunner, character: &str| {
+        utils.send_event(PlatformEvent::Keyboard {
+            name: KeyboardEventName::KeyDown,
+            key: Key::Character(character.to_string()),
+            code: Code::Unidentified,
+            modifiers: if cfg!(target_os = "macos") {
+                Modifiers::META
+            } else {
+                Modifiers::CONTROL
+            },
+        });
+        utils.sync_and_update();
+    };
+
+    assert_eq!(
+        spans(&utils),
+        vec![
+            ("Hello ".to_string(), false, false),
+            ("Rustaceans".to_string(), false, true)
+        ]
+    );
+
+    // Typing at the start continues the first run
+    utils.write_text("!");
+    assert_eq!(
+        spans(&utils),
+        vec![
+            ("!Hello ".to_string(), false, false),
+            ("Rustaceans".to_string(), false, true)
+        ]
+    );
+
+    // Wait for the typed text to be its own transaction in the history
+    std::thread::sleep(Duration::from_millis(20));
+
+    // Make everything bold, the runs keep their other attributes
+    for character in ["a", "b"] {
+        shortcut(&mut utils, character);
+    }
+    assert_eq!(
+        spans(&utils),
+        vec![
+            ("!Hello ".to_string(), true, false),
+            ("Rustaceans".to_string(), true, true)
+        ]
+    );
+
+    // Undoing the formatting restores the previous attributes of every run
+    shortcut(&mut utils, "z");
+    assert_eq!(
+        spans(&utils),
+        vec![
+            ("!Hello ".to_string(), false, false),
+            ("Rustaceans".to_string(), false, true)
+        ]
+    );
+
+    shortcut(&mut utils, "y");
+    assert_eq!(
+        spans(&utils),
+        vec![
+            ("!Hello ".to_string(), true, false),
+            ("Rustaceans".to_string(), true, true)
+        ]
+    );
+
+    // Removed text gets its attributes back when undoing, even across runs
+    for _ in 0..11 {
+        utils.press_key(Key::Named(NamedKey::ArrowLeft));
+    }
+    std::thread::sleep(Duration::from_millis(20));
+    for _ in 0..3 {
+        utils.press_key(Key::Named(NamedKey::Delete));
+    }
+    assert_eq!(
+        spans(&utils),
+        vec![
+            ("!Hello".to_string(), true, false),
+            ("staceans".to_string(), true, true)
+        ]
+    );
+    shortcut(&mut utils, "z");
+    assert_eq!(
+        spans(&utils),
+        vec![
+            ("!Hello ".to_string(), true, false),
+            ("Rustaceans".to_string(), true, true)
+        ]
+    );
+}
@@ -13,6 +13,7 @@ pub mod prelude {
     }
 
     pub use source::{
+        BlendMode as SkBlendMode,
         BlurStyle as SkBlurStyle,
         Color as SkColor,
         Color4f as SkColor4f,
@@ -24,6 +25,7 @@ pub mod prelude {
         Matrix as SkMatrix,
         Paragraph as SkParagraph,
         Path as SkPath,
+        PathEffect as SkPathEffect,
         PathFillType as SkPathFillType,
         Point as SkPoint,
         RGB as SkRGB,
@@ -32,6 +34,7 @@ pub mod prelude {
         Surface as SkSurface,
         TextAlign as SkTextAlign,
         TextDecoration as SkTextDecoration,
+        TextDirection as SkTextDirection,
         TextHeightBehavior as SkTextHeightBehavior,
         TextShadow as SkTextShadow,
         *,
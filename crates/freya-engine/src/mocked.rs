@@ -301,6 +301,24 @@ impl<'a> Gradient<'a> {
     }
 }
 
+pub struct ColorFilter;
+
+pub mod color_filters {
+    use super::{
+        BlendMode,
+        Color,
+        ColorFilter,
+    };
+
+    pub fn matrix_row_major(_array: &[f32; 20], _clamp: impl Into<Option<()>>) -> ColorFilter {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn blend(_color: impl Into<Color>, _mode: BlendMode) -> Option<ColorFilter> {
+        unimplemented!("This is mocked")
+    }
+}
+
 pub mod shaders {
     use super::{
         Gradient,
@@ -333,6 +351,15 @@ pub mod shaders {
     ) -> Option<Shader> {
         unimplemented!("This is mocked")
     }
+
+    pub fn two_point_conical_gradient<'a>(
+        _start: (impl Into<Point>, f32),
+        _end: (impl Into<Point>, f32),
+        _gradient: &Gradient<'a>,
+        _local_matrix: impl Into<Option<&'a Matrix>>,
+    ) -> Option<Shader> {
+        unimplemented!("This is mocked")
+    }
 }
 
 #[repr(C)]
@@ -343,6 +370,85 @@ pub struct Matrix {
 }
 
 impl Matrix {
+    pub fn translate(_d: impl Into<Point>) -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn scale(_s: (f32, f32)) -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn skew(_k: (f32, f32)) -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn rotate_deg(_degrees: f32) -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_all(
+        _scale_x: f32,
+        _skew_x: f32,
+        _trans_x: f32,
+        _skew_y: f32,
+        _scale_y: f32,
+        _trans_y: f32,
+        _pers_0: f32,
+        _pers_1: f32,
+        _pers_2: f32,
+    ) -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn pre_concat(&mut self, _other: &Matrix) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn pre_translate(&mut self, _d: impl Into<Point>) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn invert(&self) -> Option<Matrix> {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn is_identity(&self) -> bool {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn map_point(&self, _point: impl Into<Point>) -> Point {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn scale_x(&self) -> f32 {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn scale_y(&self) -> f32 {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn skew_x(&self) -> f32 {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn skew_y(&self) -> f32 {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn translate_x(&self) -> f32 {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn translate_y(&self) -> f32 {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn pre_scale(&mut self, _s: (f32, f32), _p: impl Into<Option<Point>>) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn new_identity() -> Self {
         unimplemented!("This is mocked")
     }
@@ -367,6 +473,10 @@ impl Point {
     pub fn new(_: f32, _: f32) -> Self {
         unimplemented!("This is mocked")
     }
+
+    pub fn length(&self) -> f32 {
+        unimplemented!("This is mocked")
+    }
 }
 
 impl Neg for Point {
@@ -806,6 +916,14 @@ impl Paint {
         unimplemented!("This is mocked")
     }
 
+    pub fn set_stroke_cap(&mut self, _cap: PaintCap) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn set_path_effect(&mut self, _path_effect: impl Into<Option<PathEffect>>) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn set_color(&mut self, _color: impl Into<Color>) -> &mut Self {
         unimplemented!("This is mocked")
     }
@@ -829,6 +947,14 @@ impl Paint {
     pub fn set_mask_filter(&mut self, _mask_filter: impl Into<Option<MaskFilter>>) -> &mut Self {
         unimplemented!("This is mocked")
     }
+
+    pub fn set_image_filter(&mut self, _image_filter: impl Into<Option<ImageFilter>>) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn set_color_filter(&mut self, _color_filter: impl Into<Option<ColorFilter>>) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
 }
 
 pub enum PaintStyle {
@@ -837,6 +963,20 @@ pub enum PaintStyle {
     StrokeAndFill = 2,
 }
 
+pub enum PaintCap {
+    Butt = 0,
+    Round = 1,
+    Square = 2,
+}
+
+pub struct PathEffect;
+
+impl PathEffect {
+    pub fn dash(_intervals: &[f32], _phase: f32) -> Option<Self> {
+        unimplemented!("This is mocked")
+    }
+}
+
 pub enum FontEdging {
     Alias,
     AntiAlias,
@@ -1235,6 +1375,10 @@ impl Canvas {
         unimplemented!("This is mocked")
     }
 
+    pub fn save_count(&self) -> usize {
+        unimplemented!("This is mocked")
+    }
+
     pub fn concat(&self, _matrix: &Matrix) {
         unimplemented!("This is mocked")
     }
@@ -1287,6 +1431,10 @@ impl Canvas {
         unimplemented!("This is mocked")
     }
 
+    pub fn recording_context(&self) -> Option<RecordingContext> {
+        unimplemented!("This is mocked")
+    }
+
     pub fn draw_image_rect_with_sampling_options(
         &self,
         image: impl AsRef<Image>,
@@ -1374,6 +1522,45 @@ impl Canvas {
     ) -> &Self {
         unimplemented!("This is mocked")
     }
+
+    pub fn draw_picture(
+        &self,
+        _picture: impl AsRef<Picture>,
+        _matrix: Option<&Matrix>,
+        _paint: Option<&Paint>,
+    ) -> &Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn local_clip_bounds(&self) -> Option<Rect> {
+        unimplemented!("This is mocked")
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Picture;
+
+impl AsRef<Picture> for Picture {
+    fn as_ref(&self) -> &Picture {
+        self
+    }
+}
+
+#[derive(Default)]
+pub struct PictureRecorder;
+
+impl PictureRecorder {
+    pub fn new() -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn begin_recording(&mut self, _bounds: impl AsRef<Rect>, _use_bbh: bool) -> &Canvas {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn finish_recording_as_picture(&mut self, _cull_rect: Option<&Rect>) -> Option<Picture> {
+        unimplemented!("This is mocked")
+    }
 }
 
 pub enum SrcRectConstraint {
@@ -1381,7 +1568,7 @@ pub enum SrcRectConstraint {
     Fast = 1,
 }
 
-#[derive(Default)]
+#[derive(Default, Clone, Copy)]
 pub struct SamplingOptions;
 
 pub struct ImageFilter;
@@ -1395,6 +1582,49 @@ pub fn blur(
     unimplemented!("This is mocked")
 }
 
+pub struct RuntimeShaderBuilder;
+
+impl RuntimeShaderBuilder {
+    pub fn new(effect: RuntimeEffect) -> Self {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn set_uniform_float(&mut self, name: impl AsRef<str>, data: &[f32]) -> Result<(), String> {
+        unimplemented!("This is mocked")
+    }
+}
+
+pub fn runtime_shader(
+    builder: &RuntimeShaderBuilder,
+    child_shader_name: impl AsRef<str>,
+    input: impl Into<Option<ImageFilter>>,
+) -> Option<ImageFilter> {
+    unimplemented!("This is mocked")
+}
+
+pub fn dilate(
+    (radius_x, radius_y): (f32, f32),
+    input: impl Into<Option<ImageFilter>>,
+    crop_rect: impl Into<Option<Rect>>,
+) -> Option<ImageFilter> {
+    unimplemented!("This is mocked")
+}
+
+pub fn color_image_filter(
+    color_filter: impl Into<ColorFilter>,
+    input: impl Into<Option<ImageFilter>>,
+    crop_rect: impl Into<Option<Rect>>,
+) -> Option<ImageFilter> {
+    unimplemented!("This is mocked")
+}
+
+pub fn merge_image_filters(
+    filters: impl IntoIterator<Item = Option<ImageFilter>>,
+    crop_rect: impl Into<Option<Rect>>,
+) -> Option<ImageFilter> {
+    unimplemented!("This is mocked")
+}
+
 #[repr(C)]
 #[derive(Default)]
 pub struct SaveLayerRec;
@@ -1583,9 +1813,14 @@ pub struct PositionWithAffinity {
     pub position: i32,
 }
 
+#[derive(Clone)]
 pub struct RuntimeEffect;
 
 impl RuntimeEffect {
+    pub fn make_for_shader(sksl: impl AsRef<str>, options: Option<&()>) -> Result<Self, String> {
+        unimplemented!("This is mocked")
+    }
+
     pub fn uniforms(&self) -> &[Uniform] {
         unimplemented!("This is mocked")
     }
@@ -1796,6 +2031,15 @@ impl PathBuilder {
         unimplemented!("This is mocked")
     }
 
+    pub fn add_oval(
+        &mut self,
+        _oval: impl AsRef<Rect>,
+        _dir: impl Into<Option<PathDirection>>,
+        _start_index: impl Into<Option<usize>>,
+    ) -> &mut Self {
+        unimplemented!("This is mocked")
+    }
+
     pub fn detach(self) -> Path {
         unimplemented!("This is mocked")
     }
@@ -1864,6 +2108,18 @@ impl Path {
     pub fn bounds(&self) -> &Rect {
         unimplemented!("This is mocked")
     }
+
+    pub fn from_svg(_svg: impl AsRef<str>) -> Option<Path> {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn with_transform(&self, _matrix: &Matrix) -> Path {
+        unimplemented!("This is mocked")
+    }
+
+    pub fn contains(&self, _p: impl Into<Point>) -> bool {
+        unimplemented!("This is mocked")
+    }
 }
 
 #[repr(i32)]
@@ -2007,6 +2263,37 @@ pub enum BlurStyle {
     Inner = 3,
 }
 
+pub mod skottie {
+    use super::{
+        Canvas,
+        Rect,
+    };
+
+    pub struct Animation;
+
+    impl Animation {
+        pub fn from_bytes(_data: &[u8]) -> Option<Self> {
+            unimplemented!("This is mocked")
+        }
+
+        pub fn duration(&self) -> f32 {
+            unimplemented!("This is mocked")
+        }
+
+        pub fn fps(&self) -> f32 {
+            unimplemented!("This is mocked")
+        }
+
+        pub fn seek_frame(&mut self, _frame: f64) {
+            unimplemented!("This is mocked")
+        }
+
+        pub fn render<'a>(&self, _canvas: &Canvas, _dst: impl Into<Option<&'a Rect>>) {
+            unimplemented!("This is mocked")
+        }
+    }
+}
+
 pub mod svg {
     use super::{
         Canvas,
@@ -2163,6 +2450,16 @@ impl Surface {
     pub fn new_surface_with_dimensions(&mut self, dim: impl Into<ISize>) -> Option<Self> {
         unimplemented!("This is mocked")
     }
+
+    pub fn read_pixels(
+        &mut self,
+        _dst_info: &ImageInfo,
+        _dst_pixels: &mut [u8],
+        _dst_row_bytes: usize,
+        _src_point: (i32, i32),
+    ) -> bool {
+        unimplemented!("This is mocked")
+    }
 }
 
 pub struct ISize;
@@ -2197,6 +2494,12 @@ use std::ops::{
 
 pub struct RecordingContext;
 
+impl RecordingContext {
+    pub fn as_direct_context(&mut self) -> Option<DirectContext> {
+        unimplemented!("This is mocked")
+    }
+}
+
 #[repr(i32)]
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
 pub enum SurfaceOrigin {
@@ -2394,6 +2697,47 @@ impl BackendRenderTarget {
     }
 }
 
+#[derive(Clone, Debug)]
+pub struct BackendTexture;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Mipmapped {
+    No,
+    Yes,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[repr(C)]
+pub struct TextureInfo {
+    pub target: std::os::raw::c_uint,
+    pub id: std::os::raw::c_uint,
+    pub format: std::os::raw::c_uint,
+    pub protected: Protected,
+}
+
+pub mod backend_textures {
+    use crate::prelude::*;
+    pub fn make_gl(
+        _dimensions: impl Into<ISize>,
+        _mipmapped: Mipmapped,
+        _texture_info: TextureInfo,
+        _label: impl AsRef<str>,
+    ) -> BackendTexture {
+        unimplemented!("This is mocked")
+    }
+}
+
+pub fn borrow_texture_from(
+    _context: &mut RecordingContext,
+    _backend_texture: &BackendTexture,
+    _origin: SurfaceOrigin,
+    _color_type: ColorType,
+    _alpha_type: AlphaType,
+    _color_space: impl Into<Option<ColorSpace>>,
+) -> Option<Image> {
+    unimplemented!("This is mocked")
+}
+
 pub mod backend_render_targets {
     use crate::prelude::*;
     pub fn make_gl(
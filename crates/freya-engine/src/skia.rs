@@ -3,11 +3,14 @@ pub use skia_safe::gpu::gl::{
     Format,
     FramebufferInfo,
     Interface,
+    TextureInfo,
 };
 #[cfg(target_os = "macos")]
 pub use skia_safe::gpu::mtl;
 #[cfg(any(target_os = "linux", target_os = "windows"))]
 pub use skia_safe::gpu::vk;
+#[cfg(feature = "skottie")]
+pub use skia_safe::skottie;
 pub use skia_safe::{
     AlphaType,
     Bitmap,
@@ -17,6 +20,7 @@ pub use skia_safe::{
     ClipOp,
     Color,
     Color4f,
+    ColorFilter,
     ColorSpace,
     ColorType,
     CubicResampler,
@@ -40,11 +44,15 @@ pub use skia_safe::{
     Matrix,
     MipmapMode,
     Paint,
+    PaintCap,
     PaintStyle,
     Path,
     PathBuilder,
     PathDirection,
+    PathEffect,
     PathFillType,
+    Picture,
+    PictureRecorder,
     Pixmap,
     Point,
     RGB,
@@ -58,7 +66,11 @@ pub use skia_safe::{
     TileMode,
     Typeface,
     V3,
-    canvas::SaveLayerRec,
+    canvas::{
+        SaveLayerRec,
+        SrcRectConstraint,
+    },
+    color_filters,
     font::Edging as FontEdging,
     font_style::{
         Slant,
@@ -68,12 +80,16 @@ pub use skia_safe::{
     gpu::{
         self,
         BackendRenderTarget,
+        BackendTexture,
         Budgeted,
         DirectContext,
+        Mipmapped,
         RecordingContext,
         SurfaceOrigin,
         backend_render_targets,
+        backend_textures,
         direct_contexts,
+        images::borrow_texture_from,
         surfaces::{
             render_target,
             wrap_backend_render_target,
@@ -92,12 +108,21 @@ pub use skia_safe::{
         set_resource_cache_single_allocation_byte_limit,
         set_resource_cache_total_bytes_limit,
     },
-    image_filters::blur,
+    image_filters::{
+        blur,
+        color_filter as color_image_filter,
+        dilate,
+        merge as merge_image_filters,
+        runtime_shader,
+    },
     images::raster_from_data,
     path_builder::ArcSize,
     resources::LocalResourceProvider,
     rrect::Corner,
-    runtime_effect::Uniform,
+    runtime_effect::{
+        RuntimeShaderBuilder,
+        Uniform,
+    },
     shaders,
     surfaces::raster_n32_premul,
     svg,
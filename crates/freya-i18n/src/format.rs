@@ -0,0 +1,118 @@
+//! Locale-aware formatting of numbers and dates, with the CLDR data of [ICU4X](https://icu4x.unicode.org).
+//!
+//! Languages without data fall back to their closest parent, and finally to English.
+
+use std::time::{
+    SystemTime,
+    UNIX_EPOCH,
+};
+
+use fixed_decimal::Decimal;
+use icu_datetime::{
+    DateTimeFormatter,
+    fieldsets::YMD,
+    options::YearStyle,
+};
+use icu_decimal::DecimalFormatter;
+use icu_locale_core::Locale;
+use unic_langid::LanguageIdentifier;
+
+/// A day of the Gregorian calendar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Date {
+    pub year: i32,
+    /// From `1` to `12`.
+    pub month: u8,
+    /// From `1` to `31`.
+    pub day: u8,
+}
+
+impl Date {
+    pub fn new(year: i32, month: u8, day: u8) -> Self {
+        Self { year, month, day }
+    }
+
+    /// Date of the given number of days since the Unix epoch.
+    fn from_days_since_epoch(days: i64) -> Self {
+        // See http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let z = days + 719468;
+        let era = z.div_euclid(146097);
+        let doe = z.rem_euclid(146097);
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + i64::from(month <= 2);
+        Self::new(year as i32, month as u8, day as u8)
+    }
+}
+
+impl From<SystemTime> for Date {
+    /// Date of the given time in UTC.
+    fn from(time: SystemTime) -> Self {
+        let seconds = match time.duration_since(UNIX_EPOCH) {
+            Ok(since) => since.as_secs() as i64,
+            Err(err) => -(err.duration().as_secs_f64().ceil() as i64),
+        };
+        Self::from_days_since_epoch(seconds.div_euclid(86400))
+    }
+}
+
+/// Format a number with the digits, decimal separator and grouping of the given language,
+/// rounded to `fraction_digits` decimals.
+///
+/// ```rust
+/// # use freya_i18n::prelude::*;
+/// assert_eq!(
+///     format_number(&langid!("en-US"), 1234567.891, 2),
+///     "1,234,567.89"
+/// );
+/// assert_eq!(
+///     format_number(&langid!("de-DE"), 1234567.891, 2),
+///     "1.234.567,89"
+/// );
+/// assert_eq!(format_number(&langid!("en-IN"), 1234567.0, 0), "12,34,567");
+/// ```
+pub fn format_number(id: &LanguageIdentifier, value: f64, fraction_digits: usize) -> String {
+    let formatted = format!("{:.*}", fraction_digits, value);
+    // Numbers rounded to zero lose their sign
+    let formatted = match formatted.strip_prefix('-') {
+        Some(unsigned) if unsigned.chars().all(|c| c == '0' || c == '.') => unsigned.to_string(),
+        _ => formatted,
+    };
+    let Ok(decimal) = formatted.parse::<Decimal>() else {
+        return formatted;
+    };
+    match DecimalFormatter::try_new(locale(id).into(), Default::default()) {
+        Ok(formatter) => formatter.format_to_string(&decimal),
+        Err(_) => formatted,
+    }
+}
+
+/// Format a date in the short numeric form of the given language, always with the full year.
+///
+/// ```rust
+/// # use freya_i18n::prelude::*;
+/// let date = Date::new(2025, 3, 9);
+/// assert_eq!(format_date(&langid!("en-US"), date), "3/9/2025");
+/// assert_eq!(format_date(&langid!("es-ES"), date), "9/3/2025");
+/// assert_eq!(format_date(&langid!("ja-JP"), date), "2025/03/09");
+/// ```
+pub fn format_date(id: &LanguageIdentifier, date: impl Into<Date>) -> String {
+    let Date { year, month, day } = date.into();
+    let fallback = format!("{year}-{month:02}-{day:02}");
+    let Ok(date) = icu_calendar::Date::try_new_iso(year, month, day) else {
+        return fallback;
+    };
+    let field_set = YMD::short().with_year_style(YearStyle::Full);
+    match DateTimeFormatter::try_new(locale(id).into(), field_set) {
+        Ok(formatter) => formatter.format(&date).to_string(),
+        Err(_) => fallback,
+    }
+}
+
+/// Locale of ICU4X for the given language, the root locale if it can't be converted.
+fn locale(id: &LanguageIdentifier) -> Locale {
+    id.to_string().parse().unwrap_or_default()
+}
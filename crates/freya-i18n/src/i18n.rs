@@ -9,9 +9,19 @@ use fluent::{
     FluentResource,
 };
 use freya_core::prelude::*;
-use unic_langid::LanguageIdentifier;
+use unic_langid::{
+    CharacterDirection,
+    LanguageIdentifier,
+};
 
-use crate::error::Error;
+use crate::{
+    error::Error,
+    format::{
+        Date,
+        format_date,
+        format_number,
+    },
+};
 
 /// `Locale` is a "place-holder" around what will eventually be a `fluent::FluentBundle`
 #[cfg_attr(test, derive(Debug, PartialEq))]
@@ -291,6 +301,35 @@ pub fn use_init_i18n(init: impl FnOnce() -> I18nConfig) -> I18n {
     })
 }
 
+/// Access the [`I18n`] provided by [`use_init_i18n`] or [`use_share_i18n`] in an ancestor.
+///
+/// Like the `t!` macros, anything read from it during render subscribes the component,
+/// so it rerenders when the language changes.
+///
+/// ```rust
+/// # use freya::prelude::*;
+/// # use freya::i18n::*;
+/// #[derive(PartialEq)]
+/// struct Price(f64);
+///
+/// impl Component for Price {
+///     fn render(&self) -> impl IntoElement {
+///         let i18n = use_i18n();
+///
+///         label()
+///             .text_direction(i18n.text_direction())
+///             .text(i18n.format_number(self.0, 2))
+///     }
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Panics if no [`I18n`] has been provided in an ancestor component.
+pub fn use_i18n() -> I18n {
+    use_consume()
+}
+
 /// The main handle for accessing and managing internationalization state.
 ///
 /// `I18n` holds the selected language, fallback language, locale resources, and the active
@@ -546,6 +585,27 @@ impl I18n {
         self.selected_language.read().clone()
     }
 
+    /// Direction in which the selected language is written.
+    ///
+    /// Set it with `text_direction` in the root of the app so the text of right to left
+    /// languages like Arabic is shaped and aligned from the right, and the layout is mirrored.
+    pub fn text_direction(&self) -> TextDirection {
+        match self.selected_language.read().character_direction() {
+            CharacterDirection::RTL => TextDirection::Rtl,
+            _ => TextDirection::Ltr,
+        }
+    }
+
+    /// Format a number for the selected language, see [`format_number`].
+    pub fn format_number(&self, value: f64, fraction_digits: usize) -> String {
+        format_number(&self.selected_language.read(), value, fraction_digits)
+    }
+
+    /// Format a date for the selected language, see [`format_date`].
+    pub fn format_date(&self, date: impl Into<Date>) -> String {
+        format_date(&self.selected_language.read(), date)
+    }
+
     /// Get the fallback language.
     pub fn fallback_language(&self) -> Option<LanguageIdentifier> {
         self.fallback_language.read().clone()
@@ -67,6 +67,7 @@
 //! ```
 
 mod error;
+pub mod format;
 pub mod i18n;
 pub mod i18n_macro;
 
@@ -83,6 +84,7 @@ pub mod prelude {
 
     pub use crate::{
         error::Error,
+        format::*,
         i18n::*,
         t,
         te,
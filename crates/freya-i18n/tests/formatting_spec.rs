@@ -0,0 +1,111 @@
+use std::time::{
+    Duration,
+    UNIX_EPOCH,
+};
+
+use freya::prelude::*;
+use freya_i18n::{
+    prelude::*,
+    t,
+};
+use freya_testing::prelude::*;
+
+const EN: &str = "items = { $count ->
+    [one] One item
+   *[other] Many items
+}";
+
+const ES: &str = "items = { $count ->
+    [one] Un elemento
+   *[other] Muchos elementos
+}";
+
+#[test]
+fn format_numbers() {
+    assert_eq!(
+        format_number(&langid!("en-US"), 1234567.891, 2),
+        "1,234,567.89"
+    );
+    assert_eq!(
+        format_number(&langid!("es-ES"), 1234567.891, 2),
+        "1.234.567,89"
+    );
+    // Four digit numbers are not grouped in Spanish
+    assert_eq!(format_number(&langid!("es-ES"), 1234.5, 1), "1234,5");
+    assert_eq!(
+        format_number(&langid!("fr-FR"), -1234.5, 1),
+        "-1\u{202f}234,5"
+    );
+    assert_eq!(format_number(&langid!("de-CH"), 1234.6, 0), "1\u{2019}235");
+    assert_eq!(
+        format_number(&langid!("en-IN"), 1234567.891, 2),
+        "12,34,567.89"
+    );
+    assert_eq!(
+        format_number(&langid!("ar-EG"), 1234.5, 1),
+        "\u{661}\u{66c}\u{662}\u{663}\u{664}\u{66b}\u{665}"
+    );
+    assert_eq!(format_number(&langid!("en-US"), 999.0, 0), "999");
+    assert_eq!(format_number(&langid!("en-US"), -0.001, 2), "0.00");
+    assert_eq!(format_number(&langid!("en-US"), 1.5, 3), "1.500");
+}
+
+#[test]
+fn format_dates() {
+    let date = Date::new(2025, 12, 1);
+    assert_eq!(format_date(&langid!("en"), date), "12/1/2025");
+    assert_eq!(format_date(&langid!("en-GB"), date), "01/12/2025");
+    assert_eq!(format_date(&langid!("de-DE"), date), "01.12.2025");
+    assert_eq!(format_date(&langid!("nl"), date), "01-12-2025");
+    assert_eq!(format_date(&langid!("zh"), date), "2025/12/1");
+    assert_eq!(format_date(&langid!("sv-SE"), date), "2025-12-01");
+
+    assert_eq!(Date::from(UNIX_EPOCH), Date::new(1970, 1, 1));
+    assert_eq!(
+        Date::from(UNIX_EPOCH + Duration::from_secs(1_709_210_096)),
+        Date::new(2024, 2, 29)
+    );
+    assert_eq!(
+        Date::from(UNIX_EPOCH - Duration::from_secs(1)),
+        Date::new(1969, 12, 31)
+    );
+}
+
+#[test]
+fn use_i18n_follows_the_language() {
+    fn app() -> impl IntoElement {
+        let i18n = use_i18n();
+        rect()
+            .child(t!("items", count: 1))
+            .child(t!("items", count: 3))
+            .child(i18n.format_number(1234.5, 1))
+    }
+
+    let (mut test, mut i18n) = TestingRunner::new(
+        app,
+        (300., 300.).into(),
+        |runner| {
+            runner.provide_root_context(|| {
+                let config = I18nConfig::new(langid!("en-US"))
+                    .with_locale((langid!("en-US"), EN))
+                    .with_locale((langid!("es-ES"), ES));
+                I18n::create(config).expect("Failed to create I18n")
+            })
+        },
+        1.,
+    );
+    test.sync_and_update();
+    assert!(test.find_by_text("One item").is_some());
+    assert!(test.find_by_text("Many items").is_some());
+    assert!(test.find_by_text("1,234.5").is_some());
+    assert_eq!(i18n.text_direction(), TextDirection::Ltr);
+
+    i18n.set_language(langid!("es-ES"));
+    test.sync_and_update();
+    assert!(test.find_by_text("Un elemento").is_some());
+    assert!(test.find_by_text("Muchos elementos").is_some());
+    assert!(test.find_by_text("1234,5").is_some());
+
+    i18n.set_language(langid!("ar"));
+    assert_eq!(i18n.text_direction(), TextDirection::Rtl);
+}
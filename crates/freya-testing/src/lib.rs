@@ -41,10 +41,7 @@ use std::{
     io::Write,
     path::PathBuf,
     rc::Rc,
-    time::{
-        Duration,
-        Instant,
-    },
+    time::Duration,
 };
 
 use freya_clipboard::copypasta::{
@@ -60,17 +57,20 @@ use freya_core::{
     prelude::*,
 };
 use freya_engine::prelude::{
+    AlphaType,
+    ColorType,
     EncodedImageFormat,
     FontCollection,
     FontMgr,
+    ImageInfo,
     SkData,
+    Surface,
     TypefaceFontProvider,
     raster_n32_premul,
 };
 use ragnarok::{
     CursorPoint,
     EventsExecutorRunner,
-    EventsMeasurerRunner,
     NodesState,
 };
 use torin::prelude::{
@@ -80,12 +80,16 @@ use torin::prelude::{
 
 pub mod prelude {
     pub use freya_core::{
-        events::platform::*,
+        events::{
+            platform::*,
+            recording::*,
+        },
         prelude::*,
     };
 
     pub use crate::{
         DocRunner,
+        RenderedPixels,
         TestingRunner,
         launch_doc,
         launch_test,
@@ -148,6 +152,9 @@ pub fn launch_test(app: impl Into<AppComponent>) -> TestingRunner {
 
 pub struct TestingRunner {
     nodes_state: NodesState<NodeId>,
+    click_counter: ClickCounter,
+    drag_tracker: DragTracker,
+    ime_composition: ImeComposition,
     runner: Runner,
     tree: Rc<RefCell<Tree>>,
     size: Size2D,
@@ -167,6 +174,8 @@ pub struct TestingRunner {
 
     default_fonts: Vec<Cow<'static, str>>,
     scale_factor: f64,
+
+    cursor: Rc<RefCell<MouseCursor>>,
 }
 
 impl TestingRunner {
@@ -186,20 +195,22 @@ impl TestingRunner {
         ticker_sender.set_overflow(true);
         runner.provide_root_context(|| ticker);
 
-        let animation_clock = runner.provide_root_context(AnimationClock::new);
-
         runner.provide_root_context(AssetCacher::create);
 
         let tree = Tree::default();
+        let animation_clock = runner.provide_root_context(|| tree.animation_clock.clone());
         let tree = Rc::new(RefCell::new(tree));
+        let cursor = Rc::new(RefCell::new(MouseCursor::default()));
 
         let platform = runner.provide_root_context({
             let tree = tree.clone();
+            let cursor = cursor.clone();
             || Platform {
                 focused_accessibility_id: State::create(ACCESSIBILITY_ROOT_ID),
                 focused_accessibility_node: State::create(accesskit::Node::new(
                     accesskit::Role::Window,
                 )),
+                focused_accessibility_ancestors: State::create(Vec::new()),
                 root_size: State::create(size),
                 navigation_mode: State::create(NavigationMode::NotKeyboard),
                 preferred_theme: State::create(PreferredTheme::Light),
@@ -211,9 +222,18 @@ impl TestingRunner {
                         UserEvent::FocusAccessibilityNode(strategy) => {
                             tree.borrow_mut().accessibility_diff.request_focus(strategy);
                         }
-                        UserEvent::SetCursorIcon(_) => {
+                        UserEvent::SetCursorIcon(cursor_icon) => {
+                            *cursor.borrow_mut() = MouseCursor::Icon(cursor_icon);
+                        }
+                        UserEvent::SetCustomCursor(custom_cursor) => {
+                            *cursor.borrow_mut() = MouseCursor::Custom(custom_cursor);
+                        }
+                        UserEvent::SetRenderMode(_) => {
                             // Nothing
                         }
+                        UserEvent::ShowRepaints(show) => {
+                            tree.borrow_mut().repaints.set_enabled(show);
+                        }
                         UserEvent::Erased(_) => {
                             // Nothing
                         }
@@ -231,6 +251,7 @@ impl TestingRunner {
         });
 
         runner.provide_root_context(|| tree.borrow().accessibility_generator.clone());
+        runner.provide_root_context(|| tree.borrow().visibility_tracker.clone());
 
         let hook_result = hook(&mut runner);
 
@@ -256,6 +277,9 @@ impl TestingRunner {
             platform,
 
             nodes_state,
+            click_counter: ClickCounter::default(),
+            drag_tracker: DragTracker::default(),
+            ime_composition: ImeComposition::default(),
             events_receiver,
             events_sender,
 
@@ -267,6 +291,8 @@ impl TestingRunner {
 
             default_fonts: default_fonts(),
             scale_factor,
+
+            cursor,
         };
 
         runner.sync_and_update();
@@ -305,7 +331,8 @@ impl TestingRunner {
         );
         self.tree.borrow_mut().accessibility_diff.clear();
         self.accessibility.focused_id = ACCESSIBILITY_ROOT_ID;
-        self.accessibility.init(&mut self.tree.borrow_mut());
+        self.accessibility
+            .init(&mut self.tree.borrow_mut(), self.scale_factor);
         self.sync_and_update();
     }
 
@@ -341,7 +368,9 @@ impl TestingRunner {
 
         let mutations = self.runner.sync_and_update();
         self.runner.run_in(|| {
-            self.tree.borrow_mut().apply_mutations(mutations);
+            let mut tree = self.tree.borrow_mut();
+            tree.apply_mutations(mutations);
+            tree.advance_transitions();
         });
         self.tree.borrow_mut().measure_layout(
             self.size,
@@ -351,48 +380,113 @@ impl TestingRunner {
             self.scale_factor,
             &self.default_fonts,
         );
+        if let Some(cursor) = self.tree.borrow_mut().hovered_cursor.take_change() {
+            *self.cursor.borrow_mut() = cursor;
+        }
 
-        let accessibility_update = self
-            .accessibility
-            .process_updates(&mut self.tree.borrow_mut(), &self.events_sender);
+        let accessibility_update = self.accessibility.process_updates(
+            &mut self.tree.borrow_mut(),
+            &self.events_sender,
+            self.scale_factor,
+        );
 
         self.platform
             .focused_accessibility_id
             .set_if_modified(accessibility_update.focus);
+        self.platform
+            .focused_accessibility_ancestors
+            .set_if_modified(self.accessibility.focused_ancestors(&self.tree.borrow()));
         let node_id = self.accessibility.focused_node_id().unwrap();
         let tree = self.tree.borrow();
         let layout_node = tree.layout.get(&node_id).unwrap();
         self.platform
             .focused_accessibility_node
-            .set_if_modified(AccessibilityTree::create_node(node_id, layout_node, &tree));
+            .set_if_modified(AccessibilityTree::create_node(
+                node_id,
+                layout_node,
+                &tree,
+                self.scale_factor,
+            ));
     }
 
     /// Poll async tasks and events every `step` time for a total time of `duration`.
     /// This is useful for animations for instance.
+    ///
+    /// With a frozen [AnimationClock] the time is moved forward instead of waited for.
     pub fn poll(&mut self, step: Duration, duration: Duration) {
-        let started = Instant::now();
-        while started.elapsed() < duration {
+        let started = self.animation_clock.now();
+        while self
+            .animation_clock
+            .now()
+            .saturating_duration_since(started)
+            < duration
+        {
             self.handle_events_immediately();
             self.sync_and_update();
-            std::thread::sleep(step);
+            self.wait(step);
             self.ticker_sender.broadcast_blocking(()).unwrap();
         }
     }
 
     /// Poll async tasks and events every `step`, N times.
     /// This is useful for animations for instance.
+    ///
+    /// With a frozen [AnimationClock] the time is moved forward instead of waited for.
     pub fn poll_n(&mut self, step: Duration, times: u32) {
         for _ in 0..times {
             self.handle_events_immediately();
             self.sync_and_update();
-            std::thread::sleep(step);
+            self.wait(step);
             self.ticker_sender.broadcast_blocking(()).unwrap();
         }
     }
 
+    /// Freeze the [AnimationClock] and move it forward by `duration` in a single frame,
+    /// so animations can be checked at exact points in time.
+    ///
+    /// Freeze the clock before the animation starts to not count the time it took to get here.
+    pub fn move_time(&mut self, duration: Duration) {
+        self.animation_clock.freeze();
+        self.handle_events_immediately();
+        self.sync_and_update();
+        self.animation_clock.advance(duration);
+        self.ticker_sender.broadcast_blocking(()).unwrap();
+        self.handle_events_immediately();
+        self.sync_and_update();
+    }
+
+    /// Send the events of a recording, waiting the time between them like they were received.
+    /// Their positions are moved to fit the scale factor and size of the runner.
+    ///
+    /// With a frozen [AnimationClock] the time is moved forward instead of waited for.
+    pub fn replay(&mut self, recording: &EventRecording) {
+        let mut last_timestamp = Duration::ZERO;
+        for RecordedEvent { timestamp, event } in recording.events_for(self.scale_factor, self.size)
+        {
+            let elapsed = timestamp.saturating_sub(last_timestamp);
+            if !elapsed.is_zero() {
+                self.wait(elapsed);
+                self.ticker_sender.broadcast_blocking(()).unwrap();
+            }
+            last_timestamp = timestamp;
+            self.send_event(event);
+            self.handle_events_immediately();
+            self.sync_and_update();
+        }
+    }
+
+    fn wait(&self, step: Duration) {
+        if self.animation_clock.is_frozen() {
+            self.animation_clock.advance(step);
+        } else {
+            std::thread::sleep(step);
+        }
+    }
+
     pub fn send_event(&mut self, platform_event: PlatformEvent) {
+        let mut tree = self.tree.borrow_mut();
         let mut events_measurer_adapter = EventsMeasurerAdapter {
-            tree: &mut self.tree.borrow_mut(),
+            tree: &mut tree,
             scale_factor: self.scale_factor,
         };
         let processed_events = events_measurer_adapter.run(
@@ -403,14 +497,39 @@ impl TestingRunner {
         self.events_sender
             .unbounded_send(EventsChunk::Processed(processed_events))
             .unwrap();
+        if let Some(cursor) = tree.hovered_cursor.take_change() {
+            *self.cursor.borrow_mut() = cursor;
+        }
+    }
+
+    /// Request an action on the given node like an assistive technology would.
+    pub fn request_a11y_action(
+        &mut self,
+        target: AccessibilityId,
+        action: accesskit::Action,
+        data: Option<accesskit::ActionData>,
+    ) {
+        self.accessibility.process_action(
+            action,
+            target,
+            data,
+            &mut self.tree.borrow_mut(),
+            &self.events_sender,
+        );
+        self.sync_and_update();
     }
 
     pub fn move_cursor(&mut self, cursor: impl Into<CursorPoint>) {
+        let cursor = cursor.into();
         self.send_event(PlatformEvent::Mouse {
             name: MouseEventName::MouseMove,
-            cursor: cursor.into(),
+            cursor,
             button: Some(MouseButton::Left),
-        })
+            click_count: 0,
+        });
+        if let Some(drag_event) = self.drag_tracker.moved(cursor) {
+            self.send_event(drag_event);
+        }
     }
 
     pub fn write_text(&mut self, text: impl ToString) {
@@ -424,7 +543,13 @@ impl TestingRunner {
         self.sync_and_update();
     }
 
+    /// Press a key, [Key::Named] `Escape` cancels the in-app drag like renderers do.
     pub fn press_key(&mut self, key: Key) {
+        if key == Key::Named(NamedKey::Escape)
+            && let Some(cancel_event) = self.drag_tracker.cancel(CursorPoint::default())
+        {
+            self.send_event(cancel_event);
+        }
         self.send_event(PlatformEvent::Keyboard {
             name: KeyboardEventName::KeyDown,
             key,
@@ -436,38 +561,46 @@ impl TestingRunner {
 
     pub fn press_cursor(&mut self, cursor: impl Into<CursorPoint>) {
         let cursor = cursor.into();
+        let button = Some(MouseButton::Left);
+        self.drag_tracker.press(cursor);
         self.send_event(PlatformEvent::Mouse {
             name: MouseEventName::MouseDown,
             cursor,
-            button: Some(MouseButton::Left),
+            button,
+            click_count: self.click_counter.press(cursor, button),
         });
         self.sync_and_update();
     }
 
+    /// Release the cursor, emitting a double click event if this completes one.
     pub fn release_cursor(&mut self, cursor: impl Into<CursorPoint>) {
         let cursor = cursor.into();
+        let button = Some(MouseButton::Left);
+        let click_count = self.click_counter.count();
+        if let Some(drop_event) = self.drag_tracker.release(cursor) {
+            self.send_event(drop_event);
+        }
         self.send_event(PlatformEvent::Mouse {
             name: MouseEventName::MouseUp,
             cursor,
-            button: Some(MouseButton::Left),
+            button,
+            click_count,
         });
+        if click_count == 2 {
+            self.send_event(PlatformEvent::Mouse {
+                name: MouseEventName::DoubleClick,
+                cursor,
+                button,
+                click_count,
+            });
+        }
         self.sync_and_update();
     }
 
     pub fn click_cursor(&mut self, cursor: impl Into<CursorPoint>) {
         let cursor = cursor.into();
-        self.send_event(PlatformEvent::Mouse {
-            name: MouseEventName::MouseDown,
-            cursor,
-            button: Some(MouseButton::Left),
-        });
-        self.sync_and_update();
-        self.send_event(PlatformEvent::Mouse {
-            name: MouseEventName::MouseUp,
-            cursor,
-            button: Some(MouseButton::Left),
-        });
-        self.sync_and_update();
+        self.press_cursor(cursor);
+        self.release_cursor(cursor);
     }
 
     pub fn scroll(&mut self, cursor: impl Into<CursorPoint>, scroll: impl Into<CursorPoint>) {
@@ -482,29 +615,186 @@ impl TestingRunner {
         self.sync_and_update();
     }
 
+    /// Send a touchpad gesture of the given `delta` at `cursor`, like a pinch.
+    pub fn gesture(
+        &mut self,
+        cursor: impl Into<CursorPoint>,
+        delta: GestureDelta,
+        phase: TouchPhase,
+    ) {
+        let name = match delta {
+            GestureDelta::Pinch(_) => GestureEventName::Pinch,
+            GestureDelta::Rotate(_) => GestureEventName::Rotate,
+            GestureDelta::Pan(_) => GestureEventName::Pan,
+        };
+        self.send_event(PlatformEvent::Gesture {
+            name,
+            cursor: cursor.into(),
+            delta,
+            phase,
+        });
+        self.sync_and_update();
+    }
+
+    /// Touch the screen with the given finger, touches of different fingers can be interleaved.
+    pub fn touch(&mut self, finger_id: u64, location: impl Into<CursorPoint>, phase: TouchPhase) {
+        let location = location.into();
+        let name = match phase {
+            TouchPhase::Started => TouchEventName::TouchStart,
+            TouchPhase::Moved => TouchEventName::TouchMove,
+            TouchPhase::Ended => TouchEventName::TouchEnd,
+            TouchPhase::Cancelled => TouchEventName::TouchCancel,
+        };
+        let drag_event = match phase {
+            TouchPhase::Started => {
+                self.drag_tracker.press(location);
+                None
+            }
+            TouchPhase::Moved => self.drag_tracker.moved(location),
+            TouchPhase::Ended => self.drag_tracker.release(location),
+            TouchPhase::Cancelled => self.drag_tracker.cancel(location),
+        };
+        if let Some(drag_event) = drag_event {
+            self.send_event(drag_event);
+        }
+        self.send_event(PlatformEvent::Touch {
+            name,
+            location,
+            finger_id,
+            phase,
+            force: None,
+        });
+        self.sync_and_update();
+    }
+
+    /// Move a finger from `from` to `to` in `steps` touch moves.
+    pub fn swipe(
+        &mut self,
+        finger_id: u64,
+        from: impl Into<CursorPoint>,
+        to: impl Into<CursorPoint>,
+        steps: u32,
+    ) {
+        let from = from.into();
+        let to = to.into();
+        self.touch(finger_id, from, TouchPhase::Started);
+        for step in 1..=steps {
+            let location = from.lerp(to, step as f64 / steps as f64);
+            self.touch(finger_id, location, TouchPhase::Moved);
+        }
+        self.touch(finger_id, to, TouchPhase::Ended);
+    }
+
+    /// Hover a file being dragged from outside of the app over `cursor`.
+    pub fn hover_file(&mut self, cursor: impl Into<CursorPoint>, file_path: impl Into<PathBuf>) {
+        self.send_event(PlatformEvent::File {
+            name: FileEventName::FileHover,
+            cursor: cursor.into(),
+            file_path: Some(file_path.into()),
+        });
+        self.sync_and_update();
+    }
+
+    /// Drop a file from outside of the app at `cursor`.
+    pub fn drop_file(&mut self, cursor: impl Into<CursorPoint>, file_path: impl Into<PathBuf>) {
+        self.send_event(PlatformEvent::File {
+            name: FileEventName::FileDrop,
+            cursor: cursor.into(),
+            file_path: Some(file_path.into()),
+        });
+        self.sync_and_update();
+    }
+
+    /// Update the IME preedit text, starting a composition if there is none.
+    /// An empty text cancels the composition.
+    pub fn ime_preedit(&mut self, text: impl ToString, cursor: Option<(usize, usize)>) {
+        for event in self.ime_composition.preedit(text.to_string(), cursor) {
+            self.send_event(event);
+        }
+        self.sync_and_update();
+    }
+
+    /// Commit text from the IME, ending the composition if there is one.
+    /// Like the renderer does, the text is typed before the composition ends.
+    pub fn ime_commit(&mut self, text: impl ToString) {
+        let text = text.to_string();
+        let composition_end = self.ime_composition.end(text.clone());
+        self.send_event(PlatformEvent::Keyboard {
+            name: KeyboardEventName::KeyDown,
+            key: Key::Character(text),
+            code: Code::Unidentified,
+            modifiers: Modifiers::default(),
+        });
+        if let Some(event) = composition_end {
+            self.send_event(event);
+        }
+        self.sync_and_update();
+    }
+
+    /// Text selected across the selectable elements, if any.
+    pub fn selected_text(&self) -> Option<String> {
+        let tree = self.tree.borrow();
+        tree.text_selection.text(&tree)
+    }
+
+    /// Cursor the app is showing, set by [Cursor] or the cursor of the hovered element.
+    pub fn cursor(&self) -> MouseCursor {
+        self.cursor.borrow().clone()
+    }
+
     pub fn animation_clock(&mut self) -> &mut AnimationClock {
         &mut self.animation_clock
     }
 
     pub fn render(&mut self) -> SkData {
+        let mut surface = self.render_surface();
+        let image = surface.image_snapshot();
+        let mut context = surface.direct_context();
+        image
+            .encode(context.as_mut(), EncodedImageFormat::PNG, None)
+            .expect("Failed to encode the snapshot.")
+    }
+
+    /// Render and read the painted pixels, to check the colors of specific points.
+    pub fn render_pixels(&mut self) -> RenderedPixels {
+        let mut surface = self.render_surface();
+        let (width, height) = (self.size.width as i32, self.size.height as i32);
+        let info = ImageInfo::new(
+            (width, height),
+            ColorType::RGBA8888,
+            AlphaType::Unpremul,
+            None,
+        );
+        let mut bytes = vec![0; width as usize * height as usize * 4];
+        assert!(
+            surface.read_pixels(&info, &mut bytes, width as usize * 4, (0, 0)),
+            "Failed to read the pixels."
+        );
+        RenderedPixels {
+            width: width as usize,
+            bytes,
+        }
+    }
+
+    fn render_surface(&mut self) -> Surface {
         let mut surface = raster_n32_premul((self.size.width as i32, self.size.height as i32))
             .expect("Failed to create the surface.");
 
+        let tree = self.tree.borrow();
         let render_pipeline = RenderPipeline {
             font_collection: &mut self.font_collection,
             font_manager: &self.font_manager,
-            tree: &self.tree.borrow(),
+            tree: &tree,
             canvas: surface.canvas(),
             scale_factor: self.scale_factor,
             background: Color::WHITE,
         };
         render_pipeline.render();
+        tree.repaints.render(surface.canvas(), &tree);
+        drop(tree);
+        self.tree.borrow_mut().repaints.clear();
 
-        let image = surface.image_snapshot();
-        let mut context = surface.direct_context();
-        image
-            .encode(context.as_mut(), EncodedImageFormat::PNG, None)
-            .expect("Failed to encode the snapshot.")
+        surface
     }
 
     pub fn render_to_file(&mut self, path: impl Into<PathBuf>) {
@@ -563,6 +853,50 @@ impl TestingRunner {
 
         matched
     }
+
+    /// First label or paragraph whose whole text is `text`.
+    pub fn find_by_text(&self, text: &str) -> Option<TestingNode> {
+        self.find(|node, element| {
+            let matches = if let Some(label) = Label::try_downcast(element) {
+                label.text == text
+            } else if let Some(paragraph) = Paragraph::try_downcast(element) {
+                paragraph
+                    .spans
+                    .iter()
+                    .map(|span| span.text.as_ref())
+                    .collect::<String>()
+                    == text
+            } else {
+                false
+            };
+            matches.then_some(node)
+        })
+    }
+
+    /// First element with the given accessibility role.
+    pub fn find_by_role(&self, role: AccessibilityRole) -> Option<TestingNode> {
+        self.find(|node, element| (element.accessibility().builder.role() == role).then_some(node))
+    }
+
+    /// All the elements with the given accessibility role.
+    pub fn find_all_by_role(&self, role: AccessibilityRole) -> Vec<TestingNode> {
+        self.find_many(|node, element| {
+            (element.accessibility().builder.role() == role).then_some(node)
+        })
+    }
+
+    /// Element with the given accessibility id.
+    pub fn find_by_a11y_id(&self, a11y_id: AccessibilityId) -> Option<TestingNode> {
+        self.find(|node, _| {
+            let matches = self
+                .tree
+                .borrow()
+                .accessibility_state
+                .get(&node.id)
+                .is_some_and(|state| state.a11y_id == a11y_id);
+            matches.then_some(node)
+        })
+    }
 }
 
 pub struct TestingNode {
@@ -606,6 +940,15 @@ impl TestingNode {
         effect_state.is_visible(&self.tree.borrow().layout, &layout.area)
     }
 
+    pub fn a11y_id(&self) -> AccessibilityId {
+        self.tree
+            .borrow()
+            .accessibility_state
+            .get(&self.id)
+            .map(|state| state.a11y_id)
+            .expect("Element does not exist.")
+    }
+
     pub fn element(&self) -> Rc<dyn ElementExt> {
         self.tree
             .borrow()
@@ -615,3 +958,18 @@ impl TestingNode {
             .expect("Element does not exist.")
     }
 }
+
+/// Pixels painted by [TestingRunner::render_pixels].
+pub struct RenderedPixels {
+    width: usize,
+    bytes: Vec<u8>,
+}
+
+impl RenderedPixels {
+    /// Color of the pixel at the given physical position.
+    pub fn color(&self, x: usize, y: usize) -> Color {
+        let i = (y * self.width + x) * 4;
+        let [r, g, b, a]: [u8; 4] = self.bytes[i..i + 4].try_into().unwrap();
+        Color::from_argb(a, r, g, b)
+    }
+}
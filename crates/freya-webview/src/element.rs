@@ -50,6 +50,7 @@ pub struct WebViewElement {
     pub webview_id: WebViewId,
     pub config: WebViewConfig,
     pub relative_layer: Layer,
+    pub z_index: i16,
     pub effect: Option<EffectData>,
 }
 
@@ -60,6 +61,7 @@ impl PartialEq for WebViewElement {
             && self.webview_id == other.webview_id
             && self.config == other.config
             && self.relative_layer == other.relative_layer
+            && self.z_index == other.z_index
             && self.effect == other.effect
     }
 }
@@ -83,7 +85,7 @@ impl ElementExt for WebViewElement {
             diff.insert(DiffModifies::ACCESSIBILITY);
         }
 
-        if self.relative_layer != webview.relative_layer {
+        if self.relative_layer != webview.relative_layer || self.z_index != webview.z_index {
             diff.insert(DiffModifies::LAYER);
         }
 
@@ -122,6 +124,10 @@ impl ElementExt for WebViewElement {
         self.relative_layer
     }
 
+    fn z_index(&self) -> i16 {
+        self.z_index
+    }
+
     fn should_measure_inner_children(&self) -> bool {
         false
     }
@@ -208,6 +214,10 @@ impl LayerExt for WebView {
     fn get_layer(&mut self) -> &mut Layer {
         &mut self.element.relative_layer
     }
+
+    fn get_z_index(&mut self) -> &mut i16 {
+        &mut self.element.z_index
+    }
 }
 
 pub fn webview(url: impl Into<String>) -> WebView {
@@ -231,6 +241,7 @@ pub fn webview(url: impl Into<String>) -> WebView {
             webview_id,
             config,
             relative_layer: Layer::default(),
+            z_index: 0,
             effect: None,
         },
     }
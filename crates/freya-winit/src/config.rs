@@ -3,7 +3,9 @@ use std::{
     fmt::Debug,
     future::Future,
     io::Cursor,
+    path::PathBuf,
     pin::Pin,
+    time::Duration,
 };
 
 use bytes::Bytes;
@@ -23,6 +25,7 @@ use winit::{
 };
 
 use crate::{
+    monitor::MonitorSelector,
     plugins::{
         FreyaPlugin,
         PluginsManager,
@@ -43,6 +46,44 @@ pub enum CloseDecision {
     KeepOpen,
 }
 
+/// Effect shown behind the content of a Window, see [`WindowConfig::with_backdrop`].
+///
+/// Effects not supported by the platform fall back to a fully transparent Window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowBackdrop {
+    /// Fully transparent, shows what is behind the Window.
+    Transparent,
+    /// Blur what is behind the Window. Supported on macOS, KDE Wayland and Windows (as Acrylic).
+    Blur,
+    /// Windows 11 Mica material.
+    Mica,
+    /// Windows 11 Mica material for tabbed windows.
+    MicaAlt,
+    /// Windows 11 Acrylic material.
+    Acrylic,
+}
+
+impl WindowBackdrop {
+    pub(crate) fn apply(self, window_attributes: WindowAttributes) -> WindowAttributes {
+        #[cfg(target_os = "windows")]
+        let window_attributes = {
+            use winit::platform::windows::{
+                BackdropType,
+                WindowAttributesExtWindows,
+            };
+            window_attributes.with_system_backdrop(match self {
+                Self::Transparent => BackdropType::None,
+                Self::Blur | Self::Acrylic => BackdropType::TransientWindow,
+                Self::Mica => BackdropType::MainWindow,
+                Self::MicaAlt => BackdropType::TabbedWindow,
+            })
+        };
+        window_attributes
+            .with_transparent(true)
+            .with_blur(self != Self::Transparent)
+    }
+}
+
 /// Hook called when a window close is requested.
 /// Returns a [`CloseDecision`] to determine whether the window should actually close.
 pub type OnCloseHook =
@@ -66,6 +107,8 @@ pub struct WindowConfig {
     pub(crate) transparent: bool,
     /// Background color of the Window.
     pub(crate) background: Color,
+    /// Effect behind the content of the Window.
+    pub(crate) backdrop: Option<WindowBackdrop>,
     /// Enable Window resizable behaviour.
     pub(crate) resizable: bool,
     /// Icon for the Window.
@@ -78,6 +121,23 @@ pub struct WindowConfig {
     pub(crate) window_handle_hook: Option<WindowHandleHook>,
     /// Hook function called when the window is requested to close.
     pub(crate) on_close: Option<OnCloseHook>,
+    /// Maximum time between two clicks for them to be a double click, the OS setting when `None`.
+    pub(crate) double_click_interval: Option<Duration>,
+    /// Monitor where the Window opens.
+    pub(crate) monitor: Option<MonitorSelector>,
+    /// Width of the borders that resize the Window when it has no decorations.
+    pub(crate) resize_border: Option<f64>,
+    /// Maximum frames per second the Window renders at.
+    pub(crate) max_fps: Option<u32>,
+    /// Hide the Window instead of closing it when there is a tray.
+    #[cfg(feature = "tray")]
+    pub(crate) hide_to_tray: bool,
+    /// File where the events received by the Window are recorded.
+    #[cfg(feature = "event-recording")]
+    pub(crate) event_recording: Option<PathBuf>,
+    /// Recording file whose events are sent to the Window once it opens.
+    #[cfg(feature = "event-recording")]
+    pub(crate) event_replay: Option<PathBuf>,
 }
 
 impl Debug for WindowConfig {
@@ -90,9 +150,14 @@ impl Debug for WindowConfig {
             .field("title", &self.title)
             .field("transparent", &self.transparent)
             .field("background", &self.background)
+            .field("backdrop", &self.backdrop)
             .field("resizable", &self.resizable)
             .field("icon", &self.icon)
             .field("app_id", &self.app_id)
+            .field("double_click_interval", &self.double_click_interval)
+            .field("monitor", &self.monitor)
+            .field("resize_border", &self.resize_border)
+            .field("max_fps", &self.max_fps)
             .finish()
     }
 }
@@ -118,12 +183,23 @@ impl WindowConfig {
             title: "Freya",
             transparent: false,
             background: Color::WHITE,
+            backdrop: None,
             resizable: true,
             icon: None,
             app_id: None,
             window_attributes_hook: None,
             window_handle_hook: None,
             on_close: None,
+            double_click_interval: None,
+            monitor: None,
+            resize_border: None,
+            max_fps: None,
+            #[cfg(feature = "tray")]
+            hide_to_tray: false,
+            #[cfg(feature = "event-recording")]
+            event_recording: None,
+            #[cfg(feature = "event-recording")]
+            event_replay: None,
         }
     }
 
@@ -169,6 +245,22 @@ impl WindowConfig {
         self
     }
 
+    /// Show a transparent or blurred backdrop behind the content of the Window,
+    /// the background becomes transparent so call [`WindowConfig::with_background`] after this to tint it.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use freya::prelude::*;
+    /// WindowConfig::new(app).with_backdrop(WindowBackdrop::Mica);
+    /// # fn app() -> impl IntoElement { "" }
+    /// ```
+    pub fn with_backdrop(mut self, backdrop: WindowBackdrop) -> Self {
+        self.backdrop = Some(backdrop);
+        self.transparent = true;
+        self.background = Color::TRANSPARENT;
+        self
+    }
+
     /// Is Window resizable.
     pub fn with_resizable(mut self, resizable: bool) -> Self {
         self.resizable = resizable;
@@ -196,6 +288,58 @@ impl WindowConfig {
         self
     }
 
+    /// Specify the maximum time between two clicks for them to be a double click.
+    /// Defaults to the OS setting, or [DOUBLE_CLICK_INTERVAL] where the platform doesn't expose one.
+    pub fn with_double_click_interval(mut self, double_click_interval: Duration) -> Self {
+        self.double_click_interval = Some(double_click_interval);
+        self
+    }
+
+    /// Resize the Window when pressing within `width` logical pixels of its edges,
+    /// for resizable Windows without decorations.
+    pub fn with_resize_border(mut self, width: f64) -> Self {
+        self.resize_border = Some(width);
+        self
+    }
+
+    /// Limit how many frames per second the Window renders, `None` renders as fast as requested.
+    /// Useful to save battery in apps with long running animations.
+    pub fn with_max_fps(mut self, max_fps: Option<u32>) -> Self {
+        self.max_fps = max_fps;
+        self
+    }
+
+    /// Open the Window centered in the given monitor, if it exists.
+    pub fn with_monitor(mut self, monitor: MonitorSelector) -> Self {
+        self.monitor = Some(monitor);
+        self
+    }
+
+    /// Hide the Window instead of closing it when the user closes it and the app has a tray.
+    /// Show it again from a tray event handler, see [crate::tray::use_tray_event].
+    #[cfg(feature = "tray")]
+    pub fn with_hide_to_tray(mut self, hide_to_tray: bool) -> Self {
+        self.hide_to_tray = hide_to_tray;
+        self
+    }
+
+    /// Record the events received by the Window to the given file, one JSON line per event.
+    /// Replay them with [WindowConfig::with_event_replay] or with `TestingRunner::replay`
+    /// after loading them with [EventRecording::load](freya_core::integration::EventRecording::load).
+    #[cfg(feature = "event-recording")]
+    pub fn with_event_recording(mut self, path: impl Into<PathBuf>) -> Self {
+        self.event_recording = Some(path.into());
+        self
+    }
+
+    /// Send the events of the given recording file to the Window once it opens, as they were received.
+    /// Their positions are moved to fit the scale factor and size of the Window.
+    #[cfg(feature = "event-recording")]
+    pub fn with_event_replay(mut self, path: impl Into<PathBuf>) -> Self {
+        self.event_replay = Some(path.into());
+        self
+    }
+
     /// Register a Window Attributes hook.
     pub fn with_window_attributes(
         mut self,
@@ -231,7 +375,7 @@ impl WindowConfig {
 
 pub type EmbeddedFonts = Vec<(Cow<'static, str>, Bytes)>;
 #[cfg(feature = "tray")]
-pub type TrayIconGetter = Box<dyn FnOnce() -> tray_icon::TrayIcon + Send>;
+pub type TrayIconGetter = Box<dyn FnOnce() -> Option<tray_icon::TrayIcon> + Send>;
 #[cfg(feature = "tray")]
 pub type TrayHandler =
     Box<dyn FnMut(crate::tray_icon::TrayEvent, crate::renderer::RendererContext)>;
@@ -323,7 +467,27 @@ impl LaunchConfig {
         tray_handler: impl FnMut(crate::tray_icon::TrayEvent, crate::renderer::RendererContext)
         + 'static,
     ) -> Self {
-        self.tray = (Some(Box::new(tray_icon)), Some(Box::new(tray_handler)));
+        self.tray = (
+            Some(Box::new(move || Some(tray_icon()))),
+            Some(Box::new(tray_handler)),
+        );
+        self
+    }
+
+    /// Register a tray icon from a [TrayConfig](crate::tray::TrayConfig).
+    /// Its events are handled from components with [use_tray_event](crate::tray::use_tray_event).
+    /// The app launches without the tray if it can't be created.
+    #[cfg(feature = "tray")]
+    pub fn with_tray_config(mut self, tray_config: crate::tray::TrayConfig) -> Self {
+        self.tray = (
+            Some(Box::new(move || {
+                tray_config
+                    .build()
+                    .inspect_err(|err| tracing::error!("Failed to create the tray icon: {err}"))
+                    .ok()
+            })),
+            Some(Box::new(|_, _| {})),
+        );
         self
     }
 
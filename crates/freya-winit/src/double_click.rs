@@ -0,0 +1,31 @@
+use std::time::Duration;
+
+use freya_core::integration::DOUBLE_CLICK_INTERVAL;
+
+/// Maximum time between two clicks for them to be a double click, as configured in the OS.
+///
+/// Read from `GetDoubleClickTime` on Windows and `NSEvent.doubleClickInterval` on macOS.
+/// Other platforms don't expose it and fall back to [DOUBLE_CLICK_INTERVAL].
+pub(crate) fn system_double_click_interval() -> Duration {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::UI::Input::KeyboardAndMouse::GetDoubleClickTime;
+
+        let millis = unsafe { GetDoubleClickTime() };
+        if millis > 0 {
+            return Duration::from_millis(millis as u64);
+        }
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        use objc2_app_kit::NSEvent;
+
+        let seconds = NSEvent::doubleClickInterval();
+        if seconds.is_finite() && seconds > 0. {
+            return Duration::from_secs_f64(seconds);
+        }
+    }
+
+    DOUBLE_CLICK_INTERVAL
+}
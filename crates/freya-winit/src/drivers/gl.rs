@@ -21,6 +21,7 @@ use gl::{
 };
 use glutin::{
     config::{
+        Config,
         ConfigTemplateBuilder,
         GlConfig,
     },
@@ -32,6 +33,8 @@ use glutin::{
         PossiblyCurrentContext,
     },
     display::{
+        Display,
+        DisplayApiPreference,
         GetGlDisplay,
         GlDisplay,
     },
@@ -47,7 +50,11 @@ use glutin::{
     },
 };
 use glutin_winit::DisplayBuilder;
-use raw_window_handle::HasWindowHandle;
+use raw_window_handle::{
+    HasWindowHandle,
+    RawDisplayHandle,
+    RawWindowHandle,
+};
 use winit::{
     dpi::PhysicalSize,
     event_loop::ActiveEventLoop,
@@ -105,47 +112,86 @@ impl OpenGLDriver {
 
         let window = window.expect("Could not create window with OpenGL context");
 
-        let window_handle = window.window_handle().unwrap();
+        let window_handle = window.window_handle().unwrap().as_raw();
+
+        let driver = Self::with_config(gl_config, window_handle, window.inner_size())
+            .expect("Could not create the OpenGL driver");
+
+        (driver, window)
+    }
+
+    /// Create the driver for a window not owned by winit.
+    ///
+    /// # Safety
+    /// The handles must be valid for as long as the driver lives.
+    pub unsafe fn from_raw_handles(
+        display_handle: RawDisplayHandle,
+        window_handle: RawWindowHandle,
+        size: PhysicalSize<u32>,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        #[cfg(target_os = "windows")]
+        let preference = DisplayApiPreference::WglThenEgl(Some(window_handle));
+        #[cfg(not(target_os = "windows"))]
+        let preference = DisplayApiPreference::Egl;
+
+        let display = unsafe { Display::new(display_handle, preference) }?;
+
+        let template = ConfigTemplateBuilder::new()
+            .with_alpha_size(8)
+            .compatible_with_native_window(window_handle)
+            .build();
+
+        let gl_config = unsafe { display.find_configs(template) }?
+            .reduce(|accum, config| {
+                if config.num_samples() < accum.num_samples() {
+                    config
+                } else {
+                    accum
+                }
+            })
+            .ok_or("No OpenGL config available")?;
 
+        Self::with_config(gl_config, window_handle, size)
+    }
+
+    fn with_config(
+        gl_config: Config,
+        window_handle: RawWindowHandle,
+        size: PhysicalSize<u32>,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
         let context_attributes = ContextAttributesBuilder::new()
             .with_profile(GlProfile::Core)
-            .build(Some(window_handle.as_raw()));
+            .build(Some(window_handle));
 
         let fallback_context_attributes = ContextAttributesBuilder::new()
             .with_profile(GlProfile::Core)
             .with_context_api(ContextApi::Gles(None))
-            .build(Some(window_handle.as_raw()));
+            .build(Some(window_handle));
 
         let not_current_gl_context = unsafe {
             gl_config
                 .display()
                 .create_context(&gl_config, &context_attributes)
-                .unwrap_or_else(|_| {
+                .or_else(|_| {
                     gl_config
                         .display()
                         .create_context(&gl_config, &fallback_context_attributes)
-                        .expect("failed to create context")
-                })
+                })?
         };
 
-        let size = window.inner_size();
-
         let attrs = SurfaceAttributesBuilder::<WindowSurface>::new().build(
-            window_handle.as_raw(),
-            NonZeroU32::new(size.width).unwrap(),
-            NonZeroU32::new(size.height).unwrap(),
+            window_handle,
+            NonZeroU32::new(size.width.max(1)).unwrap(),
+            NonZeroU32::new(size.height.max(1)).unwrap(),
         );
 
         let gl_surface = unsafe {
             gl_config
                 .display()
-                .create_window_surface(&gl_config, &attrs)
-                .expect("Could not create gl window surface")
+                .create_window_surface(&gl_config, &attrs)?
         };
 
-        let gl_context = not_current_gl_context
-            .make_current(&gl_surface)
-            .expect("Could not make GL context current when setting up skia renderer");
+        let gl_context = not_current_gl_context.make_current(&gl_surface)?;
 
         // Try setting vsync.
         gl_surface
@@ -165,7 +211,7 @@ impl OpenGLDriver {
                 .display()
                 .get_proc_address(CString::new(name).unwrap().as_c_str())
         })
-        .expect("Could not create interface");
+        .ok_or("Could not create the OpenGL interface")?;
 
         let fb_info = {
             let mut fboid: GLint = 0;
@@ -181,8 +227,8 @@ impl OpenGLDriver {
         let num_samples = gl_config.num_samples() as usize;
         let stencil_size = gl_config.stencil_size() as usize;
 
-        let mut gr_context =
-            direct_contexts::make_gl(interface, None).expect("Could not create direct context");
+        let mut gr_context = direct_contexts::make_gl(interface, None)
+            .ok_or("Could not create the direct context")?;
 
         let render_target = backend_render_targets::make_gl(
             (size.width as i32, size.height as i32),
@@ -198,9 +244,9 @@ impl OpenGLDriver {
             None,
             None,
         )
-        .expect("Could not create skia surface");
+        .ok_or("Could not create the Skia surface")?;
 
-        let driver = OpenGLDriver {
+        Ok(OpenGLDriver {
             gl_context,
             gl_surface,
             gr_context,
@@ -208,19 +254,19 @@ impl OpenGLDriver {
             stencil_size,
             fb_info,
             surface,
-        };
-
-        (driver, window)
+        })
     }
 
-    pub fn present(&mut self, window: &Window, render: impl FnOnce(&mut SkiaSurface)) {
+    pub fn present(&mut self, window: Option<&Window>, render: impl FnOnce(&mut SkiaSurface)) {
         if !self.gl_context.is_current() {
             self.gl_context.make_current(&self.gl_surface).unwrap();
         }
 
         render(&mut self.surface);
 
-        window.pre_present_notify();
+        if let Some(window) = window {
+            window.pre_present_notify();
+        }
         self.gr_context.flush_submit_and_sync_cpu();
         if let Err(error) = self.gl_surface.swap_buffers(&self.gl_context) {
             tracing::error!("Failed to swap buffers: {:?}", error);
@@ -56,9 +56,27 @@ impl MetalDriver {
             .create_window(window_attributes)
             .expect("Could not create window with Metal context");
 
-        let device = MTLCreateSystemDefaultDevice().expect("No Metal-capable device found");
+        let raw_handle = window
+            .window_handle()
+            .expect("Could not get window handle")
+            .as_raw();
 
-        let size = window.inner_size();
+        let driver = unsafe { Self::from_raw_handle(raw_handle, window.inner_size(), transparent) }
+            .expect("Could not create the Metal driver");
+
+        (driver, window)
+    }
+
+    /// Create the driver for a window not owned by winit.
+    ///
+    /// # Safety
+    /// The handle must be valid for as long as the driver lives.
+    pub unsafe fn from_raw_handle(
+        raw_handle: RawWindowHandle,
+        size: PhysicalSize<u32>,
+        transparent: bool,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let device = MTLCreateSystemDefaultDevice().ok_or("No Metal-capable device found")?;
 
         let metal_layer = {
             let layer = CAMetalLayer::new();
@@ -75,20 +93,15 @@ impl MetalDriver {
                 layer.setOpaque(false);
             }
 
-            let raw_handle = window
-                .window_handle()
-                .expect("Could not get window handle")
-                .as_raw();
-
             match raw_handle {
                 RawWindowHandle::AppKit(appkit) => {
                     let view = unsafe { (appkit.ns_view.as_ptr() as *mut NSView).as_ref() }
-                        .expect("NSView pointer is null");
+                        .ok_or("NSView pointer is null")?;
 
                     view.setWantsLayer(true);
                     view.setLayer(Some(&layer));
                 }
-                _ => panic!("Metal driver only supports AppKit (macOS) windows"),
+                _ => return Err("Metal driver only supports AppKit (macOS) windows".into()),
             };
 
             layer
@@ -96,7 +109,7 @@ impl MetalDriver {
 
         let command_queue = device
             .newCommandQueue()
-            .expect("Could not create Metal command queue");
+            .ok_or("Could not create Metal command queue")?;
 
         let backend = unsafe {
             mtl::BackendContext::new(
@@ -106,21 +119,19 @@ impl MetalDriver {
         };
 
         let gr_context =
-            direct_contexts::make_metal(&backend, None).expect("Could not create Metal context");
+            direct_contexts::make_metal(&backend, None).ok_or("Could not create Metal context")?;
 
-        let driver = Self {
+        Ok(Self {
             metal_layer,
             command_queue,
             gr_context,
-        };
-
-        (driver, window)
+        })
     }
 
     pub fn present(
         &mut self,
         _size: PhysicalSize<u32>,
-        window: &Window,
+        window: Option<&Window>,
         render: impl FnOnce(&mut SkiaSurface),
     ) {
         let Some(drawable) = self.metal_layer.nextDrawable() else {
@@ -151,7 +162,9 @@ impl MetalDriver {
 
         render(&mut surface);
 
-        window.pre_present_notify();
+        if let Some(window) = window {
+            window.pre_present_notify();
+        }
         self.gr_context.flush_and_submit();
         drop(surface);
 
@@ -6,6 +6,10 @@ mod metal;
 mod vulkan;
 
 use freya_engine::prelude::Surface as SkiaSurface;
+use raw_window_handle::{
+    RawDisplayHandle,
+    RawWindowHandle,
+};
 use winit::{
     dpi::PhysicalSize,
     event_loop::ActiveEventLoop,
@@ -77,10 +81,32 @@ impl GraphicsDriver {
         }
     }
 
+    /// Create the driver for a window not owned by winit, using Metal on macOS and OpenGL elsewhere.
+    ///
+    /// # Safety
+    /// The handles must be valid for as long as the driver lives.
+    pub unsafe fn from_raw_handles(
+        _display_handle: RawDisplayHandle,
+        window_handle: RawWindowHandle,
+        size: PhysicalSize<u32>,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        #[cfg(target_os = "macos")]
+        let driver = Self::Metal(unsafe {
+            metal::MetalDriver::from_raw_handle(window_handle, size, false)
+        }?);
+
+        #[cfg(not(target_os = "macos"))]
+        let driver = Self::OpenGl(unsafe {
+            gl::OpenGLDriver::from_raw_handles(_display_handle, window_handle, size)
+        }?);
+
+        Ok(driver)
+    }
+
     pub fn present(
         &mut self,
         _size: PhysicalSize<u32>,
-        window: &Window,
+        window: Option<&Window>,
         render: impl FnOnce(&mut SkiaSurface),
     ) {
         match self {
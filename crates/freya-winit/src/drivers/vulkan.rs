@@ -247,7 +247,7 @@ impl VulkanDriver {
     pub fn present(
         &mut self,
         size: PhysicalSize<u32>,
-        window: &Window,
+        window: Option<&Window>,
         render: impl FnOnce(&mut SkiaSurface),
     ) {
         if size.width == 0 || size.height == 0 {
@@ -310,7 +310,9 @@ impl VulkanDriver {
 
         render(&mut surface);
 
-        window.pre_present_notify();
+        if let Some(window) = window {
+            window.pre_present_notify();
+        }
 
         self.gr_context.flush_and_submit();
 
@@ -0,0 +1,346 @@
+use std::{
+    borrow::Cow,
+    cell::RefCell,
+    rc::Rc,
+};
+
+use freya_clipboard::copypasta::{
+    ClipboardContext,
+    ClipboardProvider,
+};
+use freya_components::integration::integration;
+use freya_core::{
+    integration::*,
+    prelude::{
+        Color,
+        CursorIcon,
+        MouseCursor,
+    },
+};
+use freya_engine::prelude::{
+    FontCollection,
+    FontMgr,
+};
+use ragnarok::{
+    EventsExecutorRunner,
+    NodesState,
+};
+use raw_window_handle::{
+    RawDisplayHandle,
+    RawWindowHandle,
+};
+use torin::prelude::Size2D;
+use winit::dpi::PhysicalSize;
+
+use crate::{
+    drivers::GraphicsDriver,
+    setup::{
+        create_fonts,
+        provide_root_contexts,
+    },
+};
+
+#[derive(Default)]
+struct EmbeddedRequests {
+    redraw: bool,
+    cursor: MouseCursor,
+    focus: Vec<AccessibilityFocusStrategy>,
+    show_repaints: Option<bool>,
+}
+
+/// Render a Freya app inside a window owned by something else, such as a game engine or a plugin host.
+///
+/// The host keeps its event loop: it forwards the input as [PlatformEvent]s with [EmbeddedRenderer::handle_event],
+/// reports size changes with [EmbeddedRenderer::resize] and calls [EmbeddedRenderer::render] every frame,
+/// or only when [EmbeddedRenderer::needs_render] is `true`.
+///
+/// Window APIs such as [crate::WinitPlatformExt] and [crate::use_window] are not available to embedded apps.
+pub struct EmbeddedRenderer {
+    runner: Runner,
+    tree: Tree,
+    driver: GraphicsDriver,
+    nodes_state: NodesState<NodeId>,
+    accessibility: AccessibilityTree,
+
+    events_receiver: futures_channel::mpsc::UnboundedReceiver<EventsChunk>,
+    events_sender: futures_channel::mpsc::UnboundedSender<EventsChunk>,
+
+    font_collection: FontCollection,
+    font_manager: FontMgr,
+    fallback_fonts: Vec<Cow<'static, str>>,
+
+    platform: Platform,
+    ticker_sender: RenderingTickerSender,
+    requests: Rc<RefCell<EmbeddedRequests>>,
+
+    size: PhysicalSize<u32>,
+    scale_factor: f64,
+    background: Color,
+    process_layout: bool,
+}
+
+impl EmbeddedRenderer {
+    /// Create a renderer for the given window, `size` is in physical pixels.
+    /// Fails if the graphics driver can't be created for the window.
+    ///
+    /// # Safety
+    /// The handles must be valid for as long as the renderer lives.
+    pub unsafe fn new(
+        app: impl Into<AppComponent>,
+        display_handle: RawDisplayHandle,
+        window_handle: RawWindowHandle,
+        size: PhysicalSize<u32>,
+        scale_factor: f64,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let driver =
+            unsafe { GraphicsDriver::from_raw_handles(display_handle, window_handle, size) }?;
+
+        let (events_sender, events_receiver) = futures_channel::mpsc::unbounded();
+
+        let app = app.into();
+        let mut runner = Runner::new(move || integration(app.clone()).into_element());
+
+        let tree = Tree::default();
+
+        let requests = Rc::new(RefCell::new(EmbeddedRequests::default()));
+        let platform = runner.provide_root_context({
+            let requests = requests.clone();
+            move || Platform {
+                focused_accessibility_id: State::create(ACCESSIBILITY_ROOT_ID),
+                focused_accessibility_node: State::create(accesskit::Node::new(
+                    accesskit::Role::Window,
+                )),
+                focused_accessibility_ancestors: State::create(Vec::new()),
+                root_size: State::create(Size2D::new(size.width as f32, size.height as f32)),
+                navigation_mode: State::create(NavigationMode::NotKeyboard),
+                preferred_theme: State::create(PreferredTheme::Light),
+                sender: Rc::new(move |user_event| {
+                    let mut requests = requests.borrow_mut();
+                    match user_event {
+                        UserEvent::RequestRedraw | UserEvent::SetRenderMode(_) => {
+                            requests.redraw = true;
+                        }
+                        UserEvent::FocusAccessibilityNode(strategy) => {
+                            requests.focus.push(strategy);
+                            requests.redraw = true;
+                        }
+                        UserEvent::SetCursorIcon(cursor_icon) => {
+                            requests.cursor = MouseCursor::Icon(cursor_icon);
+                        }
+                        UserEvent::SetCustomCursor(custom_cursor) => {
+                            requests.cursor = MouseCursor::Custom(custom_cursor);
+                        }
+                        UserEvent::ShowRepaints(show) => {
+                            requests.show_repaints = Some(show);
+                            requests.redraw = true;
+                        }
+                        UserEvent::Erased(_) => {
+                            tracing::warn!("Window APIs are not supported by embedded apps.");
+                        }
+                    }
+                }),
+            }
+        });
+
+        let clipboard = ClipboardContext::new()
+            .ok()
+            .map(|c| Box::new(c) as Box<dyn ClipboardProvider>);
+
+        let (font_collection, font_manager) = create_fonts(Vec::new());
+
+        let ticker_sender = provide_root_contexts(
+            &mut runner,
+            &tree,
+            ScreenReader::new(),
+            clipboard,
+            &font_collection,
+        );
+
+        Ok(Self {
+            runner,
+            tree,
+            driver,
+            nodes_state: NodesState::default(),
+            accessibility: AccessibilityTree::default(),
+
+            events_receiver,
+            events_sender,
+
+            font_collection,
+            font_manager,
+            fallback_fonts: default_fonts(),
+
+            platform,
+            ticker_sender,
+            requests,
+
+            size,
+            scale_factor,
+            background: Color::WHITE,
+            process_layout: true,
+        })
+    }
+
+    /// Change the color the window is cleared with before rendering the app.
+    pub fn set_background(&mut self, background: impl Into<Color>) {
+        self.background = background.into();
+        self.requests.borrow_mut().redraw = true;
+    }
+
+    /// Dispatch an input event, its handlers run in the next [EmbeddedRenderer::render].
+    pub fn handle_event(&mut self, platform_event: PlatformEvent) {
+        let mut events_measurer_adapter = EventsMeasurerAdapter {
+            tree: &mut self.tree,
+            scale_factor: self.scale_factor,
+        };
+        let processed_events = events_measurer_adapter.run(
+            &mut vec![platform_event],
+            &mut self.nodes_state,
+            self.accessibility.focused_node_id(),
+        );
+        self.events_sender
+            .unbounded_send(EventsChunk::Processed(processed_events))
+            .ok();
+        let mut requests = self.requests.borrow_mut();
+        if let Some(cursor) = self.tree.hovered_cursor.take_change() {
+            requests.cursor = cursor;
+        }
+        requests.redraw = true;
+    }
+
+    /// Update the size in physical pixels and the scale factor of the window.
+    pub fn resize(&mut self, size: PhysicalSize<u32>, scale_factor: f64) {
+        if scale_factor != self.scale_factor {
+            self.scale_factor = scale_factor;
+            self.tree.layout.reset();
+            self.tree.text_cache.reset();
+        } else {
+            self.tree.layout.invalidate(NodeId::ROOT);
+        }
+        self.size = size;
+        self.driver.resize(size);
+        self.process_layout = true;
+    }
+
+    /// Whether something changed since the last [EmbeddedRenderer::render],
+    /// including tasks woken up by timers or by the rendering ticker of animations.
+    pub fn needs_render(&mut self) -> bool {
+        self.process_layout
+            || self.requests.borrow().redraw
+            || self.tree.transitions.is_running()
+            || self.runner.has_pending_work()
+    }
+
+    /// Cursor icon requested by the app, the host is in charge of showing it.
+    pub fn cursor_icon(&self) -> CursorIcon {
+        match self.requests.borrow().cursor {
+            MouseCursor::Icon(cursor_icon) => cursor_icon,
+            MouseCursor::Custom(_) => CursorIcon::default(),
+        }
+    }
+
+    /// Like [EmbeddedRenderer::cursor_icon] but it can also be a custom image.
+    pub fn cursor(&self) -> MouseCursor {
+        self.requests.borrow().cursor.clone()
+    }
+
+    /// Run the pending events and tasks, update the app and render it to the window.
+    pub fn render(&mut self) {
+        while let Ok(events_chunk) = self.events_receiver.try_recv() {
+            match events_chunk {
+                EventsChunk::Processed(processed_events) => {
+                    let events_executor_adapter = EventsExecutorAdapter {
+                        runner: &mut self.runner,
+                    };
+                    events_executor_adapter.run(&mut self.nodes_state, processed_events);
+                }
+                EventsChunk::Batch(events) => {
+                    for event in events {
+                        self.runner.handle_event(
+                            event.node_id,
+                            event.name,
+                            event.data,
+                            event.bubbles,
+                        );
+                    }
+                }
+            }
+        }
+
+        let mutations = self.runner.sync_and_update();
+        let result = self.runner.run_in(|| {
+            let mut result = self.tree.apply_mutations(mutations);
+            if self.tree.transitions.is_running() {
+                result.needs_render |= self.tree.advance_transitions().needs_render;
+            }
+            result
+        });
+        if result.needs_render {
+            self.process_layout = true;
+        }
+
+        for strategy in self.requests.borrow_mut().focus.drain(..) {
+            self.tree.accessibility_diff.request_focus(strategy);
+        }
+        if let Some(show) = self.requests.borrow_mut().show_repaints.take() {
+            self.tree.repaints.set_enabled(show);
+        }
+
+        let size = Size2D::new(self.size.width as f32, self.size.height as f32);
+        if self.process_layout {
+            self.tree.measure_layout(
+                size,
+                &mut self.font_collection,
+                &self.font_manager,
+                &self.events_sender,
+                self.scale_factor,
+                &self.fallback_fonts,
+            );
+            self.platform.root_size.set_if_modified(size);
+            if let Some(cursor) = self.tree.hovered_cursor.take_change() {
+                self.requests.borrow_mut().cursor = cursor;
+            }
+            self.process_layout = false;
+        }
+
+        let mut needs_next_frame = false;
+        self.driver.present(self.size, None, |surface| {
+            let render_pipeline = RenderPipeline {
+                font_collection: &mut self.font_collection,
+                font_manager: &self.font_manager,
+                tree: &self.tree,
+                canvas: surface.canvas(),
+                scale_factor: self.scale_factor,
+                background: self.background,
+            };
+            needs_next_frame = render_pipeline.render();
+            self.tree.repaints.render(surface.canvas(), &self.tree);
+        });
+        self.tree.repaints.clear();
+
+        let update = self.accessibility.process_updates(
+            &mut self.tree,
+            &self.events_sender,
+            self.scale_factor,
+        );
+        self.platform
+            .focused_accessibility_id
+            .set_if_modified(update.focus);
+        self.platform
+            .focused_accessibility_ancestors
+            .set_if_modified(self.accessibility.focused_ancestors(&self.tree));
+        if let Some(node_id) = self.accessibility.focused_node_id()
+            && let Some(layout_node) = self.tree.layout.get(&node_id)
+        {
+            self.platform.focused_accessibility_node.set_if_modified(
+                AccessibilityTree::create_node(node_id, layout_node, &self.tree, self.scale_factor),
+            );
+        }
+
+        self.requests.borrow_mut().redraw = needs_next_frame;
+
+        if self.ticker_sender.receiver_count() > 0 {
+            self.ticker_sender.broadcast_blocking(()).ok();
+        }
+    }
+}
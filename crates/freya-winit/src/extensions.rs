@@ -1,6 +1,10 @@
+use bytes::Bytes;
 use freya_core::{
     elements::rect::Rect,
     prelude::{
+        AccessibilityId,
+        Cursor,
+        CursorIcon,
         Event,
         EventHandlersExt,
         EventsCombos,
@@ -12,12 +16,25 @@ use freya_core::{
     user_event::SingleThreadErasedEvent,
 };
 use winit::window::{
+    ResizeDirection,
     Window,
     WindowId,
 };
 
+#[cfg(feature = "notifications")]
+use crate::notifications::Notification;
+#[cfg(feature = "taskbar")]
+use crate::taskbar::{
+    self,
+    TaskbarProgress,
+};
 use crate::{
     config::WindowConfig,
+    monitor::MonitorInfo,
+    recorder::{
+        FrameRecorder,
+        RecordedFrame,
+    },
     renderer::{
         NativeWindowErasedEventAction,
         WithWindowCallback,
@@ -117,10 +134,137 @@ pub trait WinitPlatformExt {
         window_id: Option<WindowId>,
         callback: impl FnOnce(&mut Window) + 'static,
     );
+
+    /// Get the available monitors, open a window in one of them with
+    /// [`WindowConfig::with_monitor`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use freya::prelude::*;
+    ///
+    /// async fn print_monitors() {
+    ///     for monitor in Platform::get().monitors().await {
+    ///         println!(
+    ///             "{:?} {:?} {}",
+    ///             monitor.name, monitor.size, monitor.scale_factor
+    ///         );
+    ///     }
+    /// }
+    /// ```
+    fn monitors(&self) -> impl Future<Output = Vec<MonitorInfo>>;
+
+    /// Render the element with the given [`AccessibilityId`] and its children into a PNG image.
+    ///
+    /// `scale` is the scale factor of the image, so `2.` gives an image twice as big as the
+    /// element in logical pixels. Returns `None` if the element is not in the current window.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use freya::prelude::*;
+    ///
+    /// fn app() -> impl IntoElement {
+    ///     let id = use_hook(Focus::new_id);
+    ///
+    ///     rect()
+    ///         .a11y_id(id)
+    ///         .child("Hello, World!")
+    ///         .on_press(move |_| {
+    ///             spawn(async move {
+    ///                 if let Some(image) = Platform::get().capture_node(id, 2.).await {
+    ///                     let _ = std::fs::write("capture.png", image);
+    ///                 }
+    ///             });
+    ///         })
+    /// }
+    /// ```
+    fn capture_node(
+        &self,
+        a11y_id: AccessibilityId,
+        scale: f64,
+    ) -> impl Future<Output = Option<Bytes>>;
+
+    /// Start recording the current window, `callback` gets every frame that the window renders
+    /// until [`WinitPlatformExt::stop_recording`] is called.
+    ///
+    /// Frames are only rendered when something changes, use the [`RecordedFrame::timestamp`]s
+    /// to know for how long each frame stays on screen.
+    /// To record at a steady rate use [`freya_core::prelude::RenderMode::Continuous`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use freya::prelude::*;
+    ///
+    /// fn record_to_channel() -> std::sync::mpsc::Receiver<RecordedFrame> {
+    ///     let (tx, rx) = std::sync::mpsc::channel();
+    ///     Platform::get().start_recording(move |frame| {
+    ///         let _ = tx.send(frame);
+    ///     });
+    ///     rx
+    /// }
+    /// ```
+    fn start_recording(&self, callback: impl FnMut(RecordedFrame) + 'static);
+
+    /// Stop the recording started with [`WinitPlatformExt::start_recording`].
+    fn stop_recording(&self);
+
+    /// Show a desktop notification, its click handler runs in the component that showed it.
+    ///
+    /// Clicks are only reported on Linux and BSDs, elsewhere the notification is just shown.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use freya::prelude::*;
+    ///
+    /// fn notify() {
+    ///     Platform::get().show_notification(Notification::new("Hello, World!"));
+    /// }
+    /// ```
+    #[cfg(feature = "notifications")]
+    fn show_notification(&self, notification: Notification);
+
+    /// Show the progress of a long running operation in the taskbar entry or dock icon of
+    /// the current window.
+    ///
+    /// Supported on Windows and on Linux launchers that implement the Unity launcher API, like
+    /// KDE Plasma or Dash to Dock, which need an `app_id` matching the `.desktop` file.
+    /// The dock of macOS has no progress, so it only logs a warning there.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use freya::prelude::*;
+    ///
+    /// fn downloading(progress: f32) {
+    ///     Platform::get().set_taskbar_progress(TaskbarProgress::Normal(progress));
+    /// }
+    /// ```
+    #[cfg(feature = "taskbar")]
+    fn set_taskbar_progress(&self, progress: TaskbarProgress);
+
+    /// Show a count in the taskbar entry or dock icon of the app, like unread messages.
+    /// `None` hides it.
+    ///
+    /// Supported on macOS and on the same Linux launchers as [`WinitPlatformExt::set_taskbar_progress`].
+    /// The taskbar of Windows has no badges, so it only logs a warning there.
+    #[cfg(feature = "taskbar")]
+    fn set_taskbar_badge(&self, count: Option<u64>);
 }
 
 pub trait WindowDragExt {
+    /// Move the window when this element is pressed, like a native titlebar.
+    /// Double pressing toggles the maximized state.
     fn window_drag(self) -> Self;
+
+    /// Keep presses in this element from reaching a [WindowDragExt::window_drag] ancestor,
+    /// useful for the buttons of a custom titlebar.
+    fn window_no_drag(self) -> Self;
+
+    /// Resize the window from the given edge when this element is pressed.
+    fn window_resize(self, direction: ResizeDirection) -> Self;
 }
 
 impl WindowDragExt for Rect {
@@ -145,6 +289,21 @@ impl WindowDragExt for Rect {
             }
         })
     }
+
+    fn window_no_drag(self) -> Self {
+        self.on_pointer_down(|e: Event<PointerEventData>| e.stop_propagation())
+    }
+
+    fn window_resize(self, direction: ResizeDirection) -> Self {
+        self.on_pointer_enter(move |_| Cursor::set(direction.into()))
+            .on_pointer_leave(|_| Cursor::set(CursorIcon::default()))
+            .on_pointer_down(move |e: Event<PointerEventData>| {
+                e.stop_propagation();
+                Platform::get().with_window(None, move |window| {
+                    let _ = window.drag_resize_window(direction);
+                });
+            })
+    }
 }
 
 impl WinitPlatformExt for Platform {
@@ -186,4 +345,56 @@ impl WinitPlatformExt for Platform {
             },
         ))));
     }
+
+    async fn monitors(&self) -> Vec<MonitorInfo> {
+        let (tx, rx) = futures_channel::oneshot::channel();
+        self.with_window(None, move |window| {
+            let primary = window.primary_monitor();
+            let monitors = window
+                .available_monitors()
+                .map(|monitor| MonitorInfo::new(&monitor, primary.as_ref()))
+                .collect();
+            let _ = tx.send(monitors);
+        });
+        rx.await.unwrap_or_default()
+    }
+
+    async fn capture_node(&self, a11y_id: AccessibilityId, scale: f64) -> Option<Bytes> {
+        let (tx, rx) = futures_channel::oneshot::channel();
+        self.send(UserEvent::Erased(SingleThreadErasedEvent(Box::new(
+            NativeWindowErasedEventAction::CaptureNode {
+                a11y_id,
+                scale,
+                ack: tx,
+            },
+        ))));
+        rx.await.ok().flatten()
+    }
+
+    fn start_recording(&self, callback: impl FnMut(RecordedFrame) + 'static) {
+        self.send(UserEvent::Erased(SingleThreadErasedEvent(Box::new(
+            NativeWindowErasedEventAction::SetFrameRecorder(Some(FrameRecorder::new(callback))),
+        ))));
+    }
+
+    fn stop_recording(&self) {
+        self.send(UserEvent::Erased(SingleThreadErasedEvent(Box::new(
+            NativeWindowErasedEventAction::SetFrameRecorder(None),
+        ))));
+    }
+
+    #[cfg(feature = "notifications")]
+    fn show_notification(&self, notification: Notification) {
+        notification.show();
+    }
+
+    #[cfg(feature = "taskbar")]
+    fn set_taskbar_progress(&self, progress: TaskbarProgress) {
+        self.with_window(None, move |window| taskbar::set_progress(window, progress));
+    }
+
+    #[cfg(feature = "taskbar")]
+    fn set_taskbar_badge(&self, count: Option<u64>) {
+        self.with_window(None, move |window| taskbar::set_badge(window, count));
+    }
 }
@@ -0,0 +1,57 @@
+use std::time::{
+    Duration,
+    Instant,
+};
+
+/// Limits how often a window renders, see [crate::config::WindowConfig::with_max_fps].
+#[derive(Default)]
+pub(crate) struct FramePacer {
+    interval: Option<Duration>,
+    last_frame: Option<Instant>,
+    deferred: bool,
+}
+
+impl FramePacer {
+    pub(crate) fn new(max_fps: Option<u32>) -> Self {
+        Self {
+            interval: max_fps
+                .filter(|max_fps| *max_fps > 0)
+                .map(|max_fps| Duration::from_secs_f64(1. / max_fps as f64)),
+            ..Self::default()
+        }
+    }
+
+    fn next_frame(&self) -> Option<Instant> {
+        Some(self.last_frame? + self.interval?)
+    }
+
+    /// Whether a frame can render at `now`, otherwise it is deferred until [FramePacer::deferred_frame].
+    pub(crate) fn begin_frame(&mut self, now: Instant) -> bool {
+        let Some(interval) = self.interval else {
+            return true;
+        };
+        match self.next_frame() {
+            Some(next_frame) if now < next_frame => {
+                self.deferred = true;
+                false
+            }
+            // Keep the frames aligned to the interval unless a whole frame was skipped,
+            // so tickers see steady deltas
+            Some(next_frame) if now.duration_since(next_frame) < interval => {
+                self.last_frame = Some(next_frame);
+                self.deferred = false;
+                true
+            }
+            _ => {
+                self.last_frame = Some(now);
+                self.deferred = false;
+                true
+            }
+        }
+    }
+
+    /// When the deferred frame should render, if a frame was deferred.
+    pub(crate) fn deferred_frame(&self) -> Option<Instant> {
+        self.deferred.then(|| self.next_frame()).flatten()
+    }
+}
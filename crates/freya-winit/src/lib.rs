@@ -15,13 +15,24 @@ use crate::{
 };
 mod accessibility;
 pub mod config;
+mod double_click;
 mod drivers;
+pub mod embedded;
 pub mod extensions;
+mod frame_pacer;
 pub mod integration;
+mod monitor;
+#[cfg(feature = "notifications")]
+mod notifications;
 pub mod plugins;
+mod recorder;
 pub mod renderer;
+mod setup;
+#[cfg(feature = "taskbar")]
+mod taskbar;
 #[cfg(feature = "tray")]
 mod tray_icon;
+mod use_window;
 mod window;
 mod winit_mappings;
 
@@ -30,6 +41,13 @@ use futures_util::task::{
     ArcWake,
     waker,
 };
+pub use monitor::*;
+#[cfg(feature = "notifications")]
+pub use notifications::Notification;
+pub use recorder::RecordedFrame;
+#[cfg(feature = "taskbar")]
+pub use taskbar::TaskbarProgress;
+pub use use_window::*;
 
 use crate::winit::event_loop::EventLoopProxy;
 
@@ -52,11 +70,6 @@ pub fn launch(mut launch_config: LaunchConfig) {
     use std::collections::HashMap;
 
     use freya_core::integration::*;
-    use freya_engine::prelude::{
-        FontCollection,
-        FontMgr,
-        TypefaceFontProvider,
-    };
     use winit::event_loop::EventLoop;
 
     #[cfg(all(not(debug_assertions), not(target_os = "android")))]
@@ -81,19 +94,7 @@ pub fn launch(mut launch_config: LaunchConfig) {
 
     let proxy = event_loop.create_proxy();
 
-    let mut font_collection = FontCollection::new();
-    let def_mgr = FontMgr::default();
-    let mut provider = TypefaceFontProvider::new();
-    for (font_name, font_data) in launch_config.embedded_fonts {
-        let ft_type = def_mgr
-            .new_from_data(&font_data, None)
-            .unwrap_or_else(|| panic!("Failed to load font {font_name}."));
-        provider.register_typeface(ft_type, Some(font_name.as_ref()));
-    }
-    let font_mgr: FontMgr = provider.into();
-    font_collection.set_default_font_manager(def_mgr, None);
-    font_collection.set_dynamic_font_manager(font_mgr.clone());
-    font_collection.paragraph_cache_mut().turn_on(false);
+    let (font_collection, font_mgr) = setup::create_fonts(launch_config.embedded_fonts);
 
     let screen_reader = ScreenReader::new();
 
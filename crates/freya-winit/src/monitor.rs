@@ -0,0 +1,58 @@
+use winit::{
+    dpi::{
+        PhysicalPosition,
+        PhysicalSize,
+    },
+    event_loop::ActiveEventLoop,
+    monitor::MonitorHandle,
+};
+
+/// Description of a monitor, get them all with [crate::WinitPlatformExt::monitors].
+///
+/// winit doesn't expose the work area of monitors (without taskbars and docks), so the bounds
+/// are those of the whole monitor.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MonitorInfo {
+    pub name: Option<String>,
+    /// Resolution in physical pixels.
+    pub size: PhysicalSize<u32>,
+    /// Top-left corner in the desktop coordinates, in physical pixels.
+    pub position: PhysicalPosition<i32>,
+    pub scale_factor: f64,
+    pub refresh_rate_millihertz: Option<u32>,
+    pub is_primary: bool,
+}
+
+impl MonitorInfo {
+    pub(crate) fn new(monitor: &MonitorHandle, primary: Option<&MonitorHandle>) -> Self {
+        Self {
+            name: monitor.name(),
+            size: monitor.size(),
+            position: monitor.position(),
+            scale_factor: monitor.scale_factor(),
+            refresh_rate_millihertz: monitor.refresh_rate_millihertz(),
+            is_primary: primary == Some(monitor),
+        }
+    }
+}
+
+/// Monitor where a window opens, see [crate::config::WindowConfig::with_monitor].
+#[derive(Clone, Debug, PartialEq)]
+pub enum MonitorSelector {
+    Primary,
+    /// Position in the list returned by [crate::WinitPlatformExt::monitors].
+    Index(usize),
+    Name(String),
+}
+
+impl MonitorSelector {
+    pub(crate) fn select(&self, active_event_loop: &ActiveEventLoop) -> Option<MonitorHandle> {
+        match self {
+            Self::Primary => active_event_loop.primary_monitor(),
+            Self::Index(index) => active_event_loop.available_monitors().nth(*index),
+            Self::Name(name) => active_event_loop
+                .available_monitors()
+                .find(|monitor| monitor.name().as_ref() == Some(name)),
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use freya_core::prelude::{
+    EventHandler,
+    spawn,
+};
+
+/// Desktop notification, show it with [`crate::WinitPlatformExt::show_notification`].
+///
+/// Clicks are only reported on Linux and BSDs, see [`Notification::on_click`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use freya::prelude::*;
+///
+/// fn notify_download() {
+///     Platform::get().show_notification(
+///         Notification::new("Download finished")
+///             .body("freya.zip was saved in your downloads")
+///             .on_click(|_| println!("Notification clicked")),
+///     );
+/// }
+/// ```
+pub struct Notification {
+    title: String,
+    body: Option<String>,
+    icon: Option<String>,
+    on_click: Option<EventHandler<()>>,
+}
+
+impl Notification {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            body: None,
+            icon: None,
+            on_click: None,
+        }
+    }
+
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Name of an icon from the system theme or path to an image.
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Called when the notification is clicked. Only supported on Linux and BSDs.
+    pub fn on_click(mut self, on_click: impl Into<EventHandler<()>>) -> Self {
+        self.on_click = Some(on_click.into());
+        self
+    }
+
+    pub(crate) fn show(self) {
+        let (clicked_sender, clicked) = futures_channel::oneshot::channel::<()>();
+        if let Some(on_click) = self.on_click {
+            spawn(async move {
+                if clicked.await.is_ok() {
+                    on_click.call(());
+                }
+            });
+        }
+
+        let Self {
+            title, body, icon, ..
+        } = self;
+
+        // Showing a notification blocks until the notification server answers
+        std::thread::spawn(move || {
+            let mut notification = notify_rust::Notification::new();
+            notification.summary(&title);
+            if let Some(body) = &body {
+                notification.body(body);
+            }
+            if let Some(icon) = &icon {
+                notification.icon(icon);
+            }
+
+            #[cfg(all(unix, not(target_os = "macos")))]
+            {
+                notification.action("default", "Open");
+                match notification.show() {
+                    Ok(handle) => handle.wait_for_action(|action| {
+                        if action == "default" {
+                            let _ = clicked_sender.send(());
+                        }
+                    }),
+                    Err(err) => tracing::error!("Failed to show notification: {err}"),
+                }
+            }
+
+            #[cfg(not(all(unix, not(target_os = "macos"))))]
+            {
+                drop(clicked_sender);
+                if let Err(err) = notification.show() {
+                    tracing::error!("Failed to show notification: {err}");
+                }
+            }
+        });
+    }
+}
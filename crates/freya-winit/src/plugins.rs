@@ -14,7 +14,9 @@ pub use keyboard_types::{
     Key,
     Modifiers,
 };
+use torin::prelude::CursorPoint;
 use winit::{
+    event::WindowEvent,
     event_loop::EventLoopProxy,
     window::{
         Window,
@@ -108,6 +110,15 @@ pub enum PluginEvent<'a> {
         tree: &'a Tree,
     },
 
+    /// A Window received an event from winit, before Freya handles it.
+    WindowEvent {
+        window: &'a Window,
+        event: &'a WindowEvent,
+    },
+
+    /// The app is about to exit, the remaining windows are closed after this.
+    Exiting,
+
     /// After having rendered, presented and everything else.
     AfterRedraw {
         window: &'a Window,
@@ -170,6 +181,18 @@ pub enum PluginEvent<'a> {
         tree: &'a Tree,
     },
 
+    /// Before running the queued event handlers and async tasks of the components.
+    StartedPollingRunner {
+        window: &'a Window,
+        tree: &'a Tree,
+    },
+
+    /// After running the queued event handlers and async tasks of the components.
+    FinishedPollingRunner {
+        window: &'a Window,
+        tree: &'a Tree,
+    },
+
     StartedUpdatingTree {
         window: &'a Window,
         tree: &'a Tree,
@@ -200,6 +223,17 @@ pub enum PluginEvent<'a> {
         modifiers: Modifiers,
         is_pressed: bool,
     },
+
+    /// The mouse moved or one of its buttons changed, `cursor` is in physical pixels.
+    /// Set `captured` to keep the app from receiving it.
+    PointerInput {
+        window: &'a Window,
+        tree: &'a Tree,
+        name: MouseEventName,
+        cursor: CursorPoint,
+        button: Option<MouseButton>,
+        captured: &'a mut bool,
+    },
 }
 
 /// Skeleton for Freya plugins.
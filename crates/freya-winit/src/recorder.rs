@@ -0,0 +1,190 @@
+use std::{
+    fmt,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use bytes::Bytes;
+use freya_engine::prelude::{
+    AlphaType,
+    ColorType,
+    ImageInfo,
+    Surface,
+};
+use winit::dpi::PhysicalSize;
+
+/// Frame rendered by a window that is being recorded, see [crate::WinitPlatformExt::start_recording].
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordedFrame {
+    /// Width in physical pixels.
+    pub width: u32,
+    /// Height in physical pixels.
+    pub height: u32,
+    /// Unpremultiplied RGBA pixels, row by row.
+    pub pixels: Bytes,
+    /// Time since the recording started.
+    pub timestamp: Duration,
+}
+
+pub(crate) struct FrameRecorder {
+    started_at: Instant,
+    callback: Box<dyn FnMut(RecordedFrame)>,
+}
+
+impl fmt::Debug for FrameRecorder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("FrameRecorder")
+    }
+}
+
+impl FrameRecorder {
+    pub(crate) fn new(callback: impl FnMut(RecordedFrame) + 'static) -> Self {
+        Self {
+            started_at: Instant::now(),
+            callback: Box::new(callback),
+        }
+    }
+
+    /// Read the pixels of the rendered frame and hand them to the callback.
+    pub(crate) fn record(&mut self, surface: &mut Surface, size: PhysicalSize<u32>) {
+        let info = ImageInfo::new(
+            (size.width as i32, size.height as i32),
+            ColorType::RGBA8888,
+            AlphaType::Unpremul,
+            None,
+        );
+        let row_bytes = size.width as usize * 4;
+        let mut pixels = vec![0; row_bytes * size.height as usize];
+        if !surface.read_pixels(&info, &mut pixels, row_bytes, (0, 0)) {
+            tracing::warn!("Failed to read the pixels of a recorded frame.");
+            return;
+        }
+
+        (self.callback)(RecordedFrame {
+            width: size.width,
+            height: size.height,
+            pixels: Bytes::from(pixels),
+            timestamp: self.started_at.elapsed(),
+        });
+    }
+}
+
+/// Writes the events received by a window to a file, see
+/// [crate::config::WindowConfig::with_event_recording].
+#[cfg(feature = "event-recording")]
+pub(crate) struct EventRecorder {
+    started_at: Instant,
+    file: std::fs::File,
+}
+
+#[cfg(feature = "event-recording")]
+impl EventRecorder {
+    /// Create the file and write the scale factor and physical size of the window first,
+    /// so the positions of the events can be moved to other windows when replayed.
+    pub(crate) fn new(
+        path: &std::path::Path,
+        scale_factor: f64,
+        window_size: PhysicalSize<u32>,
+    ) -> Option<Self> {
+        use std::io::Write;
+
+        use freya_core::integration::EventRecording;
+
+        let created = std::fs::File::create(path).and_then(|mut file| {
+            let header = EventRecording::header_to_json_line(
+                scale_factor,
+                (window_size.width as f32, window_size.height as f32).into(),
+            )?;
+            file.write_all(header.as_bytes())?;
+            Ok(file)
+        });
+        match created {
+            Ok(file) => Some(Self {
+                started_at: Instant::now(),
+                file,
+            }),
+            Err(err) => {
+                tracing::error!("Failed to create the event recording {path:?}: {err}");
+                None
+            }
+        }
+    }
+
+    /// Append the events to the file right away so they are kept even if the app crashes.
+    pub(crate) fn record(&mut self, platform_events: &[freya_core::integration::PlatformEvent]) {
+        use std::io::Write;
+
+        use freya_core::integration::{
+            EventRecording,
+            PlatformEvent,
+            RecordedEvent,
+        };
+
+        let timestamp = self.started_at.elapsed();
+        let written = platform_events
+            .iter()
+            .filter(|event| !matches!(event, PlatformEvent::Drag { .. }))
+            .try_for_each(|event| {
+                let line = EventRecording::event_to_json_line(&RecordedEvent {
+                    timestamp,
+                    event: event.clone(),
+                })?;
+                self.file.write_all(line.as_bytes())
+            });
+        if let Err(err) = written {
+            tracing::warn!("Failed to record events: {err}");
+        }
+    }
+}
+
+/// Sends the events of a recording to a window as they were received, see
+/// [crate::config::WindowConfig::with_event_replay].
+///
+/// Must be called inside the runner of the window.
+#[cfg(feature = "event-recording")]
+pub(crate) fn replay_events(
+    recording: &freya_core::integration::EventRecording,
+    scale_factor: f64,
+    window_size: PhysicalSize<u32>,
+    handle: crate::plugins::PluginHandle,
+    window_id: winit::window::WindowId,
+) {
+    use freya_core::prelude::spawn;
+    use futures_util::StreamExt;
+
+    let events = recording
+        .events_for(
+            scale_factor,
+            (window_size.width as f32, window_size.height as f32).into(),
+        )
+        .collect::<Vec<_>>();
+    let timestamps = events
+        .iter()
+        .map(|recorded| recorded.timestamp)
+        .collect::<Vec<_>>();
+
+    let (due_sender, mut due) = futures_channel::mpsc::unbounded::<()>();
+    spawn(async move {
+        for recorded in events {
+            if due.next().await.is_none() {
+                break;
+            }
+            handle.send_platform_event(recorded.event, window_id);
+        }
+    });
+
+    // Events can't leave the main thread, so only their timing is kept in the background
+    std::thread::spawn(move || {
+        let started_at = Instant::now();
+        for timestamp in timestamps {
+            if let Some(left) = timestamp.checked_sub(started_at.elapsed()) {
+                std::thread::sleep(left);
+            }
+            if due_sender.unbounded_send(()).is_err() {
+                break;
+            }
+        }
+    });
+}
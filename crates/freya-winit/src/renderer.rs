@@ -3,9 +3,11 @@ use std::{
     fmt,
     pin::Pin,
     task::Waker,
+    time::Instant,
 };
 
 use accesskit_winit::WindowEvent as AccessibilityWindowEvent;
+use bytes::Bytes;
 use freya_core::integration::*;
 use freya_engine::prelude::{
     FontCollection,
@@ -17,10 +19,7 @@ use futures_util::{
     StreamExt,
     select,
 };
-use ragnarok::{
-    EventsExecutorRunner,
-    EventsMeasurerRunner,
-};
+use ragnarok::EventsExecutorRunner;
 use rustc_hash::FxHashMap;
 use torin::prelude::{
     CursorPoint,
@@ -44,6 +43,7 @@ use winit::{
     },
     event_loop::{
         ActiveEventLoop,
+        ControlFlow,
         EventLoopProxy,
     },
     window::{
@@ -66,10 +66,12 @@ use crate::{
         PluginHandle,
         PluginsManager,
     },
+    recorder::FrameRecorder,
     window::AppWindow,
     winit_mappings::{
         self,
         map_winit_mouse_button,
+        map_winit_pen,
         map_winit_touch_force,
         map_winit_touch_phase,
     },
@@ -202,6 +204,12 @@ pub enum NativeWindowErasedEventAction {
         window_id: Option<WindowId>,
         callback: WithWindowCallback,
     },
+    CaptureNode {
+        a11y_id: AccessibilityId,
+        scale: f64,
+        ack: futures_channel::oneshot::Sender<Option<Bytes>>,
+    },
+    SetFrameRecorder(Option<FrameRecorder>),
 }
 
 #[derive(Debug)]
@@ -268,7 +276,7 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
             {
                 #[cfg(not(target_os = "linux"))]
                 if let Some(tray_icon) = self.tray.0.take() {
-                    self.tray_icon = Some((tray_icon)());
+                    self.tray_icon = (tray_icon)();
                 }
 
                 #[cfg(target_os = "macos")]
@@ -358,6 +366,26 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
             }
             #[cfg(feature = "tray")]
             NativeEvent::Tray(NativeTrayEvent { action }) => {
+                use crate::tray::TrayEvent;
+
+                let tray_event = match &action {
+                    NativeTrayEventAction::TrayEvent(icon_event) => {
+                        Some(TrayEvent::Icon(icon_event.clone()))
+                    }
+                    NativeTrayEventAction::MenuEvent(menu_event) => {
+                        Some(TrayEvent::Menu(menu_event.clone()))
+                    }
+                    NativeTrayEventAction::LaunchWindow(_) => None,
+                };
+
+                // Let the apps react to the tray events
+                if let Some(tray_event) = &tray_event {
+                    for app in self.windows.values_mut() {
+                        let handlers = app.tray_event_handlers.clone();
+                        app.runner.run_in(|| handlers.emit(tray_event));
+                    }
+                }
+
                 let renderer_context = RendererContext {
                     fallback_fonts: &mut self.fallback_fonts,
                     active_event_loop,
@@ -369,16 +397,11 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
                     font_collection: &mut self.font_collection,
                 };
                 match action {
-                    NativeTrayEventAction::TrayEvent(icon_event) => {
-                        use crate::tray::TrayEvent;
-                        if let Some(tray_handler) = &mut self.tray.1 {
-                            (tray_handler)(TrayEvent::Icon(icon_event), renderer_context)
-                        }
-                    }
-                    NativeTrayEventAction::MenuEvent(menu_event) => {
-                        use crate::tray::TrayEvent;
-                        if let Some(tray_handler) = &mut self.tray.1 {
-                            (tray_handler)(TrayEvent::Menu(menu_event), renderer_context)
+                    NativeTrayEventAction::TrayEvent(_) | NativeTrayEventAction::MenuEvent(_) => {
+                        if let Some(tray_handler) = &mut self.tray.1
+                            && let Some(tray_event) = tray_event
+                        {
+                            (tray_handler)(tray_event, renderer_context)
                         }
                     }
                     NativeTrayEventAction::LaunchWindow(data) => {
@@ -414,6 +437,13 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
                         NativeWindowEventAction::PollRunner => {
                             let mut cx = std::task::Context::from_waker(&app.waker);
 
+                            self.plugins.send(
+                                PluginEvent::StartedPollingRunner {
+                                    window: &app.window,
+                                    tree: &app.tree,
+                                },
+                                PluginHandle::new(&self.proxy),
+                            );
                             {
                                 let fut = std::pin::pin!(async {
                                     select! {
@@ -450,6 +480,13 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
                                     std::task::Poll::Pending => {}
                                 }
                             }
+                            self.plugins.send(
+                                PluginEvent::FinishedPollingRunner {
+                                    window: &app.window,
+                                    tree: &app.tree,
+                                },
+                                PluginHandle::new(&self.proxy),
+                            );
 
                             self.plugins.send(
                                 PluginEvent::StartedUpdatingTree {
@@ -489,8 +526,21 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
                             self.screen_reader.set(false);
                         }
                         NativeWindowEventAction::Accessibility(
-                            accesskit_winit::WindowEvent::ActionRequested(_),
-                        ) => {}
+                            accesskit_winit::WindowEvent::ActionRequested(request),
+                        ) => {
+                            let needs_update = app.accessibility.process_action(
+                                request.action,
+                                request.target_node,
+                                request.data,
+                                &mut app.tree,
+                                &app.events_sender,
+                            );
+                            if needs_update {
+                                app.accessibility_tasks_for_next_render =
+                                    AccessibilityTask::ProcessUpdate { mode: None };
+                            }
+                            app.window.request_redraw();
+                        }
                         NativeWindowEventAction::Accessibility(
                             accesskit_winit::WindowEvent::InitialTreeRequested,
                         ) => {
@@ -505,7 +555,8 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
                             UserEvent::FocusAccessibilityNode(strategy) => {
                                 let task = match strategy {
                                     AccessibilityFocusStrategy::Backward(_)
-                                    | AccessibilityFocusStrategy::Forward(_) => {
+                                    | AccessibilityFocusStrategy::Forward(_)
+                                    | AccessibilityFocusStrategy::Among(..) => {
                                         AccessibilityTask::ProcessUpdate {
                                             mode: Some(NavigationMode::Keyboard),
                                         }
@@ -517,7 +568,18 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
                                 app.window.request_redraw();
                             }
                             UserEvent::SetCursorIcon(cursor_icon) => {
-                                app.window.set_cursor(cursor_icon);
+                                app.set_mouse_cursor(cursor_icon.into(), active_event_loop);
+                            }
+                            UserEvent::SetCustomCursor(custom_cursor) => {
+                                app.set_mouse_cursor(custom_cursor.into(), active_event_loop);
+                            }
+                            UserEvent::SetRenderMode(render_mode) => {
+                                app.render_mode = render_mode;
+                                app.window.request_redraw();
+                            }
+                            UserEvent::ShowRepaints(show) => {
+                                app.tree.repaints.set_enabled(show);
+                                app.window.request_redraw();
                             }
                             UserEvent::Erased(data) => {
                                 let action = data
@@ -585,22 +647,28 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
                                             (callback.0)(&mut app.window)
                                         }
                                     }
+                                    NativeWindowErasedEventAction::CaptureNode {
+                                        a11y_id,
+                                        scale,
+                                        ack,
+                                    } => {
+                                        let image = app.capture_node(
+                                            a11y_id,
+                                            scale,
+                                            &mut self.font_collection,
+                                            &self.font_manager,
+                                        );
+                                        let _ = ack.send(image);
+                                    }
+                                    NativeWindowErasedEventAction::SetFrameRecorder(recorder) => {
+                                        app.recorder = recorder;
+                                        app.window.request_redraw();
+                                    }
                                 }
                             }
                         },
                         NativeWindowEventAction::PlatformEvent(platform_event) => {
-                            let mut events_measurer_adapter = EventsMeasurerAdapter {
-                                tree: &mut app.tree,
-                                scale_factor: app.window.scale_factor(),
-                            };
-                            let processed_events = events_measurer_adapter.run(
-                                &mut vec![platform_event],
-                                &mut app.nodes_state,
-                                app.accessibility.focused_node_id(),
-                            );
-                            app.events_sender
-                                .unbounded_send(EventsChunk::Processed(processed_events))
-                                .unwrap();
+                            app.process_platform_events(vec![platform_event]);
                         }
                     }
                 }
@@ -608,6 +676,31 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
         }
     }
 
+    fn about_to_wait(&mut self, event_loop: &winit::event_loop::ActiveEventLoop) {
+        let now = Instant::now();
+        let mut next_frame = None;
+        for app in self.windows.values() {
+            if let Some(deferred_frame) = app.frame_pacer.deferred_frame() {
+                if deferred_frame <= now {
+                    app.window.request_redraw();
+                } else {
+                    next_frame = Some(next_frame.map_or(deferred_frame, |next_frame: Instant| {
+                        next_frame.min(deferred_frame)
+                    }));
+                }
+            }
+        }
+        event_loop.set_control_flow(match next_frame {
+            Some(next_frame) => ControlFlow::WaitUntil(next_frame),
+            None => ControlFlow::Wait,
+        });
+    }
+
+    fn exiting(&mut self, _event_loop: &winit::event_loop::ActiveEventLoop) {
+        self.plugins
+            .send(PluginEvent::Exiting, PluginHandle::new(&self.proxy));
+    }
+
     fn window_event(
         &mut self,
         event_loop: &winit::event_loop::ActiveEventLoop,
@@ -615,6 +708,13 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
         event: winit::event::WindowEvent,
     ) {
         if let Some(app) = &mut self.windows.get_mut(&window_id) {
+            self.plugins.send(
+                PluginEvent::WindowEvent {
+                    window: &app.window,
+                    event: &event,
+                },
+                PluginHandle::new(&self.proxy),
+            );
             app.accessibility_adapter.process_event(&app.window, &event);
             match event {
                 WindowEvent::ThemeChanged(theme) => {
@@ -624,12 +724,21 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
                     });
                 }
                 WindowEvent::ScaleFactorChanged { .. } => {
+                    let mut window_states = app.window_states;
+                    app.runner.run_in(|| window_states.update(&app.window));
+
                     app.window.request_redraw();
                     app.process_layout_on_next_render = true;
                     app.tree.layout.reset();
                     app.tree.text_cache.reset();
                 }
                 WindowEvent::CloseRequested => {
+                    #[cfg(feature = "tray")]
+                    if app.hide_to_tray && self.tray.1.is_some() {
+                        app.window.set_visible(false);
+                        return;
+                    }
+
                     let mut on_close_hook = self
                         .windows
                         .get_mut(&window_id)
@@ -688,7 +797,20 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
                     }
                 }
                 WindowEvent::RedrawRequested => {
+                    // Rendered later in `about_to_wait` if the frame comes too early
+                    if !app.frame_pacer.begin_frame(Instant::now()) {
+                        return;
+                    }
+
                     hotpath::measure_block!("RedrawRequested", {
+                        if app.tree.transitions.is_running() {
+                            let result = app.runner.run_in(|| app.tree.advance_transitions());
+                            if result.needs_render {
+                                app.process_layout_on_next_render = true;
+                            }
+                            app.window.request_redraw();
+                        }
+
                         if app.process_layout_on_next_render {
                             self.plugins.send(
                                 PluginEvent::StartedMeasuringLayout {
@@ -712,6 +834,7 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
                                 &self.fallback_fonts,
                             );
                             app.platform.root_size.set_if_modified(size);
+                            app.send_hovered_cursor();
                             app.process_layout_on_next_render = false;
                             self.plugins.send(
                                 PluginEvent::FinishedMeasuringLayout {
@@ -722,9 +845,10 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
                             );
                         }
 
+                        let mut needs_next_frame = false;
                         app.driver.present(
                             app.window.inner_size().cast(),
-                            &app.window,
+                            Some(&app.window),
                             |surface| {
                                 self.plugins.send(
                                     PluginEvent::BeforeRender {
@@ -745,7 +869,8 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
                                     background: app.background,
                                 };
 
-                                render_pipeline.render();
+                                needs_next_frame = render_pipeline.render();
+                                app.tree.repaints.render(surface.canvas(), &app.tree);
 
                                 self.plugins.send(
                                     PluginEvent::AfterRender {
@@ -757,6 +882,11 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
                                     },
                                     PluginHandle::new(&self.proxy),
                                 );
+
+                                if let Some(recorder) = &mut app.recorder {
+                                    recorder.record(surface, app.window.inner_size());
+                                }
+
                                 self.plugins.send(
                                     PluginEvent::BeforePresenting {
                                         window: &app.window,
@@ -775,6 +905,7 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
                             },
                             PluginHandle::new(&self.proxy),
                         );
+                        app.tree.repaints.clear();
 
                         self.plugins.send(
                             PluginEvent::BeforeAccessibility {
@@ -787,16 +918,27 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
 
                         match app.accessibility_tasks_for_next_render.take() {
                             AccessibilityTask::ProcessUpdate { mode } => {
-                                let update = app
-                                    .accessibility
-                                    .process_updates(&mut app.tree, &app.events_sender);
+                                let update = app.accessibility.process_updates(
+                                    &mut app.tree,
+                                    &app.events_sender,
+                                    app.window.scale_factor(),
+                                );
                                 app.platform
                                     .focused_accessibility_id
                                     .set_if_modified(update.focus);
+                                app.platform
+                                    .focused_accessibility_ancestors
+                                    .set_if_modified(
+                                        app.accessibility.focused_ancestors(&app.tree),
+                                    );
                                 let node_id = app.accessibility.focused_node_id().unwrap();
                                 let layout_node = app.tree.layout.get(&node_id).unwrap();
-                                let focused_node =
-                                    AccessibilityTree::create_node(node_id, layout_node, &app.tree);
+                                let focused_node = AccessibilityTree::create_node(
+                                    node_id,
+                                    layout_node,
+                                    &app.tree,
+                                    app.window.scale_factor(),
+                                );
                                 app.window.set_ime_allowed(is_ime_role(focused_node.role()));
                                 app.platform
                                     .focused_accessibility_node
@@ -814,14 +956,25 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
                                 app.accessibility_adapter.update_if_active(|| update);
                             }
                             AccessibilityTask::Init => {
-                                let update = app.accessibility.init(&mut app.tree);
+                                let update = app
+                                    .accessibility
+                                    .init(&mut app.tree, app.window.scale_factor());
                                 app.platform
                                     .focused_accessibility_id
                                     .set_if_modified(update.focus);
+                                app.platform
+                                    .focused_accessibility_ancestors
+                                    .set_if_modified(
+                                        app.accessibility.focused_ancestors(&app.tree),
+                                    );
                                 let node_id = app.accessibility.focused_node_id().unwrap();
                                 let layout_node = app.tree.layout.get(&node_id).unwrap();
-                                let focused_node =
-                                    AccessibilityTree::create_node(node_id, layout_node, &app.tree);
+                                let focused_node = AccessibilityTree::create_node(
+                                    node_id,
+                                    layout_node,
+                                    &app.tree,
+                                    app.window.scale_factor(),
+                                );
                                 app.window.set_ime_allowed(is_ime_role(focused_node.role()));
                                 app.platform
                                     .focused_accessibility_node
@@ -851,6 +1004,10 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
                             app.ticker_sender.broadcast_blocking(()).unwrap();
                         }
 
+                        if app.render_mode == RenderMode::Continuous || needs_next_frame {
+                            app.window.request_redraw();
+                        }
+
                         self.plugins.send(
                             PluginEvent::AfterRedraw {
                                 window: &app.window,
@@ -871,35 +1028,83 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
                     app.tree.layout.invalidate(NodeId::ROOT);
                 }
 
+                WindowEvent::Moved(_) => {
+                    let mut window_states = app.window_states;
+                    app.runner.run_in(|| window_states.update(&app.window));
+                }
+
                 WindowEvent::MouseInput { state, button, .. } => {
+                    if let Some(direction) = app.resize_direction
+                        && state == ElementState::Pressed
+                        && map_winit_mouse_button(button) == MouseButton::Left
+                    {
+                        let _ = app.window.drag_resize_window(direction);
+                        return;
+                    }
+
                     app.just_focused = false;
                     app.mouse_state = state;
                     app.platform
                         .navigation_mode
                         .set(NavigationMode::NotKeyboard);
 
-                    let name = if state == ElementState::Pressed {
-                        MouseEventName::MouseDown
+                    let cursor = (app.position.x, app.position.y).into();
+                    let button = Some(map_winit_mouse_button(button));
+
+                    let mut captured = false;
+                    self.plugins.send(
+                        PluginEvent::PointerInput {
+                            window: &app.window,
+                            tree: &app.tree,
+                            name: if state == ElementState::Pressed {
+                                MouseEventName::MouseDown
+                            } else {
+                                MouseEventName::MouseUp
+                            },
+                            cursor,
+                            button,
+                            captured: &mut captured,
+                        },
+                        PluginHandle::new(&self.proxy),
+                    );
+                    if captured {
+                        return;
+                    }
+
+                    let (name, click_count) = if state == ElementState::Pressed {
+                        (
+                            MouseEventName::MouseDown,
+                            app.click_counter.press(cursor, button),
+                        )
                     } else {
-                        MouseEventName::MouseUp
+                        (MouseEventName::MouseUp, app.click_counter.count())
                     };
-                    let platform_event = PlatformEvent::Mouse {
+                    let mut platform_events = Vec::new();
+                    if button == Some(MouseButton::Left) {
+                        if name == MouseEventName::MouseDown {
+                            app.drag_tracker.press(cursor);
+                        } else {
+                            platform_events.extend(app.drag_tracker.release(cursor));
+                        }
+                    }
+                    platform_events.push(PlatformEvent::Mouse {
                         name,
-                        cursor: (app.position.x, app.position.y).into(),
-                        button: Some(map_winit_mouse_button(button)),
-                    };
-                    let mut events_measurer_adapter = EventsMeasurerAdapter {
-                        tree: &mut app.tree,
-                        scale_factor: app.window.scale_factor(),
-                    };
-                    let processed_events = events_measurer_adapter.run(
-                        &mut vec![platform_event],
-                        &mut app.nodes_state,
-                        app.accessibility.focused_node_id(),
-                    );
-                    app.events_sender
-                        .unbounded_send(EventsChunk::Processed(processed_events))
-                        .unwrap();
+                        cursor,
+                        button,
+                        click_count,
+                    });
+                    if name == MouseEventName::MouseUp && click_count == 2 {
+                        platform_events.push(PlatformEvent::Mouse {
+                            name: MouseEventName::DoubleClick,
+                            cursor,
+                            button,
+                            click_count,
+                        });
+                    }
+                    app.process_platform_events(platform_events);
+                    if app.tree.text_selection.take_changed() {
+                        app.window.request_redraw();
+                    }
                 }
 
                 WindowEvent::KeyboardInput { event, .. } => {
@@ -928,24 +1133,46 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
                         PluginHandle::new(&self.proxy),
                     );
 
-                    let platform_event = PlatformEvent::Keyboard {
+                    // Copy the text selected across the selectable elements,
+                    // unless a focused element like an input handles the shortcut itself
+                    let meta_or_ctrl = if cfg!(target_os = "macos") {
+                        modifiers.meta()
+                    } else {
+                        modifiers.ctrl()
+                    };
+                    let unfocused = app
+                        .accessibility
+                        .focused_node_id()
+                        .is_none_or(|id| id == NodeId::ROOT);
+                    if name == KeyboardEventName::KeyDown
+                        && meta_or_ctrl
+                        && unfocused
+                        && matches!(&key, keyboard_types::Key::Character(c) if c == "c")
+                        && let Some(text) = app.tree.text_selection.text(&app.tree)
+                    {
+                        let _ = app
+                            .runner
+                            .run_in(|| freya_clipboard::prelude::Clipboard::set(text));
+                    }
+
+                    // Escape cancels the in-app drag
+                    let mut platform_events = Vec::new();
+                    if name == KeyboardEventName::KeyDown
+                        && key == keyboard_types::Key::Named(keyboard_types::NamedKey::Escape)
+                    {
+                        platform_events.extend(app.drag_tracker.cancel(app.position));
+                    }
+
+                    platform_events.push(PlatformEvent::Keyboard {
                         name,
                         key,
                         code,
                         modifiers,
-                    };
-                    let mut events_measurer_adapter = EventsMeasurerAdapter {
-                        tree: &mut app.tree,
-                        scale_factor: app.window.scale_factor(),
-                    };
-                    let processed_events = events_measurer_adapter.run(
-                        &mut vec![platform_event],
-                        &mut app.nodes_state,
-                        app.accessibility.focused_node_id(),
-                    );
-                    app.events_sender
-                        .unbounded_send(EventsChunk::Processed(processed_events))
-                        .unwrap();
+                    });
+                    app.process_platform_events(platform_events);
+                    if app.tree.text_selection.take_changed() {
+                        app.window.request_redraw();
+                    }
                 }
 
                 WindowEvent::MouseWheel { delta, phase, .. } => {
@@ -972,18 +1199,7 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
                             cursor: app.position,
                             source: WheelSource::Device,
                         };
-                        let mut events_measurer_adapter = EventsMeasurerAdapter {
-                            tree: &mut app.tree,
-                            scale_factor: app.window.scale_factor(),
-                        };
-                        let processed_events = events_measurer_adapter.run(
-                            &mut vec![platform_event],
-                            &mut app.nodes_state,
-                            app.accessibility.focused_node_id(),
-                        );
-                        app.events_sender
-                            .unbounded_send(EventsChunk::Processed(processed_events))
-                            .unwrap();
+                        app.process_platform_events(vec![platform_event]);
                     }
                 }
 
@@ -994,30 +1210,41 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
                             name: MouseEventName::MouseMove,
                             cursor: app.position,
                             button: None,
+                            click_count: 0,
                         };
-                        let mut events_measurer_adapter = EventsMeasurerAdapter {
-                            tree: &mut app.tree,
-                            scale_factor: app.window.scale_factor(),
-                        };
-                        let processed_events = events_measurer_adapter.run(
-                            &mut vec![platform_event],
-                            &mut app.nodes_state,
-                            app.accessibility.focused_node_id(),
-                        );
-                        app.events_sender
-                            .unbounded_send(EventsChunk::Processed(processed_events))
-                            .unwrap();
+                        app.process_platform_events(vec![platform_event]);
                     }
                 }
                 WindowEvent::CursorMoved { position, .. } => {
                     app.just_focused = false;
                     app.position = CursorPoint::from((position.x, position.y));
 
+                    let resize_direction = app.resize_direction_at(app.position);
+                    app.set_resize_direction(resize_direction, event_loop);
+
+                    let mut captured = false;
+                    self.plugins.send(
+                        PluginEvent::PointerInput {
+                            window: &app.window,
+                            tree: &app.tree,
+                            name: MouseEventName::MouseMove,
+                            cursor: app.position,
+                            button: None,
+                            captured: &mut captured,
+                        },
+                        PluginHandle::new(&self.proxy),
+                    );
+                    if captured {
+                        return;
+                    }
+
                     let mut platform_event = vec![PlatformEvent::Mouse {
                         name: MouseEventName::MouseMove,
                         cursor: app.position,
                         button: None,
+                        click_count: 0,
                     }];
+                    platform_event.extend(app.drag_tracker.moved(app.position));
 
                     for dropped_file_path in app.dropped_file_paths.drain(..) {
                         platform_event.push(PlatformEvent::File {
@@ -1027,18 +1254,10 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
                         });
                     }
 
-                    let mut events_measurer_adapter = EventsMeasurerAdapter {
-                        tree: &mut app.tree,
-                        scale_factor: app.window.scale_factor(),
-                    };
-                    let processed_events = events_measurer_adapter.run(
-                        &mut platform_event,
-                        &mut app.nodes_state,
-                        app.accessibility.focused_node_id(),
-                    );
-                    app.events_sender
-                        .unbounded_send(EventsChunk::Processed(processed_events))
-                        .unwrap();
+                    app.process_platform_events(platform_event);
+                    if app.tree.text_selection.take_changed() {
+                        app.window.request_redraw();
+                    }
                 }
 
                 WindowEvent::Touch(Touch {
@@ -1057,65 +1276,83 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
                         TouchPhase::Started => TouchEventName::TouchStart,
                     };
 
-                    let platform_event = PlatformEvent::Touch {
+                    let mut platform_events = Vec::new();
+                    match phase {
+                        TouchPhase::Started => app.drag_tracker.press(app.position),
+                        TouchPhase::Moved => {
+                            platform_events.extend(app.drag_tracker.moved(app.position))
+                        }
+                        TouchPhase::Ended => {
+                            platform_events.extend(app.drag_tracker.release(app.position))
+                        }
+                        TouchPhase::Cancelled => {
+                            platform_events.extend(app.drag_tracker.cancel(app.position))
+                        }
+                    }
+                    if let Some((pen_name, state)) = map_winit_pen(phase, force) {
+                        platform_events.push(PlatformEvent::Pen {
+                            name: pen_name,
+                            location: app.position,
+                            state,
+                        });
+                    }
+                    platform_events.push(PlatformEvent::Touch {
                         name,
                         location: app.position,
                         finger_id: id,
                         phase: map_winit_touch_phase(phase),
                         force: force.map(map_winit_touch_force),
+                    });
+                    app.process_platform_events(platform_events);
+                    app.position = CursorPoint::from((location.x, location.y));
+                }
+                WindowEvent::PinchGesture { .. }
+                | WindowEvent::RotationGesture { .. }
+                | WindowEvent::PanGesture { .. } => {
+                    let (name, delta, phase) = match event {
+                        WindowEvent::PinchGesture { delta, phase, .. } => {
+                            (GestureEventName::Pinch, GestureDelta::Pinch(delta), phase)
+                        }
+                        WindowEvent::RotationGesture { delta, phase, .. } => (
+                            GestureEventName::Rotate,
+                            GestureDelta::Rotate(delta as f64),
+                            phase,
+                        ),
+                        WindowEvent::PanGesture { delta, phase, .. } => (
+                            GestureEventName::Pan,
+                            GestureDelta::Pan(CursorPoint::new(delta.x as f64, delta.y as f64)),
+                            phase,
+                        ),
+                        _ => return,
                     };
-                    let mut events_measurer_adapter = EventsMeasurerAdapter {
-                        tree: &mut app.tree,
-                        scale_factor: app.window.scale_factor(),
+
+                    let platform_event = PlatformEvent::Gesture {
+                        name,
+                        cursor: app.position,
+                        delta,
+                        phase: map_winit_touch_phase(phase),
                     };
-                    let processed_events = events_measurer_adapter.run(
-                        &mut vec![platform_event],
-                        &mut app.nodes_state,
-                        app.accessibility.focused_node_id(),
-                    );
-                    app.events_sender
-                        .unbounded_send(EventsChunk::Processed(processed_events))
-                        .unwrap();
-                    app.position = CursorPoint::from((location.x, location.y));
+                    app.process_platform_events(vec![platform_event]);
                 }
                 WindowEvent::Ime(Ime::Commit(text)) => {
-                    let platform_event = PlatformEvent::Keyboard {
+                    let composition_end = app.ime_composition.end(text.clone());
+                    let mut platform_events = vec![PlatformEvent::Keyboard {
                         name: KeyboardEventName::KeyDown,
                         key: keyboard_types::Key::Character(text),
                         code: keyboard_types::Code::Unidentified,
                         modifiers: winit_mappings::map_winit_modifiers(app.modifiers_state),
-                    };
-                    let mut events_measurer_adapter = EventsMeasurerAdapter {
-                        tree: &mut app.tree,
-                        scale_factor: app.window.scale_factor(),
-                    };
-                    let processed_events = events_measurer_adapter.run(
-                        &mut vec![platform_event],
-                        &mut app.nodes_state,
-                        app.accessibility.focused_node_id(),
-                    );
-                    app.events_sender
-                        .unbounded_send(EventsChunk::Processed(processed_events))
-                        .unwrap();
+                    }];
+                    platform_events.extend(composition_end);
+                    app.process_platform_events(platform_events);
                 }
                 WindowEvent::Ime(Ime::Preedit(text, pos)) => {
-                    let platform_event = PlatformEvent::ImePreedit {
-                        name: ImeEventName::Preedit,
-                        text,
-                        cursor: pos,
-                    };
-                    let mut events_measurer_adapter = EventsMeasurerAdapter {
-                        tree: &mut app.tree,
-                        scale_factor: app.window.scale_factor(),
-                    };
-                    let processed_events = events_measurer_adapter.run(
-                        &mut vec![platform_event],
-                        &mut app.nodes_state,
-                        app.accessibility.focused_node_id(),
-                    );
-                    app.events_sender
-                        .unbounded_send(EventsChunk::Processed(processed_events))
-                        .unwrap();
+                    let platform_events = app.ime_composition.preedit(text, pos);
+                    app.process_platform_events(platform_events);
+                }
+                WindowEvent::Ime(Ime::Disabled) => {
+                    if let Some(platform_event) = app.ime_composition.end(String::new()) {
+                        app.process_platform_events(vec![platform_event]);
+                    }
                 }
                 WindowEvent::DroppedFile(file_path) => {
                     app.dropped_file_paths.push(file_path);
@@ -1126,18 +1363,7 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
                         file_path: Some(file_path),
                         cursor: app.position,
                     };
-                    let mut events_measurer_adapter = EventsMeasurerAdapter {
-                        tree: &mut app.tree,
-                        scale_factor: app.window.scale_factor(),
-                    };
-                    let processed_events = events_measurer_adapter.run(
-                        &mut vec![platform_event],
-                        &mut app.nodes_state,
-                        app.accessibility.focused_node_id(),
-                    );
-                    app.events_sender
-                        .unbounded_send(EventsChunk::Processed(processed_events))
-                        .unwrap();
+                    app.process_platform_events(vec![platform_event]);
                 }
                 WindowEvent::HoveredFileCancelled => {
                     let platform_event = PlatformEvent::File {
@@ -1145,18 +1371,7 @@ impl ApplicationHandler<NativeEvent> for WinitRenderer {
                         file_path: None,
                         cursor: app.position,
                     };
-                    let mut events_measurer_adapter = EventsMeasurerAdapter {
-                        tree: &mut app.tree,
-                        scale_factor: app.window.scale_factor(),
-                    };
-                    let processed_events = events_measurer_adapter.run(
-                        &mut vec![platform_event],
-                        &mut app.nodes_state,
-                        app.accessibility.focused_node_id(),
-                    );
-                    app.events_sender
-                        .unbounded_send(EventsChunk::Processed(processed_events))
-                        .unwrap();
+                    app.process_platform_events(vec![platform_event]);
                 }
                 _ => {}
             }
@@ -0,0 +1,57 @@
+use freya_clipboard::copypasta::ClipboardProvider;
+use freya_components::cache::AssetCacher;
+use freya_core::integration::*;
+use freya_engine::prelude::{
+    FontCollection,
+    FontMgr,
+    TypefaceFontProvider,
+};
+
+use crate::config::EmbeddedFonts;
+
+/// Create the fonts of an app, the embedded fonts are registered with their names.
+pub(crate) fn create_fonts(embedded_fonts: EmbeddedFonts) -> (FontCollection, FontMgr) {
+    let mut font_collection = FontCollection::new();
+    let def_mgr = FontMgr::default();
+    let mut provider = TypefaceFontProvider::new();
+    for (font_name, font_data) in embedded_fonts {
+        let ft_type = def_mgr
+            .new_from_data(&font_data, None)
+            .unwrap_or_else(|| panic!("Failed to load font {font_name}."));
+        provider.register_typeface(ft_type, Some(font_name.as_ref()));
+    }
+    let font_mgr: FontMgr = provider.into();
+    font_collection.set_default_font_manager(def_mgr, None);
+    font_collection.set_dynamic_font_manager(font_mgr.clone());
+    font_collection.paragraph_cache_mut().turn_on(false);
+    (font_collection, font_mgr)
+}
+
+/// Provide the root contexts shared by windows and embedded apps, the [Platform] is provided by each of them.
+pub(crate) fn provide_root_contexts(
+    runner: &mut Runner,
+    tree: &Tree,
+    screen_reader: ScreenReader,
+    clipboard: Option<Box<dyn ClipboardProvider>>,
+    font_collection: &FontCollection,
+) -> RenderingTickerSender {
+    runner.provide_root_context(|| screen_reader);
+
+    let (mut ticker_sender, ticker) = RenderingTicker::new();
+    ticker_sender.set_overflow(true);
+    runner.provide_root_context(|| ticker);
+
+    runner.provide_root_context(|| tree.animation_clock.clone());
+
+    runner.provide_root_context(AssetCacher::create);
+
+    runner.provide_root_context(|| State::create(clipboard));
+
+    runner.provide_root_context(|| tree.accessibility_generator.clone());
+
+    runner.provide_root_context(|| tree.visibility_tracker.clone());
+
+    runner.provide_root_context(|| font_collection.clone());
+
+    ticker_sender
+}
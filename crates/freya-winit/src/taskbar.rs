@@ -0,0 +1,264 @@
+use winit::window::Window;
+
+/// Progress shown in the taskbar entry or dock icon of the app, see
+/// [`crate::WinitPlatformExt::set_taskbar_progress`]. Not shown on macOS.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TaskbarProgress {
+    #[default]
+    None,
+    /// Busy with an operation of unknown length.
+    Indeterminate,
+    /// Progress from `0.` to `1.`.
+    Normal(f32),
+    Paused(f32),
+    Error(f32),
+}
+
+impl TaskbarProgress {
+    fn value(&self) -> Option<f32> {
+        match self {
+            Self::None | Self::Indeterminate => None,
+            Self::Normal(value) | Self::Paused(value) | Self::Error(value) => {
+                Some(value.clamp(0., 1.))
+            }
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use std::cell::OnceCell;
+
+    use raw_window_handle::{
+        HasWindowHandle,
+        RawWindowHandle,
+    };
+    use windows::Win32::{
+        Foundation::HWND,
+        System::Com::{
+            CLSCTX_INPROC_SERVER,
+            COINIT_APARTMENTTHREADED,
+            CoCreateInstance,
+            CoInitializeEx,
+        },
+        UI::Shell::{
+            ITaskbarList3,
+            TBPF_ERROR,
+            TBPF_INDETERMINATE,
+            TBPF_NOPROGRESS,
+            TBPF_NORMAL,
+            TBPF_PAUSED,
+            TaskbarList,
+        },
+    };
+    use winit::window::Window;
+
+    use crate::taskbar::TaskbarProgress;
+
+    /// Resolution of the progress values given to the taskbar.
+    const PROGRESS_TOTAL: u64 = 1000;
+
+    thread_local! {
+        /// Created once per thread, creating it is slow and progress can be updated every frame.
+        static TASKBAR: OnceCell<Option<ITaskbarList3>> = const { OnceCell::new() };
+    }
+
+    fn create_taskbar() -> windows::core::Result<ITaskbarList3> {
+        unsafe {
+            // Fails harmlessly when COM was already initialized in this thread
+            let _ = CoInitializeEx(None, COINIT_APARTMENTTHREADED);
+            let taskbar: ITaskbarList3 =
+                CoCreateInstance(&TaskbarList, None, CLSCTX_INPROC_SERVER)?;
+            taskbar.HrInit()?;
+            Ok(taskbar)
+        }
+    }
+
+    pub fn set_progress(window: &Window, progress: TaskbarProgress) {
+        let Ok(handle) = window.window_handle() else {
+            return;
+        };
+        let RawWindowHandle::Win32(handle) = handle.as_raw() else {
+            return;
+        };
+        let hwnd = HWND(handle.hwnd.get() as _);
+        let state = match progress {
+            TaskbarProgress::None => TBPF_NOPROGRESS,
+            TaskbarProgress::Indeterminate => TBPF_INDETERMINATE,
+            TaskbarProgress::Normal(_) => TBPF_NORMAL,
+            TaskbarProgress::Paused(_) => TBPF_PAUSED,
+            TaskbarProgress::Error(_) => TBPF_ERROR,
+        };
+
+        TASKBAR.with(|taskbar| {
+            let taskbar = taskbar.get_or_init(|| {
+                create_taskbar()
+                    .inspect_err(|err| tracing::error!("Failed to access the taskbar: {err}"))
+                    .ok()
+            });
+            let Some(taskbar) = taskbar else {
+                return;
+            };
+            let update = || -> windows::core::Result<()> {
+                unsafe {
+                    taskbar.SetProgressState(hwnd, state)?;
+                    if let Some(value) = progress.value() {
+                        let completed = (value * PROGRESS_TOTAL as f32) as u64;
+                        taskbar.SetProgressValue(hwnd, completed, PROGRESS_TOTAL)?;
+                    }
+                }
+                Ok(())
+            };
+            if let Err(err) = update() {
+                tracing::error!("Failed to set the taskbar progress: {err}");
+            }
+        });
+    }
+
+    pub fn set_badge(_window: &Window, _count: Option<u64>) {
+        tracing::warn!("Taskbar badges are not supported on Windows");
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use objc2::MainThreadMarker;
+    use objc2_app_kit::NSApplication;
+    use objc2_foundation::NSString;
+    use winit::window::Window;
+
+    use crate::taskbar::TaskbarProgress;
+
+    pub fn set_progress(_window: &Window, _progress: TaskbarProgress) {
+        tracing::warn!("Dock progress is not supported on macOS");
+    }
+
+    pub fn set_badge(_window: &Window, count: Option<u64>) {
+        let Some(main_thread) = MainThreadMarker::new() else {
+            return;
+        };
+        let label = count.map(|count| NSString::from_str(&count.to_string()));
+        NSApplication::sharedApplication(main_thread)
+            .dockTile()
+            .setBadgeLabel(label.as_deref());
+    }
+}
+
+/// Uses the launcher API from Unity, also supported by KDE Plasma and the Dash to Dock
+/// extension of GNOME. Needs the `app_id` of the window to match the `.desktop` file.
+#[cfg(target_os = "linux")]
+mod platform {
+    use std::{
+        collections::HashMap,
+        sync::{
+            OnceLock,
+            mpsc::{
+                Receiver,
+                Sender,
+                channel,
+            },
+        },
+    };
+
+    use winit::window::Window;
+    use zbus::zvariant::Value;
+
+    use crate::taskbar::TaskbarProgress;
+
+    static APP_ID: OnceLock<String> = OnceLock::new();
+
+    type Properties = Vec<(&'static str, Value<'static>)>;
+
+    /// Updates sent to the worker that talks to the session bus, it blocks.
+    static UPDATES: OnceLock<Sender<(String, Properties)>> = OnceLock::new();
+
+    pub fn set_app_id(app_id: &str) {
+        let _ = APP_ID.set(app_id.to_string());
+    }
+
+    /// Emit the updates with a single connection, reconnecting when it breaks.
+    fn run_worker(updates: Receiver<(String, Properties)>) {
+        let mut connection = None;
+        for (app_uri, properties) in updates {
+            let properties = properties.into_iter().collect::<HashMap<_, _>>();
+            let result = match connection.take() {
+                Some(connection) => Ok(connection),
+                None => zbus::blocking::Connection::session(),
+            }
+            .and_then(|new_connection| {
+                new_connection.emit_signal(
+                    None::<&str>,
+                    "/com/canonical/unity/launcherentry/freya",
+                    "com.canonical.Unity.LauncherEntry",
+                    "Update",
+                    &(app_uri, properties),
+                )?;
+                connection = Some(new_connection);
+                Ok(())
+            });
+            if let Err(err) = result {
+                tracing::error!("Failed to update the launcher entry: {err}");
+            }
+        }
+    }
+
+    fn update(properties: Properties) {
+        let Some(app_id) = APP_ID.get() else {
+            tracing::warn!("Set an app_id in the WindowConfig to update the launcher entry");
+            return;
+        };
+        let app_uri = format!("application://{app_id}.desktop");
+        let updates = UPDATES.get_or_init(|| {
+            let (sender, receiver) = channel();
+            std::thread::spawn(move || run_worker(receiver));
+            sender
+        });
+        let _ = updates.send((app_uri, properties));
+    }
+
+    pub fn set_progress(_window: &Window, progress: TaskbarProgress) {
+        update(vec![
+            (
+                "progress",
+                Value::from(progress.value().unwrap_or(0.) as f64),
+            ),
+            (
+                "progress-visible",
+                Value::from(progress != TaskbarProgress::None),
+            ),
+            (
+                "urgent",
+                Value::from(matches!(progress, TaskbarProgress::Error(_))),
+            ),
+        ]);
+    }
+
+    pub fn set_badge(_window: &Window, count: Option<u64>) {
+        update(vec![
+            ("count", Value::from(count.unwrap_or(0) as i64)),
+            ("count-visible", Value::from(count.is_some())),
+        ]);
+    }
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+mod platform {
+    use winit::window::Window;
+
+    use crate::taskbar::TaskbarProgress;
+
+    pub fn set_progress(_window: &Window, _progress: TaskbarProgress) {}
+
+    pub fn set_badge(_window: &Window, _count: Option<u64>) {}
+}
+
+#[cfg(target_os = "linux")]
+pub(crate) use platform::set_app_id;
+
+pub(crate) fn set_progress(window: &Window, progress: TaskbarProgress) {
+    platform::set_progress(window, progress)
+}
+
+pub(crate) fn set_badge(window: &Window, count: Option<u64>) {
+    platform::set_badge(window, count)
+}
@@ -1,10 +1,237 @@
+use std::{
+    cell::RefCell,
+    rc::{
+        Rc,
+        Weak,
+    },
+};
+
+use bytes::Bytes;
+use freya_core::prelude::{
+    Callback,
+    use_hook,
+    use_try_consume,
+};
 use tray_icon::{
+    TrayIcon,
+    TrayIconBuilder,
     TrayIconEvent,
-    menu::MenuEvent,
+    menu::{
+        IsMenuItem,
+        Menu,
+        MenuEvent,
+        MenuItem,
+        PredefinedMenuItem,
+        Submenu,
+    },
 };
 
+use crate::config::LaunchConfig;
+
 #[derive(Clone, Debug)]
 pub enum TrayEvent {
     Icon(TrayIconEvent),
     Menu(MenuEvent),
 }
+
+impl TrayEvent {
+    /// ID of the pressed menu item, if this is a menu event.
+    pub fn menu_id(&self) -> Option<&str> {
+        match self {
+            Self::Menu(MenuEvent { id }) => Some(id.as_ref()),
+            Self::Icon(_) => None,
+        }
+    }
+}
+
+enum TrayMenuEntry {
+    Item {
+        id: String,
+        text: String,
+        enabled: bool,
+    },
+    Separator,
+    Submenu {
+        text: String,
+        menu: TrayMenu,
+    },
+}
+
+/// Menu of a tray icon, see [TrayConfig].
+///
+/// ```rust,no_run
+/// # use freya::tray::TrayMenu;
+/// let menu = TrayMenu::new()
+///     .item("show", "Show window")
+///     .separator()
+///     .item("quit", "Quit");
+/// ```
+#[derive(Default)]
+pub struct TrayMenu {
+    entries: Vec<TrayMenuEntry>,
+}
+
+impl TrayMenu {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an item, its `id` is reported back in [TrayEvent::Menu] when pressed.
+    pub fn item(self, id: impl Into<String>, text: impl Into<String>) -> Self {
+        self.item_enabled(id, text, true)
+    }
+
+    /// Add an item that can be disabled.
+    pub fn item_enabled(
+        mut self,
+        id: impl Into<String>,
+        text: impl Into<String>,
+        enabled: bool,
+    ) -> Self {
+        self.entries.push(TrayMenuEntry::Item {
+            id: id.into(),
+            text: text.into(),
+            enabled,
+        });
+        self
+    }
+
+    pub fn separator(mut self) -> Self {
+        self.entries.push(TrayMenuEntry::Separator);
+        self
+    }
+
+    pub fn submenu(mut self, text: impl Into<String>, menu: TrayMenu) -> Self {
+        self.entries.push(TrayMenuEntry::Submenu {
+            text: text.into(),
+            menu,
+        });
+        self
+    }
+
+    fn build(self) -> Menu {
+        let menu = Menu::new();
+        self.append_to(|item| menu.append(item));
+        menu
+    }
+
+    fn build_submenu(self, text: String) -> Submenu {
+        let submenu = Submenu::new(text, true);
+        self.append_to(|item| submenu.append(item));
+        submenu
+    }
+
+    fn append_to(self, append: impl Fn(&dyn IsMenuItem) -> tray_icon::menu::Result<()>) {
+        for entry in self.entries {
+            let result = match entry {
+                TrayMenuEntry::Item { id, text, enabled } => {
+                    append(&MenuItem::with_id(id, text, enabled, None))
+                }
+                TrayMenuEntry::Separator => append(&PredefinedMenuItem::separator()),
+                TrayMenuEntry::Submenu { text, menu } => append(&menu.build_submenu(text)),
+            };
+            if let Err(err) = result {
+                tracing::error!("Failed to add a tray menu entry: {err}");
+            }
+        }
+    }
+}
+
+/// Configuration of the tray icon, register it with [LaunchConfig::with_tray_config].
+///
+/// The events of the tray can be handled from components with [use_tray_event].
+pub struct TrayConfig {
+    icon: Bytes,
+    tooltip: Option<String>,
+    menu: Option<TrayMenu>,
+}
+
+impl TrayConfig {
+    /// Create a tray with the given encoded image as icon.
+    pub fn new(icon: impl Into<Bytes>) -> Self {
+        Self {
+            icon: icon.into(),
+            tooltip: None,
+            menu: None,
+        }
+    }
+
+    pub fn with_tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    pub fn with_menu(mut self, menu: TrayMenu) -> Self {
+        self.menu = Some(menu);
+        self
+    }
+
+    /// Create the tray icon, useful to register it along a handler with [LaunchConfig::with_tray].
+    pub fn build(self) -> tray_icon::Result<TrayIcon> {
+        let mut builder = TrayIconBuilder::new().with_icon(LaunchConfig::tray_icon(&self.icon));
+        if let Some(tooltip) = self.tooltip {
+            builder = builder.with_tooltip(tooltip);
+        }
+        if let Some(menu) = self.menu {
+            builder = builder.with_menu(Box::new(menu.build()));
+        }
+        builder.build()
+    }
+}
+
+/// Handlers of the tray events registered with [use_tray_event], provided to the app of every window.
+#[derive(Clone, Default)]
+pub(crate) struct TrayEventHandlers(Rc<RefCell<Vec<(Callback<TrayEvent, ()>, Weak<()>)>>>);
+
+impl TrayEventHandlers {
+    /// Run every alive handler with the given event.
+    pub(crate) fn emit(&self, event: &TrayEvent) {
+        let handlers = {
+            let mut handlers = self.0.borrow_mut();
+            handlers.retain(|(_, alive)| alive.strong_count() > 0);
+            handlers
+                .iter()
+                .map(|(handler, _)| handler.clone())
+                .collect::<Vec<_>>()
+        };
+        for handler in handlers {
+            handler.call(event.clone());
+        }
+    }
+}
+
+/// Run `handler` for every event of the tray icon and its menu.
+///
+/// ```rust,no_run
+/// # use freya::{prelude::*, tray::use_tray_event};
+/// fn app() -> impl IntoElement {
+///     use_tray_event(|event| {
+///         if event.menu_id() == Some("show") {
+///             Platform::get().with_window(None, |window| {
+///                 window.set_visible(true);
+///                 window.focus_window();
+///             });
+///         }
+///     });
+///
+///     rect()
+/// }
+/// ```
+pub fn use_tray_event(handler: impl FnMut(TrayEvent) + 'static) {
+    let handlers = use_try_consume::<TrayEventHandlers>();
+    let (current_handler, _alive) = use_hook(|| {
+        let current_handler = Rc::new(RefCell::new(Callback::new(|_: TrayEvent| {})));
+        let alive = Rc::new(());
+        if let Some(handlers) = handlers {
+            let current_handler = current_handler.clone();
+            handlers.0.borrow_mut().push((
+                Callback::new(move |event| current_handler.borrow().call(event)),
+                Rc::downgrade(&alive),
+            ));
+        }
+        (current_handler, alive)
+    });
+
+    // Keep the handler up to date with the latest render
+    *current_handler.borrow_mut() = Callback::new(handler);
+}
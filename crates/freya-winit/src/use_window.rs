@@ -0,0 +1,191 @@
+use freya_core::prelude::{
+    Platform,
+    ReadableUtils,
+    State,
+    WritableUtils,
+    use_consume,
+    use_hook,
+};
+use torin::prelude::Size2D;
+use winit::{
+    dpi::PhysicalPosition,
+    window::{
+        Fullscreen,
+        Icon,
+        ResizeDirection,
+        UserAttentionType,
+        Window,
+        WindowLevel,
+    },
+};
+
+use crate::{
+    extensions::WinitPlatformExt,
+    monitor::MonitorInfo,
+};
+
+/// Position and monitor of the window, provided to the app of every window.
+#[derive(Clone, Copy)]
+pub(crate) struct WindowStates {
+    pub(crate) position: State<Option<PhysicalPosition<i32>>>,
+    pub(crate) scale_factor: State<f64>,
+    pub(crate) monitor: State<Option<MonitorInfo>>,
+}
+
+impl WindowStates {
+    pub(crate) fn new(window: &Window) -> Self {
+        let primary = window.primary_monitor();
+        Self {
+            position: State::create(window.outer_position().ok()),
+            scale_factor: State::create(window.scale_factor()),
+            monitor: State::create(
+                window
+                    .current_monitor()
+                    .map(|monitor| MonitorInfo::new(&monitor, primary.as_ref())),
+            ),
+        }
+    }
+
+    /// Sync the states after the window moved or its scale factor changed.
+    pub(crate) fn update(&mut self, window: &Window) {
+        let primary = window.primary_monitor();
+        self.position.set_if_modified(window.outer_position().ok());
+        self.scale_factor.set_if_modified(window.scale_factor());
+        self.monitor.set_if_modified(
+            window
+                .current_monitor()
+                .map(|monitor| MonitorInfo::new(&monitor, primary.as_ref())),
+        );
+    }
+}
+
+/// Control the window of the current app, see [use_window].
+#[derive(Clone, Copy, PartialEq)]
+pub struct UseWindow {
+    size: State<Size2D>,
+    position: State<Option<PhysicalPosition<i32>>>,
+    scale_factor: State<f64>,
+    monitor: State<Option<MonitorInfo>>,
+}
+
+impl UseWindow {
+    /// Inner size of the window in physical pixels, subscribes to changes.
+    pub fn size(&self) -> Size2D {
+        *self.size.read()
+    }
+
+    /// Outer position of the window in physical pixels, subscribes to changes.
+    ///
+    /// This is `None` on platforms that don't expose it, such as Wayland.
+    pub fn position(&self) -> Option<PhysicalPosition<i32>> {
+        *self.position.read()
+    }
+
+    /// Scale factor of the window, subscribes to changes.
+    ///
+    /// It changes when the window moves to a monitor with a different scale factor.
+    pub fn scale_factor(&self) -> f64 {
+        *self.scale_factor.read()
+    }
+
+    /// Monitor the window is currently in, subscribes to changes.
+    pub fn monitor(&self) -> Option<MonitorInfo> {
+        self.monitor.read().clone()
+    }
+
+    pub fn minimize(&self) {
+        Platform::get().with_window(None, |window| window.set_minimized(true));
+    }
+
+    pub fn maximize(&self) {
+        Platform::get().with_window(None, |window| window.set_maximized(true));
+    }
+
+    pub fn toggle_maximized(&self) {
+        Platform::get().with_window(None, |window| window.set_maximized(!window.is_maximized()));
+    }
+
+    /// Switch between borderless fullscreen in the current monitor and windowed mode.
+    pub fn toggle_fullscreen(&self) {
+        Platform::get().with_window(None, |window| {
+            if window.fullscreen().is_some() {
+                window.set_fullscreen(None);
+            } else {
+                window.set_fullscreen(Some(Fullscreen::Borderless(None)));
+            }
+        });
+    }
+
+    pub fn set_title(&self, title: impl Into<String>) {
+        let title = title.into();
+        Platform::get().with_window(None, move |window| window.set_title(&title));
+    }
+
+    pub fn set_icon(&self, icon: Option<Icon>) {
+        Platform::get().with_window(None, move |window| window.set_window_icon(icon));
+    }
+
+    pub fn set_always_on_top(&self, always_on_top: bool) {
+        Platform::get().with_window(None, move |window| {
+            window.set_window_level(if always_on_top {
+                WindowLevel::AlwaysOnTop
+            } else {
+                WindowLevel::Normal
+            })
+        });
+    }
+
+    /// Start moving the window with the pointer, meant to be called while it is pressed.
+    pub fn start_drag(&self) {
+        Platform::get().with_window(None, |window| {
+            if let Err(err) = window.drag_window() {
+                tracing::error!("Failed to drag the window: {err}");
+            }
+        });
+    }
+
+    /// Start resizing the window from the given edge, meant to be called while the pointer is pressed.
+    pub fn start_resize(&self, direction: ResizeDirection) {
+        Platform::get().with_window(None, move |window| {
+            if let Err(err) = window.drag_resize_window(direction) {
+                tracing::error!("Failed to resize the window: {err}");
+            }
+        });
+    }
+
+    /// Ask the user to pay attention to the window, `None` cancels a previous request.
+    pub fn request_user_attention(&self, attention: Option<UserAttentionType>) {
+        Platform::get().with_window(None, move |window| window.request_user_attention(attention));
+    }
+}
+
+/// Control the window of the current app and read its size and position.
+///
+/// ```rust,no_run
+/// # use freya::prelude::*;
+/// fn app() -> impl IntoElement {
+///     let window = use_window();
+///     let size = window.size();
+///
+///     rect()
+///         .child(format!("{}x{}", size.width, size.height))
+///         .child(
+///             Button::new()
+///                 .on_press(move |_| window.toggle_fullscreen())
+///                 .child("Fullscreen"),
+///         )
+/// }
+/// ```
+pub fn use_window() -> UseWindow {
+    let WindowStates {
+        position,
+        scale_factor,
+        monitor,
+    } = use_consume::<WindowStates>();
+    use_hook(|| UseWindow {
+        size: Platform::get().root_size,
+        position,
+        scale_factor,
+        monitor,
+    })
+}
@@ -7,27 +7,35 @@ use std::{
 };
 
 use accesskit_winit::Adapter;
+use bytes::Bytes;
 use freya_clipboard::copypasta::{
     ClipboardContext,
     ClipboardProvider,
 };
-use freya_components::{
-    cache::AssetCacher,
-    integration::integration,
-};
+use freya_components::integration::integration;
 use freya_core::{
+    fifo_cache::FifoCache,
     integration::*,
-    prelude::Color,
+    prelude::{
+        Color,
+        CustomCursor,
+        MouseCursor,
+    },
 };
 use freya_engine::prelude::{
+    EncodedImageFormat,
     FontCollection,
     FontMgr,
+    raster_n32_premul,
 };
 use futures_util::task::{
     ArcWake,
     waker,
 };
-use ragnarok::NodesState;
+use ragnarok::{
+    EventsExecutorRunner,
+    NodesState,
+};
 use raw_window_handle::HasDisplayHandle;
 #[cfg(target_os = "linux")]
 use raw_window_handle::RawDisplayHandle;
@@ -36,7 +44,10 @@ use torin::prelude::{
     Size2D,
 };
 use winit::{
-    dpi::LogicalSize,
+    dpi::{
+        LogicalSize,
+        PhysicalPosition,
+    },
     event::ElementState,
     event_loop::{
         ActiveEventLoop,
@@ -44,6 +55,9 @@ use winit::{
     },
     keyboard::ModifiersState,
     window::{
+        CursorIcon,
+        CustomCursor as WinitCustomCursor,
+        ResizeDirection,
         Theme,
         Window,
         WindowAttributes,
@@ -57,19 +71,26 @@ use crate::{
         OnCloseHook,
         WindowConfig,
     },
+    double_click::system_double_click_interval,
     drivers::GraphicsDriver,
+    frame_pacer::FramePacer,
     plugins::{
         PluginEvent,
         PluginHandle,
         PluginsManager,
     },
+    recorder::FrameRecorder,
     renderer::{
         NativeEvent,
         NativeWindowEvent,
         NativeWindowEventAction,
     },
+    setup::provide_root_contexts,
 };
 
+/// Native custom cursors kept by every window, creating them is slow and they hold their pixels.
+const MAX_CUSTOM_CURSORS: usize = 16;
+
 pub struct AppWindow {
     pub(crate) runner: Runner,
     pub(crate) tree: Tree,
@@ -79,8 +100,23 @@ pub struct AppWindow {
 
     pub(crate) position: CursorPoint,
     pub(crate) mouse_state: ElementState,
+    pub(crate) click_counter: ClickCounter,
     pub(crate) modifiers_state: ModifiersState,
     pub(crate) just_focused: bool,
+    pub(crate) ime_composition: ImeComposition,
+    pub(crate) drag_tracker: DragTracker,
+    pub(crate) resize_border: Option<f64>,
+    pub(crate) resize_direction: Option<ResizeDirection>,
+    pub(crate) frame_pacer: FramePacer,
+    pub(crate) render_mode: RenderMode,
+    pub(crate) recorder: Option<FrameRecorder>,
+    #[cfg(feature = "event-recording")]
+    pub(crate) event_recorder: Option<crate::recorder::EventRecorder>,
+    pub(crate) window_states: crate::use_window::WindowStates,
+    #[cfg(feature = "tray")]
+    pub(crate) tray_event_handlers: crate::tray_icon::TrayEventHandlers,
+    #[cfg(feature = "tray")]
+    pub(crate) hide_to_tray: bool,
 
     pub(crate) events_receiver: futures_channel::mpsc::UnboundedReceiver<EventsChunk>,
     pub(crate) events_sender: futures_channel::mpsc::UnboundedSender<EventsChunk>,
@@ -103,6 +139,12 @@ pub struct AppWindow {
 
     pub(crate) dropped_file_paths: Vec<PathBuf>,
 
+    /// Native cursors of the [CustomCursor]s by their id, only the most recent ones are kept.
+    pub(crate) custom_cursors: FifoCache<u64, WinitCustomCursor>,
+
+    /// Cursor set by the app, hidden by the resize cursor while on the resize border.
+    pub(crate) mouse_cursor: MouseCursor,
+
     pub(crate) on_close: Option<OnCloseHook>,
 
     pub(crate) window_attributes: WindowAttributes,
@@ -129,6 +171,9 @@ impl AppWindow {
             .with_transparent(window_config.transparent)
             .with_inner_size(LogicalSize::<f64>::from(window_config.size));
 
+        if let Some(backdrop) = window_config.backdrop {
+            window_attributes = backdrop.apply(window_attributes);
+        }
         if let Some(min_size) = window_config.min_size {
             window_attributes =
                 window_attributes.with_min_inner_size(LogicalSize::<f64>::from(min_size));
@@ -137,9 +182,25 @@ impl AppWindow {
             window_attributes =
                 window_attributes.with_max_inner_size(LogicalSize::<f64>::from(max_size));
         }
+        if let Some(monitor) = window_config
+            .monitor
+            .as_ref()
+            .and_then(|monitor| monitor.select(active_event_loop))
+        {
+            let monitor_size = monitor.size();
+            let window_size = LogicalSize::<f64>::from(window_config.size)
+                .to_physical::<i32>(monitor.scale_factor());
+            let position = monitor.position();
+            window_attributes = window_attributes.with_position(PhysicalPosition::new(
+                position.x + (monitor_size.width as i32 - window_size.width) / 2,
+                position.y + (monitor_size.height as i32 - window_size.height) / 2,
+            ));
+        }
         #[cfg(target_os = "linux")]
         if let Some(app_id) = window_config.app_id.take() {
             use winit::platform::wayland::WindowAttributesExtWayland;
+            #[cfg(feature = "taskbar")]
+            crate::taskbar::set_app_id(&app_id);
             window_attributes = window_attributes.with_name(&app_id, &app_id);
         }
         if let Some(window_attributes_hook) = window_config.window_attributes_hook.take() {
@@ -165,17 +226,8 @@ impl AppWindow {
             }
         });
 
-        runner.provide_root_context(|| screen_reader);
-
-        let (mut ticker_sender, ticker) = RenderingTicker::new();
-        ticker_sender.set_overflow(true);
-        runner.provide_root_context(|| ticker);
-
-        let animation_clock = AnimationClock::new();
-        runner.provide_root_context(|| animation_clock.clone());
-
-        runner.provide_root_context(AssetCacher::create);
         let mut tree = Tree::default();
+        let animation_clock = tree.animation_clock.clone();
 
         let window_size = window.inner_size();
         let platform = runner.provide_root_context({
@@ -190,6 +242,7 @@ impl AppWindow {
                 focused_accessibility_node: State::create(accesskit::Node::new(
                     accesskit::Role::Window,
                 )),
+                focused_accessibility_ancestors: State::create(Vec::new()),
                 root_size: State::create(Size2D::new(
                     window_size.width as f32,
                     window_size.height as f32,
@@ -233,13 +286,20 @@ impl AppWindow {
             }
         };
 
-        runner.provide_root_context(|| State::create(clipboard));
-
-        runner.provide_root_context(|| tree.accessibility_generator.clone());
+        let ticker_sender = provide_root_contexts(
+            &mut runner,
+            &tree,
+            screen_reader,
+            clipboard,
+            font_collection,
+        );
 
-        runner.provide_root_context(|| tree.accessibility_generator.clone());
+        let window_states =
+            runner.provide_root_context(|| crate::use_window::WindowStates::new(&window));
 
-        runner.provide_root_context(|| font_collection.clone());
+        #[cfg(feature = "tray")]
+        let tray_event_handlers =
+            runner.provide_root_context(crate::tray_icon::TrayEventHandlers::default);
 
         plugins.send(
             PluginEvent::RunnerCreated {
@@ -285,6 +345,22 @@ impl AppWindow {
 
         let waker = waker(Arc::new(TreeHandle(event_loop_proxy.clone(), window.id())));
 
+        #[cfg(feature = "event-recording")]
+        if let Some(path) = &window_config.event_replay {
+            match freya_core::integration::EventRecording::load(path) {
+                Ok(recording) => runner.run_in(|| {
+                    crate::recorder::replay_events(
+                        &recording,
+                        window.scale_factor(),
+                        window.inner_size(),
+                        PluginHandle::new(event_loop_proxy),
+                        window.id(),
+                    )
+                }),
+                Err(err) => tracing::error!("Failed to load the event recording {path:?}: {err}"),
+            }
+        }
+
         plugins.send(
             PluginEvent::WindowCreated {
                 window: &window,
@@ -306,8 +382,33 @@ impl AppWindow {
 
             mouse_state: ElementState::Released,
             position: CursorPoint::default(),
+            click_counter: ClickCounter::new(
+                window_config
+                    .double_click_interval
+                    .unwrap_or_else(system_double_click_interval),
+            ),
             modifiers_state: ModifiersState::default(),
             just_focused: false,
+            ime_composition: ImeComposition::default(),
+            drag_tracker: DragTracker::default(),
+            resize_border: window_config.resize_border,
+            resize_direction: None,
+            frame_pacer: FramePacer::new(window_config.max_fps),
+            render_mode: RenderMode::default(),
+            recorder: None,
+            #[cfg(feature = "event-recording")]
+            event_recorder: window_config.event_recording.as_deref().and_then(|path| {
+                crate::recorder::EventRecorder::new(
+                    path,
+                    window.scale_factor(),
+                    window.inner_size(),
+                )
+            }),
+            window_states,
+            #[cfg(feature = "tray")]
+            tray_event_handlers,
+            #[cfg(feature = "tray")]
+            hide_to_tray: window_config.hide_to_tray,
 
             events_receiver,
             events_sender,
@@ -330,6 +431,10 @@ impl AppWindow {
 
             dropped_file_paths: Vec::new(),
 
+            custom_cursors: FifoCache::with_capacity(MAX_CUSTOM_CURSORS),
+
+            mouse_cursor: MouseCursor::default(),
+
             on_close,
 
             window_attributes,
@@ -343,4 +448,163 @@ impl AppWindow {
     pub fn window_mut(&mut self) -> &mut Window {
         &mut self.window
     }
+
+    /// Measure the given events against the tree and send them to be handled.
+    pub(crate) fn process_platform_events(&mut self, mut platform_events: Vec<PlatformEvent>) {
+        #[cfg(feature = "event-recording")]
+        if let Some(event_recorder) = &mut self.event_recorder {
+            event_recorder.record(&platform_events);
+        }
+        let mut events_measurer_adapter = EventsMeasurerAdapter {
+            tree: &mut self.tree,
+            scale_factor: self.window.scale_factor(),
+        };
+        let processed_events = events_measurer_adapter.run(
+            &mut platform_events,
+            &mut self.nodes_state,
+            self.accessibility.focused_node_id(),
+        );
+        self.events_sender
+            .unbounded_send(EventsChunk::Processed(processed_events))
+            .unwrap();
+        self.send_hovered_cursor();
+    }
+
+    /// Show the cursor of the element under the pointer if it changed, after events or layouts.
+    pub(crate) fn send_hovered_cursor(&mut self) {
+        if let Some(cursor) = self.tree.hovered_cursor.take_change() {
+            self.platform.send(cursor.into());
+        }
+    }
+
+    /// Set the cursor of the app, shown once the pointer is not on the resize border.
+    pub(crate) fn set_mouse_cursor(
+        &mut self,
+        mouse_cursor: MouseCursor,
+        active_event_loop: &ActiveEventLoop,
+    ) {
+        self.mouse_cursor = mouse_cursor;
+        if self.resize_direction.is_none() {
+            self.show_cursor(active_event_loop);
+        }
+    }
+
+    /// Show the resize cursor of the border under the pointer, or the cursor of the app outside of it.
+    pub(crate) fn set_resize_direction(
+        &mut self,
+        resize_direction: Option<ResizeDirection>,
+        active_event_loop: &ActiveEventLoop,
+    ) {
+        if resize_direction != self.resize_direction {
+            self.resize_direction = resize_direction;
+            self.show_cursor(active_event_loop);
+        }
+    }
+
+    fn show_cursor(&mut self, active_event_loop: &ActiveEventLoop) {
+        if let Some(resize_direction) = self.resize_direction {
+            self.window.set_cursor(CursorIcon::from(resize_direction));
+            return;
+        }
+        match self.mouse_cursor.clone() {
+            MouseCursor::Icon(cursor_icon) => self.window.set_cursor(cursor_icon),
+            MouseCursor::Custom(custom_cursor) => {
+                self.show_custom_cursor(custom_cursor, active_event_loop)
+            }
+        }
+    }
+
+    /// Show an image as cursor, the native cursors of the recently used images are reused.
+    fn show_custom_cursor(
+        &mut self,
+        custom_cursor: CustomCursor,
+        active_event_loop: &ActiveEventLoop,
+    ) {
+        let cursor = match self.custom_cursors.get(&custom_cursor.id()) {
+            Some(cursor) => cursor.clone(),
+            None => {
+                let (hotspot_x, hotspot_y) = custom_cursor.hotspot();
+                let source = match WinitCustomCursor::from_rgba(
+                    custom_cursor.rgba().to_vec(),
+                    custom_cursor.width(),
+                    custom_cursor.height(),
+                    hotspot_x,
+                    hotspot_y,
+                ) {
+                    Ok(source) => source,
+                    Err(err) => {
+                        tracing::error!("Failed to create a custom cursor: {err}");
+                        return;
+                    }
+                };
+                let cursor = active_event_loop.create_custom_cursor(source);
+                self.custom_cursors
+                    .insert(custom_cursor.id(), cursor.clone());
+                cursor
+            }
+        };
+        self.window.set_cursor(cursor);
+    }
+
+    /// Render a node and its descendants to a PNG image, `scale` is the scale factor of the image.
+    pub(crate) fn capture_node(
+        &self,
+        a11y_id: AccessibilityId,
+        scale: f64,
+        font_collection: &mut FontCollection,
+        font_manager: &FontMgr,
+    ) -> Option<Bytes> {
+        let node_id = *self.accessibility.map.get(&a11y_id)?;
+        let area = self.tree.layout.get(&node_id)?.visible_area();
+        let ratio = scale / self.window.scale_factor();
+        let mut surface = raster_n32_premul((
+            (area.width() as f64 * ratio).ceil() as i32,
+            (area.height() as f64 * ratio).ceil() as i32,
+        ))?;
+        surface.canvas().scale((ratio as f32, ratio as f32));
+
+        let render_pipeline = RenderPipeline {
+            font_collection,
+            font_manager,
+            tree: &self.tree,
+            canvas: surface.canvas(),
+            scale_factor: self.window.scale_factor(),
+            background: Color::TRANSPARENT,
+        };
+        render_pipeline.render_node(node_id);
+
+        let image = surface.image_snapshot();
+        let mut context = surface.direct_context();
+        let image = image.encode(context.as_mut(), EncodedImageFormat::PNG, None)?;
+        Some(Bytes::copy_from_slice(image.as_bytes()))
+    }
+
+    /// Edge of the resize border under the cursor, if any.
+    pub(crate) fn resize_direction_at(&self, cursor: CursorPoint) -> Option<ResizeDirection> {
+        let border = self.resize_border? * self.window.scale_factor();
+        if self.window.is_decorated()
+            || !self.window.is_resizable()
+            || self.window.is_maximized()
+            || self.window.fullscreen().is_some()
+        {
+            return None;
+        }
+        let size = self.window.inner_size();
+        let (width, height) = (size.width as f64, size.height as f64);
+        let west = cursor.x < border;
+        let east = cursor.x > width - border;
+        let north = cursor.y < border;
+        let south = cursor.y > height - border;
+        match (north, south, west, east) {
+            (true, _, true, _) => Some(ResizeDirection::NorthWest),
+            (true, _, _, true) => Some(ResizeDirection::NorthEast),
+            (_, true, true, _) => Some(ResizeDirection::SouthWest),
+            (_, true, _, true) => Some(ResizeDirection::SouthEast),
+            (true, ..) => Some(ResizeDirection::North),
+            (_, true, ..) => Some(ResizeDirection::South),
+            (_, _, true, _) => Some(ResizeDirection::West),
+            (_, _, _, true) => Some(ResizeDirection::East),
+            _ => None,
+        }
+    }
 }
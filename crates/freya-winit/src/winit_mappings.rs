@@ -1,7 +1,11 @@
-use freya_core::prelude::{
-    Force,
-    MouseButton,
-    TouchPhase,
+use freya_core::{
+    events::platform::PenEventName,
+    prelude::{
+        Force,
+        MouseButton,
+        PenState,
+        TouchPhase,
+    },
 };
 use keyboard_types::{
     Code,
@@ -383,3 +387,45 @@ pub fn map_winit_touch_force(event: winit::event::Force) -> Force {
         winit::event::Force::Normalized(f) => Force::Normalized(f),
     }
 }
+
+/// Map a touch into a stylus event.
+///
+/// Only styluses report an altitude angle. winit only provides the pressure and the altitude
+/// of the pen, it reports neither the azimuth, so the tilt can't be split into its X and Y axes,
+/// nor the twist, the eraser or the barrel button. Those are left as `None`.
+pub fn map_winit_pen(
+    phase: winit::event::TouchPhase,
+    force: Option<winit::event::Force>,
+) -> Option<(PenEventName, PenState)> {
+    let Some(winit::event::Force::Calibrated {
+        force,
+        max_possible_force,
+        altitude_angle: Some(altitude_angle),
+    }) = force
+    else {
+        return None;
+    };
+
+    let name = match phase {
+        winit::event::TouchPhase::Started => PenEventName::PenDown,
+        winit::event::TouchPhase::Moved => PenEventName::PenMove,
+        winit::event::TouchPhase::Ended | winit::event::TouchPhase::Cancelled => {
+            PenEventName::PenUp
+        }
+    };
+
+    let pressure = if max_possible_force > 0. {
+        (force / max_possible_force).clamp(0., 1.)
+    } else {
+        0.
+    };
+
+    Some((
+        name,
+        PenState {
+            pressure,
+            altitude: altitude_angle.to_degrees(),
+            ..PenState::default()
+        },
+    ))
+}
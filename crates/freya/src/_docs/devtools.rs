@@ -39,8 +39,45 @@
 //!
 //! The devtools app will connect to your running application automatically. If the app is not running yet it will keep retrying until it connects.
 //!
+//! ## Remote Inspection
+//!
+//! The app and the devtools app talk JSON over a WebSocket, so they don't need to run on the same machine.
+//! By default the server listens on `[::1]:7354`, set the `FREYA_DEVTOOLS_ADDRESS` environment variable to listen somewhere else:
+//!
+//! ```sh
+//! FREYA_DEVTOOLS_ADDRESS=0.0.0.0:7354 cargo run --features devtools
+//! ```
+//!
+//! Then pass the address of the app to the devtools app:
+//!
+//! ```sh
+//! freya-devtools-app 192.168.1.20:7354
+//! ```
+//!
+//! **Warning**: the devtools server has no authentication, anyone who can reach its address can inspect the app
+//! and send it commands. Binding to `0.0.0.0` exposes it to the whole network, so only do it on networks you trust
+//! and never in builds you ship.
+//!
+//! The messages are described by `IncomingMessage` and `OutgoingMessage` from the `freya-devtools` crate,
+//! other inspectors can implement them too. The server greets every client with its `PROTOCOL_VERSION`,
+//! the devtools app shows a warning when it differs from its own.
+//!
+//! ## Apps Built Without the `devtools` Feature
+//!
+//! The devtools app can only attach to apps running the devtools server, it shows the address it is waiting on until one is found.
+//! The server is small and the inspector UI is never part of your app, so you can also compile the server in and only start it on demand.
+//! Depend on `freya-devtools` with its `server` feature and register the plugin yourself:
+//!
+//! ```rust, ignore
+//! let mut launch_config = LaunchConfig::new().with_window(WindowConfig::new(app));
+//! if std::env::var_os("MY_APP_DEVTOOLS").is_some() {
+//!     launch_config = launch_config.with_plugin(freya_devtools::DevtoolsPlugin::default());
+//! }
+//! launch(launch_config);
+//! ```
+//!
 //! ## Limitations
 //!
-//! Only **one** Freya application with devtools enabled can run at a time.
-//! The embedded server listens on a fixed port (`7354` on localhost), so launching a second devtools-enabled app will fail to bind that port.
+//! Only **one** Freya application with devtools enabled can run at a time on the same address.
+//! Launching a second devtools-enabled app will fail to bind the port, unless it uses a different `FREYA_DEVTOOLS_ADDRESS`.
 //! Make sure to close any previous devtools-enabled instance before starting a new one.
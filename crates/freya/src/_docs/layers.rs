@@ -19,4 +19,22 @@
 //! }
 //! ```
 //!
-//! > **Note:** The rendering order of elements within the same layer is not guaranteed. Always use distinct layer values when order matters.
+//! ## Z-Index
+//!
+//! An element with a `.z_index()` creates a stacking context: it is rendered and receives events together with all its descendants,
+//! below the rest of the elements of the parent context for negative values and above them for positive ones, whatever their layer is.
+//! Stacking contexts nest, so a z-index only competes with the z-indexes of elements in the same context.
+//! This is useful for dropdowns, drag previews or sticky headers that must paint above later siblings and their children without restructuring the tree.
+//!
+//! Overlays escape the stacking contexts of their ancestors and are always rendered above them.
+//!
+//! ```rust,no_run
+//! # use freya::prelude::*;
+//! fn app() -> impl IntoElement {
+//!     rect()
+//!         .child(rect().z_index(1).child("Rendered on top"))
+//!         .child(rect().offset_y(-10.).child(rect().child("Rendered below")))
+//! }
+//! ```
+//!
+//! > **Note:** Elements with the same layer and z-index are rendered in the order they were created, use distinct values when order matters.
@@ -66,8 +66,11 @@
 //! - `i18n`: Reexport [freya_i18n] under [i18n]
 //! - `remote-asset`: Enables support for **HTTP** asset sources for [ImageViewer](components::ImageViewer) and [GifViewer](components::GifViewer) components.
 //! - `tray`: Enables tray support using the [tray_icon] crate.
+//! - `notifications`: Enables showing desktop notifications with `Platform::show_notification`.
+//! - `taskbar`: Enables showing progress and badges in the taskbar or dock with `Platform::set_taskbar_progress` and `Platform::set_taskbar_badge`.
 //! - `sdk`: Reexport [freya_sdk] under [sdk].
-//! - `gif`: Enables the [GifViewer](components::GifViewer) component.
+//! - `gif`: Enables the [GifViewer](components::GifViewer) component, which also plays APNG and WebP animations.
+//! - `lottie`: Enables the [LottieViewer](components::LottieViewer) component, rendered with Skia's Skottie.
 //! - `plot`: Reexport of plotters under [plot].
 //! - `material-design`: Reexport [freya_material_design] under [material_design].
 //! - `calendar`: Enables the [Calendar](components::Calendar) component.
@@ -85,6 +88,9 @@
 //! - `performance`: Reexports the performance overlay plugin. The plugin is auto-added in debug builds.
 //! - `vulkan`: Enables Vulkan rendering support.
 //! - `hotpath`: Enables Freya's internal usage of hotpath.
+//! - `theme-hot-reload`: Enables `use_theme_hot_reload`, to apply theme overrides from a file every time it changes.
+//! - `hyphenation`: Enables the dictionary based `hyphenation` text attribute, in English (US).
+//! - `event-recording`: Enables recording the events of a window to a file and replaying them, see `WindowConfig::with_event_recording` and `WindowConfig::with_event_replay`.
 
 pub mod prelude {
     pub use freya_core::prelude::*;
@@ -92,18 +98,28 @@ pub mod prelude {
         Clipboard,
         ClipboardError,
     };
+    #[cfg(feature = "notifications")]
+    pub use freya_winit::Notification;
+    #[cfg(feature = "taskbar")]
+    pub use freya_winit::TaskbarProgress;
     pub use freya_winit::{
+        MonitorInfo,
+        MonitorSelector,
+        RecordedFrame,
+        UseWindow,
         WindowDragExt,
         WinitPlatformExt,
         config::{
             CloseDecision,
             LaunchConfig,
+            WindowBackdrop,
             WindowConfig,
         },
         renderer::{
             NativeEvent,
             RendererContext,
         },
+        use_window,
     };
 
     pub use crate::components::*;
@@ -122,14 +138,22 @@ pub mod prelude {
     pub use freya_router;
     pub use torin::{
         alignment::Alignment,
+        calc::Calc,
         content::Content,
         direction::Direction,
-        gaps::Gaps,
+        gaps::{
+            Gaps,
+            GapsUnit,
+        },
         geometry::{
             Area,
             CursorPoint,
             Size2D,
         },
+        grid::{
+            GridPlacement,
+            GridSize,
+        },
         position::Position,
         size::Size,
         visible_size::VisibleSize,
@@ -143,6 +167,9 @@ pub mod components {
     #[cfg_attr(feature = "docs", doc(cfg(feature = "gif")))]
     #[cfg(feature = "gif")]
     pub use freya_components::gif_viewer::*;
+    #[cfg_attr(feature = "docs", doc(cfg(feature = "lottie")))]
+    #[cfg(feature = "lottie")]
+    pub use freya_components::lottie::*;
     #[cfg_attr(feature = "docs", doc(cfg(feature = "markdown")))]
     #[cfg(feature = "markdown")]
     pub use freya_components::markdown::*;
@@ -166,6 +193,7 @@ pub mod components {
     pub use freya_components::{
         accordion::*,
         activable_route_context::*,
+        anchored::*,
         attached::*,
         button::*,
         canvas::*,
@@ -175,10 +203,12 @@ pub mod components {
         color_picker::*,
         context_menu::*,
         cursor_area::*,
+        data_table::*,
         define_theme,
         drag_drop::*,
         draggable_canvas::*,
         element_expansions::*,
+        external_texture::*,
         floating_tab::*,
         gallery,
         get_theme,
@@ -204,18 +234,24 @@ pub mod components {
         slider::*,
         switch::*,
         table::*,
+        tabs::*,
         theming::{
             component_themes::{
                 ColorsSheet,
+                DesignTokens,
                 Theme,
             },
             extensions::*,
             hooks::*,
             macros::Preference,
+            overrides::*,
+            provider::*,
             themes::*,
         },
         tile::*,
+        toast::*,
         tooltip::*,
+        tree_view::*,
     };
 }
 
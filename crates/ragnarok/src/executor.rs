@@ -4,6 +4,7 @@ use crate::{
     NodeKey,
     NodesState,
     NodesStatesUpdate,
+    PointerCapture,
     PotentialEvent,
     SourceEvent,
 };
@@ -46,6 +47,11 @@ where
 
     // All events have been emitted
     fn emitted_events(&mut self) {}
+
+    /// Take the [PointerCapture] change requested while emitting the events, if any.
+    fn take_pointer_capture(&mut self) -> Option<PointerCapture<Self::Key>> {
+        None
+    }
 }
 
 impl<T: EventsExecutor> private::Sealed for T {}
@@ -106,6 +112,10 @@ impl<T: EventsExecutor + private::Sealed> EventsExecutorRunner for T {
 
         self.emitted_events();
 
+        if let Some(pointer_capture) = self.take_pointer_capture() {
+            nodes_state.apply_pointer_capture(pointer_capture);
+        }
+
         nodes_state.apply_update(nodes_states_update);
     }
 }
@@ -48,6 +48,25 @@ pub fn measure_source_global_events<
     }
 }
 
+/// Check if the pointer of the given source event is inside a node,
+/// or if the node is capturing the pointer (or is an ancestor of the capturing node).
+pub fn is_pointer_inside<Key: NodeKey, Name: NameOfEvent, Source: SourceEvent<Name = Name>>(
+    events_measurer: &impl EventsMeasurer<Key = Key, Name = Name>,
+    captured_node: Option<Key>,
+    node_key: &Key,
+    source_event: &Source,
+) -> bool {
+    match captured_node {
+        Some(captured_node) if source_event.as_event_name().is_capturable() => {
+            *node_key == captured_node
+                || events_measurer.is_node_parent_of(&captured_node, *node_key)
+        }
+        _ => source_event
+            .try_location()
+            .is_none_or(|cursor| events_measurer.is_point_inside(node_key, cursor)),
+    }
+}
+
 /// Measure what event listeners could potentially be triggered
 pub fn measure_potential_events<
     Key: NodeKey,
@@ -63,6 +82,7 @@ pub fn measure_potential_events<
         Source = Source,
     >,
     focus_id: Option<Key>,
+    captured_node: Option<Key>,
 ) -> PotentialEvents<Key, Name, Source> {
     let mut potential_events = PotentialEvents::default();
 
@@ -73,7 +93,7 @@ pub fn measure_potential_events<
     {
         for node_id in layer_nodes {
             for source_event in source_events {
-                let Some(cursor) = source_event.try_location() else {
+                if source_event.try_location().is_none() {
                     if focus_id == Some(*node_id) {
                         let potential_event = PotentialEvent {
                             node_key: *node_id,
@@ -87,9 +107,9 @@ pub fn measure_potential_events<
                             .push(potential_event);
                     }
                     continue;
-                };
+                }
 
-                if !events_measurer.is_point_inside(node_id, cursor) {
+                if !is_pointer_inside(events_measurer, captured_node, node_id, source_event) {
                     continue;
                 }
 
@@ -58,12 +58,13 @@ impl<T: EventsMeasurer + private::Sealed> EventsMeasurerRunner for T {
         focus_id: Option<Self::Key>,
     ) -> ProcessedEvents<Self::Key, Self::Name, Self::Emmitable, Self::Source> {
         // Get potential events that could be emitted based on the elements layout and viewports
-        let potential_events = measure_potential_events::<
-            Self::Key,
-            Self::Name,
-            Self::Source,
-            Self::Emmitable,
-        >(source_events, self, focus_id);
+        let potential_events =
+            measure_potential_events::<Self::Key, Self::Name, Self::Source, Self::Emmitable>(
+                source_events,
+                self,
+                focus_id,
+                nodes_state.captured_pointer(),
+            );
 
         // Get what events can be actually emitted based on what elements are listening
         let mut emmitable_events =
@@ -92,6 +93,14 @@ impl<T: EventsMeasurer + private::Sealed> EventsMeasurerRunner for T {
         let mut flattened_potential_events = potential_events.into_values().flatten().collect_vec();
         flattened_potential_events.sort_unstable();
 
+        // The pointer capture ends once the pointer is released or the capturing node is gone
+        if let Some(captured_node) = nodes_state.captured_pointer()
+            && (source_events.iter().any(|e| e.is_released())
+                || self.try_area_of(&captured_node).is_none())
+        {
+            nodes_state.release_pointer();
+        }
+
         // Clear the source events vec as all events have been processed
         source_events.clear();
 
@@ -16,6 +16,10 @@ pub trait NameOfEvent:
     fn is_pressed(&self) -> bool;
     /// Check if this event means that the pointer device was released while hovering and hovering a node.
     fn is_released(&self) -> bool;
+    /// Check if this event is delivered to the node capturing the pointer, independently of where the pointer is.
+    fn is_capturable(&self) -> bool {
+        false
+    }
     /// Check if this event is global, where global means that an event will be emitted to every node independently of where they are or how they are.
     fn is_global(&self) -> bool;
 
@@ -10,6 +10,7 @@ use crate::{
     NodeKey,
     PotentialEvent,
     SourceEvent,
+    is_pointer_inside,
 };
 
 /// [`NodesState`] stores the nodes states given incoming events.
@@ -17,6 +18,7 @@ pub struct NodesState<Key: NodeKey> {
     pressed_nodes: FxHashSet<Key>,
     hovered_nodes: FxHashSet<Key>,
     entered_node: Option<Key>,
+    captured_node: Option<Key>,
 }
 
 impl<Key: NodeKey> Default for NodesState<Key> {
@@ -25,10 +27,18 @@ impl<Key: NodeKey> Default for NodesState<Key> {
             pressed_nodes: FxHashSet::default(),
             hovered_nodes: FxHashSet::default(),
             entered_node: None,
+            captured_node: None,
         }
     }
 }
 
+/// Change of the pointer capture requested while emitting events.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PointerCapture<Key: NodeKey> {
+    Capture(Key),
+    Release,
+}
+
 pub type PotentialEvents<Key, Name, Source> =
     FxHashMap<Name, Vec<PotentialEvent<Key, Name, Source>>>;
 
@@ -37,7 +47,7 @@ impl<Key: NodeKey> NodesState<Key> {
     pub(crate) fn retain_states<
         Emmitable: EmmitableEvent<Key = Key, Name = Name>,
         Name: NameOfEvent,
-        Source: SourceEvent,
+        Source: SourceEvent<Name = Name>,
     >(
         &mut self,
         events_measurer: &impl EventsMeasurer<
@@ -74,6 +84,7 @@ impl<Key: NodeKey> NodesState<Key> {
             .iter()
             .find(|e| e.is_moved() || e.is_touch_released());
         let mut removed_from_hovered = FxHashSet::default();
+        let captured_node = self.captured_node;
 
         self.hovered_nodes.retain(|node_key| {
             let Some(area) = events_measurer.try_area_of(node_key) else {
@@ -81,9 +92,9 @@ impl<Key: NodeKey> NodesState<Key> {
                 return false;
             };
 
-            let cursor_still_inside = source_movement_event
-                .and_then(|e| e.try_location())
-                .is_none_or(|cursor| events_measurer.is_point_inside(node_key, cursor));
+            let cursor_still_inside = source_movement_event.is_none_or(|source_event| {
+                is_pointer_inside(events_measurer, captured_node, node_key, source_event)
+            });
 
             if cursor_still_inside {
                 return true;
@@ -225,6 +236,33 @@ impl<Key: NodeKey> NodesState<Key> {
         self.pressed_nodes.extend(update.pressed_nodes);
     }
 
+    /// Deliver the pointer events to the given node even if the pointer is outside of it,
+    /// until [NodesState::release_pointer] is called or the pointer device is released.
+    pub fn capture_pointer(&mut self, key: Key) {
+        #[cfg(debug_assertions)]
+        tracing::info!("Pointer captured by {:?}", key);
+
+        self.captured_node = Some(key);
+    }
+
+    /// Stop capturing the pointer.
+    pub fn release_pointer(&mut self) {
+        self.captured_node = None;
+    }
+
+    /// Get the node that is currently capturing the pointer, if any.
+    pub fn captured_pointer(&self) -> Option<Key> {
+        self.captured_node
+    }
+
+    /// Apply a [PointerCapture] change.
+    pub fn apply_pointer_capture(&mut self, pointer_capture: PointerCapture<Key>) {
+        match pointer_capture {
+            PointerCapture::Capture(key) => self.capture_pointer(key),
+            PointerCapture::Release => self.release_pointer(),
+        }
+    }
+
     pub fn is_hovered(&self, key: Key) -> bool {
         self.hovered_nodes.contains(&key)
     }
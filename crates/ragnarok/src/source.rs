@@ -9,6 +9,10 @@ pub trait SourceEvent: Clone + PartialEq {
     fn is_pressed(&self) -> bool;
     fn is_moved(&self) -> bool;
     fn is_touch_released(&self) -> bool;
+    /// Check if this event releases the pointer device, e.g a mouse button or a finger.
+    fn is_released(&self) -> bool {
+        self.is_touch_released()
+    }
 
     fn try_location(&self) -> Option<CursorPoint>;
 
@@ -38,7 +38,7 @@ impl TestingTree {
 }
 
 impl TreeAdapter<usize> for TestingTree {
-    fn children_of(&mut self, node_id: &usize) -> Vec<usize> {
+    fn children_of(&self, node_id: &usize) -> Vec<usize> {
         self.mapper
             .get(node_id)
             .map(|c| c.1.clone())
@@ -100,6 +100,7 @@ impl BenchmarkConfig {
 enum BenchmarkMode {
     NoCache,
     InvalidatedCache,
+    ResizedRoot,
 }
 
 impl Display for BenchmarkMode {
@@ -107,6 +108,7 @@ impl Display for BenchmarkMode {
         match self {
             Self::NoCache => f.write_str("not cached"),
             Self::InvalidatedCache => f.write_str("cached"),
+            Self::ResizedRoot => f.write_str("resized"),
         }
     }
 }
@@ -122,6 +124,14 @@ fn criterion_benchmark(c: &mut Criterion) {
         )
     }
 
+    fn percentage_node_generator(_depth: usize) -> Node {
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Pixels(Length::new(100.0)),
+            Direction::Vertical,
+        )
+    }
+
     let benchmarks = [
         BenchmarkConfig {
             depth: 2,
@@ -231,6 +241,46 @@ fn criterion_benchmark(c: &mut Criterion) {
             },
             prefix: "alignments=true ".to_string(),
         },
+        BenchmarkConfig {
+            depth: 2,
+            wide: 10000,
+            mode: BenchmarkMode::ResizedRoot,
+            sample: 100,
+            node_generator: simple_node_generator,
+            prefix: String::default(),
+        },
+        BenchmarkConfig {
+            depth: 14,
+            wide: 2,
+            mode: BenchmarkMode::ResizedRoot,
+            sample: 100,
+            node_generator: simple_node_generator,
+            prefix: String::default(),
+        },
+        BenchmarkConfig {
+            depth: 7,
+            wide: 5,
+            mode: BenchmarkMode::ResizedRoot,
+            sample: 100,
+            node_generator: simple_node_generator,
+            prefix: String::default(),
+        },
+        BenchmarkConfig {
+            depth: 7,
+            wide: 5,
+            mode: BenchmarkMode::NoCache,
+            sample: 100,
+            node_generator: percentage_node_generator,
+            prefix: "percentages=true ".to_string(),
+        },
+        BenchmarkConfig {
+            depth: 7,
+            wide: 5,
+            mode: BenchmarkMode::ResizedRoot,
+            sample: 100,
+            node_generator: percentage_node_generator,
+            prefix: "percentages=true ".to_string(),
+        },
     ];
 
     for bench in benchmarks {
@@ -248,6 +298,11 @@ fn criterion_benchmark(c: &mut Criterion) {
 
         g.bench_function(name, |b| {
             let root_area = Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0));
+            let measure_area = if mode == BenchmarkMode::ResizedRoot {
+                Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1200.0, 800.0))
+            } else {
+                root_area
+            };
             b.iter_batched(
                 || {
                     let mut measurer = Some(NoopMeasurer);
@@ -334,11 +389,17 @@ fn criterion_benchmark(c: &mut Criterion) {
                         layout.invalidate(invalidate_node);
                     }
 
+                    if mode == BenchmarkMode::ResizedRoot {
+                        layout.find_best_root(&mut mocked_tree);
+                        layout.measure(0, root_area, &mut measurer, &mut mocked_tree);
+                        layout.invalidate(0);
+                    }
+
                     (mocked_tree, measurer, layout)
                 },
                 |(mut mocked_tree, mut measurer, mut layout)| {
                     layout.find_best_root(&mut mocked_tree);
-                    layout.measure(0, root_area, &mut measurer, &mut mocked_tree)
+                    layout.measure(0, measure_area, &mut measurer, &mut mocked_tree)
                 },
                 criterion::BatchSize::SmallInput,
             )
@@ -57,7 +57,7 @@ impl DemoTree {
 }
 
 impl TreeAdapter<usize> for DemoTree {
-    fn children_of(&mut self, node_id: &usize) -> Vec<usize> {
+    fn children_of(&self, node_id: &usize) -> Vec<usize> {
         self.nodes
             .get(node_id)
             .map(|c| c.children.clone())
@@ -22,6 +22,11 @@ pub trait LayoutMeasurer<Key: NodeKey> {
 
     fn should_measure_inner_children(&mut self, node_id: Key) -> bool;
 
+    /// Distance from the top of the measured content to its first baseline.
+    fn baseline(&mut self, _node_id: Key, _data: &Rc<dyn Any>) -> Option<f32> {
+        None
+    }
+
     fn notify_layout_references(
         &mut self,
         _node_id: Key,
@@ -12,6 +12,8 @@ pub mod custom_measurer;
 pub mod geometry;
 pub mod measure;
 pub mod node;
+#[cfg(feature = "parallel")]
+mod parallel;
 pub mod scaled;
 pub mod torin;
 pub mod tree_adapter;
@@ -1,10 +1,16 @@
+use std::borrow::Cow;
+
 pub use euclid::Rect;
-use rustc_hash::FxHashMap;
+use rustc_hash::{
+    FxHashMap,
+    FxHashSet,
+};
 
 use crate::{
     custom_measurer::LayoutMeasurer,
     geometry::{
         Area,
+        Point2D,
         Size2D,
     },
     node::Node,
@@ -19,6 +25,8 @@ use crate::{
         AvailableAreaModel,
         Content,
         Direction,
+        GridPlacement,
+        GridSize,
         Inner,
         LayoutMetadata,
         Length,
@@ -43,6 +51,20 @@ pub enum Phase {
     Final,
 }
 
+/// Areas a cached Node was measured with and which of them its layout depends on.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MeasureInputs {
+    /// Layout configuration the Node was measured with.
+    pub node: Node,
+    pub initial_parent_area: AreaOf<Parent>,
+    pub available_parent_area: AreaOf<Available>,
+    pub root_area: Area,
+    /// The Node or its descendants are sized or positioned relative to the parent size.
+    pub depends_on_parent: bool,
+    /// The Node or its descendants are sized or positioned relative to the root size.
+    pub depends_on_root: bool,
+}
+
 pub struct MeasureContext<'a, Key, L, D>
 where
     Key: NodeKey,
@@ -51,8 +73,12 @@ where
 {
     pub layout: &'a mut Torin<Key>,
     pub measurer: &'a mut Option<L>,
-    pub tree_adapter: &'a mut D,
+    pub tree_adapter: &'a D,
     pub layout_metadata: LayoutMetadata,
+    /// Ancestors of the dirty Nodes, any other cached subtree can be skipped
+    pub dirty_ancestors: &'a FxHashSet<Key>,
+    /// Nodes measured ahead of time that only need to be moved to their place
+    pub premeasured: FxHashSet<Key>,
 }
 
 impl<Key, L, D> MeasureContext<'_, Key, L, D>
@@ -61,18 +87,22 @@ where
     L: LayoutMeasurer<Key>,
     D: TreeAdapter<Key>,
 {
+    /// Get the Node with its viewport relative gaps resolved.
+    fn get_node(&self, node_id: &Key) -> Option<Node> {
+        self.tree_adapter
+            .get_node(node_id)
+            .map(|node| node.resolve_gaps(self.layout_metadata.root_area.size))
+    }
+
     /// Translate all the children of the given Node by the specified X and Y offsets.
     fn recursive_translate(&mut self, node_id: Key, offset_x: Length, offset_y: Length) {
         let mut buffer = self.tree_adapter.children_of(&node_id);
         while let Some(child) = buffer.pop() {
-            let node = self
-                .tree_adapter
-                .get_node(&child)
-                .expect("Node does not exist");
+            let node = self.get_node(&child).expect("Node does not exist");
 
             let translate = match node.position {
                 Position::Global(_) => false,
-                Position::Stacked(_) | Position::Absolute(_) => true,
+                Position::Stacked(_) | Position::Absolute(_) | Position::Sticky(_) => true,
             };
 
             if translate {
@@ -99,6 +129,114 @@ where
         }
     }
 
+    /// Move the sticky descendants of a scrolled Node so they stay within its visible area.
+    fn apply_sticky(
+        &mut self,
+        node_id: Key,
+        node: &Node,
+        layout_node: &LayoutNode,
+        parent_area: &AreaOf<Parent>,
+    ) {
+        // Scrolled Nodes are usually as big as their content and clipped by their parent
+        let viewport = layout_node
+            .area
+            .without_gaps(&node.margin)
+            .without_gaps(&node.padding);
+        let viewport = viewport
+            .intersection(&parent_area.cast_unit())
+            .unwrap_or(viewport);
+
+        let mut buffer = self.tree_adapter.children_of(&node_id);
+        while let Some(child) = buffer.pop() {
+            let child_data = self.get_node(&child).expect("Node does not exist");
+
+            // Global Nodes are not scrolled and nested scrolled Nodes pin their own descendants
+            if child_data.position.is_global()
+                || child_data.offset_x.get() != 0.
+                || child_data.offset_y.get() != 0.
+            {
+                continue;
+            }
+
+            if let Position::Sticky(sides) = &child_data.position {
+                // Sticky Nodes never leave their parent
+                let parent_inner_area = match self.tree_adapter.parent_of(&child) {
+                    Some(parent_id) if parent_id != node_id => {
+                        self.layout.get(&parent_id).map(|parent| parent.inner_area)
+                    }
+                    _ => Some(layout_node.inner_area),
+                };
+
+                if let Some(parent_inner_area) = parent_inner_area
+                    && let Some(child_layout) = self.layout.get_mut(&child)
+                {
+                    let area = child_layout.area;
+                    let flow_x = area.origin.x - child_layout.sticky_offset.x;
+                    let flow_y = area.origin.y - child_layout.sticky_offset.y;
+                    let x = Self::sticky_axis(
+                        flow_x,
+                        area.width(),
+                        sides.left,
+                        sides.right,
+                        (viewport.min_x(), viewport.max_x()),
+                        (parent_inner_area.min_x(), parent_inner_area.max_x()),
+                    );
+                    let y = Self::sticky_axis(
+                        flow_y,
+                        area.height(),
+                        sides.top,
+                        sides.bottom,
+                        (viewport.min_y(), viewport.max_y()),
+                        (parent_inner_area.min_y(), parent_inner_area.max_y()),
+                    );
+                    let diff_x = x - area.origin.x;
+                    let diff_y = y - area.origin.y;
+
+                    if diff_x != 0. || diff_y != 0. {
+                        child_layout.area.origin = Point2D::new(x, y);
+                        child_layout.inner_area.origin.x += diff_x;
+                        child_layout.inner_area.origin.y += diff_y;
+                        child_layout.sticky_offset = Point2D::new(x - flow_x, y - flow_y);
+
+                        if let Some(measurer) = self.measurer {
+                            measurer.notify_layout_references(
+                                child,
+                                child_layout.area,
+                                child_layout.visible_area(),
+                                child_layout.inner_sizes,
+                            );
+                        }
+
+                        self.recursive_translate(child, Length::new(diff_x), Length::new(diff_y));
+                    }
+                }
+            }
+
+            buffer.extend(self.tree_adapter.children_of(&child));
+        }
+    }
+
+    /// Position of a sticky Node along one axis.
+    fn sticky_axis(
+        flow: f32,
+        size: f32,
+        start: Option<f32>,
+        end: Option<f32>,
+        (viewport_start, viewport_end): (f32, f32),
+        (parent_start, parent_end): (f32, f32),
+    ) -> f32 {
+        let mut position = flow;
+        if let Some(start) = start {
+            position = position.max(viewport_start + start);
+        }
+        if let Some(end) = end {
+            position = position.min(viewport_end - end - size);
+        }
+        position
+            .min((parent_end - size).max(flow))
+            .max(parent_start.min(flow))
+    }
+
     /// Measure a Node and all its children.
     #[allow(clippy::too_many_arguments, clippy::missing_panics_doc)]
     pub fn measure_node(
@@ -118,6 +256,23 @@ where
     ) -> (bool, LayoutNode) {
         let reason = self.layout.dirty.get(&node_id).copied();
 
+        // Subtrees measured ahead of time only need to be moved to their place
+        if self.premeasured.contains(&node_id)
+            && let Some(layout_node) = self.translate_cached(
+                node_id,
+                node,
+                &initial_parent_area,
+                &available_parent_area,
+                must_cache_children,
+                phase,
+            )
+        {
+            if must_cache_children && phase == Phase::Final {
+                self.premeasured.remove(&node_id);
+            }
+            return (false, layout_node);
+        }
+
         // If possible translate all this Node's descendants to avoid relayout
         if let Some(layout_node) = self.layout.get_mut(&node_id)
             && reason == Some(DirtyReason::InnerLayout)
@@ -133,16 +288,37 @@ where
             let layout_node = layout_node.clone();
 
             self.recursive_translate(node_id, offset_x, offset_y);
+            self.apply_sticky(node_id, node, &layout_node, &initial_parent_area);
 
             return (must_cache_children, layout_node);
         }
 
+        // Reuse the cached layout if the parent changed in a way that doesn't affect this Node
+        if parent_is_dirty
+            && reason.is_none()
+            && let Some(layout_node) = self.reuse_unaffected(
+                node_id,
+                node,
+                &initial_parent_area,
+                &available_parent_area,
+                must_cache_children,
+                phase,
+            )
+        {
+            return (false, layout_node);
+        }
+
         // 1. If parent is dirty
         // 2. If this Node has been marked as dirty
         // 3. If there is no know cached data about this Node.
         let must_revalidate =
             parent_is_dirty || reason.is_some() || !self.layout.results.contains_key(&node_id);
         if must_revalidate {
+            let original_node = node;
+            let aspect_ratio_node =
+                self.apply_aspect_ratio(node, &initial_parent_area, &available_parent_area, phase);
+            let node = aspect_ratio_node.as_ref();
+
             // Create the initial Node area size
             let mut area_size = Size2D::new(node.padding.horizontal(), node.padding.vertical());
 
@@ -156,6 +332,7 @@ where
                 &node.minimum_width,
                 &node.maximum_width,
                 self.layout_metadata.root_area.width(),
+                self.layout_metadata.root_area.size,
                 phase,
             );
             area_size.height = node.height.min_max(
@@ -167,12 +344,13 @@ where
                 &node.minimum_height,
                 &node.maximum_height,
                 self.layout_metadata.root_area.height(),
+                self.layout_metadata.root_area.size,
                 phase,
             );
 
             // If available, run a custom layout measure function
             // This is useful when you use third-party libraries (e.g. rust-skia, cosmic-text) to measure text layouts
-            let node_data = if let Some(measurer) = self.measurer {
+            let (node_data, baseline) = if let Some(measurer) = self.measurer {
                 if measurer.should_hook_measurement(node_id) {
                     let available_width =
                         Size::Pixels(Length::new(available_parent_area.size.width)).min_max(
@@ -184,6 +362,7 @@ where
                             &node.minimum_width,
                             &node.maximum_width,
                             self.layout_metadata.root_area.width(),
+                            self.layout_metadata.root_area.size,
                             phase,
                         );
                     let available_height =
@@ -196,6 +375,7 @@ where
                             &node.minimum_height,
                             &node.maximum_height,
                             self.layout_metadata.root_area.height(),
+                            self.layout_metadata.root_area.size,
                             phase,
                         );
                     let most_fitting_width = *node
@@ -222,6 +402,7 @@ where
                                 &node.minimum_width,
                                 &node.maximum_width,
                                 self.layout_metadata.root_area.width(),
+                                self.layout_metadata.root_area.size,
                                 phase,
                             );
                         }
@@ -235,20 +416,26 @@ where
                                 &node.minimum_height,
                                 &node.maximum_height,
                                 self.layout_metadata.root_area.height(),
+                                self.layout_metadata.root_area.size,
                                 phase,
                             );
                         }
 
+                        // Measured baselines start at the content, skip margin and padding
+                        let baseline = measurer
+                            .baseline(node_id, &node_data)
+                            .map(|baseline| baseline + node.margin.top() + node.padding.top());
+
                         // Do not measure inner children
-                        Some(node_data)
+                        (Some(node_data), baseline)
                     } else {
-                        None
+                        (None, None)
                     }
                 } else {
-                    None
+                    (None, None)
                 }
             } else {
-                None
+                (None, None)
             };
 
             let measure_inner_children = if let Some(measurer) = self.measurer {
@@ -280,6 +467,7 @@ where
                         &node.minimum_width,
                         &node.maximum_width,
                         self.layout_metadata.root_area.width(),
+                        self.layout_metadata.root_area.size,
                         phase,
                     );
                 }
@@ -293,6 +481,7 @@ where
                         &node.minimum_height,
                         &node.maximum_height,
                         self.layout_metadata.root_area.height(),
+                        self.layout_metadata.root_area.size,
                         phase,
                     );
                 }
@@ -345,6 +534,7 @@ where
                         &node.minimum_width,
                         &node.maximum_width,
                         self.layout_metadata.root_area.width(),
+                        self.layout_metadata.root_area.size,
                         phase,
                     );
                 }
@@ -358,6 +548,7 @@ where
                         &node.minimum_height,
                         &node.maximum_height,
                         self.layout_metadata.root_area.height(),
+                        self.layout_metadata.root_area.size,
                         phase,
                     );
                 }
@@ -387,16 +578,39 @@ where
                 }
             }
 
+            let is_measured = node_data.is_some();
             let layout_node = LayoutNode {
                 area,
                 margin: node.margin,
                 offset_x: node.offset_x,
                 offset_y: node.offset_y,
                 inner_area,
+                baseline,
+                sticky_offset: Point2D::default(),
                 data: node_data,
                 inner_sizes,
             };
 
+            if must_cache_children && phase == Phase::Final {
+                self.record_inputs(
+                    node_id,
+                    original_node,
+                    node,
+                    is_measured,
+                    measure_inner_children,
+                    initial_parent_area,
+                    available_parent_area,
+                );
+            }
+
+            // Pin the sticky descendants of scrolled Nodes
+            if must_cache_children
+                && phase == Phase::Final
+                && (node.offset_x.get() != 0. || node.offset_y.get() != 0.)
+            {
+                self.apply_sticky(node_id, node, &layout_node, &initial_parent_area);
+            }
+
             // In case of any layout listener, notify it with the new areas.
             if must_cache_children
                 && phase == Phase::Final
@@ -432,7 +646,8 @@ where
                 true
             };
 
-            if measure_inner_children {
+            // Only the subtrees with dirty Nodes need to be walked
+            if measure_inner_children && self.dirty_ancestors.contains(&node_id) {
                 self.measure_children(
                     &node_id,
                     node,
@@ -449,6 +664,206 @@ where
         }
     }
 
+    /// Reuse the cached layout of a Node whose inputs only changed in ways it doesn't depend on,
+    /// translating it and its descendants to their new origin.
+    fn reuse_unaffected(
+        &mut self,
+        node_id: Key,
+        node: &Node,
+        initial_parent_area: &AreaOf<Parent>,
+        available_parent_area: &AreaOf<Available>,
+        must_cache_children: bool,
+        phase: Phase,
+    ) -> Option<LayoutNode> {
+        // Dirty descendants need a full measurement, flex sizes are not resolved until
+        // the final phase and sticky Nodes are stacked without their pinned offset
+        if self.dirty_ancestors.contains(&node_id)
+            || node.position.is_sticky()
+            || (phase == Phase::Initial && (node.width.is_flex() || node.height.is_flex()))
+        {
+            return None;
+        }
+
+        // Nodes that changed without being invalidated, e.g. a new Node with the key of a removed one
+        let inputs = self.layout.measure_inputs.get(&node_id)?;
+        if inputs.node != *node {
+            return None;
+        }
+        if inputs.depends_on_parent
+            && (inputs.initial_parent_area.size != initial_parent_area.size
+                || inputs.available_parent_area.size != available_parent_area.size)
+        {
+            return None;
+        }
+        if inputs.depends_on_root && inputs.root_area != self.layout_metadata.root_area {
+            return None;
+        }
+
+        self.translate_cached(
+            node_id,
+            node,
+            initial_parent_area,
+            available_parent_area,
+            must_cache_children,
+            phase,
+        )
+    }
+
+    /// Move the cached layout of a Node and its descendants to the origin they get
+    /// with the given areas.
+    fn translate_cached(
+        &mut self,
+        node_id: Key,
+        node: &Node,
+        initial_parent_area: &AreaOf<Parent>,
+        available_parent_area: &AreaOf<Available>,
+        must_cache_children: bool,
+        phase: Phase,
+    ) -> Option<LayoutNode> {
+        let root_area = self.layout_metadata.root_area;
+        let mut layout_node = self.layout.get(&node_id)?.clone();
+        let inputs = self.layout.measure_inputs.get_mut(&node_id)?;
+
+        let (offset_x, offset_y) = match node.position {
+            Position::Global(_) => (0., 0.),
+            Position::Absolute(_) => (
+                initial_parent_area.origin.x - inputs.initial_parent_area.origin.x,
+                initial_parent_area.origin.y - inputs.initial_parent_area.origin.y,
+            ),
+            Position::Stacked(_) | Position::Sticky(_) => (
+                available_parent_area.origin.x - inputs.available_parent_area.origin.x,
+                available_parent_area.origin.y - inputs.available_parent_area.origin.y,
+            ),
+        };
+
+        layout_node.area.origin.x += offset_x;
+        layout_node.area.origin.y += offset_y;
+        layout_node.inner_area.origin.x += offset_x;
+        layout_node.inner_area.origin.y += offset_y;
+
+        if must_cache_children && phase == Phase::Final {
+            inputs.initial_parent_area = *initial_parent_area;
+            inputs.available_parent_area = *available_parent_area;
+            inputs.root_area = root_area;
+
+            if offset_x != 0. || offset_y != 0. {
+                self.layout.cache_node(node_id, layout_node.clone());
+                self.recursive_translate(node_id, Length::new(offset_x), Length::new(offset_y));
+            }
+
+            if node.has_layout_references
+                && let Some(measurer) = self.measurer
+            {
+                let mut inner_sizes = layout_node.inner_sizes;
+                inner_sizes.width += node.padding.horizontal();
+                inner_sizes.height += node.padding.vertical();
+                measurer.notify_layout_references(
+                    node_id,
+                    layout_node.area,
+                    layout_node.visible_area(),
+                    inner_sizes,
+                );
+            }
+        }
+
+        Some(layout_node)
+    }
+
+    /// Store the areas a Node was measured with and whether its subtree depends on them.
+    fn record_inputs(
+        &mut self,
+        node_id: Key,
+        original_node: &Node,
+        node: &Node,
+        is_measured: bool,
+        measure_inner_children: bool,
+        initial_parent_area: AreaOf<Parent>,
+        available_parent_area: AreaOf<Available>,
+    ) {
+        let inner_sized = node.width.inner_sized() || node.height.inner_sized();
+
+        // Measured content like text wraps within the available size
+        let mut depends_on_parent =
+            node.does_depend_on_parent_size() || (is_measured && inner_sized);
+        let mut depends_on_root = node.does_depend_on_root_size();
+
+        if measure_inner_children {
+            for child_id in self.tree_adapter.children_of(&node_id) {
+                if let Some(inputs) = self.layout.measure_inputs.get(&child_id) {
+                    // Children sized relative to this Node only matter if it grows with them
+                    depends_on_parent |= inner_sized && inputs.depends_on_parent;
+                    depends_on_root |= inputs.depends_on_root;
+                } else {
+                    depends_on_parent |= inner_sized;
+                    depends_on_root = true;
+                }
+            }
+        }
+
+        self.layout.measure_inputs.insert(
+            node_id,
+            MeasureInputs {
+                node: original_node.clone(),
+                initial_parent_area,
+                available_parent_area,
+                root_area: self.layout_metadata.root_area,
+                depends_on_parent,
+                depends_on_root,
+            },
+        );
+    }
+
+    /// Derive the size of an unsized axis from the other one using the Node's aspect ratio.
+    fn apply_aspect_ratio<'n>(
+        &self,
+        node: &'n Node,
+        initial_parent_area: &AreaOf<Parent>,
+        available_parent_area: &AreaOf<Available>,
+        phase: Phase,
+    ) -> Cow<'n, Node> {
+        let Some(aspect_ratio) = node.aspect_ratio.filter(|ratio| *ratio > 0.) else {
+            return Cow::Borrowed(node);
+        };
+
+        match (node.width.inner_sized(), node.height.inner_sized()) {
+            (false, true) => {
+                let width = node.width.min_max(
+                    node.padding.horizontal(),
+                    initial_parent_area.size.width,
+                    available_parent_area.size.width,
+                    node.margin.left(),
+                    node.margin.horizontal(),
+                    &node.minimum_width,
+                    &node.maximum_width,
+                    self.layout_metadata.root_area.width(),
+                    self.layout_metadata.root_area.size,
+                    phase,
+                ) - node.margin.horizontal();
+                let mut node = node.clone();
+                node.height = Size::Pixels(Length::new(width / aspect_ratio));
+                Cow::Owned(node)
+            }
+            (true, false) => {
+                let height = node.height.min_max(
+                    node.padding.vertical(),
+                    initial_parent_area.size.height,
+                    available_parent_area.size.height,
+                    node.margin.top(),
+                    node.margin.vertical(),
+                    &node.minimum_height,
+                    &node.maximum_height,
+                    self.layout_metadata.root_area.height(),
+                    self.layout_metadata.root_area.size,
+                    phase,
+                ) - node.margin.vertical();
+                let mut node = node.clone();
+                node.width = Size::Pixels(Length::new(height * aspect_ratio));
+                Cow::Owned(node)
+            }
+            _ => Cow::Borrowed(node),
+        }
+    }
+
     /// Measure the children layouts of a Node.
     #[allow(clippy::too_many_arguments)]
     pub fn measure_children(
@@ -467,12 +882,31 @@ where
     ) {
         let children = self.tree_adapter.children_of(parent_node_id);
 
+        if parent_node.content.is_grid() {
+            self.measure_grid_children(
+                children,
+                parent_node,
+                parent_area,
+                inner_area,
+                available_area,
+                inner_sizes,
+                must_cache_children,
+                parent_is_dirty,
+            );
+            return;
+        }
+
         let initial_area = *inner_area;
 
         let mut initial_phase_flex_grows = FxHashMap::default();
         let mut initial_phase_sizes = FxHashMap::default();
+        let mut initial_phase_baselines = FxHashMap::default();
         let mut initial_phase_inner_sizes = Size2D::default();
 
+        let is_baseline_aligned = parent_node.cross_alignment == Alignment::Baseline
+            && parent_node.direction == Direction::Horizontal
+            && !parent_node.content.is_wrap();
+
         // Used to calculate the spacing and some alignments
         let (non_absolute_children_len, first_child, last_child) = if parent_node.spacing.get() > 0.
         {
@@ -481,7 +915,7 @@ where
             let len = children
                 .iter()
                 .filter(|child_id| {
-                    let Some(child_data) = self.tree_adapter.get_node(child_id) else {
+                    let Some(child_data) = self.get_node(child_id) else {
                         return false;
                     };
                     let is_stacked = child_data.position.is_stacked();
@@ -508,7 +942,8 @@ where
             || parent_node.main_alignment.is_not_start()
             || parent_node.content.is_fit()
             || parent_node.content.is_flex()
-            || parent_node.content.is_wrap();
+            || parent_node.content.is_wrap()
+            || parent_node.mirrored;
 
         let mut initial_phase_parent_area = *parent_area;
         let mut initial_phase_inner_area = *inner_area;
@@ -519,7 +954,7 @@ where
         if needs_initial_phase {
             //  Measure the children
             for child_id in &children {
-                let Some(child_data) = self.tree_adapter.get_node(child_id) else {
+                let Some(child_data) = self.get_node(child_id) else {
                     continue;
                 };
 
@@ -559,10 +994,17 @@ where
                 if parent_node.cross_alignment.is_not_start()
                     || parent_node.main_alignment.is_spaced()
                     || parent_node.content.is_wrap()
+                    || parent_node.mirrored
                 {
                     initial_phase_sizes.insert(*child_id, child_areas.area.size);
                 }
 
+                // Children without a baseline are aligned on their bottom edge
+                if is_baseline_aligned {
+                    let baseline = child_areas.baseline.unwrap_or(child_areas.area.height());
+                    initial_phase_baselines.insert(*child_id, baseline);
+                }
+
                 if parent_node.content.is_flex() {
                     match parent_node.direction {
                         Direction::Vertical => {
@@ -580,6 +1022,33 @@ where
             }
         }
 
+        // Offset every child so their baselines match the lowest one
+        let max_baseline = initial_phase_baselines
+            .values()
+            .copied()
+            .fold(0.0, f32::max);
+        let baseline_offsets = initial_phase_baselines
+            .iter()
+            .map(|(child_id, baseline)| (*child_id, max_baseline - baseline))
+            .collect::<FxHashMap<_, _>>();
+        if is_baseline_aligned && parent_node.height.inner_sized() {
+            let line_height = baseline_offsets
+                .iter()
+                .filter_map(|(child_id, offset)| {
+                    initial_phase_sizes
+                        .get(child_id)
+                        .map(|size| size.height + offset)
+                })
+                .fold(0.0, f32::max);
+            let gaps = parent_node.padding.vertical() + parent_node.margin.vertical();
+            initial_phase_parent_area.size.height = initial_phase_parent_area
+                .size
+                .height
+                .max(line_height + gaps);
+            initial_phase_inner_area.size.height =
+                initial_phase_inner_area.size.height.max(line_height);
+        }
+
         let flex_grows = initial_phase_flex_grows
             .values()
             .copied()
@@ -631,7 +1100,7 @@ where
                     let Some(flex_grow) = initial_phase_flex_grows.get(child_id) else {
                         continue;
                     };
-                    let Some(child_data) = self.tree_adapter.get_node(child_id) else {
+                    let Some(child_data) = self.get_node(child_id) else {
                         continue;
                     };
                     if !child_data.position.is_stacked() {
@@ -738,11 +1207,25 @@ where
             }
         }
 
+        let wrap_lines_offsets = match parent_node.content {
+            Content::Wrap { wrap_spacing } if parent_node.wrap_alignment.is_not_start() => {
+                Self::wrap_lines_offsets(
+                    &children,
+                    &initial_phase_sizes,
+                    parent_node,
+                    available_area,
+                    wrap_spacing.unwrap_or_default(),
+                )
+            }
+            _ => Vec::new(),
+        };
+        let mut wrap_line = 0;
+
         let initial_available_area = *available_area;
 
         // Final phase: measure the children with all the axis and sizes adjusted
         for child_id in children {
-            let Some(child_data) = self.tree_adapter.get_node(&child_id) else {
+            let Some(child_data) = self.get_node(&child_id) else {
                 continue;
             };
 
@@ -805,9 +1288,12 @@ where
                 }
             }
 
+            let baseline_offset = baseline_offsets.get(&child_id).copied().unwrap_or_default();
+            adapted_available_area.origin.y += baseline_offset;
+
             if let Content::Wrap { wrap_spacing } = parent_node.content {
                 let initial_phase_size = initial_phase_sizes.get(&child_id);
-                Self::wrap_child(
+                let wrapped = Self::wrap_child(
                     wrap_spacing.unwrap_or_default(),
                     parent_node.direction,
                     initial_phase_size,
@@ -817,8 +1303,42 @@ where
                     &mut adapted_available_area,
                     *inner_sizes,
                 );
+                if wrapped {
+                    wrap_line += 1;
+                }
+
+                // Move the whole line along the cross axis
+                if initial_phase_size.is_some()
+                    && let Some(offset) = wrap_lines_offsets.get(wrap_line)
+                {
+                    match parent_node.direction {
+                        Direction::Horizontal => adapted_available_area.origin.y += offset,
+                        Direction::Vertical => adapted_available_area.origin.x += offset,
+                    }
+                }
             }
 
+            // Mirror the child inside the parent, leaving the paddings and margins in place
+            let mirror_offset = if parent_node.mirrored && child_data.position.is_stacked() {
+                let child_width = if parent_node.direction == Direction::Horizontal
+                    && parent_node.content.is_flex()
+                    && child_data.width.is_flex()
+                {
+                    Some(adapted_available_area.width())
+                } else {
+                    initial_phase_sizes.get(&child_id).map(|size| size.width)
+                };
+                child_width.map_or(0., |child_width| {
+                    let min_x = parent_area.min_x() + parent_node.margin.left();
+                    let max_x = parent_area.min_x() + initial_phase_parent_area.width()
+                        - parent_node.margin.right();
+                    min_x + max_x - child_width - 2. * adapted_available_area.min_x()
+                })
+            } else {
+                0.
+            };
+            adapted_available_area.origin.x += mirror_offset;
+
             // Final measurement
             let (child_revalidated, mut child_areas) = self.measure_node(
                 child_id,
@@ -835,6 +1355,11 @@ where
 
             // Stack this child into the parent
             if child_data.position.is_stacked() {
+                // Include the baseline offset so the parent covers the whole line
+                let mut stacked_area = child_areas.area;
+                stacked_area.origin.x -= mirror_offset;
+                stacked_area.origin.y -= baseline_offset;
+                stacked_area.size.height += baseline_offset;
                 Self::stack_child(
                     available_area,
                     parent_node,
@@ -842,7 +1367,7 @@ where
                     parent_area,
                     inner_area,
                     inner_sizes,
-                    &child_areas.area,
+                    &stacked_area,
                     is_last_child,
                     Phase::Final,
                 );
@@ -856,6 +1381,342 @@ where
         }
     }
 
+    /// Measure the children of a Node with a [`Grid`](Content::Grid) content.
+    #[allow(clippy::too_many_arguments)]
+    fn measure_grid_children(
+        &mut self,
+        children: Vec<Key>,
+        parent_node: &Node,
+        parent_area: &mut AreaOf<Parent>,
+        inner_area: &mut AreaOf<Inner>,
+        available_area: &AreaOf<Available>,
+        inner_sizes: &mut Size2D,
+        must_cache_children: bool,
+        parent_is_dirty: bool,
+    ) {
+        let Content::Grid {
+            columns,
+            rows,
+            column_spacing,
+            row_spacing,
+        } = &parent_node.content
+        else {
+            return;
+        };
+
+        let initial_area = *inner_area;
+        let columns_len = columns.len().max(1);
+
+        // Place every stacked child in a cell, the rest float on their own
+        let mut cells = Vec::new();
+        let mut occupied = FxHashSet::default();
+        let mut cursor = (0, 0);
+        for child_id in children {
+            let Some(child_data) = self.get_node(&child_id) else {
+                continue;
+            };
+
+            if !child_data.position.is_stacked() {
+                let (child_revalidated, mut child_areas) = self.measure_node(
+                    child_id,
+                    &child_data,
+                    initial_area.as_parent(),
+                    *available_area,
+                    must_cache_children,
+                    parent_is_dirty,
+                    Phase::Final,
+                );
+                child_areas.area.adjust_size(&child_data);
+                if child_revalidated && must_cache_children {
+                    self.layout.cache_node(child_id, child_areas);
+                }
+                continue;
+            }
+
+            let cell = Self::place_grid_child(
+                &child_data.grid_column,
+                &child_data.grid_row,
+                columns_len,
+                &mut occupied,
+                &mut cursor,
+            );
+            cells.push((child_id, child_data, cell));
+        }
+
+        let rows_len = cells
+            .iter()
+            .map(|(_, _, cell)| cell.row + cell.row_span)
+            .max()
+            .unwrap_or_default()
+            .max(rows.len());
+        let column_tracks = (0..columns_len)
+            .map(|i| columns.get(i).copied().unwrap_or_default())
+            .collect::<Vec<_>>();
+        let row_tracks = (0..rows_len)
+            .map(|i| rows.get(i).copied().unwrap_or_default())
+            .collect::<Vec<_>>();
+
+        // Measure the content of the columns that depend on their children
+        let columns_depend_on_children = column_tracks.iter().any(|track| {
+            matches!(track, GridSize::Auto)
+                || (matches!(track, GridSize::Fraction(_)) && parent_node.width.inner_sized())
+        });
+        let mut columns_content = vec![0.; columns_len];
+        if columns_depend_on_children {
+            for (child_id, child_data, cell) in &cells {
+                if cell.column_span > 1 || Self::is_grid_cell_relative(&child_data.width) {
+                    continue;
+                }
+                let (_, mut child_areas) = self.measure_node(
+                    *child_id,
+                    child_data,
+                    initial_area.as_parent(),
+                    initial_area.as_available(),
+                    false,
+                    parent_is_dirty,
+                    Phase::Initial,
+                );
+                child_areas.area.adjust_size(child_data);
+                columns_content[cell.column] =
+                    f32::max(columns_content[cell.column], child_areas.area.width());
+            }
+        }
+        let columns_sizes = Self::resolve_grid_tracks(
+            &column_tracks,
+            &columns_content,
+            initial_area.width(),
+            *column_spacing,
+            parent_node.width.inner_sized(),
+        );
+
+        // Measure the content of the rows now that the columns are known
+        let mut rows_content = vec![0.; rows_len];
+        for (child_id, child_data, cell) in &cells {
+            if cell.row_span > 1 || Self::is_grid_cell_relative(&child_data.height) {
+                continue;
+            }
+            let cell_area = Self::grid_cell_area(
+                &initial_area,
+                &columns_sizes,
+                *column_spacing,
+                &[initial_area.height()],
+                0.,
+                &GridCell {
+                    row: 0,
+                    row_span: 1,
+                    ..*cell
+                },
+            );
+            let (_, mut child_areas) = self.measure_node(
+                *child_id,
+                child_data,
+                cell_area.as_parent(),
+                cell_area.as_available(),
+                false,
+                parent_is_dirty,
+                Phase::Initial,
+            );
+            child_areas.area.adjust_size(child_data);
+            rows_content[cell.row] = f32::max(rows_content[cell.row], child_areas.area.height());
+        }
+        let rows_sizes = Self::resolve_grid_tracks(
+            &row_tracks,
+            &rows_content,
+            initial_area.height(),
+            *row_spacing,
+            parent_node.height.inner_sized(),
+        );
+
+        // Final measurement of the children inside their cells
+        for (child_id, child_data, cell) in cells {
+            let cell_area = Self::grid_cell_area(
+                &initial_area,
+                &columns_sizes,
+                *column_spacing,
+                &rows_sizes,
+                *row_spacing,
+                &cell,
+            );
+            let (child_revalidated, mut child_areas) = self.measure_node(
+                child_id,
+                &child_data,
+                cell_area.as_parent(),
+                cell_area.as_available(),
+                must_cache_children,
+                parent_is_dirty,
+                Phase::Final,
+            );
+            child_areas.area.adjust_size(&child_data);
+            if child_revalidated && must_cache_children {
+                self.layout.cache_node(child_id, child_areas);
+            }
+        }
+
+        let tracks_size = |sizes: &[f32], spacing: f32| {
+            sizes.iter().sum::<f32>() + spacing * sizes.len().saturating_sub(1) as f32
+        };
+        inner_sizes.width = tracks_size(&columns_sizes, *column_spacing);
+        inner_sizes.height = tracks_size(&rows_sizes, *row_spacing);
+
+        if parent_node.width.inner_sized() {
+            parent_area.size.width = inner_sizes.width
+                + parent_node.padding.horizontal()
+                + parent_node.margin.horizontal();
+            inner_area.size.width = inner_sizes.width;
+        }
+        if parent_node.height.inner_sized() {
+            parent_area.size.height =
+                inner_sizes.height + parent_node.padding.vertical() + parent_node.margin.vertical();
+            inner_area.size.height = inner_sizes.height;
+        }
+    }
+
+    /// Find the cell of a grid child.
+    /// Auto placed children take the first free cell after the cursor.
+    fn place_grid_child(
+        column_placement: &GridPlacement,
+        row_placement: &GridPlacement,
+        columns_len: usize,
+        occupied: &mut FxHashSet<(usize, usize)>,
+        cursor: &mut (usize, usize),
+    ) -> GridCell {
+        let column_span = column_placement.tracks().min(columns_len);
+        let row_span = row_placement.tracks();
+        let last_column = columns_len - column_span;
+        // Rows past the last occupied one are always free
+        let last_row = occupied
+            .iter()
+            .map(|(_, row)| row + 1)
+            .max()
+            .unwrap_or_default()
+            .max(cursor.1);
+        let fits = |occupied: &FxHashSet<(usize, usize)>, column: usize, row: usize| {
+            (column..column + column_span)
+                .all(|c| (row..row + row_span).all(|r| !occupied.contains(&(c, r))))
+        };
+
+        let (column, row) = match (column_placement.start, row_placement.start) {
+            (Some(column), Some(row)) => (column.min(last_column), row),
+            (Some(column), None) => {
+                let column = column.min(last_column);
+                let row = (cursor.1..=last_row)
+                    .find(|row| fits(occupied, column, *row))
+                    .unwrap_or(cursor.1);
+                (column, row)
+            }
+            (None, Some(row)) => {
+                let column = (0..=last_column)
+                    .find(|column| fits(occupied, *column, row))
+                    .unwrap_or_default();
+                (column, row)
+            }
+            (None, None) => (cursor.1..=last_row)
+                .find_map(|row| {
+                    let first_column = if row == cursor.1 { cursor.0 } else { 0 };
+                    (first_column..=last_column)
+                        .find(|column| fits(occupied, *column, row))
+                        .map(|column| (column, row))
+                })
+                .unwrap_or((0, cursor.1)),
+        };
+
+        if row_placement.start.is_none() {
+            *cursor = if column + column_span >= columns_len {
+                (0, row + 1)
+            } else {
+                (column + column_span, row)
+            };
+        }
+
+        for c in column..column + column_span {
+            for r in row..row + row_span {
+                occupied.insert((c, r));
+            }
+        }
+
+        GridCell {
+            column,
+            row,
+            column_span,
+            row_span,
+        }
+    }
+
+    /// Sizes relative to the grid cell do not contribute to the size of their tracks.
+    fn is_grid_cell_relative(size: &Size) -> bool {
+        matches!(size, Size::Fill | Size::Percentage(_) | Size::Flex(_))
+    }
+
+    /// Compute the size of every track of a grid axis.
+    fn resolve_grid_tracks(
+        tracks: &[GridSize],
+        content_sizes: &[f32],
+        available_size: f32,
+        spacing: f32,
+        inner_sized: bool,
+    ) -> Vec<f32> {
+        let mut sizes = tracks
+            .iter()
+            .zip(content_sizes)
+            .map(|(track, content_size)| match track {
+                GridSize::Pixels(px) => px.get(),
+                GridSize::Fraction(_) if !inner_sized => 0.,
+                GridSize::Auto | GridSize::Fraction(_) => *content_size,
+            })
+            .collect::<Vec<_>>();
+
+        let fractions = tracks
+            .iter()
+            .filter_map(|track| match track {
+                GridSize::Fraction(fr) => Some(*fr),
+                _ => None,
+            })
+            .sum::<f32>();
+
+        if !inner_sized && fractions > 0. {
+            let used = sizes.iter().sum::<f32>() + spacing * tracks.len().saturating_sub(1) as f32;
+            let remaining = (available_size - used).max(0.);
+            for (size, track) in sizes.iter_mut().zip(tracks) {
+                if let GridSize::Fraction(fr) = track {
+                    *size = remaining * fr / fractions;
+                }
+            }
+        }
+
+        sizes
+    }
+
+    /// Get the area covered by a grid cell.
+    fn grid_cell_area(
+        inner_area: &AreaOf<Inner>,
+        columns_sizes: &[f32],
+        column_spacing: f32,
+        rows_sizes: &[f32],
+        row_spacing: f32,
+        cell: &GridCell,
+    ) -> Area {
+        let offset = |sizes: &[f32], spacing: f32, start: usize| {
+            sizes[..start]
+                .iter()
+                .map(|size| size + spacing)
+                .sum::<f32>()
+        };
+        let span = |sizes: &[f32], spacing: f32, start: usize, span: usize| {
+            sizes[start..start + span].iter().sum::<f32>() + spacing * (span - 1) as f32
+        };
+
+        Area::new(
+            Point2D::new(
+                inner_area.min_x() + offset(columns_sizes, column_spacing, cell.column),
+                inner_area.min_y() + offset(rows_sizes, row_spacing, cell.row),
+            ),
+            Size2D::new(
+                span(columns_sizes, column_spacing, cell.column, cell.column_span),
+                span(rows_sizes, row_spacing, cell.row, cell.row_span),
+            ),
+        )
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn wrap_child(
         wrap_spacing: f32,
@@ -866,7 +1727,7 @@ where
         available_area: &mut AreaOf<Available>,
         adapted_available_area: &mut AreaOf<Available>,
         inner_sizes: Size2D,
-    ) {
+    ) -> bool {
         if let Some(initial_phase_size) = initial_phase_size {
             match direction {
                 Direction::Vertical => {
@@ -879,6 +1740,7 @@ where
                         adapted_available_area.size.height = initial_available_area.size.height;
                         adapted_available_area.origin.x += advance;
                         parent_area.size.width += advance;
+                        return true;
                     }
                 }
                 Direction::Horizontal => {
@@ -891,10 +1753,94 @@ where
                         adapted_available_area.size.width = initial_available_area.size.width;
                         adapted_available_area.origin.y += advance;
                         parent_area.size.height += advance;
+                        return true;
                     }
                 }
             }
         }
+        false
+    }
+
+    /// Compute the cross axis offset of every wrapped line given the wrap alignment.
+    fn wrap_lines_offsets(
+        children: &[Key],
+        initial_phase_sizes: &FxHashMap<Key, Size2D>,
+        parent_node: &Node,
+        available_area: &AreaOf<Available>,
+        wrap_spacing: f32,
+    ) -> Vec<f32> {
+        let (main_available, cross_available, cross_inner_sized) = match parent_node.direction {
+            Direction::Horizontal => (
+                available_area.width(),
+                available_area.height(),
+                parent_node.height.inner_sized(),
+            ),
+            Direction::Vertical => (
+                available_area.height(),
+                available_area.width(),
+                parent_node.width.inner_sized(),
+            ),
+        };
+
+        // There is no free space to distribute when the lines decide the size
+        if cross_inner_sized {
+            return Vec::new();
+        }
+
+        // Break the lines the same way the final phase will
+        let stacked_children = children
+            .iter()
+            .filter_map(|child_id| initial_phase_sizes.get(child_id))
+            .collect::<Vec<_>>();
+        let mut lines_sizes = vec![0f32];
+        let mut remaining = main_available;
+        for (i, size) in stacked_children.iter().enumerate() {
+            let (main, cross) = match parent_node.direction {
+                Direction::Horizontal => (size.width, size.height),
+                Direction::Vertical => (size.height, size.width),
+            };
+            if remaining - main < 0. {
+                lines_sizes.push(0.);
+                remaining = main_available;
+            }
+            if let Some(line_size) = lines_sizes.last_mut() {
+                *line_size = line_size.max(cross);
+            }
+            let spacing = if i + 1 == stacked_children.len() {
+                0.
+            } else {
+                parent_node.spacing.get()
+            };
+            remaining -= main + spacing;
+        }
+
+        // Every line advances by the biggest line so far
+        let mut lines_size = 0.;
+        let mut biggest_line = 0f32;
+        for (i, line_size) in lines_sizes.iter().enumerate() {
+            biggest_line = biggest_line.max(*line_size);
+            lines_size = if i + 1 == lines_sizes.len() {
+                lines_size + line_size
+            } else {
+                lines_size + biggest_line + wrap_spacing
+            };
+        }
+
+        let free_space = (cross_available - lines_size).max(0.);
+        let lines_len = lines_sizes.len() as f32;
+        (0..lines_sizes.len())
+            .map(|i| {
+                let i = i as f32;
+                match parent_node.wrap_alignment {
+                    Alignment::SpaceBetween if lines_len > 1. => free_space / (lines_len - 1.) * i,
+                    Alignment::Start | Alignment::SpaceBetween | Alignment::Baseline => 0.,
+                    Alignment::Center => free_space / 2.,
+                    Alignment::End => free_space,
+                    Alignment::SpaceEvenly => free_space / (lines_len + 1.) * (i + 1.),
+                    Alignment::SpaceAround => free_space / lines_len * (i + 0.5),
+                }
+            })
+            .collect()
     }
 
     /// Align the content of this node.
@@ -1161,3 +2107,12 @@ where
         *available_size = *inner_size;
     }
 }
+
+/// Cell covered by a child of a Node with a [`Grid`](Content::Grid) content.
+#[derive(Clone, Copy)]
+struct GridCell {
+    column: usize,
+    row: usize,
+    column_span: usize,
+    row_span: usize,
+}
@@ -4,9 +4,13 @@ use crate::{
     alignment::Alignment,
     direction::Direction,
     gaps::Gaps,
-    geometry::Length,
+    geometry::{
+        Length,
+        Size2D,
+    },
     prelude::{
         Content,
+        GridPlacement,
         Position,
         VisibleSize,
     },
@@ -60,6 +64,20 @@ pub struct Node {
     pub has_layout_references: bool,
 
     pub spacing: Length,
+
+    /// Ratio between the width and the height, used to derive the size of an unsized axis
+    pub aspect_ratio: Option<f32>,
+
+    /// Alignment of the wrapped lines along the cross axis when using a wrap content
+    pub wrap_alignment: Alignment,
+
+    /// Placement inside a parent with a grid content
+    pub grid_column: GridPlacement,
+    pub grid_row: GridPlacement,
+
+    /// Lay out the children from right to left, like in right to left languages.
+    /// Rows, horizontal alignments and paddings are mirrored.
+    pub mirrored: bool,
 }
 
 impl Scaled for Node {
@@ -76,6 +94,7 @@ impl Scaled for Node {
         self.offset_y *= scale_factor;
         self.position.scale(scale_factor);
         self.spacing *= scale_factor;
+        self.content.scale(scale_factor);
     }
 }
 
@@ -104,6 +123,11 @@ impl Node {
             && self.content == other.content
             && self.has_layout_references == other.has_layout_references
             && self.spacing == other.spacing
+            && self.wrap_alignment == other.wrap_alignment
+            && self.aspect_ratio == other.aspect_ratio
+            && self.grid_column == other.grid_column
+            && self.grid_row == other.grid_row
+            && self.mirrored == other.mirrored
     }
 
     pub fn inner_layout_eq(&self, other: &Self) -> bool {
@@ -313,5 +337,47 @@ impl Node {
         self.cross_alignment.is_not_start()
             || self.main_alignment.is_not_start()
             || self.has_layout_references
+            || self.mirrored
+            || self.content.is_grid()
+            || (self.content.is_wrap() && self.wrap_alignment.is_not_start())
+    }
+
+    /// Has properties that depend on the size of its parent?
+    pub fn does_depend_on_parent_size(&self) -> bool {
+        let positions_inner = self.main_alignment.is_not_start()
+            || self.cross_alignment.is_not_start()
+            || self.mirrored
+            || !matches!(self.content, Content::Normal | Content::Fit);
+
+        self.sizes().any(Size::depends_on_parent)
+            || self.position.depends_on_parent_size()
+            || ((self.width.inner_sized() || self.height.inner_sized()) && positions_inner)
+    }
+
+    /// Has properties that depend on the size of the root?
+    pub fn does_depend_on_root_size(&self) -> bool {
+        self.sizes().any(Size::depends_on_root)
+            || self.position.depends_on_root_size()
+            || self.padding.depends_on_root()
+            || self.margin.depends_on_root()
+    }
+
+    /// Resolve the viewport relative padding and margin into pixels.
+    pub(crate) fn resolve_gaps(mut self, viewport: Size2D) -> Self {
+        self.padding = self.padding.resolve(viewport);
+        self.margin = self.margin.resolve(viewport);
+        self
+    }
+
+    fn sizes(&self) -> impl Iterator<Item = &Size> {
+        [
+            &self.width,
+            &self.height,
+            &self.minimum_width,
+            &self.minimum_height,
+            &self.maximum_width,
+            &self.maximum_height,
+        ]
+        .into_iter()
     }
 }
@@ -0,0 +1,339 @@
+use std::{
+    any::Any,
+    rc::Rc,
+};
+
+use rayon::prelude::*;
+use rustc_hash::FxHashSet;
+
+use crate::{
+    custom_measurer::LayoutMeasurer,
+    geometry::{
+        Area,
+        Point2D,
+        Size2D,
+    },
+    measure::{
+        MeasureContext,
+        MeasureInputs,
+        Phase,
+    },
+    node::Node,
+    prelude::{
+        AreaConverter,
+        AreaModel,
+        AreaOf,
+        Gaps,
+        Inner,
+        LayoutMetadata,
+        Length,
+    },
+    torin::{
+        DirtyReason,
+        RootNodeCandidate,
+        Torin,
+    },
+    tree_adapter::{
+        LayoutNode,
+        NodeKey,
+        TreeAdapter,
+    },
+};
+
+/// Layout of a Node without its measurer data, so it can be sent across threads.
+#[derive(Clone, Copy)]
+struct DetachedLayoutNode {
+    area: Area,
+    inner_area: AreaOf<Inner>,
+    inner_sizes: Size2D,
+    margin: Gaps,
+    offset_x: Length,
+    offset_y: Length,
+    baseline: Option<f32>,
+    sticky_offset: Point2D,
+}
+
+impl From<&LayoutNode> for DetachedLayoutNode {
+    fn from(layout_node: &LayoutNode) -> Self {
+        Self {
+            area: layout_node.area,
+            inner_area: layout_node.inner_area,
+            inner_sizes: layout_node.inner_sizes,
+            margin: layout_node.margin,
+            offset_x: layout_node.offset_x,
+            offset_y: layout_node.offset_y,
+            baseline: layout_node.baseline,
+            sticky_offset: layout_node.sticky_offset,
+        }
+    }
+}
+
+impl From<DetachedLayoutNode> for LayoutNode {
+    fn from(layout_node: DetachedLayoutNode) -> Self {
+        Self {
+            area: layout_node.area,
+            inner_area: layout_node.inner_area,
+            inner_sizes: layout_node.inner_sizes,
+            margin: layout_node.margin,
+            offset_x: layout_node.offset_x,
+            offset_y: layout_node.offset_y,
+            baseline: layout_node.baseline,
+            sticky_offset: layout_node.sticky_offset,
+            data: None,
+        }
+    }
+}
+
+/// Cached state of a Node inside a detached subtree.
+struct DetachedNode<Key: NodeKey> {
+    node_id: Key,
+    layout_node: Option<DetachedLayoutNode>,
+    inputs: Option<MeasureInputs>,
+    reason: Option<DirtyReason>,
+}
+
+/// Subtree whose layout doesn't depend on its parent, measured on its own thread.
+struct DetachedSubtree<Key: NodeKey> {
+    root_id: Key,
+    root: Node,
+    nodes: Vec<DetachedNode<Key>>,
+}
+
+type MeasuredSubtree<Key> = (Key, Vec<(Key, DetachedLayoutNode, Option<MeasureInputs>)>);
+
+impl<Key: NodeKey> DetachedSubtree<Key> {
+    /// Measure the subtree from the origin of the root area, ready to be moved to its place.
+    fn measure(
+        self,
+        root_area: Area,
+        tree_adapter: &impl TreeAdapter<Key>,
+        dirty_ancestors: &FxHashSet<Key>,
+    ) -> MeasuredSubtree<Key> {
+        let mut layout = Torin::new();
+        for node in self.nodes {
+            if let Some(layout_node) = node.layout_node {
+                layout.results.insert(node.node_id, layout_node.into());
+            }
+            if let Some(inputs) = node.inputs {
+                layout.measure_inputs.insert(node.node_id, inputs);
+            }
+            if let Some(reason) = node.reason {
+                layout.dirty.insert(node.node_id, reason);
+            }
+        }
+
+        let area = Area::new(Point2D::default(), root_area.size);
+        let mut measurer = None::<DetachedMeasurer>;
+        let mut measure_context = MeasureContext {
+            layout: &mut layout,
+            measurer: &mut measurer,
+            tree_adapter,
+            layout_metadata: LayoutMetadata { root_area },
+            dirty_ancestors,
+            premeasured: FxHashSet::default(),
+        };
+
+        let (root_revalidated, mut root_layout_node) = measure_context.measure_node(
+            self.root_id,
+            &self.root,
+            area.as_parent(),
+            area.as_available(),
+            true,
+            false,
+            Phase::Final,
+        );
+
+        if root_revalidated {
+            root_layout_node.area.adjust_size(&self.root);
+            layout.cache_node(self.root_id, root_layout_node);
+        }
+
+        let nodes = layout
+            .results
+            .iter()
+            .map(|(node_id, layout_node)| {
+                (
+                    *node_id,
+                    DetachedLayoutNode::from(layout_node),
+                    layout.measure_inputs.get(node_id).cloned(),
+                )
+            })
+            .collect();
+
+        (self.root_id, nodes)
+    }
+}
+
+/// Detached subtrees never contain Nodes with custom measurements.
+struct DetachedMeasurer;
+
+impl<Key: NodeKey> LayoutMeasurer<Key> for DetachedMeasurer {
+    fn measure(
+        &mut self,
+        _node_id: Key,
+        _node: &Node,
+        _size: &Size2D,
+    ) -> Option<(Size2D, Rc<dyn Any>)> {
+        None
+    }
+
+    fn should_hook_measurement(&mut self, _node_id: Key) -> bool {
+        false
+    }
+
+    fn should_measure_inner_children(&mut self, _node_id: Key) -> bool {
+        true
+    }
+}
+
+impl<Key: NodeKey + Send + Sync> Torin<Key> {
+    /// Measure dirty Nodes like [`Torin::measure`], but the dirty subtrees that have a fixed size
+    /// and no custom measurements are measured in parallel first.
+    /// # Panics
+    /// Might panic if the final root node is not found.
+    pub fn measure_parallel(
+        &mut self,
+        suggested_root_id: Key,
+        root_area: Area,
+        measurer: &mut Option<impl LayoutMeasurer<Key>>,
+        tree_adapter: &mut (impl TreeAdapter<Key> + Sync),
+    ) {
+        if self.dirty.is_empty() && !self.results.is_empty() {
+            return;
+        }
+
+        // Try the Root candidate otherwise use the provided Root
+        let root_id = if let RootNodeCandidate::Valid(id) = self.root_node_candidate.take() {
+            id
+        } else {
+            suggested_root_id
+        };
+        let tree_adapter = &*tree_adapter;
+        let dirty_ancestors = self.dirty_ancestors(tree_adapter);
+
+        let subtrees =
+            self.independent_subtrees(root_id, &mut *measurer, tree_adapter, &dirty_ancestors);
+
+        let mut premeasured = FxHashSet::default();
+
+        // A single subtree is not worth a thread
+        if subtrees.len() > 1 {
+            let measured_subtrees = subtrees
+                .into_par_iter()
+                .map(|subtree| subtree.measure(root_area, tree_adapter, &dirty_ancestors))
+                .collect::<Vec<_>>();
+
+            for (subtree_root_id, nodes) in measured_subtrees {
+                premeasured.insert(subtree_root_id);
+                for (node_id, layout_node, inputs) in nodes {
+                    self.results.insert(node_id, layout_node.into());
+                    if let Some(inputs) = inputs {
+                        self.measure_inputs.insert(node_id, inputs);
+                    }
+                }
+            }
+        }
+
+        self.measure_from(
+            root_id,
+            root_area,
+            measurer,
+            tree_adapter,
+            &dirty_ancestors,
+            premeasured,
+        );
+    }
+
+    /// Find the topmost subtrees that need to be measured and can be measured on their own.
+    fn independent_subtrees(
+        &self,
+        root_id: Key,
+        measurer: &mut Option<impl LayoutMeasurer<Key>>,
+        tree_adapter: &impl TreeAdapter<Key>,
+        dirty_ancestors: &FxHashSet<Key>,
+    ) -> Vec<DetachedSubtree<Key>> {
+        let mut subtrees = Vec::new();
+        let mut buffer = vec![root_id];
+        while let Some(node_id) = buffer.pop() {
+            if let Some(measurer) = measurer.as_mut()
+                && !measurer.should_measure_inner_children(node_id)
+            {
+                continue;
+            }
+
+            for child_id in tree_adapter.children_of(&node_id) {
+                let reason = self.dirty.get(&child_id);
+
+                // Scrolled Nodes only translate their cached descendants
+                if reason == Some(&DirtyReason::InnerLayout) {
+                    continue;
+                }
+
+                let needs_measure = reason.is_some()
+                    || dirty_ancestors.contains(&child_id)
+                    || !self.results.contains_key(&child_id);
+                if !needs_measure {
+                    continue;
+                }
+
+                if let Some(subtree) = self.detach_subtree(child_id, measurer, tree_adapter) {
+                    subtrees.push(subtree);
+                } else {
+                    buffer.push(child_id);
+                }
+            }
+        }
+        subtrees
+    }
+
+    /// Copy the cached state of a subtree if its layout doesn't depend on its parent
+    /// and none of its Nodes need the measurer.
+    fn detach_subtree(
+        &self,
+        root_id: Key,
+        measurer: &mut Option<impl LayoutMeasurer<Key>>,
+        tree_adapter: &impl TreeAdapter<Key>,
+    ) -> Option<DetachedSubtree<Key>> {
+        let root = tree_adapter.get_node(&root_id)?;
+        if root.width.inner_sized()
+            || root.height.inner_sized()
+            || root.does_depend_on_parent_size()
+            || root.position.is_sticky()
+        {
+            return None;
+        }
+
+        let mut nodes = Vec::new();
+        let mut buffer = vec![root_id];
+        while let Some(node_id) = buffer.pop() {
+            let children = tree_adapter.children_of(&node_id);
+
+            if tree_adapter
+                .get_node(&node_id)
+                .is_some_and(|node| node.has_layout_references)
+            {
+                return None;
+            }
+            if let Some(measurer) = measurer.as_mut()
+                && (measurer.should_hook_measurement(node_id)
+                    || (!children.is_empty() && !measurer.should_measure_inner_children(node_id)))
+            {
+                return None;
+            }
+
+            nodes.push(DetachedNode {
+                node_id,
+                layout_node: self.results.get(&node_id).map(DetachedLayoutNode::from),
+                inputs: self.measure_inputs.get(&node_id).cloned(),
+                reason: self.dirty.get(&node_id).copied(),
+            });
+            buffer.extend(children);
+        }
+
+        Some(DetachedSubtree {
+            root_id,
+            root,
+            nodes,
+        })
+    }
+}
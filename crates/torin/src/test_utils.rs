@@ -33,7 +33,7 @@ impl TestingTree {
 }
 
 impl TreeAdapter<usize> for TestingTree {
-    fn children_of(&mut self, node_id: &usize) -> Vec<usize> {
+    fn children_of(&self, node_id: &usize) -> Vec<usize> {
         self.mapper
             .get(node_id)
             .map(|c| c.1.clone())
@@ -4,13 +4,17 @@ use std::{
 };
 
 use itertools::Itertools;
-use rustc_hash::FxHashMap;
+use rustc_hash::{
+    FxHashMap,
+    FxHashSet,
+};
 
 use crate::{
     custom_measurer::LayoutMeasurer,
     geometry::Area,
     measure::{
         MeasureContext,
+        MeasureInputs,
         Phase,
     },
     prelude::{
@@ -18,6 +22,7 @@ use crate::{
         AreaModel,
         Gaps,
         Length,
+        Point2D,
         Size2D,
     },
     tree_adapter::{
@@ -111,6 +116,9 @@ pub struct Torin<Key: NodeKey> {
 
     /// Best Root node candidate from where to start measuringg
     pub root_node_candidate: RootNodeCandidate<Key>,
+
+    /// Areas the cached Nodes were measured with, used to skip unaffected subtrees
+    pub measure_inputs: FxHashMap<Key, MeasureInputs>,
 }
 
 impl<Key: NodeKey> Default for Torin<Key> {
@@ -126,6 +134,7 @@ impl<Key: NodeKey> Torin<Key> {
             results: HashMap::default(),
             dirty: FxHashMap::default(),
             root_node_candidate: RootNodeCandidate::None,
+            measure_inputs: FxHashMap::default(),
         }
     }
 
@@ -143,6 +152,7 @@ impl<Key: NodeKey> Torin<Key> {
         self.root_node_candidate = RootNodeCandidate::None;
         self.results.clear();
         self.dirty.clear();
+        self.measure_inputs.clear();
     }
 
     /// Read the HashSet of dirty nodes
@@ -154,6 +164,7 @@ impl<Key: NodeKey> Torin<Key> {
     pub fn raw_remove(&mut self, node_id: Key) {
         self.results.remove(&node_id);
         self.dirty.remove(&node_id);
+        self.measure_inputs.remove(&node_id);
         if let RootNodeCandidate::Valid(id) = self.root_node_candidate
             && id == node_id
         {
@@ -310,6 +321,43 @@ impl<Key: NodeKey> Torin<Key> {
         } else {
             suggested_root_id
         };
+        let dirty_ancestors = self.dirty_ancestors(&*tree_adapter);
+
+        self.measure_from(
+            root_id,
+            root_area,
+            measurer,
+            &*tree_adapter,
+            &dirty_ancestors,
+            FxHashSet::default(),
+        );
+    }
+
+    /// Collect the ancestors of the dirty Nodes so clean subtrees can be skipped
+    pub(crate) fn dirty_ancestors(&self, tree_adapter: &impl TreeAdapter<Key>) -> FxHashSet<Key> {
+        let mut dirty_ancestors = FxHashSet::default();
+        for node_id in self.dirty.keys() {
+            let mut parent_id = tree_adapter.parent_of(node_id);
+            while let Some(id) = parent_id
+                && dirty_ancestors.insert(id)
+            {
+                parent_id = tree_adapter.parent_of(&id);
+            }
+        }
+        dirty_ancestors
+    }
+
+    /// Measure the dirty Nodes starting from the given root Node.
+    /// The `premeasured` Nodes are already measured and only need to be moved to their place.
+    pub(crate) fn measure_from(
+        &mut self,
+        root_id: Key,
+        root_area: Area,
+        measurer: &mut Option<impl LayoutMeasurer<Key>>,
+        tree_adapter: &impl TreeAdapter<Key>,
+        dirty_ancestors: &FxHashSet<Key>,
+        premeasured: FxHashSet<Key>,
+    ) {
         let root_parent_id = tree_adapter.parent_of(&root_id);
         let layout_node = root_parent_id
             .and_then(|root_parent_id| self.get(&root_parent_id).cloned())
@@ -320,9 +368,14 @@ impl<Key: NodeKey> Torin<Key> {
                 margin: Gaps::default(),
                 offset_x: Length::default(),
                 offset_y: Length::default(),
+                baseline: None,
+                sticky_offset: Point2D::default(),
                 data: None,
             });
-        let root = tree_adapter.get_node(&root_id).unwrap();
+        let root = tree_adapter
+            .get_node(&root_id)
+            .unwrap()
+            .resolve_gaps(root_area.size);
 
         #[cfg(debug_assertions)]
         {
@@ -345,6 +398,8 @@ impl<Key: NodeKey> Torin<Key> {
             layout_metadata,
             tree_adapter,
             measurer,
+            dirty_ancestors,
+            premeasured,
         };
 
         let (root_revalidated, mut root_layout_node) = measure_context.measure_node(
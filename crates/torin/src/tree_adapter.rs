@@ -14,6 +14,7 @@ use crate::{
         Gaps,
         Inner,
         Length,
+        Point2D,
         Size2D,
     },
 };
@@ -38,6 +39,12 @@ pub struct LayoutNode {
     pub offset_x: Length,
     pub offset_y: Length,
 
+    /// Distance from the top of the area to the first text baseline
+    pub baseline: Option<f32>,
+
+    /// Offset applied to this Node by a sticky position
+    pub sticky_offset: Point2D,
+
     /// Associated data
     #[cfg_attr(feature = "serde", serde(skip_deserializing, skip_serializing))]
     pub data: Option<Rc<dyn Any>>,
@@ -77,7 +84,7 @@ pub trait TreeAdapter<Key: NodeKey> {
     fn parent_of(&self, node_id: &Key) -> Option<Key>;
 
     /// Get the children of a Node
-    fn children_of(&mut self, node_id: &Key) -> Vec<Key>;
+    fn children_of(&self, node_id: &Key) -> Vec<Key>;
 
     /// Get the closest common parent Node of two Nodes
     fn closest_common_parent(
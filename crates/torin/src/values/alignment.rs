@@ -14,6 +14,9 @@ pub enum Alignment {
     SpaceEvenly,
     /// Distribute children with equal space around them, half-size space at the edges.
     SpaceAround,
+    /// Align children on their first text baseline, only as cross alignment of horizontal Nodes.
+    /// Children without text are aligned on their bottom edge.
+    Baseline,
 }
 
 impl Alignment {
@@ -47,6 +50,11 @@ impl Alignment {
         Alignment::SpaceAround
     }
 
+    /// Use a [`Baseline`](Alignment::Baseline) alignment.
+    pub fn baseline() -> Alignment {
+        Alignment::Baseline
+    }
+
     pub const fn is_not_start(&self) -> bool {
         !matches!(self, Self::Start)
     }
@@ -66,6 +74,7 @@ impl Alignment {
             Self::SpaceBetween => "space-between".to_string(),
             Self::SpaceEvenly => "space-evenly".to_string(),
             Self::SpaceAround => "space-around".to_string(),
+            Self::Baseline => "baseline".to_string(),
         }
     }
 }
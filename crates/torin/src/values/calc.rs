@@ -0,0 +1,203 @@
+use std::{
+    fmt,
+    iter::Peekable,
+    ops::{
+        Add,
+        Div,
+        Mul,
+        Sub,
+    },
+    str::{
+        Chars,
+        FromStr,
+    },
+};
+
+use crate::scaled::Scaled;
+
+/// Arithmetic expression of pixels and percentages of the parent, like CSS `calc()`.
+///
+/// ```
+/// # use torin::prelude::*;
+/// let calc = Calc::percent(100.) - 64.;
+/// assert_eq!(calc, "calc(100% - 64)".parse().unwrap());
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Clone, Debug)]
+pub enum Calc {
+    Pixels(f32),
+    Percentage(f32),
+    Add(Box<Calc>, Box<Calc>),
+    Sub(Box<Calc>, Box<Calc>),
+    Mul(Box<Calc>, Box<Calc>),
+    Div(Box<Calc>, Box<Calc>),
+}
+
+impl Calc {
+    /// Use a [`Pixels`](Calc::Pixels) value.
+    pub fn px(px: impl Into<f32>) -> Calc {
+        Calc::Pixels(px.into())
+    }
+
+    /// Use a [`Percentage`](Calc::Percentage) value.
+    pub fn percent(percent: impl Into<f32>) -> Calc {
+        Calc::Percentage(percent.into())
+    }
+
+    /// Evaluate the expression given the size of the parent.
+    pub fn eval(&self, parent: f32) -> f32 {
+        match self {
+            Self::Pixels(px) => *px,
+            Self::Percentage(per) => parent * per / 100.0,
+            Self::Add(a, b) => a.eval(parent) + b.eval(parent),
+            Self::Sub(a, b) => a.eval(parent) - b.eval(parent),
+            Self::Mul(a, b) => a.eval(parent) * b.eval(parent),
+            Self::Div(a, b) => a.eval(parent) / b.eval(parent),
+        }
+    }
+
+    /// Whether this expression does not depend on the parent.
+    fn is_constant(&self) -> bool {
+        match self {
+            Self::Pixels(_) => true,
+            Self::Percentage(_) => false,
+            Self::Add(a, b) | Self::Sub(a, b) | Self::Mul(a, b) | Self::Div(a, b) => {
+                a.is_constant() && b.is_constant()
+            }
+        }
+    }
+}
+
+impl From<f32> for Calc {
+    fn from(px: f32) -> Self {
+        Calc::Pixels(px)
+    }
+}
+
+macro_rules! impl_calc_op {
+    ($trait:ident, $method:ident, $variant:ident) => {
+        impl<T: Into<Calc>> $trait<T> for Calc {
+            type Output = Calc;
+
+            fn $method(self, rhs: T) -> Calc {
+                Calc::$variant(Box::new(self), Box::new(rhs.into()))
+            }
+        }
+    };
+}
+
+impl_calc_op!(Add, add, Add);
+impl_calc_op!(Sub, sub, Sub);
+impl_calc_op!(Mul, mul, Mul);
+impl_calc_op!(Div, div, Div);
+
+impl Scaled for Calc {
+    fn scale(&mut self, scale_factor: f32) {
+        match self {
+            Self::Pixels(px) => *px *= scale_factor,
+            Self::Percentage(_) => {}
+            Self::Add(a, b) | Self::Sub(a, b) => {
+                a.scale(scale_factor);
+                b.scale(scale_factor);
+            }
+            // Only one side of a product is a length, the other one is a factor
+            Self::Mul(a, b) if a.is_constant() && !b.is_constant() => b.scale(scale_factor),
+            Self::Mul(a, _) | Self::Div(a, _) => a.scale(scale_factor),
+        }
+    }
+}
+
+impl fmt::Display for Calc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pixels(px) => write!(f, "{px}"),
+            Self::Percentage(per) => write!(f, "{per}%"),
+            Self::Add(a, b) => write!(f, "({a} + {b})"),
+            Self::Sub(a, b) => write!(f, "({a} - {b})"),
+            Self::Mul(a, b) => write!(f, "{a} * {b}"),
+            Self::Div(a, b) => write!(f, "{a} / {b}"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ParseCalcError;
+
+impl fmt::Display for ParseCalcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid calc() expression")
+    }
+}
+
+impl std::error::Error for ParseCalcError {}
+
+impl FromStr for Calc {
+    type Err = ParseCalcError;
+
+    /// Parse an expression like `calc(50% + 2 * 16)`, the `calc()` wrapper is optional.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = s
+            .strip_prefix("calc(")
+            .and_then(|s| s.strip_suffix(')'))
+            .unwrap_or(s);
+        let mut chars = s.chars().peekable();
+        let calc = parse_expr(&mut chars)?;
+        skip_whitespace(&mut chars);
+        if chars.peek().is_some() {
+            return Err(ParseCalcError);
+        }
+        Ok(calc)
+    }
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars<'_>>) {
+    while chars.next_if(|c| c.is_whitespace()).is_some() {}
+}
+
+fn parse_expr(chars: &mut Peekable<Chars<'_>>) -> Result<Calc, ParseCalcError> {
+    let mut calc = parse_term(chars)?;
+    loop {
+        skip_whitespace(chars);
+        match chars.next_if(|c| matches!(c, '+' | '-')) {
+            Some('+') => calc = calc + parse_term(chars)?,
+            Some(_) => calc = calc - parse_term(chars)?,
+            None => return Ok(calc),
+        }
+    }
+}
+
+fn parse_term(chars: &mut Peekable<Chars<'_>>) -> Result<Calc, ParseCalcError> {
+    let mut calc = parse_factor(chars)?;
+    loop {
+        skip_whitespace(chars);
+        match chars.next_if(|c| matches!(c, '*' | '/')) {
+            Some('*') => calc = calc * parse_factor(chars)?,
+            Some(_) => calc = calc / parse_factor(chars)?,
+            None => return Ok(calc),
+        }
+    }
+}
+
+fn parse_factor(chars: &mut Peekable<Chars<'_>>) -> Result<Calc, ParseCalcError> {
+    skip_whitespace(chars);
+    if chars.next_if_eq(&'-').is_some() {
+        return Ok(Calc::Pixels(0.) - parse_factor(chars)?);
+    }
+    if chars.next_if_eq(&'(').is_some() {
+        let calc = parse_expr(chars)?;
+        skip_whitespace(chars);
+        return chars.next_if_eq(&')').map(|_| calc).ok_or(ParseCalcError);
+    }
+
+    let mut number = String::new();
+    while let Some(c) = chars.next_if(|c| c.is_ascii_digit() || *c == '.') {
+        number.push(c);
+    }
+    let number = number.parse::<f32>().map_err(|_| ParseCalcError)?;
+    if chars.next_if_eq(&'%').is_some() {
+        Ok(Calc::Percentage(number))
+    } else {
+        Ok(Calc::Pixels(number))
+    }
+}
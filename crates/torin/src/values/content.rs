@@ -1,3 +1,8 @@
+use crate::{
+    grid::GridSize,
+    scaled::Scaled,
+};
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(PartialEq, Clone, Debug, Default)]
 pub enum Content {
@@ -11,6 +16,14 @@ pub enum Content {
     /// Wrap children to the next line or column when they exceed the available space,
     /// with an optional gap between wrapped lines.
     Wrap { wrap_spacing: Option<f32> },
+    /// Place children in the cells of a grid made of the given column and row tracks.
+    /// Rows beyond the given ones are sized as [`GridSize::Auto`].
+    Grid {
+        columns: Vec<GridSize>,
+        rows: Vec<GridSize>,
+        column_spacing: f32,
+        row_spacing: f32,
+    },
 }
 
 impl Content {
@@ -41,6 +54,31 @@ impl Content {
         }
     }
 
+    /// Use a [`Grid`](Content::Grid) content with no spacing.
+    pub fn grid(columns: impl Into<Vec<GridSize>>, rows: impl Into<Vec<GridSize>>) -> Content {
+        Content::Grid {
+            columns: columns.into(),
+            rows: rows.into(),
+            column_spacing: 0.,
+            row_spacing: 0.,
+        }
+    }
+
+    /// Use a [`Grid`](Content::Grid) content with the given spacing between columns and rows.
+    pub fn grid_spacing(
+        columns: impl Into<Vec<GridSize>>,
+        rows: impl Into<Vec<GridSize>>,
+        column_spacing: f32,
+        row_spacing: f32,
+    ) -> Content {
+        Content::Grid {
+            columns: columns.into(),
+            rows: rows.into(),
+            column_spacing,
+            row_spacing,
+        }
+    }
+
     pub fn is_fit(&self) -> bool {
         self == &Self::Fit
     }
@@ -53,6 +91,10 @@ impl Content {
         matches!(self, Self::Wrap { .. })
     }
 
+    pub fn is_grid(&self) -> bool {
+        matches!(self, Self::Grid { .. })
+    }
+
     pub fn allows_alignments(&self) -> bool {
         matches!(self, Self::Normal | Self::Flex | Self::Fit)
     }
@@ -65,6 +107,33 @@ impl Content {
             Self::Fit => "fit".to_owned(),
             Self::Flex => "flex".to_owned(),
             Self::Wrap { .. } => "wrap".to_owned(),
+            Self::Grid { columns, rows, .. } => {
+                let tracks = |tracks: &[GridSize]| {
+                    tracks
+                        .iter()
+                        .map(GridSize::pretty)
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                };
+                format!("grid({} / {})", tracks(columns), tracks(rows))
+            }
+        }
+    }
+}
+
+impl Scaled for Content {
+    fn scale(&mut self, scale_factor: f32) {
+        if let Self::Grid {
+            columns,
+            rows,
+            column_spacing,
+            row_spacing,
+        } = self
+        {
+            columns.iter_mut().for_each(|c| c.scale(scale_factor));
+            rows.iter_mut().for_each(|r| r.scale(scale_factor));
+            *column_spacing *= scale_factor;
+            *row_spacing *= scale_factor;
         }
     }
 }
@@ -1,10 +1,29 @@
 pub use euclid::Rect;
 
 use crate::{
-    geometry::Length,
+    geometry::{
+        Length,
+        Size2D,
+    },
     scaled::Scaled,
 };
 
+/// Unit of the values of [Gaps].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Clone, Debug, Default, Copy)]
+pub enum GapsUnit {
+    #[default]
+    Pixels,
+    /// Percentage of the viewport (window) width, like CSS `vw`.
+    ViewportWidth,
+    /// Percentage of the viewport (window) height, like CSS `vh`.
+    ViewportHeight,
+    /// Percentage of the smallest side of the viewport, like CSS `vmin`.
+    ViewportMin,
+    /// Percentage of the largest side of the viewport, like CSS `vmax`.
+    ViewportMax,
+}
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(PartialEq, Clone, Debug, Default, Copy)]
 pub struct Gaps {
@@ -12,6 +31,7 @@ pub struct Gaps {
     right: Length,
     bottom: Length,
     left: Length,
+    unit: GapsUnit,
 }
 
 impl From<f32> for Gaps {
@@ -39,9 +59,47 @@ impl Gaps {
             right: Length::new(right),
             bottom: Length::new(bottom),
             left: Length::new(left),
+            unit: GapsUnit::Pixels,
         }
     }
 
+    /// Use the given unit for all the values, they are resolved against the viewport when measuring.
+    ///
+    /// ```
+    /// # use torin::prelude::*;
+    /// let padding = Gaps::new_all(5.).with_unit(GapsUnit::ViewportWidth);
+    /// ```
+    pub const fn with_unit(mut self, unit: GapsUnit) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    pub fn unit(&self) -> GapsUnit {
+        self.unit
+    }
+
+    /// Whether the values are relative to the viewport.
+    pub fn depends_on_root(&self) -> bool {
+        self.unit != GapsUnit::Pixels
+    }
+
+    /// Pixel gaps for the given viewport size.
+    pub fn resolve(&self, viewport: Size2D) -> Self {
+        let unit = match self.unit {
+            GapsUnit::Pixels => return *self,
+            GapsUnit::ViewportWidth => viewport.width,
+            GapsUnit::ViewportHeight => viewport.height,
+            GapsUnit::ViewportMin => viewport.width.min(viewport.height),
+            GapsUnit::ViewportMax => viewport.width.max(viewport.height),
+        } / 100.;
+        Self::new(
+            self.top() * unit,
+            self.right() * unit,
+            self.bottom() * unit,
+            self.left() * unit,
+        )
+    }
+
     pub const fn new_all(gaps: f32) -> Self {
         Self::new(gaps, gaps, gaps, gaps)
     }
@@ -90,8 +148,15 @@ impl Gaps {
     }
 
     pub fn pretty(&self) -> String {
+        let unit = match self.unit {
+            GapsUnit::Pixels => "",
+            GapsUnit::ViewportWidth => "vw",
+            GapsUnit::ViewportHeight => "vh",
+            GapsUnit::ViewportMin => "vmin",
+            GapsUnit::ViewportMax => "vmax",
+        };
         format!(
-            "({}, {}, {}, {})",
+            "({}{unit}, {}{unit}, {}{unit}, {}{unit})",
             self.top(),
             self.right(),
             self.bottom(),
@@ -102,6 +167,10 @@ impl Gaps {
 
 impl Scaled for Gaps {
     fn scale(&mut self, scale: f32) {
+        // The viewport is already scaled
+        if self.unit != GapsUnit::Pixels {
+            return;
+        }
         self.left *= scale;
         self.right *= scale;
         self.top *= scale;
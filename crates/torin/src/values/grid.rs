@@ -0,0 +1,91 @@
+use crate::{
+    geometry::Length,
+    scaled::Scaled,
+};
+
+/// Size of a column or row track in a [`Grid`](crate::content::Content::Grid) content.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub enum GridSize {
+    /// Fit the biggest child placed in this track.
+    #[default]
+    Auto,
+    /// Fixed size in pixels.
+    Pixels(Length),
+    /// Proportional share of the space left by the other tracks.
+    Fraction(f32),
+}
+
+impl GridSize {
+    /// Use an [`Auto`](GridSize::Auto) track.
+    pub fn auto() -> GridSize {
+        GridSize::Auto
+    }
+
+    /// Use a [`Pixels`](GridSize::Pixels) track.
+    pub fn px(px: impl Into<f32>) -> GridSize {
+        GridSize::Pixels(Length::new(px.into()))
+    }
+
+    /// Use a [`Fraction`](GridSize::Fraction) track.
+    pub fn fr(fraction: impl Into<f32>) -> GridSize {
+        GridSize::Fraction(fraction.into())
+    }
+
+    pub fn pretty(&self) -> String {
+        match self {
+            Self::Auto => "auto".to_owned(),
+            Self::Pixels(px) => format!("{}", px.get()),
+            Self::Fraction(fr) => format!("{fr}fr"),
+        }
+    }
+}
+
+impl Scaled for GridSize {
+    fn scale(&mut self, scale_factor: f32) {
+        if let Self::Pixels(px) = self {
+            *px *= scale_factor;
+        }
+    }
+}
+
+/// Placement of a child along one axis of its parent grid.
+/// Children without a `start` are placed in the first free cell.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub struct GridPlacement {
+    /// Index of the first track, starting from `0`.
+    pub start: Option<usize>,
+    /// Amount of tracks covered, `0` is treated as `1`.
+    pub span: usize,
+}
+
+impl GridPlacement {
+    pub fn new(start: usize, span: usize) -> Self {
+        Self {
+            start: Some(start),
+            span,
+        }
+    }
+
+    /// Place automatically but cover the given amount of tracks.
+    pub fn span(span: usize) -> Self {
+        Self { start: None, span }
+    }
+
+    pub fn tracks(&self) -> usize {
+        self.span.max(1)
+    }
+}
+
+impl From<usize> for GridPlacement {
+    fn from(start: usize) -> Self {
+        Self::new(start, 1)
+    }
+}
+
+impl From<(usize, usize)> for GridPlacement {
+    fn from((start, span): (usize, usize)) -> Self {
+        Self::new(start, span)
+    }
+}
@@ -1,7 +1,9 @@
 pub mod alignment;
+pub mod calc;
 pub mod content;
 pub mod direction;
 pub mod gaps;
+pub mod grid;
 pub mod position;
 pub mod size;
 pub mod visible_size;
@@ -9,9 +11,11 @@ pub mod visible_size;
 pub mod prelude {
     pub use crate::{
         alignment::*,
+        calc::*,
         content::*,
         direction::*,
         gaps::*,
+        grid::*,
         position::*,
         size::*,
         visible_size::*,
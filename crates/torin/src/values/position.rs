@@ -26,6 +26,9 @@ pub enum Position {
 
     Absolute(Box<PositionSides>),
     Global(Box<PositionSides>),
+    /// Stacked like [`Position::Stacked`] but pinned to the given sides of the
+    /// nearest scrolled ancestor while its parent is visible.
+    Sticky(Box<PositionSides>),
 }
 
 impl Default for Position {
@@ -53,6 +56,20 @@ impl Position {
         }))
     }
 
+    /// Same as [`Position::new_global`], relative to the root regardless of any scroll offsets.
+    pub fn new_fixed() -> Self {
+        Self::new_global()
+    }
+
+    pub fn new_sticky() -> Self {
+        Self::Sticky(Box::new(PositionSides {
+            top: None,
+            right: None,
+            bottom: None,
+            left: None,
+        }))
+    }
+
     pub fn new_stacked() -> Self {
         Self::Stacked(Box::new(PositionSides {
             top: None,
@@ -88,12 +105,20 @@ impl Position {
 
     fn position_mut(&mut self) -> &mut PositionSides {
         match self {
-            Self::Absolute(position) | Self::Global(position) | Self::Stacked(position) => position,
+            Self::Absolute(position)
+            | Self::Global(position)
+            | Self::Stacked(position)
+            | Self::Sticky(position) => position,
         }
     }
 
+    /// Whether it is laid out along its siblings, which is also the case of [`Position::Sticky`].
     pub fn is_stacked(&self) -> bool {
-        matches!(self, Self::Stacked { .. })
+        matches!(self, Self::Stacked { .. } | Self::Sticky { .. })
+    }
+
+    pub fn is_sticky(&self) -> bool {
+        matches!(self, Self::Sticky { .. })
     }
 
     pub fn is_absolute(&self) -> bool {
@@ -104,6 +129,16 @@ impl Position {
         matches!(self, Self::Global { .. })
     }
 
+    /// Whether the origin is computed from the end sides of the parent.
+    pub(crate) fn depends_on_parent_size(&self) -> bool {
+        matches!(self, Self::Absolute(sides) if sides.right.is_some() || sides.bottom.is_some())
+    }
+
+    /// Whether the origin is computed from the end sides of the root.
+    pub(crate) fn depends_on_root_size(&self) -> bool {
+        matches!(self, Self::Global(sides) if sides.right.is_some() || sides.bottom.is_some())
+    }
+
     pub(crate) fn get_origin(
         &self,
         available_parent_area: &AreaOf<Available>,
@@ -112,7 +147,7 @@ impl Position {
         root_area: &Area,
     ) -> Point2D {
         match self {
-            Self::Stacked(_) => available_parent_area.origin.cast_unit(),
+            Self::Stacked(_) | Self::Sticky(_) => available_parent_area.origin.cast_unit(),
             Self::Absolute(absolute_position) => {
                 let PositionSides {
                     top,
@@ -174,7 +209,7 @@ impl Position {
 impl Scaled for Position {
     fn scale(&mut self, scale_factor: f32) {
         match self {
-            Self::Absolute(position) | Self::Global(position) => {
+            Self::Absolute(position) | Self::Global(position) | Self::Sticky(position) => {
                 if let Some(top) = &mut position.top {
                     *top *= scale_factor;
                 }
@@ -197,6 +232,13 @@ impl Position {
     pub fn pretty(&self) -> String {
         match self {
             Self::Stacked(_) => "stacked".to_string(),
+            Self::Sticky(positions) => format!(
+                "sticky {}, {}, {}, {}",
+                positions.top.unwrap_or_default(),
+                positions.right.unwrap_or_default(),
+                positions.bottom.unwrap_or_default(),
+                positions.left.unwrap_or_default()
+            ),
             Self::Absolute(positions) | Self::Global(positions) => format!(
                 "{}, {}, {}, {}",
                 positions.top.unwrap_or_default(),
@@ -7,7 +7,11 @@ use std::{
 pub use euclid::Rect;
 
 use crate::{
-    geometry::Length,
+    calc::Calc,
+    geometry::{
+        Length,
+        Size2D,
+    },
     measure::Phase,
     scaled::Scaled,
 };
@@ -178,6 +182,56 @@ pub enum Size {
     /// let size = Size::flex(1.0);
     /// ```
     Flex(Length),
+
+    /// Arithmetic expression of pixels and percentages of the parent's size.
+    ///
+    /// Can also be created with [`Size::calc`].
+    ///
+    /// ```
+    /// # use torin::prelude::*;
+    /// let size = Size::calc(Calc::percent(100.) - 64.);
+    /// ```
+    Calc(Box<Calc>),
+
+    /// Sizes as a percentage of the viewport (window) width, like CSS `vw`.
+    ///
+    /// Can also be created with [`Size::vw`].
+    ///
+    /// ```
+    /// # use torin::prelude::*;
+    /// let size = Size::vw(50.0);
+    /// ```
+    ViewportWidth(Length),
+
+    /// Sizes as a percentage of the viewport (window) height, like CSS `vh`.
+    ///
+    /// Can also be created with [`Size::vh`].
+    ///
+    /// ```
+    /// # use torin::prelude::*;
+    /// let size = Size::vh(50.0);
+    /// ```
+    ViewportHeight(Length),
+
+    /// Sizes as a percentage of the smallest viewport (window) axis, like CSS `vmin`.
+    ///
+    /// Can also be created with [`Size::vmin`].
+    ///
+    /// ```
+    /// # use torin::prelude::*;
+    /// let size = Size::vmin(50.0);
+    /// ```
+    ViewportMin(Length),
+
+    /// Sizes as a percentage of the biggest viewport (window) axis, like CSS `vmax`.
+    ///
+    /// Can also be created with [`Size::vmax`].
+    ///
+    /// ```
+    /// # use torin::prelude::*;
+    /// let size = Size::vmax(50.0);
+    /// ```
+    ViewportMax(Length),
 }
 
 impl Size {
@@ -216,6 +270,31 @@ impl Size {
         Size::Flex(Length::new(flex.into()))
     }
 
+    /// Use a [`ViewportWidth`](Size::ViewportWidth) size.
+    pub fn vw(percent: impl Into<f32>) -> Size {
+        Size::ViewportWidth(Length::new(percent.into()))
+    }
+
+    /// Use a [`ViewportHeight`](Size::ViewportHeight) size.
+    pub fn vh(percent: impl Into<f32>) -> Size {
+        Size::ViewportHeight(Length::new(percent.into()))
+    }
+
+    /// Use a [`ViewportMin`](Size::ViewportMin) size.
+    pub fn vmin(percent: impl Into<f32>) -> Size {
+        Size::ViewportMin(Length::new(percent.into()))
+    }
+
+    /// Use a [`ViewportMax`](Size::ViewportMax) size.
+    pub fn vmax(percent: impl Into<f32>) -> Size {
+        Size::ViewportMax(Length::new(percent.into()))
+    }
+
+    /// Use a [`Calc`](Size::Calc) size.
+    pub fn calc(calc: impl Into<Calc>) -> Size {
+        Size::Calc(Box::new(calc.into()))
+    }
+
     /// Use a dynamic [`Fn`](Size::Fn) size computed by the given closure.
     pub fn func(func: impl Fn(SizeFnContext) -> Option<f32> + 'static + Sync + Send) -> Size {
         Self::Fn(Box::new(SizeFn::new(func)))
@@ -244,6 +323,32 @@ impl Size {
         matches!(self, Self::Inner | Self::FillMinimum)
     }
 
+    /// Whether this size is computed from the size of the parent.
+    pub(crate) fn depends_on_parent(&self) -> bool {
+        matches!(
+            self,
+            Self::Fill
+                | Self::FillMinimum
+                | Self::Percentage(_)
+                | Self::Fn(_)
+                | Self::Flex(_)
+                | Self::Calc(_)
+        )
+    }
+
+    /// Whether this size is computed from the size of the root.
+    pub(crate) fn depends_on_root(&self) -> bool {
+        matches!(
+            self,
+            Self::RootPercentage(_)
+                | Self::Fn(_)
+                | Self::ViewportWidth(_)
+                | Self::ViewportHeight(_)
+                | Self::ViewportMin(_)
+                | Self::ViewportMax(_)
+        )
+    }
+
     pub fn pretty(&self) -> String {
         match self {
             Self::Inner => "auto".to_string(),
@@ -254,6 +359,11 @@ impl Size {
             Self::FillMinimum => "fill-min".to_string(),
             Self::RootPercentage(p) => format!("{}% of root", p.get()),
             Self::Flex(f) => format!("flex({})", f.get()),
+            Self::Calc(calc) => format!("calc({calc})"),
+            Self::ViewportWidth(p) => format!("{}vw", p.get()),
+            Self::ViewportHeight(p) => format!("{}vh", p.get()),
+            Self::ViewportMin(p) => format!("{}vmin", p.get()),
+            Self::ViewportMax(p) => format!("{}vmax", p.get()),
         }
     }
 
@@ -263,13 +373,19 @@ impl Size {
         available_parent: f32,
         parent_margin: f32,
         root: f32,
+        viewport: Size2D,
         phase: Phase,
     ) -> Option<f32> {
         match self {
             Self::Pixels(px) => Some(px.get() + parent_margin),
-            Self::Percentage(per) => Some(parent / 100.0 * per.get()),
+            Self::Percentage(per) => Some(parent * per.get() / 100.0),
             Self::Fill => Some(available_parent),
-            Self::RootPercentage(per) => Some(root / 100.0 * per.get()),
+            Self::RootPercentage(per) => Some(root * per.get() / 100.0),
+            Self::Calc(calc) => Some(calc.eval(parent) + parent_margin),
+            Self::ViewportWidth(per) => Some(viewport.width * per.get() / 100.0),
+            Self::ViewportHeight(per) => Some(viewport.height * per.get() / 100.0),
+            Self::ViewportMin(per) => Some(viewport.width.min(viewport.height) * per.get() / 100.0),
+            Self::ViewportMax(per) => Some(viewport.width.max(viewport.height) * per.get() / 100.0),
             Self::Flex(_) | Self::FillMinimum if phase == Phase::Final => Some(available_parent),
             Self::Fn(f) => f.call(SizeFnContext {
                 parent,
@@ -293,6 +409,7 @@ impl Size {
         minimum: &Self,
         maximum: &Self,
         root_value: f32,
+        viewport: Size2D,
         phase: Phase,
     ) -> f32 {
         let value = self
@@ -301,6 +418,7 @@ impl Size {
                 available_parent_value,
                 margin,
                 root_value,
+                viewport,
                 phase,
             )
             .unwrap_or(value + margin);
@@ -311,6 +429,7 @@ impl Size {
                 available_parent_value,
                 margin,
                 root_value,
+                viewport,
                 phase,
             )
             .map(|v| v + single_margin);
@@ -319,6 +438,7 @@ impl Size {
             available_parent_value,
             margin,
             root_value,
+            viewport,
             phase,
         );
 
@@ -349,8 +469,10 @@ impl Size {
 
 impl Scaled for Size {
     fn scale(&mut self, scale_factor: f32) {
-        if let Self::Pixels(s) = self {
-            *s *= scale_factor;
+        match self {
+            Self::Pixels(s) => *s *= scale_factor,
+            Self::Calc(calc) => calc.scale(scale_factor),
+            _ => {}
         }
     }
 }
@@ -1,3 +1,8 @@
+use std::{
+    any::Any,
+    rc::Rc,
+};
+
 use euclid::Length;
 use torin::{
     prelude::*,
@@ -663,3 +668,119 @@ pub fn alignment_with_absolute_child() {
         Rect::new(Point2D::new(450.0, 507.5), Size2D::new(100.0, 100.0)),
     );
 }
+
+/// Measures text-like Nodes as `(width, height, baseline)`.
+struct BaselineMeasurer {
+    text_nodes: Vec<(usize, f32, f32, f32)>,
+}
+
+impl BaselineMeasurer {
+    fn text_node(&self, node_id: usize) -> Option<&(usize, f32, f32, f32)> {
+        self.text_nodes.iter().find(|(id, ..)| *id == node_id)
+    }
+}
+
+impl LayoutMeasurer<usize> for BaselineMeasurer {
+    fn measure(
+        &mut self,
+        node_id: usize,
+        _node: &Node,
+        _size: &Size2D,
+    ) -> Option<(Size2D, Rc<dyn Any>)> {
+        let (_, width, height, _) = self.text_node(node_id)?;
+        Some((Size2D::new(*width, *height), Rc::new(())))
+    }
+
+    fn should_hook_measurement(&mut self, node_id: usize) -> bool {
+        self.text_node(node_id).is_some()
+    }
+
+    fn should_measure_inner_children(&mut self, node_id: usize) -> bool {
+        self.text_node(node_id).is_none()
+    }
+
+    fn baseline(&mut self, node_id: usize, _data: &Rc<dyn Any>) -> Option<f32> {
+        self.text_node(node_id).map(|(.., baseline)| *baseline)
+    }
+}
+
+#[test]
+pub fn cross_align_baseline() {
+    let mut layout = Torin::<usize>::new();
+
+    let mut measurer = Some(BaselineMeasurer {
+        text_nodes: vec![(2, 50.0, 40.0, 30.0), (3, 50.0, 30.0, 10.0)],
+    });
+
+    let mut mocked_tree = TestingTree::default();
+    mocked_tree.add(
+        0,
+        None,
+        vec![1],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(1000.0)),
+            Size::Pixels(Length::new(1000.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_tree.add(
+        1,
+        Some(0),
+        vec![2, 3, 4],
+        Node::from_size_and_alignments_and_direction(
+            Size::Inner,
+            Size::Inner,
+            Alignment::Start,
+            Alignment::Baseline,
+            Direction::Horizontal,
+        ),
+    );
+    mocked_tree.add(
+        2,
+        Some(1),
+        vec![],
+        Node::from_size_and_direction(Size::Inner, Size::Inner, Direction::Vertical),
+    );
+    mocked_tree.add(
+        3,
+        Some(1),
+        vec![],
+        Node::from_size_and_direction(Size::Inner, Size::Inner, Direction::Vertical),
+    );
+    mocked_tree.add(
+        4,
+        Some(1),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(10.0)),
+            Size::Pixels(Length::new(10.0)),
+            Direction::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    assert_eq!(
+        layout.get(&2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(50.0, 40.0)),
+    );
+    assert_eq!(
+        layout.get(&3).unwrap().area,
+        Rect::new(Point2D::new(50.0, 20.0), Size2D::new(50.0, 30.0)),
+    );
+    // Nodes without a baseline sit on it with their bottom edge
+    assert_eq!(
+        layout.get(&4).unwrap().area,
+        Rect::new(Point2D::new(100.0, 20.0), Size2D::new(10.0, 10.0)),
+    );
+    // The parent grows to cover the shifted children
+    assert_eq!(
+        layout.get(&1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(110.0, 50.0)),
+    );
+}
@@ -0,0 +1,69 @@
+use euclid::Length;
+use torin::{
+    prelude::*,
+    test_utils::*,
+};
+
+#[test]
+pub fn aspect_ratio() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_tree = TestingTree::default();
+    mocked_tree.add(
+        0,
+        None,
+        vec![1, 2, 3],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(400.0)),
+            Size::Pixels(Length::new(600.0)),
+            Direction::Vertical,
+        ),
+    );
+
+    // Height derived from the width
+    let mut video = Node::from_size_and_direction(
+        Size::Percentage(Length::new(100.0)),
+        Size::Inner,
+        Direction::Vertical,
+    );
+    video.aspect_ratio = Some(16. / 9.);
+    mocked_tree.add(1, Some(0), vec![], video);
+
+    // Width derived from the height
+    let mut thumbnail = Node::from_size_and_direction(
+        Size::Inner,
+        Size::Pixels(Length::new(90.0)),
+        Direction::Vertical,
+    );
+    thumbnail.aspect_ratio = Some(2.);
+    mocked_tree.add(2, Some(0), vec![], thumbnail);
+
+    // Both axis are sized, the ratio is ignored
+    let mut fixed = Node::from_size_and_direction(
+        Size::Pixels(Length::new(50.0)),
+        Size::Pixels(Length::new(50.0)),
+        Direction::Vertical,
+    );
+    fixed.aspect_ratio = Some(2.);
+    mocked_tree.add(3, Some(0), vec![], fixed);
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    assert_eq!(
+        layout.get(&1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(400.0, 225.0)),
+    );
+    assert_eq!(
+        layout.get(&2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 225.0), Size2D::new(180.0, 90.0)),
+    );
+    assert_eq!(
+        layout.get(&3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 315.0), Size2D::new(50.0, 50.0)),
+    );
+}
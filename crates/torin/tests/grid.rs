@@ -0,0 +1,199 @@
+use euclid::Length;
+use torin::{
+    prelude::*,
+    test_utils::*,
+};
+
+#[test]
+pub fn content_grid_tracks() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_tree = TestingTree::default();
+    let mut root = Node::from_size_and_direction(
+        Size::Pixels(Length::new(400.0)),
+        Size::Pixels(Length::new(300.0)),
+        Direction::Vertical,
+    );
+    root.content = Content::grid_spacing(
+        [GridSize::px(100.), GridSize::fr(1.), GridSize::fr(3.)],
+        [GridSize::auto(), GridSize::fr(1.)],
+        10.,
+        20.,
+    );
+
+    mocked_tree.add(0, None, vec![1, 2, 3, 4], root);
+
+    mocked_tree.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(50.0)),
+            Size::Pixels(Length::new(40.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_tree.add(
+        2,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Pixels(Length::new(60.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_tree.add(
+        3,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Percentage(Length::new(100.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_tree.add(
+        4,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Percentage(Length::new(100.0)),
+            Direction::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    // Columns: 100, (400 - 100 - 20) / 4 = 70, 210
+    // Rows: 60 (auto), 300 - 60 - 20 = 220
+    assert_eq!(
+        layout.get(&1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(50.0, 40.0)),
+    );
+    assert_eq!(
+        layout.get(&2).unwrap().area,
+        Rect::new(Point2D::new(110.0, 0.0), Size2D::new(70.0, 60.0)),
+    );
+    assert_eq!(
+        layout.get(&3).unwrap().area,
+        Rect::new(Point2D::new(190.0, 0.0), Size2D::new(210.0, 60.0)),
+    );
+    assert_eq!(
+        layout.get(&4).unwrap().area,
+        Rect::new(Point2D::new(0.0, 80.0), Size2D::new(100.0, 220.0)),
+    );
+}
+
+#[test]
+pub fn content_grid_placement() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_tree = TestingTree::default();
+    let mut root = Node::from_size_and_direction(
+        Size::Pixels(Length::new(300.0)),
+        Size::Pixels(Length::new(300.0)),
+        Direction::Vertical,
+    );
+    root.content = Content::grid(
+        [GridSize::fr(1.), GridSize::fr(1.), GridSize::fr(1.)],
+        [GridSize::px(100.), GridSize::px(100.), GridSize::px(100.)],
+    );
+
+    mocked_tree.add(0, None, vec![1, 2, 3], root);
+
+    let cell = || {
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Percentage(Length::new(100.0)),
+            Direction::Vertical,
+        )
+    };
+
+    // Header covering the whole first row
+    let mut header = cell();
+    header.grid_column = GridPlacement::span(3);
+    mocked_tree.add(1, Some(0), vec![], header);
+
+    // Sidebar placed explicitly in the last column, covering two rows
+    let mut sidebar = cell();
+    sidebar.grid_column = GridPlacement::new(2, 1);
+    sidebar.grid_row = GridPlacement::new(1, 2);
+    mocked_tree.add(2, Some(0), vec![], sidebar);
+
+    // Auto placed content covering the two free columns
+    let mut content = cell();
+    content.grid_column = GridPlacement::span(2);
+    mocked_tree.add(3, Some(0), vec![], content);
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    assert_eq!(
+        layout.get(&1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(300.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(&2).unwrap().area,
+        Rect::new(Point2D::new(200.0, 100.0), Size2D::new(100.0, 200.0)),
+    );
+    assert_eq!(
+        layout.get(&3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 100.0), Size2D::new(200.0, 100.0)),
+    );
+}
+
+#[test]
+pub fn content_grid_auto_size() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_tree = TestingTree::default();
+    let mut root = Node::from_size_and_direction(Size::Inner, Size::Inner, Direction::Vertical);
+    root.content = Content::grid_spacing([GridSize::auto(), GridSize::fr(1.)], [], 5., 5.);
+
+    mocked_tree.add(0, None, vec![1, 2, 3], root);
+
+    for (id, width, height) in [(1, 30.0, 20.0), (2, 50.0, 10.0), (3, 40.0, 30.0)] {
+        mocked_tree.add(
+            id,
+            Some(0),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(width)),
+                Size::Pixels(Length::new(height)),
+                Direction::Vertical,
+            ),
+        );
+    }
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    // Columns: 40 and 50, rows: 20 and 30
+    assert_eq!(
+        layout.get(&0).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(95.0, 55.0)),
+    );
+    assert_eq!(
+        layout.get(&2).unwrap().area,
+        Rect::new(Point2D::new(45.0, 0.0), Size2D::new(50.0, 10.0)),
+    );
+    assert_eq!(
+        layout.get(&3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 25.0), Size2D::new(40.0, 30.0)),
+    );
+}
@@ -0,0 +1,198 @@
+use euclid::Length;
+use torin::{
+    prelude::*,
+    test_utils::*,
+};
+
+#[test]
+pub fn mirrored_row_with_padding() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_tree = TestingTree::default();
+    mocked_tree.add(
+        0,
+        None,
+        vec![1, 2],
+        Node {
+            mirrored: true,
+            ..Node::from_size_and_alignments_and_direction_and_padding(
+                Size::Pixels(Length::new(200.0)),
+                Size::Pixels(Length::new(100.0)),
+                Alignment::Start,
+                Alignment::Start,
+                Direction::Horizontal,
+                Gaps::new(0.0, 0.0, 0.0, 20.0),
+            )
+        },
+    );
+    mocked_tree.add(
+        1,
+        Some(0),
+        vec![3],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(50.0)),
+            Size::Pixels(Length::new(50.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_tree.add(
+        2,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(30.0)),
+            Size::Pixels(Length::new(30.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_tree.add(
+        3,
+        Some(1),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(10.0)),
+            Size::Pixels(Length::new(10.0)),
+            Direction::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    // The first child starts from the right, after the padding that is now on the right
+    assert_eq!(
+        layout.get(&1).unwrap().area,
+        Rect::new(Point2D::new(130.0, 0.0), Size2D::new(50.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(&2).unwrap().area,
+        Rect::new(Point2D::new(100.0, 0.0), Size2D::new(30.0, 30.0)),
+    );
+    // Descendants of a child that is not mirrored itself move with it
+    assert_eq!(
+        layout.get(&3).unwrap().area,
+        Rect::new(Point2D::new(130.0, 0.0), Size2D::new(10.0, 10.0)),
+    );
+}
+
+#[test]
+pub fn mirrored_cross_alignment() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_tree = TestingTree::default();
+    mocked_tree.add(
+        0,
+        None,
+        vec![1],
+        Node {
+            mirrored: true,
+            ..Node::from_size_and_alignments_and_direction(
+                Size::Pixels(Length::new(200.0)),
+                Size::Pixels(Length::new(200.0)),
+                Alignment::Start,
+                Alignment::End,
+                Direction::Vertical,
+            )
+        },
+    );
+    mocked_tree.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(50.0)),
+            Size::Pixels(Length::new(50.0)),
+            Direction::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    // The end of a mirrored element is its left side
+    assert_eq!(
+        layout.get(&1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(50.0, 50.0)),
+    );
+}
+
+#[test]
+pub fn mirrored_inner_sized_row() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_tree = TestingTree::default();
+    mocked_tree.add(
+        0,
+        None,
+        vec![1],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(1000.0)),
+            Size::Pixels(Length::new(1000.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_tree.add(
+        1,
+        Some(0),
+        vec![2, 3],
+        Node {
+            mirrored: true,
+            ..Node::from_size_and_alignments_and_direction_and_padding(
+                Size::Inner,
+                Size::Inner,
+                Alignment::Start,
+                Alignment::Start,
+                Direction::Horizontal,
+                Gaps::new(0.0, 0.0, 0.0, 20.0),
+            )
+        },
+    );
+    mocked_tree.add(
+        2,
+        Some(1),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(50.0)),
+            Size::Pixels(Length::new(50.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_tree.add(
+        3,
+        Some(1),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(30.0)),
+            Size::Pixels(Length::new(30.0)),
+            Direction::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    assert_eq!(
+        layout.get(&1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(100.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(&2).unwrap().area,
+        Rect::new(Point2D::new(30.0, 0.0), Size2D::new(50.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(&3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(30.0, 30.0)),
+    );
+}
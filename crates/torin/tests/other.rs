@@ -1,3 +1,8 @@
+use std::{
+    any::Any,
+    rc::Rc,
+};
+
 use rustc_hash::FxHashMap;
 use torin::{
     prelude::*,
@@ -530,3 +535,125 @@ pub fn node_reordering() {
         Rect::new(Point2D::new(0.0, 100.0), Size2D::new(200.0, 100.0)),
     );
 }
+
+/// Measures text-like Nodes with a fixed size and counts how many times each was measured.
+#[derive(Default)]
+struct CountingMeasurer {
+    text_nodes: Vec<usize>,
+    measurements: FxHashMap<usize, usize>,
+}
+
+impl LayoutMeasurer<usize> for CountingMeasurer {
+    fn measure(
+        &mut self,
+        node_id: usize,
+        _node: &Node,
+        _size: &Size2D,
+    ) -> Option<(Size2D, Rc<dyn Any>)> {
+        *self.measurements.entry(node_id).or_default() += 1;
+        Some((Size2D::new(50.0, 20.0), Rc::new(())))
+    }
+
+    fn should_hook_measurement(&mut self, node_id: usize) -> bool {
+        self.text_nodes.contains(&node_id)
+    }
+
+    fn should_measure_inner_children(&mut self, node_id: usize) -> bool {
+        !self.text_nodes.contains(&node_id)
+    }
+}
+
+#[test]
+pub fn resize_skips_unaffected_subtrees() {
+    let mut layout = Torin::<usize>::new();
+    let mut measurer = Some(CountingMeasurer {
+        text_nodes: vec![2, 4],
+        ..Default::default()
+    });
+
+    let mut mocked_tree = TestingTree::default();
+    mocked_tree.add(
+        0,
+        None,
+        vec![1, 3],
+        Node::from_size_and_alignments_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Percentage(Length::new(100.0)),
+            Alignment::Center,
+            Alignment::Center,
+            Direction::Vertical,
+        ),
+    );
+    mocked_tree.add(
+        1,
+        Some(0),
+        vec![2],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(100.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_tree.add(
+        2,
+        Some(1),
+        vec![],
+        Node::from_size_and_direction(Size::Inner, Size::Inner, Direction::Vertical),
+    );
+    mocked_tree.add(
+        3,
+        Some(0),
+        vec![4],
+        Node::from_size_and_direction(Size::Fill, Size::Inner, Direction::Vertical),
+    );
+    mocked_tree.add(
+        4,
+        Some(3),
+        vec![],
+        Node::from_size_and_direction(Size::Inner, Size::Inner, Direction::Vertical),
+    );
+
+    layout.find_best_root(&mut mocked_tree);
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    let measurements = measurer.as_ref().unwrap().measurements.clone();
+
+    // Resize the root, like a window resize does
+    layout.invalidate(0);
+    layout.find_best_root(&mut mocked_tree);
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(800.0, 600.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    let new_measurements = &measurer.as_ref().unwrap().measurements;
+
+    // The fixed subtree is only translated
+    assert_eq!(new_measurements.get(&2), measurements.get(&2));
+    assert_eq!(
+        layout.get(&1).unwrap().area,
+        Rect::new(Point2D::new(350.0, 240.0), Size2D::new(100.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(&2).unwrap().area,
+        Rect::new(Point2D::new(350.0, 240.0), Size2D::new(50.0, 20.0)),
+    );
+
+    // The filling subtree is measured again
+    assert!(new_measurements.get(&4) > measurements.get(&4));
+    assert_eq!(
+        layout.get(&3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 340.0), Size2D::new(800.0, 20.0)),
+    );
+    assert_eq!(
+        layout.get(&4).unwrap().area,
+        Rect::new(Point2D::new(0.0, 340.0), Size2D::new(50.0, 20.0)),
+    );
+}
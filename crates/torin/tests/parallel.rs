@@ -0,0 +1,103 @@
+#![cfg(feature = "parallel")]
+
+use torin::{
+    prelude::*,
+    test_utils::*,
+};
+
+fn fixed_subtrees_tree() -> TestingTree {
+    let mut mocked_tree = TestingTree::default();
+    mocked_tree.add(
+        0,
+        None,
+        vec![1, 2, 3],
+        Node::from_size_and_direction(
+            Size::Percentage(Length::new(100.0)),
+            Size::Percentage(Length::new(100.0)),
+            Direction::Vertical,
+        ),
+    );
+    for (subtree_id, child_id) in [(1, 4), (2, 5), (3, 6)] {
+        mocked_tree.add(
+            subtree_id,
+            Some(0),
+            vec![child_id],
+            Node::from_size_and_padding(
+                Size::Pixels(Length::new(200.0)),
+                Size::Pixels(Length::new(100.0)),
+                Gaps::new(10.0, 10.0, 10.0, 10.0),
+            ),
+        );
+        mocked_tree.add(
+            child_id,
+            Some(subtree_id),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Percentage(Length::new(50.0)),
+                Size::Fill,
+                Direction::Vertical,
+            ),
+        );
+    }
+    mocked_tree
+}
+
+#[test]
+pub fn parallel_matches_sequential() {
+    let root_area = Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0));
+
+    let (mut layout, mut measurer) = test_utils();
+    let mut mocked_tree = fixed_subtrees_tree();
+    layout.measure(0, root_area, &mut measurer, &mut mocked_tree);
+
+    let (mut parallel_layout, mut measurer) = test_utils();
+    let mut mocked_tree = fixed_subtrees_tree();
+    parallel_layout.measure_parallel(0, root_area, &mut measurer, &mut mocked_tree);
+
+    for node_id in 0..=6 {
+        assert_eq!(
+            parallel_layout.get(&node_id).unwrap().area,
+            layout.get(&node_id).unwrap().area,
+        );
+    }
+
+    assert_eq!(
+        parallel_layout.get(&5).unwrap().area,
+        Rect::new(Point2D::new(10.0, 110.0), Size2D::new(90.0, 80.0)),
+    );
+}
+
+#[test]
+pub fn parallel_relayout_of_invalidated_subtrees() {
+    let root_area = Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0));
+
+    let (mut layout, mut measurer) = test_utils();
+    let mut mocked_tree = fixed_subtrees_tree();
+    layout.measure_parallel(0, root_area, &mut measurer, &mut mocked_tree);
+
+    for subtree_id in [1, 3] {
+        mocked_tree.set_node(
+            subtree_id,
+            Node::from_size_and_padding(
+                Size::Pixels(Length::new(300.0)),
+                Size::Pixels(Length::new(50.0)),
+                Gaps::new(5.0, 5.0, 5.0, 5.0),
+            ),
+        );
+        layout.invalidate(subtree_id);
+    }
+    layout.measure_parallel(0, root_area, &mut measurer, &mut mocked_tree);
+
+    assert_eq!(
+        layout.get(&2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 50.0), Size2D::new(200.0, 100.0)),
+    );
+    assert_eq!(
+        layout.get(&3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 150.0), Size2D::new(300.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(&6).unwrap().area,
+        Rect::new(Point2D::new(5.0, 155.0), Size2D::new(145.0, 40.0)),
+    );
+}
@@ -464,3 +464,116 @@ pub fn global_with_inner_sized_and_bottom() {
         Rect::new(Point2D::new(20.0, 460.0), Size2D::new(80.0, 30.0)),
     );
 }
+
+#[test]
+pub fn sticky() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_tree = TestingTree::default();
+    mocked_tree.add(
+        0,
+        None,
+        vec![1],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(100.0)),
+            Direction::Vertical,
+        ),
+    );
+    let content = |offset_y: f32| {
+        Node::from_size_and_offset(
+            Size::Pixels(Length::new(100.0)),
+            Size::Inner,
+            Length::new(0.),
+            Length::new(offset_y),
+        )
+    };
+    mocked_tree.add(1, Some(0), vec![2, 3, 4], content(-50.));
+    mocked_tree.add(
+        2,
+        Some(1),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(20.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_tree.add(
+        3,
+        Some(1),
+        vec![],
+        Node::from_size_and_position(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(20.0)),
+            Position::new_sticky().top(0.),
+        ),
+    );
+    mocked_tree.add(
+        4,
+        Some(1),
+        vec![],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(300.0)),
+            Direction::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    // Scrolled past, pinned to the top
+    assert_eq!(
+        layout.get(&2).unwrap().area,
+        Rect::new(Point2D::new(0.0, -50.0), Size2D::new(100.0, 20.0)),
+    );
+    assert_eq!(
+        layout.get(&3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(100.0, 20.0)),
+    );
+    assert_eq!(
+        layout.get(&4).unwrap().area,
+        Rect::new(Point2D::new(0.0, -10.0), Size2D::new(100.0, 300.0)),
+    );
+
+    // Back in view, stacked as usual
+    mocked_tree.set_node(1, content(-10.));
+    layout.invalidate_with_reason(1, DirtyReason::InnerLayout);
+    layout.find_best_root(&mut mocked_tree);
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    assert_eq!(
+        layout.get(&3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 10.0), Size2D::new(100.0, 20.0)),
+    );
+
+    // Scrolled past again
+    mocked_tree.set_node(1, content(-200.));
+    layout.invalidate_with_reason(1, DirtyReason::InnerLayout);
+    layout.find_best_root(&mut mocked_tree);
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    assert_eq!(
+        layout.get(&3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(100.0, 20.0)),
+    );
+    assert_eq!(
+        layout.get(&4).unwrap().area,
+        Rect::new(Point2D::new(0.0, -160.0), Size2D::new(100.0, 300.0)),
+    );
+}
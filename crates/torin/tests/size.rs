@@ -943,3 +943,141 @@ pub fn size_fn() {
         Rect::new(Point2D::new(0.0, 0.0), Size2D::new(250.0, 1000.0)),
     );
 }
+
+#[test]
+pub fn size_calc() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_tree = TestingTree::default();
+    mocked_tree.add(
+        0,
+        None,
+        vec![1],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(400.0)),
+            Size::Pixels(Length::new(300.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_tree.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(
+            Size::calc("calc(100% - 64)".parse::<Calc>().unwrap()),
+            Size::calc("calc(50% + 2 * 16)".parse::<Calc>().unwrap()),
+            Direction::Vertical,
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    assert_eq!(
+        layout.get(&1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(336.0, 182.0)),
+    );
+}
+
+#[test]
+pub fn size_calc_parse() {
+    assert_eq!(
+        "calc(100% - 64)".parse::<Calc>(),
+        Ok(Calc::percent(100.) - 64.)
+    );
+    assert_eq!(
+        "(50% + 16) / -2".parse::<Calc>(),
+        Ok((Calc::percent(50.) + 16.) / (Calc::px(0.) - 2.))
+    );
+    assert_eq!("calc(100% - )".parse::<Calc>(), Err(ParseCalcError));
+    assert_eq!("calc(50% 10)".parse::<Calc>(), Err(ParseCalcError));
+}
+
+#[test]
+pub fn size_viewport() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_tree = TestingTree::default();
+    mocked_tree.add(
+        0,
+        None,
+        vec![1, 2],
+        Node::from_size_and_direction(
+            Size::Pixels(Length::new(400.0)),
+            Size::Pixels(Length::new(400.0)),
+            Direction::Vertical,
+        ),
+    );
+    mocked_tree.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(Size::vh(50.), Size::vmin(10.), Direction::Vertical),
+    );
+    mocked_tree.add(
+        2,
+        Some(0),
+        vec![],
+        Node::from_size_and_direction(Size::vmax(10.), Size::vw(5.), Direction::Vertical),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 500.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    assert_eq!(
+        layout.get(&1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(250.0, 50.0)),
+    );
+    assert_eq!(
+        layout.get(&2).unwrap().area,
+        Rect::new(Point2D::new(0.0, 50.0), Size2D::new(100.0, 50.0)),
+    );
+}
+
+#[test]
+pub fn gaps_viewport() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_tree = TestingTree::default();
+    mocked_tree.add(
+        0,
+        None,
+        vec![1],
+        Node::from_size_and_padding(
+            Size::Pixels(Length::new(400.0)),
+            Size::Pixels(Length::new(400.0)),
+            Gaps::new_all(2.).with_unit(GapsUnit::ViewportWidth),
+        ),
+    );
+    mocked_tree.add(
+        1,
+        Some(0),
+        vec![],
+        Node::from_size_and_margin(
+            Size::Pixels(Length::new(100.0)),
+            Size::Pixels(Length::new(100.0)),
+            Gaps::new(10., 0., 0., 20.).with_unit(GapsUnit::ViewportHeight),
+        ),
+    );
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 500.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    // 2vw of padding and 10vh and 20vh of margin
+    assert_eq!(
+        layout.get(&1).unwrap().visible_area(),
+        Rect::new(Point2D::new(120.0, 70.0), Size2D::new(100.0, 100.0)),
+    );
+}
@@ -198,3 +198,81 @@ pub fn content_wrap_horizontal_with_spacing() {
         Rect::new(Point2D::new(0.0, 30.0), Size2D::new(150.0, 20.0)),
     );
 }
+
+#[test]
+pub fn content_wrap_alignment() {
+    let (mut layout, mut measurer) = test_utils();
+
+    let mut mocked_tree = TestingTree::default();
+    let mut root = Node::from_size_and_direction(
+        Size::Pixels(Length::new(300.0)),
+        Size::Pixels(Length::new(200.0)),
+        Direction::Horizontal,
+    );
+    root.content = Content::wrap();
+    root.wrap_alignment = Alignment::Center;
+
+    mocked_tree.add(0, None, vec![1, 2, 3], root);
+
+    for id in [1, 2, 3] {
+        mocked_tree.add(
+            id,
+            Some(0),
+            vec![],
+            Node::from_size_and_direction(
+                Size::Pixels(Length::new(150.0)),
+                Size::Pixels(Length::new(20.0)),
+                Direction::Vertical,
+            ),
+        );
+    }
+
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    // Two lines of 20 leave 160 of free space
+    assert_eq!(
+        layout.get(&1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 80.0), Size2D::new(150.0, 20.0)),
+    );
+    assert_eq!(
+        layout.get(&2).unwrap().area,
+        Rect::new(Point2D::new(150.0, 80.0), Size2D::new(150.0, 20.0)),
+    );
+    assert_eq!(
+        layout.get(&3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 100.0), Size2D::new(150.0, 20.0)),
+    );
+
+    mocked_tree.set_node(0, {
+        let mut root = Node::from_size_and_direction(
+            Size::Pixels(Length::new(300.0)),
+            Size::Pixels(Length::new(200.0)),
+            Direction::Horizontal,
+        );
+        root.content = Content::wrap();
+        root.wrap_alignment = Alignment::SpaceBetween;
+        root
+    });
+    layout.invalidate(0);
+    layout.find_best_root(&mut mocked_tree);
+    layout.measure(
+        0,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(1000.0, 1000.0)),
+        &mut measurer,
+        &mut mocked_tree,
+    );
+
+    assert_eq!(
+        layout.get(&1).unwrap().area,
+        Rect::new(Point2D::new(0.0, 0.0), Size2D::new(150.0, 20.0)),
+    );
+    assert_eq!(
+        layout.get(&3).unwrap().area,
+        Rect::new(Point2D::new(0.0, 180.0), Size2D::new(150.0, 20.0)),
+    );
+}
@@ -0,0 +1,65 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+use itertools::Itertools;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app)))
+}
+
+fn app() -> impl IntoElement {
+    let mut sort = use_state(|| Some((0, OrderDirection::Down)));
+    let mut selected = use_state(Vec::<usize>::new);
+    let data = use_hook(|| {
+        (0..10_000)
+            .map(|i| {
+                vec![
+                    format!("Item {i}"),
+                    format!("{}", i % 7),
+                    format!("{}", i * 31 % 997),
+                ]
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let sorted_data = {
+        let sorted = data.iter().cloned().sorted_by(|a, b| match sort() {
+            Some((0, _)) | None => Ord::cmp(&a[0], &b[0]),
+            Some((column, _)) => Ord::cmp(
+                &a[column].parse::<u32>().unwrap_or_default(),
+                &b[column].parse::<u32>().unwrap_or_default(),
+            ),
+        });
+        if matches!(sort(), Some((_, OrderDirection::Up))) {
+            sorted.rev().collect_vec()
+        } else {
+            sorted.collect_vec()
+        }
+    };
+    let len = sorted_data.len();
+
+    rect()
+        .padding(8.)
+        .spacing(8.)
+        .child(format!("Selected rows: {}", selected.read().len()))
+        .child(
+            DataTable::new(sorted_data, |row, column, data| {
+                label().text(data[row][column].clone()).into()
+            })
+            .columns(vec![
+                DataColumn::new("Name").width(200.),
+                DataColumn::new("Group"),
+                DataColumn::new("Score"),
+            ])
+            .length(len)
+            .sort(sort())
+            .on_sort(move |new_sort| sort.set(Some(new_sort)))
+            .selection_mode(RowSelectionMode::Multiple)
+            .on_selection_change(move |selection: RowSelection| {
+                selected.set(selection.rows().to_vec())
+            }),
+        )
+}
@@ -0,0 +1,43 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app)))
+}
+
+fn app() -> impl IntoElement {
+    let mut lottie = use_lottie();
+    let mut fast = use_state(|| false);
+
+    rect()
+        .expanded()
+        .center()
+        .spacing(8.)
+        .child(
+            LottieViewer::new(("pulse", include_bytes!("./pulse.json")))
+                .controller(lottie)
+                .speed(if fast() { 2. } else { 1. })
+                .width(Size::px(200.))
+                .height(Size::px(200.)),
+        )
+        .child(format!("Frame {}", lottie.frame()))
+        .child(
+            rect()
+                .horizontal()
+                .spacing(8.)
+                .child(
+                    Button::new()
+                        .on_press(move |_| lottie.toggle())
+                        .child(if lottie.is_playing() { "Pause" } else { "Play" }),
+                )
+                .child(
+                    Button::new()
+                        .on_press(move |_| fast.toggle())
+                        .child(if fast() { "Normal speed" } else { "Fast" }),
+                ),
+        )
+}
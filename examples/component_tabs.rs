@@ -0,0 +1,40 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app)))
+}
+
+fn app() -> impl IntoElement {
+    let mut selected = use_state(|| 0);
+    let mut tabs = use_state(|| (1..=12).map(|i| format!("Tab {i}")).collect::<Vec<_>>());
+
+    Tabs::new()
+        .selected(selected())
+        .on_select(move |tab| selected.set(tab))
+        .on_close(move |tab| {
+            tabs.write().remove(tab);
+            let len = tabs.read().len();
+            selected.set(selected().min(len.saturating_sub(1)));
+        })
+        .on_reorder(move |(from, to)| {
+            let tab = tabs.write().remove(from);
+            tabs.write().insert(to, tab);
+            selected.set(to);
+        })
+        .tabs(tabs.read().iter().map(|title| Tab::new(title.clone())))
+        .children(tabs.read().iter().map(|title| {
+            TabPanel::new()
+                .child(
+                    rect()
+                        .center()
+                        .expanded()
+                        .child(format!("Content of {title}")),
+                )
+                .into()
+        }))
+}
@@ -0,0 +1,52 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app)))
+}
+
+fn app() -> impl IntoElement {
+    ToastProvider::new().child(Actions {})
+}
+
+#[derive(PartialEq)]
+struct Actions {}
+
+impl Component for Actions {
+    fn render(&self) -> impl IntoElement {
+        let toasts = use_toast();
+        let mut count = use_state(|| 0);
+
+        rect()
+            .expanded()
+            .center()
+            .spacing(8.)
+            .child(
+                Button::new()
+                    .on_press(move |_| {
+                        *count.write() += 1;
+                        toasts.show(
+                            Toast::new(format!("Saved #{}", count()))
+                                .description("All changes were saved"),
+                        );
+                    })
+                    .child("Save"),
+            )
+            .child(
+                Button::new()
+                    .on_press(move |_| {
+                        toasts.show(Toast::new("Connection lost").persistent());
+                    })
+                    .child("Disconnect"),
+            )
+            .child(
+                Button::new()
+                    .on_press(move |_| toasts.dismiss_all())
+                    .child("Dismiss all"),
+            )
+    }
+}
@@ -0,0 +1,53 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use std::time::Duration;
+
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app)))
+}
+
+fn app() -> impl IntoElement {
+    let mut selected = use_state(|| None::<String>);
+    let items = use_hook(|| {
+        (0..100)
+            .map(|folder| {
+                TreeItem::new(format!("folder-{folder}"), format!("Folder {folder}")).children(
+                    (0..100)
+                        .map(|file| {
+                            TreeItem::new(
+                                format!("folder-{folder}/file-{file}"),
+                                format!("File {file}"),
+                            )
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .chain([TreeItem::new("remote".to_string(), "Remote (lazy)").lazy()])
+            .collect::<Vec<_>>()
+    });
+
+    rect()
+        .expanded()
+        .padding(8.)
+        .spacing(8.)
+        .child(format!(
+            "Selected: {}",
+            selected.read().as_deref().unwrap_or("None")
+        ))
+        .child(
+            TreeView::new(items)
+                .load_children(|id: String| async move {
+                    // Pretend the children are fetched from somewhere else
+                    async_io::Timer::after(Duration::from_millis(500)).await;
+                    (0..10)
+                        .map(|i| TreeItem::new(format!("{id}/{i}"), format!("Remote file {i}")))
+                        .collect()
+                })
+                .on_select(move |id| selected.set(Some(id))),
+        )
+}
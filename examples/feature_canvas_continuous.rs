@@ -0,0 +1,42 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+use std::time::Instant;
+
+use freya::prelude::*;
+use skia_safe::{
+    Paint,
+    PaintStyle,
+};
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app)))
+}
+
+fn app() -> impl IntoElement {
+    let started = use_hook(Instant::now);
+
+    canvas(RenderCallback::new(move |context| {
+        let area = context.layout_node.visible_area();
+        let scale_factor = context.scale_factor as f32;
+        let center_x = area.width() / scale_factor / 2.;
+        let center_y = area.height() / scale_factor / 2.;
+        let angle = started.elapsed().as_secs_f32() * 2.;
+
+        let mut paint = Paint::default();
+        paint.set_anti_alias(true);
+        paint.set_style(PaintStyle::Stroke);
+        paint.set_stroke_width(6.);
+        paint.set_color(Color::BLUE);
+
+        context.canvas.draw_line(
+            (center_x, center_y),
+            (center_x + angle.cos() * 100., center_y + angle.sin() * 100.),
+            &paint,
+        );
+    }))
+    .continuous(true)
+    .width(Size::percent(100.))
+    .height(Size::percent(100.))
+}
@@ -0,0 +1,45 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app).with_size(500., 450.)))
+}
+
+fn app() -> impl IntoElement {
+    let card = use_hook(Focus::new_id);
+    let mut status = use_state(|| "Not captured yet".to_string());
+
+    rect()
+        .expanded()
+        .center()
+        .spacing(12.)
+        .child(
+            rect()
+                .a11y_id(card)
+                .width(Size::px(250.))
+                .padding(16.)
+                .corner_radius(12.)
+                .background((100, 140, 230))
+                .color(Color::WHITE)
+                .child("This card will be saved as capture.png"),
+        )
+        .child(
+            Button::new()
+                .on_press(move |_| {
+                    spawn(async move {
+                        match Platform::get().capture_node(card, 2.).await {
+                            Some(image) => match std::fs::write("capture.png", image) {
+                                Ok(()) => status.set("Saved to capture.png".to_string()),
+                                Err(err) => status.set(format!("Failed to save: {err}")),
+                            },
+                            None => status.set("Failed to capture".to_string()),
+                        }
+                    });
+                })
+                .child("Capture"),
+        )
+        .child(status.read().clone())
+}
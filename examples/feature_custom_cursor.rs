@@ -0,0 +1,54 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app).with_size(500., 450.)))
+}
+
+/// A red circle of 24x24 pixels, pointing from its center.
+fn dot_cursor() -> Option<CustomCursor> {
+    const SIZE: u16 = 24;
+    let radius = SIZE as f32 / 2.;
+    let mut rgba = Vec::with_capacity(SIZE as usize * SIZE as usize * 4);
+    for y in 0..SIZE {
+        for x in 0..SIZE {
+            let distance = (x as f32 + 0.5 - radius).hypot(y as f32 + 0.5 - radius);
+            let alpha = if distance <= radius { 255 } else { 0 };
+            rgba.extend_from_slice(&[230, 60, 60, alpha]);
+        }
+    }
+    CustomCursor::from_rgba(rgba, SIZE, SIZE, (SIZE / 2, SIZE / 2))
+}
+
+fn app() -> impl IntoElement {
+    let dot = use_hook(dot_cursor);
+
+    rect()
+        .expanded()
+        .center()
+        .horizontal()
+        .spacing(12.)
+        .child(
+            rect()
+                .width(Size::px(150.))
+                .height(Size::px(150.))
+                .center()
+                .corner_radius(12.)
+                .background((100, 140, 230))
+                .cursor(CursorIcon::Pointer)
+                .child("Pointer"),
+        )
+        .child(
+            rect()
+                .width(Size::px(150.))
+                .height(Size::px(150.))
+                .center()
+                .corner_radius(12.)
+                .background((230, 200, 100))
+                .map(dot, |rect, dot| rect.cursor(dot))
+                .child("Custom image"),
+        )
+}
@@ -0,0 +1,98 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use std::time::{
+    Duration,
+    Instant,
+};
+
+use async_io::Timer;
+use freya::prelude::*;
+use skia_safe::{
+    ColorType,
+    ImageInfo,
+    Paint,
+    Surface,
+    gpu::{
+        Budgeted,
+        SurfaceOrigin,
+        surfaces,
+    },
+    surface::BackendHandleAccess,
+};
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app)))
+}
+
+fn app() -> impl IntoElement {
+    let texture = use_external_texture();
+
+    use_hook({
+        let texture = texture.clone();
+        move || {
+            // Stands in for another renderer that draws into its own texture of Freya's GPU context
+            let mut surface: Option<Surface> = None;
+            let started = Instant::now();
+            texture.on_render(move |direct_context, area| {
+                let (width, height) = (area.width() as i32, area.height() as i32);
+                if surface
+                    .as_ref()
+                    .is_none_or(|surface| surface.width() != width || surface.height() != height)
+                {
+                    surface = surfaces::render_target(
+                        direct_context,
+                        Budgeted::Yes,
+                        &ImageInfo::new_n32_premul((width, height), None),
+                        None,
+                        SurfaceOrigin::TopLeft,
+                        None,
+                        false,
+                        None,
+                    );
+                }
+                let surface = surface.as_mut()?;
+
+                let offset = started.elapsed().as_secs_f32().sin() * width as f32 / 4.;
+                let mut paint = Paint::default();
+                paint.set_anti_alias(true);
+                paint.set_color(Color::from_rgb(240, 140, 60));
+                let canvas = surface.canvas();
+                canvas.clear(Color::from_rgb(30, 30, 30));
+                canvas.draw_circle(
+                    (width as f32 / 2. + offset, height as f32 / 2.),
+                    40.,
+                    &paint,
+                );
+
+                let backend_texture =
+                    surfaces::get_backend_texture(surface, BackendHandleAccess::FlushRead)?;
+                Some(ExternalTextureFrame::new(
+                    backend_texture,
+                    SurfaceOrigin::TopLeft,
+                    ColorType::N32,
+                ))
+            });
+        }
+    });
+
+    use_hook({
+        let texture = texture.clone();
+        move || {
+            spawn(async move {
+                loop {
+                    Timer::after(Duration::from_millis(16)).await;
+                    texture.redraw();
+                }
+            })
+        }
+    });
+
+    rect().expanded().center().child(
+        external_texture(texture)
+            .width(Size::px(400.))
+            .height(Size::px(300.)),
+    )
+}
@@ -28,6 +28,7 @@ fn app() -> impl IntoElement {
         .expanded()
         .center()
         .spacing(6.)
+        .text_direction(i18n.text_direction())
         .child(
             rect()
                 .spacing(6.)
@@ -37,4 +38,6 @@ fn app() -> impl IntoElement {
         )
         .child(t!("hello_world"))
         .child(t!("hello", name: "Freya!"))
+        .child(i18n.format_number(1234567.89, 2))
+        .child(i18n.format_date(std::time::SystemTime::now()))
 }
@@ -0,0 +1,65 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(
+        LaunchConfig::new()
+            .with_window(WindowConfig::new(app).with_monitor(MonitorSelector::Primary)),
+    )
+}
+
+fn app() -> impl IntoElement {
+    let mut monitors = use_state(Vec::new);
+    let window = use_window();
+
+    use_future(move || async move {
+        monitors.set(Platform::get().monitors().await);
+    });
+
+    let current = window.monitor().and_then(|monitor| monitor.name);
+
+    rect()
+        .expanded()
+        .center()
+        .spacing(6.)
+        .child(format!(
+            "Scale factor {} in {}",
+            window.scale_factor(),
+            current.as_deref().unwrap_or("an unknown monitor")
+        ))
+        .children(monitors.read().iter().enumerate().map(|(i, monitor)| {
+            let on_press = move |_| {
+                spawn(async move {
+                    Platform::get()
+                        .launch_window(
+                            WindowConfig::new(sub_app).with_monitor(MonitorSelector::Index(i)),
+                        )
+                        .await;
+                });
+            };
+
+            Button::new()
+                .key(i)
+                .on_press(on_press)
+                .child(format!(
+                    "Open in {} ({}x{})",
+                    monitor.name.as_deref().unwrap_or("Unknown"),
+                    monitor.size.width,
+                    monitor.size.height
+                ))
+                .into()
+        }))
+}
+
+fn sub_app() -> impl IntoElement {
+    let window = use_window();
+
+    rect()
+        .expanded()
+        .center()
+        .child(format!("Scale factor {}", window.scale_factor()))
+}
@@ -0,0 +1,46 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app).with_size(500., 450.)))
+}
+
+fn app() -> impl IntoElement {
+    let mut clicks = use_state(|| 0);
+    let mut progress = use_state(|| 0.);
+
+    rect()
+        .expanded()
+        .center()
+        .spacing(12.)
+        .child(format!("Notification clicked {} times", clicks()))
+        .child(
+            Button::new()
+                .on_press(move |_| {
+                    Platform::get().show_notification(
+                        Notification::new("Hello from Freya")
+                            .body("Click me to increase the counter")
+                            .on_click(move |_| *clicks.write() += 1),
+                    );
+                    Platform::get().set_taskbar_badge(Some(1));
+                })
+                .child("Notify"),
+        )
+        .child(
+            Button::new()
+                .on_press(move |_| {
+                    let value = (progress() + 0.25) % 1.25;
+                    progress.set(value);
+                    Platform::get().set_taskbar_progress(if value > 0. {
+                        TaskbarProgress::Normal(value)
+                    } else {
+                        TaskbarProgress::None
+                    });
+                    Platform::get().set_taskbar_badge(None);
+                })
+                .child(format!("Progress {:.0}%", progress() * 100.)),
+        )
+}
@@ -0,0 +1,71 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+use std::time::{
+    Duration,
+    Instant,
+};
+
+use async_io::Timer;
+use freya::prelude::*;
+use torin::prelude::Area;
+
+const SKSL: &str = "
+    uniform shader content;
+    uniform float2 u_resolution;
+    uniform float2 u_offset;
+    uniform float2 u_cursor;
+    uniform float u_time;
+
+    half4 main(float2 coord) {
+        float2 uv = (coord - u_offset) / u_resolution;
+        float light = 1.0 - distance(uv, u_cursor) * 1.5;
+        float wave = sin(uv.x * 20.0 + u_time * 3.0) * 0.1;
+        half4 color = content.eval(coord);
+        return half4(color.rgb * half(clamp(light + wave, 0.2, 1.0)), color.a);
+    }
+";
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app)))
+}
+
+fn app() -> impl IntoElement {
+    let shader = use_hook(|| ShaderEffect::new(SKSL).expect("Invalid SkSL"));
+    let mut time = use_state(|| 0.);
+    let mut cursor = use_state(|| (0.5, 0.5));
+    let mut size = use_state(Area::default);
+
+    use_hook(move || {
+        spawn(async move {
+            let started = Instant::now();
+            loop {
+                Timer::after(Duration::from_millis(16)).await;
+                time.set(started.elapsed().as_secs_f32());
+            }
+        })
+    });
+
+    rect()
+        .expanded()
+        .center()
+        .background((120, 90, 220))
+        .color(Color::WHITE)
+        .font_size(32.)
+        .on_sized(move |e: Event<SizedEventData>| size.set(e.area))
+        .on_mouse_move(move |e: Event<MouseEventData>| {
+            let size = size.read();
+            cursor.set((
+                e.element_location.x as f32 / size.width().max(1.),
+                e.element_location.y as f32 / size.height().max(1.),
+            ));
+        })
+        .shader(
+            shader
+                .clone()
+                .uniform("u_time", time())
+                .uniform("u_cursor", cursor()),
+        )
+        .child("Move the cursor around")
+}
@@ -0,0 +1,38 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app)))
+}
+
+fn app() -> impl IntoElement {
+    rect()
+        .padding(25.)
+        .spacing(10.)
+        .font_size(20.)
+        .child(
+            label()
+                .font_size(30.)
+                .selectable(true)
+                .theme_highlight_color()
+                .text("Drag across the text below, then copy it"),
+        )
+        .child(
+            paragraph()
+                .selectable(true)
+                .theme_highlight_color()
+                .span("The selection can start in one element ")
+                .span(Span::new("and end in another one.").font_weight(FontWeight::BOLD)),
+        )
+        .child(
+            label()
+                .selectable(true)
+                .theme_highlight_color()
+                .text("Use Ctrl+A to select everything"),
+        )
+        .child(label().text("This text can't be selected"))
+}
@@ -1,7 +1,13 @@
 use freya::prelude::*;
 
 fn main() {
-    launch(LaunchConfig::new().with_window(WindowConfig::new(app).with_decorations(false)))
+    launch(
+        LaunchConfig::new().with_window(
+            WindowConfig::new(app)
+                .with_decorations(false)
+                .with_resize_border(6.),
+        ),
+    )
 }
 
 fn app() -> impl IntoElement {
@@ -30,6 +36,7 @@ fn app() -> impl IntoElement {
         .vertical()
         .child(
             rect()
+                .window_drag()
                 .horizontal()
                 .background((225, 225, 225))
                 .content(Content::Flex)
@@ -37,15 +44,16 @@ fn app() -> impl IntoElement {
                 .cross_align(Alignment::Center)
                 .padding((0., 0., 0., 8.))
                 .child("Custom Titlebar!")
+                .child(rect().width(Size::flex(1.)))
                 .child(
+                    // Pressing the buttons must not move the window
                     rect()
-                        .window_drag()
-                        .width(Size::flex(1.))
-                        .height(Size::fill()),
-                )
-                .child(TitlebarButton::new(TitlebarAction::Minimize).on_press(minimize))
-                .child(TitlebarButton::new(TitlebarAction::Maximize).on_press(maximize))
-                .child(TitlebarButton::new(TitlebarAction::Close).on_press(close)),
+                        .window_no_drag()
+                        .horizontal()
+                        .child(TitlebarButton::new(TitlebarAction::Minimize).on_press(minimize))
+                        .child(TitlebarButton::new(TitlebarAction::Maximize).on_press(maximize))
+                        .child(TitlebarButton::new(TitlebarAction::Close).on_press(close)),
+                ),
         )
         .child(rect().expanded().center().child("Hello, World!"))
 }
@@ -0,0 +1,69 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+use freya::{
+    prelude::*,
+    tray::{
+        TrayConfig,
+        TrayMenu,
+        use_tray_event,
+    },
+};
+
+const ICON: &[u8] = include_bytes!("./freya_icon.png");
+
+fn main() {
+    launch(
+        LaunchConfig::new()
+            .with_window(
+                WindowConfig::new(app)
+                    .with_size(500., 450.)
+                    .with_hide_to_tray(true),
+            )
+            .with_tray(
+                || {
+                    TrayConfig::new(ICON)
+                        .with_tooltip("Freya Tray")
+                        .with_menu(
+                            TrayMenu::new()
+                                .item("show", "Show window")
+                                .item("hide", "Hide window")
+                                .separator()
+                                .item("quit", "Quit"),
+                        )
+                        .build()
+                        .unwrap()
+                },
+                |event, mut ctx: RendererContext| {
+                    if event.menu_id() == Some("quit") {
+                        ctx.exit();
+                    }
+                },
+            ),
+    )
+}
+
+fn app() -> impl IntoElement {
+    let mut events = use_state(|| 0);
+
+    use_tray_event(move |event| {
+        *events.write() += 1;
+        match event.menu_id() {
+            Some("show") => Platform::get().with_window(None, |window| {
+                window.set_visible(true);
+                window.focus_window();
+            }),
+            Some("hide") => Platform::get().with_window(None, |window| {
+                window.set_visible(false);
+            }),
+            _ => {}
+        }
+    });
+
+    rect()
+        .expanded()
+        .center()
+        .child("Close this window, it will keep running in the tray.")
+        .child(format!("Tray events: {}", events.read()))
+}
@@ -0,0 +1,55 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app).with_size(500., 450.)))
+}
+
+fn app() -> impl IntoElement {
+    let window = use_window();
+    let mut always_on_top = use_state(|| false);
+
+    let size = window.size();
+    let position = window
+        .position()
+        .map(|position| format!("{}, {}", position.x, position.y))
+        .unwrap_or_else(|| "Unknown".to_string());
+
+    rect()
+        .expanded()
+        .center()
+        .spacing(8.)
+        .child(format!("Size: {}x{}", size.width, size.height))
+        .child(format!("Position: {position}"))
+        .child(
+            Button::new()
+                .on_press(move |_| window.minimize())
+                .child("Minimize"),
+        )
+        .child(
+            Button::new()
+                .on_press(move |_| window.toggle_maximized())
+                .child("Toggle maximized"),
+        )
+        .child(
+            Button::new()
+                .on_press(move |_| window.toggle_fullscreen())
+                .child("Toggle fullscreen"),
+        )
+        .child(
+            Button::new()
+                .on_press(move |_| {
+                    let always_on_top = always_on_top.toggled();
+                    window.set_always_on_top(always_on_top);
+                    window.set_title(if always_on_top {
+                        "Always on top"
+                    } else {
+                        "Freya"
+                    });
+                })
+                .child("Toggle always on top"),
+        )
+}
@@ -0,0 +1,24 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(
+        LaunchConfig::new().with_window(
+            WindowConfig::new(app)
+                .with_backdrop(WindowBackdrop::Mica)
+                .with_background((255, 255, 255, 0.3)),
+        ),
+    )
+}
+
+fn app() -> impl IntoElement {
+    rect()
+        .expanded()
+        .center()
+        .font_size(24.)
+        .child("Hello from a Mica window!")
+}
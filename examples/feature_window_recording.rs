@@ -0,0 +1,58 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+use std::{
+    cell::RefCell,
+    rc::Rc,
+};
+
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app).with_size(500., 450.)))
+}
+
+fn app() -> impl IntoElement {
+    let frames = use_hook(|| Rc::new(RefCell::new(Vec::<RecordedFrame>::new())));
+    let mut recording = use_state(|| false);
+    let mut summary = use_state(String::new);
+    let mut count = use_state(|| 0);
+
+    let on_record = move |_| {
+        if recording.toggled() {
+            frames.borrow_mut().clear();
+            let frames = frames.clone();
+            Platform::get().start_recording(move |frame| frames.borrow_mut().push(frame));
+        } else {
+            Platform::get().stop_recording();
+            let frames = frames.borrow();
+            let duration = frames
+                .last()
+                .map(|frame| frame.timestamp)
+                .unwrap_or_default();
+            summary.set(format!(
+                "Recorded {} frames in {:.2}s",
+                frames.len(),
+                duration.as_secs_f32()
+            ));
+        }
+    };
+
+    rect()
+        .expanded()
+        .center()
+        .spacing(8.)
+        .child(format!("Count: {}", count.read()))
+        .child(
+            Button::new()
+                .on_press(move |_| *count.write() += 1)
+                .child("Increase"),
+        )
+        .child(
+            Button::new()
+                .on_press(on_record)
+                .child(if recording() { "Stop" } else { "Record" }),
+        )
+        .child(summary.read().clone())
+}
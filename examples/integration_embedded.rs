@@ -0,0 +1,143 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use std::time::{
+    Duration,
+    Instant,
+};
+
+use async_io::Timer;
+use freya::{
+    prelude::*,
+    winit::{
+        application::ApplicationHandler,
+        event::{
+            ElementState,
+            WindowEvent,
+        },
+        event_loop::{
+            ActiveEventLoop,
+            ControlFlow,
+            EventLoop,
+        },
+        raw_window_handle::{
+            HasDisplayHandle,
+            HasWindowHandle,
+        },
+        window::{
+            Window,
+            WindowId,
+        },
+    },
+};
+use freya_core::integration::{
+    MouseButton,
+    MouseEventName,
+    PlatformEvent,
+};
+use freya_winit::embedded::EmbeddedRenderer;
+
+/// A host that owns its window and event loop, and only renders the Freya app when it needs to.
+#[derive(Default)]
+struct Host {
+    window: Option<Window>,
+    renderer: Option<EmbeddedRenderer>,
+    cursor: CursorPoint,
+}
+
+impl ApplicationHandler for Host {
+    fn resumed(&mut self, event_loop: &ActiveEventLoop) {
+        let window = event_loop
+            .create_window(Window::default_attributes().with_title("Embedded"))
+            .expect("Failed to create the window.");
+        let renderer = unsafe {
+            EmbeddedRenderer::new(
+                app,
+                window.display_handle().unwrap().as_raw(),
+                window.window_handle().unwrap().as_raw(),
+                window.inner_size(),
+                window.scale_factor(),
+            )
+        }
+        .expect("Failed to create the renderer.");
+        self.window = Some(window);
+        self.renderer = Some(renderer);
+    }
+
+    fn window_event(&mut self, event_loop: &ActiveEventLoop, _: WindowId, event: WindowEvent) {
+        let (Some(window), Some(renderer)) = (&self.window, &mut self.renderer) else {
+            return;
+        };
+        match event {
+            WindowEvent::CloseRequested => event_loop.exit(),
+            WindowEvent::Resized(size) => renderer.resize(size, window.scale_factor()),
+            WindowEvent::CursorMoved { position, .. } => {
+                self.cursor = CursorPoint::new(position.x, position.y);
+                renderer.handle_event(PlatformEvent::Mouse {
+                    name: MouseEventName::MouseMove,
+                    cursor: self.cursor,
+                    button: None,
+                    click_count: 0,
+                });
+            }
+            WindowEvent::MouseInput { state, .. } => {
+                renderer.handle_event(PlatformEvent::Mouse {
+                    name: match state {
+                        ElementState::Pressed => MouseEventName::MouseDown,
+                        ElementState::Released => MouseEventName::MouseUp,
+                    },
+                    cursor: self.cursor,
+                    button: Some(MouseButton::Left),
+                    click_count: 1,
+                });
+            }
+            WindowEvent::RedrawRequested => renderer.render(),
+            _ => {}
+        }
+    }
+
+    fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
+        // Timers and animations wake up tasks without any input, so poll every frame
+        if let (Some(window), Some(renderer)) = (&self.window, &mut self.renderer)
+            && renderer.needs_render()
+        {
+            window.request_redraw();
+        }
+        event_loop.set_control_flow(ControlFlow::WaitUntil(
+            Instant::now() + Duration::from_millis(16),
+        ));
+    }
+}
+
+fn main() {
+    let event_loop = EventLoop::new().expect("Failed to create event loop.");
+    event_loop
+        .run_app(&mut Host::default())
+        .expect("Failed to run the event loop.");
+}
+
+fn app() -> impl IntoElement {
+    let mut seconds = use_state(|| 0);
+    let mut clicks = use_state(|| 0);
+
+    use_hook(move || {
+        spawn(async move {
+            loop {
+                Timer::after(Duration::from_secs(1)).await;
+                *seconds.write() += 1;
+            }
+        })
+    });
+
+    rect()
+        .expanded()
+        .center()
+        .on_press(move |_| *clicks.write() += 1)
+        .child(format!(
+            "{} seconds, {} clicks",
+            seconds.read(),
+            clicks.read()
+        ))
+}
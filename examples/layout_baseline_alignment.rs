@@ -0,0 +1,30 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app)))
+}
+
+fn app() -> impl IntoElement {
+    rect()
+        .expanded()
+        .center()
+        .child(
+            rect()
+                .horizontal()
+                .cross_align(Alignment::Baseline)
+                .spacing(8.)
+                .child(label().text("Big").font_size(48.))
+                .child(label().text("medium").font_size(24.))
+                .child(label().text("small").font_size(12.))
+                .child(
+                    rect()
+                        .width(Size::px(12.))
+                        .height(Size::px(12.))
+                        .background((0, 119, 182)),
+                ),
+        )
+}
@@ -0,0 +1,45 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app)))
+}
+
+fn app() -> impl IntoElement {
+    rect()
+        .expanded()
+        .padding(10.)
+        .content(Content::grid_spacing(
+            [GridSize::px(150.), GridSize::fr(1.), GridSize::fr(1.)],
+            [GridSize::auto(), GridSize::fr(1.), GridSize::px(60.)],
+            10.,
+            10.,
+        ))
+        .child(
+            rect()
+                .width(Size::fill())
+                .padding(10.)
+                .grid_column(GridPlacement::span(3))
+                .background((50, 50, 255))
+                .child("Header"),
+        )
+        .child(
+            rect()
+                .expanded()
+                .grid_row((1, 2))
+                .background((150, 150, 150))
+                .child("Sidebar"),
+        )
+        .child(rect().expanded().background((255, 50, 50)).child("Chart"))
+        .child(rect().expanded().background((50, 255, 50)).child("Stats"))
+        .child(
+            rect()
+                .expanded()
+                .grid_column((1, 2))
+                .background((200, 50, 200))
+                .child("Footer"),
+        )
+}
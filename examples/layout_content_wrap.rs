@@ -55,6 +55,7 @@ fn app() -> impl IntoElement {
         .child(
             rect()
                 .content(Content::wrap())
+                .wrap_align(Alignment::SpaceEvenly)
                 .width(Size::fill())
                 .height(Size::flex(1.))
                 .children(cards()),
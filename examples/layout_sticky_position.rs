@@ -0,0 +1,43 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app)))
+}
+
+fn app() -> impl IntoElement {
+    rect()
+        .expanded()
+        .child(ScrollView::new().children((0..5).map(|section| {
+            rect()
+                .width(Size::fill())
+                .child(
+                    rect()
+                        .position(Position::new_sticky().top(0.))
+                        .width(Size::fill())
+                        .padding(8.)
+                        .background((0, 119, 182))
+                        .color((255, 255, 255))
+                        .child(format!("Section {section}")),
+                )
+                .children((0..10).map(|row| {
+                    rect()
+                        .width(Size::fill())
+                        .padding(8.)
+                        .child(format!("Row {row}"))
+                        .into()
+                }))
+                .into()
+        })))
+        .child(
+            rect()
+                .position(Position::new_fixed().bottom(16.).right(16.))
+                .padding(12.)
+                .corner_radius(8.)
+                .background((182, 119, 0))
+                .child("Fixed"),
+        )
+}
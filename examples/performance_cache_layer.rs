@@ -0,0 +1,52 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+use freya::{
+    animation::*,
+    prelude::*,
+};
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app)))
+}
+
+fn app() -> impl IntoElement {
+    let animation = use_animation(|conf| {
+        conf.on_creation(OnCreation::Run);
+        conf.on_finish(OnFinish::reverse());
+        AnimNum::new(0., 360.).time(2000)
+    });
+
+    rect()
+        .expanded()
+        .center()
+        .spacing(24.)
+        .child(
+            rect()
+                .width(Size::px(60.))
+                .height(Size::px(60.))
+                .background((205, 86, 86))
+                .rotation(animation.read().value()),
+        )
+        .child(
+            // This grid is recorded once and reused while the square above spins
+            rect()
+                .cache_layer(true)
+                .spacing(2.)
+                .children((0..40).map(|row| {
+                    rect()
+                        .horizontal()
+                        .spacing(2.)
+                        .children((0..60).map(move |column| {
+                            rect()
+                                .width(Size::px(8.))
+                                .height(Size::px(8.))
+                                .corner_radius(2.)
+                                .background(((row * 6) as u8, (column * 4) as u8, 180))
+                                .into()
+                        }))
+                        .into()
+                })),
+        )
+}
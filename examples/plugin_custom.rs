@@ -0,0 +1,73 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::{
+    engine::prelude::{
+        Paint,
+        PaintStyle,
+        SkRect,
+    },
+    prelude::*,
+    winit::event::WindowEvent,
+};
+use freya_winit::plugins::{
+    FreyaPlugin,
+    PluginEvent,
+    PluginHandle,
+};
+
+/// Logs the window events, draws a frame around every window and reports when the app exits.
+#[derive(Default)]
+struct FramePlugin {
+    resizes: usize,
+}
+
+impl FreyaPlugin for FramePlugin {
+    fn plugin_id(&self) -> &'static str {
+        "frame-plugin"
+    }
+
+    fn on_event(&mut self, event: &mut PluginEvent, _handle: PluginHandle) {
+        match event {
+            PluginEvent::WindowEvent { event, .. } => {
+                if let WindowEvent::Resized(size) = event {
+                    self.resizes += 1;
+                    println!("Resized to {}x{}", size.width, size.height);
+                }
+            }
+            PluginEvent::AfterRender { window, canvas, .. } => {
+                let size = window.inner_size();
+                let mut paint = Paint::default();
+                paint.set_anti_alias(true);
+                paint.set_style(PaintStyle::Stroke);
+                paint.set_stroke_width(8.);
+                paint.set_color(Color::from_rgb(230, 60, 60));
+                canvas.draw_rect(
+                    SkRect::new(0., 0., size.width as f32, size.height as f32),
+                    &paint,
+                );
+            }
+            PluginEvent::Exiting => {
+                println!("Exiting after {} resizes", self.resizes);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn main() {
+    launch(
+        LaunchConfig::new()
+            .with_plugin(FramePlugin::default())
+            .with_window(WindowConfig::new(app)),
+    )
+}
+
+fn app() -> impl IntoElement {
+    rect()
+        .expanded()
+        .center()
+        .child("Resize the window and close it")
+}
@@ -196,17 +196,8 @@ impl Component for ShaderView {
     fn render(&self) -> impl IntoElement {
         let editable = self.0;
 
-        use_hook(|| {
-            let mut ticker = consume_root_context::<RenderingTicker>();
-            let platform = Platform::get();
-
-            spawn(async move {
-                loop {
-                    ticker.tick().await;
-                    platform.send(UserEvent::RequestRedraw);
-                }
-            });
-        });
+        // The shader is animated so render every frame
+        use_hook(|| Platform::get().set_render_mode(RenderMode::Continuous));
 
         let runtime_effect = use_side_effect_value(move || {
             RuntimeEffect::make_for_shader(editable.editor().read().rope().to_string(), None)
@@ -0,0 +1,49 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app).with_size(700., 400.)))
+}
+
+fn app() -> impl IntoElement {
+    let modes = [
+        BlendMode::Normal,
+        BlendMode::Multiply,
+        BlendMode::Screen,
+        BlendMode::Overlay,
+        BlendMode::Difference,
+        BlendMode::Exclusion,
+    ];
+
+    rect()
+        .expanded()
+        .center()
+        .horizontal()
+        .spacing(12.)
+        .background_linear_gradient(
+            LinearGradient::new()
+                .angle(90.)
+                .stop(((255, 190, 0), 0.))
+                .stop(((0, 120, 255), 100.)),
+        )
+        .children(modes.into_iter().map(|mode| {
+            rect()
+                .width(Size::px(100.))
+                .height(Size::px(100.))
+                .center()
+                .corner_radius(50.)
+                .background((230, 40, 90))
+                .blend_mode(mode)
+                .child(
+                    label()
+                        .text(mode.pretty())
+                        .color(Color::WHITE)
+                        .blend_mode(BlendMode::Overlay),
+                )
+                .into()
+        }))
+}
@@ -0,0 +1,63 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app)))
+}
+
+fn app() -> impl IntoElement {
+    rect()
+        .expanded()
+        .center()
+        .horizontal()
+        .spacing(24.)
+        .child(
+            rect()
+                .width(Size::px(150.))
+                .height(Size::px(150.))
+                .corner_radius(24.)
+                .border(
+                    Border::new()
+                        .width(BorderWidth {
+                            top: 4.,
+                            right: 8.,
+                            bottom: 12.,
+                            left: 8.,
+                        })
+                        .side_fills(BorderColor::new(
+                            (255, 100, 50),
+                            (255, 192, 203),
+                            (0, 119, 182),
+                            (255, 192, 203),
+                        )),
+                ),
+        )
+        .child(
+            rect()
+                .width(Size::px(150.))
+                .height(Size::px(150.))
+                .corner_radius(24.)
+                .border(
+                    Border::new()
+                        .width(4.)
+                        .fill((0, 119, 182))
+                        .style(BorderStyle::Dashed),
+                ),
+        )
+        .child(
+            rect()
+                .width(Size::px(150.))
+                .height(Size::px(150.))
+                .corner_radius(75.)
+                .border(
+                    Border::new()
+                        .width(6.)
+                        .fill((255, 100, 50))
+                        .alignment(BorderAlignment::Outer)
+                        .style(BorderStyle::Dotted),
+                ),
+        )
+}
@@ -0,0 +1,69 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app).with_size(700., 400.)))
+}
+
+fn app() -> impl IntoElement {
+    let mut clicks = use_state(|| 0);
+
+    rect()
+        .expanded()
+        .center()
+        .spacing(16.)
+        .child(
+            rect()
+                .horizontal()
+                .spacing(16.)
+                .child(shape("Circle", ClipPath::Circle).on_press(move |_| *clicks.write() += 1))
+                .child(
+                    shape(
+                        "Star",
+                        ClipPath::polygon([
+                            (0.5, 0.),
+                            (0.62, 0.38),
+                            (1., 0.38),
+                            (0.69, 0.62),
+                            (0.81, 1.),
+                            (0.5, 0.76),
+                            (0.19, 1.),
+                            (0.31, 0.62),
+                            (0., 0.38),
+                            (0.38, 0.38),
+                        ]),
+                    )
+                    .on_press(move |_| *clicks.write() += 1),
+                )
+                .child(
+                    shape(
+                        "Path",
+                        ClipPath::path("M 0 75 Q 75 0 150 75 Q 75 150 0 75 Z"),
+                    )
+                    .on_press(move |_| *clicks.write() += 1),
+                )
+                .child(
+                    shape("Fade", ClipPath::Ellipse).mask(
+                        LinearGradient::new()
+                            .stop(((0, 0, 0), 0.))
+                            .stop(((0, 0, 0, 0.), 100.)),
+                    ),
+                ),
+        )
+        .child(format!("Clicks inside the shapes: {}", clicks()))
+}
+
+fn shape(text: &str, clip_path: ClipPath) -> Rect {
+    rect()
+        .width(Size::px(150.))
+        .height(Size::px(150.))
+        .center()
+        .background((60, 130, 240))
+        .color(Color::WHITE)
+        .clip_path(clip_path)
+        .child(text.to_string())
+}
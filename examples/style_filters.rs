@@ -0,0 +1,55 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app).with_size(700., 500.)))
+}
+
+fn app() -> impl IntoElement {
+    let mut disabled = use_state(|| false);
+
+    ImageViewer::new(
+        "https://github.com/user-attachments/assets/2528e366-a149-469f-a66c-82e5b572ca7c",
+    )
+    .aspect_ratio(AspectRatio::Max)
+    .image_cover(ImageCover::Center)
+    .expanded()
+    .center()
+    .spacing(16.)
+    .child(
+        rect()
+            .horizontal()
+            .spacing(16.)
+            .child(panel("Backdrop blur").backdrop_blur(10.))
+            .child(panel("Brightness").backdrop_blur(10.).brightness(1.5))
+            .child(panel("Saturate").backdrop_blur(10.).saturate(3.)),
+    )
+    .child(
+        rect()
+            .padding(12.)
+            .corner_radius(12.)
+            .background((255, 255, 255, 0.6))
+            .grayscale(if disabled() { 1. } else { 0. })
+            .opacity(if disabled() { 0.6 } else { 1. })
+            .child(
+                Button::new()
+                    .on_press(move |_| disabled.toggle())
+                    .child(if disabled() { "Enable" } else { "Disable" }),
+            ),
+    )
+}
+
+fn panel(text: &str) -> Rect {
+    rect()
+        .width(Size::px(140.))
+        .height(Size::px(100.))
+        .center()
+        .background((255, 120, 120, 0.30))
+        .color((255, 255, 255))
+        .corner_radius(12.0)
+        .child(text.to_string())
+}
@@ -0,0 +1,57 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app)))
+}
+
+fn app() -> impl IntoElement {
+    rect()
+        .expanded()
+        .center()
+        .horizontal()
+        .spacing(16.)
+        .child(
+            rect()
+                .height(Size::px(220.))
+                .width(Size::px(220.))
+                .background_linear_gradient(
+                    LinearGradient::new()
+                        .angle(45.)
+                        .repeating(true)
+                        .stop(((255, 214, 10), 0.))
+                        .stop(((255, 214, 10), 5.))
+                        .stop(((30, 30, 30), 5.))
+                        .stop(((30, 30, 30), 10.)),
+                ),
+        )
+        .child(
+            rect()
+                .height(Size::px(220.))
+                .width(Size::px(220.))
+                .background_radial_gradient(
+                    RadialGradient::new()
+                        .center(0.3, 0.3)
+                        .repeating(true)
+                        .stop(((255, 100, 50), 0.))
+                        .stop(((255, 192, 203), 10.)),
+                ),
+        )
+        .child(
+            rect()
+                .height(Size::px(220.))
+                .width(Size::px(220.))
+                .background_conic_gradient(
+                    ConicGradient::new()
+                        .center(0.5, 0.7)
+                        .repeating(true)
+                        .stop(((0, 119, 182), 0.))
+                        .stop(((0, 119, 182), 6.25))
+                        .stop(((240, 240, 240), 6.25))
+                        .stop(((240, 240, 240), 12.5)),
+                ),
+        )
+}
@@ -0,0 +1,44 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app)))
+}
+
+fn app() -> impl IntoElement {
+    rect()
+        .expanded()
+        .center()
+        .spacing(16.)
+        .background_linear_gradient(
+            LinearGradient::new()
+                .angle(45.)
+                .stop(((255, 214, 10), 0.))
+                .stop(((0, 119, 182), 50.))
+                .stop(((240, 240, 240), 100.)),
+        )
+        .font_size(48.)
+        .font_weight(FontWeight::BOLD)
+        .child(
+            label()
+                .text("Outlined")
+                .color(Color::WHITE)
+                .text_stroke((2., Color::BLACK)),
+        )
+        .child(label().text("Shadows").color(Color::WHITE).text_shadows([
+            TextShadow::new((0, 0, 0, 0.6).into(), (2., 2.), 2.),
+            TextShadow::new((255, 0, 80, 0.6).into(), (-2., -2.), 4.),
+        ]))
+        .child(
+            paragraph()
+                .span("Both ")
+                .span(Span::new("at once").color((255, 214, 10)))
+                .color(Color::WHITE)
+                .text_stroke((1.5, (20, 20, 20)))
+                .text_shadow(TextShadow::new(Color::BLACK, (0., 4.), 6.)),
+        )
+}
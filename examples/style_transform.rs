@@ -0,0 +1,53 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+
+use freya::prelude::*;
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app).with_size(700., 400.)))
+}
+
+fn app() -> impl IntoElement {
+    let mut clicks = use_state(|| 0);
+
+    rect()
+        .expanded()
+        .center()
+        .spacing(48.)
+        .child(
+            rect()
+                .horizontal()
+                .spacing(48.)
+                .child(
+                    Button::new()
+                        .on_press(move |_| *clicks.write() += 1)
+                        .child("Normal"),
+                )
+                .child(
+                    rect().transform(Transform::new().rotate(30.)).child(
+                        Button::new()
+                            .on_press(move |_| *clicks.write() += 1)
+                            .child("Rotated"),
+                    ),
+                )
+                .child(
+                    rect()
+                        .transform(Transform::new().skew(20., 0.).scale(1.5))
+                        .child(
+                            Button::new()
+                                .on_press(move |_| *clicks.write() += 1)
+                                .child("Skewed"),
+                        ),
+                )
+                .child(
+                    rect().transform(Transform::new().translate(0., 40.)).child(
+                        Button::new()
+                            .on_press(move |_| *clicks.write() += 1)
+                            .child("Translated"),
+                    ),
+                ),
+        )
+        .child(format!("Clicks: {}", clicks()))
+}
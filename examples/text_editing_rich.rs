@@ -0,0 +1,82 @@
+#![cfg_attr(
+    all(not(debug_assertions), target_os = "windows"),
+    windows_subsystem = "windows"
+)]
+use freya::{
+    prelude::*,
+    text_edit::*,
+};
+
+fn main() {
+    launch(LaunchConfig::new().with_window(WindowConfig::new(app)))
+}
+
+fn app() -> impl IntoElement {
+    let holder = use_state(ParagraphHolder::default);
+    let mut editable = use_rich_editable(
+        || {
+            vec![
+                RichTextSegment::new("Use Ctrl+B and Ctrl+I to make text ", Default::default()),
+                RichTextSegment::new("bold", TextAttributes::default().bold(true)),
+                RichTextSegment::new(" or ", Default::default()),
+                RichTextSegment::new("italic", TextAttributes::default().italic(true)),
+            ]
+        },
+        || EditableConfig::new().with_allow_formatting(true),
+    );
+    let focus = use_focus();
+
+    let editor = editable.editor().read();
+
+    rect()
+        .padding(25.)
+        .spacing(10.)
+        .child(
+            Button::new()
+                .on_press(move |_| {
+                    editable
+                        .editor_mut()
+                        .write()
+                        .format_selection(|attributes| {
+                            attributes.color = Some(Color::RED);
+                        })
+                })
+                .child("Red"),
+        )
+        .child(
+            paragraph()
+                .a11y_id(focus.a11y_id())
+                .font_size(20.)
+                .cursor_index(editor.cursor_pos())
+                .highlights(editor.get_selection().map(|selection| vec![selection]))
+                .on_mouse_down(move |e: Event<MouseEventData>| {
+                    focus.request_focus();
+                    editable.process_event(EditableEvent::Down {
+                        location: e.element_location,
+                        editor_line: EditorLine::SingleParagraph,
+                        holder: &holder.read(),
+                    });
+                })
+                .on_mouse_move(move |e: Event<MouseEventData>| {
+                    editable.process_event(EditableEvent::Move {
+                        location: e.element_location,
+                        editor_line: EditorLine::SingleParagraph,
+                        holder: &holder.read(),
+                    });
+                })
+                .on_global_pointer_press(move |_: Event<PointerEventData>| {
+                    editable.process_event(EditableEvent::Release)
+                })
+                .on_key_down(move |e: Event<KeyboardEventData>| {
+                    editable.process_event(EditableEvent::KeyDown {
+                        key: &e.key,
+                        modifiers: e.modifiers,
+                    });
+                })
+                .on_key_up(move |e: Event<KeyboardEventData>| {
+                    editable.process_event(EditableEvent::KeyUp { key: &e.key });
+                })
+                .spans_iter(editor.spans().into_iter())
+                .holder(holder.read().clone()),
+        )
+}